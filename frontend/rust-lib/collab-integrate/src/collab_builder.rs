@@ -1,7 +1,11 @@
 use std::borrow::BorrowMut;
+use std::collections::HashMap;
 use std::fmt::{Debug, Display};
-use std::sync::{Arc, Weak};
+use std::sync::{Arc, Mutex as StdMutex, RwLock as StdRwLock, Weak};
 
+use crate::snapshot::{
+  encode_snapshot, SnapshotCheckpoint, SnapshotPlugin, SnapshotPolicy, SnapshotState,
+};
 use crate::CollabKVDB;
 use anyhow::Error;
 use arc_swap::{ArcSwap, ArcSwapOption};
@@ -74,6 +78,8 @@ pub struct AppFlowyCollabBuilder {
   network_reachability: CollabConnectReachability,
   plugin_provider: ArcSwap<Arc<dyn CollabCloudPluginProvider>>,
   snapshot_persistence: ArcSwapOption<Arc<dyn SnapshotPersistence + 'static>>,
+  snapshot_policies: StdRwLock<HashMap<CollabType, SnapshotPolicy>>,
+  snapshot_states: StdMutex<HashMap<String, Weak<SnapshotState>>>,
   #[cfg(not(target_arch = "wasm32"))]
   rocksdb_backup: ArcSwapOption<Arc<dyn RocksdbBackup>>,
   workspace_integrate: Arc<dyn WorkspaceCollabIntegrate>,
@@ -88,6 +94,8 @@ impl AppFlowyCollabBuilder {
       network_reachability: CollabConnectReachability::new(),
       plugin_provider: ArcSwap::new(Arc::new(Arc::new(storage_provider))),
       snapshot_persistence: Default::default(),
+      snapshot_policies: Default::default(),
+      snapshot_states: Default::default(),
       #[cfg(not(target_arch = "wasm32"))]
       rocksdb_backup: Default::default(),
       workspace_integrate: Arc::new(workspace_integrate),
@@ -100,6 +108,154 @@ impl AppFlowyCollabBuilder {
       .store(Some(snapshot_persistence.into()));
   }
 
+  /// Overrides the [SnapshotPolicy] of the given object type. The policy applies to the objects
+  /// that are built after it is set.
+  pub fn set_snapshot_policy(&self, collab_type: CollabType, policy: SnapshotPolicy) {
+    if let Ok(mut policies) = self.snapshot_policies.write() {
+      policies.insert(collab_type, policy);
+    }
+  }
+
+  /// Returns the [SnapshotPolicy] of the given object type. Snapshots are disabled unless a
+  /// policy was set with [AppFlowyCollabBuilder::set_snapshot_policy].
+  pub fn snapshot_policy(&self, collab_type: &CollabType) -> SnapshotPolicy {
+    self
+      .snapshot_policies
+      .read()
+      .ok()
+      .and_then(|policies| policies.get(collab_type).cloned())
+      .unwrap_or_else(SnapshotPolicy::disabled)
+  }
+
+  /// Creates a snapshot of the current state of the collab regardless of the [SnapshotPolicy].
+  /// It's used before the operations that are hard to undo, e.g. switching the type of a field.
+  pub fn create_snapshot<T>(
+    &self,
+    uid: i64,
+    collab_type: &CollabType,
+    collab: &T,
+  ) -> Result<(), Error>
+  where
+    T: BorrowMut<Collab> + Send + Sync + 'static,
+  {
+    let snapshot_persistence = self
+      .snapshot_persistence
+      .load_full()
+      .ok_or_else(|| anyhow::anyhow!("snapshot persistence is not set"))?;
+    let collab: &Collab = collab.borrow();
+    let object_id = collab.object_id().to_string();
+    let checkpoint = self.snapshot_checkpoint(&object_id);
+    let encoded_v1 = encode_snapshot(collab_type, collab)?;
+    trace!("create {} snapshot: {}", collab_type, object_id);
+    snapshot_persistence.create_snapshot(uid, &object_id, collab_type, encoded_v1)?;
+    if let Some(checkpoint) = checkpoint {
+      self.did_save_snapshot(&object_id, &checkpoint);
+    }
+    Ok(())
+  }
+
+  /// Captures the triggers of the [SnapshotPolicy] of the object before a snapshot of it is
+  /// encoded without the builder. Must be called while no update can be applied to the object.
+  pub fn snapshot_checkpoint(&self, object_id: &str) -> Option<SnapshotCheckpoint> {
+    self.snapshot_state(object_id)?.checkpoint()
+  }
+
+  /// Restarts the triggers of the [SnapshotPolicy] of the object from the checkpoint, once the
+  /// snapshot encoded at the checkpoint is saved. The triggers are kept if the snapshot fails,
+  /// so the object still gets a snapshot on its next update.
+  pub fn did_save_snapshot(&self, object_id: &str, checkpoint: &SnapshotCheckpoint) {
+    if let Some(state) = self.snapshot_state(object_id) {
+      state.did_save_snapshot(checkpoint);
+    }
+  }
+
+  /// Creates a snapshot of the collab if the [SnapshotPolicy] it was built with asks for a
+  /// snapshot on close and the collab was edited since its last snapshot. Called when the object
+  /// is closed by the user.
+  pub fn create_snapshot_on_close<T>(&self, uid: i64, collab_type: &CollabType, collab: &T)
+  where
+    T: BorrowMut<Collab> + Send + Sync + 'static,
+  {
+    let object_id = collab.borrow().object_id().to_string();
+    let should_create = self
+      .snapshot_state(&object_id)
+      .map(|state| state.policy().on_close && state.has_pending_updates())
+      .unwrap_or(false);
+    if !should_create {
+      return;
+    }
+    if let Err(err) = self.create_snapshot(uid, collab_type, collab) {
+      warn!("create snapshot on close failed: {}", err);
+    }
+  }
+
+  fn snapshot_state(&self, object_id: &str) -> Option<Arc<SnapshotState>> {
+    self
+      .snapshot_states
+      .lock()
+      .ok()
+      .and_then(|states| states.get(object_id).and_then(Weak::upgrade))
+  }
+
+  fn insert_snapshot_state(&self, object_id: &str, state: &Arc<SnapshotState>) {
+    if let Ok(mut states) = self.snapshot_states.lock() {
+      states.retain(|_, state| state.strong_count() > 0);
+      states.insert(object_id.to_string(), Arc::downgrade(state));
+    }
+  }
+
+  /// Starts the task that creates the snapshots of the collab when its [SnapshotState] says that
+  /// a snapshot is due. The task ends when the collab is closed.
+  fn spawn_snapshot_task<T>(&self, object: &CollabObject, collab: &Arc<RwLock<T>>)
+  where
+    T: BorrowMut<Collab> + Send + Sync + 'static,
+  {
+    let (Some(state), Some(snapshot_persistence)) = (
+      self.snapshot_state(&object.object_id),
+      self.snapshot_persistence.load_full(),
+    ) else {
+      return;
+    };
+
+    let uid = object.uid;
+    let object_id = object.object_id.clone();
+    let collab_type = object.collab_type.clone();
+    let weak_collab = Arc::downgrade(collab);
+    tokio::spawn(async move {
+      while state.wait_until_due().await {
+        let Some(collab) = weak_collab.upgrade() else {
+          break;
+        };
+        let (checkpoint, result) = {
+          let read_guard = collab.read().await;
+          // no update can be applied while the read lock is held
+          let checkpoint = state.checkpoint();
+          (
+            checkpoint,
+            encode_snapshot(&collab_type, (*read_guard).borrow()),
+          )
+        };
+        drop(collab);
+
+        trace!("create {} snapshot: {}", collab_type, object_id);
+        let result = result.and_then(|encoded_v1| {
+          snapshot_persistence
+            .create_snapshot(uid, &object_id, &collab_type, encoded_v1)
+            .map_err(Error::from)
+        });
+        match result {
+          // The trigger still fires on the next update if the snapshot failed
+          Ok(_) => {
+            if let Some(checkpoint) = checkpoint {
+              state.did_save_snapshot(&checkpoint);
+            }
+          },
+          Err(err) => warn!("create snapshot for {} failed: {}", object_id, err),
+        }
+      }
+    });
+  }
+
   #[cfg(not(target_arch = "wasm32"))]
   pub fn set_rocksdb_backup(&self, rocksdb_backup: Arc<dyn RocksdbBackup>) {
     self.rocksdb_backup.store(Some(rocksdb_backup.into()));
@@ -283,6 +439,16 @@ impl AppFlowyCollabBuilder {
       persistence_config.clone(),
     );
     collab.add_plugin(Box::new(db_plugin));
+
+    let snapshot_policy = self.snapshot_policy(&object.collab_type);
+    if snapshot_policy.is_enabled() && self.snapshot_persistence.load().is_some() {
+      let state = Arc::new(SnapshotState::new(snapshot_policy));
+      self.insert_snapshot_state(&object.object_id, &state);
+      collab.add_plugin(Box::new(SnapshotPlugin::new(
+        object.collab_type.clone(),
+        state,
+      )));
+    }
     collab.initialize();
     Ok(collab)
   }
@@ -296,6 +462,7 @@ impl AppFlowyCollabBuilder {
   where
     T: BorrowMut<Collab> + Send + Sync + 'static,
   {
+    self.spawn_snapshot_task(&object, &collab);
     let mut write_collab = collab.try_write()?;
     let has_cloud_plugin = write_collab.borrow().has_cloud_plugin();
     if has_cloud_plugin {
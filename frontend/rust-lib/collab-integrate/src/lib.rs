@@ -5,6 +5,7 @@ use collab_plugins::{if_native, if_wasm};
 
 pub mod collab_builder;
 pub mod config;
+pub mod snapshot;
 
 if_native! {
     mod native;
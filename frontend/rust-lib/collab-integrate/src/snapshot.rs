@@ -0,0 +1,223 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use collab::core::collab_plugin::{CollabPlugin, CollabPluginType};
+use collab::preclude::{Collab, TransactionMut};
+use collab_entity::CollabType;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+use tracing::trace;
+
+/// Decides when the snapshots of a collab object are created. Every trigger is independent, so
+/// a snapshot is created as soon as any of them fires. Snapshots are opt-in: the default policy
+/// is [SnapshotPolicy::disabled] and a trigger never fires for an object that wasn't edited
+/// since its last snapshot.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotPolicy {
+  /// Create a snapshot after this many updates since the last snapshot.
+  pub every_n_updates: Option<u32>,
+  /// Create a snapshot on the first update after this much time has passed since the last
+  /// snapshot. An object that is not edited doesn't get new snapshots.
+  pub every_interval: Option<Duration>,
+  /// Create a snapshot when the object is closed after being edited.
+  pub on_close: bool,
+}
+
+impl SnapshotPolicy {
+  pub fn disabled() -> Self {
+    Self {
+      every_n_updates: None,
+      every_interval: None,
+      on_close: false,
+    }
+  }
+
+  pub fn every_n_updates(mut self, n: u32) -> Self {
+    self.every_n_updates = (n > 0).then_some(n);
+    self
+  }
+
+  pub fn every_interval(mut self, interval: Duration) -> Self {
+    self.every_interval = (!interval.is_zero()).then_some(interval);
+    self
+  }
+
+  pub fn on_close(mut self, on_close: bool) -> Self {
+    self.on_close = on_close;
+    self
+  }
+
+  /// Returns true if the policy has any trigger.
+  pub fn is_enabled(&self) -> bool {
+    self.every_n_updates.is_some() || self.every_interval.is_some() || self.on_close
+  }
+}
+
+/// Counts the updates of an object since its last snapshot and tells when the update triggers
+/// of a [SnapshotPolicy] fire.
+#[derive(Debug)]
+struct SnapshotTrigger {
+  num_of_updates: u32,
+  last_snapshot_at: Instant,
+}
+
+impl SnapshotTrigger {
+  fn new() -> Self {
+    Self {
+      num_of_updates: 0,
+      last_snapshot_at: Instant::now(),
+    }
+  }
+
+  /// Returns true if a snapshot is due after the update. It keeps returning true until
+  /// [SnapshotTrigger::reset] is called.
+  fn did_receive_update(&mut self, policy: &SnapshotPolicy) -> bool {
+    self.num_of_updates = self.num_of_updates.saturating_add(1);
+    let by_updates = policy
+      .every_n_updates
+      .map(|n| self.num_of_updates >= n)
+      .unwrap_or(false);
+    let by_interval = policy
+      .every_interval
+      .map(|interval| self.last_snapshot_at.elapsed() >= interval)
+      .unwrap_or(false);
+    by_updates || by_interval
+  }
+
+  fn checkpoint(&self) -> SnapshotCheckpoint {
+    SnapshotCheckpoint {
+      num_of_updates: self.num_of_updates,
+      taken_at: Instant::now(),
+    }
+  }
+
+  /// Restarts the triggers from the checkpoint. The updates received after the checkpoint are
+  /// not in the snapshot, so they keep counting toward the next one.
+  fn did_save_snapshot(&mut self, checkpoint: &SnapshotCheckpoint) {
+    self.num_of_updates = self
+      .num_of_updates
+      .saturating_sub(checkpoint.num_of_updates);
+    self.last_snapshot_at = checkpoint.taken_at;
+  }
+}
+
+/// The state of a [SnapshotTrigger] when the snapshot of an object is encoded. The trigger is
+/// only restarted from it once the snapshot is saved, see [SnapshotState::did_save_snapshot], so
+/// a snapshot that fails to be encoded or saved is still due.
+#[derive(Debug)]
+pub struct SnapshotCheckpoint {
+  num_of_updates: u32,
+  taken_at: Instant,
+}
+
+/// The snapshot state of an opened collab object. It's shared by the [SnapshotPlugin] that
+/// counts the updates and the task that encodes the snapshots, so the full state of the object
+/// is never encoded on the update path.
+pub(crate) struct SnapshotState {
+  policy: SnapshotPolicy,
+  trigger: Mutex<SnapshotTrigger>,
+  due: Notify,
+  closed: AtomicBool,
+}
+
+impl SnapshotState {
+  pub(crate) fn new(policy: SnapshotPolicy) -> Self {
+    Self {
+      policy,
+      trigger: Mutex::new(SnapshotTrigger::new()),
+      due: Notify::new(),
+      closed: AtomicBool::new(false),
+    }
+  }
+
+  pub(crate) fn policy(&self) -> &SnapshotPolicy {
+    &self.policy
+  }
+
+  /// Returns true if the object was edited since its last snapshot.
+  pub(crate) fn has_pending_updates(&self) -> bool {
+    self
+      .trigger
+      .lock()
+      .map(|trigger| trigger.num_of_updates > 0)
+      .unwrap_or(false)
+  }
+
+  /// Must be called with the state of the object that goes into the snapshot, i.e. while no
+  /// update can be applied to the object.
+  pub(crate) fn checkpoint(&self) -> Option<SnapshotCheckpoint> {
+    self.trigger.lock().ok().map(|trigger| trigger.checkpoint())
+  }
+
+  /// Called once the snapshot encoded at the checkpoint is saved.
+  pub(crate) fn did_save_snapshot(&self, checkpoint: &SnapshotCheckpoint) {
+    if let Ok(mut trigger) = self.trigger.lock() {
+      trigger.did_save_snapshot(checkpoint);
+    }
+  }
+
+  /// Waits until a snapshot is due. Returns false when the object is closed.
+  pub(crate) async fn wait_until_due(&self) -> bool {
+    self.due.notified().await;
+    !self.closed.load(Ordering::Acquire)
+  }
+
+  fn did_receive_update(&self) -> bool {
+    let is_due = match self.trigger.lock() {
+      Ok(mut trigger) => trigger.did_receive_update(&self.policy),
+      Err(_) => false,
+    };
+    if is_due {
+      self.due.notify_one();
+    }
+    is_due
+  }
+
+  fn close(&self) {
+    self.closed.store(true, Ordering::Release);
+    self.due.notify_one();
+  }
+}
+
+/// Counts the updates of a collab object for its [SnapshotState]. The snapshots are encoded and
+/// saved by the task that `AppFlowyCollabBuilder::finalize` starts for the object.
+pub(crate) struct SnapshotPlugin {
+  collab_type: CollabType,
+  state: Arc<SnapshotState>,
+}
+
+impl SnapshotPlugin {
+  pub(crate) fn new(collab_type: CollabType, state: Arc<SnapshotState>) -> Self {
+    Self { collab_type, state }
+  }
+}
+
+impl CollabPlugin for SnapshotPlugin {
+  fn receive_update(&self, object_id: &str, _txn: &TransactionMut, _update: &[u8]) {
+    if self.state.did_receive_update() {
+      trace!("{} snapshot is due: {}", self.collab_type, object_id);
+    }
+  }
+
+  fn plugin_type(&self) -> CollabPluginType {
+    CollabPluginType::Other("SnapshotPlugin".to_string())
+  }
+}
+
+impl Drop for SnapshotPlugin {
+  fn drop(&mut self) {
+    self.state.close();
+  }
+}
+
+/// Encodes the full state of the collab into the bytes that are saved as its snapshot.
+pub(crate) fn encode_snapshot(
+  collab_type: &CollabType,
+  collab: &Collab,
+) -> Result<Vec<u8>, anyhow::Error> {
+  let encoded_v1 = collab
+    .encode_collab_v1(|collab| collab_type.validate_require_data(collab))?
+    .encode_to_bytes()?;
+  Ok(encoded_v1)
+}
@@ -28,7 +28,7 @@ impl EventIntegrationTest {
       .get_database_editor_with_view_id(database_view_id)
       .await
       .unwrap()
-      .export_csv(CSVFormat::Original)
+      .export_csv(CSVFormat::Original, false)
       .await
       .unwrap()
   }
@@ -244,6 +244,7 @@ impl EventIntegrationTest {
         row_position,
         group_id: None,
         data: data.unwrap_or_default(),
+        row_id: None,
       })
       .async_send()
       .await
@@ -323,6 +324,24 @@ impl EventIntegrationTest {
       .error()
   }
 
+  pub async fn move_row_to_index(
+    &self,
+    view_id: &str,
+    row_id: &str,
+    to_index: i32,
+  ) -> Option<FlowyError> {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::MoveRowToIndex)
+      .payload(MoveRowToIndexPayloadPB {
+        view_id: view_id.to_string(),
+        row_id: row_id.to_string(),
+        to_index,
+      })
+      .async_send()
+      .await
+      .error()
+  }
+
   pub async fn update_cell(&self, changeset: CellChangesetPB) -> Option<FlowyError> {
     EventBuilder::new(self.clone())
       .event(DatabaseEvent::UpdateCell)
@@ -484,6 +503,26 @@ impl EventIntegrationTest {
         group_id: group_id.to_string(),
         name,
         visible,
+        collapsed: None,
+      })
+      .async_send()
+      .await
+      .error()
+  }
+
+  pub async fn collapse_group(
+    &self,
+    view_id: &str,
+    group_id: &str,
+    collapsed: bool,
+  ) -> Option<FlowyError> {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::UpdateGroup)
+      .payload(UpdateGroupPB {
+        view_id: view_id.to_string(),
+        group_id: group_id.to_string(),
+        collapsed: Some(collapsed),
+        ..Default::default()
       })
       .async_send()
       .await
@@ -516,6 +555,7 @@ impl EventIntegrationTest {
       .event(DatabaseEvent::GetAllCalendarEvents)
       .payload(CalendarEventRequestPB {
         view_id: view_id.to_string(),
+        ..Default::default()
       })
       .async_send()
       .await
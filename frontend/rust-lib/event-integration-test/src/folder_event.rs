@@ -339,6 +339,42 @@ impl EventIntegrationTest {
       .parse::<RepeatedViewPB>()
       .items
   }
+
+  pub async fn add_view_tag(&self, view_id: &str, tag: &str) -> Option<FlowyError> {
+    EventBuilder::new(self.clone())
+      .event(FolderEvent::AddViewTag)
+      .payload(ViewTagPayloadPB {
+        view_id: view_id.to_string(),
+        tag: tag.to_string(),
+      })
+      .async_send()
+      .await
+      .error()
+  }
+
+  pub async fn remove_view_tag(&self, view_id: &str, tag: &str) -> Option<FlowyError> {
+    EventBuilder::new(self.clone())
+      .event(FolderEvent::RemoveViewTag)
+      .payload(ViewTagPayloadPB {
+        view_id: view_id.to_string(),
+        tag: tag.to_string(),
+      })
+      .async_send()
+      .await
+      .error()
+  }
+
+  pub async fn get_views_with_tag(&self, tag: &str) -> Vec<ViewPB> {
+    EventBuilder::new(self.clone())
+      .event(FolderEvent::GetViewsWithTag)
+      .payload(ViewTagPB {
+        tag: tag.to_string(),
+      })
+      .async_send()
+      .await
+      .parse::<RepeatedViewPB>()
+      .items
+  }
 }
 
 pub struct ViewTest {
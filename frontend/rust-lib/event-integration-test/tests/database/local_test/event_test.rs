@@ -8,7 +8,7 @@ use flowy_database2::entities::{
   CellChangesetPB, CellIdPB, CheckboxCellDataPB, ChecklistCellDataChangesetPB,
   ChecklistCellInsertPB, DatabaseLayoutPB, DatabaseSettingChangesetPB, DatabaseViewIdPB,
   DateCellChangesetPB, FieldType, OrderObjectPositionPB, RelationCellChangesetPB,
-  SelectOptionCellDataPB, UpdateRowMetaChangesetPB,
+  SearchCellsPayloadPB, SelectOptionCellDataPB, UpdateRowMetaChangesetPB,
 };
 use lib_infra::util::timestamp;
 
@@ -426,6 +426,35 @@ async fn move_row_event_with_invalid_row_id_test() {
   }
 }
 
+#[tokio::test]
+async fn move_row_to_index_event_test() {
+  let test = EventIntegrationTest::new_anon().await;
+  let current_workspace = test.get_current_workspace().await;
+  let grid_view = test
+    .create_grid(&current_workspace.id, "my grid view".to_owned(), vec![])
+    .await;
+  let database = test.get_database(&grid_view.id).await;
+  let row_1 = database.rows[0].id.clone();
+  let row_2 = database.rows[1].id.clone();
+  let row_3 = database.rows[2].id.clone();
+  let error = test.move_row_to_index(&grid_view.id, &row_3, 0).await;
+  assert!(error.is_none());
+
+  let database = test.get_database(&grid_view.id).await;
+  assert_eq!(database.rows[0].id, row_3);
+  assert_eq!(database.rows[1].id, row_1);
+  assert_eq!(database.rows[2].id, row_2);
+
+  // An index past the last row moves the row to the end
+  let error = test.move_row_to_index(&grid_view.id, &row_3, 10).await;
+  assert!(error.is_none());
+  let database = test.get_database(&grid_view.id).await;
+  assert_eq!(database.rows[2].id, row_3);
+
+  let error = test.move_row_to_index(&grid_view.id, &row_1, -1).await;
+  assert!(error.is_some());
+}
+
 #[tokio::test]
 async fn update_text_cell_event_test() {
   let test = EventIntegrationTest::new_anon().await;
@@ -915,3 +944,28 @@ async fn get_detailed_relation_cell_data() {
   assert_eq!(rows.len(), 1);
   assert_eq!(rows[0].name, "hello world");
 }
+
+#[tokio::test]
+async fn search_database_cells_with_invalid_page_event_test() {
+  let test = EventIntegrationTest::new_anon().await;
+  let current_workspace = test.get_current_workspace().await;
+  let grid_view = test
+    .create_grid(&current_workspace.id, "my grid view".to_owned(), vec![])
+    .await;
+
+  // A negative offset or limit is rejected before it reaches the editor
+  for (offset, limit) in [(-1, 10), (0, -1), (0, 0)] {
+    let error = EventBuilder::new(test.clone())
+      .event(flowy_database2::event_map::DatabaseEvent::SearchDatabaseCells)
+      .payload(SearchCellsPayloadPB {
+        view_id: grid_view.id.clone(),
+        query: "hello".to_string(),
+        offset,
+        limit,
+      })
+      .async_send()
+      .await
+      .error();
+    assert!(error.is_some());
+  }
+}
@@ -123,6 +123,29 @@ async fn hide_group_event_test() {
   assert!(!groups[0].is_visible);
 }
 
+#[tokio::test]
+async fn collapse_group_event_test() {
+  let test = EventIntegrationTest::new_anon().await;
+  let current_workspace = test.get_current_workspace().await;
+  let board_view = test
+    .create_board(&current_workspace.id, "my board view".to_owned(), vec![])
+    .await;
+
+  let groups = test.get_groups(&board_view.id).await;
+  assert!(groups.iter().all(|group| !group.is_collapsed));
+
+  let error = test
+    .collapse_group(&board_view.id, &groups[1].group_id, true)
+    .await;
+  assert!(error.is_none());
+
+  let groups = test.get_groups(&board_view.id).await;
+  assert_eq!(groups.len(), 4);
+  assert!(groups[1].is_collapsed);
+  assert!(groups[1].is_visible);
+  assert!(!groups[2].is_collapsed);
+}
+
 #[tokio::test]
 async fn update_group_name_test() {
   let test = EventIntegrationTest::new_anon().await;
@@ -347,3 +347,40 @@ async fn create_orphan_child_view_and_get_its_ancestors_test() {
   assert_eq!(ancestors[0].name, "Orphan View");
   assert_eq!(ancestors[0].id, view_id);
 }
+
+#[tokio::test]
+async fn tag_views_across_the_folder_test() {
+  let test = EventIntegrationTest::new_anon().await;
+  let workspace_id = test.get_current_workspace().await.id;
+  let document = test
+    .create_view(&workspace_id, "Document".to_string())
+    .await;
+  let grid = test
+    .create_grid(&document.id, "Grid".to_string(), vec![])
+    .await;
+
+  assert!(test.add_view_tag(&document.id, "work").await.is_none());
+  assert!(test.add_view_tag(&grid.id, " work ").await.is_none());
+  assert!(test.add_view_tag(&grid.id, "todo").await.is_none());
+  assert!(test.add_view_tag(&grid.id, "").await.is_some());
+
+  let view = test.get_view(&grid.id).await;
+  assert_eq!(view.tags, vec!["work".to_string(), "todo".to_string()]);
+
+  let mut view_ids = test
+    .get_views_with_tag("work")
+    .await
+    .into_iter()
+    .map(|view| view.id)
+    .collect::<Vec<_>>();
+  view_ids.sort();
+  let mut expected = vec![document.id.clone(), grid.id.clone()];
+  expected.sort();
+  assert_eq!(view_ids, expected);
+
+  assert!(test.remove_view_tag(&grid.id, "work").await.is_none());
+  let views = test.get_views_with_tag("work").await;
+  assert_eq!(views.len(), 1);
+  assert_eq!(views[0].id, document.id);
+  assert_eq!(test.get_view(&grid.id).await.tags, vec!["todo".to_string()]);
+}
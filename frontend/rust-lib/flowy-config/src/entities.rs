@@ -14,3 +14,32 @@ pub struct KeyPB {
   #[pb(index = 1)]
   pub key: String,
 }
+
+#[derive(Default, ProtoBuf)]
+pub struct FeatureFlagPB {
+  #[pb(index = 1)]
+  pub key: String,
+
+  #[pb(index = 2)]
+  pub is_enabled: bool,
+
+  /// True if the value is overridden locally instead of coming from the server.
+  #[pb(index = 3)]
+  pub is_overridden: bool,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct RepeatedFeatureFlagPB {
+  #[pb(index = 1)]
+  pub items: Vec<FeatureFlagPB>,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct FeatureFlagOverridePB {
+  #[pb(index = 1)]
+  pub key: String,
+
+  /// Removes the local override if it's None.
+  #[pb(index = 2, one_of)]
+  pub is_enabled: Option<bool>,
+}
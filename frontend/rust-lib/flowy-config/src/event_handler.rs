@@ -1,10 +1,13 @@
-use std::sync::Weak;
+use std::sync::{Arc, Weak};
 
 use flowy_error::{FlowyError, FlowyResult};
 use flowy_sqlite::kv::KVStorePreferences;
 use lib_dispatch::prelude::{data_result_ok, AFPluginData, AFPluginState, DataResult};
 
-use crate::entities::{KeyPB, KeyValuePB};
+use crate::entities::{
+  FeatureFlagOverridePB, FeatureFlagPB, KeyPB, KeyValuePB, RepeatedFeatureFlagPB,
+};
+use crate::feature_flag::{FeatureFlag, FeatureFlags};
 
 pub(crate) async fn set_key_value_handler(
   store_preferences: AFPluginState<Weak<KVStorePreferences>>,
@@ -54,3 +57,38 @@ pub(crate) async fn remove_key_value_handler(
     },
   }
 }
+
+pub(crate) async fn get_feature_flags_handler(
+  feature_flags: AFPluginState<Weak<FeatureFlags>>,
+) -> DataResult<RepeatedFeatureFlagPB, FlowyError> {
+  let feature_flags = upgrade_feature_flags(feature_flags)?;
+  let items = FeatureFlag::ALL
+    .into_iter()
+    .map(|flag| FeatureFlagPB {
+      key: flag.key().to_string(),
+      is_enabled: feature_flags.is_enabled(flag),
+      is_overridden: feature_flags.is_overridden(flag),
+    })
+    .collect();
+  data_result_ok(RepeatedFeatureFlagPB { items })
+}
+
+pub(crate) async fn set_feature_flag_override_handler(
+  feature_flags: AFPluginState<Weak<FeatureFlags>>,
+  data: AFPluginData<FeatureFlagOverridePB>,
+) -> FlowyResult<()> {
+  let feature_flags = upgrade_feature_flags(feature_flags)?;
+  let data = data.into_inner();
+  let flag = FeatureFlag::from_key(&data.key).ok_or_else(|| {
+    FlowyError::invalid_data().with_context(format!("Unknown feature flag: {}", data.key))
+  })?;
+  feature_flags.set_local_override(flag, data.is_enabled)
+}
+
+fn upgrade_feature_flags(
+  feature_flags: AFPluginState<Weak<FeatureFlags>>,
+) -> FlowyResult<Arc<FeatureFlags>> {
+  feature_flags
+    .upgrade()
+    .ok_or_else(|| FlowyError::internal().with_context("The feature flags are already drop"))
+}
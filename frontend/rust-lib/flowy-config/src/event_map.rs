@@ -15,6 +15,11 @@ pub fn init(store_preferences: Weak<KVStorePreferences>) -> AFPlugin {
     .event(ConfigEvent::SetKeyValue, set_key_value_handler)
     .event(ConfigEvent::GetKeyValue, get_key_value_handler)
     .event(ConfigEvent::RemoveKeyValue, remove_key_value_handler)
+    .event(ConfigEvent::GetFeatureFlags, get_feature_flags_handler)
+    .event(
+      ConfigEvent::SetFeatureFlagOverride,
+      set_feature_flag_override_handler,
+    )
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Display, ProtoBuf_Enum, Flowy_Event)]
@@ -28,4 +33,12 @@ pub enum ConfigEvent {
 
   #[event(input = "KeyPB")]
   RemoveKeyValue = 2,
+
+  /// Returns the feature flags of the current workspace.
+  #[event(output = "RepeatedFeatureFlagPB")]
+  GetFeatureFlags = 3,
+
+  /// Overrides a feature flag of the current workspace on this device, or removes the override.
+  #[event(input = "FeatureFlagOverridePB")]
+  SetFeatureFlagOverride = 4,
 }
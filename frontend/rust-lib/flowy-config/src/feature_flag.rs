@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use std::sync::{RwLock, Weak};
+
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sqlite::kv::KVStorePreferences;
+
+/// The experimental subsystems that ship dark and can be turned on per workspace without a
+/// rebuild.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeatureFlag {
+  Formulas,
+  Automations,
+}
+
+impl FeatureFlag {
+  pub const ALL: [FeatureFlag; 2] = [FeatureFlag::Formulas, FeatureFlag::Automations];
+
+  /// The key of the flag, shared with the server and the local overrides.
+  pub fn key(&self) -> &'static str {
+    match self {
+      FeatureFlag::Formulas => "formulas",
+      FeatureFlag::Automations => "automations",
+    }
+  }
+
+  pub fn from_key(key: &str) -> Option<Self> {
+    Self::ALL.into_iter().find(|flag| flag.key() == key)
+  }
+
+  /// The value of the flag if neither the server nor the user set it.
+  pub fn default_value(&self) -> bool {
+    false
+  }
+}
+
+/// The feature flags of the current workspace. The value of a flag is, in order of precedence,
+/// the local override set by the user, the default provided by the server, or the default value
+/// of the flag.
+///
+/// The flags are registered as a state of every plugin, so any event handler can read them with
+/// `AFPluginState<Weak<FeatureFlags>>`.
+pub struct FeatureFlags {
+  store_preferences: Weak<KVStorePreferences>,
+  workspace_id: RwLock<String>,
+  server_defaults: RwLock<HashMap<FeatureFlag, bool>>,
+  local_overrides: RwLock<HashMap<FeatureFlag, bool>>,
+}
+
+impl FeatureFlags {
+  pub fn new(store_preferences: Weak<KVStorePreferences>) -> Self {
+    Self {
+      store_preferences,
+      workspace_id: Default::default(),
+      server_defaults: Default::default(),
+      local_overrides: Default::default(),
+    }
+  }
+
+  /// Switches to the flags of the workspace. The local overrides of the workspace are loaded
+  /// from the disk. The unknown flags of the server are ignored, they belong to a newer version
+  /// of the app.
+  pub fn open_workspace(&self, workspace_id: &str, server_defaults: HashMap<String, bool>) {
+    let local_overrides = self
+      .store_preferences
+      .upgrade()
+      .and_then(|store| store.get_object::<HashMap<String, bool>>(&overrides_key(workspace_id)))
+      .unwrap_or_default();
+
+    *self.workspace_id.write().unwrap() = workspace_id.to_string();
+    *self.server_defaults.write().unwrap() = parse_flags(server_defaults);
+    *self.local_overrides.write().unwrap() = parse_flags(local_overrides);
+  }
+
+  /// Replaces the defaults provided by the server. The defaults are ignored if another
+  /// workspace was opened since they were requested.
+  pub fn set_server_defaults(&self, workspace_id: &str, server_defaults: HashMap<String, bool>) {
+    if *self.workspace_id.read().unwrap() != workspace_id {
+      return;
+    }
+    *self.server_defaults.write().unwrap() = parse_flags(server_defaults);
+  }
+
+  pub fn is_enabled(&self, flag: FeatureFlag) -> bool {
+    if let Some(value) = self.local_overrides.read().unwrap().get(&flag) {
+      return *value;
+    }
+    self
+      .server_defaults
+      .read()
+      .unwrap()
+      .get(&flag)
+      .copied()
+      .unwrap_or_else(|| flag.default_value())
+  }
+
+  pub fn is_overridden(&self, flag: FeatureFlag) -> bool {
+    self.local_overrides.read().unwrap().contains_key(&flag)
+  }
+
+  /// Overrides the value of the flag in the current workspace, or removes the override if
+  /// `value` is None. The override is saved to the disk.
+  pub fn set_local_override(&self, flag: FeatureFlag, value: Option<bool>) -> FlowyResult<()> {
+    let workspace_id = self.workspace_id.read().unwrap().clone();
+    if workspace_id.is_empty() {
+      return Err(FlowyError::internal().with_context("No workspace is opened"));
+    }
+
+    let local_overrides = {
+      let mut local_overrides = self.local_overrides.write().unwrap();
+      match value {
+        None => local_overrides.remove(&flag),
+        Some(value) => local_overrides.insert(flag, value),
+      };
+      local_overrides
+        .iter()
+        .map(|(flag, value)| (flag.key().to_string(), *value))
+        .collect::<HashMap<_, _>>()
+    };
+
+    let store = self.store_preferences.upgrade().ok_or_else(|| {
+      FlowyError::internal().with_context("The store preferences is already drop")
+    })?;
+    store
+      .set_object(&overrides_key(&workspace_id), &local_overrides)
+      .map_err(|err| FlowyError::internal().with_context(err))
+  }
+}
+
+fn overrides_key(workspace_id: &str) -> String {
+  format!("feature_flag_overrides:{}", workspace_id)
+}
+
+fn parse_flags(flags: HashMap<String, bool>) -> HashMap<FeatureFlag, bool> {
+  flags
+    .into_iter()
+    .filter_map(|(key, value)| FeatureFlag::from_key(&key).map(|flag| (flag, value)))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+  use std::sync::Arc;
+
+  use flowy_sqlite::kv::KVStorePreferences;
+  use tempfile::TempDir;
+
+  use super::{FeatureFlag, FeatureFlags};
+
+  #[test]
+  fn feature_flag_precedence_test() {
+    let dir = TempDir::new().unwrap();
+    let store = Arc::new(KVStorePreferences::new(dir.path().to_str().unwrap()).unwrap());
+    let flags = FeatureFlags::new(Arc::downgrade(&store));
+    flags.open_workspace("w1", HashMap::new());
+
+    // The compiled default applies when neither the server nor the user set the flag
+    assert!(!flags.is_enabled(FeatureFlag::Automations));
+
+    // The default of the server overrides the compiled default
+    flags.set_server_defaults("w1", HashMap::from([("automations".to_string(), true)]));
+    assert!(flags.is_enabled(FeatureFlag::Automations));
+    assert!(!flags.is_enabled(FeatureFlag::Formulas));
+
+    // The local override wins over the default of the server
+    flags
+      .set_local_override(FeatureFlag::Automations, Some(false))
+      .unwrap();
+    assert!(!flags.is_enabled(FeatureFlag::Automations));
+    assert!(flags.is_overridden(FeatureFlag::Automations));
+
+    // Removing the override restores the default of the server
+    flags
+      .set_local_override(FeatureFlag::Automations, None)
+      .unwrap();
+    assert!(flags.is_enabled(FeatureFlag::Automations));
+  }
+
+  #[test]
+  fn feature_flag_overrides_are_per_workspace_test() {
+    let dir = TempDir::new().unwrap();
+    let store = Arc::new(KVStorePreferences::new(dir.path().to_str().unwrap()).unwrap());
+    let flags = FeatureFlags::new(Arc::downgrade(&store));
+    flags.open_workspace("w1", HashMap::new());
+    flags
+      .set_local_override(FeatureFlag::Formulas, Some(true))
+      .unwrap();
+
+    // The defaults requested for a workspace that is no longer open are ignored
+    flags.open_workspace("w2", HashMap::new());
+    flags.set_server_defaults("w1", HashMap::from([("formulas".to_string(), true)]));
+    assert!(!flags.is_enabled(FeatureFlag::Formulas));
+
+    // The overrides are loaded from the disk when the workspace is opened again
+    flags.open_workspace("w1", HashMap::new());
+    assert!(flags.is_enabled(FeatureFlag::Formulas));
+  }
+}
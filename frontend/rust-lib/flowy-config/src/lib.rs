@@ -1,4 +1,5 @@
 pub mod entities;
 mod event_handler;
 pub mod event_map;
+pub mod feature_flag;
 mod protobuf;
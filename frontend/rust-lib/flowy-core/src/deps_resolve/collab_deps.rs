@@ -14,6 +14,10 @@ use lib_infra::util::timestamp;
 use std::sync::{Arc, Weak};
 use tracing::debug;
 
+/// The number of local snapshots that are kept for each object. The oldest ones are deleted
+/// when a new snapshot is created.
+const MAX_SNAPSHOTS_PER_OBJECT: i64 = 20;
+
 pub struct SnapshotDBImpl(pub Weak<AuthenticateUser>);
 
 impl SnapshotPersistence for SnapshotDBImpl {
@@ -52,7 +56,7 @@ impl SnapshotPersistence for SnapshotDBImpl {
 #[diesel(table_name = collab_snapshot)]
 pub(crate) struct CollabSnapshotRow {
   pub(crate) id: String,
-  object_id: String,
+  pub(crate) object_id: String,
   title: String,
   desc: String,
   collab_type: String,
@@ -72,6 +76,11 @@ impl CollabSnapshotRow {
       data,
     }
   }
+
+  pub fn with_desc(mut self, desc: String) -> Self {
+    self.desc = desc;
+    self
+  }
 }
 
 impl From<CollabSnapshotRow> for CollabSnapshot {
@@ -115,13 +124,13 @@ impl CollabSnapshotSql {
         .select(count_star())
         .first(conn)?;
 
-      // If there are more than 5 snapshots, delete the oldest one
-      if total_snapshots > 5 {
+      // If there are too many snapshots, delete the oldest ones
+      if total_snapshots > MAX_SNAPSHOTS_PER_OBJECT {
         let ids_to_delete: Vec<String> = dsl::collab_snapshot
           .filter(dsl::object_id.eq(&row.object_id))
           .order(dsl::timestamp.asc())
           .select(dsl::id)
-          .limit(1)
+          .limit(total_snapshots - MAX_SNAPSHOTS_PER_OBJECT)
           .load(conn)?;
 
         debug!(
@@ -180,6 +189,19 @@ impl CollabSnapshotSql {
       .ok()
   }
 
+  pub(crate) fn get_snapshot_with_desc(
+    object_id: &str,
+    desc: &str,
+    conn: &mut SqliteConnection,
+  ) -> Option<CollabSnapshotRow> {
+    dsl::collab_snapshot
+      .filter(dsl::object_id.eq(object_id))
+      .filter(dsl::desc.eq(desc))
+      .order(dsl::timestamp.desc())
+      .first::<CollabSnapshotRow>(conn)
+      .ok()
+  }
+
   #[allow(dead_code)]
   pub(crate) fn delete(
     object_id: &str,
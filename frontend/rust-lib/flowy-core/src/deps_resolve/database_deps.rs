@@ -1,22 +1,33 @@
+use crate::deps_resolve::{CollabSnapshotRow, CollabSnapshotSql};
 use appflowy_local_ai::ai_ops::{LocalAITranslateItem, LocalAITranslateRowData};
+use collab_entity::CollabType;
 use collab_integrate::collab_builder::AppFlowyCollabBuilder;
+use collab_integrate::snapshot::SnapshotPolicy;
 use collab_integrate::CollabKVDB;
 use flowy_ai::ai_manager::AIManager;
-use flowy_database2::{DatabaseManager, DatabaseUser};
+use flowy_config::feature_flag::FeatureFlags;
+use flowy_database2::services::snapshot::entities::{DatabaseSnapshotData, DatabaseSnapshotMeta};
+use flowy_database2::services::webhook::{Webhook, WebhookStore};
+use flowy_database2::{DatabaseManager, DatabaseSnapshotService, DatabaseUser};
 use flowy_database_pub::cloud::{
   DatabaseAIService, DatabaseCloudService, SummaryRowContent, TranslateRowContent,
   TranslateRowResponse,
 };
-use flowy_error::FlowyError;
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use flowy_sqlite::kv::KVStorePreferences;
 use flowy_user::services::authenticate_user::AuthenticateUser;
 use lib_infra::async_trait::async_trait;
 use lib_infra::priority_task::TaskDispatcher;
 use std::sync::{Arc, Weak};
 use tokio::sync::RwLock;
 
+const DATABASE_SNAPSHOT_POLICY_KEY: &str = "database_snapshot_policy";
+const DATABASE_WEBHOOKS_KEY_PREFIX: &str = "database_webhooks";
+
 pub struct DatabaseDepsResolver();
 
 impl DatabaseDepsResolver {
+  #[allow(clippy::too_many_arguments)]
   pub async fn resolve(
     authenticate_user: Weak<AuthenticateUser>,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
@@ -24,9 +35,15 @@ impl DatabaseDepsResolver {
     cloud_service: Arc<dyn DatabaseCloudService>,
     ai_service: Arc<dyn DatabaseAIService>,
     ai_manager: Arc<AIManager>,
+    feature_flags: Weak<FeatureFlags>,
+    store_preferences: Weak<KVStorePreferences>,
   ) -> Arc<DatabaseManager> {
-    let user = Arc::new(DatabaseUserImpl(authenticate_user));
-    Arc::new(DatabaseManager::new(
+    let user = Arc::new(DatabaseUserImpl(authenticate_user.clone()));
+    let snapshot_service = Arc::new(DatabaseSnapshotImpl {
+      authenticate_user,
+      store_preferences: store_preferences.clone(),
+    });
+    let manager = Arc::new(DatabaseManager::new(
       user,
       task_scheduler,
       collab_builder,
@@ -35,7 +52,12 @@ impl DatabaseDepsResolver {
         ai_manager,
         ai_service,
       }),
-    ))
+      snapshot_service,
+      feature_flags,
+    ));
+    manager.set_webhook_store(Arc::new(DatabaseWebhookStoreImpl(store_preferences)));
+    manager.start_automations();
+    manager
   }
 }
 
@@ -101,6 +123,35 @@ impl DatabaseAIService for DatabaseAIServiceMiddleware {
   }
 }
 
+/// Saves the webhooks of each database in the key value store of the device.
+struct DatabaseWebhookStoreImpl(Weak<KVStorePreferences>);
+
+impl WebhookStore for DatabaseWebhookStoreImpl {
+  fn get_webhooks(&self, database_id: &str) -> Vec<Webhook> {
+    self
+      .0
+      .upgrade()
+      .and_then(|store_preferences| {
+        store_preferences.get_object::<Vec<Webhook>>(&webhooks_key(database_id))
+      })
+      .unwrap_or_default()
+  }
+
+  fn save_webhooks(&self, database_id: &str, webhooks: &[Webhook]) -> FlowyResult<()> {
+    let store_preferences = self
+      .0
+      .upgrade()
+      .ok_or(FlowyError::internal().with_context("The store preferences are dropped"))?;
+    store_preferences
+      .set_object(&webhooks_key(database_id), &webhooks)
+      .map_err(internal_error)
+  }
+}
+
+fn webhooks_key(database_id: &str) -> String {
+  format!("{}:{}", DATABASE_WEBHOOKS_KEY_PREFIX, database_id)
+}
+
 struct DatabaseUserImpl(Weak<AuthenticateUser>);
 impl DatabaseUserImpl {
   fn upgrade_user(&self) -> Result<Arc<AuthenticateUser>, FlowyError> {
@@ -129,3 +180,118 @@ impl DatabaseUser for DatabaseUserImpl {
     self.upgrade_user()?.workspace_database_object_id()
   }
 }
+
+struct DatabaseSnapshotImpl {
+  authenticate_user: Weak<AuthenticateUser>,
+  store_preferences: Weak<KVStorePreferences>,
+}
+
+impl DatabaseSnapshotImpl {
+  pub fn get_authenticate_user(&self) -> FlowyResult<Arc<AuthenticateUser>> {
+    self
+      .authenticate_user
+      .upgrade()
+      .ok_or(FlowyError::internal().with_context("Unexpected error: UserSession is None"))
+  }
+}
+
+impl DatabaseSnapshotService for DatabaseSnapshotImpl {
+  fn get_database_snapshot_metas(
+    &self,
+    database_id: &str,
+  ) -> FlowyResult<Vec<DatabaseSnapshotMeta>> {
+    let authenticate_user = self.get_authenticate_user()?;
+    let uid = authenticate_user.user_id()?;
+    let mut db = authenticate_user.get_sqlite_connection(uid)?;
+    let mut rows = CollabSnapshotSql::get_all_snapshots(database_id, &mut db)?;
+    // The timestamps are in seconds, so the snapshots created in the same second keep the
+    // reversed insertion order
+    rows.reverse();
+    rows.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| DatabaseSnapshotMeta {
+          snapshot_id: row.id,
+          object_id: row.object_id,
+          created_at: row.timestamp,
+        })
+        .collect(),
+    )
+  }
+
+  fn get_database_snapshot(&self, snapshot_id: &str) -> FlowyResult<DatabaseSnapshotData> {
+    let authenticate_user = self.get_authenticate_user()?;
+    let uid = authenticate_user.user_id()?;
+    let mut db = authenticate_user.get_sqlite_connection(uid)?;
+    CollabSnapshotSql::get_snapshot(snapshot_id, &mut db)
+      .map(|row| DatabaseSnapshotData {
+        object_id: row.object_id,
+        encoded_v1: row.data,
+      })
+      .ok_or(
+        FlowyError::record_not_found().with_context(format!("Snapshot {} not found", snapshot_id)),
+      )
+  }
+
+  fn create_database_snapshot(
+    &self,
+    database_id: &str,
+    encoded_database: Vec<u8>,
+    encoded_rows: Vec<(String, Vec<u8>)>,
+  ) -> FlowyResult<String> {
+    let authenticate_user = self.get_authenticate_user()?;
+    let uid = authenticate_user.user_id()?;
+    let mut db = authenticate_user.get_sqlite_connection(uid)?;
+    let database_snapshot = CollabSnapshotRow::new(
+      database_id.to_string(),
+      CollabType::Database.to_string(),
+      encoded_database,
+    );
+    let snapshot_id = database_snapshot.id.clone();
+    // The row snapshots are linked to the database snapshot by their desc
+    for (row_id, encoded_row) in encoded_rows {
+      let row_snapshot =
+        CollabSnapshotRow::new(row_id, CollabType::DatabaseRow.to_string(), encoded_row)
+          .with_desc(snapshot_id.clone());
+      CollabSnapshotSql::create(row_snapshot, &mut db)?;
+    }
+    CollabSnapshotSql::create(database_snapshot, &mut db)?;
+    Ok(snapshot_id)
+  }
+
+  fn get_row_snapshot(
+    &self,
+    row_id: &str,
+    database_snapshot_id: &str,
+  ) -> FlowyResult<Option<DatabaseSnapshotData>> {
+    let authenticate_user = self.get_authenticate_user()?;
+    let uid = authenticate_user.user_id()?;
+    let mut db = authenticate_user.get_sqlite_connection(uid)?;
+    Ok(
+      CollabSnapshotSql::get_snapshot_with_desc(row_id, database_snapshot_id, &mut db).map(|row| {
+        DatabaseSnapshotData {
+          object_id: row.object_id,
+          encoded_v1: row.data,
+        }
+      }),
+    )
+  }
+
+  fn get_snapshot_policy(&self) -> Option<SnapshotPolicy> {
+    self
+      .store_preferences
+      .upgrade()?
+      .get_object::<SnapshotPolicy>(DATABASE_SNAPSHOT_POLICY_KEY)
+  }
+
+  fn set_snapshot_policy(&self, policy: &SnapshotPolicy) -> FlowyResult<()> {
+    let store_preferences = self
+      .store_preferences
+      .upgrade()
+      .ok_or(FlowyError::internal().with_context("The store preferences are dropped"))?;
+    store_preferences
+      .set_object(DATABASE_SNAPSHOT_POLICY_KEY, policy)
+      .map_err(internal_error)
+  }
+}
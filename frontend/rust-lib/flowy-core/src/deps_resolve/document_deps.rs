@@ -3,19 +3,23 @@ use std::sync::{Arc, Weak};
 use crate::deps_resolve::CollabSnapshotSql;
 use collab_integrate::collab_builder::AppFlowyCollabBuilder;
 use collab_integrate::CollabKVDB;
+use flowy_database2::services::row_document::RowDocumentService;
 use flowy_database2::DatabaseManager;
 use flowy_document::entities::{DocumentSnapshotData, DocumentSnapshotMeta};
-use flowy_document::manager::{DocumentManager, DocumentSnapshotService, DocumentUserService};
+use flowy_document::manager::{
+  DocumentDatabaseService, DocumentManager, DocumentSnapshotService, DocumentUserService,
+};
 use flowy_document_pub::cloud::DocumentCloudService;
 use flowy_error::{FlowyError, FlowyResult};
 use flowy_storage_pub::storage::StorageService;
 use flowy_user::services::authenticate_user::AuthenticateUser;
+use lib_infra::async_trait::async_trait;
 
 pub struct DocumentDepsResolver();
 impl DocumentDepsResolver {
   pub fn resolve(
     authenticate_user: Weak<AuthenticateUser>,
-    _database_manager: &Arc<DatabaseManager>,
+    database_manager: &Arc<DatabaseManager>,
     collab_builder: Arc<AppFlowyCollabBuilder>,
     cloud_service: Arc<dyn DocumentCloudService>,
     storage_service: Weak<dyn StorageService>,
@@ -23,13 +27,56 @@ impl DocumentDepsResolver {
     let user_service: Arc<dyn DocumentUserService> =
       Arc::new(DocumentUserImpl(authenticate_user.clone()));
     let snapshot_service = Arc::new(DocumentSnapshotImpl(authenticate_user));
-    Arc::new(DocumentManager::new(
+    let database_service = Arc::new(DocumentDatabaseImpl(Arc::downgrade(database_manager)));
+    let document_manager = Arc::new(DocumentManager::new(
       user_service.clone(),
       collab_builder,
       cloud_service,
       storage_service,
       snapshot_service,
-    ))
+      database_service,
+    ));
+    database_manager
+      .set_row_document_service(Arc::new(RowDocumentImpl(Arc::downgrade(&document_manager))));
+    document_manager
+  }
+}
+
+struct DocumentDatabaseImpl(Weak<DatabaseManager>);
+
+#[async_trait]
+impl DocumentDatabaseService for DocumentDatabaseImpl {
+  async fn export_view_markdown(&self, view_id: &str) -> FlowyResult<String> {
+    let database_manager = self
+      .0
+      .upgrade()
+      .ok_or(FlowyError::internal().with_context("The database manager is dropped"))?;
+    database_manager.export_view_markdown(view_id).await
+  }
+}
+
+struct RowDocumentImpl(Weak<DocumentManager>);
+
+impl RowDocumentImpl {
+  fn document_manager(&self) -> FlowyResult<Arc<DocumentManager>> {
+    self
+      .0
+      .upgrade()
+      .ok_or(FlowyError::internal().with_context("The document manager is dropped"))
+  }
+}
+
+#[async_trait]
+impl RowDocumentService for RowDocumentImpl {
+  async fn open_or_create_document(&self, document_id: &str) -> FlowyResult<()> {
+    self
+      .document_manager()?
+      .open_or_create_document(document_id)
+      .await
+  }
+
+  async fn delete_document(&self, document_id: &str) -> FlowyResult<()> {
+    self.document_manager()?.delete_document(document_id).await
   }
 }
 
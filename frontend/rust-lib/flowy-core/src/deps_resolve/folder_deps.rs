@@ -6,7 +6,7 @@ use collab_integrate::collab_builder::AppFlowyCollabBuilder;
 use collab_integrate::CollabKVDB;
 use flowy_ai::ai_manager::AIManager;
 use flowy_database2::entities::DatabaseLayoutPB;
-use flowy_database2::services::share::csv::CSVFormat;
+use flowy_database2::services::share::csv::{CSVFormat, CSVImportOptions, CSVSource, ImportResult};
 use flowy_database2::template::{make_default_board, make_default_calendar, make_default_grid};
 use flowy_database2::DatabaseManager;
 use flowy_document::entities::DocumentDataPB;
@@ -495,32 +495,77 @@ impl FolderOperationHandler for DatabaseFolderOperation {
     import_type: ImportType,
     bytes: Vec<u8>,
   ) -> Result<Vec<ImportedData>, FlowyError> {
-    let format = match import_type {
-      ImportType::CSV => CSVFormat::Original,
-      ImportType::AFDatabase => CSVFormat::META,
-      _ => CSVFormat::Original,
-    };
+    // These importers read the bytes themselves, the others import a string
+    match import_type {
+      ImportType::XLSX => {
+        let result = self.0.import_xlsx(view_id.to_string(), bytes, None).await?;
+        return Ok(imported_data(result));
+      },
+      ImportType::AirtableCSV => {
+        let result = self
+          .0
+          .import_csv_with_options(
+            view_id.to_string(),
+            CSVSource::Bytes(bytes),
+            CSVImportOptions::airtable(),
+          )
+          .await?;
+        return Ok(imported_data(result));
+      },
+      ImportType::NotionDatabase => {
+        let result = self.0.import_notion(view_id.to_string(), bytes).await?;
+        return Ok(imported_data(result));
+      },
+      ImportType::GridTemplate => {
+        let result = self
+          .0
+          .create_grid_from_template(view_id.to_string(), bytes)
+          .await?;
+        return Ok(imported_data(result));
+      },
+      ImportType::DatabaseBackup => {
+        let result = self.0.restore_database(view_id.to_string(), bytes).await?;
+        return Ok(imported_data(result));
+      },
+      _ => {},
+    }
     let content = tokio::task::spawn_blocking(move || {
       String::from_utf8(bytes).map_err(|err| FlowyError::internal().with_context(err))
     })
     .await??;
-    let result = self
-      .0
-      .import_csv(view_id.to_string(), content, format)
-      .await?;
-    Ok(
-      result
-        .encoded_collabs
-        .into_iter()
-        .map(|encoded| {
-          (
-            encoded.object_id,
-            encoded.collab_type,
-            encoded.encoded_collab,
-          )
-        })
-        .collect(),
-    )
+    let result = match import_type {
+      ImportType::GridJSON => {
+        self
+          .0
+          .import_grid_json(view_id.to_string(), content)
+          .await?
+      },
+      ImportType::HtmlTable => {
+        self
+          .0
+          .import_html_table(view_id.to_string(), content)
+          .await?
+      },
+      ImportType::MarkdownTable => {
+        self
+          .0
+          .import_markdown_table(view_id.to_string(), content)
+          .await?
+      },
+      ImportType::AFDatabase => {
+        self
+          .0
+          .import_csv(view_id.to_string(), content, CSVFormat::META)
+          .await?
+      },
+      _ => {
+        self
+          .0
+          .import_csv(view_id.to_string(), content, CSVFormat::Original)
+          .await?
+      },
+    };
+    Ok(imported_data(result))
   }
 
   async fn import_from_file_path(
@@ -555,6 +600,20 @@ impl FolderOperationHandler for DatabaseFolderOperation {
   }
 }
 
+fn imported_data(result: ImportResult) -> Vec<ImportedData> {
+  result
+    .encoded_collabs
+    .into_iter()
+    .map(|encoded| {
+      (
+        encoded.object_id,
+        encoded.collab_type,
+        encoded.encoded_collab,
+      )
+    })
+    .collect()
+}
+
 #[derive(Debug, serde::Deserialize)]
 struct CreateDatabaseExtParams {
   database_id: String,
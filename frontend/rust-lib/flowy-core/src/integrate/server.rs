@@ -52,7 +52,6 @@ pub struct ServerProvider {
   config: AppFlowyCoreConfig,
   providers: DashMap<Server, Arc<dyn AppFlowyServer>>,
   pub(crate) encryption: Arc<dyn AppFlowyEncryption>,
-  #[allow(dead_code)]
   pub(crate) store_preferences: Weak<KVStorePreferences>,
   pub(crate) user_enable_sync: AtomicBool,
 
@@ -25,8 +25,8 @@ use flowy_ai_pub::cloud::{
   RepeatedChatMessage, StreamAnswer, StreamComplete, SubscriptionPlan,
 };
 use flowy_database_pub::cloud::{
-  DatabaseAIService, DatabaseCloudService, DatabaseSnapshot, EncodeCollabByOid, SummaryRowContent,
-  TranslateRowContent, TranslateRowResponse,
+  DatabaseAIService, DatabaseCloudService, DatabaseSnapshot, EncodeCollabByOid, LinkPreview,
+  SummaryRowContent, TranslateRowContent, TranslateRowResponse,
 };
 use flowy_document::deps::DocumentData;
 use flowy_document_pub::cloud::{DocumentCloudService, DocumentSnapshot};
@@ -35,6 +35,7 @@ use flowy_folder_pub::cloud::{
   FolderCloudService, FolderCollabParams, FolderData, FolderSnapshot, Workspace, WorkspaceRecord,
 };
 use flowy_folder_pub::entities::{PublishInfoResponse, PublishPayload};
+use flowy_server::LINK_PREVIEW_OPT_IN_KEY;
 use flowy_server_pub::af_cloud_config::AFCloudConfiguration;
 use flowy_storage_pub::cloud::{ObjectIdentity, ObjectValue, StorageCloudService};
 use flowy_storage_pub::storage::{CompletedPartRequest, CreateUploadResponse, UploadPartResponse};
@@ -416,6 +417,21 @@ impl DatabaseCloudService for ServerProvider {
       .get_database_collab_object_snapshots(&database_id, limit)
       .await
   }
+
+  /// Fetches the page only if the user opted in, see [LINK_PREVIEW_OPT_IN_KEY]. The app doesn't
+  /// reach the link's host otherwise, with either server.
+  async fn get_link_preview(&self, url: &str) -> Result<LinkPreview, Error> {
+    let is_enabled = self
+      .store_preferences
+      .upgrade()
+      .is_some_and(|store| store.get_bool_or_default(LINK_PREVIEW_OPT_IN_KEY));
+    if !is_enabled {
+      debug!("skip fetching the link preview, the user didn't opt in");
+      return Ok(LinkPreview::default());
+    }
+    let server = self.get_server()?;
+    server.database_service().get_link_preview(url).await
+  }
 }
 
 #[async_trait]
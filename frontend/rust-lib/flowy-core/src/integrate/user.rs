@@ -1,12 +1,16 @@
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Context;
 use client_api::entity::billing_dto::SubscriptionPlan;
+use tokio::time::timeout;
 use tracing::{event, trace};
 
 use collab_entity::CollabType;
 use collab_integrate::collab_builder::AppFlowyCollabBuilder;
 use flowy_ai::ai_manager::AIManager;
+use flowy_config::feature_flag::FeatureFlags;
 use flowy_database2::DatabaseManager;
 use flowy_document::manager::DocumentManager;
 use flowy_error::{FlowyError, FlowyResult};
@@ -19,6 +23,9 @@ use lib_infra::async_trait::async_trait;
 
 use crate::integrate::server::{Server, ServerProvider};
 
+/// How long the sign in waits in the background for the feature flags of the server.
+const FEATURE_FLAGS_TIMEOUT: Duration = Duration::from_secs(10);
+
 pub(crate) struct UserStatusCallbackImpl {
   pub(crate) collab_builder: Arc<AppFlowyCollabBuilder>,
   pub(crate) folder_manager: Arc<FolderManager>,
@@ -27,6 +34,50 @@ pub(crate) struct UserStatusCallbackImpl {
   pub(crate) server_provider: Arc<ServerProvider>,
   pub(crate) storage_manager: Arc<StorageManager>,
   pub(crate) ai_manager: Arc<AIManager>,
+  pub(crate) feature_flags: Arc<FeatureFlags>,
+}
+
+impl UserStatusCallbackImpl {
+  /// Loads the feature flags of the workspace. The local overrides apply right away, while the
+  /// defaults of the server are fetched in the background, so an unreachable server doesn't
+  /// delay the sign in. The flags fall back to their local values if the server can't provide
+  /// the defaults within [FEATURE_FLAGS_TIMEOUT], e.g. when the user is offline or uses the
+  /// local server.
+  fn open_feature_flags(&self, workspace_id: &str) {
+    self
+      .feature_flags
+      .open_workspace(workspace_id, HashMap::new());
+    let Ok(user_service) = self.server_provider.get_user_service() else {
+      return;
+    };
+    let feature_flags = Arc::downgrade(&self.feature_flags);
+    let workspace_id = workspace_id.to_string();
+    tokio::spawn(async move {
+      let server_defaults = match timeout(
+        FEATURE_FLAGS_TIMEOUT,
+        user_service.get_workspace_feature_flags(&workspace_id),
+      )
+      .await
+      {
+        Ok(Ok(server_defaults)) => server_defaults,
+        Ok(Err(err)) => {
+          trace!(
+            "Get feature flags of workspace {} failed: {}",
+            workspace_id,
+            err
+          );
+          return;
+        },
+        Err(_) => {
+          trace!("Get feature flags of workspace {} timed out", workspace_id);
+          return;
+        },
+      };
+      if let Some(feature_flags) = feature_flags.upgrade() {
+        feature_flags.set_server_defaults(&workspace_id, server_defaults);
+      }
+    });
+  }
 }
 
 #[async_trait]
@@ -71,6 +122,7 @@ impl UserStatusCallback for UserStatusCallbackImpl {
       .await?;
     self.document_manager.initialize(user_id).await?;
     self.ai_manager.initialize(&user_workspace.id).await?;
+    self.open_feature_flags(&user_workspace.id);
     Ok(())
   }
 
@@ -97,6 +149,7 @@ impl UserStatusCallback for UserStatusCallbackImpl {
       .initialize(user_id, authenticator.is_local())
       .await?;
     self.document_manager.initialize(user_id).await?;
+    self.open_feature_flags(&user_workspace.id);
     Ok(())
   }
 
@@ -175,6 +228,7 @@ impl UserStatusCallback for UserStatusCallbackImpl {
       .initialize_with_new_user(user_profile.uid)
       .await
       .context("DocumentManager error")?;
+    self.open_feature_flags(&user_workspace.id);
     Ok(())
   }
 
@@ -199,6 +253,7 @@ impl UserStatusCallback for UserStatusCallbackImpl {
       .await?;
     self.document_manager.initialize(user_id).await?;
     self.ai_manager.initialize(&user_workspace.id).await?;
+    self.open_feature_flags(&user_workspace.id);
     self.storage_manager.initialize(&user_workspace.id).await;
     Ok(())
   }
@@ -207,6 +262,7 @@ impl UserStatusCallback for UserStatusCallbackImpl {
     trace!("Notify did update network: reachable: {}", reachable);
     self.collab_builder.update_network(reachable);
     self.storage_manager.update_network_reachable(reachable);
+    self.database_manager.update_network_reachable(reachable);
   }
 
   fn did_update_plans(&self, plans: Vec<SubscriptionPlan>) {
@@ -10,6 +10,7 @@ use tracing::{debug, error, event, info, instrument};
 
 use collab_integrate::collab_builder::{AppFlowyCollabBuilder, CollabPluginProviderType};
 use flowy_ai::ai_manager::AIManager;
+use flowy_config::feature_flag::FeatureFlags;
 use flowy_database2::DatabaseManager;
 use flowy_document::manager::DocumentManager;
 use flowy_error::{FlowyError, FlowyResult};
@@ -61,6 +62,7 @@ pub struct AppFlowyCore {
   pub search_manager: Arc<SearchManager>,
   pub ai_manager: Arc<AIManager>,
   pub storage_manager: Arc<StorageManager>,
+  pub feature_flags: Arc<FeatureFlags>,
 }
 
 impl AppFlowyCore {
@@ -135,6 +137,7 @@ impl AppFlowyCore {
     ));
 
     event!(tracing::Level::DEBUG, "Init managers",);
+    let feature_flags = Arc::new(FeatureFlags::new(Arc::downgrade(&store_preference)));
     let (
       user_manager,
       folder_manager,
@@ -175,6 +178,8 @@ impl AppFlowyCore {
         server_provider.clone(),
         server_provider.clone(),
         ai_manager.clone(),
+        Arc::downgrade(&feature_flags),
+        Arc::downgrade(&store_preference),
       )
       .await;
 
@@ -245,6 +250,7 @@ impl AppFlowyCore {
       server_provider: server_provider.clone(),
       storage_manager: storage_manager.clone(),
       ai_manager: ai_manager.clone(),
+      feature_flags: feature_flags.clone(),
     };
 
     let collab_interact_impl = CollabInteractImpl {
@@ -272,6 +278,7 @@ impl AppFlowyCore {
         Arc::downgrade(&search_manager),
         Arc::downgrade(&ai_manager),
         Arc::downgrade(&storage_manager),
+        Arc::downgrade(&feature_flags),
       ),
     ));
 
@@ -288,6 +295,7 @@ impl AppFlowyCore {
       search_manager,
       ai_manager,
       storage_manager,
+      feature_flags,
     }
   }
 
@@ -1,4 +1,5 @@
 use flowy_ai::ai_manager::AIManager;
+use flowy_config::feature_flag::FeatureFlags;
 use std::sync::Weak;
 
 use flowy_database2::DatabaseManager;
@@ -17,6 +18,7 @@ pub fn make_plugins(
   search_manager: Weak<SearchManager>,
   ai_manager: Weak<AIManager>,
   file_storage_manager: Weak<StorageManager>,
+  feature_flags: Weak<FeatureFlags>,
 ) -> Vec<AFPlugin> {
   let store_preferences = user_session
     .upgrade()
@@ -31,6 +33,7 @@ pub fn make_plugins(
   let search_plugin = flowy_search::event_map::init(search_manager);
   let ai_plugin = flowy_ai::event_map::init(ai_manager);
   let file_storage_plugin = flowy_storage::event_map::init(file_storage_manager);
+  // The feature flags are readable by the handlers of every plugin
   vec![
     user_plugin,
     folder_plugin,
@@ -42,4 +45,7 @@ pub fn make_plugins(
     ai_plugin,
     file_storage_plugin,
   ]
+  .into_iter()
+  .map(|plugin| plugin.state(feature_flags.clone()))
+  .collect()
 }
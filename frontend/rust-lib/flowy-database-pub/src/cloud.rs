@@ -67,6 +67,24 @@ pub trait DatabaseCloudService: Send + Sync {
     object_id: &str,
     limit: usize,
   ) -> Result<Vec<DatabaseSnapshot>, Error>;
+
+  /// Returns the [LinkPreview] of the web page at the given url.
+  async fn get_link_preview(&self, _url: &str) -> Result<LinkPreview, Error> {
+    Ok(LinkPreview::default())
+  }
+}
+
+/// The title and the favicon of a web page. Used to render the link of a URL cell.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinkPreview {
+  pub title: String,
+  pub favicon: String,
+}
+
+impl LinkPreview {
+  pub fn is_empty(&self) -> bool {
+    self.title.is_empty() && self.favicon.is_empty()
+  }
 }
 
 pub struct DatabaseSnapshot {
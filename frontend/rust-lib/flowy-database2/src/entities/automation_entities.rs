@@ -0,0 +1,210 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use lib_infra::validator_fn::required_not_empty_str;
+use validator::Validate;
+
+use crate::entities::{FilterDataPB, FilterPB};
+use crate::services::automation::{
+  AutomationAction, AutomationCellValue, AutomationRule, AutomationTrigger,
+};
+
+#[derive(Debug, ProtoBuf_Enum, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AutomationTriggerTypePB {
+  /// A row starts matching the condition of the rule
+  #[default]
+  CellMatches = 0,
+  RowCreated = 1,
+  /// The date of a row in the date field of the rule is reached
+  DateReached = 2,
+}
+
+#[derive(Debug, ProtoBuf_Enum, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AutomationActionTypePB {
+  #[default]
+  SetCell = 0,
+  CreateRow = 1,
+  Notify = 2,
+}
+
+/// [AutomationRulePB] runs its actions when its trigger fires for a row. The values of the
+/// actions can refer to the cells of that row with `{{Field name}}`.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct AutomationRulePB {
+  /// A new id is generated for the rule if it's empty
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub is_enabled: bool,
+
+  #[pb(index = 4)]
+  pub trigger_type: AutomationTriggerTypePB,
+
+  /// The condition of a [AutomationTriggerTypePB::CellMatches] rule. It uses the same data as a
+  /// filter of a view.
+  #[pb(index = 5)]
+  pub condition: FilterDataPB,
+
+  /// The date field of a [AutomationTriggerTypePB::DateReached] rule.
+  #[pb(index = 6)]
+  pub date_field_id: String,
+
+  #[pb(index = 7)]
+  pub actions: Vec<AutomationActionPB>,
+}
+
+/// An action of a rule. Only the properties of its type are used:
+/// - [AutomationActionTypePB::SetCell]: `field_id` and `value`
+/// - [AutomationActionTypePB::CreateRow]: `view_id` and `cells`
+/// - [AutomationActionTypePB::Notify]: `value` as the message
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct AutomationActionPB {
+  #[pb(index = 1)]
+  pub action_type: AutomationActionTypePB,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub value: String,
+
+  #[pb(index = 4)]
+  pub view_id: String,
+
+  #[pb(index = 5)]
+  pub cells: Vec<AutomationCellValuePB>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct AutomationCellValuePB {
+  /// The name of a field of the view the row is created in
+  #[pb(index = 1)]
+  pub field_name: String,
+
+  #[pb(index = 2)]
+  pub value: String,
+}
+
+impl std::convert::From<&AutomationRule> for AutomationRulePB {
+  fn from(rule: &AutomationRule) -> Self {
+    let mut pb = Self {
+      id: rule.id.clone(),
+      name: rule.name.clone(),
+      is_enabled: rule.is_enabled,
+      actions: rule.actions.iter().map(AutomationActionPB::from).collect(),
+      ..Default::default()
+    };
+    match &rule.trigger {
+      AutomationTrigger::CellMatches(condition) => {
+        pb.trigger_type = AutomationTriggerTypePB::CellMatches;
+        pb.condition = FilterPB::from(condition).data.unwrap_or_default();
+      },
+      AutomationTrigger::RowCreated => pb.trigger_type = AutomationTriggerTypePB::RowCreated,
+      AutomationTrigger::DateReached { field_id } => {
+        pb.trigger_type = AutomationTriggerTypePB::DateReached;
+        pb.date_field_id = field_id.clone();
+      },
+    }
+    pb
+  }
+}
+
+impl std::convert::From<&AutomationAction> for AutomationActionPB {
+  fn from(action: &AutomationAction) -> Self {
+    match action {
+      AutomationAction::SetCell { field_id, value } => Self {
+        action_type: AutomationActionTypePB::SetCell,
+        field_id: field_id.clone(),
+        value: value.clone(),
+        ..Default::default()
+      },
+      AutomationAction::CreateRow { view_id, cells } => Self {
+        action_type: AutomationActionTypePB::CreateRow,
+        view_id: view_id.clone(),
+        cells: cells
+          .iter()
+          .map(|cell| AutomationCellValuePB {
+            field_name: cell.field_name.clone(),
+            value: cell.value.clone(),
+          })
+          .collect(),
+        ..Default::default()
+      },
+      AutomationAction::Notify { message } => Self {
+        action_type: AutomationActionTypePB::Notify,
+        value: message.clone(),
+        ..Default::default()
+      },
+    }
+  }
+}
+
+impl std::convert::From<AutomationActionPB> for AutomationAction {
+  fn from(action: AutomationActionPB) -> Self {
+    match action.action_type {
+      AutomationActionTypePB::SetCell => AutomationAction::SetCell {
+        field_id: action.field_id,
+        value: action.value,
+      },
+      AutomationActionTypePB::CreateRow => AutomationAction::CreateRow {
+        view_id: action.view_id,
+        cells: action
+          .cells
+          .into_iter()
+          .map(|cell| AutomationCellValue {
+            field_name: cell.field_name,
+            value: cell.value,
+          })
+          .collect(),
+      },
+      AutomationActionTypePB::Notify => AutomationAction::Notify {
+        message: action.value,
+      },
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedAutomationRulePB {
+  #[pb(index = 1)]
+  pub items: Vec<AutomationRulePB>,
+}
+
+impl std::convert::From<&[AutomationRule]> for RepeatedAutomationRulePB {
+  fn from(rules: &[AutomationRule]) -> Self {
+    Self {
+      items: rules.iter().map(AutomationRulePB::from).collect(),
+    }
+  }
+}
+
+/// Replaces the automation rules of the database of the view.
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct UpdateAutomationRulesPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub rules: Vec<AutomationRulePB>,
+}
+
+/// Sent with [DatabaseNotification::DidTriggerAutomation] when a rule with a notify action fires.
+///
+/// [DatabaseNotification::DidTriggerAutomation]: crate::notification::DatabaseNotification::DidTriggerAutomation
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct AutomationNotificationPB {
+  #[pb(index = 1)]
+  pub rule_id: String,
+
+  #[pb(index = 2)]
+  pub rule_name: String,
+
+  #[pb(index = 3)]
+  pub row_id: String,
+
+  #[pb(index = 4)]
+  pub message: String,
+}
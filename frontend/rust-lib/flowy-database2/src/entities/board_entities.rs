@@ -1,6 +1,6 @@
-use flowy_derive::ProtoBuf;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 
-use crate::services::setting::BoardLayoutSetting;
+use crate::services::setting::{BoardLayoutSetting, CardSize};
 
 #[derive(Debug, Clone, Default, Eq, PartialEq, ProtoBuf)]
 pub struct BoardLayoutSettingPB {
@@ -9,6 +9,12 @@ pub struct BoardLayoutSettingPB {
 
   #[pb(index = 2)]
   pub collapse_hidden_groups: bool,
+
+  #[pb(index = 3)]
+  pub card_cover_field_id: String,
+
+  #[pb(index = 4)]
+  pub card_size: CardSizePB,
 }
 
 impl From<BoardLayoutSetting> for BoardLayoutSettingPB {
@@ -16,6 +22,8 @@ impl From<BoardLayoutSetting> for BoardLayoutSettingPB {
     Self {
       hide_ungrouped_column: setting.hide_ungrouped_column,
       collapse_hidden_groups: setting.collapse_hidden_groups,
+      card_cover_field_id: setting.card_cover_field_id,
+      card_size: setting.card_size.into(),
     }
   }
 }
@@ -25,6 +33,37 @@ impl From<BoardLayoutSettingPB> for BoardLayoutSetting {
     Self {
       hide_ungrouped_column: setting.hide_ungrouped_column,
       collapse_hidden_groups: setting.collapse_hidden_groups,
+      card_cover_field_id: setting.card_cover_field_id,
+      card_size: setting.card_size.into(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Default, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum CardSizePB {
+  Small = 0,
+  #[default]
+  Medium = 1,
+  Large = 2,
+}
+
+impl From<CardSizePB> for CardSize {
+  fn from(pb: CardSizePB) -> Self {
+    match pb {
+      CardSizePB::Small => CardSize::Small,
+      CardSizePB::Medium => CardSize::Medium,
+      CardSizePB::Large => CardSize::Large,
+    }
+  }
+}
+
+impl From<CardSize> for CardSizePB {
+  fn from(size: CardSize) -> Self {
+    match size {
+      CardSize::Small => CardSizePB::Small,
+      CardSize::Medium => CardSizePB::Medium,
+      CardSize::Large => CardSizePB::Large,
     }
   }
 }
@@ -81,11 +81,21 @@ impl std::convert::From<CalendarLayout> for CalendarLayoutPB {
 pub struct CalendarEventRequestPB {
   #[pb(index = 1)]
   pub view_id: String,
+
+  /// If set, only the events on or after this timestamp are returned.
+  #[pb(index = 2, one_of)]
+  pub start: Option<i64>,
+
+  /// If set, only the events before this timestamp are returned.
+  #[pb(index = 3, one_of)]
+  pub end: Option<i64>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct CalendarEventRequestParams {
   pub view_id: String,
+  pub start: Option<i64>,
+  pub end: Option<i64>,
 }
 
 impl TryInto<CalendarEventRequestParams> for CalendarEventRequestPB {
@@ -93,7 +103,11 @@ impl TryInto<CalendarEventRequestParams> for CalendarEventRequestPB {
 
   fn try_into(self) -> Result<CalendarEventRequestParams, Self::Error> {
     let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
-    Ok(CalendarEventRequestParams { view_id: view_id.0 })
+    Ok(CalendarEventRequestParams {
+      view_id: view_id.0,
+      start: self.start,
+      end: self.end,
+    })
   }
 }
 
@@ -1,12 +1,17 @@
+use std::str::FromStr;
+
 use collab_database::rows::RowId;
 
 use flowy_derive::ProtoBuf;
-use flowy_error::ErrorCode;
+use flowy_error::{ErrorCode, FlowyError};
 use lib_infra::validator_fn::required_not_empty_str;
+use rust_decimal::Decimal;
 use validator::Validate;
 
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::FieldType;
+use crate::services::cell::{CellDisplay, TypedCellChangeset};
+use crate::services::share::clipboard::{ClipboardData, PasteCellFailure, PasteResult};
 
 #[derive(ProtoBuf, Default)]
 pub struct CreateSelectOptionPayloadPB {
@@ -96,6 +101,14 @@ pub struct CellPB {
   /// the field_type will be None if the field with field_id is not found
   #[pb(index = 4, one_of)]
   pub field_type: Option<FieldType>,
+
+  /// The cell belongs to a sensitive field and its data is not included.
+  #[pb(index = 5)]
+  pub is_masked: bool,
+
+  /// The stored value of the cell and its display string. Empty if the cell is masked.
+  #[pb(index = 6)]
+  pub display: CellDisplayPB,
 }
 
 impl CellPB {
@@ -105,6 +118,8 @@ impl CellPB {
       row_id,
       data,
       field_type: Some(field_type),
+      is_masked: false,
+      display: CellDisplayPB::default(),
     }
   }
 
@@ -114,6 +129,42 @@ impl CellPB {
       row_id,
       data: vec![],
       field_type: None,
+      is_masked: false,
+      display: CellDisplayPB::default(),
+    }
+  }
+
+  pub fn masked(field_id: &str, row_id: String, field_type: FieldType) -> Self {
+    Self {
+      field_id: field_id.to_owned(),
+      row_id,
+      data: vec![],
+      field_type: Some(field_type),
+      is_masked: true,
+      display: CellDisplayPB::default(),
+    }
+  }
+
+  pub fn with_display(mut self, display: CellDisplay) -> Self {
+    self.display = display.into();
+    self
+  }
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct CellDisplayPB {
+  #[pb(index = 1)]
+  pub value: String,
+
+  #[pb(index = 2)]
+  pub display: String,
+}
+
+impl From<CellDisplay> for CellDisplayPB {
+  fn from(display: CellDisplay) -> Self {
+    Self {
+      value: display.value,
+      display: display.display,
     }
   }
 }
@@ -169,3 +220,256 @@ pub struct CellChangesetNotifyPB {
   #[pb(index = 3)]
   pub field_id: String,
 }
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RepeatedCellChangesetNotifyPB {
+  #[pb(index = 1)]
+  pub items: Vec<CellChangesetNotifyPB>,
+}
+
+/// Updates many cells of a view at once with the [UpdateCells] event.
+#[derive(Debug, Clone, Default, ProtoBuf, Validate)]
+pub struct BatchCellChangesetPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub changesets: Vec<CellChangesetPB>,
+}
+
+/// Updates a cell with the data of its field type instead of the string of [CellChangesetPB].
+/// Exactly one of the data must be set, and it must match the type of the field.
+#[derive(Debug, Clone, Default, ProtoBuf, Validate)]
+pub struct TypedCellChangesetPB {
+  #[pb(index = 1)]
+  #[validate(nested)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2, one_of)]
+  pub text: Option<String>,
+
+  /// A decimal number, e.g. `-12.5`, without the symbols of the number format of the field.
+  #[pb(index = 3, one_of)]
+  pub number: Option<String>,
+
+  #[pb(index = 4, one_of)]
+  pub checkbox: Option<bool>,
+
+  /// The timestamp of the date in seconds.
+  #[pb(index = 5, one_of)]
+  pub date: Option<i64>,
+
+  #[pb(index = 6, one_of)]
+  pub select_option_ids: Option<SelectOptionIdsPB>,
+
+  #[pb(index = 7, one_of)]
+  pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct SelectOptionIdsPB {
+  #[pb(index = 1)]
+  pub option_ids: Vec<String>,
+}
+
+impl TryFrom<TypedCellChangesetPB> for TypedCellChangeset {
+  type Error = FlowyError;
+
+  fn try_from(payload: TypedCellChangesetPB) -> Result<Self, Self::Error> {
+    let mut changesets = vec![];
+    if let Some(text) = payload.text {
+      changesets.push(TypedCellChangeset::Text(text));
+    }
+    if let Some(number) = payload.number {
+      let decimal = Decimal::from_str(number.trim()).map_err(|err| {
+        FlowyError::invalid_data().with_context(format!("Invalid number {}: {}", number, err))
+      })?;
+      changesets.push(TypedCellChangeset::Number(decimal));
+    }
+    if let Some(is_checked) = payload.checkbox {
+      changesets.push(TypedCellChangeset::Checkbox(is_checked));
+    }
+    if let Some(timestamp) = payload.date {
+      changesets.push(TypedCellChangeset::Date(timestamp));
+    }
+    if let Some(option_ids) = payload.select_option_ids {
+      changesets.push(TypedCellChangeset::SelectOptionIds(option_ids.option_ids));
+    }
+    if let Some(url) = payload.url {
+      changesets.push(TypedCellChangeset::URL(url));
+    }
+
+    match changesets.len() {
+      1 => Ok(changesets.remove(0)),
+      _ => Err(FlowyError::invalid_data().with_context("Exactly one of the cell data must be set")),
+    }
+  }
+}
+
+/// Marks the cell as being edited by the current user, or clears the mark if `is_editing` is
+/// false. The client is expected to send it again while the user keeps typing, otherwise the
+/// mark expires after a few seconds.
+#[derive(Debug, Clone, Default, ProtoBuf, Validate)]
+pub struct CellEditingPayloadPB {
+  #[pb(index = 1)]
+  #[validate(nested)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2)]
+  pub is_editing: bool,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct CellEditingStatePB {
+  #[pb(index = 1)]
+  pub uid: i64,
+
+  #[pb(index = 2)]
+  pub view_id: String,
+
+  #[pb(index = 3)]
+  pub row_id: String,
+
+  #[pb(index = 4)]
+  pub field_id: String,
+
+  #[pb(index = 5)]
+  pub timestamp: i64,
+}
+
+/// The cells that are being edited by the other collaborators of the database.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RepeatedCellEditingStatePB {
+  #[pb(index = 1)]
+  pub items: Vec<CellEditingStatePB>,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf, Validate)]
+pub struct CopyCellsPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_ids: Vec<String>,
+
+  #[pb(index = 3)]
+  pub field_ids: Vec<String>,
+}
+
+/// The copied cells. The [ClipboardDataPB::plain_text] is put on the clipboard for the other
+/// apps, and both are passed back to [PasteCellsPayloadPB] when pasting.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct ClipboardDataPB {
+  #[pb(index = 1)]
+  pub plain_text: String,
+
+  /// Empty if the cells are copied from another app.
+  #[pb(index = 2)]
+  pub payload: String,
+}
+
+impl From<ClipboardData> for ClipboardDataPB {
+  fn from(data: ClipboardData) -> Self {
+    Self {
+      plain_text: data.plain_text,
+      payload: data.payload,
+    }
+  }
+}
+
+impl From<ClipboardDataPB> for ClipboardData {
+  fn from(data: ClipboardDataPB) -> Self {
+    Self {
+      plain_text: data.plain_text,
+      payload: data.payload,
+    }
+  }
+}
+
+/// Pastes the cells with the cell of [PasteCellsPayloadPB::anchor] as the top left corner.
+#[derive(Debug, Clone, Default, ProtoBuf, Validate)]
+pub struct PasteCellsPayloadPB {
+  #[pb(index = 1)]
+  #[validate(nested)]
+  pub anchor: CellIdPB,
+
+  #[pb(index = 2)]
+  pub data: ClipboardDataPB,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct PasteCellsResultPB {
+  /// The cells that can't be pasted into their fields are not counted.
+  #[pb(index = 1)]
+  pub num_of_pasted_cells: i64,
+
+  #[pb(index = 2)]
+  pub failures: Vec<PasteCellFailurePB>,
+
+  /// The ids of the rows created by [PasteRowsPayloadPB]. Empty when pasting into existing rows.
+  #[pb(index = 3)]
+  pub created_row_ids: Vec<String>,
+}
+
+impl From<PasteResult> for PasteCellsResultPB {
+  fn from(result: PasteResult) -> Self {
+    Self {
+      num_of_pasted_cells: result.num_of_pasted_cells as i64,
+      failures: result
+        .failures
+        .into_iter()
+        .map(PasteCellFailurePB::from)
+        .collect(),
+      created_row_ids: result
+        .created_row_ids
+        .into_iter()
+        .map(|row_id| row_id.to_string())
+        .collect(),
+    }
+  }
+}
+
+/// A copied cell that couldn't be pasted into its field. The indexes are the position of the
+/// cell in the copied data.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct PasteCellFailurePB {
+  #[pb(index = 1)]
+  pub row_index: i32,
+
+  #[pb(index = 2)]
+  pub column_index: i32,
+
+  #[pb(index = 3)]
+  pub field_id: String,
+
+  #[pb(index = 4)]
+  pub text: String,
+
+  #[pb(index = 5)]
+  pub reason: String,
+}
+
+impl From<PasteCellFailure> for PasteCellFailurePB {
+  fn from(failure: PasteCellFailure) -> Self {
+    Self {
+      row_index: failure.row_index as i32,
+      column_index: failure.column_index as i32,
+      field_id: failure.field_id,
+      text: failure.text,
+      reason: failure.reason,
+    }
+  }
+}
+
+/// Pastes each copied row as a new row at the end of the view.
+#[derive(Debug, Clone, Default, ProtoBuf, Validate)]
+pub struct PasteRowsPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub data: ClipboardDataPB,
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::rows::Row;
+use collab_database::views::RowOrder;
+
+use crate::entities::{FieldPB, RepeatedFieldPB, RepeatedRowMetaPB, RowMetaPB};
+
+/// Converts a batch of items into protobuf entities by reference, so the items are not cloned
+/// just to be converted. The output is allocated once with the number of items.
+///
+/// Prefer it over `items.iter().map(|item| P::from(item.clone()))` when reading many rows or
+/// fields.
+pub fn to_pb_vec<'a, T, P, I>(items: I) -> Vec<P>
+where
+  T: 'a + ?Sized,
+  P: From<&'a T>,
+  I: IntoIterator<Item = &'a T>,
+  I::IntoIter: ExactSizeIterator,
+{
+  let items = items.into_iter();
+  let mut pbs = Vec::with_capacity(items.len());
+  pbs.extend(items.map(P::from));
+  pbs
+}
+
+impl From<&[Arc<Row>]> for RepeatedRowMetaPB {
+  fn from(rows: &[Arc<Row>]) -> Self {
+    Self {
+      items: to_pb_vec::<Row, _, _>(rows.iter().map(|row| row.as_ref())),
+    }
+  }
+}
+
+impl From<&[RowOrder]> for RepeatedRowMetaPB {
+  fn from(row_orders: &[RowOrder]) -> Self {
+    Self {
+      items: to_pb_vec(row_orders),
+    }
+  }
+}
+
+impl From<Vec<Field>> for RepeatedFieldPB {
+  fn from(fields: Vec<Field>) -> Self {
+    let mut items = Vec::with_capacity(fields.len());
+    items.extend(fields.into_iter().map(FieldPB::new));
+    Self { items }
+  }
+}
+
+impl From<&RowOrder> for RowMetaPB {
+  fn from(row_order: &RowOrder) -> Self {
+    Self {
+      id: row_order.id.to_string(),
+      document_id: None,
+      icon: None,
+      is_document_empty: None,
+      attachment_count: None,
+      cover: None,
+      created_at: None,
+      modified_at: None,
+      is_locked: None,
+    }
+  }
+}
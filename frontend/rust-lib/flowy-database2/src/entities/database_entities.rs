@@ -1,6 +1,8 @@
 use collab::core::collab_state::SyncState;
 use collab_database::rows::RowId;
 use collab_database::views::DatabaseLayout;
+use collab_integrate::snapshot::SnapshotPolicy;
+use std::time::Duration;
 
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::{ErrorCode, FlowyError};
@@ -10,7 +12,8 @@ use validator::Validate;
 
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::{DatabaseLayoutPB, FieldIdPB, RowMetaPB};
-use crate::services::database::CreateDatabaseViewParams;
+use crate::services::database::{CreateDatabaseViewParams, DatabaseEditorDiagnostics};
+use crate::services::snapshot::diff::{CellDiff, DatabaseDiff, RowDiff};
 
 /// [DatabasePB] describes how many fields and blocks the grid has
 #[derive(Debug, Clone, Default, ProtoBuf)]
@@ -29,6 +32,10 @@ pub struct DatabasePB {
 
   #[pb(index = 5)]
   pub is_linked: bool,
+
+  /// True if the database is opened in safe mode, see [DatabaseCorruptionPB].
+  #[pb(index = 6)]
+  pub is_read_only: bool,
 }
 
 #[derive(ProtoBuf, Default)]
@@ -87,6 +94,46 @@ impl AsRef<str> for DatabaseViewIdPB {
   }
 }
 
+/// Describes one of the views of a database. All the views share the rows and fields of the
+/// database, but each view has its own layout, filters, sorts and field visibility.
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct DatabaseViewMetaPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub layout: DatabaseLayoutPB,
+
+  /// True if the view is the inline view of the database, i.e. the view the database was
+  /// created with.
+  #[pb(index = 4)]
+  pub is_inline: bool,
+
+  /// True if the view opens by default when the database is opened.
+  #[pb(index = 5)]
+  pub is_default: bool,
+}
+
+#[derive(Clone, ProtoBuf, Default, Debug)]
+pub struct RepeatedDatabaseViewMetaPB {
+  #[pb(index = 1)]
+  pub items: Vec<DatabaseViewMetaPB>,
+}
+
+/// Moves the view to the given index of the display order of the views of its database.
+#[derive(Debug, Clone, Default, ProtoBuf, Validate)]
+pub struct MoveDatabaseViewPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub to_index: i32,
+}
+
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct MoveFieldPayloadPB {
   #[pb(index = 1)]
@@ -156,6 +203,41 @@ impl TryInto<MoveRowParams> for MoveRowPayloadPB {
   }
 }
 
+/// Moves a row to an index of the rows that are visible in the view.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct MoveRowToIndexPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub to_index: i32,
+}
+
+pub struct MoveRowToIndexParams {
+  pub view_id: String,
+  pub row_id: RowId,
+  pub to_index: usize,
+}
+
+impl TryInto<MoveRowToIndexParams> for MoveRowToIndexPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<MoveRowToIndexParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+    let to_index = usize::try_from(self.to_index).map_err(|_| ErrorCode::InvalidParams)?;
+
+    Ok(MoveRowToIndexParams {
+      view_id: view_id.0,
+      row_id: RowId::from(row_id.0),
+      to_index,
+    })
+  }
+}
+
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct MoveGroupRowPayloadPB {
   #[pb(index = 1)]
@@ -313,7 +395,7 @@ pub struct RepeatedDatabaseSnapshotPB {
 #[derive(Debug, Default, ProtoBuf)]
 pub struct DatabaseSnapshotPB {
   #[pb(index = 1)]
-  pub snapshot_id: i64,
+  pub snapshot_id: String,
 
   #[pb(index = 2)]
   pub snapshot_desc: String,
@@ -325,6 +407,170 @@ pub struct DatabaseSnapshotPB {
   pub data: Vec<u8>,
 }
 
+/// The policy that decides when the snapshots of the databases are created. Snapshots are
+/// disabled by default.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct DatabaseSnapshotPolicyPB {
+  /// Create a snapshot after this many updates since the last snapshot. Zero disables it.
+  #[pb(index = 1)]
+  pub every_n_updates: u32,
+
+  /// Create a snapshot on the first update after this many seconds since the last snapshot.
+  /// Zero disables it.
+  #[pb(index = 2)]
+  pub every_interval_secs: i64,
+
+  /// Create a snapshot when the database is closed after being edited.
+  #[pb(index = 3)]
+  pub on_close: bool,
+}
+
+impl From<SnapshotPolicy> for DatabaseSnapshotPolicyPB {
+  fn from(policy: SnapshotPolicy) -> Self {
+    Self {
+      every_n_updates: policy.every_n_updates.unwrap_or(0),
+      every_interval_secs: policy
+        .every_interval
+        .map(|interval| interval.as_secs() as i64)
+        .unwrap_or(0),
+      on_close: policy.on_close,
+    }
+  }
+}
+
+impl From<DatabaseSnapshotPolicyPB> for SnapshotPolicy {
+  fn from(pb: DatabaseSnapshotPolicyPB) -> Self {
+    SnapshotPolicy::disabled()
+      .every_n_updates(pb.every_n_updates)
+      .every_interval(Duration::from_secs(pb.every_interval_secs.max(0) as u64))
+      .on_close(pb.on_close)
+  }
+}
+
+/// Sent with the [DidDetectDatabaseCorruption] notification when the local data of a database
+/// can't be loaded. If a snapshot is available, the database is opened read-only from the
+/// snapshot until the user repairs it with the [RepairDatabase] event.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct DatabaseCorruptionPB {
+  #[pb(index = 1)]
+  pub database_id: String,
+
+  /// The error that occurred when loading the database.
+  #[pb(index = 2)]
+  pub reason: String,
+
+  /// The id of the snapshot the database is opened from, or None if there is no snapshot to
+  /// restore and the database can't be opened.
+  #[pb(index = 3, one_of)]
+  pub snapshot_id: Option<String>,
+}
+
+#[derive(Debug, Default, ProtoBuf, Validate)]
+pub struct DiffDatabasePayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  /// The id of a snapshot of the database, as returned by the [GetDatabaseSnapshots] event.
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub before_snapshot_id: String,
+
+  /// The current state of the database is compared if it's None.
+  #[pb(index = 3, one_of)]
+  pub after_snapshot_id: Option<String>,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DatabaseDiffPB {
+  #[pb(index = 1)]
+  pub fields_added: Vec<FieldIdPB>,
+
+  #[pb(index = 2)]
+  pub fields_removed: Vec<FieldIdPB>,
+
+  #[pb(index = 3)]
+  pub fields_updated: Vec<FieldIdPB>,
+
+  #[pb(index = 4)]
+  pub rows_added: Vec<String>,
+
+  #[pb(index = 5)]
+  pub rows_removed: Vec<String>,
+
+  #[pb(index = 6)]
+  pub rows_changed: Vec<RowDiffPB>,
+}
+
+impl From<DatabaseDiff> for DatabaseDiffPB {
+  fn from(diff: DatabaseDiff) -> Self {
+    Self {
+      fields_added: diff
+        .fields_added
+        .iter()
+        .map(|field| FieldIdPB::from(field.id.as_str()))
+        .collect(),
+      fields_removed: diff
+        .fields_removed
+        .iter()
+        .map(|field| FieldIdPB::from(field.id.as_str()))
+        .collect(),
+      fields_updated: diff
+        .fields_updated
+        .iter()
+        .map(|field| FieldIdPB::from(field.after.id.as_str()))
+        .collect(),
+      rows_added: diff.rows_added.into_iter().map(RowId::into_inner).collect(),
+      rows_removed: diff
+        .rows_removed
+        .into_iter()
+        .map(RowId::into_inner)
+        .collect(),
+      rows_changed: diff.rows_changed.into_iter().map(RowDiffPB::from).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RowDiffPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub cells: Vec<CellDiffPB>,
+}
+
+impl From<RowDiff> for RowDiffPB {
+  fn from(diff: RowDiff) -> Self {
+    Self {
+      row_id: diff.row_id.into_inner(),
+      cells: diff.cells.into_iter().map(CellDiffPB::from).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct CellDiffPB {
+  #[pb(index = 1)]
+  pub field_id: String,
+
+  #[pb(index = 2)]
+  pub before: String,
+
+  #[pb(index = 3)]
+  pub after: String,
+}
+
+impl From<CellDiff> for CellDiffPB {
+  fn from(diff: CellDiff) -> Self {
+    Self {
+      field_id: diff.field_id,
+      before: diff.before,
+      after: diff.after,
+    }
+  }
+}
+
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct RemoveCoverPayloadPB {
   #[pb(index = 1)]
@@ -352,3 +598,51 @@ impl TryInto<RemoveCoverParams> for RemoveCoverPayloadPB {
     })
   }
 }
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DatabaseEditorDiagnosticsPB {
+  #[pb(index = 1)]
+  pub database_id: String,
+
+  #[pb(index = 2)]
+  pub view_ids: Vec<String>,
+
+  /// None if the database never synced, e.g. for a local user. There is no count of the
+  /// unsynced updates, the collab doesn't expose it. [DatabaseSyncState::Syncing] means that
+  /// some local updates are not acknowledged by the server yet.
+  #[pb(index = 3, one_of)]
+  pub sync_state: Option<DatabaseSyncState>,
+
+  #[pb(index = 4)]
+  pub num_of_cached_cells: i64,
+
+  #[pb(index = 5)]
+  pub num_of_loaded_rows: i64,
+
+  /// The timestamp in seconds of the last time the editor was used.
+  #[pb(index = 6)]
+  pub last_activity: i64,
+
+  #[pb(index = 7)]
+  pub is_closing: bool,
+}
+
+impl From<DatabaseEditorDiagnostics> for DatabaseEditorDiagnosticsPB {
+  fn from(diagnostics: DatabaseEditorDiagnostics) -> Self {
+    Self {
+      database_id: diagnostics.database_id,
+      view_ids: diagnostics.view_ids,
+      sync_state: diagnostics.sync_state,
+      num_of_cached_cells: diagnostics.num_of_cached_cells as i64,
+      num_of_loaded_rows: diagnostics.num_of_loaded_rows as i64,
+      last_activity: diagnostics.last_activity,
+      is_closing: diagnostics.is_closing,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedDatabaseEditorDiagnosticsPB {
+  #[pb(index = 1)]
+  pub items: Vec<DatabaseEditorDiagnosticsPB>,
+}
@@ -0,0 +1,79 @@
+use flowy_derive::ProtoBuf;
+
+use crate::entities::{FieldType, SortConditionPB};
+use crate::services::filter::FilterExplanation;
+use crate::services::sort::SortKeyExplanation;
+
+/// [QueryExplanationPB] describes how the filters and the sorts of a view are applied to a row.
+/// It is used to debug why a row is hidden or why it is placed at its position.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct QueryExplanationPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub is_visible: bool,
+
+  #[pb(index = 4)]
+  pub filters: Vec<FilterExplanationPB>,
+
+  #[pb(index = 5)]
+  pub sorts: Vec<SortKeyExplanationPB>,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct FilterExplanationPB {
+  #[pb(index = 1)]
+  pub filter_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub field_type: FieldType,
+
+  /// False if the filter rejects the row.
+  #[pb(index = 4)]
+  pub is_match: bool,
+}
+
+impl From<FilterExplanation> for FilterExplanationPB {
+  fn from(data: FilterExplanation) -> Self {
+    Self {
+      filter_id: data.filter_id,
+      field_id: data.field_id,
+      field_type: data.field_type,
+      is_match: data.is_match,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct SortKeyExplanationPB {
+  #[pb(index = 1)]
+  pub sort_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub condition: SortConditionPB,
+
+  /// The content of the row's cell that is used as the sort key.
+  #[pb(index = 4)]
+  pub cell_content: String,
+}
+
+impl From<SortKeyExplanation> for SortKeyExplanationPB {
+  fn from(data: SortKeyExplanation) -> Self {
+    Self {
+      sort_id: data.sort_id,
+      field_id: data.field_id,
+      condition: data.condition.into(),
+      cell_content: data.cell_content,
+    }
+  }
+}
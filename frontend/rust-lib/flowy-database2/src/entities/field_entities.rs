@@ -16,7 +16,9 @@ use validator::Validate;
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::position_entities::OrderObjectPositionPB;
 use crate::impl_into_field_type;
-use crate::services::field::{default_type_option_data_from_type, type_option_to_pb};
+use crate::services::field::{
+  default_type_option_data_from_type, type_option_to_pb, FieldAttributes,
+};
 
 /// [FieldPB] defines a Field's attributes. Such as the name, field_type, and width. etc.
 #[derive(Debug, Clone, Default, ProtoBuf)]
@@ -38,6 +40,21 @@ pub struct FieldPB {
 
   #[pb(index = 6)]
   pub type_option_data: Vec<u8>,
+
+  #[pb(index = 7)]
+  pub is_sensitive: bool,
+
+  #[pb(index = 8)]
+  pub max_selections: i64,
+
+  #[pb(index = 9)]
+  pub default_value: String,
+
+  #[pb(index = 10)]
+  pub is_unique: bool,
+
+  #[pb(index = 11)]
+  pub is_required: bool,
 }
 
 impl FieldPB {
@@ -46,6 +63,7 @@ impl FieldPB {
     let type_option = field
       .get_any_type_option(field_type)
       .unwrap_or_else(|| default_type_option_data_from_type(field_type));
+    let attributes = FieldAttributes::from_field(&field);
     Self {
       id: field.id,
       name: field.name,
@@ -53,6 +71,11 @@ impl FieldPB {
       field_type,
       is_primary: field.is_primary,
       type_option_data: type_option_to_pb(type_option, &field_type).to_vec(),
+      is_sensitive: attributes.is_sensitive,
+      max_selections: attributes.max_selections,
+      default_value: attributes.default_value,
+      is_unique: attributes.is_unique,
+      is_required: attributes.is_required,
     }
   }
 }
@@ -398,6 +421,31 @@ pub struct FieldChangesetPB {
 
   #[pb(index = 6, one_of)]
   pub frozen: Option<bool>,
+
+  /// Mark the field as sensitive. The cells of a sensitive field are masked unless they
+  /// are explicitly revealed.
+  #[pb(index = 7, one_of)]
+  pub is_sensitive: Option<bool>,
+
+  /// The maximum number of options a multi-select cell can hold. Zero removes the limit.
+  #[pb(index = 8, one_of)]
+  pub max_selections: Option<i64>,
+
+  /// The value of the cell of a new row that is created without a value for the field, e.g.
+  /// `today` for a date field or the name of an option for a select field. An empty string
+  /// removes the default value.
+  #[pb(index = 9, one_of)]
+  pub default_value: Option<String>,
+
+  /// Mark the field as unique. The existing duplicate values are kept, but no new duplicate
+  /// value can be set.
+  #[pb(index = 10, one_of)]
+  pub is_unique: Option<bool>,
+
+  /// Mark the field as required. The rows with an empty cell in the field are reported by
+  /// the `ValidateRow` and `GetInvalidRows` events.
+  #[pb(index = 11, one_of)]
+  pub is_required: Option<bool>,
 }
 
 /// Certain field types have user-defined options such as color, date format, number format,
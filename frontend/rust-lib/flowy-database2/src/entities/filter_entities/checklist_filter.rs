@@ -15,6 +15,8 @@ pub enum ChecklistFilterConditionPB {
   #[default]
   IsComplete = 0,
   IsIncomplete = 1,
+  IsEmpty = 2,
+  IsNotEmpty = 3,
 }
 
 impl std::convert::From<ChecklistFilterConditionPB> for u32 {
@@ -30,6 +32,8 @@ impl std::convert::TryFrom<u8> for ChecklistFilterConditionPB {
     match value {
       0 => Ok(ChecklistFilterConditionPB::IsComplete),
       1 => Ok(ChecklistFilterConditionPB::IsIncomplete),
+      2 => Ok(ChecklistFilterConditionPB::IsEmpty),
+      3 => Ok(ChecklistFilterConditionPB::IsNotEmpty),
       _ => Err(ErrorCode::InvalidParams),
     }
   }
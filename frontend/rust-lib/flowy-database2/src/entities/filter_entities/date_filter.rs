@@ -20,6 +20,11 @@ pub struct DateFilterPB {
 
   #[pb(index = 4, one_of)]
   pub timestamp: Option<i64>,
+
+  /// The number of days before today for the `DateStartsInLastDays` and `DateEndsInLastDays`
+  /// conditions.
+  #[pb(index = 5, one_of)]
+  pub days: Option<i64>,
 }
 
 #[derive(Deserialize, Serialize, Default, Clone, Debug)]
@@ -27,6 +32,8 @@ pub struct DateFilterContent {
   pub start: Option<i64>,
   pub end: Option<i64>,
   pub timestamp: Option<i64>,
+  #[serde(default)]
+  pub days: Option<i64>,
 }
 
 impl ToString for DateFilterContent {
@@ -63,6 +70,12 @@ pub enum DateFilterConditionPB {
   DateEndsBetween = 13,
   DateEndIsEmpty = 14,
   DateEndIsNotEmpty = 15,
+  DateStartsToday = 16,
+  DateStartsThisWeek = 17,
+  DateStartsInLastDays = 18,
+  DateEndsToday = 19,
+  DateEndsThisWeek = 20,
+  DateEndsInLastDays = 21,
 }
 
 impl DateFilterConditionPB {
@@ -76,7 +89,10 @@ impl DateFilterConditionPB {
         | Self::DateStartsOnOrAfter
         | Self::DateStartsBetween
         | Self::DateStartIsEmpty
-        | Self::DateStartIsNotEmpty,
+        | Self::DateStartIsNotEmpty
+        | Self::DateStartsToday
+        | Self::DateStartsThisWeek
+        | Self::DateStartsInLastDays,
     )
   }
 }
@@ -108,6 +124,12 @@ impl std::convert::TryFrom<u8> for DateFilterConditionPB {
       13 => Ok(Self::DateEndsBetween),
       14 => Ok(Self::DateEndIsEmpty),
       15 => Ok(Self::DateEndIsNotEmpty),
+      16 => Ok(Self::DateStartsToday),
+      17 => Ok(Self::DateStartsThisWeek),
+      18 => Ok(Self::DateStartsInLastDays),
+      19 => Ok(Self::DateEndsToday),
+      20 => Ok(Self::DateEndsThisWeek),
+      21 => Ok(Self::DateEndsInLastDays),
       _ => Err(ErrorCode::InvalidParams),
     }
   }
@@ -126,6 +148,7 @@ impl ParseFilterData for DateFilterPB {
       date_filter.start = content.start;
       date_filter.end = content.end;
       date_filter.timestamp = content.timestamp;
+      date_filter.days = content.days;
     };
 
     date_filter
@@ -0,0 +1,68 @@
+use flowy_derive::ProtoBuf;
+use lib_infra::validator_fn::required_not_empty_str;
+use validator::Validate;
+
+use crate::entities::FilterPB;
+use crate::services::filter::{Filter, FilterExpressionError};
+
+/// Parses a filter expression like `Status = "Live" AND Price > 100` against the fields of the
+/// view.
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct FilterExpressionPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub expression: String,
+}
+
+/// The error of an expression that can't be parsed. `start` and `end` are the offsets, in chars,
+/// of the part of the expression that caused the error.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct FilterExpressionErrorPB {
+  #[pb(index = 1)]
+  pub message: String,
+
+  #[pb(index = 2)]
+  pub start: i32,
+
+  #[pb(index = 3)]
+  pub end: i32,
+}
+
+impl From<FilterExpressionError> for FilterExpressionErrorPB {
+  fn from(err: FilterExpressionError) -> Self {
+    Self {
+      message: err.message,
+      start: err.start as i32,
+      end: err.end as i32,
+    }
+  }
+}
+
+/// Either the filter parsed from the expression or the error of the expression. The filter isn't
+/// applied to the view.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct FilterExpressionResultPB {
+  #[pb(index = 1, one_of)]
+  pub filter: Option<FilterPB>,
+
+  #[pb(index = 2, one_of)]
+  pub error: Option<FilterExpressionErrorPB>,
+}
+
+impl From<Result<Filter, FilterExpressionError>> for FilterExpressionResultPB {
+  fn from(result: Result<Filter, FilterExpressionError>) -> Self {
+    match result {
+      Ok(filter) => Self {
+        filter: Some(FilterPB::from(&filter)),
+        error: None,
+      },
+      Err(err) => Self {
+        filter: None,
+        error: Some(err.into()),
+      },
+    }
+  }
+}
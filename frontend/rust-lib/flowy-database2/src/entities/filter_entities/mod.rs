@@ -2,6 +2,7 @@ mod checkbox_filter;
 mod checklist_filter;
 mod date_filter;
 mod filter_changeset;
+mod filter_expression;
 mod media_filter;
 mod number_filter;
 mod relation_filter;
@@ -14,6 +15,7 @@ pub use checkbox_filter::*;
 pub use checklist_filter::*;
 pub use date_filter::*;
 pub use filter_changeset::*;
+pub use filter_expression::*;
 pub use media_filter::*;
 pub use number_filter::*;
 pub use relation_filter::*;
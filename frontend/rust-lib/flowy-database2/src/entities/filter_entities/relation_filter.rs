@@ -1,22 +1,59 @@
-use collab_database::{fields::Field, rows::Cell};
-use flowy_derive::ProtoBuf;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
 
-use crate::services::filter::{ParseFilterData, PreFillCellsWithFilter};
+use crate::services::filter::ParseFilterData;
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct RelationFilterPB {
   #[pb(index = 1)]
-  pub condition: i64,
+  pub condition: RelationFilterConditionPB,
+
+  #[pb(index = 2)]
+  pub row_ids: Vec<String>,
 }
 
-impl ParseFilterData for RelationFilterPB {
-  fn parse(_condition: u8, _content: String) -> Self {
-    RelationFilterPB { condition: 0 }
+/// The relation filters saved before the conditions were added have the condition `0` and no
+/// row ids, so `0` is [RelationFilterConditionPB::RelationContains], which doesn't hide any row
+/// until a row is picked.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum RelationFilterConditionPB {
+  #[default]
+  RelationContains = 0,
+  RelationDoesNotContain = 1,
+  RelationIsEmpty = 2,
+  RelationIsNotEmpty = 3,
+}
+
+impl std::convert::From<RelationFilterConditionPB> for u32 {
+  fn from(value: RelationFilterConditionPB) -> Self {
+    value as u32
   }
 }
 
-impl PreFillCellsWithFilter for RelationFilterPB {
-  fn get_compliant_cell(&self, _field: &Field) -> Option<Cell> {
-    None
+impl std::convert::TryFrom<u8> for RelationFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      0 => Ok(RelationFilterConditionPB::RelationContains),
+      1 => Ok(RelationFilterConditionPB::RelationDoesNotContain),
+      2 => Ok(RelationFilterConditionPB::RelationIsEmpty),
+      3 => Ok(RelationFilterConditionPB::RelationIsNotEmpty),
+      _ => Err(ErrorCode::InvalidParams),
+    }
+  }
+}
+
+impl ParseFilterData for RelationFilterPB {
+  fn parse(condition: u8, content: String) -> Self {
+    Self {
+      condition: RelationFilterConditionPB::try_from(condition).unwrap_or_default(),
+      row_ids: content
+        .split(',')
+        .filter(|row_id| !row_id.is_empty())
+        .map(|row_id| row_id.to_string())
+        .collect(),
+    }
   }
 }
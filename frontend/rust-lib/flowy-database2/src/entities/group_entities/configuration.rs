@@ -31,6 +31,9 @@ pub struct GroupRecordPB {
 
   #[pb(index = 2)]
   visible: bool,
+
+  #[pb(index = 3)]
+  collapsed: bool,
 }
 
 impl std::convert::From<Group> for GroupRecordPB {
@@ -38,6 +41,7 @@ impl std::convert::From<Group> for GroupRecordPB {
     Self {
       group_id: rev.id,
       visible: rev.visible,
+      collapsed: rev.collapsed,
     }
   }
 }
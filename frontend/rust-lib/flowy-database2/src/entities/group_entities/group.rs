@@ -21,6 +21,9 @@ pub struct GroupSettingPB {
 
   #[pb(index = 3)]
   pub content: Vec<u8>,
+
+  #[pb(index = 4)]
+  pub aggregate_field_id: String,
 }
 
 impl std::convert::From<&GroupSetting> for GroupSettingPB {
@@ -30,6 +33,7 @@ impl std::convert::From<&GroupSetting> for GroupSettingPB {
       id: rev.id.clone(),
       field_id: rev.field_id.clone(),
       content: group_config_json_to_pb(rev.content.clone(), &field_type).to_vec(),
+      aggregate_field_id: rev.aggregate_field_id.clone(),
     }
   }
 }
@@ -92,6 +96,12 @@ pub struct GroupPB {
 
   #[pb(index = 6)]
   pub is_visible: bool,
+
+  #[pb(index = 7)]
+  pub is_collapsed: bool,
+
+  #[pb(index = 8)]
+  pub aggregate: GroupAggregatePB,
 }
 
 impl std::convert::From<GroupData> for GroupPB {
@@ -99,13 +109,46 @@ impl std::convert::From<GroupData> for GroupPB {
     Self {
       field_id: group_data.field_id,
       group_id: group_data.id,
-      rows: group_data.rows.into_iter().map(RowMetaPB::from).collect(),
+      rows: group_data
+        .rows
+        .iter()
+        .map(|row| RowMetaPB::from(row.as_ref()))
+        .collect(),
       is_default: group_data.is_default,
       is_visible: group_data.is_visible,
+      is_collapsed: group_data.is_collapsed,
+      aggregate: GroupAggregatePB {
+        count: group_data.rows.len() as i64,
+        sum: String::new(),
+      },
     }
   }
 }
 
+/// The aggregate of the rows of a group. `sum` is the sum of the aggregate field of the group
+/// setting, formatted like the calculations of the field, and is empty if the setting doesn't
+/// have an aggregate field.
+#[derive(ProtoBuf, Debug, Default, Clone, PartialEq, Eq)]
+pub struct GroupAggregatePB {
+  #[pb(index = 1)]
+  pub count: i64,
+
+  #[pb(index = 2)]
+  pub sum: String,
+}
+
+/// Sets the field whose values are summed up in each group of the view. An empty `field_id`
+/// removes the aggregate field, so the groups only count their rows.
+#[derive(ProtoBuf, Debug, Default, Clone, Validate)]
+pub struct UpdateGroupAggregatePayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2, one_of)]
+  pub field_id: Option<String>,
+}
+
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct GroupByFieldPayloadPB {
   #[pb(index = 1)]
@@ -158,6 +201,9 @@ pub struct UpdateGroupPB {
 
   #[pb(index = 4, one_of)]
   pub visible: Option<bool>,
+
+  #[pb(index = 5, one_of)]
+  pub collapsed: Option<bool>,
 }
 
 impl TryInto<UpdateGroupParams> for UpdateGroupPB {
@@ -176,6 +222,7 @@ impl TryInto<UpdateGroupParams> for UpdateGroupPB {
       group_id,
       name: self.name,
       visible: self.visible,
+      collapsed: self.collapsed,
     })
   }
 }
@@ -185,6 +232,7 @@ pub struct UpdateGroupParams {
   pub group_id: String,
   pub name: Option<String>,
   pub visible: Option<bool>,
+  pub collapsed: Option<bool>,
 }
 
 impl From<UpdateGroupParams> for GroupChangeset {
@@ -193,6 +241,7 @@ impl From<UpdateGroupParams> for GroupChangeset {
       group_id: params.group_id,
       name: params.name,
       visible: params.visible,
+      collapsed: params.collapsed,
     }
   }
 }
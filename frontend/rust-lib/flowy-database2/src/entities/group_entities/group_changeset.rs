@@ -4,7 +4,7 @@ use flowy_derive::ProtoBuf;
 use flowy_error::ErrorCode;
 
 use crate::entities::parser::NotEmptyStr;
-use crate::entities::{GroupPB, InsertedRowPB, RowMetaPB};
+use crate::entities::{GroupAggregatePB, GroupPB, InsertedRowPB, RowMetaPB};
 
 #[derive(Debug, Default, ProtoBuf)]
 pub struct GroupRowsNotificationPB {
@@ -19,6 +19,10 @@ pub struct GroupRowsNotificationPB {
 
   #[pb(index = 4)]
   pub updated_rows: Vec<RowMetaPB>,
+
+  /// The aggregate of the group after the change
+  #[pb(index = 5, one_of)]
+  pub aggregate: Option<GroupAggregatePB>,
 }
 
 impl std::fmt::Display for GroupRowsNotificationPB {
@@ -0,0 +1,36 @@
+use flowy_derive::ProtoBuf;
+use validator::Validate;
+
+use crate::services::local_server::LocalServer;
+
+/// Starts the local server, see [LocalServer].
+#[derive(Debug, ProtoBuf, Default, Clone, Validate)]
+pub struct StartLocalServerPayloadPB {
+  /// A free port is picked if it's 0.
+  #[pb(index = 1)]
+  #[validate(range(min = 0, max = 65535))]
+  pub port: i32,
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct LocalServerPB {
+  #[pb(index = 1)]
+  pub is_running: bool,
+
+  #[pb(index = 2)]
+  pub port: i32,
+
+  /// The token that the requests must send in an `Authorization: Bearer <token>` header.
+  #[pb(index = 3)]
+  pub token: String,
+}
+
+impl From<&LocalServer> for LocalServerPB {
+  fn from(server: &LocalServer) -> Self {
+    Self {
+      is_running: true,
+      port: server.port() as i32,
+      token: server.token().to_string(),
+    }
+  }
+}
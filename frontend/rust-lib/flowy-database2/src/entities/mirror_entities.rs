@@ -0,0 +1,64 @@
+use flowy_derive::ProtoBuf;
+use lib_infra::validator_fn::required_not_empty_str;
+use validator::Validate;
+
+use crate::services::mirror::{PostgresMirrorColumn, PostgresMirrorConfig};
+
+/// Mirrors a Postgres table into the database of a view. The view is read-only while it's
+/// mirrored. The mirror isn't saved, so it has to be started again after the app restarts.
+#[derive(Debug, ProtoBuf, Default, Clone, Validate)]
+pub struct PostgresMirrorPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  /// The Postgres connection string. TLS is required unless it sets another `sslmode`.
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub connection: String,
+
+  #[pb(index = 3)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub table: String,
+
+  #[pb(index = 4)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub key_column: String,
+
+  #[pb(index = 5)]
+  #[validate(length(min = 1))]
+  pub columns: Vec<MirrorColumnPB>,
+
+  /// The number of seconds between two pulls of the rows.
+  #[pb(index = 6)]
+  #[validate(range(min = 10))]
+  pub interval_secs: i64,
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct MirrorColumnPB {
+  #[pb(index = 1)]
+  pub column: String,
+
+  /// The name of the field the values of the column are written to.
+  #[pb(index = 2)]
+  pub field_name: String,
+}
+
+impl From<PostgresMirrorPayloadPB> for PostgresMirrorConfig {
+  fn from(payload: PostgresMirrorPayloadPB) -> Self {
+    Self {
+      connection: payload.connection,
+      table: payload.table,
+      key_column: payload.key_column,
+      columns: payload
+        .columns
+        .into_iter()
+        .map(|column| PostgresMirrorColumn {
+          column: column.column,
+          field_name: column.field_name,
+        })
+        .collect(),
+    }
+  }
+}
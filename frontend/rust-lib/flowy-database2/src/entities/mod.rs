@@ -1,16 +1,25 @@
+mod automation_entities;
 mod board_entities;
 pub mod calculation;
 mod calendar_entities;
 mod cell_entities;
+mod convert;
 mod database_entities;
+mod explain_entities;
 mod field_entities;
 mod field_settings_entities;
 pub mod file_entities;
 pub mod filter_entities;
 mod group_entities;
+mod local_server_entities;
+mod mirror_entities;
 pub mod parser;
 mod position_entities;
+mod row_color_entities;
+mod row_comment_entities;
 mod row_entities;
+mod row_template_entities;
+mod row_trash_entities;
 pub mod setting_entities;
 mod share_entities;
 mod sort_entities;
@@ -20,18 +29,27 @@ mod view_entities;
 #[macro_use]
 mod macros;
 
+pub use automation_entities::*;
 pub use board_entities::*;
 pub use calculation::*;
 pub use calendar_entities::*;
 pub use cell_entities::*;
+pub use convert::*;
 pub use database_entities::*;
+pub use explain_entities::*;
 pub use field_entities::*;
 pub use field_settings_entities::*;
 pub use file_entities::*;
 pub use filter_entities::*;
 pub use group_entities::*;
+pub use local_server_entities::*;
+pub use mirror_entities::*;
 pub use position_entities::*;
+pub use row_color_entities::*;
+pub use row_comment_entities::*;
 pub use row_entities::*;
+pub use row_template_entities::*;
+pub use row_trash_entities::*;
 pub use setting_entities::*;
 pub use share_entities::*;
 pub use sort_entities::*;
@@ -0,0 +1,72 @@
+use flowy_derive::ProtoBuf;
+use lib_infra::validator_fn::required_not_empty_str;
+use validator::Validate;
+
+use crate::entities::{FilterDataPB, FilterPB};
+use crate::services::setting::RowColorRule;
+
+/// [RowColorRulePB] colors the rows of a view that match its condition. The condition uses the
+/// same data as a filter of the view.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowColorRulePB {
+  /// A new id is generated for the rule if it's empty
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub color: String,
+
+  #[pb(index = 3)]
+  pub condition: FilterDataPB,
+}
+
+impl std::convert::From<&RowColorRule> for RowColorRulePB {
+  fn from(rule: &RowColorRule) -> Self {
+    Self {
+      id: rule.id().to_string(),
+      color: rule.color.clone(),
+      condition: FilterPB::from(&rule.condition).data.unwrap_or_default(),
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedRowColorRulePB {
+  #[pb(index = 1)]
+  pub items: Vec<RowColorRulePB>,
+}
+
+impl std::convert::From<&[RowColorRule]> for RepeatedRowColorRulePB {
+  fn from(rules: &[RowColorRule]) -> Self {
+    Self {
+      items: rules.iter().map(RowColorRulePB::from).collect(),
+    }
+  }
+}
+
+/// Replaces the row color rules of the view. The rules are evaluated in the given order.
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct UpdateRowColorRulesPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub rules: Vec<RowColorRulePB>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowColorPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub color: String,
+}
+
+/// The colors of the rows of a view. The rows that don't match any rule are omitted.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedRowColorPB {
+  #[pb(index = 1)]
+  pub items: Vec<RowColorPB>,
+}
@@ -0,0 +1,114 @@
+use flowy_derive::ProtoBuf;
+use lib_infra::validator_fn::required_not_empty_str;
+use validator::Validate;
+
+use crate::services::row_comment::{RowComment, RowCommentChangeset};
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowCommentPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub author_id: i64,
+
+  #[pb(index = 3)]
+  pub author_name: String,
+
+  /// The time the comment was added, in seconds
+  #[pb(index = 4)]
+  pub created_at: i64,
+
+  #[pb(index = 5)]
+  pub text: String,
+}
+
+impl std::convert::From<RowComment> for RowCommentPB {
+  fn from(comment: RowComment) -> Self {
+    Self {
+      id: comment.id,
+      author_id: comment.author_id,
+      author_name: comment.author_name,
+      created_at: comment.created_at,
+      text: comment.text,
+    }
+  }
+}
+
+/// The comments of a row, oldest first.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedRowCommentPB {
+  #[pb(index = 1)]
+  pub items: Vec<RowCommentPB>,
+}
+
+impl std::convert::From<Vec<RowComment>> for RepeatedRowCommentPB {
+  fn from(comments: Vec<RowComment>) -> Self {
+    Self {
+      items: comments.into_iter().map(RowCommentPB::from).collect(),
+    }
+  }
+}
+
+/// Adds a comment to a row. The comment is added by the current user, under the given name.
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct AddRowCommentPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub author_name: String,
+
+  #[pb(index = 4)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub text: String,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct DeleteRowCommentPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub comment_id: String,
+}
+
+/// Sent with the [DidUpdateRowComments] notification after the comments of a row are changed.
+///
+/// [DidUpdateRowComments]: crate::notification::DatabaseNotification::DidUpdateRowComments
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowCommentChangesetPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub inserted_comments: Vec<RowCommentPB>,
+
+  #[pb(index = 3)]
+  pub deleted_comment_ids: Vec<String>,
+}
+
+impl std::convert::From<RowCommentChangeset> for RowCommentChangesetPB {
+  fn from(changeset: RowCommentChangeset) -> Self {
+    Self {
+      row_id: changeset.row_id,
+      inserted_comments: changeset
+        .inserted_comments
+        .into_iter()
+        .map(RowCommentPB::from)
+        .collect(),
+      deleted_comment_ids: changeset.deleted_comment_ids,
+    }
+  }
+}
@@ -13,8 +13,9 @@ use validator::Validate;
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::position_entities::OrderObjectPositionPB;
 use crate::services::database::{InsertedRow, UpdatedRow};
+use crate::services::row_lock::is_row_locked;
 
-use super::FileUploadTypePB;
+use super::{CellPB, FileUploadTypePB, SortPB};
 
 /// [RowPB] Describes a row. Has the id of the parent Block. Has the metadata of the row.
 #[derive(Debug, Default, Clone, ProtoBuf, Eq, PartialEq)]
@@ -72,6 +73,18 @@ pub struct RowMetaPB {
 
   #[pb(index = 6, one_of)]
   pub cover: Option<RowCoverPB>,
+
+  /// The time the row was created, in seconds. None if the row isn't loaded.
+  #[pb(index = 7, one_of)]
+  pub created_at: Option<i64>,
+
+  /// The time a cell of the row was last edited, in seconds. None if the row isn't loaded.
+  #[pb(index = 8, one_of)]
+  pub modified_at: Option<i64>,
+
+  /// Whether the row is locked, see [crate::services::row_lock]. None if the row isn't loaded.
+  #[pb(index = 9, one_of)]
+  pub is_locked: Option<bool>,
 }
 
 #[derive(Debug, Default, Clone, ProtoBuf, Serialize, Deserialize)]
@@ -144,6 +157,32 @@ pub struct RepeatedRowMetaPB {
   pub items: Vec<RowMetaPB>,
 }
 
+/// A page of the rows of a view, with the filters and the sorts of the view applied.
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct RowPagePayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  #[validate(range(min = 0))]
+  pub offset: i32,
+
+  #[pb(index = 3)]
+  #[validate(range(min = 1, max = 1000))]
+  pub limit: i32,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RowPagePB {
+  #[pb(index = 1)]
+  pub items: Vec<RowMetaPB>,
+
+  /// The number of rows in the view, including the ones outside of the requested page.
+  #[pb(index = 2)]
+  pub total_count: i64,
+}
+
 impl From<RowOrder> for RowMetaPB {
   fn from(data: RowOrder) -> Self {
     Self {
@@ -153,6 +192,9 @@ impl From<RowOrder> for RowMetaPB {
       is_document_empty: None,
       attachment_count: None,
       cover: None,
+      created_at: None,
+      modified_at: None,
+      is_locked: None,
     }
   }
 }
@@ -166,12 +208,16 @@ impl From<&Row> for RowMetaPB {
       cover: None,
       is_document_empty: None,
       attachment_count: None,
+      created_at: Some(data.created_at),
+      modified_at: Some(data.modified_at),
+      is_locked: Some(is_row_locked(data)),
     }
   }
 }
 
 impl From<Row> for RowMetaPB {
   fn from(data: Row) -> Self {
+    let is_locked = is_row_locked(&data);
     Self {
       id: data.id.into_inner(),
       document_id: None,
@@ -179,6 +225,9 @@ impl From<Row> for RowMetaPB {
       is_document_empty: None,
       attachment_count: None,
       cover: None,
+      created_at: Some(data.created_at),
+      modified_at: Some(data.modified_at),
+      is_locked: Some(is_locked),
     }
   }
 }
@@ -187,11 +236,14 @@ impl From<RowDetail> for RowMetaPB {
   fn from(row_detail: RowDetail) -> Self {
     Self {
       id: row_detail.row.id.to_string(),
-      document_id: Some(row_detail.document_id.clone()),
-      icon: row_detail.meta.icon_url.clone(),
+      document_id: Some(row_detail.document_id),
+      icon: row_detail.meta.icon_url,
       is_document_empty: Some(row_detail.meta.is_document_empty),
       attachment_count: Some(row_detail.meta.attachment_count),
       cover: row_detail.meta.cover.map(|cover| cover.into()),
+      created_at: Some(row_detail.row.created_at),
+      modified_at: Some(row_detail.row.modified_at),
+      is_locked: Some(is_row_locked(&row_detail.row)),
     }
   }
 }
@@ -204,11 +256,29 @@ impl From<&RowDetail> for RowMetaPB {
       icon: row_detail.meta.icon_url.clone(),
       is_document_empty: Some(row_detail.meta.is_document_empty),
       attachment_count: Some(row_detail.meta.attachment_count),
-      cover: row_detail.meta.clone().cover.map(|cover| cover.into()),
+      cover: row_detail.meta.cover.clone().map(|cover| cover.into()),
+      created_at: Some(row_detail.row.created_at),
+      modified_at: Some(row_detail.row.modified_at),
+      is_locked: Some(is_row_locked(&row_detail.row)),
     }
   }
 }
 
+/// Locks or unlocks a row, see [crate::services::row_lock].
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct UpdateRowLockPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub locked: bool,
+}
+
 #[derive(Debug, Default, Clone, ProtoBuf)]
 pub struct UpdateRowMetaChangesetPB {
   #[pb(index = 1)]
@@ -478,6 +548,25 @@ pub struct CreateRowPayloadPB {
 
   #[pb(index = 4)]
   pub data: HashMap<String, String>,
+
+  /// The id of the row, generated if it's not given. Creating a row with the id of a row that
+  /// already exists returns that row, so a creation can be retried without duplicating the row.
+  #[pb(index = 5, one_of)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub row_id: Option<String>,
+}
+
+/// Creates a row at an index of the rows that are visible in the view. The position of the row
+/// is ignored.
+#[derive(ProtoBuf, Default, Validate)]
+pub struct CreateRowAtIndexPayloadPB {
+  #[pb(index = 1)]
+  #[validate(nested)]
+  pub row: CreateRowPayloadPB,
+
+  #[pb(index = 2)]
+  #[validate(range(min = 0))]
+  pub index: i32,
 }
 
 #[derive(Debug, Default, Clone, ProtoBuf)]
@@ -506,3 +595,177 @@ pub struct TranslateRowPB {
   #[validate(custom(function = "required_not_empty_str"))]
   pub field_id: String,
 }
+
+#[derive(Debug, Default, Clone, Copy, ProtoBuf_Enum, PartialEq, Eq)]
+#[repr(u8)]
+pub enum RowSampleModePB {
+  #[default]
+  First = 0,
+  Last = 1,
+  Random = 2,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct GetSampleRowsPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  #[validate(range(min = 1, max = 100))]
+  pub count: i32,
+
+  #[pb(index = 3)]
+  pub mode: RowSampleModePB,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowSamplePB {
+  #[pb(index = 1)]
+  pub row: RowMetaPB,
+
+  #[pb(index = 2)]
+  pub cells: Vec<CellPB>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedRowSamplePB {
+  #[pb(index = 1)]
+  pub items: Vec<RowSamplePB>,
+
+  /// The number of rows in the view, so the preview can tell how many rows are not shown.
+  #[pb(index = 2)]
+  pub total_count: i64,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct SearchCellsPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub query: String,
+
+  #[pb(index = 3)]
+  #[validate(range(min = 0))]
+  pub offset: i32,
+
+  #[pb(index = 4)]
+  #[validate(range(min = 1, max = 100))]
+  pub limit: i32,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct CellSearchHitPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  /// The part of the cell's display string around the first match of the query.
+  #[pb(index = 3)]
+  pub snippet: String,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedCellSearchHitPB {
+  #[pb(index = 1)]
+  pub items: Vec<CellSearchHitPB>,
+
+  /// The number of hits in the view, including the ones outside of the requested page.
+  #[pb(index = 2)]
+  pub total_count: i64,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct ReplaceCellsPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub query: String,
+
+  #[pb(index = 3)]
+  pub replacement: String,
+
+  /// The text fields to replace the query in. All the text fields of the view if it's empty.
+  #[pb(index = 4)]
+  pub field_ids: Vec<String>,
+
+  /// Counts the cells that would be changed without changing them.
+  #[pb(index = 5)]
+  pub dry_run: bool,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct ReplaceCellsResultPB {
+  #[pb(index = 1)]
+  pub num_of_changed_cells: i64,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowValidationPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  /// The required fields whose cells are empty in the row.
+  #[pb(index = 2)]
+  pub missing_field_ids: Vec<String>,
+}
+
+impl RowValidationPB {
+  pub fn is_valid(&self) -> bool {
+    self.missing_field_ids.is_empty()
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedRowValidationPB {
+  #[pb(index = 1)]
+  pub items: Vec<RowValidationPB>,
+}
+
+/// Queries the rows of a view without loading the whole view. The filters and the sorts of the
+/// view don't apply, only the ones of the query.
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct QueryRowsPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  /// The filter of the query, in the syntax of a filter expression. All the rows match if it's
+  /// empty.
+  #[pb(index = 2)]
+  pub filter_expression: String,
+
+  /// The first sort has the highest priority. The ids of the sorts are ignored.
+  #[pb(index = 3)]
+  pub sorts: Vec<SortPB>,
+
+  #[pb(index = 4)]
+  #[validate(range(min = 0))]
+  pub offset: i32,
+
+  #[pb(index = 5)]
+  #[validate(range(min = 1, max = 1000))]
+  pub limit: i32,
+
+  /// The fields whose cells are returned. All the fields of the view if it's empty.
+  #[pb(index = 6)]
+  pub field_ids: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct QueryRowsResultPB {
+  #[pb(index = 1)]
+  pub items: Vec<RowSamplePB>,
+
+  /// The number of rows that match the filter, including the ones outside of the requested page.
+  #[pb(index = 2)]
+  pub total_count: i64,
+}
@@ -0,0 +1,99 @@
+use flowy_derive::ProtoBuf;
+use lib_infra::validator_fn::required_not_empty_str;
+use validator::Validate;
+
+use crate::services::setting::{RowTemplate, RowTemplateCell};
+
+/// A cell of a row template. The value is written as text, like a value of a CSV file.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowTemplateCellPB {
+  #[pb(index = 1)]
+  pub field_id: String,
+
+  #[pb(index = 2)]
+  pub value: String,
+}
+
+/// [RowTemplatePB] prefills the cells of the rows created from it.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowTemplatePB {
+  /// A new id is generated for the template if it's empty
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub cells: Vec<RowTemplateCellPB>,
+}
+
+impl std::convert::From<&RowTemplate> for RowTemplatePB {
+  fn from(template: &RowTemplate) -> Self {
+    Self {
+      id: template.id.clone(),
+      name: template.name.clone(),
+      cells: template
+        .cells
+        .iter()
+        .map(|cell| RowTemplateCellPB {
+          field_id: cell.field_id.clone(),
+          value: cell.value.clone(),
+        })
+        .collect(),
+    }
+  }
+}
+
+impl std::convert::From<RowTemplatePB> for RowTemplate {
+  fn from(template: RowTemplatePB) -> Self {
+    Self {
+      id: template.id,
+      name: template.name,
+      cells: template
+        .cells
+        .into_iter()
+        .map(|cell| RowTemplateCell {
+          field_id: cell.field_id,
+          value: cell.value,
+        })
+        .collect(),
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedRowTemplatePB {
+  #[pb(index = 1)]
+  pub items: Vec<RowTemplatePB>,
+}
+
+impl std::convert::From<&[RowTemplate]> for RepeatedRowTemplatePB {
+  fn from(templates: &[RowTemplate]) -> Self {
+    Self {
+      items: templates.iter().map(RowTemplatePB::from).collect(),
+    }
+  }
+}
+
+/// Replaces the row templates of the database of the view.
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct UpdateRowTemplatesPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub templates: Vec<RowTemplatePB>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct CreateRowFromTemplatePayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub template_id: String,
+}
@@ -0,0 +1,21 @@
+use flowy_derive::ProtoBuf;
+
+use crate::entities::RowMetaPB;
+
+/// A row in the trash of a database, which can be restored until it's purged.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct TrashedRowPB {
+  #[pb(index = 1)]
+  pub row: RowMetaPB,
+
+  /// The time the row was deleted, in seconds
+  #[pb(index = 2)]
+  pub deleted_at: i64,
+}
+
+/// The rows in the trash of a database, in the order they were deleted.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedTrashedRowPB {
+  #[pb(index = 1)]
+  pub items: Vec<TrashedRowPB>,
+}
@@ -5,6 +5,7 @@ use strum_macros::EnumIter;
 
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
+use lib_infra::validator_fn::required_not_empty_str;
 use validator::Validate;
 
 use crate::entities::parser::NotEmptyStr;
@@ -212,3 +213,16 @@ impl TryInto<LayoutSettingChangeset> for LayoutSettingChangesetPB {
     })
   }
 }
+
+/// The filters, sorts, grouping and field visibility of a view as a JSON string. It's the output
+/// of the [ExportViewSettings] event and the input of the [ImportViewSettings] event, which
+/// applies the settings to a view that might belong to another database.
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct ViewSettingsDataPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub settings: String,
+}
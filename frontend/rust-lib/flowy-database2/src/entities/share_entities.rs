@@ -1,4 +1,10 @@
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::{ErrorCode, FlowyError};
+use lib_infra::validator_fn::required_not_empty_str;
+use validator::Validate;
+
+use crate::entities::{FieldPB, RowSamplePB};
+use crate::services::share::csv::CSVExportOptions;
 
 #[derive(Debug, ProtoBuf_Enum, Clone, Default)]
 pub enum DatabaseExportDataType {
@@ -7,6 +13,18 @@ pub enum DatabaseExportDataType {
 
   // DatabaseData
   RawDatabaseData = 1,
+
+  // The portable JSON of the fields and rows, see [GridJson](crate::services::share::json::GridJson)
+  GridJSON = 2,
+
+  // An xlsx workbook with a sheet per view
+  XLSX = 3,
+
+  // A zip file with the data, the edit history and the snapshots of the database
+  DatabaseBackup = 4,
+
+  // An iCalendar feed with an event for each row that has a date
+  ICal = 5,
 }
 
 #[derive(Debug, ProtoBuf, Default, Clone)]
@@ -17,3 +35,132 @@ pub struct DatabaseExportDataPB {
   #[pb(index = 2)]
   pub data: String,
 }
+
+/// The exported data of a binary format, e.g. an xlsx workbook.
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct DatabaseExportFilePB {
+  #[pb(index = 1)]
+  pub export_type: DatabaseExportDataType,
+
+  #[pb(index = 2)]
+  pub data: Vec<u8>,
+}
+
+/// Exports the rows of a view as CSV with the display format of each field.
+#[derive(Debug, ProtoBuf, Default, Clone, Validate)]
+pub struct ExportViewCSVPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  /// A single ASCII character. Defaults to a comma if empty.
+  #[pb(index = 2)]
+  pub delimiter: String,
+
+  #[pb(index = 3)]
+  pub include_hidden_fields: bool,
+}
+
+/// Exports the rows of a view as portable JSON, in the order of the view and with its filters
+/// applied.
+#[derive(Debug, ProtoBuf, Default, Clone, Validate)]
+pub struct ExportViewJSONPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub include_hidden_fields: bool,
+}
+
+/// Exports the fields of a view, and optionally its rows, as a template for new grids.
+#[derive(Debug, ProtoBuf, Default, Clone, Validate)]
+pub struct ExportTemplatePayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub include_rows: bool,
+}
+
+/// Exports the rows of a view that have a date as the events of an iCalendar feed.
+#[derive(Debug, ProtoBuf, Default, Clone, Validate)]
+pub struct ExportICalPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub date_field_id: String,
+
+  /// The field used as the title of the events. The primary field is used if it's empty.
+  #[pb(index = 3)]
+  pub title_field_id: String,
+}
+
+impl TryFrom<&ExportViewCSVPayloadPB> for CSVExportOptions {
+  type Error = FlowyError;
+
+  fn try_from(payload: &ExportViewCSVPayloadPB) -> Result<Self, Self::Error> {
+    let delimiter = match payload.delimiter.as_bytes() {
+      [] => b',',
+      [delimiter] if delimiter.is_ascii() => *delimiter,
+      _ => {
+        return Err(
+          FlowyError::from(ErrorCode::InvalidParams)
+            .with_context("The delimiter must be a single ASCII character"),
+        )
+      },
+    };
+    Ok(Self {
+      delimiter,
+      include_hidden_fields: payload.include_hidden_fields,
+    })
+  }
+}
+
+/// Returns the changes of the rows and fields of the database of the view after the revision.
+#[derive(Debug, ProtoBuf, Default, Clone, Validate)]
+pub struct ExportChangesPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  /// The revision returned by the previous call, or 0 for the first call.
+  #[pb(index = 2)]
+  pub rev_id: i64,
+}
+
+/// The changes since a revision, see [ChangesSince](crate::services::change_log::ChangesSince).
+/// The rows and fields are the ones of the view, as they are now.
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct DatabaseChangesPB {
+  /// The revision to pass to the next call.
+  #[pb(index = 1)]
+  pub rev_id: i64,
+
+  /// True if the changes since the revision are unknown, e.g. after a restart. The database must
+  /// be exported as a whole and the other properties are empty.
+  #[pb(index = 2)]
+  pub is_full_export_required: bool,
+
+  #[pb(index = 3)]
+  pub created_rows: Vec<RowSamplePB>,
+
+  #[pb(index = 4)]
+  pub updated_rows: Vec<RowSamplePB>,
+
+  #[pb(index = 5)]
+  pub deleted_row_ids: Vec<String>,
+
+  #[pb(index = 6)]
+  pub created_fields: Vec<FieldPB>,
+
+  #[pb(index = 7)]
+  pub updated_fields: Vec<FieldPB>,
+
+  #[pb(index = 8)]
+  pub deleted_field_ids: Vec<String>,
+}
@@ -2,7 +2,7 @@ use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use lib_infra::validator_fn::required_not_empty_str;
 use validator::Validate;
 
-use crate::services::sort::{Sort, SortCondition};
+use crate::services::sort::{Sort, SortCollation, SortCondition};
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct SortPB {
@@ -14,6 +14,9 @@ pub struct SortPB {
 
   #[pb(index = 3)]
   pub condition: SortConditionPB,
+
+  #[pb(index = 4)]
+  pub collation: SortCollationPB,
 }
 
 impl std::convert::From<&Sort> for SortPB {
@@ -22,6 +25,7 @@ impl std::convert::From<&Sort> for SortPB {
       id: sort.id.clone(),
       field_id: sort.field_id.clone(),
       condition: sort.condition.into(),
+      collation: sort.collation.into(),
     }
   }
 }
@@ -32,6 +36,7 @@ impl std::convert::From<Sort> for SortPB {
       id: sort.id,
       field_id: sort.field_id,
       condition: sort.condition.into(),
+      collation: sort.collation.into(),
     }
   }
 }
@@ -94,6 +99,35 @@ impl std::convert::From<SortConditionPB> for SortCondition {
   }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum, Default)]
+#[repr(u8)]
+pub enum SortCollationPB {
+  #[default]
+  Binary = 0,
+  CaseInsensitive = 1,
+  Natural = 2,
+}
+
+impl std::convert::From<SortCollation> for SortCollationPB {
+  fn from(collation: SortCollation) -> Self {
+    match collation {
+      SortCollation::Binary => SortCollationPB::Binary,
+      SortCollation::CaseInsensitive => SortCollationPB::CaseInsensitive,
+      SortCollation::Natural => SortCollationPB::Natural,
+    }
+  }
+}
+
+impl std::convert::From<SortCollationPB> for SortCollation {
+  fn from(collation: SortCollationPB) -> Self {
+    match collation {
+      SortCollationPB::Binary => SortCollation::Binary,
+      SortCollationPB::CaseInsensitive => SortCollation::CaseInsensitive,
+      SortCollationPB::Natural => SortCollation::Natural,
+    }
+  }
+}
+
 #[derive(ProtoBuf, Debug, Default, Clone, Validate)]
 pub struct UpdateSortPayloadPB {
   #[pb(index = 1)]
@@ -111,6 +145,9 @@ pub struct UpdateSortPayloadPB {
 
   #[pb(index = 4)]
   pub condition: SortConditionPB,
+
+  #[pb(index = 5)]
+  pub collation: SortCollationPB,
 }
 
 #[derive(Debug, Default, Clone, Validate, ProtoBuf)]
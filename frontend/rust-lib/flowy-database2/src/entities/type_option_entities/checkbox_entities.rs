@@ -1,6 +1,8 @@
 use collab_database::fields::checkbox_type_option::CheckboxTypeOption;
 use flowy_derive::ProtoBuf;
 
+use crate::services::field::CheckboxOptions;
+
 #[derive(Default, Debug, Clone, ProtoBuf)]
 pub struct CheckboxCellDataPB {
   #[pb(index = 1)]
@@ -18,11 +20,35 @@ pub struct CheckboxTypeOptionPB {
   /// unused
   #[pb(index = 1)]
   pub dummy_field: bool,
+
+  #[pb(index = 2)]
+  pub is_checked_by_default: bool,
+
+  /// The label of the checked cells, used when the cells are displayed or exported. Defaults
+  /// to `Yes` if empty.
+  #[pb(index = 3)]
+  pub checked_label: String,
+
+  /// The label of the unchecked cells. Defaults to `No` if empty.
+  #[pb(index = 4)]
+  pub unchecked_label: String,
+}
+
+impl CheckboxTypeOptionPB {
+  pub fn with_checkbox_options(mut self, options: CheckboxOptions) -> Self {
+    self.is_checked_by_default = options.is_checked_by_default;
+    self.checked_label = options.checked_label;
+    self.unchecked_label = options.unchecked_label;
+    self
+  }
 }
 
 impl From<CheckboxTypeOption> for CheckboxTypeOptionPB {
   fn from(_type_option: CheckboxTypeOption) -> Self {
-    Self { dummy_field: false }
+    Self {
+      dummy_field: false,
+      ..Default::default()
+    }
   }
 }
 
@@ -1,6 +1,8 @@
 use collab_database::fields::number_type_option::{NumberFormat, NumberTypeOption};
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 
+use crate::services::field::{NumberUnit, NumberUnitPosition};
+
 // Number
 #[derive(Clone, Debug, Default, ProtoBuf)]
 pub struct NumberTypeOptionPB {
@@ -15,6 +17,12 @@ pub struct NumberTypeOptionPB {
 
   #[pb(index = 5)]
   pub name: String,
+
+  #[pb(index = 6)]
+  pub unit: String,
+
+  #[pb(index = 7)]
+  pub unit_position: NumberUnitPositionPB,
 }
 
 impl From<NumberTypeOption> for NumberTypeOptionPB {
@@ -24,6 +32,8 @@ impl From<NumberTypeOption> for NumberTypeOptionPB {
       scale: data.scale,
       symbol: data.symbol,
       name: data.name,
+      unit: String::new(),
+      unit_position: NumberUnitPositionPB::default(),
     }
   }
 }
@@ -39,6 +49,43 @@ impl From<NumberTypeOptionPB> for NumberTypeOption {
   }
 }
 
+impl NumberTypeOptionPB {
+  pub fn number_unit(&self) -> NumberUnit {
+    NumberUnit::new(&self.unit, self.unit_position.into())
+  }
+
+  pub fn with_number_unit(mut self, number_unit: NumberUnit) -> Self {
+    self.unit = number_unit.unit;
+    self.unit_position = number_unit.position.into();
+    self
+  }
+}
+
+#[derive(Clone, Copy, Debug, ProtoBuf_Enum, Default)]
+pub enum NumberUnitPositionPB {
+  #[default]
+  Suffix = 0,
+  Prefix = 1,
+}
+
+impl From<NumberUnitPosition> for NumberUnitPositionPB {
+  fn from(data: NumberUnitPosition) -> Self {
+    match data {
+      NumberUnitPosition::Suffix => NumberUnitPositionPB::Suffix,
+      NumberUnitPosition::Prefix => NumberUnitPositionPB::Prefix,
+    }
+  }
+}
+
+impl From<NumberUnitPositionPB> for NumberUnitPosition {
+  fn from(data: NumberUnitPositionPB) -> Self {
+    match data {
+      NumberUnitPositionPB::Suffix => NumberUnitPosition::Suffix,
+      NumberUnitPositionPB::Prefix => NumberUnitPosition::Prefix,
+    }
+  }
+}
+
 #[derive(Clone, Copy, Debug, ProtoBuf_Enum, Default)]
 pub enum NumberFormatPB {
   #[default]
@@ -6,6 +6,8 @@ use collab_database::fields::select_type_option::{
 };
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
+use lib_infra::validator_fn::required_not_empty_str;
+use validator::Validate;
 
 /// [SelectOptionPB] represents an option for a single select, and multiple select.
 #[derive(Clone, Debug, Default, PartialEq, Eq, ProtoBuf)]
@@ -48,6 +50,9 @@ pub struct RepeatedSelectOptionPayload {
   #[pb(index = 2)]
   pub field_id: String,
 
+  /// The row whose cell selects the inserted options. Only used by
+  /// DatabaseEvent::InsertOrUpdateSelectOption, DatabaseEvent::DeleteSelectOption ignores it and
+  /// removes the options from every cell of the field.
   #[pb(index = 3)]
   pub row_id: String,
 
@@ -55,6 +60,172 @@ pub struct RepeatedSelectOptionPayload {
   pub items: Vec<SelectOptionPB>,
 }
 
+/// [UpdateSelectOptionPayloadPB] is used to rename or recolor an existing option of a single
+/// select or multiple select field. The fields that are `None` are left unchanged.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct UpdateSelectOptionPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub option_id: String,
+
+  #[pb(index = 4, one_of)]
+  pub name: Option<String>,
+
+  #[pb(index = 5, one_of)]
+  pub color: Option<SelectOptionColorPB>,
+}
+
+pub struct UpdateSelectOptionParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub option_id: String,
+  pub name: Option<String>,
+  pub color: Option<SelectOptionColor>,
+}
+
+impl TryInto<UpdateSelectOptionParams> for UpdateSelectOptionPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateSelectOptionParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    let option_id = NotEmptyStr::parse(self.option_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let name = match self.name {
+      None => None,
+      Some(name) => Some(
+        NotEmptyStr::parse(name)
+          .map_err(|_| ErrorCode::SelectOptionNameIsEmpty)?
+          .0,
+      ),
+    };
+    Ok(UpdateSelectOptionParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+      option_id: option_id.0,
+      name,
+      color: self.color.map(Into::into),
+    })
+  }
+}
+
+/// [MoveSelectOptionPayloadPB] is used to move an option of a single select or multiple select
+/// field to a new position. The order of the options decides the order of the board columns.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct MoveSelectOptionPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub option_id: String,
+
+  #[pb(index = 4)]
+  pub to_index: i32,
+}
+
+pub struct MoveSelectOptionParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub option_id: String,
+  pub to_index: usize,
+}
+
+impl TryInto<MoveSelectOptionParams> for MoveSelectOptionPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<MoveSelectOptionParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    let option_id = NotEmptyStr::parse(self.option_id).map_err(|_| ErrorCode::InvalidParams)?;
+    if self.to_index < 0 {
+      return Err(ErrorCode::InvalidParams);
+    }
+    Ok(MoveSelectOptionParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+      option_id: option_id.0,
+      to_index: self.to_index as usize,
+    })
+  }
+}
+
+/// [MergeSelectOptionsPayloadPB] is used to merge the option `from_option_id` into the option
+/// `to_option_id` of a single select or multiple select field.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct MergeSelectOptionsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub from_option_id: String,
+
+  #[pb(index = 4)]
+  pub to_option_id: String,
+}
+
+pub struct MergeSelectOptionsParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub from_option_id: String,
+  pub to_option_id: String,
+}
+
+impl TryInto<MergeSelectOptionsParams> for MergeSelectOptionsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<MergeSelectOptionsParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    let from_option_id =
+      NotEmptyStr::parse(self.from_option_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let to_option_id =
+      NotEmptyStr::parse(self.to_option_id).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(MergeSelectOptionsParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+      from_option_id: from_option_id.0,
+      to_option_id: to_option_id.0,
+    })
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf, Validate)]
+pub struct SelectOptionUsagePayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub field_id: String,
+}
+
+/// The number of cells that reference the option.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct SelectOptionUsagePB {
+  #[pb(index = 1)]
+  pub option: SelectOptionPB,
+
+  #[pb(index = 2)]
+  pub count: i64,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RepeatedSelectOptionUsagePB {
+  #[pb(index = 1)]
+  pub items: Vec<SelectOptionUsagePB>,
+}
+
 #[derive(ProtoBuf_Enum, PartialEq, Eq, Debug, Clone, Default)]
 #[repr(u8)]
 pub enum SelectOptionColorPB {
@@ -1,16 +1,41 @@
 use collab_database::fields::text_type_option::RichTextTypeOption;
 use flowy_derive::ProtoBuf;
+use validator::Validate;
+
+use crate::entities::CellIdPB;
+use crate::services::field::TextValidation;
 
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct RichTextTypeOptionPB {
   #[pb(index = 1)]
   data: String,
+
+  /// The regular expression the text of a cell must match. Empty means no validation.
+  #[pb(index = 2)]
+  pub validation_pattern: String,
+
+  /// The message of the error returned when a text doesn't match the pattern.
+  #[pb(index = 3)]
+  pub validation_error_message: String,
+}
+
+impl RichTextTypeOptionPB {
+  pub fn text_validation(&self) -> TextValidation {
+    TextValidation::new(&self.validation_pattern, &self.validation_error_message)
+  }
+
+  pub fn with_text_validation(mut self, validation: TextValidation) -> Self {
+    self.validation_pattern = validation.pattern;
+    self.validation_error_message = validation.error_message;
+    self
+  }
 }
 
 impl From<RichTextTypeOption> for RichTextTypeOptionPB {
   fn from(_data: RichTextTypeOption) -> Self {
     RichTextTypeOptionPB {
       data: "".to_string(),
+      ..Default::default()
     }
   }
 }
@@ -20,3 +45,35 @@ impl From<RichTextTypeOptionPB> for RichTextTypeOption {
     RichTextTypeOption
   }
 }
+
+/// A partial edit of a text cell. The ops are applied to the content of the cell in order, and
+/// the content after the last op is kept.
+#[derive(Debug, Clone, Default, ProtoBuf, Validate)]
+pub struct TextCellDeltaChangesetPB {
+  #[pb(index = 1)]
+  #[validate(nested)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2)]
+  pub ops: Vec<TextDeltaOpPB>,
+
+  /// The content of the cell the ops were made against. If the cell changed since, the ops are
+  /// transformed against the change. If it's not set, the ops are applied to the current
+  /// content as they are.
+  #[pb(index = 3, one_of)]
+  pub base_content: Option<String>,
+}
+
+/// Exactly one of the values is expected to be set. The lengths are measured in UTF-16 code
+/// units.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct TextDeltaOpPB {
+  #[pb(index = 1, one_of)]
+  pub retain: Option<i64>,
+
+  #[pb(index = 2, one_of)]
+  pub insert: Option<String>,
+
+  #[pb(index = 3, one_of)]
+  pub delete: Option<i64>,
+}
@@ -5,6 +5,14 @@ use flowy_derive::ProtoBuf;
 pub struct URLCellDataPB {
   #[pb(index = 1)]
   pub content: String,
+
+  /// The title of the linked web page. Empty until the link preview is fetched.
+  #[pb(index = 2)]
+  pub title: String,
+
+  /// The url of the favicon of the linked web page.
+  #[pb(index = 3)]
+  pub favicon: String,
 }
 
 #[derive(Debug, Clone, Default, ProtoBuf)]
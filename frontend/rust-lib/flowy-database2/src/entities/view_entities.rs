@@ -17,6 +17,25 @@ pub struct RowsVisibilityChangePB {
   pub invisible_rows: Vec<String>,
 }
 
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct ViewRowCountPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub visible_count: i64,
+
+  #[pb(index = 3)]
+  pub total_count: i64,
+}
+
+/// The number of rows of a database, including the rows hidden by the filters of its views.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct DatabaseRowCountPB {
+  #[pb(index = 1)]
+  pub count: i64,
+}
+
 #[derive(Debug, Default, Clone, ProtoBuf)]
 pub struct RowsChangePB {
   #[pb(index = 1)]
@@ -2,6 +2,7 @@ use collab_database::fields::media_type_option::MediaCellData;
 use collab_database::rows::{Cell, CoverType, RowCover, RowId};
 use lib_infra::box_any::BoxAny;
 use std::sync::{Arc, Weak};
+use std::time::Duration;
 use tokio::sync::oneshot;
 use tracing::{info, instrument};
 
@@ -10,12 +11,16 @@ use lib_dispatch::prelude::{af_spawn, data_result_ok, AFPluginData, AFPluginStat
 
 use crate::entities::*;
 use crate::manager::DatabaseManager;
+use crate::services::cell::TypedCellChangeset;
+use crate::services::database::EditingCell;
 use crate::services::field::{
   type_option_data_from_pb, ChecklistCellChangeset, DateCellChangeset, RelationCellChangeset,
-  SelectOptionCellChangeset, TypeOptionCellExt,
+  SelectOptionCellChangeset, TextDelta, TypeOptionCellExt,
 };
 use crate::services::group::GroupChangeset;
-use crate::services::share::csv::CSVFormat;
+use crate::services::mirror::PostgresMirrorConnector;
+use crate::services::setting::RowTemplate;
+use crate::services::share::csv::{CSVExportOptions, CSVFormat};
 
 fn upgrade_manager(
   database_manager: AFPluginState<Weak<DatabaseManager>>,
@@ -64,11 +69,7 @@ pub(crate) async fn get_all_rows_handler(
     .await?;
   let database_editor = manager.get_or_init_database_editor(&database_id).await?;
   let row_details = database_editor.get_all_rows(view_id.as_ref()).await?;
-  let rows = row_details
-    .into_iter()
-    .map(|detail| RowMetaPB::from(detail.as_ref()))
-    .collect::<Vec<RowMetaPB>>();
-  data_result_ok(RepeatedRowMetaPB { items: rows })
+  data_result_ok(RepeatedRowMetaPB::from(row_details.as_slice()))
 }
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub(crate) async fn open_database_handler(
@@ -206,7 +207,7 @@ pub(crate) async fn delete_all_sorts_handler(
   let database_editor = manager
     .get_database_editor_with_view_id(view_id.as_ref())
     .await?;
-  database_editor.delete_all_sorts(view_id.as_ref()).await;
+  database_editor.delete_all_sorts(view_id.as_ref()).await?;
   Ok(())
 }
 
@@ -222,12 +223,8 @@ pub(crate) async fn get_fields_handler(
     .await?;
   let fields = database_editor
     .get_fields(&params.view_id, params.field_ids)
-    .await
-    .into_iter()
-    .map(FieldPB::new)
-    .collect::<Vec<FieldPB>>()
-    .into();
-  data_result_ok(fields)
+    .await;
+  data_result_ok(RepeatedFieldPB::from(fields))
 }
 
 #[tracing::instrument(level = "trace", skip(data, manager), err)]
@@ -411,6 +408,290 @@ pub(crate) async fn get_row_handler(
   data_result_ok(OptionalRowPB { row })
 }
 
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn explain_query_handler(
+  data: AFPluginData<DatabaseViewRowIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<QueryExplanationPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: RowIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let explanation = database_editor
+    .explain_query(&params.view_id, &params.row_id)
+    .await?;
+  data_result_ok(explanation)
+}
+
+pub(crate) async fn export_view_settings_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<ViewSettingsDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let database_editor = manager
+    .get_database_editor_with_view_id(view_id.as_ref())
+    .await?;
+  let settings = database_editor
+    .export_view_settings(view_id.as_ref())
+    .await?;
+  data_result_ok(ViewSettingsDataPB {
+    view_id: view_id.value,
+    settings,
+  })
+}
+
+pub(crate) async fn import_view_settings_handler(
+  data: AFPluginData<ViewSettingsDataPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .import_view_settings(&params.view_id, &params.settings)
+    .await?;
+  Ok(())
+}
+
+pub(crate) async fn get_sample_rows_handler(
+  data: AFPluginData<GetSampleRowsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedRowSamplePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let rows = database_editor
+    .get_sample_rows(&params.view_id, params.count as usize, params.mode)
+    .await?;
+  data_result_ok(rows)
+}
+
+pub(crate) async fn search_database_cells_handler(
+  data: AFPluginData<SearchCellsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedCellSearchHitPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let hits = database_editor
+    .search_cells(
+      &params.view_id,
+      &params.query,
+      params.offset as usize,
+      params.limit as usize,
+    )
+    .await?;
+  data_result_ok(hits)
+}
+
+pub(crate) async fn replace_database_cells_handler(
+  data: AFPluginData<ReplaceCellsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<ReplaceCellsResultPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let num_of_changed_cells = database_editor
+    .replace_all(
+      &params.view_id,
+      &params.query,
+      &params.replacement,
+      params.field_ids,
+      params.dry_run,
+    )
+    .await?;
+  data_result_ok(ReplaceCellsResultPB {
+    num_of_changed_cells: num_of_changed_cells as i64,
+  })
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_row_color_rules_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedRowColorRulePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.try_into_inner()?.value;
+  let database_editor = manager.get_database_editor_with_view_id(&view_id).await?;
+  let rules = database_editor.get_row_color_rules(&view_id).await?;
+  data_result_ok(rules)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_row_color_rules_handler(
+  data: AFPluginData<UpdateRowColorRulesPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor.update_row_color_rules(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_row_colors_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedRowColorPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.try_into_inner()?.value;
+  let database_editor = manager.get_database_editor_with_view_id(&view_id).await?;
+  let colors = database_editor.get_row_colors(&view_id).await?;
+  data_result_ok(colors)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_automation_rules_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedAutomationRulePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.try_into_inner()?.value;
+  let database_editor = manager.get_database_editor_with_view_id(&view_id).await?;
+  data_result_ok(database_editor.get_automation_rules().await)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_automation_rules_handler(
+  data: AFPluginData<UpdateAutomationRulesPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor.update_automation_rules(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_row_templates_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedRowTemplatePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.try_into_inner()?.value;
+  let database_editor = manager.get_database_editor_with_view_id(&view_id).await?;
+  let templates = database_editor.get_row_templates().await;
+  data_result_ok(RepeatedRowTemplatePB::from(templates.as_slice()))
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_row_templates_handler(
+  data: AFPluginData<UpdateRowTemplatesPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let templates = params
+    .templates
+    .into_iter()
+    .map(RowTemplate::from)
+    .collect();
+  database_editor.update_row_templates(templates).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn create_row_from_template_handler(
+  data: AFPluginData<CreateRowFromTemplatePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RowMetaPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let row_detail = database_editor
+    .create_row_from_template(&params.view_id, &params.template_id)
+    .await?;
+  data_result_ok(RowMetaPB::from(row_detail))
+}
+
+pub(crate) async fn query_rows_handler(
+  data: AFPluginData<QueryRowsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<QueryRowsResultPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let result = database_editor.query_rows(params).await?;
+  data_result_ok(result)
+}
+
+pub(crate) async fn parse_filter_expression_handler(
+  data: AFPluginData<FilterExpressionPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<FilterExpressionResultPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let result = database_editor
+    .parse_filter_expression(&params.view_id, &params.expression)
+    .await;
+  data_result_ok(FilterExpressionResultPB::from(result))
+}
+
+pub(crate) async fn get_database_diagnostics_handler(
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedDatabaseEditorDiagnosticsPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let items = manager
+    .diagnostics()
+    .await
+    .into_iter()
+    .map(DatabaseEditorDiagnosticsPB::from)
+    .collect();
+  data_result_ok(RepeatedDatabaseEditorDiagnosticsPB { items })
+}
+
+pub(crate) async fn validate_row_handler(
+  data: AFPluginData<DatabaseViewRowIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RowValidationPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: RowIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let validation = database_editor
+    .validate_row(&params.view_id, &params.row_id)
+    .await?;
+  data_result_ok(validation)
+}
+
+pub(crate) async fn get_invalid_rows_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedRowValidationPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let database_editor = manager.get_database_editor_with_view_id(&view_id).await?;
+  let items = database_editor.get_invalid_rows(&view_id).await?;
+  data_result_ok(RepeatedRowValidationPB { items })
+}
+
 pub(crate) async fn init_row_handler(
   data: AFPluginData<DatabaseViewRowIdPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
@@ -454,7 +735,7 @@ pub(crate) async fn update_row_meta_handler(
   let row_id = RowId::from(params.id.clone());
   database_editor
     .update_row_meta(&row_id.clone(), params)
-    .await;
+    .await?;
   Ok(())
 }
 
@@ -473,7 +754,7 @@ pub(crate) async fn delete_rows_handler(
     .into_iter()
     .map(RowId::from)
     .collect::<Vec<_>>();
-  database_editor.delete_rows(&row_ids).await;
+  database_editor.delete_rows(&row_ids).await?;
   Ok(())
 }
 
@@ -509,6 +790,22 @@ pub(crate) async fn move_row_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn move_row_to_index_handler(
+  data: AFPluginData<MoveRowToIndexPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: MoveRowToIndexParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .move_row_to_index(&params.view_id, params.row_id, params.to_index)
+    .await?;
+  Ok(())
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn remove_cover_handler(
   data: AFPluginData<RemoveCoverPayloadPB>,
@@ -529,7 +826,7 @@ pub(crate) async fn remove_cover_handler(
 
   database_editor
     .update_row_meta(&params.row_id, update_row_changeset)
-    .await;
+    .await?;
 
   Ok(())
 }
@@ -551,106 +848,514 @@ pub(crate) async fn create_row_handler(
   }
 }
 
-// #[tracing::instrument(level = "trace", skip_all, err)]
-pub(crate) async fn get_cell_handler(
-  data: AFPluginData<CellIdPB>,
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn delete_all_rows_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> DataResult<CellPB, FlowyError> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
-  let params: CellIdParams = data.into_inner().try_into()?;
+  let view_id: DatabaseViewIdPB = data.into_inner();
   let database_editor = manager
-    .get_database_editor_with_view_id(&params.view_id)
+    .get_database_editor_with_view_id(view_id.as_ref())
     .await?;
-  let cell = database_editor
-    .get_cell_pb(&params.field_id, &params.row_id)
-    .await
-    .unwrap_or_else(|| CellPB::empty(&params.field_id, params.row_id.into_inner()));
-  data_result_ok(cell)
+  database_editor.delete_all_rows().await?;
+  Ok(())
 }
 
-#[tracing::instrument(level = "debug", skip_all, err)]
-pub(crate) async fn update_cell_handler(
-  data: AFPluginData<CellChangesetPB>,
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_trashed_rows_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> Result<(), FlowyError> {
+) -> DataResult<RepeatedTrashedRowPB, FlowyError> {
   let manager = upgrade_manager(manager)?;
-  let params: CellChangesetPB = data.into_inner();
+  let view_id: DatabaseViewIdPB = data.into_inner();
   let database_editor = manager
-    .get_database_editor_with_view_id(&params.view_id)
-    .await?;
-  database_editor
-    .update_cell_with_changeset(
-      &params.view_id,
-      &RowId::from(params.row_id),
-      &params.field_id,
-      BoxAny::new(params.cell_changeset),
-    )
+    .get_database_editor_with_view_id(view_id.as_ref())
     .await?;
-  Ok(())
+  data_result_ok(database_editor.get_trashed_row_pbs().await)
 }
 
-#[tracing::instrument(level = "trace", skip_all, err)]
-pub(crate) async fn new_select_option_handler(
-  data: AFPluginData<CreateSelectOptionPayloadPB>,
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn restore_rows_handler(
+  data: AFPluginData<RepeatedRowIdPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> DataResult<SelectOptionPB, FlowyError> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
-  let params: CreateSelectOptionParams = data.into_inner().try_into()?;
+  let params: RepeatedRowIdPB = data.into_inner();
   let database_editor = manager
     .get_database_editor_with_view_id(&params.view_id)
     .await?;
-  let result = database_editor
-    .create_select_option(&params.field_id, params.option_name)
-    .await;
-  match result {
-    None => Err(
-      FlowyError::record_not_found()
-        .with_context("Create select option fail. Can't find the field"),
-    ),
-    Some(pb) => data_result_ok(pb),
-  }
+  let row_ids = params
+    .row_ids
+    .into_iter()
+    .map(RowId::from)
+    .collect::<Vec<_>>();
+  database_editor.restore_rows(&row_ids).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn purge_row_trash_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let database_editor = manager
+    .get_database_editor_with_view_id(view_id.as_ref())
+    .await?;
+  database_editor.purge_trash().await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_row_count_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseRowCountPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let database_editor = manager
+    .get_database_editor_with_view_id(view_id.as_ref())
+    .await?;
+  data_result_ok(DatabaseRowCountPB {
+    count: database_editor.row_count() as i64,
+  })
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_row_lock_handler(
+  data: AFPluginData<UpdateRowLockPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .set_row_locked(&RowId::from(params.row_id), params.locked)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn compact_database_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let database_editor = manager
+    .get_database_editor_with_view_id(view_id.as_ref())
+    .await?;
+  database_editor.compact().await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn open_row_document_handler(
+  data: AFPluginData<DatabaseViewRowIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RowMetaPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: RowIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .open_row_document(&params.view_id, &params.row_id)
+    .await?;
+  match database_editor
+    .get_row_meta(&params.view_id, &params.row_id)
+    .await
+  {
+    None => Err(FlowyError::record_not_found()),
+    Some(row_meta) => data_result_ok(row_meta),
+  }
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_row_comments_handler(
+  data: AFPluginData<DatabaseViewRowIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedRowCommentPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: RowIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let comments = database_editor.get_comments(&params.row_id).await?;
+  data_result_ok(RepeatedRowCommentPB::from(comments))
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn add_row_comment_handler(
+  data: AFPluginData<AddRowCommentPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RowCommentPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let comment = database_editor
+    .add_comment(&RowId::from(params.row_id), params.author_name, params.text)
+    .await?;
+  data_result_ok(RowCommentPB::from(comment))
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn delete_row_comment_handler(
+  data: AFPluginData<DeleteRowCommentPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .delete_comment(&RowId::from(params.row_id), &params.comment_id)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_row_page_handler(
+  data: AFPluginData<RowPagePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RowPagePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let (rows, total_count) = database_editor
+    .get_rows_paged(
+      &params.view_id,
+      params.offset as usize,
+      params.limit as usize,
+    )
+    .await?;
+  data_result_ok(RowPagePB {
+    items: rows
+      .iter()
+      .map(|row| RowMetaPB::from(row.as_ref()))
+      .collect(),
+    total_count: total_count as i64,
+  })
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn create_row_at_index_handler(
+  data: AFPluginData<CreateRowAtIndexPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RowMetaPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.row.view_id)
+    .await?;
+
+  match database_editor
+    .create_row_at_index(params.row, params.index as usize)
+    .await?
+  {
+    Some(row) => data_result_ok(RowMetaPB::from(row)),
+    None => Err(FlowyError::internal().with_context("Error creating row")),
+  }
+}
+
+// #[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_cell_handler(
+  data: AFPluginData<CellIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<CellPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: CellIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let cell = database_editor
+    .get_cell_pb(&params.field_id, &params.row_id, false)
+    .await
+    .unwrap_or_else(|| CellPB::empty(&params.field_id, params.row_id.into_inner()));
+  data_result_ok(cell)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn reveal_cell_handler(
+  data: AFPluginData<CellIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<CellPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: CellIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let cell = database_editor
+    .get_cell_pb(&params.field_id, &params.row_id, true)
+    .await
+    .unwrap_or_else(|| CellPB::empty(&params.field_id, params.row_id.into_inner()));
+  data_result_ok(cell)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn update_cells_handler(
+  data: AFPluginData<BatchCellChangesetPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .update_cells(&params.view_id, params.changesets)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn update_cell_handler(
+  data: AFPluginData<CellChangesetPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: CellChangesetPB = data.into_inner();
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let row_id = RowId::from(params.row_id);
+  database_editor
+    .update_cell_with_changeset(
+      &params.view_id,
+      &row_id,
+      &params.field_id,
+      BoxAny::new(params.cell_changeset),
+    )
+    .await?;
+
+  let is_url_field = database_editor
+    .get_field(&params.field_id)
+    .await
+    .map(|field| FieldType::from(field.field_type).is_url())
+    .unwrap_or(false);
+  if is_url_field {
+    manager
+      .fetch_link_preview(params.view_id, row_id, params.field_id)
+      .await?;
+  }
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_typed_cell_handler(
+  data: AFPluginData<TypedCellChangesetPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let cell_id = params.cell_id.clone();
+  let changeset = TypedCellChangeset::try_from(params)?;
+  let is_url = matches!(changeset, TypedCellChangeset::URL(_));
+  let database_editor = manager
+    .get_database_editor_with_view_id(&cell_id.view_id)
+    .await?;
+  let row_id = RowId::from(cell_id.row_id);
+  database_editor
+    .update_cell_with_typed_changeset(&cell_id.view_id, &row_id, &cell_id.field_id, changeset)
+    .await?;
+
+  if is_url {
+    manager
+      .fetch_link_preview(cell_id.view_id, row_id, cell_id.field_id)
+      .await?;
+  }
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn copy_cells_handler(
+  data: AFPluginData<CopyCellsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<ClipboardDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let row_ids = params
+    .row_ids
+    .into_iter()
+    .map(RowId::from)
+    .collect::<Vec<_>>();
+  let data = database_editor
+    .serialize_cells(&params.view_id, &row_ids, &params.field_ids, false)
+    .await?;
+  data_result_ok(ClipboardDataPB::from(data))
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn paste_cells_handler(
+  data: AFPluginData<PasteCellsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<PasteCellsResultPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let anchor = params.anchor;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&anchor.view_id)
+    .await?;
+  let result = database_editor
+    .paste_cells(
+      &anchor.view_id,
+      &RowId::from(anchor.row_id),
+      &anchor.field_id,
+      params.data.into(),
+    )
+    .await?;
+  data_result_ok(PasteCellsResultPB::from(result))
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn paste_rows_handler(
+  data: AFPluginData<PasteRowsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<PasteCellsResultPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let result = database_editor
+    .paste_rows(&params.view_id, params.data.into())
+    .await?;
+  data_result_ok(PasteCellsResultPB::from(result))
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn new_select_option_handler(
+  data: AFPluginData<CreateSelectOptionPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<SelectOptionPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: CreateSelectOptionParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let result = database_editor
+    .create_select_option(&params.field_id, params.option_name)
+    .await;
+  match result {
+    None => Err(
+      FlowyError::record_not_found()
+        .with_context("Create select option fail. Can't find the field"),
+    ),
+    Some(pb) => data_result_ok(pb),
+  }
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn insert_or_update_select_option_handler(
+  data: AFPluginData<RepeatedSelectOptionPayload>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.into_inner();
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .insert_select_options(
+      &params.view_id,
+      &params.field_id,
+      RowId::from(params.row_id),
+      params.items,
+    )
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn delete_select_option_handler(
+  data: AFPluginData<RepeatedSelectOptionPayload>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.into_inner();
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .delete_select_options(&params.view_id, &params.field_id, params.items)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_select_option_handler(
+  data: AFPluginData<UpdateSelectOptionPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: UpdateSelectOptionParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor.update_select_option(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn move_select_option_handler(
+  data: AFPluginData<MoveSelectOptionPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: MoveSelectOptionParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor.move_select_option(params).await?;
+  Ok(())
 }
 
 #[tracing::instrument(level = "trace", skip_all, err)]
-pub(crate) async fn insert_or_update_select_option_handler(
-  data: AFPluginData<RepeatedSelectOptionPayload>,
+pub(crate) async fn merge_select_options_handler(
+  data: AFPluginData<MergeSelectOptionsPayloadPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
 ) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
-  let params = data.into_inner();
+  let params: MergeSelectOptionsParams = data.into_inner().try_into()?;
   let database_editor = manager
     .get_database_editor_with_view_id(&params.view_id)
     .await?;
   database_editor
-    .insert_select_options(
+    .merge_select_options(
       &params.view_id,
       &params.field_id,
-      RowId::from(params.row_id),
-      params.items,
+      &params.from_option_id,
+      &params.to_option_id,
     )
     .await?;
   Ok(())
 }
 
-#[tracing::instrument(level = "trace", skip_all, err)]
-pub(crate) async fn delete_select_option_handler(
-  data: AFPluginData<RepeatedSelectOptionPayload>,
+pub(crate) async fn get_select_option_usage_handler(
+  data: AFPluginData<SelectOptionUsagePayloadPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> Result<(), FlowyError> {
+) -> DataResult<RepeatedSelectOptionUsagePB, FlowyError> {
   let manager = upgrade_manager(manager)?;
-  let params = data.into_inner();
+  let params = data.try_into_inner()?;
   let database_editor = manager
     .get_database_editor_with_view_id(&params.view_id)
     .await?;
-  database_editor
-    .delete_select_options(
-      &params.view_id,
-      &params.field_id,
-      RowId::from(params.row_id),
-      params.items,
-    )
+  let items = database_editor
+    .get_select_option_usage(&params.field_id)
     .await?;
-  Ok(())
+  data_result_ok(RepeatedSelectOptionUsagePB { items })
 }
 
 #[tracing::instrument(level = "trace", skip_all, err)]
@@ -702,6 +1407,47 @@ pub(crate) async fn update_checklist_cell_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_text_cell_with_delta_handler(
+  data: AFPluginData<TextCellDeltaChangesetPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.cell_id.view_id)
+    .await?;
+
+  let cell_id = params.cell_id.clone();
+  database_editor
+    .update_cell_with_changeset(
+      &cell_id.view_id,
+      &(RowId::from(cell_id.row_id)),
+      &cell_id.field_id,
+      BoxAny::new(TextDelta::from(params)),
+    )
+    .await?;
+  Ok(())
+}
+
+pub(crate) async fn set_cell_editing_state_handler(
+  data: AFPluginData<CellEditingPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.cell_id.view_id)
+    .await?;
+  let cell = params.is_editing.then(|| EditingCell {
+    view_id: params.cell_id.view_id,
+    row_id: params.cell_id.row_id,
+    field_id: params.cell_id.field_id,
+  });
+  database_editor.set_cell_editing_state(cell).await?;
+  Ok(())
+}
+
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub(crate) async fn update_date_cell_handler(
   data: AFPluginData<DateCellChangesetPB>,
@@ -795,6 +1541,21 @@ pub(crate) async fn update_group_handler(
   Ok(())
 }
 
+pub(crate) async fn update_group_aggregate_handler(
+  data: AFPluginData<UpdateGroupAggregatePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .update_group_aggregate_field(&params.view_id, params.field_id)
+    .await?;
+  Ok(())
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn move_group_handler(
   data: AFPluginData<MoveGroupPayloadPB>,
@@ -940,9 +1701,15 @@ pub(crate) async fn get_calendar_events_handler(
   let database_editor = manager
     .get_database_editor_with_view_id(&params.view_id)
     .await?;
-  let events = database_editor
-    .get_all_calendar_events(&params.view_id)
-    .await;
+  let events = if params.start.is_none() && params.end.is_none() {
+    database_editor
+      .get_all_calendar_events(&params.view_id)
+      .await
+  } else {
+    database_editor
+      .get_calendar_events_in_range(&params.view_id, params.start, params.end)
+      .await
+  };
   data_result_ok(RepeatedCalendarEventPB { items: events })
 }
 
@@ -956,10 +1723,10 @@ pub(crate) async fn get_no_date_calendar_events_handler(
   let database_editor = manager
     .get_database_editor_with_view_id(&params.view_id)
     .await?;
-  let _events = database_editor
+  let events = database_editor
     .get_all_no_date_calendar_events(&params.view_id)
-    .await;
-  todo!()
+    .await?;
+  data_result_ok(RepeatedNoDateCalendarEventPB { items: events })
 }
 
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
@@ -1016,6 +1783,58 @@ pub(crate) async fn create_database_view(
   Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_database_views_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedDatabaseViewMetaPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let database_editor = manager.get_database_editor_with_view_id(&view_id).await?;
+  let items = database_editor.get_database_views().await;
+  data_result_ok(RepeatedDatabaseViewMetaPB { items })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn duplicate_database_view_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseViewMetaPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let database_editor = manager.get_database_editor_with_view_id(&view_id).await?;
+  let view = database_editor.duplicate_view(&view_id).await?;
+  data_result_ok(view)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn set_default_database_view_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let database_editor = manager.get_database_editor_with_view_id(&view_id).await?;
+  database_editor.set_default_view(&view_id).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn move_database_view_handler(
+  data: AFPluginData<MoveDatabaseViewPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .move_view(&params.view_id, params.to_index.max(0) as usize)
+    .await?;
+  Ok(())
+}
+
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub(crate) async fn export_csv_handler(
   data: AFPluginData<DatabaseViewIdPB>,
@@ -1024,7 +1843,22 @@ pub(crate) async fn export_csv_handler(
   let manager = upgrade_manager(manager)?;
   let view_id = data.into_inner().value;
   let database = manager.get_database_editor_with_view_id(&view_id).await?;
-  let data = database.export_csv(CSVFormat::Original).await?;
+  let data = database.export_csv(CSVFormat::Original, false).await?;
+  data_result_ok(DatabaseExportDataPB {
+    export_type: DatabaseExportDataType::CSV,
+    data,
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_view_csv_handler(
+  data: AFPluginData<ExportViewCSVPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let options = CSVExportOptions::try_from(&params)?;
+  let data = manager.export_view_csv(&params.view_id, options).await?;
   data_result_ok(DatabaseExportDataPB {
     export_type: DatabaseExportDataType::CSV,
     data,
@@ -1045,6 +1879,157 @@ pub(crate) async fn export_raw_database_data_handler(
   })
 }
 
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_grid_json_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let data = manager.export_grid_json(&view_id, false).await?;
+  data_result_ok(DatabaseExportDataPB {
+    export_type: DatabaseExportDataType::GridJSON,
+    data,
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_view_json_handler(
+  data: AFPluginData<ExportViewJSONPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let data = manager
+    .export_view_json(&params.view_id, params.include_hidden_fields, false)
+    .await?;
+  data_result_ok(DatabaseExportDataPB {
+    export_type: DatabaseExportDataType::GridJSON,
+    data,
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_changes_since_handler(
+  data: AFPluginData<ExportChangesPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseChangesPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let changes = manager
+    .export_changes_since(&params.view_id, params.rev_id)
+    .await?;
+  data_result_ok(changes)
+}
+
+pub(crate) async fn backup_database_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportFilePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let data = manager.backup_database(&view_id).await?;
+  data_result_ok(DatabaseExportFilePB {
+    export_type: DatabaseExportDataType::DatabaseBackup,
+    data,
+  })
+}
+
+pub(crate) async fn export_template_handler(
+  data: AFPluginData<ExportTemplatePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let data = manager
+    .export_template(&params.view_id, params.include_rows)
+    .await?;
+  data_result_ok(DatabaseExportDataPB {
+    export_type: DatabaseExportDataType::GridJSON,
+    data,
+  })
+}
+
+pub(crate) async fn export_ical_handler(
+  data: AFPluginData<ExportICalPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let data = manager
+    .export_ical(
+      &params.view_id,
+      &params.date_field_id,
+      &params.title_field_id,
+    )
+    .await?;
+  data_result_ok(DatabaseExportDataPB {
+    export_type: DatabaseExportDataType::ICal,
+    data,
+  })
+}
+
+pub(crate) async fn start_postgres_mirror_handler(
+  data: AFPluginData<PostgresMirrorPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let view_id = params.view_id.clone();
+  let interval = Duration::from_secs(params.interval_secs as u64);
+  let connector = PostgresMirrorConnector::new(params.into())?;
+  manager
+    .start_mirror(&view_id, Arc::new(connector), interval)
+    .await
+}
+
+pub(crate) async fn stop_database_mirror_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  manager.stop_mirror(&view_id).await
+}
+
+pub(crate) async fn start_local_server_handler(
+  data: AFPluginData<StartLocalServerPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<LocalServerPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let server = manager.start_local_server(params.port as u16).await?;
+  data_result_ok(server)
+}
+
+pub(crate) async fn stop_local_server_handler(
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  manager.stop_local_server().await;
+  Ok(())
+}
+
+pub(crate) async fn get_local_server_handler(
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<LocalServerPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  data_result_ok(manager.get_local_server().await)
+}
+
+pub(crate) async fn export_xlsx_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportFilePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let data = manager.export_xlsx(&view_id).await?;
+  data_result_ok(DatabaseExportFilePB {
+    export_type: DatabaseExportDataType::XLSX,
+    data,
+  })
+}
+
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub(crate) async fn get_snapshots_handler(
   data: AFPluginData<DatabaseViewIdPB>,
@@ -1056,6 +2041,62 @@ pub(crate) async fn get_snapshots_handler(
   data_result_ok(RepeatedDatabaseSnapshotPB { items: snapshots })
 }
 
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn create_snapshot_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  manager.create_database_snapshot(&view_id).await?;
+  Ok(())
+}
+
+pub(crate) async fn get_snapshot_policy_handler(
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseSnapshotPolicyPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  data_result_ok(manager.get_database_snapshot_policy().into())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_snapshot_policy_handler(
+  data: AFPluginData<DatabaseSnapshotPolicyPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  manager.set_database_snapshot_policy(data.into_inner().into())?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn diff_database_handler(
+  data: AFPluginData<DiffDatabasePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseDiffPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.try_into_inner()?;
+  let diff = manager
+    .diff_database(
+      &params.view_id,
+      &params.before_snapshot_id,
+      params.after_snapshot_id.as_deref(),
+    )
+    .await?;
+  data_result_ok(DatabaseDiffPB::from(diff))
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn repair_database_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  manager.repair_database(&view_id).await?;
+  Ok(())
+}
+
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub(crate) async fn get_field_settings_handler(
   data: AFPluginData<FieldIdsPB>,
@@ -1344,7 +2385,7 @@ pub(crate) async fn update_media_cell_handler(
 
       database_editor
         .update_row_meta(&cell_id.row_id, update_row_meta)
-        .await;
+        .await?;
     }
   }
 
@@ -34,24 +34,49 @@ pub fn init(database_manager: Weak<DatabaseManager>) -> AFPlugin {
          .event(DatabaseEvent::CreateField, create_field_handler)
          // Row
          .event(DatabaseEvent::CreateRow, create_row_handler)
+         .event(DatabaseEvent::CreateRowAtIndex, create_row_at_index_handler)
+         .event(DatabaseEvent::GetRowPage, get_row_page_handler)
+         .event(DatabaseEvent::GetRowComments, get_row_comments_handler)
+         .event(DatabaseEvent::AddRowComment, add_row_comment_handler)
+         .event(DatabaseEvent::DeleteRowComment, delete_row_comment_handler)
+         .event(DatabaseEvent::OpenRowDocument, open_row_document_handler)
          .event(DatabaseEvent::GetRow, get_row_handler)
          .event(DatabaseEvent::InitRow, init_row_handler)
          .event(DatabaseEvent::GetRowMeta, get_row_meta_handler)
          .event(DatabaseEvent::UpdateRowMeta, update_row_meta_handler)
          .event(DatabaseEvent::DeleteRows, delete_rows_handler)
+         .event(DatabaseEvent::DeleteAllRows, delete_all_rows_handler)
+         .event(DatabaseEvent::GetTrashedRows, get_trashed_rows_handler)
+         .event(DatabaseEvent::RestoreRows, restore_rows_handler)
+         .event(DatabaseEvent::PurgeRowTrash, purge_row_trash_handler)
+         .event(DatabaseEvent::GetRowCount, get_row_count_handler)
+         .event(DatabaseEvent::UpdateRowLock, update_row_lock_handler)
          .event(DatabaseEvent::DuplicateRow, duplicate_row_handler)
          .event(DatabaseEvent::MoveRow, move_row_handler)
+         .event(DatabaseEvent::MoveRowToIndex, move_row_to_index_handler)
          .event(DatabaseEvent::RemoveCover, remove_cover_handler)
          // Cell
          .event(DatabaseEvent::GetCell, get_cell_handler)
+         .event(DatabaseEvent::RevealCell, reveal_cell_handler)
          .event(DatabaseEvent::UpdateCell, update_cell_handler)
+         .event(DatabaseEvent::UpdateCells, update_cells_handler)
+         .event(DatabaseEvent::UpdateTypedCell, update_typed_cell_handler)
+         .event(DatabaseEvent::CopyCells, copy_cells_handler)
+         .event(DatabaseEvent::PasteCells, paste_cells_handler)
+         .event(DatabaseEvent::PasteRows, paste_rows_handler)
          // SelectOption
          .event(DatabaseEvent::CreateSelectOption, new_select_option_handler)
          .event(DatabaseEvent::InsertOrUpdateSelectOption, insert_or_update_select_option_handler)
          .event(DatabaseEvent::DeleteSelectOption, delete_select_option_handler)
+         .event(DatabaseEvent::UpdateSelectOption, update_select_option_handler)
+         .event(DatabaseEvent::MoveSelectOption, move_select_option_handler)
+         .event(DatabaseEvent::MergeSelectOptions, merge_select_options_handler)
+         .event(DatabaseEvent::GetSelectOptionUsage, get_select_option_usage_handler)
          .event(DatabaseEvent::UpdateSelectOptionCell, update_select_option_cell_handler)
          // Checklist
          .event(DatabaseEvent::UpdateChecklistCell, update_checklist_cell_handler)
+         .event(DatabaseEvent::UpdateTextCellWithDelta, update_text_cell_with_delta_handler)
+         .event(DatabaseEvent::SetCellEditingState, set_cell_editing_state_handler)
          // Date
          .event(DatabaseEvent::UpdateDateCell, update_date_cell_handler)
          // Group
@@ -75,10 +100,54 @@ pub fn init(database_manager: Weak<DatabaseManager>) -> AFPlugin {
          .event(DatabaseEvent::SetLayoutSetting, set_layout_setting_handler)
          .event(DatabaseEvent::GetLayoutSetting, get_layout_setting_handler)
          .event(DatabaseEvent::CreateDatabaseView, create_database_view)
+         .event(DatabaseEvent::GetDatabaseViews, get_database_views_handler)
+         .event(DatabaseEvent::SearchDatabaseCells, search_database_cells_handler)
+         .event(DatabaseEvent::ReplaceDatabaseCells, replace_database_cells_handler)
+         .event(DatabaseEvent::GetRowColorRules, get_row_color_rules_handler)
+         .event(DatabaseEvent::UpdateRowColorRules, update_row_color_rules_handler)
+         .event(DatabaseEvent::GetRowColors, get_row_colors_handler)
+         .event(DatabaseEvent::ParseFilterExpression, parse_filter_expression_handler)
+         .event(DatabaseEvent::UpdateGroupAggregate, update_group_aggregate_handler)
+         .event(DatabaseEvent::DuplicateDatabaseView, duplicate_database_view_handler)
+         .event(DatabaseEvent::SetDefaultDatabaseView, set_default_database_view_handler)
+         .event(DatabaseEvent::MoveDatabaseView, move_database_view_handler)
+         .event(DatabaseEvent::GetAutomationRules, get_automation_rules_handler)
+         .event(DatabaseEvent::UpdateAutomationRules, update_automation_rules_handler)
+         .event(DatabaseEvent::GetRowTemplates, get_row_templates_handler)
+         .event(DatabaseEvent::UpdateRowTemplates, update_row_templates_handler)
+         .event(DatabaseEvent::CreateRowFromTemplate, create_row_from_template_handler)
+         .event(DatabaseEvent::QueryRows, query_rows_handler)
          // Export
          .event(DatabaseEvent::ExportCSV, export_csv_handler)
+         .event(DatabaseEvent::ExportViewCSV, export_view_csv_handler)
          .event(DatabaseEvent::ExportRawDatabaseData, export_raw_database_data_handler)
+         .event(DatabaseEvent::ExportGridJSON, export_grid_json_handler)
+         .event(DatabaseEvent::ExportViewJSON, export_view_json_handler)
+         .event(DatabaseEvent::ExportChangesSince, export_changes_since_handler)
+         .event(DatabaseEvent::ExportXLSX, export_xlsx_handler)
+         .event(DatabaseEvent::ExportTemplate, export_template_handler)
+         .event(DatabaseEvent::BackupDatabase, backup_database_handler)
+         .event(DatabaseEvent::CompactDatabase, compact_database_handler)
+         .event(DatabaseEvent::ExportICal, export_ical_handler)
+         .event(DatabaseEvent::StartPostgresMirror, start_postgres_mirror_handler)
+         .event(DatabaseEvent::StopDatabaseMirror, stop_database_mirror_handler)
+         .event(DatabaseEvent::StartLocalServer, start_local_server_handler)
+         .event(DatabaseEvent::StopLocalServer, stop_local_server_handler)
+         .event(DatabaseEvent::GetLocalServer, get_local_server_handler)
          .event(DatabaseEvent::GetDatabaseSnapshots, get_snapshots_handler)
+         .event(DatabaseEvent::CreateDatabaseSnapshot, create_snapshot_handler)
+         .event(DatabaseEvent::GetDatabaseSnapshotPolicy, get_snapshot_policy_handler)
+         .event(DatabaseEvent::UpdateDatabaseSnapshotPolicy, update_snapshot_policy_handler)
+         .event(DatabaseEvent::RepairDatabase, repair_database_handler)
+         .event(DatabaseEvent::DiffDatabase, diff_database_handler)
+         // Debug
+         .event(DatabaseEvent::ExplainQuery, explain_query_handler)
+         .event(DatabaseEvent::ExportViewSettings, export_view_settings_handler)
+         .event(DatabaseEvent::ImportViewSettings, import_view_settings_handler)
+         .event(DatabaseEvent::GetSampleRows, get_sample_rows_handler)
+         .event(DatabaseEvent::GetDatabaseDiagnostics, get_database_diagnostics_handler)
+         .event(DatabaseEvent::ValidateRow, validate_row_handler)
+         .event(DatabaseEvent::GetInvalidRows, get_invalid_rows_handler)
          // Field settings
          .event(DatabaseEvent::GetFieldSettings, get_field_settings_handler)
          .event(DatabaseEvent::GetAllFieldSettings, get_all_field_settings_handler)
@@ -219,9 +288,34 @@ pub enum DatabaseEvent {
   #[event(input = "RepeatedSelectOptionPayload")]
   InsertOrUpdateSelectOption = 31,
 
+  /// [DeleteSelectOption] event is used to delete the options of a FieldType::SingleSelect or
+  /// FieldType::MultiSelect field. The options are removed from every cell of the field, so the
+  /// `row_id` of the payload is ignored.
   #[event(input = "RepeatedSelectOptionPayload")]
   DeleteSelectOption = 32,
 
+  /// [UpdateSelectOption] event is used to rename or recolor an existing option of a
+  /// FieldType::SingleSelect or FieldType::MultiSelect field.
+  #[event(input = "UpdateSelectOptionPayloadPB")]
+  UpdateSelectOption = 33,
+
+  /// [MoveSelectOption] event is used to reorder the options of a FieldType::SingleSelect or
+  /// FieldType::MultiSelect field.
+  #[event(input = "MoveSelectOptionPayloadPB")]
+  MoveSelectOption = 34,
+
+  /// [MergeSelectOptions] event is used to merge one option of a FieldType::SingleSelect or
+  /// FieldType::MultiSelect field into another. The cells that contain the merged option are
+  /// rewritten and the merged option is removed.
+  #[event(input = "MergeSelectOptionsPayloadPB")]
+  MergeSelectOptions = 35,
+
+  /// [GetSelectOptionUsage] event returns how many cells reference each option of a
+  /// FieldType::SingleSelect or FieldType::MultiSelect field, e.g. to find the options that are
+  /// safe to delete.
+  #[event(input = "SelectOptionUsagePayloadPB", output = "RepeatedSelectOptionUsagePB")]
+  GetSelectOptionUsage = 36,
+
   #[event(input = "CreateRowPayloadPB", output = "RowMetaPB")]
   CreateRow = 50,
 
@@ -273,12 +367,53 @@ pub enum DatabaseEvent {
   #[event(input = "ChecklistCellDataChangesetPB")]
   UpdateChecklistCell = 73,
 
+  /// [RevealCell] event is the same as [GetCell] except that the data of a cell that belongs
+  /// to a sensitive field is returned instead of being masked.
+  #[event(input = "CellIdPB", output = "CellPB")]
+  RevealCell = 74,
+
+  /// [UpdateTextCellWithDelta] event is used to edit a part of a text cell. Unlike [UpdateCell],
+  /// which replaces the whole content, the delta is transformed against the changes made since
+  /// the content it was based on, so concurrent edits to different parts of the text don't
+  /// overwrite each other.
+  #[event(input = "TextCellDeltaChangesetPB")]
+  UpdateTextCellWithDelta = 75,
+
+  /// [SetCellEditingState] event is used to tell the other collaborators which cell the user is
+  /// editing. The other collaborators receive the editing cells with the
+  /// `DidUpdateCellEditingStates` notification.
+  #[event(input = "CellEditingPayloadPB")]
+  SetCellEditingState = 76,
+
+  /// [UpdateTypedCell] event is the same as [UpdateCell] except that the data is typed by the
+  /// field type, e.g. a timestamp for a date or the option ids for a select option, instead of
+  /// a string in the format of the field. See [TypedCellChangesetPB].
+  #[event(input = "TypedCellChangesetPB")]
+  UpdateTypedCell = 77,
+
+  /// [CopyCells] event is used to copy the cells of the fields in the rows. The cells keep the
+  /// select options and the dates when they are pasted with [PasteCells].
+  #[event(input = "CopyCellsPayloadPB", output = "ClipboardDataPB")]
+  CopyCells = 78,
+
+  /// [PasteCells] event is used to paste the copied cells, or the tab separated text copied from
+  /// another app. It returns the number of pasted cells and the cells that couldn't be parsed
+  /// by their fields.
+  #[event(input = "PasteCellsPayloadPB", output = "PasteCellsResultPB")]
+  PasteCells = 79,
+
   /// [UpdateDateCell] event is used to update a date cell's data. [DateCellChangesetPB]
   /// contains the date and the time string. It can be cast to [CellChangesetPB] that
   /// will be used by the `update_cell` function.
   #[event(input = "DateCellChangesetPB")]
   UpdateDateCell = 80,
 
+  /// [UpdateCells] event updates many cells of a view at once, e.g. when pasting a range of
+  /// cells. Nothing is written if any of the changesets is invalid, and a single
+  /// `DidUpdateCells` notification is sent with all the updated cells.
+  #[event(input = "BatchCellChangesetPB")]
+  UpdateCells = 81,
+
   /// [SetGroupByField] event is used to create a new grouping in a database
   /// view based on the `field_id`
   #[event(input = "GroupByFieldPayloadPB")]
@@ -339,10 +474,21 @@ pub enum DatabaseEvent {
   #[event(input = "DatabaseViewIdPB", output = "DatabaseExportDataPB")]
   ExportCSV = 141,
 
-  /// Returns all the snapshots of the database view.
+  /// Returns the latest local snapshots of the database of the view, the latest first.
   #[event(input = "DatabaseViewIdPB", output = "RepeatedDatabaseSnapshotPB")]
   GetDatabaseSnapshots = 150,
 
+  /// Creates a snapshot of the database and of its rows regardless of the snapshot policy. The
+  /// client sends it before a risky operation like switching the type of a field.
+  #[event(input = "DatabaseViewIdPB")]
+  CreateDatabaseSnapshot = 151,
+
+  /// Returns the differences between two snapshots of the database, or a snapshot and the current
+  /// state: the added, removed and updated fields, and the added, removed and changed rows with
+  /// the before and after content of their cells. Used by the version history.
+  #[event(input = "DiffDatabasePayloadPB", output = "DatabaseDiffPB")]
+  DiffDatabase = 152,
+
   /// Returns the field settings for the provided fields in the given view
   #[event(input = "FieldIdsPB", output = "RepeatedFieldSettingsPB")]
   GetFieldSettings = 160,
@@ -396,9 +542,285 @@ pub enum DatabaseEvent {
   #[event(input = "DatabaseViewIdPB", output = "DatabaseExportDataPB")]
   ExportRawDatabaseData = 178,
 
+  /// [ExplainQuery] event is used to debug why a row is hidden or ordered the way it is. It
+  /// returns the result of every filter of the view and the sort keys of the row.
+  #[event(input = "DatabaseViewRowIdPB", output = "QueryExplanationPB")]
+  ExplainQuery = 179,
+
+  /// [ExportViewSettings] event exports the filters, sorts, grouping and field visibility of a
+  /// view as JSON, without any of its rows.
+  #[event(input = "DatabaseViewIdPB", output = "ViewSettingsDataPB")]
+  ExportViewSettings = 180,
+
+  /// [ImportViewSettings] event applies the exported settings to a view. The fields are matched
+  /// by name and type, so the view can belong to another database.
+  #[event(input = "ViewSettingsDataPB")]
+  ImportViewSettings = 181,
+
+  /// [GetSampleRows] event returns the first, the last or random rows of a view with their
+  /// cells, without loading the other rows. Used to preview a database.
+  #[event(input = "GetSampleRowsPayloadPB", output = "RepeatedRowSamplePB")]
+  GetSampleRows = 182,
+
+  /// Returns the state of the open database editors, e.g. their sync state, cache sizes and last
+  /// activity, to inspect the memory and sync issues at runtime. The number of unsynced updates
+  /// is not included, the collab doesn't expose it.
+  #[event(output = "RepeatedDatabaseEditorDiagnosticsPB")]
+  GetDatabaseDiagnostics = 183,
+
+  /// [ValidateRow] event returns the required fields whose cells are empty in the row.
+  #[event(input = "DatabaseViewRowIdPB", output = "RowValidationPB")]
+  ValidateRow = 184,
+
+  /// [GetInvalidRows] event returns the rows of the view that have empty cells in the required
+  /// fields, so the UI can badge them.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedRowValidationPB")]
+  GetInvalidRows = 185,
+
+  /// [RepairDatabase] event replaces the corrupted local data of a database that is opened in
+  /// safe mode with the snapshot it is opened from, and makes the database editable again.
+  #[event(input = "DatabaseViewIdPB")]
+  RepairDatabase = 186,
+
+  /// [GetDatabaseViews] event returns all the views of the database that the given view belongs
+  /// to, with their names and layouts.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedDatabaseViewMetaPB")]
+  GetDatabaseViews = 187,
+
+  /// [SearchDatabaseCells] event returns a page of the cells of the view whose content contains
+  /// the query.
+  #[event(input = "SearchCellsPayloadPB", output = "RepeatedCellSearchHitPB")]
+  SearchDatabaseCells = 188,
+
+  /// [ReplaceDatabaseCells] event replaces the query in the text cells of the view and returns
+  /// the number of changed cells. With `dry_run`, the cells are only counted.
+  #[event(input = "ReplaceCellsPayloadPB", output = "ReplaceCellsResultPB")]
+  ReplaceDatabaseCells = 189,
+
+  /// [GetRowColorRules] event returns the row color rules of the view.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedRowColorRulePB")]
+  GetRowColorRules = 190,
+
+  /// [UpdateRowColorRules] event replaces the row color rules of the view. A row takes the color
+  /// of the first rule whose condition it matches.
+  #[event(input = "UpdateRowColorRulesPayloadPB")]
+  UpdateRowColorRules = 191,
+
+  /// [GetRowColors] event returns the color of every visible row of the view that matches one of
+  /// its row color rules.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedRowColorPB")]
+  GetRowColors = 192,
+
+  /// [ParseFilterExpression] event parses a filter expression like
+  /// `Status = "Live" AND Price > 100` into a filter tree of the view. The filter isn't applied
+  /// to the view.
+  #[event(
+    input = "FilterExpressionPayloadPB",
+    output = "FilterExpressionResultPB"
+  )]
+  ParseFilterExpression = 193,
+
+  /// [UpdateGroupAggregate] event sets the field whose values are summed up in each group of the
+  /// view. The groups carry their row count and sum in [GroupPB] and in the group row
+  /// notifications.
+  #[event(input = "UpdateGroupAggregatePayloadPB")]
+  UpdateGroupAggregate = 194,
+
+  /// [DuplicateDatabaseView] event creates a new view of the database with the layout, filters,
+  /// sorts, field settings and row order of the given view, and returns the new view.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseViewMetaPB")]
+  DuplicateDatabaseView = 195,
+
+  /// [SetDefaultDatabaseView] event sets the view that opens by default when its database is
+  /// opened. [DatabaseNotification::DidUpdateDatabaseViews] is sent with the database id.
+  #[event(input = "DatabaseViewIdPB")]
+  SetDefaultDatabaseView = 196,
+
+  /// [MoveDatabaseView] event moves the view to the given index of the display order of the views
+  /// of its database. [GetDatabaseViews] returns the views in that order.
+  #[event(input = "MoveDatabaseViewPayloadPB")]
+  MoveDatabaseView = 197,
+
+  /// [ExportViewCSV] event exports the rows of the view as CSV, in the order of the view and with
+  /// its filters applied. Each cell is formatted the way its field displays it.
+  #[event(input = "ExportViewCSVPayloadPB", output = "DatabaseExportDataPB")]
+  ExportViewCSV = 198,
+
+  /// [PasteRows] event pastes each row of the copied cells, or of the tab separated text copied
+  /// from another app, as a new row at the end of the view.
+  #[event(input = "PasteRowsPayloadPB", output = "PasteCellsResultPB")]
+  PasteRows = 199,
+
   #[event(input = "MediaCellChangesetPB")]
   UpdateMediaCell = 200,
 
   #[event(input = "RenameMediaChangesetPB")]
   RenameMediaFile = 201,
+
+  /// [ExportGridJSON] event exports the fields and rows of the view as portable JSON. The JSON is
+  /// imported as a new grid with the `GridJSON` import type of the folder.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseExportDataPB")]
+  ExportGridJSON = 202,
+
+  /// [ExportXLSX] event exports each view of the database as a sheet of an xlsx workbook. The
+  /// numbers, checkboxes and dates are written as typed cells.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseExportFilePB")]
+  ExportXLSX = 203,
+
+  /// [ExportTemplate] event exports the fields of the view, and optionally its rows as seed data,
+  /// as a template. A grid is created from the template with the `GridTemplate` import type of
+  /// the folder.
+  #[event(input = "ExportTemplatePayloadPB", output = "DatabaseExportDataPB")]
+  ExportTemplate = 204,
+
+  /// [BackupDatabase] event backs up the database of the view as a single zip file, including
+  /// the current state and the local snapshots of the database. A backup is restored with the
+  /// `DatabaseBackup` import type of the folder, as a new database without the edit history.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseExportFilePB")]
+  BackupDatabase = 205,
+
+  /// [ExportICal] event exports the rows of the view that have a date as an iCalendar feed, so
+  /// the rows can be subscribed to from external calendars.
+  #[event(input = "ExportICalPayloadPB", output = "DatabaseExportDataPB")]
+  ExportICal = 206,
+
+  /// [StartPostgresMirror] event starts pulling the rows of a Postgres table into the database
+  /// of the view periodically, over TLS unless the connection string sets another `sslmode`.
+  /// The database is read-only until the mirror is stopped. The mirror isn't saved, so it has
+  /// to be started again after the app restarts.
+  #[event(input = "PostgresMirrorPayloadPB")]
+  StartPostgresMirror = 207,
+
+  #[event(input = "DatabaseViewIdPB")]
+  StopDatabaseMirror = 208,
+
+  /// [GetAutomationRules] event returns the automation rules of the database of the view.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedAutomationRulePB")]
+  GetAutomationRules = 209,
+
+  /// [UpdateAutomationRules] event replaces the automation rules of the database of the view. A
+  /// rule runs its actions, like setting a cell or creating a row in another grid, when its
+  /// trigger fires for a row.
+  #[event(input = "UpdateAutomationRulesPayloadPB")]
+  UpdateAutomationRules = 210,
+
+  /// [QueryRows] event returns a page of the rows of the view that match a filter expression,
+  /// sorted by the sorts of the query, so the rows can be queried without loading the view.
+  #[event(input = "QueryRowsPayloadPB", output = "QueryRowsResultPB")]
+  QueryRows = 211,
+
+  /// [StartLocalServer] event starts a server on the loopback interface that lets the local
+  /// tools read and write the grids over HTTP. It returns the port and the token of the server.
+  #[event(input = "StartLocalServerPayloadPB", output = "LocalServerPB")]
+  StartLocalServer = 212,
+
+  #[event()]
+  StopLocalServer = 213,
+
+  #[event(output = "LocalServerPB")]
+  GetLocalServer = 214,
+
+  /// [ExportViewJSON] event exports the rows of the view as portable JSON, in the order of the
+  /// view and with its filters applied. Unlike [ExportGridJSON], the fields hidden in the view are
+  /// skipped unless they're asked for.
+  #[event(input = "ExportViewJSONPayloadPB", output = "DatabaseExportDataPB")]
+  ExportViewJSON = 215,
+
+  /// [ExportChangesSince] event returns the rows and fields of the view that were created,
+  /// updated or deleted after a revision, and the revision to poll from next time. The sync tools
+  /// poll it instead of exporting the whole grid.
+  #[event(input = "ExportChangesPayloadPB", output = "DatabaseChangesPB")]
+  ExportChangesSince = 216,
+
+  /// [MoveRowToIndex] event moves a row to an index of the rows that are visible in the view, i.e.
+  /// with the filters and sorts of the view applied. An index past the last row moves the row to
+  /// the end.
+  #[event(input = "MoveRowToIndexPayloadPB")]
+  MoveRowToIndex = 217,
+
+  /// [CreateRowAtIndex] event creates a row at an index of the rows that are visible in the view,
+  /// like [MoveRowToIndex] moves one. The rows before and after a row are created with the
+  /// position of [CreateRow] instead.
+  #[event(input = "CreateRowAtIndexPayloadPB", output = "RowMetaPB")]
+  CreateRowAtIndex = 218,
+
+  /// [GetRowPage] event returns a page of the rows of the view, with the filters and the sorts of
+  /// the view applied, so the rows can be loaded as the grid is scrolled.
+  #[event(input = "RowPagePayloadPB", output = "RowPagePB")]
+  GetRowPage = 219,
+
+  /// [CompactDatabase] event replaces the updates of the database stored on disk with its current
+  /// state, e.g. after many rows were deleted. The database is also compacted on its own after
+  /// many rows were deleted.
+  #[event(input = "DatabaseViewIdPB")]
+  CompactDatabase = 220,
+
+  /// [GetRowComments] event returns the comments of a row, oldest first.
+  #[event(input = "DatabaseViewRowIdPB", output = "RepeatedRowCommentPB")]
+  GetRowComments = 221,
+
+  /// [AddRowComment] event adds a comment to a row and returns it. The comments of the row are
+  /// sent with the [DidUpdateRowComments] notification after they're changed.
+  #[event(input = "AddRowCommentPayloadPB", output = "RowCommentPB")]
+  AddRowComment = 222,
+
+  #[event(input = "DeleteRowCommentPayloadPB")]
+  DeleteRowComment = 223,
+
+  /// [OpenRowDocument] event opens the document of a row, creating it the first time the row is
+  /// opened as a page, and returns the meta of the row with the id of the document.
+  #[event(input = "DatabaseViewRowIdPB", output = "RowMetaPB")]
+  OpenRowDocument = 224,
+
+  /// [GetRowTemplates] event returns the row templates of the database of the view.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedRowTemplatePB")]
+  GetRowTemplates = 225,
+
+  /// [UpdateRowTemplates] event replaces the row templates of the database of the view. A
+  /// template is a named set of cell values that the rows created from it start with.
+  #[event(input = "UpdateRowTemplatesPayloadPB")]
+  UpdateRowTemplates = 226,
+
+  /// [CreateRowFromTemplate] event creates a row at the end of the view with the cells of the
+  /// template.
+  #[event(input = "CreateRowFromTemplatePayloadPB", output = "RowMetaPB")]
+  CreateRowFromTemplate = 227,
+
+  /// [DeleteAllRows] event deletes every row of the database of the view, keeping its fields and
+  /// views. It's much faster than deleting the rows with [DeleteRows] when the grid is large.
+  #[event(input = "DatabaseViewIdPB")]
+  DeleteAllRows = 228,
+
+  /// [GetTrashedRows] event returns the rows in the trash of the database of the view. The deleted
+  /// rows stay in the trash for 30 days before they're purged.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedTrashedRowPB")]
+  GetTrashedRows = 229,
+
+  /// [RestoreRows] event restores the rows from the trash, at the position they had in each view.
+  #[event(input = "RepeatedRowIdPB")]
+  RestoreRows = 230,
+
+  /// [PurgeRowTrash] event deletes the rows in the trash of the database for good.
+  #[event(input = "DatabaseViewIdPB")]
+  PurgeRowTrash = 231,
+
+  /// [GetRowCount] event returns the number of rows of the database of the view. The count is kept
+  /// up to date as rows are inserted and deleted, so the rows aren't loaded.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseRowCountPB")]
+  GetRowCount = 232,
+
+  /// [UpdateRowLock] event locks or unlocks a row. Editing the cells of a locked row or deleting
+  /// it fails with the `RowLocked` error code until the row is unlocked.
+  #[event(input = "UpdateRowLockPayloadPB")]
+  UpdateRowLock = 233,
+
+  /// [GetDatabaseSnapshotPolicy] event returns the policy that decides when the local snapshots
+  /// of the databases are created.
+  #[event(output = "DatabaseSnapshotPolicyPB")]
+  GetDatabaseSnapshotPolicy = 234,
+
+  /// [UpdateDatabaseSnapshotPolicy] event sets the snapshot policy of the databases. It applies to
+  /// the databases that are opened after it's set.
+  #[event(input = "DatabaseSnapshotPolicyPB")]
+  UpdateDatabaseSnapshotPolicy = 235,
 }
@@ -4,10 +4,11 @@ use async_trait::async_trait;
 use collab::core::collab::DataSource;
 use collab::core::origin::CollabOrigin;
 use collab::lock::RwLock;
-use collab::preclude::Collab;
-use collab_database::database::{Database, DatabaseData};
-use collab_database::entity::{CreateDatabaseParams, CreateViewParams};
+use collab::preclude::{Collab, CollabBuilder};
+use collab_database::database::{timestamp, Database, DatabaseData};
+use collab_database::entity::{CreateDatabaseParams, CreateViewParams, EncodedCollabInfo};
 use collab_database::error::DatabaseError;
+use collab_database::fields::url_type_option::URLCellData;
 use collab_database::rows::RowId;
 use collab_database::template::csv::CSVTemplate;
 use collab_database::views::DatabaseLayout;
@@ -17,30 +18,62 @@ use collab_database::workspace_database::{
 };
 use collab_entity::{CollabObject, CollabType, EncodedCollab};
 use collab_plugins::local_storage::kv::KVTransactionDB;
+use dashmap::DashMap;
 use rayon::prelude::*;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Weak};
 use std::time::Duration;
+use tokio::select;
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 use tracing::{error, info, instrument, trace, warn};
 
 use collab_integrate::collab_builder::{AppFlowyCollabBuilder, CollabBuilderConfig};
+use collab_integrate::snapshot::SnapshotPolicy;
 use collab_integrate::{CollabKVAction, CollabKVDB};
+use flowy_config::feature_flag::FeatureFlags;
 use flowy_database_pub::cloud::{
   DatabaseAIService, DatabaseCloudService, SummaryRowContent, TranslateItem, TranslateRowContent,
 };
-use flowy_error::{internal_error, FlowyError, FlowyResult};
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
 use lib_dispatch::prelude::af_spawn;
 use lib_infra::box_any::BoxAny;
 use lib_infra::priority_task::TaskDispatcher;
 
-use crate::entities::{DatabaseLayoutPB, DatabaseSnapshotPB, FieldType, RowMetaPB};
-use crate::services::cell::stringify_cell;
-use crate::services::database::DatabaseEditor;
+use crate::entities::{
+  DatabaseChangesPB, DatabaseCorruptionPB, DatabaseLayoutPB, DatabaseSnapshotPB, FieldType,
+  LocalServerPB, RowMetaPB,
+};
+use crate::notification::{send_notification, DatabaseNotification};
+use crate::services::automation::{AutomationQueue, AutomationRowRequest};
+use crate::services::cell::{stringify_cell, CellValidator, CellValidators};
+use crate::services::change_log::ChangeLog;
+use crate::services::database::{DatabaseEditor, DatabaseEditorDiagnostics};
 use crate::services::database_view::DatabaseLayoutDepsResolver;
 use crate::services::field::translate_type_option::translate::TranslateTypeOption;
 use crate::services::field_settings::default_field_settings_by_layout_map;
-use crate::services::share::csv::{CSVFormat, CSVImporter, ImportResult};
+use crate::services::local_server::LocalServer;
+use crate::services::mirror::MirrorConnector;
+use crate::services::row_document::{RowDocumentService, RowDocuments};
+use crate::services::share::backup::{
+  restore_database_params, DatabaseBackup, DatabaseBackupMeta, DatabaseBackupSnapshot,
+  DATABASE_BACKUP_VERSION,
+};
+use crate::services::share::csv::{
+  database_from_csv, CSVExportOptions, CSVFormat, CSVImportOptions, CSVImporter, CSVSource,
+  ImportResult,
+};
+use crate::services::share::html::database_from_html_table;
+use crate::services::share::json::GridJson;
+use crate::services::share::markdown::database_from_markdown_table;
+use crate::services::share::notion::{document_from_markdown, NotionExport};
+use crate::services::share::xlsx::database_from_xlsx;
+use crate::services::snapshot::diff::{diff_database_data, DatabaseDiff};
+use crate::services::snapshot::entities::{DatabaseSnapshotData, DatabaseSnapshotMeta};
+use crate::services::webhook::{
+  Webhook, WebhookClient, WebhookDelivery, WebhookEmitter, WebhookRetryConfig, WebhookStore,
+};
 use tokio::sync::RwLock as TokioRwLock;
 
 pub trait DatabaseUser: Send + Sync {
@@ -50,16 +83,59 @@ pub trait DatabaseUser: Send + Sync {
   fn workspace_database_object_id(&self) -> Result<String, FlowyError>;
 }
 
+/// Reads the local snapshots of the databases. The snapshots are created according to the
+/// snapshot policy of the databases or with [DatabaseManager::create_database_snapshot].
+pub trait DatabaseSnapshotService: Send + Sync {
+  /// Returns the metas of the snapshots of the database or of one of its rows, the latest first.
+  fn get_database_snapshot_metas(&self, object_id: &str) -> FlowyResult<Vec<DatabaseSnapshotMeta>>;
+  fn get_database_snapshot(&self, snapshot_id: &str) -> FlowyResult<DatabaseSnapshotData>;
+  /// Saves the snapshot of a database together with the snapshots of its rows. Returns the id of
+  /// the database snapshot.
+  fn create_database_snapshot(
+    &self,
+    database_id: &str,
+    encoded_database: Vec<u8>,
+    encoded_rows: Vec<(String, Vec<u8>)>,
+  ) -> FlowyResult<String>;
+  /// Returns the snapshot of the row that was saved with the database snapshot, see
+  /// [DatabaseSnapshotService::create_database_snapshot].
+  fn get_row_snapshot(
+    &self,
+    row_id: &str,
+    database_snapshot_id: &str,
+  ) -> FlowyResult<Option<DatabaseSnapshotData>>;
+  /// Returns the snapshot policy saved with [DatabaseSnapshotService::set_snapshot_policy].
+  fn get_snapshot_policy(&self) -> Option<SnapshotPolicy>;
+  /// Saves the snapshot policy of the databases, so it's restored when the app restarts.
+  fn set_snapshot_policy(&self, policy: &SnapshotPolicy) -> FlowyResult<()>;
+}
+
+/// The number of the latest snapshots of a database that are included in its backup.
+const BACKUP_SNAPSHOT_LIMIT: usize = 50;
+/// How often the dates of the automation rules of the open databases are checked.
+const DATE_AUTOMATION_INTERVAL: Duration = Duration::from_secs(60);
+
 pub(crate) type DatabaseEditorMap = HashMap<String, Arc<DatabaseEditor>>;
+/// The snapshots that the corrupted databases are opened from, keyed by database id.
+type SafeModeSnapshots = Arc<DashMap<String, EncodedCollab>>;
 pub struct DatabaseManager {
   user: Arc<dyn DatabaseUser>,
   workspace_database_manager: ArcSwapOption<RwLock<WorkspaceDatabaseManager>>,
   task_scheduler: Arc<TokioRwLock<TaskDispatcher>>,
   pub(crate) editors: Mutex<DatabaseEditorMap>,
   removing_editor: Arc<Mutex<HashMap<String, Arc<DatabaseEditor>>>>,
+  safe_mode_snapshots: SafeModeSnapshots,
+  cell_validators: CellValidators,
+  webhooks: WebhookEmitter,
+  change_log: ChangeLog,
+  automations: AutomationQueue,
+  row_documents: RowDocuments,
+  local_server: Mutex<Option<LocalServer>>,
   collab_builder: Arc<AppFlowyCollabBuilder>,
   cloud_service: Arc<dyn DatabaseCloudService>,
   ai_service: Arc<dyn DatabaseAIService>,
+  snapshot_service: Arc<dyn DatabaseSnapshotService>,
+  is_network_reachable: AtomicBool,
 }
 
 impl DatabaseManager {
@@ -69,16 +145,30 @@ impl DatabaseManager {
     collab_builder: Arc<AppFlowyCollabBuilder>,
     cloud_service: Arc<dyn DatabaseCloudService>,
     ai_service: Arc<dyn DatabaseAIService>,
+    snapshot_service: Arc<dyn DatabaseSnapshotService>,
+    feature_flags: Weak<FeatureFlags>,
   ) -> Self {
+    if let Some(policy) = snapshot_service.get_snapshot_policy() {
+      collab_builder.set_snapshot_policy(CollabType::Database, policy);
+    }
     Self {
       user: database_user,
       workspace_database_manager: Default::default(),
       task_scheduler,
       editors: Default::default(),
       removing_editor: Default::default(),
+      safe_mode_snapshots: Default::default(),
+      cell_validators: Default::default(),
+      webhooks: Default::default(),
+      change_log: Default::default(),
+      automations: AutomationQueue::new(feature_flags),
+      row_documents: Default::default(),
+      local_server: Default::default(),
       collab_builder,
       cloud_service,
       ai_service,
+      snapshot_service,
+      is_network_reachable: AtomicBool::new(true),
     }
   }
 
@@ -92,7 +182,10 @@ impl DatabaseManager {
     }
     self.editors.lock().await.clear();
     self.removing_editor.lock().await.clear();
-    // 3. Clear the workspace database
+    self.safe_mode_snapshots.clear();
+    // 3. Stop the local server, its token was given out for the previous workspace
+    self.stop_local_server().await;
+    // 4. Clear the workspace database
     if let Some(old_workspace_database) = self.workspace_database_manager.swap(None) {
       info!("Close the old workspace database");
       let wdb = old_workspace_database.read().await;
@@ -105,6 +198,7 @@ impl DatabaseManager {
       self.user.clone(),
       self.collab_builder.clone(),
       self.cloud_service.clone(),
+      self.safe_mode_snapshots.clone(),
     );
 
     let workspace_database_object_id = self.user.workspace_database_object_id()?;
@@ -223,9 +317,11 @@ impl DatabaseManager {
     database_id: &str,
   ) -> FlowyResult<Arc<DatabaseEditor>> {
     if let Some(editor) = self.editors.lock().await.get(database_id).cloned() {
+      editor.touch();
       return Ok(editor);
     }
     let editor = self.open_database(database_id).await?;
+    editor.touch();
     Ok(editor)
   }
 
@@ -245,14 +341,103 @@ impl DatabaseManager {
     // When the user opens the database from the left-side bar, it may fail because the workspace database
     // hasn't finished syncing yet. In such cases, get_or_create_database will return None.
     // The workaround is to add a retry mechanism to attempt fetching the database again.
-    let database = open_database_with_retry(workspace_database, database_id).await?;
+    let database = match open_database_with_retry(workspace_database, database_id).await {
+      Ok(database) => database,
+      Err(err) if self.is_corrupted(database_id, &err) => {
+        return self.open_database_in_safe_mode(database_id, err).await;
+      },
+      Err(err) => return Err(err.into()),
+    };
+    let editor = DatabaseEditor::new(
+      self.user.clone(),
+      database,
+      self.task_scheduler.clone(),
+      self.collab_builder.clone(),
+      self.cell_validators.clone(),
+      self.webhooks.clone(),
+      self.change_log.clone(),
+      self.automations.clone(),
+      self.row_documents.clone(),
+    )
+    .await?;
+
+    self
+      .editors
+      .lock()
+      .await
+      .insert(database_id.to_string(), editor.clone());
+    Ok(editor)
+  }
+
+  /// The database is corrupted if it exists in the local storage but can't be loaded from it.
+  fn is_corrupted(&self, database_id: &str, err: &DatabaseError) -> bool {
+    if matches!(
+      err,
+      DatabaseError::RecordNotFound | DatabaseError::ActionCancelled
+    ) {
+      return false;
+    }
+    DatabasePersistenceImpl {
+      user: self.user.clone(),
+    }
+    .is_collab_exist(database_id)
+  }
+
+  /// Opens the corrupted database read-only from its latest snapshot, so the user can still see
+  /// the data and decide to repair it. The corrupted local data is kept until
+  /// [DatabaseManager::repair_database] is called.
+  async fn open_database_in_safe_mode(
+    &self,
+    database_id: &str,
+    err: DatabaseError,
+  ) -> FlowyResult<Arc<DatabaseEditor>> {
+    error!(
+      "[Database]: database:{} is corrupted, try to open it in safe mode, error:{}",
+      database_id, err
+    );
+    let snapshot = self.get_latest_snapshot(database_id);
+    send_notification(
+      database_id,
+      DatabaseNotification::DidDetectDatabaseCorruption,
+    )
+    .payload(DatabaseCorruptionPB {
+      database_id: database_id.to_string(),
+      reason: err.to_string(),
+      snapshot_id: snapshot
+        .as_ref()
+        .map(|(snapshot_id, _)| snapshot_id.clone()),
+    })
+    .send();
+
+    let (snapshot_id, encoded_collab) = snapshot.ok_or_else(|| FlowyError::from(err))?;
+    info!(
+      "[Database]: open database:{} in safe mode from snapshot:{}",
+      database_id, snapshot_id
+    );
+    self
+      .safe_mode_snapshots
+      .insert(database_id.to_string(), encoded_collab);
+    let result = open_database_with_retry(self.workspace_database()?, database_id).await;
+    let database = match result {
+      Ok(database) => database,
+      Err(err) => {
+        self.safe_mode_snapshots.remove(database_id);
+        return Err(err.into());
+      },
+    };
     let editor = DatabaseEditor::new(
       self.user.clone(),
       database,
       self.task_scheduler.clone(),
       self.collab_builder.clone(),
+      self.cell_validators.clone(),
+      self.webhooks.clone(),
+      self.change_log.clone(),
+      self.automations.clone(),
+      self.row_documents.clone(),
     )
     .await?;
+    editor.set_read_only(true);
 
     self
       .editors
@@ -262,6 +447,73 @@ impl DatabaseManager {
     Ok(editor)
   }
 
+  /// Returns the latest local snapshot of the database that can be decoded.
+  fn get_latest_snapshot(&self, database_id: &str) -> Option<(String, EncodedCollab)> {
+    let metas = self
+      .snapshot_service
+      .get_database_snapshot_metas(database_id)
+      .map_err(|err| {
+        warn!(
+          "[Database]: get snapshots of {} failed: {}",
+          database_id, err
+        )
+      })
+      .ok()?;
+    metas.into_iter().find_map(|meta| {
+      let result = self
+        .snapshot_service
+        .get_database_snapshot(&meta.snapshot_id)
+        .and_then(|snapshot| {
+          EncodedCollab::decode_from_bytes(&snapshot.encoded_v1).map_err(internal_error)
+        });
+      match result {
+        Ok(encoded_collab) => Some((meta.snapshot_id, encoded_collab)),
+        Err(err) => {
+          warn!(
+            "[Database]: decode snapshot {} failed: {}",
+            meta.snapshot_id, err
+          );
+          None
+        },
+      }
+    })
+  }
+
+  /// Replaces the corrupted local data of a database that is opened in safe mode with the
+  /// snapshot it is opened from, then reopens the database for editing.
+  #[instrument(level = "debug", skip(self), err)]
+  pub async fn repair_database(&self, view_id: &str) -> FlowyResult<()> {
+    let database_id = self.get_database_id_with_view_id(view_id).await?;
+    let encoded_collab = self
+      .safe_mode_snapshots
+      .get(&database_id)
+      .map(|snapshot| snapshot.value().clone())
+      .ok_or_else(|| {
+        FlowyError::invalid_data()
+          .with_context(format!("The database:{} is not in safe mode", database_id))
+      })?;
+
+    DatabasePersistenceImpl {
+      user: self.user.clone(),
+    }
+    .save_collab(&database_id, encoded_collab)?;
+    self.safe_mode_snapshots.remove(&database_id);
+
+    let editor = self.editors.lock().await.remove(&database_id);
+    if let Some(editor) = editor {
+      editor.close_all_views().await;
+      editor.close_database().await;
+    }
+    let workspace_database = self.workspace_database()?;
+    workspace_database
+      .write()
+      .await
+      .close_database(&database_id);
+    info!("[Database]: did repair database:{}", database_id);
+    self.open_database(&database_id).await?;
+    Ok(())
+  }
+
   /// Open the database view
   #[instrument(level = "trace", skip_all, err)]
   pub async fn open_database_view<T: AsRef<str>>(&self, view_id: T) -> FlowyResult<()> {
@@ -334,6 +586,158 @@ impl DatabaseManager {
     Ok(())
   }
 
+  /// Installs a validator that is consulted before a cell of any database is updated. It applies
+  /// to the databases that are already open too.
+  pub fn add_cell_validator(&self, validator: Arc<dyn CellValidator>) {
+    self.cell_validators.add(validator);
+  }
+
+  /// Registers a URL that receives the rows created, updated or deleted in the database of the
+  /// view and the changes of its fields as JSON payloads, see [WebhookEmitter]. The webhook is
+  /// saved with the store set with [DatabaseManager::set_webhook_store].
+  pub async fn add_webhook(&self, view_id: &str, url: &str) -> FlowyResult<Webhook> {
+    let database_id = self.get_database_id_with_view_id(view_id).await?;
+    self.webhooks.add_webhook(&database_id, url)
+  }
+
+  pub async fn remove_webhook(&self, view_id: &str, webhook_id: &str) -> FlowyResult<()> {
+    let database_id = self.get_database_id_with_view_id(view_id).await?;
+    if self.webhooks.remove_webhook(&database_id, webhook_id)? {
+      Ok(())
+    } else {
+      Err(
+        FlowyError::record_not_found()
+          .with_context(format!("Can't find the webhook: {}", webhook_id)),
+      )
+    }
+  }
+
+  pub async fn get_webhooks(&self, view_id: &str) -> FlowyResult<Vec<Webhook>> {
+    let database_id = self.get_database_id_with_view_id(view_id).await?;
+    Ok(self.webhooks.webhooks(&database_id))
+  }
+
+  /// Returns the delivery log of the webhooks of the database of the view, oldest first. The log
+  /// keeps the latest deliveries of all the databases.
+  pub async fn get_webhook_deliveries(&self, view_id: &str) -> FlowyResult<Vec<WebhookDelivery>> {
+    let database_id = self.get_database_id_with_view_id(view_id).await?;
+    Ok(self.webhooks.deliveries(&database_id))
+  }
+
+  /// Replaces the client that posts the payloads of the webhooks.
+  pub fn set_webhook_client(&self, client: Arc<dyn WebhookClient>) {
+    self.webhooks.set_client(client);
+  }
+
+  /// Sets where the webhooks are saved. It must be called before any database is opened.
+  pub fn set_webhook_store(&self, store: Arc<dyn WebhookStore>) {
+    self.webhooks.set_store(store);
+  }
+
+  pub fn set_webhook_retry_config(&self, retry_config: WebhookRetryConfig) {
+    self.webhooks.set_retry_config(retry_config);
+  }
+
+  /// Sets the service that opens and deletes the documents of the rows, see
+  /// [DatabaseEditor::open_row_document].
+  pub fn set_row_document_service(&self, service: Arc<dyn RowDocumentService>) {
+    self.row_documents.set_service(service);
+  }
+
+  /// Starts running the parts of the automation rules that need the manager: the rows that the
+  /// rules create in other databases, and the date triggers of the open databases, checked every
+  /// [DATE_AUTOMATION_INTERVAL]. It does nothing if the automations are already running.
+  pub fn start_automations(self: &Arc<Self>) {
+    let Some(mut rx) = self.automations.take_receiver() else {
+      return;
+    };
+    let weak_manager = Arc::downgrade(self);
+    af_spawn(async move {
+      let mut interval = tokio::time::interval(DATE_AUTOMATION_INTERVAL);
+      loop {
+        select! {
+          request = rx.recv() => {
+            let (Some(request), Some(manager)) = (request, weak_manager.upgrade()) else {
+              break;
+            };
+            if !manager.automations.is_enabled() {
+              continue;
+            }
+            if let Err(err) = manager.create_automation_row(&request).await {
+              warn!(
+                "[Database]: automation can't create a row in view:{}: {}",
+                request.view_id, err
+              );
+            }
+          },
+          _ = interval.tick() => {
+            let Some(manager) = weak_manager.upgrade() else {
+              break;
+            };
+            manager.check_date_automations().await;
+          },
+        }
+      }
+      trace!("[Database]: stop running automations");
+    });
+  }
+
+  /// Runs the date triggers of the automation rules of the open databases, see
+  /// [DatabaseEditor::run_date_automations].
+  pub async fn check_date_automations(&self) {
+    if !self.automations.is_enabled() {
+      return;
+    }
+    let editors = self
+      .editors
+      .lock()
+      .await
+      .values()
+      .cloned()
+      .collect::<Vec<_>>();
+    for editor in editors {
+      editor.run_date_automations(timestamp()).await;
+    }
+  }
+
+  async fn create_automation_row(&self, request: &AutomationRowRequest) -> FlowyResult<()> {
+    let editor = self
+      .get_database_editor_with_view_id(&request.view_id)
+      .await?;
+    editor
+      .create_automation_row(&request.view_id, &request.cells)
+      .await?;
+    Ok(())
+  }
+
+  /// Returns the state of the open database editors, including the closed ones that are not
+  /// dropped yet.
+  pub async fn diagnostics(&self) -> Vec<DatabaseEditorDiagnostics> {
+    let editors = self
+      .editors
+      .lock()
+      .await
+      .values()
+      .cloned()
+      .collect::<Vec<_>>();
+    let removing_editors = self
+      .removing_editor
+      .lock()
+      .await
+      .values()
+      .cloned()
+      .collect::<Vec<_>>();
+
+    let mut diagnostics = Vec::with_capacity(editors.len() + removing_editors.len());
+    for editor in editors {
+      diagnostics.push(editor.diagnostics(false).await);
+    }
+    for editor in removing_editors {
+      diagnostics.push(editor.diagnostics(true).await);
+    }
+    diagnostics
+  }
+
   pub async fn delete_database_view(&self, view_id: &str) -> FlowyResult<()> {
     let database = self.get_database_editor_with_view_id(view_id).await?;
     let _ = database.delete_database_view(view_id).await?;
@@ -472,6 +876,302 @@ impl DatabaseManager {
     Ok(result)
   }
 
+  /// Imports the CSV data as a new grid database whose inline view is `view_id`. Unlike
+  /// [Self::import_csv], the field types are inferred from the values of each column and a file
+  /// is read record by record instead of being loaded into memory.
+  pub async fn import_csv_with_options(
+    &self,
+    view_id: String,
+    source: CSVSource,
+    options: CSVImportOptions,
+  ) -> FlowyResult<ImportResult> {
+    let params =
+      tokio::task::spawn_blocking(move || database_from_csv(&view_id, &source, &options))
+        .await
+        .map_err(internal_error)??;
+
+    let view_id = params.inline_view_id.clone();
+    let database_id = params.database_id.clone();
+    let database = self.import_database(params).await?;
+    let encoded_database = database.read().await.encode_database_collabs().await?;
+    let encoded_collabs = std::iter::once(encoded_database.encoded_database_collab)
+      .chain(encoded_database.encoded_row_collabs.into_iter())
+      .collect::<Vec<_>>();
+
+    let result = ImportResult {
+      database_id,
+      view_id,
+      encoded_collabs,
+    };
+    info!("import csv result: {}", result);
+    Ok(result)
+  }
+
+  /// Imports the JSON exported by [Self::export_grid_json] as a new grid database whose inline
+  /// view is `view_id`.
+  pub async fn import_grid_json(&self, view_id: String, json: String) -> FlowyResult<ImportResult> {
+    let params =
+      tokio::task::spawn_blocking(move || GridJson::from_json(&json)?.into_params(&view_id))
+        .await
+        .map_err(internal_error)??;
+
+    let view_id = params.inline_view_id.clone();
+    let database_id = params.database_id.clone();
+    let database = self.import_database(params).await?;
+    let encoded_database = database.read().await.encode_database_collabs().await?;
+    let encoded_collabs = std::iter::once(encoded_database.encoded_database_collab)
+      .chain(encoded_database.encoded_row_collabs.into_iter())
+      .collect::<Vec<_>>();
+
+    let result = ImportResult {
+      database_id,
+      view_id,
+      encoded_collabs,
+    };
+    info!("import grid json result: {}", result);
+    Ok(result)
+  }
+
+  /// Creates a new grid from the template exported by [Self::export_template]. The template is
+  /// validated first, and the fields and rows get new ids, so the grids created from the same
+  /// template don't collide.
+  pub async fn create_grid_from_template(
+    &self,
+    view_id: String,
+    data: Vec<u8>,
+  ) -> FlowyResult<ImportResult> {
+    let params = tokio::task::spawn_blocking(move || {
+      let json = String::from_utf8(data).map_err(|err| {
+        FlowyError::invalid_data().with_context(format!("Invalid template: {}", err))
+      })?;
+      GridJson::from_json(&json)?.into_template_params(&view_id)
+    })
+    .await
+    .map_err(internal_error)??;
+
+    let view_id = params.inline_view_id.clone();
+    let database_id = params.database_id.clone();
+    let database = self.import_database(params).await?;
+    let encoded_database = database.read().await.encode_database_collabs().await?;
+    let encoded_collabs = std::iter::once(encoded_database.encoded_database_collab)
+      .chain(encoded_database.encoded_row_collabs.into_iter())
+      .collect::<Vec<_>>();
+
+    let result = ImportResult {
+      database_id,
+      view_id,
+      encoded_collabs,
+    };
+    info!("create grid from template result: {}", result);
+    Ok(result)
+  }
+
+  /// Imports a database exported by Notion as a new grid. The markdown page of each row becomes
+  /// the document of the row, see [NotionExport].
+  pub async fn import_notion(&self, view_id: String, data: Vec<u8>) -> FlowyResult<ImportResult> {
+    let (params, row_pages) = tokio::task::spawn_blocking(move || {
+      let export = NotionExport::from_zip(data)?;
+      let params = database_from_csv(&view_id, &export.csv_source(), &CSVImportOptions::notion())?;
+      let row_pages = export.row_pages(&params);
+      Ok::<_, FlowyError>((params, row_pages))
+    })
+    .await
+    .map_err(internal_error)??;
+
+    let view_id = params.inline_view_id.clone();
+    let database_id = params.database_id.clone();
+    let database = self.import_database(params).await?;
+    let mut row_documents = vec![];
+    {
+      let mut database = database.write().await;
+      for (row_id, markdown) in row_pages {
+        let Some(document_id) = database.get_row_document_id(&row_id) else {
+          continue;
+        };
+        match document_from_markdown(&document_id, &markdown) {
+          Ok(encoded_collab) => {
+            database
+              .update_row_meta(&row_id, |meta_update| {
+                meta_update.update_is_document_empty_if_not_none(Some(false));
+              })
+              .await;
+            row_documents.push(EncodedCollabInfo {
+              object_id: document_id,
+              collab_type: CollabType::Document,
+              encoded_collab,
+            });
+          },
+          Err(err) => warn!(
+            "Failed to import the page of row {}: {}",
+            row_id.as_str(),
+            err
+          ),
+        }
+      }
+    }
+
+    let encoded_database = database.read().await.encode_database_collabs().await?;
+    let encoded_collabs = std::iter::once(encoded_database.encoded_database_collab)
+      .chain(encoded_database.encoded_row_collabs.into_iter())
+      .chain(row_documents)
+      .collect::<Vec<_>>();
+
+    let result = ImportResult {
+      database_id,
+      view_id,
+      encoded_collabs,
+    };
+    info!("import notion result: {}", result);
+    Ok(result)
+  }
+
+  /// Imports a sheet of the xlsx workbook as a new grid. The first sheet is imported if no sheet
+  /// name is given.
+  pub async fn import_xlsx(
+    &self,
+    view_id: String,
+    data: Vec<u8>,
+    sheet_name: Option<String>,
+  ) -> FlowyResult<ImportResult> {
+    let params = tokio::task::spawn_blocking(move || {
+      database_from_xlsx(&view_id, data, sheet_name.as_deref())
+    })
+    .await
+    .map_err(internal_error)??;
+
+    let view_id = params.inline_view_id.clone();
+    let database_id = params.database_id.clone();
+    let database = self.import_database(params).await?;
+    let encoded_database = database.read().await.encode_database_collabs().await?;
+    let encoded_collabs = std::iter::once(encoded_database.encoded_database_collab)
+      .chain(encoded_database.encoded_row_collabs.into_iter())
+      .collect::<Vec<_>>();
+
+    let result = ImportResult {
+      database_id,
+      view_id,
+      encoded_collabs,
+    };
+    info!("import xlsx result: {}", result);
+    Ok(result)
+  }
+
+  /// Imports the first table of the HTML, e.g. copied from a web page or Google Sheets, as a new
+  /// grid database whose inline view is `view_id`, see [database_from_html_table].
+  pub async fn import_html_table(
+    &self,
+    view_id: String,
+    html: String,
+  ) -> FlowyResult<ImportResult> {
+    let params = tokio::task::spawn_blocking(move || database_from_html_table(&view_id, &html))
+      .await
+      .map_err(internal_error)??;
+
+    let view_id = params.inline_view_id.clone();
+    let database_id = params.database_id.clone();
+    let database = self.import_database(params).await?;
+    let encoded_database = database.read().await.encode_database_collabs().await?;
+    let encoded_collabs = std::iter::once(encoded_database.encoded_database_collab)
+      .chain(encoded_database.encoded_row_collabs.into_iter())
+      .collect::<Vec<_>>();
+
+    let result = ImportResult {
+      database_id,
+      view_id,
+      encoded_collabs,
+    };
+    info!("import html table result: {}", result);
+    Ok(result)
+  }
+
+  /// Imports the first table of the markdown, e.g. a table selected in a document, as a new grid
+  /// database whose inline view is `view_id`, see [database_from_markdown_table].
+  pub async fn import_markdown_table(
+    &self,
+    view_id: String,
+    markdown: String,
+  ) -> FlowyResult<ImportResult> {
+    let params =
+      tokio::task::spawn_blocking(move || database_from_markdown_table(&view_id, &markdown))
+        .await
+        .map_err(internal_error)??;
+
+    let view_id = params.inline_view_id.clone();
+    let database_id = params.database_id.clone();
+    let database = self.import_database(params).await?;
+    let encoded_database = database.read().await.encode_database_collabs().await?;
+    let encoded_collabs = std::iter::once(encoded_database.encoded_database_collab)
+      .chain(encoded_database.encoded_row_collabs.into_iter())
+      .collect::<Vec<_>>();
+
+    let result = ImportResult {
+      database_id,
+      view_id,
+      encoded_collabs,
+    };
+    info!("import markdown table result: {}", result);
+    Ok(result)
+  }
+
+  /// Exports the fields and rows of the view as portable JSON that can be imported with
+  /// [Self::import_grid_json]. The values of the sensitive fields are masked unless
+  /// `reveal_sensitive` is true.
+  pub async fn export_grid_json(
+    &self,
+    view_id: &str,
+    reveal_sensitive: bool,
+  ) -> FlowyResult<String> {
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    database.export_json(view_id, reveal_sensitive).await
+  }
+
+  /// Exports the rows of the view as portable JSON, see [DatabaseEditor::export_view_json]. The
+  /// values of the sensitive fields are masked unless `reveal_sensitive` is true.
+  pub async fn export_view_json(
+    &self,
+    view_id: &str,
+    include_hidden_fields: bool,
+    reveal_sensitive: bool,
+  ) -> FlowyResult<String> {
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    database
+      .export_view_json(view_id, include_hidden_fields, reveal_sensitive)
+      .await
+  }
+
+  /// Exports the rows of the view as a markdown table, e.g. to inline a grid in a document when
+  /// the document is exported, see [DatabaseEditor::export_view_markdown].
+  pub async fn export_view_markdown(&self, view_id: &str) -> FlowyResult<String> {
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    database.export_view_markdown(view_id).await
+  }
+
+  /// Returns the rows and fields of the view that changed after the revision, so the external
+  /// sync tools don't have to export the whole grid, see [DatabaseEditor::export_changes_since].
+  pub async fn export_changes_since(
+    &self,
+    view_id: &str,
+    rev_id: i64,
+  ) -> FlowyResult<DatabaseChangesPB> {
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    database.export_changes_since(view_id, rev_id).await
+  }
+
+  /// Exports the view as a reusable template, see [DatabaseEditor::export_template]. A new grid
+  /// is created from the template with [Self::create_grid_from_template]. The values of the
+  /// sensitive fields are always masked, since a template is meant to be shared.
+  pub async fn export_template(&self, view_id: &str, include_rows: bool) -> FlowyResult<String> {
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    database.export_template(view_id, include_rows, false).await
+  }
+
+  /// Appends the rows of the CSV data to the database of the view. Returns the number of
+  /// appended rows, see [DatabaseEditor::append_csv].
+  pub async fn append_csv(&self, view_id: &str, source: CSVSource) -> FlowyResult<usize> {
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    database.append_csv(view_id, source).await
+  }
+
   // will implement soon
   pub async fn import_csv_from_file(
     &self,
@@ -481,9 +1181,144 @@ impl DatabaseManager {
     Ok(())
   }
 
-  pub async fn export_csv(&self, view_id: &str, style: CSVFormat) -> FlowyResult<String> {
+  pub async fn export_csv(
+    &self,
+    view_id: &str,
+    style: CSVFormat,
+    reveal_sensitive: bool,
+  ) -> FlowyResult<String> {
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    database.export_csv(style, reveal_sensitive).await
+  }
+
+  /// Exports the rows of the view as CSV, see [DatabaseEditor::export_view_csv]. The values of
+  /// the sensitive fields are masked.
+  pub async fn export_view_csv(
+    &self,
+    view_id: &str,
+    options: CSVExportOptions,
+  ) -> FlowyResult<String> {
     let database = self.get_database_editor_with_view_id(view_id).await?;
-    database.export_csv(style).await
+    database.export_view_csv(view_id, options, false).await
+  }
+
+  /// Exports each view of the database of the view as a sheet of an xlsx workbook, see
+  /// [DatabaseEditor::export_xlsx]. The values of the sensitive fields are masked.
+  pub async fn export_xlsx(&self, view_id: &str) -> FlowyResult<Vec<u8>> {
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    database.export_xlsx(false).await
+  }
+
+  /// Exports the rows of the view that have a date as an iCalendar feed, see
+  /// [DatabaseEditor::export_ical]. The titles of the events are masked if the title field is
+  /// sensitive.
+  pub async fn export_ical(
+    &self,
+    view_id: &str,
+    date_field_id: &str,
+    title_field_id: &str,
+  ) -> FlowyResult<String> {
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    database
+      .export_ical(view_id, date_field_id, title_field_id, false)
+      .await
+  }
+
+  /// Mirrors an external table into the database of the view. The rows are pulled right away and
+  /// then every `interval`, see [DatabaseEditor::sync_mirror]. The database is read-only while
+  /// it's mirrored, until [Self::stop_mirror] is called or the database is closed.
+  ///
+  /// The mirror isn't saved, since its connector may hold credentials, so it stops when the app
+  /// quits and has to be started again after a restart.
+  pub async fn start_mirror(
+    &self,
+    view_id: &str,
+    connector: Arc<dyn MirrorConnector>,
+    interval: Duration,
+  ) -> FlowyResult<()> {
+    let editor = self.get_database_editor_with_view_id(view_id).await?;
+    if editor.is_read_only() && !editor.is_mirrored() {
+      return Err(FlowyError::new(
+        ErrorCode::DatabaseReadOnly,
+        "Can't mirror a database that is opened in safe mode",
+      ));
+    }
+    let token = CancellationToken::new();
+    editor.set_mirror_cancellation(token.clone());
+    info!("[Database]: start mirroring into view:{}", view_id);
+
+    let weak_editor = Arc::downgrade(&editor);
+    let view_id = view_id.to_string();
+    af_spawn(async move {
+      loop {
+        let result = select! {
+          _ = token.cancelled() => break,
+          result = connector.pull() => result,
+        };
+        let Some(editor) = weak_editor.upgrade() else {
+          break;
+        };
+        match result {
+          Ok(table) => {
+            if let Err(err) = editor.sync_mirror(&view_id, table).await {
+              error!(
+                "[Database]: sync mirror of view:{} failed: {}",
+                view_id, err
+              );
+            }
+          },
+          Err(err) => warn!(
+            "[Database]: pull mirror of view:{} failed: {}",
+            view_id, err
+          ),
+        }
+        drop(editor);
+
+        select! {
+          _ = token.cancelled() => break,
+          _ = tokio::time::sleep(interval) => {},
+        }
+      }
+      trace!("[Database]: stop mirroring into view:{}", view_id);
+    });
+    Ok(())
+  }
+
+  /// Stops mirroring the external table into the database of the view. The rows that were
+  /// pulled are kept and the database becomes editable again.
+  pub async fn stop_mirror(&self, view_id: &str) -> FlowyResult<()> {
+    let editor = self.get_database_editor_with_view_id(view_id).await?;
+    editor.stop_mirror();
+    Ok(())
+  }
+
+  /// Starts the [LocalServer] on the port, or on a free port if it's 0. The running server is
+  /// stopped first, so the token of the new server is different.
+  pub async fn start_local_server(self: &Arc<Self>, port: u16) -> FlowyResult<LocalServerPB> {
+    let mut local_server = self.local_server.lock().await;
+    if let Some(server) = local_server.take() {
+      server.stop();
+    }
+    let server = LocalServer::start(Arc::downgrade(self), port)?;
+    let server_pb = LocalServerPB::from(&server);
+    *local_server = Some(server);
+    Ok(server_pb)
+  }
+
+  pub async fn stop_local_server(&self) {
+    if let Some(server) = self.local_server.lock().await.take() {
+      server.stop();
+    }
+  }
+
+  pub async fn get_local_server(&self) -> LocalServerPB {
+    self
+      .local_server
+      .lock()
+      .await
+      .as_ref()
+      .map(LocalServerPB::from)
+      .unwrap_or_default()
   }
 
   pub async fn update_database_layout(
@@ -495,26 +1330,280 @@ impl DatabaseManager {
     database.update_view_layout(view_id, layout.into()).await
   }
 
+  /// Backs up the database of the view as a single zip file with its raw data, the current state
+  /// of the database and its rows, and its local snapshots. See [DatabaseBackup] for the layout
+  /// of the file.
+  pub async fn backup_database(&self, view_id: &str) -> FlowyResult<Vec<u8>> {
+    let editor = self.get_database_editor_with_view_id(view_id).await?;
+    let database_json = self.get_database_json_bytes(view_id).await?;
+    let (database_id, inline_view_id, collabs, num_of_rows) = {
+      let database = editor.database.read().await;
+      let encoded_database = database.encode_database_collabs().await?;
+      let num_of_rows = encoded_database.encoded_row_collabs.len();
+      let collabs = std::iter::once(encoded_database.encoded_database_collab)
+        .chain(encoded_database.encoded_row_collabs.into_iter())
+        .collect::<Vec<_>>();
+      (
+        database.get_database_id(),
+        database.get_inline_view_id(),
+        collabs,
+        num_of_rows,
+      )
+    };
+
+    // The backup is still useful without the snapshots, so a snapshot that can't be read is
+    // skipped
+    let metas = self
+      .snapshot_service
+      .get_database_snapshot_metas(&database_id)
+      .unwrap_or_else(|err| {
+        warn!(
+          "[Database]: back up {} without snapshots: {}",
+          database_id, err
+        );
+        vec![]
+      });
+    let mut snapshots = metas
+      .into_iter()
+      .take(BACKUP_SNAPSHOT_LIMIT)
+      .filter_map(|meta| {
+        match self
+          .snapshot_service
+          .get_database_snapshot(&meta.snapshot_id)
+        {
+          Ok(snapshot) => Some(DatabaseBackupSnapshot {
+            snapshot_id: meta.snapshot_id,
+            created_at: meta.created_at,
+            data: snapshot.encoded_v1,
+          }),
+          Err(err) => {
+            warn!(
+              "[Database]: skip snapshot {} in the backup: {}",
+              meta.snapshot_id, err
+            );
+            None
+          },
+        }
+      })
+      .collect::<Vec<_>>();
+    // The metas are the latest first, the backup lists the snapshots oldest first
+    snapshots.reverse();
+
+    let backup = DatabaseBackup {
+      meta: DatabaseBackupMeta {
+        version: DATABASE_BACKUP_VERSION,
+        database_id,
+        inline_view_id,
+        created_at: timestamp(),
+        num_of_rows,
+        snapshot_ids: snapshots
+          .iter()
+          .map(|snapshot| snapshot.snapshot_id.clone())
+          .collect(),
+      },
+      database_json,
+      collabs,
+      snapshots,
+    };
+    tokio::task::spawn_blocking(move || backup.to_zip())
+      .await
+      .map_err(internal_error)?
+  }
+
+  /// Restores the backup made by [Self::backup_database] as a new database whose inline view is
+  /// `view_id`. The backup is validated before anything is created, see
+  /// [DatabaseBackup::read_database_data]. The new database is created from the raw data of the
+  /// backup, so it doesn't have the edit history or the snapshots of the original database.
+  pub async fn restore_database(
+    &self,
+    view_id: String,
+    data: Vec<u8>,
+  ) -> FlowyResult<ImportResult> {
+    let params = tokio::task::spawn_blocking(move || {
+      let database_data = DatabaseBackup::read_database_data(data)?;
+      Ok::<_, FlowyError>(restore_database_params(database_data, &view_id))
+    })
+    .await
+    .map_err(internal_error)??;
+
+    let view_id = params.inline_view_id.clone();
+    let database_id = params.database_id.clone();
+    let database = self.import_database(params).await?;
+    let encoded_database = database.read().await.encode_database_collabs().await?;
+    let encoded_collabs = std::iter::once(encoded_database.encoded_database_collab)
+      .chain(encoded_database.encoded_row_collabs.into_iter())
+      .collect::<Vec<_>>();
+
+    let result = ImportResult {
+      database_id,
+      view_id,
+      encoded_collabs,
+    };
+    info!("restore database result: {}", result);
+    Ok(result)
+  }
+
+  /// Returns the latest local snapshots of the database of the view, the latest first.
   pub async fn get_database_snapshots(
     &self,
     view_id: &str,
     limit: usize,
   ) -> FlowyResult<Vec<DatabaseSnapshotPB>> {
     let database_id = self.get_database_id_with_view_id(view_id).await?;
-    let snapshots = self
-      .cloud_service
-      .get_database_collab_object_snapshots(&database_id, limit)
-      .await?
+    self
+      .snapshot_service
+      .get_database_snapshot_metas(&database_id)?
       .into_iter()
-      .map(|snapshot| DatabaseSnapshotPB {
-        snapshot_id: snapshot.snapshot_id,
-        snapshot_desc: "".to_string(),
-        created_at: snapshot.created_at,
-        data: snapshot.data,
+      .take(limit)
+      .map(|meta| {
+        let snapshot = self
+          .snapshot_service
+          .get_database_snapshot(&meta.snapshot_id)?;
+        Ok(DatabaseSnapshotPB {
+          snapshot_id: meta.snapshot_id,
+          snapshot_desc: "".to_string(),
+          created_at: meta.created_at,
+          data: snapshot.encoded_v1,
+        })
       })
-      .collect::<Vec<_>>();
+      .collect()
+  }
+
+  /// Creates a snapshot of the database of the view and of each of its rows regardless of the
+  /// snapshot policy, so the database can be restored or compared with a later version if a
+  /// risky operation, e.g. switching the type of a field, goes wrong. Returns the id of the
+  /// snapshot.
+  pub async fn create_database_snapshot(&self, view_id: &str) -> FlowyResult<String> {
+    let editor = self.get_database_editor_with_view_id(view_id).await?;
+    let (database_id, checkpoint, encoded_database, encoded_rows) = {
+      let database = editor.database.read().await;
+      let database_id = database.get_database_id();
+      let checkpoint = self.collab_builder.snapshot_checkpoint(&database_id);
+      let encoded_database = database.encode_database_collabs().await?;
+      let encoded_rows = encoded_database
+        .encoded_row_collabs
+        .into_iter()
+        .map(|row| {
+          let encoded_v1 = row
+            .encoded_collab
+            .encode_to_bytes()
+            .map_err(internal_error)?;
+          Ok((row.object_id, encoded_v1))
+        })
+        .collect::<FlowyResult<Vec<_>>>()?;
+      let encoded_database = encoded_database
+        .encoded_database_collab
+        .encoded_collab
+        .encode_to_bytes()
+        .map_err(internal_error)?;
+      (database_id, checkpoint, encoded_database, encoded_rows)
+    };
 
-    Ok(snapshots)
+    let snapshot_service = self.snapshot_service.clone();
+    let cloned_database_id = database_id.clone();
+    let snapshot_id = tokio::task::spawn_blocking(move || {
+      snapshot_service.create_database_snapshot(&cloned_database_id, encoded_database, encoded_rows)
+    })
+    .await
+    .map_err(internal_error)??;
+    // The snapshot policy restarts only once the snapshot is saved
+    if let Some(checkpoint) = checkpoint {
+      self
+        .collab_builder
+        .did_save_snapshot(&database_id, &checkpoint);
+    }
+    Ok(snapshot_id)
+  }
+
+  pub fn get_database_snapshot_policy(&self) -> SnapshotPolicy {
+    self.collab_builder.snapshot_policy(&CollabType::Database)
+  }
+
+  /// Sets the policy that decides when the snapshots of the databases are created. The policy is
+  /// saved, so it applies after a restart too. The databases that are already open keep the
+  /// policy they were opened with.
+  pub fn set_database_snapshot_policy(&self, policy: SnapshotPolicy) -> FlowyResult<()> {
+    self.snapshot_service.set_snapshot_policy(&policy)?;
+    self
+      .collab_builder
+      .set_snapshot_policy(CollabType::Database, policy);
+    Ok(())
+  }
+
+  /// Compares two local snapshots of the database of the view, see
+  /// [DatabaseManager::get_database_snapshots]. The current state of the database is compared
+  /// if `after_snapshot_id` is None.
+  pub async fn diff_database(
+    &self,
+    view_id: &str,
+    before_snapshot_id: &str,
+    after_snapshot_id: Option<&str>,
+  ) -> FlowyResult<DatabaseDiff> {
+    let database_id = self.get_database_id_with_view_id(view_id).await?;
+    let before = self
+      .get_snapshot_database_data(view_id, &database_id, before_snapshot_id)
+      .await?;
+    let after = match after_snapshot_id {
+      Some(after_snapshot_id) => {
+        self
+          .get_snapshot_database_data(view_id, &database_id, after_snapshot_id)
+          .await?
+      },
+      None => {
+        let lock = self.workspace_database()?;
+        let wdb = lock.read().await;
+        wdb.get_database_data(view_id).await?
+      },
+    };
+    Ok(diff_database_data(&before, &after))
+  }
+
+  /// Returns the data of the database as it was when the snapshot was created. Each row is read
+  /// from the snapshot that was saved with the database snapshot, see
+  /// [DatabaseManager::create_database_snapshot], or from its current state if it doesn't have
+  /// one, e.g. for the snapshots created by the snapshot policy. Nothing is written to the local
+  /// data.
+  async fn get_snapshot_database_data(
+    &self,
+    view_id: &str,
+    database_id: &str,
+    snapshot_id: &str,
+  ) -> FlowyResult<DatabaseData> {
+    let snapshot = self.snapshot_service.get_database_snapshot(snapshot_id)?;
+    if snapshot.object_id != database_id {
+      return Err(FlowyError::record_not_found().with_context(format!(
+        "The database:{} doesn't have the snapshot:{}",
+        database_id, snapshot_id
+      )));
+    }
+    let database_snapshot =
+      EncodedCollab::decode_from_bytes(&snapshot.encoded_v1).map_err(internal_error)?;
+
+    let collab_service = SnapshotCollabService {
+      uid: self.user.user_id()?,
+      persistence: DatabasePersistenceImpl {
+        user: self.user.clone(),
+      },
+      snapshot_service: self.snapshot_service.clone(),
+      database_id: database_id.to_string(),
+      database_snapshot,
+      snapshot_id: snapshot_id.to_string(),
+    };
+    let workspace_database_object_id = self.user.workspace_database_object_id()?;
+    let workspace_database_collab = collab_service
+      .build_collab(
+        &workspace_database_object_id,
+        CollabType::WorkspaceDatabase,
+        None,
+      )
+      .await?;
+    let workspace_database = WorkspaceDatabaseManager::open(
+      &workspace_database_object_id,
+      workspace_database_collab,
+      collab_service,
+    )?;
+    let database_data = workspace_database.get_database_data(view_id).await?;
+    Ok(database_data)
   }
 
   fn workspace_database(&self) -> FlowyResult<Arc<RwLock<WorkspaceDatabaseManager>>> {
@@ -643,6 +1732,53 @@ impl DatabaseManager {
     Ok(())
   }
 
+  pub fn update_network_reachable(&self, reachable: bool) {
+    self
+      .is_network_reachable
+      .store(reachable, Ordering::Relaxed);
+  }
+
+  /// Fetches the title and the favicon of the url in the URL cell in the background and stores
+  /// them in the cell. Nothing is fetched while the network is unreachable.
+  #[instrument(level = "debug", skip_all)]
+  pub async fn fetch_link_preview(
+    &self,
+    view_id: String,
+    row_id: RowId,
+    field_id: String,
+  ) -> FlowyResult<()> {
+    if !self.is_network_reachable.load(Ordering::Relaxed) {
+      trace!("[Database]: skip fetching the link preview, the network is unreachable");
+      return Ok(());
+    }
+
+    let database = self.get_database_editor_with_view_id(&view_id).await?;
+    let url = match database.get_cell(&field_id, &row_id).await {
+      Some(cell) => URLCellData::from(&cell).data,
+      None => return Ok(()),
+    };
+    if url.trim().is_empty() {
+      return Ok(());
+    }
+
+    let cloud_service = self.cloud_service.clone();
+    af_spawn(async move {
+      match cloud_service.get_link_preview(&url).await {
+        Ok(preview) if !preview.is_empty() => {
+          if let Err(err) = database
+            .update_link_preview(&view_id, &row_id, &field_id, &url, preview)
+            .await
+          {
+            error!("[Database]: save link preview of {} failed: {}", url, err);
+          }
+        },
+        Ok(_) => {},
+        Err(err) => warn!("[Database]: fetch link preview of {} failed: {}", url, err),
+      }
+    });
+    Ok(())
+  }
+
   /// Only expose this method for testing
   #[cfg(debug_assertions)]
   pub fn get_cloud_service(&self) -> &Arc<dyn DatabaseCloudService> {
@@ -656,6 +1792,7 @@ struct WorkspaceDatabaseCollabServiceImpl {
   collab_builder: Arc<AppFlowyCollabBuilder>,
   persistence: Arc<dyn DatabaseCollabPersistenceService>,
   cloud_service: Arc<dyn DatabaseCloudService>,
+  safe_mode_snapshots: SafeModeSnapshots,
 }
 
 impl WorkspaceDatabaseCollabServiceImpl {
@@ -664,6 +1801,7 @@ impl WorkspaceDatabaseCollabServiceImpl {
     user: Arc<dyn DatabaseUser>,
     collab_builder: Arc<AppFlowyCollabBuilder>,
     cloud_service: Arc<dyn DatabaseCloudService>,
+    safe_mode_snapshots: SafeModeSnapshots,
   ) -> Self {
     let persistence = DatabasePersistenceImpl { user: user.clone() };
     Self {
@@ -672,6 +1810,7 @@ impl WorkspaceDatabaseCollabServiceImpl {
       collab_builder,
       persistence: Arc::new(persistence),
       cloud_service,
+      safe_mode_snapshots,
     }
   }
 
@@ -748,7 +1887,18 @@ impl DatabaseCollabService for WorkspaceDatabaseCollabServiceImpl {
     encoded_collab: Option<(EncodedCollab, bool)>,
   ) -> Result<Collab, DatabaseError> {
     let object = self.build_collab_object(object_id, collab_type.clone())?;
-    let data_source = if self.persistence.is_collab_exist(object_id) {
+    let safe_mode_snapshot = self
+      .safe_mode_snapshots
+      .get(object_id)
+      .map(|snapshot| snapshot.value().clone());
+    let data_source = if let Some(snapshot) = safe_mode_snapshot {
+      // The local data of the object is corrupted, build it from the snapshot instead.
+      info!(
+        "build collab: {}:{} from snapshot in safe mode",
+        collab_type, object_id
+      );
+      DataSource::from(snapshot)
+    } else if self.persistence.is_collab_exist(object_id) {
       if encoded_collab.is_some() {
         warn!(
           "build collab: {}:{} with both local and remote encode collab",
@@ -897,6 +2047,78 @@ impl DatabaseCollabService for WorkspaceDatabaseCollabServiceImpl {
   }
 }
 
+/// Builds the collabs of a database as they were when a snapshot of the database was created,
+/// see [DatabaseManager::get_snapshot_database_data]. The collabs are built without plugins and
+/// without persistence, so they never write to the local data.
+struct SnapshotCollabService {
+  uid: i64,
+  persistence: DatabasePersistenceImpl,
+  snapshot_service: Arc<dyn DatabaseSnapshotService>,
+  database_id: String,
+  database_snapshot: EncodedCollab,
+  snapshot_id: String,
+}
+
+impl SnapshotCollabService {
+  fn get_encoded_collab(&self, object_id: &str, collab_type: CollabType) -> Option<EncodedCollab> {
+    if object_id == self.database_id {
+      return Some(self.database_snapshot.clone());
+    }
+    if matches!(collab_type, CollabType::DatabaseRow) {
+      if let Some(row_snapshot) = self.get_row_snapshot(object_id) {
+        return Some(row_snapshot);
+      }
+    }
+    self.persistence.get_encoded_collab(object_id, collab_type)
+  }
+
+  fn get_row_snapshot(&self, row_id: &str) -> Option<EncodedCollab> {
+    let snapshot = self
+      .snapshot_service
+      .get_row_snapshot(row_id, &self.snapshot_id)
+      .ok()??;
+    EncodedCollab::decode_from_bytes(&snapshot.encoded_v1).ok()
+  }
+}
+
+#[async_trait]
+impl DatabaseCollabService for SnapshotCollabService {
+  async fn build_collab(
+    &self,
+    object_id: &str,
+    collab_type: CollabType,
+    encoded_collab: Option<(EncodedCollab, bool)>,
+  ) -> Result<Collab, DatabaseError> {
+    let encoded_collab = self
+      .get_encoded_collab(object_id, collab_type)
+      .or(encoded_collab.map(|(encoded_collab, _)| encoded_collab))
+      .ok_or(DatabaseError::RecordNotFound)?;
+    let mut collab = CollabBuilder::new(self.uid, object_id, DataSource::from(encoded_collab))
+      .build()
+      .map_err(|err| DatabaseError::Internal(err.into()))?;
+    collab.initialize();
+    Ok(collab)
+  }
+
+  async fn get_collabs(
+    &self,
+    object_ids: Vec<String>,
+    collab_type: CollabType,
+  ) -> Result<EncodeCollabByOid, DatabaseError> {
+    let mut encoded_collab_by_id = EncodeCollabByOid::new();
+    for object_id in object_ids {
+      if let Some(encoded_collab) = self.get_encoded_collab(&object_id, collab_type.clone()) {
+        encoded_collab_by_id.insert(object_id, encoded_collab);
+      }
+    }
+    Ok(encoded_collab_by_id)
+  }
+
+  fn persistence(&self) -> Option<Arc<dyn DatabaseCollabPersistenceService>> {
+    None
+  }
+}
+
 pub struct DatabasePersistenceImpl {
   user: Arc<dyn DatabaseUser>,
 }
@@ -52,6 +52,35 @@ pub enum DatabaseNotification {
   DidUpdateFieldSettings = 86,
   // Trigger when Calculation changed
   DidUpdateCalculation = 87,
+  // Trigger when the cells that are being edited by the other collaborators are changed
+  DidUpdateCellEditingStates = 88,
+  // Trigger when the local data of the database is corrupted and it is opened in safe mode
+  DidDetectDatabaseCorruption = 89,
+  /// Trigger after updating a batch of cells, with all the updated cells
+  DidUpdateCells = 90,
+  /// Trigger after the filters are applied, with the number of visible rows and the total number
+  /// of rows of the view
+  DidUpdateViewRowCount = 91,
+  /// Trigger after updating the row color rules of a view
+  DidUpdateRowColorRules = 92,
+  /// Trigger after changing the default view or the display order of the views of a database.
+  /// The notification is sent with the database id, so the folder can update its view list.
+  DidUpdateDatabaseViews = 93,
+  /// Trigger when an automation rule with a notify action fires. The notification is sent with
+  /// the database id.
+  DidTriggerAutomation = 94,
+  /// Trigger after updating the automation rules of a database. The notification is sent with
+  /// the database id.
+  DidUpdateAutomationRules = 95,
+  /// Trigger after adding or deleting the comments of a row. The notification is sent with the
+  /// row id.
+  DidUpdateRowComments = 96,
+  /// Trigger after updating the row templates of a database. The notification is sent with the
+  /// database id.
+  DidUpdateRowTemplates = 97,
+  /// Trigger after rows are moved to, restored from or purged from the trash of a database. The
+  /// notification is sent with the database id.
+  DidUpdateRowTrash = 98,
 }
 
 impl std::convert::From<DatabaseNotification> for i32 {
@@ -84,6 +113,17 @@ impl std::convert::From<i32> for DatabaseNotification {
       84 => DatabaseNotification::DidMoveDatabaseViewToTrash,
       86 => DatabaseNotification::DidUpdateFieldSettings,
       87 => DatabaseNotification::DidUpdateCalculation,
+      88 => DatabaseNotification::DidUpdateCellEditingStates,
+      89 => DatabaseNotification::DidDetectDatabaseCorruption,
+      90 => DatabaseNotification::DidUpdateCells,
+      91 => DatabaseNotification::DidUpdateViewRowCount,
+      92 => DatabaseNotification::DidUpdateRowColorRules,
+      93 => DatabaseNotification::DidUpdateDatabaseViews,
+      94 => DatabaseNotification::DidTriggerAutomation,
+      95 => DatabaseNotification::DidUpdateAutomationRules,
+      96 => DatabaseNotification::DidUpdateRowComments,
+      97 => DatabaseNotification::DidUpdateRowTemplates,
+      98 => DatabaseNotification::DidUpdateRowTrash,
       _ => DatabaseNotification::Unknown,
     }
   }
@@ -0,0 +1,57 @@
+mod rule;
+
+pub use rule::*;
+
+use std::sync::{Arc, Mutex, Weak};
+
+use flowy_config::feature_flag::{FeatureFlag, FeatureFlags};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+/// A row that an automation rule creates in the database of another view. The database editor
+/// that runs the rule can't open other databases, so the row is created by the manager.
+#[derive(Debug, Clone)]
+pub struct AutomationRowRequest {
+  pub view_id: String,
+  pub cells: Vec<AutomationCellValue>,
+}
+
+/// The queue of the rows that the automation rules create in other databases, shared by the
+/// manager and every database editor it opens. The manager takes the receiver when it starts
+/// running the automations, see [crate::DatabaseManager::start_automations].
+///
+/// The automations ship behind [FeatureFlag::Automations]: while the flag is off, the rules
+/// can't be changed and don't run.
+#[derive(Clone)]
+pub struct AutomationQueue {
+  tx: UnboundedSender<AutomationRowRequest>,
+  rx: Arc<Mutex<Option<UnboundedReceiver<AutomationRowRequest>>>>,
+  feature_flags: Weak<FeatureFlags>,
+}
+
+impl AutomationQueue {
+  pub fn new(feature_flags: Weak<FeatureFlags>) -> Self {
+    let (tx, rx) = unbounded_channel();
+    Self {
+      tx,
+      rx: Arc::new(Mutex::new(Some(rx))),
+      feature_flags,
+    }
+  }
+
+  /// Returns true if [FeatureFlag::Automations] is enabled in the current workspace.
+  pub fn is_enabled(&self) -> bool {
+    self
+      .feature_flags
+      .upgrade()
+      .is_some_and(|flags| flags.is_enabled(FeatureFlag::Automations))
+  }
+
+  pub fn push(&self, request: AutomationRowRequest) {
+    let _ = self.tx.send(request);
+  }
+
+  /// Returns None if the receiver was already taken.
+  pub(crate) fn take_receiver(&self) -> Option<UnboundedReceiver<AutomationRowRequest>> {
+    self.rx.lock().ok().and_then(|mut rx| rx.take())
+  }
+}
@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use anyhow::bail;
+use collab::preclude::Any;
+use collab::util::AnyMapExt;
+use collab_database::fields::Field;
+use collab_database::rows::Row;
+use collab_database::views::{FilterMap, FilterMapBuilder, LayoutSetting};
+use serde::{Deserialize, Serialize};
+use tracing::error;
+
+use crate::services::cell::stringify_cell;
+use crate::services::field::{should_mask_field, MASKED_CELL_VALUE};
+use crate::services::filter::{Filter, FilterInner};
+use crate::services::share::csv::row_cell;
+
+const AUTOMATION_RULES: &str = "automation_rules";
+const RULE_ID: &str = "id";
+const RULE_NAME: &str = "name";
+const RULE_IS_ENABLED: &str = "is_enabled";
+const RULE_TRIGGER: &str = "trigger";
+const RULE_DATE_FIELD_ID: &str = "date_field_id";
+const RULE_ACTIONS: &str = "actions";
+const RULE_CHECKED_AT: &str = "checked_at";
+
+const TRIGGER_CELL_MATCHES: i64 = 0;
+const TRIGGER_ROW_CREATED: i64 = 1;
+const TRIGGER_DATE_REACHED: i64 = 2;
+
+/// The automation rules of a database. Each rule runs its actions, in order, when its trigger
+/// fires for a row.
+///
+/// The rules belong to the database rather than to one of its views, so they're stored in the
+/// grid layout settings of the inline view, next to the views setting of the database.
+#[derive(Debug, Default, Clone)]
+pub struct AutomationSetting {
+  pub rules: Vec<AutomationRule>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AutomationRule {
+  pub id: String,
+  pub name: String,
+  pub is_enabled: bool,
+  pub trigger: AutomationTrigger,
+  pub actions: Vec<AutomationAction>,
+  /// The time, in seconds, up to which the dates of a [AutomationTrigger::DateReached] rule were
+  /// checked when the rule last fired.
+  pub checked_at: i64,
+}
+
+#[derive(Debug, Clone)]
+pub enum AutomationTrigger {
+  /// Fires when a row starts matching the condition, either when it's created or when one of its
+  /// cells is edited. The condition is a data filter, so a rule supports the same conditions as
+  /// the filters of a view.
+  CellMatches(Filter),
+  RowCreated,
+  /// Fires when the date of the row in the date field is reached.
+  DateReached {
+    field_id: String,
+  },
+}
+
+/// An action of a rule. The values and the message can refer to the cells of the row that fired
+/// the rule with `{{Field name}}`, see [render_template].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AutomationAction {
+  /// Sets the cell of the row that fired the rule. The value is converted to the type of the
+  /// field like a value of an appended CSV file, an empty value clears the cell.
+  SetCell { field_id: String, value: String },
+  /// Creates a row in the database of another view. The cells are matched to the fields of that
+  /// view by name.
+  CreateRow {
+    view_id: String,
+    cells: Vec<AutomationCellValue>,
+  },
+  /// Sends the message to the app, see [DatabaseNotification::DidTriggerAutomation].
+  ///
+  /// [DatabaseNotification::DidTriggerAutomation]: crate::notification::DatabaseNotification::DidTriggerAutomation
+  Notify { message: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutomationCellValue {
+  pub field_name: String,
+  pub value: String,
+}
+
+impl AutomationRule {
+  /// Returns the ids of the fields the rule refers to, except for the fields of the other
+  /// databases.
+  pub fn field_ids(&self) -> Vec<&str> {
+    let mut field_ids = vec![];
+    match &self.trigger {
+      AutomationTrigger::CellMatches(condition) => {
+        if let FilterInner::Data { field_id, .. } = &condition.inner {
+          field_ids.push(field_id.as_str());
+        }
+      },
+      AutomationTrigger::RowCreated => {},
+      AutomationTrigger::DateReached { field_id } => field_ids.push(field_id.as_str()),
+    }
+    for action in &self.actions {
+      if let AutomationAction::SetCell { field_id, .. } = action {
+        field_ids.push(field_id.as_str());
+      }
+    }
+    field_ids
+  }
+}
+
+impl TryFrom<FilterMap> for AutomationRule {
+  type Error = anyhow::Error;
+
+  fn try_from(value: FilterMap) -> Result<Self, Self::Error> {
+    let trigger = match value.get_as::<i64>(RULE_TRIGGER) {
+      Some(TRIGGER_CELL_MATCHES) => {
+        let condition = Filter::try_from(value.clone())?;
+        if !matches!(condition.inner, FilterInner::Data { .. }) {
+          bail!("The condition of an automation rule must be a data filter")
+        }
+        AutomationTrigger::CellMatches(condition)
+      },
+      Some(TRIGGER_ROW_CREATED) => AutomationTrigger::RowCreated,
+      Some(TRIGGER_DATE_REACHED) => AutomationTrigger::DateReached {
+        field_id: value
+          .get_as::<String>(RULE_DATE_FIELD_ID)
+          .unwrap_or_default(),
+      },
+      trigger => bail!("Unsupported automation trigger: {:?}", trigger),
+    };
+    let actions = serde_json::from_str(
+      &value
+        .get_as::<String>(RULE_ACTIONS)
+        .unwrap_or_else(|| "[]".to_string()),
+    )?;
+    Ok(Self {
+      id: value.get_as::<String>(RULE_ID).unwrap_or_default(),
+      name: value.get_as::<String>(RULE_NAME).unwrap_or_default(),
+      is_enabled: !matches!(value.get(RULE_IS_ENABLED), Some(Any::Bool(false))),
+      trigger,
+      actions,
+      checked_at: value.get_as::<i64>(RULE_CHECKED_AT).unwrap_or_default(),
+    })
+  }
+}
+
+impl<'a> From<&'a AutomationRule> for FilterMap {
+  fn from(rule: &'a AutomationRule) -> Self {
+    // The condition of the rule is stored with the id of the rule, so the filter map of the
+    // condition is the map of the rule.
+    let (mut map, trigger) = match &rule.trigger {
+      AutomationTrigger::CellMatches(condition) => {
+        let condition = Filter {
+          id: rule.id.clone(),
+          inner: condition.inner.clone(),
+        };
+        (FilterMap::from(&condition), TRIGGER_CELL_MATCHES)
+      },
+      AutomationTrigger::RowCreated => (FilterMapBuilder::new(), TRIGGER_ROW_CREATED),
+      AutomationTrigger::DateReached { field_id } => (
+        FilterMapBuilder::from([(RULE_DATE_FIELD_ID.into(), field_id.as_str().into())]),
+        TRIGGER_DATE_REACHED,
+      ),
+    };
+    let actions = serde_json::to_string(&rule.actions).unwrap_or_else(|_| "[]".to_string());
+    map.insert(RULE_ID.into(), rule.id.as_str().into());
+    map.insert(RULE_NAME.into(), rule.name.as_str().into());
+    map.insert(RULE_IS_ENABLED.into(), Any::Bool(rule.is_enabled));
+    map.insert(RULE_TRIGGER.into(), Any::BigInt(trigger));
+    map.insert(RULE_ACTIONS.into(), actions.into());
+    map.insert(RULE_CHECKED_AT.into(), Any::BigInt(rule.checked_at));
+    map
+  }
+}
+
+impl AutomationSetting {
+  /// Writes the setting into the given layout setting, keeping the other keys of it.
+  pub fn write_to(&self, setting: &mut LayoutSetting) {
+    let rules = self
+      .rules
+      .iter()
+      .map(|rule| FilterMap::from(rule).into())
+      .collect::<Vec<Any>>();
+    setting.insert(AUTOMATION_RULES.into(), Any::from(rules));
+  }
+}
+
+impl From<LayoutSetting> for AutomationSetting {
+  fn from(setting: LayoutSetting) -> Self {
+    let mut rules = vec![];
+    if let Any::Map(setting) = Any::from(setting) {
+      if let Some(Any::Array(items)) = setting.get(AUTOMATION_RULES) {
+        for item in items.iter() {
+          if let Any::Map(rule_map) = item {
+            match AutomationRule::try_from(rule_map.deref().clone()) {
+              Ok(rule) => rules.push(rule),
+              Err(err) => error!("Failed to deserialize automation rule: {:?}", err),
+            }
+          }
+        }
+      }
+    }
+    Self { rules }
+  }
+}
+
+/// Replaces each `{{Field name}}` in the template with the cell of the row in that field. The
+/// cells of the sensitive fields are masked, and the names that don't match any field are kept
+/// as they are.
+pub fn render_template(template: &str, row: &Row, fields: &[Field]) -> String {
+  let fields_by_name = fields
+    .iter()
+    .map(|field| (field.name.as_str(), field))
+    .collect::<HashMap<_, _>>();
+  let mut rendered = String::with_capacity(template.len());
+  let mut rest = template;
+  while let Some(start) = rest.find("{{") {
+    let Some(len) = rest[start + 2..].find("}}") else {
+      break;
+    };
+    rendered.push_str(&rest[..start]);
+    let name = &rest[start + 2..start + 2 + len];
+    match fields_by_name.get(name.trim()) {
+      Some(field) if should_mask_field(field, false) => rendered.push_str(MASKED_CELL_VALUE),
+      Some(field) => {
+        if let Some(cell) = row_cell(row, field) {
+          rendered.push_str(&stringify_cell(&cell, field));
+        }
+      },
+      None => rendered.push_str(&rest[start..start + len + 4]),
+    }
+    rest = &rest[start + len + 4..];
+  }
+  rendered.push_str(rest);
+  rendered
+}
+
+#[cfg(test)]
+mod tests {
+  use collab_database::database::gen_row_id;
+
+  use super::*;
+  use crate::entities::FieldType;
+  use crate::services::cell::insert_text_cell;
+  use crate::services::field::FieldBuilder;
+
+  #[test]
+  fn render_template_test() {
+    let field = FieldBuilder::from_field_type(FieldType::RichText)
+      .name("Name")
+      .build();
+    let mut row = Row::new(gen_row_id(), "database");
+    row.cells.insert(
+      field.id.clone(),
+      insert_text_cell("Launch".to_string(), &field),
+    );
+
+    assert_eq!(
+      render_template("{{ Name }} is due, see {{Missing}} {{", &row, &[field]),
+      "Launch is due, see {{Missing}} {{"
+    );
+  }
+
+  #[test]
+  fn actions_round_trip_test() {
+    let rule = AutomationRule {
+      id: "rule".to_string(),
+      name: "Done".to_string(),
+      is_enabled: false,
+      trigger: AutomationTrigger::DateReached {
+        field_id: "date".to_string(),
+      },
+      actions: vec![
+        AutomationAction::SetCell {
+          field_id: "status".to_string(),
+          value: "Done".to_string(),
+        },
+        AutomationAction::Notify {
+          message: "{{Name}} is done".to_string(),
+        },
+      ],
+      checked_at: 10,
+    };
+    let decoded = AutomationRule::try_from(FilterMap::from(&rule)).unwrap();
+    assert_eq!(decoded.id, rule.id);
+    assert!(!decoded.is_enabled);
+    assert_eq!(decoded.actions, rule.actions);
+    assert_eq!(decoded.checked_at, 10);
+    assert!(
+      matches!(decoded.trigger, AutomationTrigger::DateReached { field_id } if field_id == "date")
+    );
+  }
+}
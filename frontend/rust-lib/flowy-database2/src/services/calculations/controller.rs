@@ -110,11 +110,7 @@ impl CalculationsController {
           .handle_field_type_changed(field_id, new_field_type)
           .await
       },
-      CalculationEvent::InitialRows(rows) => {
-        for row in rows {
-          self.handle_row_changed(row.as_ref()).await;
-        }
-      },
+      CalculationEvent::InitialRows(_) => self.handle_all_calculations_changed().await,
     }
 
     Ok(())
@@ -285,6 +281,29 @@ impl CalculationsController {
     }
   }
 
+  /// Recalculates every calculation of the view once, instead of once for each row, e.g. after
+  /// the filters of the view are changed.
+  async fn handle_all_calculations_changed(&self) {
+    let mut updates = vec![];
+    let calculations = self.delegate.get_all_calculations(&self.view_id).await;
+    for calculation in calculations.into_iter() {
+      let cells = self
+        .delegate
+        .get_cells_for_field(&self.view_id, &calculation.field_id)
+        .await;
+      updates.extend(self.handle_cells_changed(calculation, cells).await);
+    }
+
+    if !updates.is_empty() {
+      let notification = CalculationChangesetNotificationPB::from_update(&self.view_id, updates);
+      let _ = self
+        .notifier
+        .send(DatabaseViewChanged::CalculationValueNotification(
+          notification,
+        ));
+    }
+  }
+
   async fn get_or_fetch_cells<'a>(
     &'a self,
     field_id: &'a str,
@@ -4,7 +4,7 @@ use collab_database::fields::Field;
 use collab_database::rows::Cell;
 
 use crate::entities::CalculationType;
-use crate::services::field::TypeOptionCellExt;
+use crate::services::field::{NumberUnit, TypeOptionCellExt};
 use rayon::prelude::*;
 
 pub struct CalculationsService;
@@ -16,12 +16,14 @@ impl CalculationsService {
   pub fn calculate(&self, field: &Field, calculation_type: i64, cells: Vec<Arc<Cell>>) -> String {
     let ty: CalculationType = calculation_type.into();
 
+    // The numeric results carry the unit of the number field, e.g. "42 kg"
+    let number_unit = NumberUnit::from_field(field);
     match ty {
-      CalculationType::Average => self.calculate_average(field, cells),
-      CalculationType::Max => self.calculate_max(field, cells),
-      CalculationType::Median => self.calculate_median(field, cells),
-      CalculationType::Min => self.calculate_min(field, cells),
-      CalculationType::Sum => self.calculate_sum(field, cells),
+      CalculationType::Average => number_unit.format(&self.calculate_average(field, cells)),
+      CalculationType::Max => number_unit.format(&self.calculate_max(field, cells)),
+      CalculationType::Median => number_unit.format(&self.calculate_median(field, cells)),
+      CalculationType::Min => number_unit.format(&self.calculate_min(field, cells)),
+      CalculationType::Sum => number_unit.format(&self.calculate_sum(field, cells)),
       CalculationType::Count => self.calculate_count(cells),
       CalculationType::CountEmpty => self.calculate_count_empty(field, cells),
       CalculationType::CountNonEmpty => self.calculate_count_non_empty(field, cells),
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::str::FromStr;
 
+use collab::util::AnyMapExt;
 use collab_database::fields::media_type_option::MediaCellData;
 use collab_database::fields::select_type_option::SelectOptionIds;
 use collab_database::fields::Field;
@@ -89,9 +90,15 @@ pub fn apply_cell_changeset(
   field: &Field,
   cell_data_cache: Option<CellCache>,
 ) -> Result<Cell, FlowyError> {
+  let changeset = apply_text_delta(changeset, cell.as_ref(), field)?;
+  let changeset = strip_display_format(changeset, field);
   match TypeOptionCellExt::new(field, cell_data_cache).get_type_option_cell_data_handler() {
     None => Ok(Cell::default()),
-    Some(handler) => Ok(handler.handle_cell_changeset(changeset, cell, field)?),
+    Some(handler) => {
+      let cell = handler.handle_cell_changeset(changeset, cell, field)?;
+      validate_cell_with_attributes(&cell, field)?;
+      Ok(cell)
+    },
   }
 }
 
@@ -112,11 +119,40 @@ pub fn get_cell_protobuf(
   field: &Field,
   cell_data_cache: Option<CellCache>,
 ) -> CellProtobufBlob {
-  match TypeOptionCellExt::new(field, cell_data_cache).get_type_option_cell_data_handler() {
-    None => CellProtobufBlob::default(),
-    Some(handler) => handler
-      .handle_get_protobuf_cell_data(cell, field)
-      .unwrap_or_default(),
+  let cell_bytes =
+    match TypeOptionCellExt::new(field, cell_data_cache).get_type_option_cell_data_handler() {
+      None => CellProtobufBlob::default(),
+      Some(handler) => handler
+        .handle_get_protobuf_cell_data(cell, field)
+        .unwrap_or_default(),
+    };
+
+  if FieldType::from(field.field_type).is_url() {
+    return with_link_preview(cell_bytes, cell);
+  }
+
+  let number_unit = NumberUnit::from_field(field);
+  if number_unit.is_empty() {
+    cell_bytes
+  } else {
+    CellProtobufBlob::new(number_unit.format(&cell_bytes.to_string()))
+  }
+}
+
+/// The link preview is stored in the cell next to the url, so it's not part of the
+/// [URLCellData](collab_database::fields::url_type_option::URLCellData).
+fn with_link_preview(cell_bytes: CellProtobufBlob, cell: &Cell) -> CellProtobufBlob {
+  let preview = link_preview_from_cell(cell);
+  if preview.is_empty() {
+    return cell_bytes;
+  }
+  match cell_bytes.parser::<URLCellDataParser>() {
+    Ok(mut url_cell_data) => {
+      url_cell_data.title = preview.title;
+      url_cell_data.favicon = preview.favicon;
+      CellProtobufBlob::from(url_cell_data).unwrap_or(cell_bytes)
+    },
+    Err(_) => cell_bytes,
   }
 }
 
@@ -130,15 +166,74 @@ pub fn get_cell_protobuf(
 ///
 pub fn stringify_cell(cell: &Cell, field: &Field) -> String {
   if let Some(field_type_of_cell) = get_field_type_from_cell::<FieldType>(cell) {
-    TypeOptionCellExt::new(field, None)
+    let s = TypeOptionCellExt::new(field, None)
       .get_type_option_cell_data_handler_with_field_type(field_type_of_cell)
       .map(|handler| handler.handle_stringify_cell(cell, field))
-      .unwrap_or_default()
+      .unwrap_or_default();
+    match FieldType::from(field.field_type) {
+      FieldType::Checkbox => CheckboxOptions::from_field(field).label(s == CHECK),
+      _ => NumberUnit::from_field(field).format(&s),
+    }
   } else {
     "".to_string()
   }
 }
 
+/// The stored value of a cell along with its display string, so the clients and the exporters
+/// don't need to format the value themselves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CellDisplay {
+  /// The value as it's stored, e.g. `1200.5` for a number or the ids of the selected options.
+  pub value: String,
+  /// The value formatted by the field, e.g. `$1,200.50`. See [stringify_cell].
+  pub display: String,
+}
+
+pub fn get_cell_display(cell: &Cell, field: &Field) -> CellDisplay {
+  CellDisplay {
+    value: cell.get_as::<String>(CELL_DATA).unwrap_or_default(),
+    display: stringify_cell(cell, field),
+  }
+}
+
+/// A [TextDelta] is applied to the current content of the cell, after being transformed against
+/// the changes made since its base content, so the text type option receives the whole new
+/// content like any other text changeset.
+fn apply_text_delta(changeset: BoxAny, cell: Option<&Cell>, field: &Field) -> FlowyResult<BoxAny> {
+  let delta = match changeset.downcast_ref::<TextDelta>() {
+    Some(delta) if FieldType::from(field.field_type).is_text() => delta,
+    _ => return Ok(changeset),
+  };
+  let content = cell
+    .map(|cell| stringify_cell(cell, field))
+    .unwrap_or_default();
+  Ok(BoxAny::new(delta.apply_to_current(&content)?))
+}
+
+/// The cells are stored without the display format of the field, so `42 kg` is saved as `42`
+/// and a custom checkbox label is saved as [CHECK] or [UNCHECK].
+fn strip_display_format(changeset: BoxAny, field: &Field) -> BoxAny {
+  let s = match changeset.downcast_ref::<String>() {
+    Some(s) => s.clone(),
+    None => return changeset,
+  };
+  match FieldType::from(field.field_type) {
+    FieldType::Number => {
+      let number_unit = NumberUnit::from_field(field);
+      if number_unit.is_empty() {
+        changeset
+      } else {
+        BoxAny::new(number_unit.strip(&s))
+      }
+    },
+    FieldType::Checkbox => match CheckboxOptions::from_field(field).unlabel(&s) {
+      Some(value) => BoxAny::new(value.to_string()),
+      None => changeset,
+    },
+    _ => changeset,
+  }
+}
+
 pub fn insert_text_cell(s: String, field: &Field) -> Cell {
   apply_cell_changeset(BoxAny::new(s), None, field, None).unwrap()
 }
@@ -0,0 +1,48 @@
+use std::sync::{Arc, RwLock};
+
+use collab_database::fields::Field;
+use collab_database::rows::{Cell, Row};
+use flowy_error::FlowyResult;
+
+/// Validates a cell before it is written. The validators are installed by the embedder with
+/// [crate::manager::DatabaseManager::add_cell_validator] to enforce the rules that the type
+/// options don't know about, e.g. a rule that depends on the other cells of the row.
+///
+/// The built-in constraints of the field, e.g. the validation pattern of a text field, are
+/// checked before the validators.
+pub trait CellValidator: Send + Sync {
+  /// Returns an error to reject the new cell. `row` is the row before the update.
+  fn validate_cell(&self, row: &Row, field: &Field, new_cell: &Cell) -> FlowyResult<()>;
+}
+
+/// The validators shared by the manager and every database editor it opens.
+#[derive(Clone, Default)]
+pub struct CellValidators(Arc<RwLock<Vec<Arc<dyn CellValidator>>>>);
+
+impl CellValidators {
+  pub fn add(&self, validator: Arc<dyn CellValidator>) {
+    if let Ok(mut validators) = self.0.write() {
+      validators.push(validator);
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self
+      .0
+      .read()
+      .map(|validators| validators.is_empty())
+      .unwrap_or(true)
+  }
+
+  /// Runs the validators in the order they were added and returns the first error.
+  pub fn validate_cell(&self, row: &Row, field: &Field, new_cell: &Cell) -> FlowyResult<()> {
+    let validators = match self.0.read() {
+      Ok(validators) => validators.clone(),
+      Err(_) => return Ok(()),
+    };
+    for validator in validators {
+      validator.validate_cell(row, field, new_cell)?;
+    }
+    Ok(())
+  }
+}
@@ -1,7 +1,11 @@
 mod cell_data_cache;
 mod cell_operation;
+mod cell_validator;
 mod type_cell_data;
+mod typed_cell_changeset;
 
 pub use cell_data_cache::*;
 pub use cell_operation::*;
+pub use cell_validator::*;
 pub use type_cell_data::*;
+pub use typed_cell_changeset::*;
@@ -0,0 +1,145 @@
+use collab_database::fields::select_type_option::SelectOptionIds;
+use collab_database::fields::Field;
+use collab_database::rows::Cell;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use lib_infra::box_any::BoxAny;
+use rust_decimal::Decimal;
+
+use crate::entities::{CheckboxCellDataPB, FieldType};
+use crate::services::cell::{apply_cell_changeset, CellCache};
+use crate::services::field::{
+  validate_cell_with_attributes, DateCellChangeset, NumberCellData, SelectOptionCellChangeset,
+};
+
+/// The new data of a cell, typed by the field type it's meant for. Unlike the string of
+/// [CellChangesetPB](crate::entities::CellChangesetPB), it doesn't depend on the format of the
+/// field, e.g. the currency symbol of a number or the date format of a date.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypedCellChangeset {
+  Text(String),
+  Number(Decimal),
+  Checkbox(bool),
+  /// The timestamp in seconds.
+  Date(i64),
+  /// The ids of all the selected options. The options that are not in the list are unselected.
+  SelectOptionIds(Vec<String>),
+  URL(String),
+}
+
+impl TypedCellChangeset {
+  /// Returns true if the changeset can be applied to the cells of the given field type.
+  pub fn is_compatible_with(&self, field_type: FieldType) -> bool {
+    matches!(
+      (self, field_type),
+      (Self::Text(_), FieldType::RichText)
+        | (Self::Number(_), FieldType::Number)
+        | (Self::Checkbox(_), FieldType::Checkbox)
+        | (Self::Date(_), FieldType::DateTime)
+        | (
+          Self::SelectOptionIds(_),
+          FieldType::SingleSelect | FieldType::MultiSelect
+        )
+        | (Self::URL(_), FieldType::URL)
+    )
+  }
+
+  /// Returns the new cell of the field. The number and the checkbox are stored as they are, they
+  /// aren't formatted to a string and parsed back by the type option, so neither the number
+  /// format nor the custom labels of the field can change them. The other data is passed to the
+  /// type option of the field as its typed changeset, e.g. a [DateCellChangeset].
+  ///
+  /// # Arguments
+  ///
+  /// * `field`: the field of the cell. Returns an error if the changeset doesn't match its type.
+  /// * `cell`: the current cell, used to unselect the options that are not in the changeset.
+  ///
+  pub fn into_cell(
+    self,
+    field: &Field,
+    cell: Option<Cell>,
+    cell_data_cache: Option<CellCache>,
+  ) -> FlowyResult<Cell> {
+    let field_type = FieldType::from(field.field_type);
+    if !self.is_compatible_with(field_type) {
+      return Err(FlowyError::new(
+        ErrorCode::CellDataTypeMismatch,
+        format!("{:?} can't be applied to a {:?} field", self, field_type),
+      ));
+    }
+
+    let changeset = match self {
+      Self::Number(decimal) => {
+        let new_cell = NumberCellData(decimal.normalize().to_string()).into();
+        validate_cell_with_attributes(&new_cell, field)?;
+        return Ok(new_cell);
+      },
+      Self::Checkbox(is_checked) => {
+        let new_cell = CheckboxCellDataPB::new(is_checked).into();
+        validate_cell_with_attributes(&new_cell, field)?;
+        return Ok(new_cell);
+      },
+      Self::Text(s) | Self::URL(s) => BoxAny::new(s),
+      Self::Date(timestamp) => BoxAny::new(DateCellChangeset {
+        timestamp: Some(timestamp),
+        ..Default::default()
+      }),
+      Self::SelectOptionIds(option_ids) => {
+        let delete_option_ids = cell
+          .as_ref()
+          .map(SelectOptionIds::from)
+          .map(|ids| {
+            ids
+              .iter()
+              .filter(|id| !option_ids.contains(*id))
+              .cloned()
+              .collect()
+          })
+          .unwrap_or_default();
+        BoxAny::new(SelectOptionCellChangeset {
+          insert_option_ids: option_ids,
+          delete_option_ids,
+        })
+      },
+    };
+    apply_cell_changeset(changeset, cell, field, cell_data_cache)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::str::FromStr;
+
+  use super::*;
+  use crate::services::field::FieldBuilder;
+
+  #[test]
+  fn typed_cell_changeset_compatibility_test() {
+    let number = TypedCellChangeset::Number(Decimal::from_str("12.50").unwrap());
+    assert!(number.is_compatible_with(FieldType::Number));
+    assert!(!number.is_compatible_with(FieldType::RichText));
+
+    let option_ids = TypedCellChangeset::SelectOptionIds(vec!["a".to_string()]);
+    assert!(option_ids.is_compatible_with(FieldType::SingleSelect));
+    assert!(option_ids.is_compatible_with(FieldType::MultiSelect));
+    assert!(!option_ids.is_compatible_with(FieldType::Checklist));
+  }
+
+  #[test]
+  fn typed_cell_changeset_into_cell_test() {
+    let number_field = FieldBuilder::from_field_type(FieldType::Number).build();
+    let number = TypedCellChangeset::Number(Decimal::from_str("1200.50").unwrap());
+    let cell = number.into_cell(&number_field, None, None).unwrap();
+    assert_eq!(NumberCellData::from(&cell).0, "1200.5");
+
+    let checkbox_field = FieldBuilder::from_field_type(FieldType::Checkbox).build();
+    let cell = TypedCellChangeset::Checkbox(true)
+      .into_cell(&checkbox_field, None, None)
+      .unwrap();
+    assert!(CheckboxCellDataPB::from(&cell).is_checked);
+
+    let error = TypedCellChangeset::Text("12".to_string())
+      .into_cell(&number_field, None, None)
+      .unwrap_err();
+    assert_eq!(error.code, ErrorCode::CellDataTypeMismatch);
+  }
+}
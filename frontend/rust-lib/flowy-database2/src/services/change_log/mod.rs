@@ -0,0 +1,384 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, RwLock};
+
+use chrono::Utc;
+
+use crate::services::webhook::WebhookEventType;
+
+/// The maximum number of changes kept for each database. The oldest ones are dropped first.
+const MAX_CHANGES_PER_DATABASE: usize = 10_000;
+
+/// A change of a row or a field, recorded at a revision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeEntry {
+  pub rev_id: i64,
+  pub event: WebhookEventType,
+  /// The id of the row or of the field, depending on the event.
+  pub object_id: String,
+}
+
+/// The rows and fields that changed after a revision. Each row or field is listed once, under the
+/// overall change since the revision: a row that was created and then updated is listed as
+/// created, and a row that was created and then deleted isn't listed at all.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangesSince {
+  /// The latest revision, to pass to the next call.
+  pub rev_id: i64,
+  /// True if the log doesn't cover all the changes since the revision, e.g. because the app was
+  /// restarted or the older changes were dropped. The database must be exported as a whole then.
+  pub is_full_export_required: bool,
+  pub created_row_ids: Vec<String>,
+  pub updated_row_ids: Vec<String>,
+  pub deleted_row_ids: Vec<String>,
+  pub created_field_ids: Vec<String>,
+  pub updated_field_ids: Vec<String>,
+  pub deleted_field_ids: Vec<String>,
+}
+
+struct DatabaseChangeLog {
+  /// Every change after this revision is in the log.
+  covered_since: i64,
+  entries: VecDeque<ChangeEntry>,
+}
+
+struct ChangeLogState {
+  started_at: i64,
+  last_rev_id: i64,
+  databases: HashMap<String, DatabaseChangeLog>,
+}
+
+impl ChangeLogState {
+  fn next_rev_id(&mut self) -> i64 {
+    self.last_rev_id = (self.last_rev_id + 1).max(Utc::now().timestamp_micros());
+    self.last_rev_id
+  }
+
+  fn database_log(&mut self, database_id: &str) -> &mut DatabaseChangeLog {
+    let started_at = self.started_at;
+    self
+      .databases
+      .entry(database_id.to_string())
+      .or_insert_with(|| DatabaseChangeLog {
+        covered_since: started_at,
+        entries: VecDeque::new(),
+      })
+  }
+
+  fn push<T: AsRef<str>>(&mut self, database_id: &str, event: WebhookEventType, object_ids: &[T]) {
+    let rev_id = self.next_rev_id();
+    let log = self.database_log(database_id);
+    for object_id in object_ids {
+      if log.entries.len() >= MAX_CHANGES_PER_DATABASE {
+        if let Some(entry) = log.entries.pop_front() {
+          log.covered_since = entry.rev_id;
+        }
+      }
+      log.entries.push_back(ChangeEntry {
+        rev_id,
+        event,
+        object_id: object_id.as_ref().to_string(),
+      });
+    }
+  }
+
+  fn push_merged(&mut self, database_id: &str, event: WebhookEventType, object_id: &str) {
+    let is_last_change = self
+      .databases
+      .get(database_id)
+      .and_then(|log| log.entries.back())
+      .map(|entry| entry.event == event && entry.object_id == object_id)
+      .unwrap_or(false);
+    if !is_last_change {
+      self.push(database_id, event, &[object_id]);
+      return;
+    }
+    let rev_id = self.next_rev_id();
+    if let Some(entry) = self
+      .databases
+      .get_mut(database_id)
+      .and_then(|log| log.entries.back_mut())
+    {
+      entry.rev_id = rev_id;
+    }
+  }
+
+  /// Returns true if the last change of the object in the log is its deletion.
+  fn is_deleted(&self, database_id: &str, object_id: &str) -> bool {
+    self
+      .databases
+      .get(database_id)
+      .and_then(|log| {
+        log
+          .entries
+          .iter()
+          .rev()
+          .find(|entry| entry.object_id == object_id)
+      })
+      .map(|entry| {
+        matches!(
+          entry.event,
+          WebhookEventType::RowDeleted | WebhookEventType::FieldDeleted
+        )
+      })
+      .unwrap_or(false)
+  }
+}
+
+/// The changes of the rows and fields of the databases, shared by the manager and every database
+/// editor it opens, so the external sync tools can poll for the changes since their last sync
+/// instead of exporting the whole database.
+///
+/// The editor records the local changes, and the observers of the opened databases record the
+/// cells and fields edited by the other collaborators, see [Self::record_observed]. The rows they
+/// insert or delete can't be listed, so they ask for a full export, see
+/// [Self::require_full_export].
+///
+/// The log isn't persisted, so it only has the changes seen while the app is running. The
+/// revisions are timestamps in microseconds that always increase, so a revision handed out before
+/// a restart is still older than the new ones, and the caller is asked for a full export instead
+/// of missing changes.
+#[derive(Clone)]
+pub struct ChangeLog {
+  state: Arc<RwLock<ChangeLogState>>,
+}
+
+impl Default for ChangeLog {
+  fn default() -> Self {
+    let now = Utc::now().timestamp_micros();
+    Self {
+      state: Arc::new(RwLock::new(ChangeLogState {
+        started_at: now,
+        last_rev_id: now,
+        databases: Default::default(),
+      })),
+    }
+  }
+}
+
+impl ChangeLog {
+  pub fn record(&self, database_id: &str, event: WebhookEventType, object_id: &str) {
+    self.record_all(database_id, event, &[object_id]);
+  }
+
+  /// Records the change like [Self::record], but moves the last change of the database to the new
+  /// revision instead if it's the same change of the same object, so the consecutive edits of a
+  /// cell are kept as one change. The callers that polled in between still see the object again.
+  pub fn record_merged(&self, database_id: &str, event: WebhookEventType, object_id: &str) {
+    if let Ok(mut state) = self.state.write() {
+      state.push_merged(database_id, event, object_id);
+    }
+  }
+
+  /// Records a change seen by the observers of the database. They see the local changes that the
+  /// editor recorded already as well as the remote ones, so the change is merged like
+  /// [Self::record_merged]. It's dropped if the object was deleted since, because the observers
+  /// lag behind the editor and the deletion must stay the last change of the object.
+  pub fn record_observed(&self, database_id: &str, event: WebhookEventType, object_id: &str) {
+    if let Ok(mut state) = self.state.write() {
+      if !state.is_deleted(database_id, object_id) {
+        state.push_merged(database_id, event, object_id);
+      }
+    }
+  }
+
+  /// Records the changes of many rows or fields at a single revision, e.g. the rows inserted in
+  /// bulk, so they're seen together by the callers of [Self::changes_since].
+  pub fn record_all<T: AsRef<str>>(
+    &self,
+    database_id: &str,
+    event: WebhookEventType,
+    object_ids: &[T],
+  ) {
+    if object_ids.is_empty() {
+      return;
+    }
+    if let Ok(mut state) = self.state.write() {
+      state.push(database_id, event, object_ids);
+    }
+  }
+
+  /// Drops the changes of the database and starts its log over at a new revision, so the callers
+  /// that polled before are asked for a full export. It's used for the changes the log can't
+  /// list, e.g. the rows inserted or deleted by the other collaborators.
+  pub fn require_full_export(&self, database_id: &str) {
+    if let Ok(mut state) = self.state.write() {
+      let rev_id = state.next_rev_id();
+      let log = state.database_log(database_id);
+      log.covered_since = rev_id;
+      log.entries.clear();
+    }
+  }
+
+  /// Returns the changes of the database after the revision. Pass 0 to get the latest revision
+  /// before exporting the database as a whole.
+  pub fn changes_since(&self, database_id: &str, rev_id: i64) -> ChangesSince {
+    let Ok(state) = self.state.read() else {
+      return ChangesSince {
+        is_full_export_required: true,
+        ..Default::default()
+      };
+    };
+    let mut changes = ChangesSince {
+      rev_id: state.last_rev_id,
+      ..Default::default()
+    };
+    let (covered_since, entries) = match state.databases.get(database_id) {
+      Some(log) => (log.covered_since, Some(&log.entries)),
+      None => (state.started_at, None),
+    };
+    if rev_id < covered_since || rev_id > state.last_rev_id {
+      changes.is_full_export_required = true;
+      return changes;
+    }
+
+    // The first and the last change of each object, in the order of their first change
+    let mut object_ids = vec![];
+    let mut events = HashMap::<&str, (WebhookEventType, WebhookEventType)>::new();
+    for entry in entries
+      .into_iter()
+      .flatten()
+      .filter(|entry| entry.rev_id > rev_id)
+    {
+      events
+        .entry(entry.object_id.as_str())
+        .and_modify(|(_, last)| *last = entry.event)
+        .or_insert_with(|| {
+          object_ids.push(entry.object_id.as_str());
+          (entry.event, entry.event)
+        });
+    }
+    for object_id in object_ids {
+      let (first, last) = events[object_id];
+      let ids = match (first, last) {
+        (WebhookEventType::RowCreated, WebhookEventType::RowDeleted)
+        | (WebhookEventType::FieldCreated, WebhookEventType::FieldDeleted) => continue,
+        (WebhookEventType::RowCreated, _) => &mut changes.created_row_ids,
+        (WebhookEventType::FieldCreated, _) => &mut changes.created_field_ids,
+        (_, WebhookEventType::RowDeleted) => &mut changes.deleted_row_ids,
+        (_, WebhookEventType::FieldDeleted) => &mut changes.deleted_field_ids,
+        (_, WebhookEventType::RowCreated | WebhookEventType::RowUpdated) => {
+          &mut changes.updated_row_ids
+        },
+        (_, WebhookEventType::FieldCreated | WebhookEventType::FieldUpdated) => {
+          &mut changes.updated_field_ids
+        },
+      };
+      ids.push(object_id.to_string());
+    }
+    changes
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn changes_since_test() {
+    let log = ChangeLog::default();
+    let changes = log.changes_since("db", 0);
+    assert!(changes.is_full_export_required);
+    let rev_id = changes.rev_id;
+
+    log.record("db", WebhookEventType::RowUpdated, "a");
+    log.record("db", WebhookEventType::RowCreated, "b");
+    log.record("db", WebhookEventType::RowUpdated, "b");
+    log.record("db", WebhookEventType::RowCreated, "c");
+    log.record("db", WebhookEventType::RowDeleted, "c");
+    log.record("db", WebhookEventType::RowUpdated, "d");
+    log.record("db", WebhookEventType::RowDeleted, "d");
+    log.record("db", WebhookEventType::FieldUpdated, "f");
+    log.record("other", WebhookEventType::RowDeleted, "e");
+
+    let changes = log.changes_since("db", rev_id);
+    assert!(!changes.is_full_export_required);
+    assert_eq!(changes.created_row_ids, vec!["b"]);
+    assert_eq!(changes.updated_row_ids, vec!["a"]);
+    assert_eq!(changes.deleted_row_ids, vec!["d"]);
+    assert_eq!(changes.updated_field_ids, vec!["f"]);
+
+    let changes = log.changes_since("db", changes.rev_id);
+    assert!(!changes.is_full_export_required);
+    assert!(changes.created_row_ids.is_empty() && changes.updated_field_ids.is_empty());
+
+    // The rows recorded together share a revision
+    log.record_all("db", WebhookEventType::RowCreated, &["g", "h"]);
+    let after_bulk = log.changes_since("db", changes.rev_id);
+    assert_eq!(after_bulk.created_row_ids, vec!["g", "h"]);
+    assert!(after_bulk.rev_id > changes.rev_id);
+    let changes = log.changes_since("db", after_bulk.rev_id);
+    assert!(changes.created_row_ids.is_empty() && changes.updated_field_ids.is_empty());
+    assert!(
+      log
+        .changes_since("db", changes.rev_id + 1)
+        .is_full_export_required
+    );
+  }
+
+  #[test]
+  fn record_merged_test() {
+    let log = ChangeLog::default();
+    let rev_id = log.changes_since("db", 0).rev_id;
+    log.record("db", WebhookEventType::RowUpdated, "a");
+    log.record_merged("db", WebhookEventType::RowUpdated, "a");
+    let changes = log.changes_since("db", rev_id);
+    assert_eq!(changes.updated_row_ids, vec!["a"]);
+    assert_eq!(log.state.read().unwrap().databases["db"].entries.len(), 1);
+
+    // The merged change is moved to a new revision, so it's seen again after the last poll
+    log.record_merged("db", WebhookEventType::RowUpdated, "a");
+    let merged = log.changes_since("db", changes.rev_id);
+    assert_eq!(merged.updated_row_ids, vec!["a"]);
+    assert!(merged.rev_id > changes.rev_id);
+
+    // Only the last change is merged
+    log.record_merged("db", WebhookEventType::RowUpdated, "b");
+    log.record_merged("db", WebhookEventType::RowUpdated, "a");
+    assert_eq!(log.state.read().unwrap().databases["db"].entries.len(), 3);
+  }
+
+  #[test]
+  fn record_observed_test() {
+    let log = ChangeLog::default();
+    let rev_id = log.changes_since("db", 0).rev_id;
+
+    // The local edit seen again by the observer is kept as one change
+    log.record_merged("db", WebhookEventType::RowUpdated, "a");
+    log.record_observed("db", WebhookEventType::RowUpdated, "a");
+    assert_eq!(log.state.read().unwrap().databases["db"].entries.len(), 1);
+
+    // A remote edit is recorded
+    log.record_observed("db", WebhookEventType::RowUpdated, "b");
+    log.record_observed("db", WebhookEventType::FieldUpdated, "f");
+    let changes = log.changes_since("db", rev_id);
+    assert_eq!(changes.updated_row_ids, vec!["a", "b"]);
+    assert_eq!(changes.updated_field_ids, vec!["f"]);
+
+    // The edit observed after the deletion doesn't hide it
+    log.record("db", WebhookEventType::RowDeleted, "a");
+    log.record_observed("db", WebhookEventType::RowUpdated, "a");
+    let changes = log.changes_since("db", changes.rev_id);
+    assert_eq!(changes.deleted_row_ids, vec!["a"]);
+    assert!(changes.updated_row_ids.is_empty());
+  }
+
+  #[test]
+  fn require_full_export_test() {
+    let log = ChangeLog::default();
+    let rev_id = log.changes_since("db", 0).rev_id;
+    log.record("db", WebhookEventType::RowUpdated, "a");
+    log.record("other", WebhookEventType::RowUpdated, "b");
+
+    log.require_full_export("db");
+    assert!(log.changes_since("db", rev_id).is_full_export_required);
+    assert!(!log.changes_since("other", rev_id).is_full_export_required);
+
+    // The callers that polled after it get the next changes
+    let changes = log.changes_since("db", 0);
+    assert!(changes.is_full_export_required);
+    log.record("db", WebhookEventType::RowUpdated, "c");
+    let changes = log.changes_since("db", changes.rev_id);
+    assert!(!changes.is_full_export_required);
+    assert_eq!(changes.updated_row_ids, vec!["c"]);
+  }
+}
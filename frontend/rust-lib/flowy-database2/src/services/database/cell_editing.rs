@@ -0,0 +1,160 @@
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// A cell that is being edited by a collaborator.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EditingCell {
+  pub view_id: String,
+  pub row_id: String,
+  pub field_id: String,
+}
+
+/// The state every client broadcasts over the awareness channel of the database collab. The
+/// state is only a hint for displaying typing indicators. It doesn't lock the cell, and the
+/// edits of the cell are still merged by the collab.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CellEditingState {
+  pub version: i64,
+  pub uid: i64,
+  /// None if the client is not editing any cell.
+  pub cell: Option<EditingCell>,
+  /// The time of the last broadcast, in seconds.
+  pub timestamp: i64,
+}
+
+impl CellEditingState {
+  /// A client that crashed or went offline can't clear its state, so the state of a client that
+  /// hasn't been refreshed for this long is ignored.
+  pub const EXPIRATION_SECS: i64 = 10;
+
+  pub fn is_expired(&self, now: i64) -> bool {
+    now - self.timestamp > Self::EXPIRATION_SECS
+  }
+}
+
+/// Limits how often the local state is broadcast while the user keeps typing in the same cell.
+/// Switching to another cell or stopping editing is always broadcast right away.
+#[derive(Debug, Default)]
+pub(crate) struct CellEditingThrottle {
+  last_broadcast: Option<(Option<EditingCell>, Instant)>,
+}
+
+impl CellEditingThrottle {
+  const INTERVAL: Duration = Duration::from_secs(2);
+
+  /// Returns true if the state should be broadcast. The state of the same cell is re-broadcast
+  /// once per interval, so it doesn't expire while the user is typing.
+  pub(crate) fn should_broadcast(&mut self, cell: &Option<EditingCell>) -> bool {
+    let now = Instant::now();
+    if let Some((last_cell, last_instant)) = &self.last_broadcast {
+      if last_cell == cell && now.duration_since(*last_instant) < Self::INTERVAL {
+        return false;
+      }
+    }
+    self.last_broadcast = Some((cell.clone(), now));
+    true
+  }
+}
+
+/// Tells whether a write of a cell follows a write of the same cell closely enough to be merged
+/// with it, e.g. while the user keeps typing in the cell. The merged writes share one change in
+/// the change log and one webhook, sent once the user pauses. The cell itself is still written to
+/// the collab every time, so the reads are never stale.
+#[derive(Debug, Default)]
+pub(crate) struct CellWriteCoalescer {
+  last_write: Option<(String, String, Instant)>,
+  num_of_writes: u64,
+}
+
+impl CellWriteCoalescer {
+  pub(crate) const WINDOW: Duration = Duration::from_secs(1);
+
+  /// Returns true if the write should be merged with the previous one. The window restarts at
+  /// every write, so a cell that is edited continuously is merged until the user pauses.
+  pub(crate) fn should_merge(&mut self, row_id: &str, field_id: &str) -> bool {
+    let now = Instant::now();
+    let should_merge = matches!(
+      &self.last_write,
+      Some((last_row_id, last_field_id, last_instant))
+        if last_row_id == row_id
+          && last_field_id == field_id
+          && now.duration_since(*last_instant) < Self::WINDOW
+    );
+    self.last_write = Some((row_id.to_string(), field_id.to_string(), now));
+    self.num_of_writes += 1;
+    should_merge
+  }
+
+  /// The id of the last write passed to [Self::should_merge].
+  pub(crate) fn last_write_id(&self) -> u64 {
+    self.num_of_writes
+  }
+
+  /// Returns true if the cell wasn't written again since the write, i.e. the write is the last
+  /// one of its burst.
+  pub(crate) fn is_last_write_of_cell(&self, row_id: &str, field_id: &str, write_id: u64) -> bool {
+    match &self.last_write {
+      Some((last_row_id, last_field_id, _))
+        if last_row_id == row_id && last_field_id == field_id =>
+      {
+        self.num_of_writes == write_id
+      },
+      _ => true,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn editing_cell(field_id: &str) -> Option<EditingCell> {
+    Some(EditingCell {
+      view_id: "v1".to_string(),
+      row_id: "r1".to_string(),
+      field_id: field_id.to_string(),
+    })
+  }
+
+  #[test]
+  fn cell_editing_throttle_test() {
+    let mut throttle = CellEditingThrottle::default();
+    assert!(throttle.should_broadcast(&editing_cell("f1")));
+    assert!(!throttle.should_broadcast(&editing_cell("f1")));
+    assert!(throttle.should_broadcast(&editing_cell("f2")));
+    assert!(throttle.should_broadcast(&None));
+    assert!(!throttle.should_broadcast(&None));
+  }
+
+  #[test]
+  fn cell_write_coalescer_test() {
+    let mut coalescer = CellWriteCoalescer::default();
+    assert!(!coalescer.should_merge("r1", "f1"));
+    assert!(coalescer.should_merge("r1", "f1"));
+    assert!(!coalescer.should_merge("r1", "f2"));
+    assert!(!coalescer.should_merge("r2", "f2"));
+    assert!(coalescer.should_merge("r2", "f2"));
+
+    // Only the last write of the cell ends its burst
+    let write_id = coalescer.last_write_id();
+    assert!(coalescer.is_last_write_of_cell("r2", "f2", write_id));
+    assert!(coalescer.should_merge("r2", "f2"));
+    assert!(!coalescer.is_last_write_of_cell("r2", "f2", write_id));
+    let write_id = coalescer.last_write_id();
+    assert!(!coalescer.should_merge("r1", "f1"));
+    assert!(coalescer.is_last_write_of_cell("r2", "f2", write_id));
+  }
+
+  #[test]
+  fn cell_editing_state_expiration_test() {
+    let state = CellEditingState {
+      version: 1,
+      uid: 1,
+      cell: editing_cell("f1"),
+      timestamp: 100,
+    };
+    assert!(!state.is_expired(100 + CellEditingState::EXPIRATION_SECS));
+    assert!(state.is_expired(101 + CellEditingState::EXPIRATION_SECS));
+  }
+}
@@ -1,60 +1,123 @@
 use crate::entities::*;
 use crate::notification::{send_notification, DatabaseNotification};
+use crate::services::automation::{
+  render_template, AutomationAction, AutomationCellValue, AutomationQueue, AutomationRowRequest,
+  AutomationRule, AutomationSetting, AutomationTrigger,
+};
 use crate::services::calculations::Calculation;
-use crate::services::cell::{apply_cell_changeset, get_cell_protobuf, CellCache};
+use crate::services::cell::{
+  apply_cell_changeset, get_cell_display, get_cell_protobuf, stringify_cell, CellCache,
+  CellValidators, TypedCellChangeset,
+};
+use crate::services::change_log::ChangeLog;
+use crate::services::database::cell_editing::{
+  CellEditingState, CellEditingThrottle, CellWriteCoalescer, EditingCell,
+};
 use crate::services::database::database_observe::*;
-use crate::services::database::util::database_view_setting_pb_from_view;
+use crate::services::database::util::{
+  database_view_setting_pb_from_view, replace_ignore_case, sample_row_orders, search_snippet,
+};
+use crate::services::database::DatabaseEditorDiagnostics;
 use crate::services::database_view::{
   DatabaseViewChanged, DatabaseViewEditor, DatabaseViewOperation, DatabaseViews, EditorByViewId,
 };
 use crate::services::field::type_option_transform::transform_type_option;
 use crate::services::field::{
-  default_type_option_data_from_type, select_type_option_from_field, type_option_data_from_pb,
-  ChecklistCellChangeset, RelationTypeOption, SelectOptionCellChangeset, StringCellData,
-  TimestampCellData, TimestampCellDataWrapper, TypeOptionCellDataHandler, TypeOptionCellExt,
+  convert_cells_of_field, default_cell_for_field, default_type_option_data_from_type,
+  insert_link_preview, missing_required_fields, select_type_option_from_field, should_mask_field,
+  type_option_data_from_pb, validate_default_value, validate_unique_cell, ChecklistCellChangeset,
+  DateCellChangeset, FieldAttributes, RelationTypeOption, SelectOptionCellChangeset,
+  SelectTypeOptionSharedAction, StringCellData, TextValidation, TimestampCellData,
+  TimestampCellDataWrapper, TypeOptionCellDataHandler, TypeOptionCellExt, UniqueValues,
+  FIELD_ATTRIBUTES_KEY,
 };
 use crate::services::field_settings::{default_field_settings_by_layout_map, FieldSettings};
-use crate::services::filter::{Filter, FilterChangeset};
+use crate::services::filter::{
+  is_row_matching_filter, parse_filter_expression, Filter, FilterChangeset, FilterExpressionError,
+  FilterInner,
+};
 use crate::services::group::{default_group_setting, GroupChangeset, GroupSetting};
-use crate::services::share::csv::{CSVExport, CSVFormat};
-use crate::services::sort::Sort;
+use crate::services::mirror::MirrorTable;
+use crate::services::row_comment::{
+  row_comments, row_comments_cell, RowComment, RowCommentChangeset, ROW_COMMENTS,
+};
+use crate::services::row_document::RowDocuments;
+use crate::services::row_lock::{is_row_locked, row_lock_cell, ROW_LOCK};
+use crate::services::setting::{
+  DatabaseViewsSetting, RowColorRule, RowColorSetting, RowTemplate, RowTemplateSetting, RowTrash,
+  TrashedRow, ViewSettingsTemplate,
+};
+use crate::services::share::clipboard::{
+  parse_pasted_text, select_option_names, serialize_cells, ClipboardCell, ClipboardCellValue,
+  ClipboardData, PasteCellFailure, PasteResult, PastedText,
+};
+use crate::services::share::csv::{
+  cell_from_text, csv_rows_in_batches, match_csv_columns, missing_select_options, row_cell,
+  CSVExport, CSVExportOptions, CSVFormat, CSVSource,
+};
+use crate::services::share::ical::ICalExport;
+use crate::services::share::json::GridJson;
+use crate::services::share::markdown::markdown_table_from_rows;
+use crate::services::share::xlsx::{XLSXExport, XLSXSheet};
+use crate::services::sort::{sort_rows_by, Sort};
+use crate::services::webhook::{WebhookEmitter, WebhookEventType, WebhookPayload};
 use crate::utils::cache::AnyTypeCache;
 use crate::DatabaseUser;
 use arc_swap::ArcSwapOption;
+use async_stream::stream;
 use async_trait::async_trait;
 use collab::core::collab_plugin::CollabPluginType;
 use collab::lock::RwLock;
-use collab_database::database::Database;
+use collab_database::database::{gen_database_filter_id, gen_row_id, Database};
 use collab_database::entity::DatabaseView;
+use collab_database::fields::date_type_option::DateCellData;
 use collab_database::fields::media_type_option::MediaCellData;
+use collab_database::fields::select_type_option::{SelectOption, SelectOptionIds};
+use collab_database::fields::url_type_option::URLCellData;
 use collab_database::fields::{Field, TypeOptionData};
-use collab_database::rows::{Cell, Cells, DatabaseRow, Row, RowCell, RowDetail, RowId, RowUpdate};
+use collab_database::rows::{
+  Cell, Cells, CreateRowParams, DatabaseRow, Row, RowCell, RowDetail, RowId, RowUpdate,
+};
 use collab_database::views::{
   DatabaseLayout, FilterMap, LayoutSetting, OrderObjectPosition, RowOrder,
 };
 use collab_entity::CollabType;
 use collab_integrate::collab_builder::{AppFlowyCollabBuilder, CollabBuilderConfig};
+use dashmap::DashMap;
+use flowy_database_pub::cloud::LinkPreview;
 use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
 use flowy_notification::DebounceNotificationSender;
 use futures::future::join_all;
-use futures::{pin_mut, StreamExt};
+use futures::{pin_mut, Stream, StreamExt};
+use indexmap::IndexMap;
 use lib_infra::box_any::BoxAny;
 use lib_infra::priority_task::TaskDispatcher;
 use lib_infra::util::timestamp;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicUsize, Ordering};
 use std::sync::{Arc, Weak};
 use std::time::Duration;
 use tokio::select;
 use tokio::sync::oneshot::Sender;
 use tokio::sync::RwLock as TokioRwLock;
-use tokio::sync::{broadcast, oneshot};
+use tokio::sync::{broadcast, oneshot, Mutex};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, event, info, instrument, trace, warn};
 
 type OpenDatabaseResult = oneshot::Sender<FlowyResult<DatabasePB>>;
 
+/// The number of rows created at a time when appending CSV data to a database.
+const CSV_APPEND_BATCH_SIZE: usize = 100;
+/// The number of rows deleted from a database after which its updates are compacted.
+const COMPACT_AFTER_DELETED_ROWS: usize = 500;
+/// The number of rows loaded at a time by [DatabaseEditor::row_stream].
+const ROW_STREAM_CHUNK_SIZE: usize = 100;
+/// The number of rows loaded when a large view is opened, if the view doesn't need all of its
+/// rows. The other rows are loaded when they're fetched, see [DatabaseViewEditor::needs_all_rows].
+const OPEN_VIEW_LOADED_ROWS: usize = 100;
+
 pub struct DatabaseEditor {
-  database_id: String,
+  pub(crate) database_id: String,
   pub(crate) database: Arc<RwLock<Database>>,
   pub cell_cache: CellCache,
   pub(crate) database_views: Arc<DatabaseViews>,
@@ -67,14 +130,44 @@ pub struct DatabaseEditor {
   database_cancellation: Arc<RwLock<Option<CancellationToken>>>,
   un_finalized_rows_cancellation: Arc<ArcSwapOption<CancellationToken>>,
   finalized_rows: Arc<moka::future::Cache<String, Weak<RwLock<DatabaseRow>>>>,
+  cell_editing_throttle: Mutex<CellEditingThrottle>,
+  cell_write_coalescer: Arc<Mutex<CellWriteCoalescer>>,
+  sync_state: Arc<ArcSwapOption<DatabaseSyncState>>,
+  last_activity: AtomicI64,
+  /// True if the database is opened in safe mode from a snapshot. The edits are rejected until
+  /// the database is repaired.
+  is_read_only: AtomicBool,
+  /// Cancels the task that mirrors an external table into the database. The edits are rejected
+  /// while the database is mirrored.
+  mirror_cancellation: ArcSwapOption<CancellationToken>,
+  cell_validators: CellValidators,
+  webhooks: WebhookEmitter,
+  pub(crate) change_log: ChangeLog,
+  automations: AutomationQueue,
+  /// The time up to which the dates of each [AutomationTrigger::DateReached] rule were checked
+  /// since the database was opened, keyed by rule id.
+  date_automation_checked_at: DashMap<String, i64>,
+  /// The number of rows deleted since the database was last compacted, see [Self::compact].
+  deleted_rows_since_compaction: AtomicUsize,
+  row_documents: RowDocuments,
+  /// The number of rows of the database, i.e. the rows of its inline view. It's counted when the
+  /// database is opened and kept up to date with the row orders of the inline view, see
+  /// [Self::row_count].
+  row_count: AtomicUsize,
 }
 
 impl DatabaseEditor {
+  #[allow(clippy::too_many_arguments)]
   pub async fn new(
     user: Arc<dyn DatabaseUser>,
     database: Arc<RwLock<Database>>,
     task_scheduler: Arc<TokioRwLock<TaskDispatcher>>,
     collab_builder: Arc<AppFlowyCollabBuilder>,
+    cell_validators: CellValidators,
+    webhooks: WebhookEmitter,
+    change_log: ChangeLog,
+    automations: AutomationQueue,
+    row_documents: RowDocuments,
   ) -> FlowyResult<Arc<Self>> {
     let finalized_rows: moka::future::Cache<String, Weak<RwLock<DatabaseRow>>> =
       moka::future::Cache::builder()
@@ -88,11 +181,13 @@ impl DatabaseEditor {
     let notification_sender = Arc::new(DebounceNotificationSender::new(200));
     let cell_cache = AnyTypeCache::<u64>::new();
     let database_id = database.read().await.get_database_id();
+    let row_count = database.read().await.get_all_row_orders().await.len();
     let database_cancellation = Arc::new(RwLock::new(None));
     // Receive database sync state and send to frontend via the notification
-    observe_sync_state(&database_id, &database).await;
-    // observe_field_change(&database_id, &database).await;
-    observe_rows_change(&database_id, &database, &notification_sender).await;
+    let sync_state = Arc::new(ArcSwapOption::empty());
+    observe_sync_state(&database_id, &database, sync_state.clone()).await;
+    observe_field_change(&database_id, &database, &change_log).await;
+    observe_rows_change(&database_id, &database, &notification_sender, &change_log).await;
 
     // Used to cache the view of the database for fast access.
     let editor_by_view_id = Arc::new(RwLock::new(EditorByViewId::default()));
@@ -138,9 +233,24 @@ impl DatabaseEditor {
       database_cancellation,
       un_finalized_rows_cancellation: Arc::new(Default::default()),
       finalized_rows: Arc::new(finalized_rows),
+      cell_editing_throttle: Default::default(),
+      cell_write_coalescer: Default::default(),
+      sync_state,
+      last_activity: AtomicI64::new(timestamp()),
+      is_read_only: AtomicBool::new(false),
+      mirror_cancellation: Default::default(),
+      cell_validators,
+      webhooks,
+      change_log,
+      automations,
+      date_automation_checked_at: Default::default(),
+      deleted_rows_since_compaction: AtomicUsize::new(0),
+      row_documents,
+      row_count: AtomicUsize::new(row_count),
     });
     observe_block_event(&database_id, &this).await;
     observe_view_change(&database_id, &this).await;
+    observe_cell_editing_states(&database_id, &this.database).await;
     Ok(this)
   }
 
@@ -164,6 +274,76 @@ impl DatabaseEditor {
     self.database_views.num_editors().await
   }
 
+  /// Records that the editor is in use. The manager calls it whenever the editor is requested.
+  pub(crate) fn touch(&self) {
+    self.last_activity.store(timestamp(), Ordering::Relaxed);
+  }
+
+  pub fn is_read_only(&self) -> bool {
+    self.is_read_only.load(Ordering::Relaxed) || self.is_mirrored()
+  }
+
+  pub(crate) fn set_read_only(&self, is_read_only: bool) {
+    self.is_read_only.store(is_read_only, Ordering::Relaxed);
+  }
+
+  pub fn is_mirrored(&self) -> bool {
+    self.mirror_cancellation.load().is_some()
+  }
+
+  /// Marks the database as mirrored until the token is cancelled by [Self::stop_mirror]. The
+  /// token of the previous mirror is cancelled.
+  pub(crate) fn set_mirror_cancellation(&self, token: CancellationToken) {
+    if let Some(previous) = self.mirror_cancellation.swap(Some(Arc::new(token))) {
+      previous.cancel();
+    }
+  }
+
+  pub(crate) fn stop_mirror(&self) {
+    if let Some(token) = self.mirror_cancellation.swap(None) {
+      token.cancel();
+    }
+  }
+
+  /// Returns an error if the database is opened in safe mode or is mirrored.
+  pub(crate) fn check_writable(&self) -> FlowyResult<()> {
+    if self.is_mirrored() {
+      return Err(FlowyError::new(
+        ErrorCode::DatabaseReadOnly,
+        format!(
+          "The database:{} mirrors an external table",
+          self.database_id
+        ),
+      ));
+    }
+    if self.is_read_only() {
+      return Err(FlowyError::new(
+        ErrorCode::DatabaseReadOnly,
+        format!("The database:{} is opened in safe mode", self.database_id),
+      ));
+    }
+    Ok(())
+  }
+
+  pub async fn diagnostics(&self, is_closing: bool) -> DatabaseEditorDiagnostics {
+    let view_ids = self
+      .database_views
+      .editors()
+      .await
+      .iter()
+      .map(|view_editor| view_editor.view_id.clone())
+      .collect();
+    DatabaseEditorDiagnostics {
+      database_id: self.database_id.clone(),
+      view_ids,
+      sync_state: self.sync_state.load_full().map(|state| *state),
+      num_of_cached_cells: self.cell_cache.len(),
+      num_of_loaded_rows: self.finalized_rows.entry_count(),
+      last_activity: self.last_activity.load(Ordering::Relaxed),
+      is_closing,
+    }
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn close_all_views(&self) {
     for view in self.database_views.editors().await {
@@ -189,6 +369,7 @@ impl DatabaseEditor {
     view_id: &str,
     layout_type: DatabaseLayout,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
     let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
     view_editor.v_update_layout_type(layout_type).await?;
 
@@ -213,6 +394,7 @@ impl DatabaseEditor {
     field_id: &str,
     data: Vec<u8>,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
     let old_group_settings: Vec<GroupSetting>;
     let mut setting_content = "".to_string();
     {
@@ -242,6 +424,7 @@ impl DatabaseEditor {
   }
 
   pub async fn delete_group(&self, params: DeleteGroupParams) -> FlowyResult<()> {
+    self.check_writable()?;
     let view_editor = self
       .database_views
       .get_or_init_view_editor(&params.view_id)
@@ -271,22 +454,37 @@ impl DatabaseEditor {
     view_id: &str,
     changesets: Vec<GroupChangeset>,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
     let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
     view_editor.v_update_group(changesets).await?;
     Ok(())
   }
 
+  /// Sets the field whose values are summed up in each group of the view. `None` only counts the
+  /// rows of the groups.
+  pub async fn update_group_aggregate_field(
+    &self,
+    view_id: &str,
+    field_id: Option<String>,
+  ) -> FlowyResult<()> {
+    self.check_writable()?;
+    let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
+    view_editor.v_update_group_aggregate_field(field_id).await
+  }
+
   pub async fn modify_view_filters(
     &self,
     view_id: &str,
     changeset: FilterChangeset,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
     let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
     view_editor.v_modify_filters(changeset).await?;
     Ok(())
   }
 
   pub async fn create_or_update_sort(&self, params: UpdateSortPayloadPB) -> FlowyResult<Sort> {
+    self.check_writable()?;
     let view_editor = self
       .database_views
       .get_or_init_view_editor(&params.view_id)
@@ -296,6 +494,7 @@ impl DatabaseEditor {
   }
 
   pub async fn reorder_sort(&self, params: ReorderSortPayloadPB) -> FlowyResult<()> {
+    self.check_writable()?;
     let view_editor = self
       .database_views
       .get_or_init_view_editor(&params.view_id)
@@ -305,6 +504,7 @@ impl DatabaseEditor {
   }
 
   pub async fn delete_sort(&self, params: DeleteSortPayloadPB) -> FlowyResult<()> {
+    self.check_writable()?;
     let view_editor = self
       .database_views
       .get_or_init_view_editor(&params.view_id)
@@ -322,6 +522,7 @@ impl DatabaseEditor {
   }
 
   pub async fn update_calculation(&self, update: UpdateCalculationChangesetPB) -> FlowyResult<()> {
+    self.check_writable()?;
     let view_editor = self
       .database_views
       .get_or_init_view_editor(&update.view_id)
@@ -331,6 +532,7 @@ impl DatabaseEditor {
   }
 
   pub async fn remove_calculation(&self, remove: RemoveCalculationChangesetPB) -> FlowyResult<()> {
+    self.check_writable()?;
     let view_editor = self
       .database_views
       .get_or_init_view_editor(&remove.view_id)
@@ -363,10 +565,12 @@ impl DatabaseEditor {
     }
   }
 
-  pub async fn delete_all_sorts(&self, view_id: &str) {
+  pub async fn delete_all_sorts(&self, view_id: &str) -> FlowyResult<()> {
+    self.check_writable()?;
     if let Ok(view_editor) = self.database_views.get_or_init_view_editor(view_id).await {
       let _ = view_editor.v_delete_all_sorts().await;
     }
+    Ok(())
   }
 
   /// Returns a list of fields of the view.
@@ -385,17 +589,54 @@ impl DatabaseEditor {
   }
 
   pub async fn update_field(&self, params: FieldChangesetPB) -> FlowyResult<()> {
+    self.check_writable()?;
     let mut database = self.database.write().await;
+    let field = database.get_field(&params.field_id);
+    let attributes = field.as_ref().and_then(|field| {
+      let mut attributes = FieldAttributes::from_field(field);
+      attributes.apply_changeset(&params).then_some(attributes)
+    });
+    if let (Some(field), Some(attributes)) = (&field, &attributes) {
+      if attributes.default_value != FieldAttributes::from_field(field).default_value {
+        validate_default_value(field, &attributes.default_value)?;
+      }
+      // A field can only become unique if its cells have no duplicates yet
+      if attributes.is_unique && !FieldAttributes::from_field(field).is_unique {
+        let mut unique_values = UniqueValues::new([field.clone()]);
+        let inline_view_id = database.get_inline_view_id();
+        for row_cell in database
+          .get_cells_for_field(&inline_view_id, &field.id)
+          .await
+        {
+          if let Some(cell) = &row_cell.cell {
+            unique_values.validate_and_insert(&field.id, cell)?;
+          }
+        }
+      }
+    }
     database.update_field(&params.field_id, |update| {
       update
         .set_name_if_not_none(params.name)
         .set_icon_if_not_none(params.icon);
     });
+    if let Some(attributes) = attributes {
+      database.update_field(&params.field_id, |update| {
+        update.update_type_options(|type_options_update| {
+          type_options_update.insert(FIELD_ATTRIBUTES_KEY, attributes.into());
+        });
+      });
+    }
     notify_did_update_database_field(&database, &params.field_id)?;
+    drop(database);
+
+    self
+      .emit_field_webhook(WebhookEventType::FieldUpdated, &params.field_id)
+      .await;
     Ok(())
   }
 
   pub async fn delete_field(&self, field_id: &str) -> FlowyResult<()> {
+    self.check_writable()?;
     let is_primary = self
       .database
       .write()
@@ -424,10 +665,14 @@ impl DatabaseEditor {
       view.v_did_delete_field(field_id).await;
     }
 
+    self
+      .emit_field_webhook(WebhookEventType::FieldDeleted, field_id)
+      .await;
     Ok(())
   }
 
   pub async fn clear_field(&self, view_id: &str, field_id: &str) -> FlowyResult<()> {
+    self.check_writable()?;
     let field_type: FieldType = self
       .get_field(field_id)
       .await
@@ -460,6 +705,11 @@ impl DatabaseEditor {
     type_option_data: TypeOptionData,
     old_field: Field,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
+    if FieldType::from(old_field.field_type).is_text() {
+      TextValidation::from(&type_option_data).check_pattern()?;
+    }
+
     let view_editors = self.database_views.editors().await;
     {
       let mut database = self.database.write().await;
@@ -472,6 +722,9 @@ impl DatabaseEditor {
         .v_did_update_field_type_option(&old_field)
         .await?;
     }
+    self
+      .emit_field_webhook(WebhookEventType::FieldUpdated, &old_field.id)
+      .await;
     Ok(())
   }
 
@@ -482,6 +735,21 @@ impl DatabaseEditor {
     new_field_type: FieldType,
     field_name: Option<String>,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
+    // Switching the type rewrites the cells of the field, so it's refused while a row that has a
+    // cell in the field is locked
+    let row_ids = self
+      .database
+      .read()
+      .await
+      .get_cells_for_field(view_id, field_id)
+      .await
+      .into_iter()
+      .filter(|row_cell| row_cell.cell.is_some())
+      .map(|row_cell| row_cell.row_id)
+      .collect::<Vec<_>>();
+    self.check_rows_unlocked(&row_ids).await?;
+
     let mut database = self.database.write().await;
     if let Some(field) = database.get_field(field_id) {
       if field.is_primary {
@@ -515,6 +783,10 @@ impl DatabaseEditor {
           .set_type_option(new_field_type.into(), Some(transformed_type_option));
       });
 
+      if let Some(new_field) = database.get_field(field_id) {
+        convert_cells_of_field(&mut database, view_id, &field, &new_field).await;
+      }
+
       drop(database);
 
       for view in self.database_views.editors().await {
@@ -524,12 +796,18 @@ impl DatabaseEditor {
       let database = self.database.read().await;
 
       notify_did_update_database_field(&database, field_id)?;
+      drop(database);
+
+      self
+        .emit_field_webhook(WebhookEventType::FieldUpdated, field_id)
+        .await;
     }
 
     Ok(())
   }
 
   pub async fn duplicate_field(&self, view_id: &str, field_id: &str) -> FlowyResult<()> {
+    self.check_writable()?;
     let mut database = self.database.write().await;
     let is_primary = database
       .get_field(field_id)
@@ -551,6 +829,9 @@ impl DatabaseEditor {
       let _ = self
         .notify_did_insert_database_field(duplicated_field.clone(), index)
         .await;
+      self
+        .emit_field_webhook(WebhookEventType::FieldCreated, &duplicated_field.id)
+        .await;
 
       let new_field_id = duplicated_field.id.clone();
       let cells = self.get_cells_for_field(view_id, field_id).await;
@@ -566,11 +847,21 @@ impl DatabaseEditor {
   }
 
   pub async fn duplicate_row(&self, view_id: &str, row_id: &RowId) -> FlowyResult<()> {
+    self.check_writable()?;
     let mut database = self.database.write().await;
-    let params = database
+    let mut params = database
       .duplicate_row(row_id)
       .await
       .ok_or_else(|| FlowyError::internal().with_context("error while copying row"))?;
+    // The comments and the lock stay with the original row
+    params.cells.remove(ROW_COMMENTS);
+    params.cells.remove(ROW_LOCK);
+    // The values of the unique fields can't be copied, so they are left empty in the copy
+    for field in database.get_fields_in_view(&database.get_inline_view_id(), None) {
+      if FieldAttributes::from_field(&field).is_unique {
+        params.cells.remove(&field.id);
+      }
+    }
     let (index, row_order) = database.create_row_in_view(view_id, params).await?;
 
     let row_meta = database.get_row_meta(row_id).await;
@@ -594,6 +885,10 @@ impl DatabaseEditor {
       index,
       row_order
     );
+    self
+      .emit_row_webhook(WebhookEventType::RowCreated, &row_order.id)
+      .await;
+    self.run_row_automations(&row_order.id, None).await;
 
     Ok(())
   }
@@ -605,6 +900,7 @@ impl DatabaseEditor {
     from_row_id: RowId,
     to_row_id: RowId,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
     let mut database = self.database.write().await;
     database.update_database_view(view_id, |view| {
       view.move_row_order(&from_row_id, &to_row_id);
@@ -613,6 +909,33 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  /// Moves the row to the `to_index` of the rows that are visible in the view, i.e. the rows
+  /// left after applying the filters and sorts of the view. The rows hidden by the filters keep
+  /// their positions in the row order of the view.
+  pub async fn move_row_to_index(
+    &self,
+    view_id: &str,
+    row_id: RowId,
+    to_index: usize,
+  ) -> FlowyResult<()> {
+    self.check_writable()?;
+    let rows = self.get_all_rows(view_id).await?;
+    if !rows.iter().any(|row| row.id == row_id) {
+      let msg = format!("Row:{} is not visible in view:{}", row_id, view_id);
+      return Err(FlowyError::record_not_found().with_context(msg));
+    }
+
+    let to_row_id = match rows.get(to_index).or_else(|| rows.last()) {
+      Some(row) => row.id.clone(),
+      None => return Ok(()),
+    };
+    if to_row_id == row_id {
+      return Ok(());
+    }
+
+    self.move_row(view_id, row_id, to_row_id).await
+  }
+
   #[tracing::instrument(level = "trace", skip_all, err)]
   pub async fn move_group_row(
     &self,
@@ -622,6 +945,7 @@ impl DatabaseEditor {
     from_row: RowId,
     to_row: Option<RowId>,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
     let row = self.get_row(view_id, &from_row).await.ok_or_else(|| {
       let msg = format!("Can not find the row:{}", from_row);
       FlowyError::internal().with_context(msg)
@@ -671,12 +995,53 @@ impl DatabaseEditor {
   }
 
   pub async fn create_row(&self, params: CreateRowPayloadPB) -> FlowyResult<Option<RowDetail>> {
+    self.create_row_with_cells(params, HashMap::new()).await
+  }
+
+  /// Creates a row at the `index` of the rows that are visible in the view, i.e. before the row
+  /// that is at the index now, so the row doesn't have to be moved after it's created. An index
+  /// past the last row creates the row at the end.
+  pub async fn create_row_at_index(
+    &self,
+    mut params: CreateRowPayloadPB,
+    index: usize,
+  ) -> FlowyResult<Option<RowDetail>> {
+    self.check_writable()?;
+    let rows = self.get_all_rows(&params.view_id).await?;
+    params.row_position = match rows.get(index) {
+      Some(row) => OrderObjectPositionPB::before(row.id.to_string()),
+      None => OrderObjectPositionPB::end(),
+    };
+    self.create_row(params).await
+  }
+
+  /// Creates a row like [Self::create_row], with the given cells replacing the ones built from
+  /// the data of the payload. If the payload has the id of a row that already exists, that row is
+  /// returned instead, see [CreateRowPayloadPB::row_id].
+  pub async fn create_row_with_cells(
+    &self,
+    params: CreateRowPayloadPB,
+    cells: HashMap<String, Cell>,
+  ) -> FlowyResult<Option<RowDetail>> {
+    self.check_writable()?;
     let view_editor = self
       .database_views
       .get_or_init_view_editor(&params.view_id)
       .await?;
 
-    let params = view_editor.v_will_create_row(params).await?;
+    let has_row_id = params.row_id.is_some();
+    let mut params = view_editor.v_will_create_row(params).await?;
+    if has_row_id {
+      let database = self.database.read().await;
+      if Self::existing_row_ids(&database, &view_editor.view_id).contains(params.id.as_str()) {
+        trace!("[Database]: row {} already exists", params.id);
+        return Ok(database.get_row_detail(&params.id).await);
+      }
+    }
+    params.cells.extend(cells);
+    for (field_id, cell) in params.cells.iter() {
+      self.validate_unique_cell(None, field_id, cell).await?;
+    }
 
     let mut database = self.database.write().await;
     let (index, row_order) = database
@@ -686,6 +1051,10 @@ impl DatabaseEditor {
     drop(database);
 
     trace!("[Database]: did create row: {} at {}", row_order.id, index);
+    self
+      .emit_row_webhook(WebhookEventType::RowCreated, &row_order.id)
+      .await;
+    self.run_row_automations(&row_order.id, None).await;
     if let Some(row_detail) = row_detail {
       trace!("created row: {:?} at {}", row_detail, index);
       return Ok(Some(row_detail));
@@ -698,6 +1067,7 @@ impl DatabaseEditor {
     &self,
     params: CreateFieldParams,
   ) -> FlowyResult<FieldPB> {
+    self.check_writable()?;
     let name = params
       .field_name
       .clone()
@@ -724,11 +1094,15 @@ impl DatabaseEditor {
     let _ = self
       .notify_did_insert_database_field(field.clone(), index)
       .await;
+    self
+      .emit_field_webhook(WebhookEventType::FieldCreated, &field.id)
+      .await;
 
     Ok(FieldPB::new(field))
   }
 
   pub async fn move_field(&self, params: MoveFieldParams) -> FlowyResult<()> {
+    self.check_writable()?;
     let (field, new_index) = {
       let mut database = self.database.write().await;
 
@@ -772,271 +1146,2248 @@ impl DatabaseEditor {
     Ok(view_editor.v_get_all_rows().await)
   }
 
-  pub async fn get_row(&self, view_id: &str, row_id: &RowId) -> Option<Row> {
-    let database = self.database.read().await;
-    if database.contains_row(view_id, row_id) {
-      Some(database.get_row(row_id).await)
-    } else {
-      None
-    }
+  /// Returns the rows of the view as a stream that loads [ROW_STREAM_CHUNK_SIZE] rows at a time,
+  /// so the rows of a large view can be processed without holding all of them, e.g. to export or
+  /// search them. The rows keep the order of the view and the filters of the view are applied, but
+  /// the sorts aren't since they need every row.
+  pub async fn row_stream(&self, view_id: &str) -> FlowyResult<impl Stream<Item = Arc<Row>> + '_> {
+    let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
+    let row_orders = self.database.read().await.get_row_orders_for_view(view_id);
+    Ok(stream! {
+      for row_orders in row_orders.chunks(ROW_STREAM_CHUNK_SIZE) {
+        let mut rows = vec![];
+        {
+          let database = self.database.read().await;
+          let rows_stream = database.get_rows_from_row_orders(row_orders, None).await;
+          pin_mut!(rows_stream);
+          while let Some(result) = rows_stream.next().await {
+            match result {
+              Ok(row) => rows.push(Arc::new(row)),
+              Err(err) => error!("Error while streaming rows: {}", err),
+            }
+          }
+        }
+        for row in view_editor.v_filter_rows(rows).await {
+          yield row;
+        }
+      }
+    })
   }
 
-  pub async fn init_database_row(&self, row_id: &RowId) -> FlowyResult<Arc<RwLock<DatabaseRow>>> {
-    if let Some(is_loading) = self.is_loading_rows.load_full() {
-      let mut rx = is_loading.subscribe();
-      trace!("[Database]: wait for loading rows when trying to init database row");
-      let _ = tokio::time::timeout(Duration::from_secs(10), rx.recv()).await;
-    }
-
-    debug!("[Database]: Init database row: {}", row_id);
-    let database_row = self
-      .database
-      .read()
-      .await
-      .get_or_init_database_row(row_id)
-      .await
-      .ok_or_else(|| {
-        FlowyError::record_not_found()
-          .with_context(format!("The row:{} in database not found", row_id))
-      })?;
-
-    let is_finalized = self.finalized_rows.get(row_id.as_str()).await.is_some();
-    if !is_finalized {
-      trace!("[Database]: finalize database row: {}", row_id);
-      let collab_object = self.collab_builder.collab_object(
-        &self.user.workspace_id()?,
-        self.user.user_id()?,
-        row_id,
-        CollabType::DatabaseRow,
-      )?;
+  /// Returns a page of the rows of the view and the number of rows in the view, so the rows can
+  /// be loaded as they're scrolled to, see [DatabaseViewEditor::v_get_rows_paged].
+  pub async fn get_rows_paged(
+    &self,
+    view_id: &str,
+    offset: usize,
+    limit: usize,
+  ) -> FlowyResult<(Vec<Arc<Row>>, usize)> {
+    let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
+    Ok(view_editor.v_get_rows_paged(offset, limit).await)
+  }
 
-      if let Err(err) = self.collab_builder.finalize(
-        collab_object,
-        CollabBuilderConfig::default(),
-        database_row.clone(),
-      ) {
-        error!("Failed to init database row: {}", err);
-      }
-      self
-        .finalized_rows
-        .insert(row_id.to_string(), Arc::downgrade(&database_row))
-        .await;
+  /// Returns `count` rows of the view without loading the rest of its rows, e.g. to preview the
+  /// database. The rows keep the order of the view, but the filters and sorts of the view are
+  /// not applied.
+  pub async fn get_sample_rows(
+    &self,
+    view_id: &str,
+    count: usize,
+    mode: RowSampleModePB,
+  ) -> FlowyResult<RepeatedRowSamplePB> {
+    let database = self.database.read().await;
+    let row_orders = database.get_row_orders_for_view(view_id);
+    let total_count = row_orders.len() as i64;
+    let sample_row_orders = sample_row_orders(row_orders, count, mode);
+    let fields = database.get_fields_in_view(view_id, None);
+
+    let mut items = vec![];
+    let rows_stream = database
+      .get_rows_from_row_orders(&sample_row_orders, None)
+      .await;
+    pin_mut!(rows_stream);
+    while let Some(result) = rows_stream.next().await {
+      let row = match result {
+        Ok(row) => row,
+        Err(err) => {
+          error!("Error while loading the sample rows: {}", err);
+          continue;
+        },
+      };
+      items.push(self.row_sample(&row, &fields));
     }
 
-    Ok(database_row)
+    Ok(RepeatedRowSamplePB { items, total_count })
   }
 
-  pub async fn get_row_meta(&self, view_id: &str, row_id: &RowId) -> Option<RowMetaPB> {
-    let database = self.database.read().await;
-    if database.contains_row(view_id, row_id) {
-      let row_meta = database.get_row_meta(row_id).await?;
-      let row_document_id = database.get_row_document_id(row_id)?;
-      Some(RowMetaPB {
-        id: row_id.clone().into_inner(),
-        document_id: Some(row_document_id),
-        icon: row_meta.icon_url,
-        is_document_empty: Some(row_meta.is_document_empty),
-        attachment_count: Some(row_meta.attachment_count),
-        cover: row_meta.cover.map(|cover| cover.into()),
+  /// Returns the row with its cells in the fields. The cells of sensitive fields are masked.
+  fn row_sample(&self, row: &Row, fields: &[Field]) -> RowSamplePB {
+    let cells = fields
+      .iter()
+      .flat_map(|field| {
+        let field_type = FieldType::from(field.field_type);
+        let row_id = row.id.clone().into_inner();
+        if should_mask_field(field, false) {
+          return Some(CellPB::masked(&field.id, row_id, field_type));
+        }
+        let cell = row.cells.get(&field.id)?;
+        let cell_bytes = get_cell_protobuf(cell, field, Some(self.cell_cache.clone()));
+        let cell_pb = CellPB::new(&field.id, row_id, field_type, cell_bytes.to_vec());
+        Some(cell_pb.with_display(get_cell_display(cell, field)))
       })
-    } else {
-      warn!(
-        "the row:{} is not exist in view:{}",
-        row_id.as_str(),
-        view_id
-      );
-      None
+      .collect();
+    RowSamplePB {
+      row: RowMetaPB::from(row),
+      cells,
     }
   }
 
-  pub async fn delete_rows(&self, row_ids: &[RowId]) {
-    let _ = self.database.write().await.remove_rows(row_ids).await;
+  /// Returns the samples of the rows that are still in the view, in the order of the ids.
+  async fn row_samples(
+    &self,
+    view_id: &str,
+    row_ids: &[String],
+    fields: &[Field],
+  ) -> Vec<RowSamplePB> {
+    let mut samples = vec![];
+    for row_id in row_ids {
+      if let Some(row) = self.get_row(view_id, &RowId::from(row_id.clone())).await {
+        samples.push(self.row_sample(&row, fields));
+      }
+    }
+    samples
   }
 
-  #[tracing::instrument(level = "trace", skip_all)]
-  pub async fn update_row_meta(&self, row_id: &RowId, changeset: UpdateRowMetaParams) {
-    let mut database = self.database.write().await;
-    database
-      .update_row_meta(row_id, |meta_update| {
-        meta_update
-          .insert_cover_if_not_none(changeset.cover)
-          .insert_icon_if_not_none(changeset.icon_url)
-          .update_is_document_empty_if_not_none(changeset.is_document_empty)
-          .update_attachment_count_if_not_none(changeset.attachment_count);
-      })
-      .await;
+  /// Returns a page of the rows of the view that match the filter expression, see
+  /// [Self::find_rows].
+  pub async fn query_rows(&self, params: QueryRowsPayloadPB) -> FlowyResult<QueryRowsResultPB> {
+    let rows = self
+      .find_rows(&params.view_id, &params.filter_expression, params.sorts)
+      .await?;
+    let fields = self.get_fields(&params.view_id, None).await;
+    let cell_fields = if params.field_ids.is_empty() {
+      fields
+    } else {
+      fields
+        .into_iter()
+        .filter(|field| params.field_ids.contains(&field.id))
+        .collect()
+    };
+    let total_count = rows.len() as i64;
+    let items = rows
+      .iter()
+      .skip(params.offset as usize)
+      .take(params.limit as usize)
+      .map(|row| self.row_sample(row, &cell_fields))
+      .collect();
+    Ok(QueryRowsResultPB { items, total_count })
+  }
 
-    // Use the temporary row meta to get rid of the lock that not implement the `Send` or 'Sync' trait.
-    let row_detail = database.get_row_detail(row_id).await;
-    drop(database);
+  /// Returns the rows of the view that match the filter expression, all of them if it's empty,
+  /// sorted by the sorts. The filters and the sorts of the view don't apply, so the rows hidden
+  /// in the view can be found too. The sensitive fields can't be filtered or sorted by, as the
+  /// order of the rows would reveal their cells.
+  pub async fn find_rows(
+    &self,
+    view_id: &str,
+    filter_expression: &str,
+    sorts: Vec<SortPB>,
+  ) -> FlowyResult<Vec<Arc<Row>>> {
+    let fields = self.get_fields(view_id, None).await;
+    let field_by_field_id = fields
+      .iter()
+      .map(|field| (field.id.clone(), field.clone()))
+      .collect::<HashMap<_, _>>();
+    let check_field = |field_id: &str| match field_by_field_id.get(field_id) {
+      None => Err(FlowyError::field_record_not_found()),
+      Some(field) if should_mask_field(field, false) => Err(
+        FlowyError::invalid_data()
+          .with_context(format!("Can't query the sensitive field: {}", field.name)),
+      ),
+      Some(_) => Ok(()),
+    };
 
-    if let Some(row_detail) = row_detail {
-      for view in self.database_views.editors().await {
-        view.v_did_update_row_meta(row_id, &row_detail).await;
+    let filter = if filter_expression.trim().is_empty() {
+      None
+    } else {
+      let filter = parse_filter_expression(filter_expression, &fields)
+        .map_err(|err| FlowyError::invalid_data().with_context(err.message))?;
+      let mut filtering_field_ids = HashMap::new();
+      filter.get_all_filtering_field_ids(&mut filtering_field_ids);
+      for field_id in filtering_field_ids.keys() {
+        check_field(field_id)?;
       }
+      Some(filter)
+    };
+    let sorts = sorts
+      .into_iter()
+      .map(|sort| {
+        check_field(&sort.field_id)?;
+        Ok(Arc::new(Sort {
+          id: sort.id,
+          field_id: sort.field_id,
+          condition: sort.condition.into(),
+          collation: sort.collation.into(),
+        }))
+      })
+      .collect::<FlowyResult<Vec<_>>>()?;
 
-      // Notifies the client that the row meta has been updated.
-      send_notification(row_id.as_str(), DatabaseNotification::DidUpdateRowMeta)
-        .payload(RowMetaPB::from(row_detail))
-        .send();
-    }
+    let rows = {
+      let database = self.database.read().await;
+      database
+        .get_rows_for_view(view_id, None)
+        .await
+        .filter_map(|result| async { result.ok() })
+        .collect::<Vec<_>>()
+        .await
+    };
+    let mut rows = rows
+      .into_iter()
+      .filter(|row| {
+        filter.as_ref().map_or(true, |filter| {
+          is_row_matching_filter(row, &field_by_field_id, &self.cell_cache, filter)
+        })
+      })
+      .map(Arc::new)
+      .collect::<Vec<_>>();
+    sort_rows_by(&mut rows, &sorts, &fields, &self.cell_cache);
+    Ok(rows)
   }
 
+  /// Returns the cells of the view whose display string contains the query, ignoring the case.
+  /// The hits follow the order of the rows and the fields in the view, and only the rows that
+  /// are visible in the view are searched. The cells of sensitive fields are never matched.
+  ///
+  /// Every search scans the visible rows instead of reading an index. The rows are changed by
+  /// remote updates that are applied to the collab without going through the editor, so an index
+  /// maintained on the editor's cell changes would miss them. The display strings of a cell also
+  /// depend on its field's type option, and a type option change would invalidate a whole
+  /// column of an index. The scan reads the rows that the grid already loads, see
+  /// [Self::get_all_rows].
+  pub async fn search_cells(
+    &self,
+    view_id: &str,
+    query: &str,
+    offset: usize,
+    limit: usize,
+  ) -> FlowyResult<RepeatedCellSearchHitPB> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+      return Ok(RepeatedCellSearchHitPB::default());
+    }
+
+    let rows = self.get_all_rows(view_id).await?;
+    let fields = self
+      .get_fields(view_id, None)
+      .await
+      .into_iter()
+      .filter(|field| !should_mask_field(field, false))
+      .collect::<Vec<_>>();
+
+    let mut hits = vec![];
+    for row in rows.iter() {
+      for field in fields.iter() {
+        let snippet = row
+          .cells
+          .get(&field.id)
+          .and_then(|cell| search_snippet(&stringify_cell(cell, field), &query));
+        if let Some(snippet) = snippet {
+          hits.push(CellSearchHitPB {
+            row_id: row.id.to_string(),
+            field_id: field.id.clone(),
+            snippet,
+          });
+        }
+      }
+    }
+
+    let total_count = hits.len() as i64;
+    let items = hits.into_iter().skip(offset).take(limit).collect();
+    Ok(RepeatedCellSearchHitPB { items, total_count })
+  }
+
+  /// Replaces every match of the query, ignoring the case, in the text cells of the view that are
+  /// visible. Only the text fields in `field_ids` are changed, or all of them if `field_ids` is
+  /// empty. The cells are changed in a single batch, see [Self::update_cells], and nothing is
+  /// written if `dry_run` is true. Returns the number of changed cells.
+  pub async fn replace_all(
+    &self,
+    view_id: &str,
+    query: &str,
+    replacement: &str,
+    field_ids: Vec<String>,
+    dry_run: bool,
+  ) -> FlowyResult<usize> {
+    if !dry_run {
+      self.check_writable()?;
+    }
+    let query = query.to_lowercase();
+    if query.is_empty() {
+      return Ok(0);
+    }
+
+    let rows = self.get_all_rows(view_id).await?;
+    let fields = self
+      .get_fields(view_id, None)
+      .await
+      .into_iter()
+      .filter(|field| {
+        FieldType::from(field.field_type).is_text()
+          && !should_mask_field(field, false)
+          && (field_ids.is_empty() || field_ids.contains(&field.id))
+      })
+      .collect::<Vec<_>>();
+
+    let mut changesets = vec![];
+    for row in rows.iter() {
+      for field in fields.iter() {
+        let new_text = row
+          .cells
+          .get(&field.id)
+          .and_then(|cell| replace_ignore_case(&stringify_cell(cell, field), &query, replacement));
+        if let Some(new_text) = new_text {
+          changesets.push(CellChangesetPB {
+            view_id: view_id.to_string(),
+            row_id: row.id.to_string(),
+            field_id: field.id.clone(),
+            cell_changeset: new_text,
+          });
+        }
+      }
+    }
+
+    let num_of_changed_cells = changesets.len();
+    if !dry_run && !changesets.is_empty() {
+      self.update_cells(view_id, changesets).await?;
+    }
+    Ok(num_of_changed_cells)
+  }
+
+  /// Explains which filters of the view accept or reject the row and which sort keys are used
+  /// to order it. Useful to find out why a row is not showing.
+  pub async fn explain_query(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+  ) -> FlowyResult<QueryExplanationPB> {
+    let row = self.get_row(view_id, row_id).await.ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Row with id:{} not found", row_id))
+    })?;
+    let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
+    Ok(view_editor.v_explain_row(&row).await)
+  }
+
+  pub async fn get_row_color_rules(&self, view_id: &str) -> FlowyResult<RepeatedRowColorRulePB> {
+    let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
+    let setting = view_editor.v_get_row_color_setting().await;
+    Ok(RepeatedRowColorRulePB::from(setting.rules.as_slice()))
+  }
+
+  /// Replaces the row color rules of the view. A new id is generated for the rules without id.
+  pub async fn update_row_color_rules(
+    &self,
+    params: UpdateRowColorRulesPayloadPB,
+  ) -> FlowyResult<()> {
+    self.check_writable()?;
+    let mut rules = Vec::with_capacity(params.rules.len());
+    for rule in params.rules {
+      let id = if rule.id.is_empty() {
+        gen_database_filter_id()
+      } else {
+        rule.id
+      };
+      let condition = FilterInner::try_from(rule.condition)?;
+      rules.push(RowColorRule::new(id, rule.color, condition));
+    }
+
+    let view_editor = self
+      .database_views
+      .get_or_init_view_editor(&params.view_id)
+      .await?;
+    view_editor
+      .v_set_row_color_setting(RowColorSetting { rules })
+      .await
+  }
+
+  /// Returns the colors of the visible rows of the view, evaluated with the row color rules of
+  /// the view.
+  pub async fn get_row_colors(&self, view_id: &str) -> FlowyResult<RepeatedRowColorPB> {
+    let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
+    let items = view_editor.v_get_row_colors().await;
+    Ok(RepeatedRowColorPB { items })
+  }
+
+  /// Parses the filter expression against the fields of the view. See [parse_filter_expression]
+  /// for the syntax of the expression.
+  pub async fn parse_filter_expression(
+    &self,
+    view_id: &str,
+    expression: &str,
+  ) -> Result<Filter, FilterExpressionError> {
+    let fields = self.get_fields(view_id, None).await;
+    parse_filter_expression(expression, &fields)
+  }
+
+  /// Returns the required fields of the view whose cells are empty in the row.
+  pub async fn validate_row(&self, view_id: &str, row_id: &RowId) -> FlowyResult<RowValidationPB> {
+    let row = self
+      .get_row(view_id, row_id)
+      .await
+      .ok_or_else(FlowyError::record_not_found)?;
+    let fields = self.get_fields(view_id, None).await;
+    Ok(RowValidationPB {
+      row_id: row.id.to_string(),
+      missing_field_ids: missing_required_fields(&row, &fields),
+    })
+  }
+
+  /// Returns the rows of the view that have empty cells in the required fields, in the order
+  /// of the view.
+  pub async fn get_invalid_rows(&self, view_id: &str) -> FlowyResult<Vec<RowValidationPB>> {
+    let fields = self.get_fields(view_id, None).await;
+    if !fields
+      .iter()
+      .any(|field| FieldAttributes::from_field(field).is_required)
+    {
+      return Ok(vec![]);
+    }
+
+    let rows = self.get_all_rows(view_id).await?;
+    Ok(
+      rows
+        .iter()
+        .map(|row| RowValidationPB {
+          row_id: row.id.to_string(),
+          missing_field_ids: missing_required_fields(row, &fields),
+        })
+        .filter(|validation| !validation.is_valid())
+        .collect(),
+    )
+  }
+
+  pub async fn get_row(&self, view_id: &str, row_id: &RowId) -> Option<Row> {
+    let database = self.database.read().await;
+    if database.contains_row(view_id, row_id) {
+      Some(database.get_row(row_id).await)
+    } else {
+      None
+    }
+  }
+
+  /// Returns the row with the given id, whatever the views it's in. The row is read from the rows
+  /// of the database by its id, so unlike [Self::get_row] and [Self::get_all_rows], the row
+  /// orders of the views aren't read. Returns None if the row doesn't exist or is in the trash.
+  pub async fn get_row_by_id(&self, row_id: &RowId) -> Option<Row> {
+    let database = self.database.read().await;
+    let row_detail = database.get_row_detail(row_id).await?;
+    if Self::row_trash(&database, &database.get_inline_view_id()).contains(row_id.as_str()) {
+      return None;
+    }
+    Some(row_detail.row)
+  }
+
+  /// Returns the number of rows of the database without loading them. The rows hidden by the
+  /// filters of a view are counted too.
+  pub fn row_count(&self) -> usize {
+    self.row_count.load(Ordering::SeqCst)
+  }
+
+  /// Updates the number of rows after the row orders of the inline view changed. A moved row is
+  /// both inserted and deleted, so it doesn't change the count.
+  pub(crate) fn did_update_row_count(&self, inserted: usize, deleted: usize) {
+    let _ = self
+      .row_count
+      .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+        Some((count + inserted).saturating_sub(deleted))
+      });
+  }
+
+  pub async fn init_database_row(&self, row_id: &RowId) -> FlowyResult<Arc<RwLock<DatabaseRow>>> {
+    if let Some(is_loading) = self.is_loading_rows.load_full() {
+      let mut rx = is_loading.subscribe();
+      trace!("[Database]: wait for loading rows when trying to init database row");
+      let _ = tokio::time::timeout(Duration::from_secs(10), rx.recv()).await;
+    }
+
+    debug!("[Database]: Init database row: {}", row_id);
+    let database_row = self
+      .database
+      .read()
+      .await
+      .get_or_init_database_row(row_id)
+      .await
+      .ok_or_else(|| {
+        FlowyError::record_not_found()
+          .with_context(format!("The row:{} in database not found", row_id))
+      })?;
+
+    let is_finalized = self.finalized_rows.get(row_id.as_str()).await.is_some();
+    if !is_finalized {
+      trace!("[Database]: finalize database row: {}", row_id);
+      let collab_object = self.collab_builder.collab_object(
+        &self.user.workspace_id()?,
+        self.user.user_id()?,
+        row_id,
+        CollabType::DatabaseRow,
+      )?;
+
+      if let Err(err) = self.collab_builder.finalize(
+        collab_object,
+        CollabBuilderConfig::default(),
+        database_row.clone(),
+      ) {
+        error!("Failed to init database row: {}", err);
+      }
+      self
+        .finalized_rows
+        .insert(row_id.to_string(), Arc::downgrade(&database_row))
+        .await;
+    }
+
+    Ok(database_row)
+  }
+
+  pub async fn get_row_meta(&self, view_id: &str, row_id: &RowId) -> Option<RowMetaPB> {
+    let database = self.database.read().await;
+    if database.contains_row(view_id, row_id) {
+      let row_meta = database.get_row_meta(row_id).await?;
+      let row_document_id = database.get_row_document_id(row_id)?;
+      let row = database.get_row(row_id).await;
+      Some(RowMetaPB {
+        id: row_id.clone().into_inner(),
+        document_id: Some(row_document_id),
+        icon: row_meta.icon_url,
+        is_document_empty: Some(row_meta.is_document_empty),
+        attachment_count: Some(row_meta.attachment_count),
+        cover: row_meta.cover.map(|cover| cover.into()),
+        created_at: Some(row.created_at),
+        modified_at: Some(row.modified_at),
+        is_locked: Some(is_row_locked(&row)),
+      })
+    } else {
+      warn!(
+        "the row:{} is not exist in view:{}",
+        row_id.as_str(),
+        view_id
+      );
+      None
+    }
+  }
+
+  /// Moves the rows to the trash of the database, see [RowTrash]. The rows are removed from every
+  /// view but their data is kept, so they can be restored with [Self::restore_rows] until they're
+  /// purged by [Self::purge_trash], or when they expire, see [RowTrash::take_expired].
+  ///
+  /// Fails without deleting any row if one of the rows is locked.
+  pub async fn delete_rows(&self, row_ids: &[RowId]) -> FlowyResult<()> {
+    self.check_writable()?;
+    self.check_rows_unlocked(row_ids).await?;
+    let now = timestamp();
+    let (deleted_row_ids, expired_rows, trash) = {
+      let mut database = self.database.write().await;
+      let inline_view_id = database.get_inline_view_id();
+      let mut trash = Self::row_trash(&database, &inline_view_id);
+      let row_ids = row_ids
+        .iter()
+        .filter(|row_id| !trash.contains(row_id.as_str()))
+        .map(|row_id| row_id.as_str())
+        .collect::<HashSet<_>>();
+      let mut indexes_by_row_id = HashMap::<String, HashMap<String, i64>>::new();
+      for view_id in Self::database_view_ids(&database) {
+        let removed_row_ids = database
+          .get_row_orders_for_view(&view_id)
+          .into_iter()
+          .enumerate()
+          .filter(|(_, row_order)| row_ids.contains(row_order.id.as_str()))
+          .map(|(index, row_order)| {
+            indexes_by_row_id
+              .entry(row_order.id.to_string())
+              .or_default()
+              .insert(view_id.clone(), index as i64);
+            row_order.id
+          })
+          .collect::<Vec<_>>();
+        if !removed_row_ids.is_empty() {
+          database.update_database_view(&view_id, |mut view| {
+            for row_id in removed_row_ids.iter() {
+              view = view.remove_row_order(row_id);
+            }
+          });
+        }
+      }
+
+      // Keep the order of the given rows
+      let mut deleted_row_ids = vec![];
+      for row_id in row_ids {
+        if let Some(indexes) = indexes_by_row_id.remove(row_id) {
+          trash.rows.push(TrashedRow {
+            row_id: row_id.to_string(),
+            deleted_at: now,
+            indexes,
+          });
+          deleted_row_ids.push(RowId::from(row_id.to_string()));
+        }
+      }
+      let expired_rows = trash.take_expired(now);
+      Self::write_row_trash(&mut database, &inline_view_id, &trash);
+      (deleted_row_ids, expired_rows, trash)
+    };
+
+    self.change_log.record_all(
+      &self.database_id,
+      WebhookEventType::RowDeleted,
+      &deleted_row_ids
+        .iter()
+        .map(|row_id| row_id.as_str())
+        .collect::<Vec<_>>(),
+    );
+    if self.webhooks.has_webhooks(&self.database_id) {
+      for row_id in deleted_row_ids.iter() {
+        let payload = WebhookPayload::new(WebhookEventType::RowDeleted, &self.database_id, now);
+        self.webhooks.emit(WebhookPayload {
+          row_id: Some(row_id.to_string()),
+          ..payload
+        });
+      }
+    }
+    self.purge_rows(expired_rows).await;
+    self.notify_did_update_row_trash(&trash).await;
+    Ok(())
+  }
+
+  /// Returns the rows in the trash of the database, in the order they were deleted.
+  pub async fn get_trashed_rows(&self) -> Vec<TrashedRow> {
+    let database = self.database.read().await;
+    Self::row_trash(&database, &database.get_inline_view_id()).rows
+  }
+
+  /// Restores the rows from the trash. Each row is put back at the index it had in each view when
+  /// it was deleted, or at the end of the view if the view has fewer rows now. The rows that
+  /// aren't in the trash are skipped.
+  pub async fn restore_rows(&self, row_ids: &[RowId]) -> FlowyResult<()> {
+    self.check_writable()?;
+    let (restored_row_ids, trash) = {
+      let mut database = self.database.write().await;
+      let inline_view_id = database.get_inline_view_id();
+      let mut trash = Self::row_trash(&database, &inline_view_id);
+      let row_ids = row_ids
+        .iter()
+        .map(|row_id| row_id.to_string())
+        .collect::<Vec<_>>();
+      let mut rows = trash.take(&row_ids);
+      if rows.is_empty() {
+        return Ok(());
+      }
+
+      let mut row_heights = HashMap::new();
+      for row in rows.iter() {
+        let row_id = RowId::from(row.row_id.clone());
+        let height = database.get_row(&row_id).await.height;
+        row_heights.insert(row.row_id.clone(), height);
+      }
+      for view_id in Self::database_view_ids(&database) {
+        // Restoring the rows from the lowest index keeps the indexes of the rows after them
+        rows.sort_by_key(|row| row.indexes.get(&view_id).copied().unwrap_or(i64::MAX));
+        let mut row_orders = database.get_row_orders_for_view(&view_id);
+        for row in rows.iter() {
+          let index = row
+            .indexes
+            .get(&view_id)
+            .map(|index| (*index).max(0) as usize)
+            .unwrap_or(usize::MAX)
+            .min(row_orders.len());
+          let position = match row_orders.get(index) {
+            Some(next_row_order) => OrderObjectPosition::Before(next_row_order.id.to_string()),
+            None => OrderObjectPosition::End,
+          };
+          let row_order = RowOrder::new(
+            RowId::from(row.row_id.clone()),
+            row_heights.get(&row.row_id).copied().unwrap_or_default(),
+          );
+          database.update_database_view(&view_id, |view| {
+            view.insert_row_order(&row_order, &position);
+          });
+          row_orders.insert(index, row_order);
+        }
+      }
+      Self::write_row_trash(&mut database, &inline_view_id, &trash);
+      let restored_row_ids = rows
+        .into_iter()
+        .map(|row| RowId::from(row.row_id))
+        .collect::<Vec<_>>();
+      (restored_row_ids, trash)
+    };
+
+    for row_id in restored_row_ids.iter() {
+      self
+        .emit_row_webhook(WebhookEventType::RowCreated, row_id)
+        .await;
+    }
+    self.notify_did_update_row_trash(&trash).await;
+    Ok(())
+  }
+
+  /// Deletes the rows in the trash of the database for good, with their documents.
+  pub async fn purge_trash(&self) -> FlowyResult<()> {
+    self.check_writable()?;
+    let (purged_rows, trash) = {
+      let mut database = self.database.write().await;
+      let inline_view_id = database.get_inline_view_id();
+      let mut trash = Self::row_trash(&database, &inline_view_id);
+      let purged_rows = std::mem::take(&mut trash.rows);
+      Self::write_row_trash(&mut database, &inline_view_id, &trash);
+      (purged_rows, trash)
+    };
+    self.purge_rows(purged_rows).await;
+    self.notify_did_update_row_trash(&trash).await;
+    Ok(())
+  }
+
+  /// Deletes the data and the documents of the rows taken out of the trash, and compacts the
+  /// database once many rows were deleted.
+  async fn purge_rows(&self, rows: Vec<TrashedRow>) {
+    if rows.is_empty() {
+      return;
+    }
+    let row_ids = rows
+      .into_iter()
+      .map(|row| RowId::from(row.row_id))
+      .collect::<Vec<_>>();
+    let document_ids = {
+      let mut database = self.database.write().await;
+      let document_ids = row_ids
+        .iter()
+        .filter_map(|row_id| database.get_row_document_id(row_id))
+        .collect::<Vec<_>>();
+      let _ = database.remove_rows(&row_ids).await;
+      document_ids
+    };
+    self.row_documents.delete(document_ids).await;
+
+    let deleted_rows = self
+      .deleted_rows_since_compaction
+      .fetch_add(row_ids.len(), Ordering::SeqCst)
+      + row_ids.len();
+    if deleted_rows >= COMPACT_AFTER_DELETED_ROWS {
+      if let Err(err) = self.compact().await {
+        error!(
+          "[Database]: failed to compact {}: {}",
+          self.database_id, err
+        );
+      }
+    }
+  }
+
+  fn row_trash(database: &Database, inline_view_id: &str) -> RowTrash {
+    let layout_setting: Option<LayoutSetting> =
+      database.get_layout_setting(inline_view_id, &DatabaseLayout::Grid);
+    layout_setting.map(RowTrash::from).unwrap_or_default()
+  }
+
+  fn write_row_trash(database: &mut Database, inline_view_id: &str, trash: &RowTrash) {
+    let mut layout_setting: LayoutSetting = database
+      .get_layout_setting(inline_view_id, &DatabaseLayout::Grid)
+      .unwrap_or_default();
+    trash.write_to(&mut layout_setting);
+    database.insert_layout_setting(inline_view_id, &DatabaseLayout::Grid, layout_setting);
+  }
+
+  /// Returns the ids of the rows of the view and of the rows in the trash, which can't be reused
+  /// by new rows.
+  fn existing_row_ids(database: &Database, view_id: &str) -> HashSet<String> {
+    let trash = Self::row_trash(database, &database.get_inline_view_id());
+    database
+      .get_row_orders_for_view(view_id)
+      .into_iter()
+      .map(|row_order| row_order.id.to_string())
+      .chain(trash.rows.into_iter().map(|row| row.row_id))
+      .collect()
+  }
+
+  fn database_view_ids(database: &Database) -> Vec<String> {
+    database
+      .get_all_database_views_meta()
+      .into_iter()
+      .map(|view_meta| view_meta.id)
+      .collect()
+  }
+
+  async fn notify_did_update_row_trash(&self, trash: &RowTrash) {
+    let trashed_rows = self.trashed_row_pbs(trash).await;
+    send_notification(&self.database_id, DatabaseNotification::DidUpdateRowTrash)
+      .payload(trashed_rows)
+      .send();
+  }
+
+  async fn trashed_row_pbs(&self, trash: &RowTrash) -> RepeatedTrashedRowPB {
+    let database = self.database.read().await;
+    let mut items = Vec::with_capacity(trash.rows.len());
+    for row in trash.rows.iter() {
+      let row_id = RowId::from(row.row_id.clone());
+      if let Some(row_detail) = database.get_row_detail(&row_id).await {
+        items.push(TrashedRowPB {
+          row: RowMetaPB::from(row_detail),
+          deleted_at: row.deleted_at,
+        });
+      }
+    }
+    RepeatedTrashedRowPB { items }
+  }
+
+  /// Returns the rows in the trash with their meta, see [Self::get_trashed_rows].
+  pub async fn get_trashed_row_pbs(&self) -> RepeatedTrashedRowPB {
+    let trash = RowTrash {
+      rows: self.get_trashed_rows().await,
+    };
+    self.trashed_row_pbs(&trash).await
+  }
+
+  /// Moves every row of the database to the trash, keeping its fields and views, and returns the
+  /// number of deleted rows. The rows are removed in a single transaction and recorded at a single
+  /// revision of the change log, see [Self::delete_rows].
+  pub async fn delete_all_rows(&self) -> FlowyResult<usize> {
+    self.check_writable()?;
+    let row_ids = self.get_row_ids().await;
+    self.delete_rows(&row_ids).await?;
+    Ok(row_ids.len())
+  }
+
+  /// Replaces the updates of the database stored on disk with its current state. Every edit is
+  /// stored as an update until the database is compacted, so a database with many deleted rows
+  /// keeps the rows in its updates. The database is compacted after many rows were deleted too.
+  pub async fn compact(&self) -> FlowyResult<()> {
+    self.check_writable()?;
+    let uid = self.user.user_id()?;
+    let collab_db = self.user.collab_db(uid)?;
+    let database = self.database.read().await;
+    self
+      .collab_builder
+      .write_collab_to_disk(
+        uid,
+        &self.database_id,
+        collab_db,
+        &CollabType::Database,
+        &*database,
+      )
+      .map_err(internal_error)?;
+    self
+      .deleted_rows_since_compaction
+      .store(0, Ordering::SeqCst);
+    trace!("[Database]: compacted {}", self.database_id);
+    Ok(())
+  }
+
+  #[tracing::instrument(level = "trace", skip_all)]
+  pub async fn update_row_meta(
+    &self,
+    row_id: &RowId,
+    changeset: UpdateRowMetaParams,
+  ) -> FlowyResult<()> {
+    self.check_writable()?;
+    let mut database = self.database.write().await;
+    database
+      .update_row_meta(row_id, |meta_update| {
+        meta_update
+          .insert_cover_if_not_none(changeset.cover)
+          .insert_icon_if_not_none(changeset.icon_url)
+          .update_is_document_empty_if_not_none(changeset.is_document_empty)
+          .update_attachment_count_if_not_none(changeset.attachment_count);
+      })
+      .await;
+
+    // Use the temporary row meta to get rid of the lock that not implement the `Send` or 'Sync' trait.
+    let row_detail = database.get_row_detail(row_id).await;
+    drop(database);
+
+    if let Some(row_detail) = row_detail {
+      for view in self.database_views.editors().await {
+        view.v_did_update_row_meta(row_id, &row_detail).await;
+      }
+
+      // Notifies the client that the row meta has been updated.
+      send_notification(row_id.as_str(), DatabaseNotification::DidUpdateRowMeta)
+        .payload(RowMetaPB::from(row_detail))
+        .send();
+    }
+    Ok(())
+  }
+
+  /// Opens the document of the row, creating it the first time the row is opened as a page, and
+  /// returns its id. The document is deleted with the row.
+  pub async fn open_row_document(&self, view_id: &str, row_id: &RowId) -> FlowyResult<String> {
+    let document_id = {
+      let database = self.database.read().await;
+      if !database.contains_row(view_id, row_id) {
+        return Err(
+          FlowyError::record_not_found().with_context(format!("Row with id:{} not found", row_id)),
+        );
+      }
+      database.get_row_document_id(row_id).ok_or_else(|| {
+        FlowyError::internal().with_context(format!("The row:{} has no document id", row_id))
+      })?
+    };
+    self.row_documents.open(&document_id).await?;
+    Ok(document_id)
+  }
+
+  /// Returns the comments of the row, oldest first.
+  pub async fn get_comments(&self, row_id: &RowId) -> FlowyResult<Vec<RowComment>> {
+    let row_detail = self.database.read().await.get_row_detail(row_id).await;
+    let row_detail = row_detail.ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Row with id:{} not found", row_id))
+    })?;
+    Ok(row_comments(&row_detail.row))
+  }
+
+  /// Adds a comment to the row, written by the current user.
+  pub async fn add_comment(
+    &self,
+    row_id: &RowId,
+    author_name: String,
+    text: String,
+  ) -> FlowyResult<RowComment> {
+    self.check_writable()?;
+    let text = text.trim().to_string();
+    if text.is_empty() {
+      return Err(FlowyError::invalid_data().with_context("The comment is empty"));
+    }
+    let comment = RowComment::new(self.user.user_id()?, author_name, text);
+    self
+      .update_comments(RowCommentChangeset {
+        row_id: row_id.to_string(),
+        inserted_comments: vec![comment.clone()],
+        deleted_comment_ids: vec![],
+      })
+      .await?;
+    Ok(comment)
+  }
+
+  pub async fn delete_comment(&self, row_id: &RowId, comment_id: &str) -> FlowyResult<()> {
+    self.check_writable()?;
+    self
+      .update_comments(RowCommentChangeset {
+        row_id: row_id.to_string(),
+        inserted_comments: vec![],
+        deleted_comment_ids: vec![comment_id.to_string()],
+      })
+      .await
+  }
+
+  /// Applies the changeset to the comments stored in the row and notifies the client with it.
+  async fn update_comments(&self, changeset: RowCommentChangeset) -> FlowyResult<()> {
+    self.check_writable()?;
+    let row_id = RowId::from(changeset.row_id.clone());
+    if self.finalized_rows.get(row_id.as_str()).await.is_none() {
+      self.init_database_row(&row_id).await?;
+    }
+
+    let mut database = self.database.write().await;
+    let row_detail = database.get_row_detail(&row_id).await.ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Row with id:{} not found", row_id))
+    })?;
+    let mut comments = row_comments(&row_detail.row);
+    for comment_id in changeset.deleted_comment_ids.iter() {
+      let index = comments
+        .iter()
+        .position(|comment| &comment.id == comment_id)
+        .ok_or_else(|| {
+          FlowyError::record_not_found()
+            .with_context(format!("Comment with id:{} not found", comment_id))
+        })?;
+      comments.remove(index);
+    }
+    comments.extend(changeset.inserted_comments.iter().cloned());
+    database
+      .update_row(row_id.clone(), |row_update| {
+        row_update.update_cells(|cell_update| {
+          cell_update.insert(ROW_COMMENTS, row_comments_cell(&comments));
+        });
+      })
+      .await;
+    drop(database);
+
+    send_notification(row_id.as_str(), DatabaseNotification::DidUpdateRowComments)
+      .payload(RowCommentChangesetPB::from(changeset))
+      .send();
+    Ok(())
+  }
+
+  /// Locks or unlocks the row. The cells of a locked row can't be edited and the row can't be
+  /// deleted, which fails with [ErrorCode::RowLocked], until the row is unlocked. The comments of a
+  /// locked row can still be edited.
+  pub async fn set_row_locked(&self, row_id: &RowId, locked: bool) -> FlowyResult<()> {
+    self.check_writable()?;
+    if self.finalized_rows.get(row_id.as_str()).await.is_none() {
+      self.init_database_row(row_id).await?;
+    }
+
+    let row_detail = {
+      let mut database = self.database.write().await;
+      database
+        .update_row(row_id.clone(), |row_update| {
+          row_update.update_cells(|cell_update| {
+            cell_update.insert(ROW_LOCK, row_lock_cell(locked));
+          });
+        })
+        .await;
+      database.get_row_detail(row_id).await
+    };
+    if let Some(row_detail) = row_detail {
+      send_notification(row_id.as_str(), DatabaseNotification::DidUpdateRowMeta)
+        .payload(RowMetaPB::from(row_detail))
+        .send();
+    }
+    trace!("[Database]: row {} is locked: {}", row_id, locked);
+    Ok(())
+  }
+
+  pub async fn is_row_locked(&self, row_id: &RowId) -> bool {
+    let database = self.database.read().await;
+    match database.get_row_detail(row_id).await {
+      Some(row_detail) => is_row_locked(&row_detail.row),
+      None => false,
+    }
+  }
+
+  /// Fails with [ErrorCode::RowLocked] if one of the rows is locked, see [Self::set_row_locked].
+  async fn check_rows_unlocked(&self, row_ids: &[RowId]) -> FlowyResult<()> {
+    let database = self.database.read().await;
+    for row_id in row_ids {
+      let Some(row_detail) = database.get_row_detail(row_id).await else {
+        continue;
+      };
+      if is_row_locked(&row_detail.row) {
+        return Err(FlowyError::new(
+          ErrorCode::RowLocked,
+          format!("The row:{} is locked", row_id),
+        ));
+      }
+    }
+    Ok(())
+  }
+
+  /// Returns the cell of the given field and row, reading the row by its id like
+  /// [Self::get_row_by_id].
   pub async fn get_cell(&self, field_id: &str, row_id: &RowId) -> Option<Cell> {
     let database = self.database.read().await;
-    let field = database.get_field(field_id)?;
-    let field_type = FieldType::from(field.field_type);
-    // If the cell data is referenced, return the reference data. Otherwise, return an empty cell.
-    match field_type {
-      FieldType::LastEditedTime | FieldType::CreatedTime => {
-        let row = database.get_row(row_id).await;
-        let wrapped_cell_data = if field_type.is_created_time() {
-          TimestampCellDataWrapper::from((field_type, TimestampCellData::new(row.created_at)))
-        } else {
-          TimestampCellDataWrapper::from((field_type, TimestampCellData::new(row.modified_at)))
-        };
-        Some(Cell::from(wrapped_cell_data))
-      },
-      _ => database.get_cell(field_id, row_id).await.cell,
+    let field = database.get_field(field_id)?;
+    let field_type = FieldType::from(field.field_type);
+    // If the cell data is referenced, return the reference data. Otherwise, return an empty cell.
+    match field_type {
+      FieldType::LastEditedTime | FieldType::CreatedTime => {
+        let row = database.get_row(row_id).await;
+        let wrapped_cell_data = if field_type.is_created_time() {
+          TimestampCellDataWrapper::from((field_type, TimestampCellData::new(row.created_at)))
+        } else {
+          TimestampCellDataWrapper::from((field_type, TimestampCellData::new(row.modified_at)))
+        };
+        Some(Cell::from(wrapped_cell_data))
+      },
+      _ => database.get_cell(field_id, row_id).await.cell,
+    }
+  }
+
+  /// Returns the cell of the given field and row. The data of a sensitive field is masked
+  /// unless `reveal_sensitive` is true.
+  pub async fn get_cell_pb(
+    &self,
+    field_id: &str,
+    row_id: &RowId,
+    reveal_sensitive: bool,
+  ) -> Option<CellPB> {
+    let (field, cell) = {
+      let cell = self.get_cell(field_id, row_id).await?;
+      let field = self.database.read().await.get_field(field_id)?;
+      (field, cell)
+    };
+
+    let field_type = FieldType::from(field.field_type);
+    if should_mask_field(&field, reveal_sensitive) {
+      return Some(CellPB::masked(field_id, row_id.clone().into(), field_type));
+    }
+
+    let cell_bytes = get_cell_protobuf(&cell, &field, Some(self.cell_cache.clone()));
+    let cell_pb = CellPB::new(
+      field_id,
+      row_id.clone().into(),
+      field_type,
+      cell_bytes.to_vec(),
+    );
+    Some(cell_pb.with_display(get_cell_display(&cell, &field)))
+  }
+
+  pub async fn get_cells_for_field(&self, view_id: &str, field_id: &str) -> Vec<RowCell> {
+    let database = self.database.read().await;
+    if let Some(field) = database.get_field(field_id) {
+      let field_type = FieldType::from(field.field_type);
+      match field_type {
+        FieldType::LastEditedTime | FieldType::CreatedTime => {
+          database
+            .get_rows_for_view(view_id, None)
+            .await
+            .filter_map(|result| async {
+              match result {
+                Ok(row) => {
+                  let data = if field_type.is_created_time() {
+                    TimestampCellData::new(row.created_at)
+                  } else {
+                    TimestampCellData::new(row.modified_at)
+                  };
+                  Some(RowCell {
+                    row_id: row.id,
+                    cell: Some(Cell::from(data)),
+                  })
+                },
+                Err(_) => None,
+              }
+            })
+            .collect()
+            .await
+        },
+        _ => database.get_cells_for_field(view_id, field_id).await,
+      }
+    } else {
+      vec![]
+    }
+  }
+
+  #[instrument(level = "trace", skip_all)]
+  pub async fn update_cell_with_changeset(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    field_id: &str,
+    cell_changeset: BoxAny,
+  ) -> FlowyResult<()> {
+    self.check_writable()?;
+    let (field, cell) = {
+      let database = self.database.read().await;
+      let field = match database.get_field(field_id) {
+        Some(field) => Ok(field),
+        None => {
+          let msg = format!("Field with id:{} not found", &field_id);
+          Err(FlowyError::internal().with_context(msg))
+        },
+      }?;
+      (field, database.get_cell(field_id, row_id).await.cell)
+    };
+
+    let new_cell =
+      apply_cell_changeset(cell_changeset, cell, &field, Some(self.cell_cache.clone()))?;
+    TextValidation::from_field(&field).validate_cell(&new_cell)?;
+    self.update_cell(view_id, row_id, field_id, new_cell).await
+  }
+
+  /// Updates many cells at once, e.g. when pasting a range of cells. Every changeset is applied
+  /// and validated before any cell is written, see [Self::write_cells].
+  pub async fn update_cells(
+    &self,
+    view_id: &str,
+    changesets: Vec<CellChangesetPB>,
+  ) -> FlowyResult<()> {
+    self.check_writable()?;
+    let mut cells_by_row: IndexMap<RowId, HashMap<String, Option<Cell>>> = IndexMap::new();
+    {
+      let database = self.database.read().await;
+      for changeset in changesets {
+        let field = database
+          .get_field(&changeset.field_id)
+          .ok_or_else(FlowyError::field_record_not_found)?;
+        let row_id = RowId::from(changeset.row_id);
+        // The cell may be changed by a previous changeset of the batch
+        let cell = match cells_by_row
+          .get(&row_id)
+          .and_then(|cells| cells.get(&field.id))
+        {
+          Some(cell) => cell.clone(),
+          None => database.get_cell(&field.id, &row_id).await.cell,
+        };
+        let new_cell = apply_cell_changeset(
+          BoxAny::new(changeset.cell_changeset),
+          cell,
+          &field,
+          Some(self.cell_cache.clone()),
+        )?;
+        TextValidation::from_field(&field).validate_cell(&new_cell)?;
+        cells_by_row
+          .entry(row_id)
+          .or_default()
+          .insert(field.id.clone(), Some(new_cell));
+      }
+    }
+    self.write_cells(view_id, cells_by_row).await
+  }
+
+  /// Writes the cells of many rows at once. A `None` cell clears the cell. Every target row is
+  /// checked to be unlocked and every new cell is validated before any cell is written, so a
+  /// locked row or an invalid cell leaves all the cells untouched. The cells of a row are written
+  /// in a single update of the row, and the view is notified once with all the updated cells.
+  pub(crate) async fn write_cells(
+    &self,
+    view_id: &str,
+    cells_by_row: IndexMap<RowId, HashMap<String, Option<Cell>>>,
+  ) -> FlowyResult<()> {
+    self.check_writable()?;
+    let row_ids = cells_by_row.keys().cloned().collect::<Vec<_>>();
+    self.check_rows_unlocked(&row_ids).await?;
+    {
+      // The new cells are checked against each other and against the cells they don't replace
+      let database = self.database.read().await;
+      let mut unique_values = Self::unique_values(&database, |row_id, field_id| {
+        cells_by_row
+          .get(row_id)
+          .is_some_and(|cells| cells.contains_key(field_id))
+      })
+      .await;
+      for cells in cells_by_row.values() {
+        for (field_id, cell) in cells {
+          if let Some(cell) = cell {
+            unique_values.validate_and_insert(field_id, cell)?;
+          }
+        }
+      }
+    }
+    for (row_id, cells) in cells_by_row.iter() {
+      if let Some(row) = self.get_row(view_id, row_id).await {
+        for (field_id, cell) in cells {
+          if let Some(cell) = cell {
+            self
+              .validate_cell_with_validators(&row, field_id, cell)
+              .await?;
+          }
+        }
+      }
+    }
+
+    let mut updated_cells = vec![];
+    for (row_id, cells) in cells_by_row {
+      let old_row = self.get_row(view_id, &row_id).await;
+      updated_cells.extend(cells.keys().map(|field_id| CellChangesetNotifyPB {
+        view_id: view_id.to_string(),
+        row_id: row_id.to_string(),
+        field_id: field_id.clone(),
+      }));
+      let mut new_cells = HashMap::new();
+      let mut cleared_field_ids = vec![];
+      for (field_id, cell) in cells {
+        match cell {
+          Some(cell) => {
+            new_cells.insert(field_id, cell);
+          },
+          None => cleared_field_ids.push(field_id),
+        }
+      }
+      self
+        .update_row(row_id.clone(), |row_update| {
+          row_update
+            .set_last_modified(timestamp())
+            .set_cells(Cells::from(new_cells))
+            .update_cells(|cell_update| {
+              for field_id in cleared_field_ids.iter() {
+                cell_update.clear(field_id);
+              }
+            });
+        })
+        .await?;
+
+      if let Some(row) = self.get_row(view_id, &row_id).await {
+        for view in self.database_views.editors().await {
+          view.v_did_update_row(&old_row, &row, None).await;
+        }
+      }
+      if let Some(old_row) = &old_row {
+        self.run_row_automations(&row_id, Some(old_row)).await;
+      }
+    }
+
+    send_notification(view_id, DatabaseNotification::DidUpdateCells)
+      .payload(RepeatedCellChangesetNotifyPB {
+        items: updated_cells,
+      })
+      .send();
+    Ok(())
+  }
+
+  /// Updates the cell with the typed data of its field type, see [TypedCellChangeset::into_cell].
+  /// Returns an error if the data doesn't match the type of the field.
+  pub async fn update_cell_with_typed_changeset(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    field_id: &str,
+    changeset: TypedCellChangeset,
+  ) -> FlowyResult<()> {
+    self.check_writable()?;
+    let (field, cell) = {
+      let database = self.database.read().await;
+      let field = database
+        .get_field(field_id)
+        .ok_or_else(FlowyError::field_record_not_found)?;
+      (field, database.get_cell(field_id, row_id).await.cell)
+    };
+    let new_cell = changeset.into_cell(&field, cell, Some(self.cell_cache.clone()))?;
+    TextValidation::from_field(&field).validate_cell(&new_cell)?;
+    self.update_cell(view_id, row_id, field_id, new_cell).await
+  }
+
+  /// Copies the cells of the fields in the rows, in the order of `row_ids` and `field_ids`. The
+  /// values of sensitive fields are masked unless `reveal_sensitive` is true.
+  pub async fn serialize_cells(
+    &self,
+    view_id: &str,
+    row_ids: &[RowId],
+    field_ids: &[String],
+    reveal_sensitive: bool,
+  ) -> FlowyResult<ClipboardData> {
+    let database = self.database.read().await;
+    let fields = field_ids
+      .iter()
+      .map(|field_id| {
+        database
+          .get_field(field_id)
+          .ok_or_else(FlowyError::field_record_not_found)
+      })
+      .collect::<FlowyResult<Vec<_>>>()?;
+    let mut rows = Vec::with_capacity(row_ids.len());
+    for row_id in row_ids {
+      if !database.contains_row(view_id, row_id) {
+        return Err(
+          FlowyError::record_not_found().with_context(format!("Row {} not found", row_id)),
+        );
+      }
+      rows.push(database.get_row(row_id).await);
+    }
+    Ok(serialize_cells(&rows, &fields, reveal_sensitive))
+  }
+
+  /// Pastes the copied cells with the cell of the anchor row and field as the top left corner.
+  /// The rows and fields follow the order of the view, and the copied cells that fall outside
+  /// of the view are dropped.
+  ///
+  /// The text of each cell is parsed with the parser of its field. A cell that can't be pasted
+  /// into its field, e.g. a word into a number or a text into a checklist, is skipped and
+  /// reported in [PasteResult::failures].
+  pub async fn paste_cells(
+    &self,
+    view_id: &str,
+    anchor_row_id: &RowId,
+    anchor_field_id: &str,
+    data: ClipboardData,
+  ) -> FlowyResult<PasteResult> {
+    self.check_writable()?;
+    let copied_rows = data.cells().rows;
+    let rows = self.get_all_rows(view_id).await?;
+    let fields = self.get_fields(view_id, None).await;
+    let row_index = rows
+      .iter()
+      .position(|row| &row.id == anchor_row_id)
+      .ok_or_else(FlowyError::record_not_found)?;
+    let field_index = fields
+      .iter()
+      .position(|field| field.id == anchor_field_id)
+      .ok_or_else(FlowyError::field_record_not_found)?;
+
+    let mut result = PasteResult::default();
+    for (copied_row_index, (row, copied_cells)) in
+      rows[row_index..].iter().zip(copied_rows).enumerate()
+    {
+      self
+        .paste_row_cells(
+          view_id,
+          &row.id,
+          &fields[field_index..],
+          copied_row_index,
+          copied_cells,
+          &mut result,
+        )
+        .await;
+    }
+    Ok(result)
+  }
+
+  /// Pastes each copied row as a new row at the end of the view. The copied columns are pasted
+  /// into the fields of the view from the first one, and the columns beyond the last field are
+  /// dropped. The cells that can't be pasted are reported like in [Self::paste_cells].
+  pub async fn paste_rows(&self, view_id: &str, data: ClipboardData) -> FlowyResult<PasteResult> {
+    self.check_writable()?;
+    let copied_rows = data.cells().rows;
+    let fields = self.get_fields(view_id, None).await;
+    let mut result = PasteResult::default();
+    for (copied_row_index, copied_cells) in copied_rows.into_iter().enumerate() {
+      let row_detail = self
+        .create_row(CreateRowPayloadPB {
+          view_id: view_id.to_string(),
+          ..Default::default()
+        })
+        .await?
+        .ok_or_else(|| FlowyError::internal().with_context("Failed to create the pasted row"))?;
+      let row_id = row_detail.row.id;
+      self
+        .paste_row_cells(
+          view_id,
+          &row_id,
+          &fields,
+          copied_row_index,
+          copied_cells,
+          &mut result,
+        )
+        .await;
+      result.created_row_ids.push(row_id);
+    }
+    Ok(result)
+  }
+
+  async fn paste_row_cells(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    fields: &[Field],
+    copied_row_index: usize,
+    copied_cells: Vec<ClipboardCell>,
+    result: &mut PasteResult,
+  ) {
+    for (column_index, (field, cell)) in fields.iter().zip(copied_cells).enumerate() {
+      let text = cell.text.clone();
+      match self.paste_cell(view_id, row_id, field, cell).await {
+        Ok(_) => result.num_of_pasted_cells += 1,
+        Err(err) => {
+          trace!("[Database]: skip pasting cell to {}: {}", field.id, err);
+          result.failures.push(PasteCellFailure {
+            row_index: copied_row_index,
+            column_index,
+            field_id: field.id.clone(),
+            text,
+            reason: err.msg,
+          });
+        },
+      }
+    }
+  }
+
+  async fn paste_cell(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    field: &Field,
+    cell: ClipboardCell,
+  ) -> FlowyResult<()> {
+    let field_type = FieldType::from(field.field_type);
+    if field_type.is_auto_update() || field_type.is_created_time() {
+      return Err(FlowyError::invalid_data().with_context("The field can't be edited"));
+    }
+
+    match (field_type, &cell.value) {
+      (
+        FieldType::DateTime,
+        ClipboardCellValue::Date {
+          timestamp: Some(timestamp),
+          end_timestamp,
+          include_time,
+          is_range,
+        },
+      ) => {
+        let changeset = DateCellChangeset {
+          timestamp: Some(*timestamp),
+          end_timestamp: *end_timestamp,
+          include_time: Some(*include_time),
+          is_range: Some(*is_range),
+          ..Default::default()
+        };
+        self
+          .update_cell_with_changeset(view_id, row_id, &field.id, BoxAny::new(changeset))
+          .await
+      },
+      (FieldType::SingleSelect | FieldType::MultiSelect, _) => {
+        let mut names = select_option_names(&cell);
+        if field_type.is_single_select() {
+          names.truncate(1);
+        }
+        let option_ids = self.get_or_create_select_options(&field.id, names).await?;
+        let changeset = TypedCellChangeset::SelectOptionIds(option_ids);
+        self
+          .update_cell_with_typed_changeset(view_id, row_id, &field.id, changeset)
+          .await
+      },
+      _ if cell.text.is_empty() => self.clear_cell(view_id, row_id.clone(), &field.id).await,
+      _ => match parse_pasted_text(&cell.text, field_type)
+        .map_err(|reason| FlowyError::invalid_data().with_context(reason))?
+      {
+        PastedText::Text(text) => {
+          self
+            .update_cell_with_changeset(view_id, row_id, &field.id, BoxAny::new(text))
+            .await
+        },
+        PastedText::Checkbox(is_checked) => {
+          self
+            .update_cell_with_typed_changeset(
+              view_id,
+              row_id,
+              &field.id,
+              TypedCellChangeset::Checkbox(is_checked),
+            )
+            .await
+        },
+        PastedText::Date {
+          timestamp,
+          include_time,
+        } => {
+          let changeset = DateCellChangeset {
+            timestamp: Some(timestamp),
+            include_time: Some(include_time),
+            ..Default::default()
+          };
+          self
+            .update_cell_with_changeset(view_id, row_id, &field.id, BoxAny::new(changeset))
+            .await
+        },
+      },
+    }
+  }
+
+  /// Returns the ids of the options with the given names. The missing options are created with
+  /// the color of the copied option, or a new color if there is none.
+  async fn get_or_create_select_options(
+    &self,
+    field_id: &str,
+    names: Vec<(String, Option<SelectOption>)>,
+  ) -> FlowyResult<Vec<String>> {
+    // Read the field again, the previous pasted cells might have created options.
+    let field = self
+      .get_field(field_id)
+      .await
+      .ok_or_else(FlowyError::field_record_not_found)?;
+    let mut type_option = select_type_option_from_field(&field)?;
+    let mut option_ids = Vec::with_capacity(names.len());
+    let mut has_new_options = false;
+    for (name, copied_option) in names {
+      let existing_id = type_option
+        .options()
+        .iter()
+        .find(|option| option.name == name)
+        .map(|option| option.id.clone());
+      let option_id = match existing_id {
+        Some(option_id) => option_id,
+        None => {
+          let option = match copied_option {
+            Some(copied_option) => SelectOption::with_color(&name, copied_option.color),
+            None => type_option.create_option(&name),
+          };
+          let option_id = option.id.clone();
+          type_option.insert_option(option);
+          has_new_options = true;
+          option_id
+        },
+      };
+      option_ids.push(option_id);
+    }
+
+    if has_new_options {
+      let mut database = self.database.write().await;
+      let view_editors = self.database_views.editors().await;
+      update_field_type_option_fn(&mut database, type_option.to_type_option_data(), &field).await?;
+      drop(database);
+      for view_editor in view_editors {
+        view_editor.v_did_update_field_type_option(&field).await?;
+      }
+    }
+    Ok(option_ids)
+  }
+
+  /// Broadcasts the cell the current user is editing to the other collaborators, or clears it if
+  /// `cell` is None. The broadcast is throttled while the user keeps editing the same cell.
+  pub async fn set_cell_editing_state(&self, cell: Option<EditingCell>) -> FlowyResult<()> {
+    if !self
+      .cell_editing_throttle
+      .lock()
+      .await
+      .should_broadcast(&cell)
+    {
+      return Ok(());
+    }
+    let state = CellEditingState {
+      version: 1,
+      uid: self.user.user_id()?,
+      cell,
+      timestamp: timestamp(),
+    };
+    let database = self.database.read().await;
+    database
+      .get_awareness()
+      .set_local_state(state)
+      .map_err(internal_error)?;
+    Ok(())
+  }
+
+  /// Update a cell in the database.
+  /// This will notify all views that the cell has been updated.
+  #[instrument(level = "trace", skip_all)]
+  pub async fn update_cell(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    field_id: &str,
+    new_cell: Cell,
+  ) -> FlowyResult<()> {
+    self
+      .validate_unique_cell(Some(row_id), field_id, &new_cell)
+      .await?;
+    // Get the old row before updating the cell. It would be better to get the old cell
+    let old_row = self.get_row(view_id, row_id).await;
+    if let Some(old_row) = &old_row {
+      self
+        .validate_cell_with_validators(old_row, field_id, &new_cell)
+        .await?;
+    }
+    trace!("[Database Row]: update cell: {:?}", new_cell);
+    self.check_writable()?;
+    self.check_rows_unlocked(&[row_id.clone()]).await?;
+    self
+      .write_row(row_id.clone(), |row_update| {
+        row_update
+          .set_last_modified(timestamp())
+          .update_cells(|cell_update| {
+            cell_update.insert(field_id, new_cell);
+          });
+      })
+      .await?;
+    // The consecutive writes of the same cell are merged into one change of the row
+    let (should_merge, write_id) = {
+      let mut coalescer = self.cell_write_coalescer.lock().await;
+      let should_merge = coalescer.should_merge(row_id.as_str(), field_id);
+      (should_merge, coalescer.last_write_id())
+    };
+    if should_merge {
+      self.change_log.record_merged(
+        &self.database_id,
+        WebhookEventType::RowUpdated,
+        row_id.as_str(),
+      );
+      self.send_merged_row_webhook(row_id, field_id, write_id);
+    } else {
+      self
+        .emit_row_webhook(WebhookEventType::RowUpdated, row_id)
+        .await;
+    }
+
+    self
+      .did_update_row(view_id, row_id, field_id, old_row.clone())
+      .await;
+    if let Some(old_row) = &old_row {
+      self.run_row_automations(row_id, Some(old_row)).await;
+    }
+
+    Ok(())
+  }
+
+  /// Checks the cell with the [CellValidator]s installed by the embedder.
+  ///
+  /// [CellValidator]: crate::services::cell::CellValidator
+  async fn validate_cell_with_validators(
+    &self,
+    row: &Row,
+    field_id: &str,
+    new_cell: &Cell,
+  ) -> FlowyResult<()> {
+    if self.cell_validators.is_empty() {
+      return Ok(());
+    }
+    let field = self
+      .get_field(field_id)
+      .await
+      .ok_or_else(FlowyError::field_record_not_found)?;
+    self.cell_validators.validate_cell(row, &field, new_cell)
+  }
+
+  /// Returns the values taken in the unique fields of the database. The cells for which `skip`
+  /// returns true are left out, e.g. the cells that are about to be replaced.
+  async fn unique_values(database: &Database, skip: impl Fn(&RowId, &str) -> bool) -> UniqueValues {
+    let inline_view_id = database.get_inline_view_id();
+    let unique_fields = database
+      .get_fields_in_view(&inline_view_id, None)
+      .into_iter()
+      .filter(|field| FieldAttributes::from_field(field).is_unique);
+    let mut unique_values = UniqueValues::new(unique_fields);
+    for field_id in unique_values.field_ids() {
+      for row_cell in database
+        .get_cells_for_field(&inline_view_id, &field_id)
+        .await
+      {
+        match &row_cell.cell {
+          Some(cell) if !skip(&row_cell.row_id, &field_id) => unique_values.insert(&field_id, cell),
+          _ => {},
+        }
+      }
+    }
+    unique_values
+  }
+
+  /// Checks the cell against the cells of the other rows of the database if the field is
+  /// unique. `row_id` is the row of the cell, or None if the row is not created yet.
+  async fn validate_unique_cell(
+    &self,
+    row_id: Option<&RowId>,
+    field_id: &str,
+    cell: &Cell,
+  ) -> FlowyResult<()> {
+    let database = self.database.read().await;
+    let field = match database.get_field(field_id) {
+      Some(field) if FieldAttributes::from_field(&field).is_unique => field,
+      _ => return Ok(()),
+    };
+    let other_cells = database
+      .get_cells_for_field(&database.get_inline_view_id(), field_id)
+      .await
+      .into_iter()
+      .filter(|row_cell| Some(&row_cell.row_id) != row_id)
+      .filter_map(|row_cell| row_cell.cell)
+      .collect::<Vec<_>>();
+    validate_unique_cell(cell, &field, &other_cells)
+  }
+
+  /// Stores the [LinkPreview] of the url in the URL cell. The preview is dropped if the url of
+  /// the cell was changed while the preview was being fetched.
+  pub async fn update_link_preview(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    field_id: &str,
+    url: &str,
+    preview: LinkPreview,
+  ) -> FlowyResult<()> {
+    let mut cell = match self.get_cell(field_id, row_id).await {
+      Some(cell) if URLCellData::from(&cell).data == url => cell,
+      _ => return Ok(()),
+    };
+    insert_link_preview(&mut cell, preview);
+
+    // The preview is not an edit of the user, so the last modified time of the row is kept.
+    let old_row = self.get_row(view_id, row_id).await;
+    self
+      .update_row(row_id.clone(), |row_update| {
+        row_update.update_cells(|cell_update| {
+          cell_update.insert(field_id, cell);
+        });
+      })
+      .await?;
+    self
+      .did_update_row(view_id, row_id, field_id, old_row)
+      .await;
+    Ok(())
+  }
+
+  pub async fn update_row<F>(&self, row_id: RowId, modify: F) -> FlowyResult<()>
+  where
+    F: FnOnce(RowUpdate),
+  {
+    self.check_writable()?;
+    self.check_rows_unlocked(&[row_id.clone()]).await?;
+    self.apply_row_update(row_id, modify).await
+  }
+
+  /// Updates the row without checking that the database is writable, used to update the rows
+  /// of a mirrored database.
+  async fn apply_row_update<F>(&self, row_id: RowId, modify: F) -> FlowyResult<()>
+  where
+    F: FnOnce(RowUpdate),
+  {
+    self.write_row(row_id.clone(), modify).await?;
+    self
+      .emit_row_webhook(WebhookEventType::RowUpdated, &row_id)
+      .await;
+    Ok(())
+  }
+
+  /// Writes the row to the collab without recording the change, see [Self::apply_row_update].
+  async fn write_row<F>(&self, row_id: RowId, modify: F) -> FlowyResult<()>
+  where
+    F: FnOnce(RowUpdate),
+  {
+    if self.finalized_rows.get(row_id.as_str()).await.is_none() {
+      info!(
+        "[Database Row]: row:{} is not finalized when editing, init it",
+        row_id
+      );
+      self.init_database_row(&row_id).await?;
     }
+    self.database.write().await.update_row(row_id, modify).await;
+    Ok(())
   }
 
-  pub async fn get_cell_pb(&self, field_id: &str, row_id: &RowId) -> Option<CellPB> {
-    let (field, cell) = {
-      let cell = self.get_cell(field_id, row_id).await?;
-      let field = self.database.read().await.get_field(field_id)?;
-      (field, cell)
+  /// Records the change of the row in the change log and sends the created or updated row to the
+  /// webhooks of the database, if it has any.
+  async fn emit_row_webhook(&self, event: WebhookEventType, row_id: &RowId) {
+    self
+      .change_log
+      .record(&self.database_id, event, row_id.as_str());
+    self.send_row_webhook(event, row_id).await;
+  }
+
+  async fn send_row_webhook(&self, event: WebhookEventType, row_id: &RowId) {
+    if !self.webhooks.has_webhooks(&self.database_id) {
+      return;
+    }
+    let payload = {
+      let database = self.database.read().await;
+      let row = database.get_row(row_id).await;
+      let fields = database.get_fields_in_view(&database.get_inline_view_id(), None);
+      WebhookPayload::new(event, &self.database_id, timestamp()).with_row(&row, &fields)
     };
+    self.webhooks.emit(payload);
+  }
 
-    let field_type = FieldType::from(field.field_type);
-    let cell_bytes = get_cell_protobuf(&cell, &field, Some(self.cell_cache.clone()));
-    Some(CellPB {
-      field_id: field_id.to_string(),
-      row_id: row_id.clone().into(),
-      data: cell_bytes.to_vec(),
-      field_type: Some(field_type),
-    })
+  /// Sends the webhook of a write merged with the previous writes of the cell once the user
+  /// pauses, see [CellWriteCoalescer]. The first write of the burst sent its own webhook, so a
+  /// burst of writes sends two webhooks, the last one with the final value of the cell.
+  fn send_merged_row_webhook(&self, row_id: &RowId, field_id: &str, write_id: u64) {
+    if !self.webhooks.has_webhooks(&self.database_id) {
+      return;
+    }
+    let coalescer = self.cell_write_coalescer.clone();
+    let weak_database = Arc::downgrade(&self.database);
+    let webhooks = self.webhooks.clone();
+    let database_id = self.database_id.clone();
+    let row_id = row_id.clone();
+    let field_id = field_id.to_string();
+    tokio::spawn(async move {
+      tokio::time::sleep(CellWriteCoalescer::WINDOW).await;
+      if !coalescer
+        .lock()
+        .await
+        .is_last_write_of_cell(row_id.as_str(), &field_id, write_id)
+      {
+        return;
+      }
+      let Some(database) = weak_database.upgrade() else {
+        return;
+      };
+      let database = database.read().await;
+      // The row may be deleted while the webhook is delayed, its deletion is sent then
+      let Some(row_detail) = database.get_row_detail(&row_id).await else {
+        return;
+      };
+      let fields = database.get_fields_in_view(&database.get_inline_view_id(), None);
+      let payload = WebhookPayload::new(WebhookEventType::RowUpdated, &database_id, timestamp())
+        .with_row(&row_detail.row, &fields);
+      webhooks.emit(payload);
+    });
   }
 
-  pub async fn get_cells_for_field(&self, view_id: &str, field_id: &str) -> Vec<RowCell> {
+  async fn emit_field_webhook(&self, event: WebhookEventType, field_id: &str) {
+    self.change_log.record(&self.database_id, event, field_id);
+    if !self.webhooks.has_webhooks(&self.database_id) {
+      return;
+    }
+    let field_name = self.get_field(field_id).await.map(|field| field.name);
+    let payload = WebhookPayload::new(event, &self.database_id, timestamp());
+    self.webhooks.emit(WebhookPayload {
+      field_id: Some(field_id.to_string()),
+      field_name,
+      ..payload
+    });
+  }
+
+  fn automation_setting(database: &Database) -> AutomationSetting {
+    let layout_setting: Option<LayoutSetting> =
+      database.get_layout_setting(&database.get_inline_view_id(), &DatabaseLayout::Grid);
+    layout_setting
+      .map(AutomationSetting::from)
+      .unwrap_or_default()
+  }
+
+  fn write_automation_setting(database: &mut Database, setting: &AutomationSetting) {
+    let inline_view_id = database.get_inline_view_id();
+    let mut layout_setting: LayoutSetting = database
+      .get_layout_setting(&inline_view_id, &DatabaseLayout::Grid)
+      .unwrap_or_default();
+    setting.write_to(&mut layout_setting);
+    database.insert_layout_setting(&inline_view_id, &DatabaseLayout::Grid, layout_setting);
+  }
+
+  pub async fn get_automation_rules(&self) -> RepeatedAutomationRulePB {
     let database = self.database.read().await;
-    if let Some(field) = database.get_field(field_id) {
-      let field_type = FieldType::from(field.field_type);
-      match field_type {
-        FieldType::LastEditedTime | FieldType::CreatedTime => {
-          database
-            .get_rows_for_view(view_id, None)
-            .await
-            .filter_map(|result| async {
-              match result {
-                Ok(row) => {
-                  let data = if field_type.is_created_time() {
-                    TimestampCellData::new(row.created_at)
-                  } else {
-                    TimestampCellData::new(row.modified_at)
-                  };
-                  Some(RowCell {
-                    row_id: row.id,
-                    cell: Some(Cell::from(data)),
-                  })
-                },
-                Err(_) => None,
-              }
-            })
-            .collect()
-            .await
+    let setting = Self::automation_setting(&database);
+    RepeatedAutomationRulePB::from(setting.rules.as_slice())
+  }
+
+  /// Replaces the automation rules of the database. A new id is generated for the rules without
+  /// id. Returns an error if a rule refers to a field that doesn't exist, or if the automations
+  /// are disabled by their feature flag.
+  pub async fn update_automation_rules(
+    &self,
+    params: UpdateAutomationRulesPayloadPB,
+  ) -> FlowyResult<()> {
+    self.check_writable()?;
+    if !self.automations.is_enabled() {
+      return Err(FlowyError::not_support().with_context("The automations are disabled"));
+    }
+    let now = timestamp();
+    let setting = {
+      let mut database = self.database.write().await;
+      let old_setting = Self::automation_setting(&database);
+      let mut rules = Vec::with_capacity(params.rules.len());
+      for rule in params.rules {
+        let id = if rule.id.is_empty() {
+          gen_database_filter_id()
+        } else {
+          rule.id
+        };
+        let trigger = match rule.trigger_type {
+          AutomationTriggerTypePB::CellMatches => AutomationTrigger::CellMatches(Filter {
+            id: id.clone(),
+            inner: FilterInner::try_from(rule.condition)?,
+          }),
+          AutomationTriggerTypePB::RowCreated => AutomationTrigger::RowCreated,
+          AutomationTriggerTypePB::DateReached => AutomationTrigger::DateReached {
+            field_id: rule.date_field_id,
+          },
+        };
+        // The dates that were checked before the rule was updated don't fire it again
+        let checked_at = old_setting
+          .rules
+          .iter()
+          .find(|old_rule| old_rule.id == id)
+          .map(|old_rule| old_rule.checked_at)
+          .unwrap_or(now);
+        let rule = AutomationRule {
+          id,
+          name: rule.name,
+          is_enabled: rule.is_enabled,
+          trigger,
+          actions: rule
+            .actions
+            .into_iter()
+            .map(AutomationAction::from)
+            .collect(),
+          checked_at,
+        };
+        Self::validate_automation_rule(&database, &rule)?;
+        rules.push(rule);
+      }
+
+      let setting = AutomationSetting { rules };
+      Self::write_automation_setting(&mut database, &setting);
+      setting
+    };
+
+    send_notification(
+      &self.database_id,
+      DatabaseNotification::DidUpdateAutomationRules,
+    )
+    .payload(RepeatedAutomationRulePB::from(setting.rules.as_slice()))
+    .send();
+    Ok(())
+  }
+
+  fn validate_automation_rule(database: &Database, rule: &AutomationRule) -> FlowyResult<()> {
+    for field_id in rule.field_ids() {
+      if database.get_field(field_id).is_none() {
+        return Err(FlowyError::field_record_not_found());
+      }
+    }
+    if let AutomationTrigger::DateReached { field_id } = &rule.trigger {
+      let field_type = database
+        .get_field(field_id)
+        .map(|field| FieldType::from(field.field_type));
+      if field_type != Some(FieldType::DateTime) {
+        return Err(
+          FlowyError::invalid_data().with_context("The trigger field must be a date field"),
+        );
+      }
+    }
+    for action in &rule.actions {
+      match action {
+        AutomationAction::CreateRow { view_id, .. } if view_id.is_empty() => {
+          return Err(
+            FlowyError::invalid_data().with_context("The view to create the row in is missing"),
+          );
         },
-        _ => database.get_cells_for_field(view_id, field_id).await,
+        AutomationAction::Notify { message } if message.trim().is_empty() => {
+          return Err(FlowyError::invalid_data().with_context("The message is empty"));
+        },
+        _ => {},
       }
-    } else {
-      vec![]
     }
+    Ok(())
   }
 
-  #[instrument(level = "trace", skip_all)]
-  pub async fn update_cell_with_changeset(
-    &self,
-    view_id: &str,
-    row_id: &RowId,
-    field_id: &str,
-    cell_changeset: BoxAny,
-  ) -> FlowyResult<()> {
-    let (field, cell) = {
+  /// Runs the automation rules triggered by the created row, or by the update of the row if
+  /// `old_row` is given. A [AutomationTrigger::CellMatches] rule only fires when the row starts
+  /// matching its condition, so editing a row that already matched doesn't fire it again.
+  ///
+  /// The changes made by the actions don't run the rules, so the rules can't trigger each other
+  /// in a loop.
+  async fn run_row_automations(&self, row_id: &RowId, old_row: Option<&Row>) {
+    if self.is_read_only() || !self.automations.is_enabled() {
+      return;
+    }
+    let (rules, row, fields) = {
       let database = self.database.read().await;
-      let field = match database.get_field(field_id) {
-        Some(field) => Ok(field),
-        None => {
-          let msg = format!("Field with id:{} not found", &field_id);
-          Err(FlowyError::internal().with_context(msg))
+      let rules = Self::automation_setting(&database)
+        .rules
+        .into_iter()
+        .filter(|rule| {
+          rule.is_enabled && !matches!(rule.trigger, AutomationTrigger::DateReached { .. })
+        })
+        .collect::<Vec<_>>();
+      if rules.is_empty() {
+        return;
+      }
+      let row = database.get_row(row_id).await;
+      let fields = database.get_fields_in_view(&database.get_inline_view_id(), None);
+      (rules, row, fields)
+    };
+
+    let field_by_field_id = fields
+      .iter()
+      .map(|field| (field.id.clone(), field.clone()))
+      .collect::<HashMap<_, _>>();
+    for rule in rules {
+      let is_fired = match &rule.trigger {
+        AutomationTrigger::CellMatches(condition) => {
+          is_row_matching_filter(&row, &field_by_field_id, &self.cell_cache, condition)
+            && !old_row.is_some_and(|old_row| {
+              is_row_matching_filter(old_row, &field_by_field_id, &self.cell_cache, condition)
+            })
         },
-      }?;
-      (field, database.get_cell(field_id, row_id).await.cell)
+        AutomationTrigger::RowCreated => old_row.is_none(),
+        AutomationTrigger::DateReached { .. } => false,
+      };
+      if is_fired {
+        self.run_automation_actions(&rule, &row, &fields).await;
+      }
+    }
+  }
+
+  /// Runs the [AutomationTrigger::DateReached] rules for the rows whose date was reached since
+  /// the previous check, up to `now` in seconds. The manager checks the open databases
+  /// periodically, see [crate::DatabaseManager::start_automations].
+  pub async fn run_date_automations(&self, now: i64) {
+    if self.is_read_only() || !self.automations.is_enabled() {
+      return;
+    }
+    let (rules, rows, fields) = {
+      let database = self.database.read().await;
+      let rules = Self::automation_setting(&database)
+        .rules
+        .into_iter()
+        .filter(|rule| {
+          rule.is_enabled && matches!(rule.trigger, AutomationTrigger::DateReached { .. })
+        })
+        .collect::<Vec<_>>();
+      if rules.is_empty() {
+        return;
+      }
+      let inline_view_id = database.get_inline_view_id();
+      let rows = database
+        .get_rows_for_view(&inline_view_id, None)
+        .await
+        .filter_map(|result| async { result.ok() })
+        .collect::<Vec<_>>()
+        .await;
+      let fields = database.get_fields_in_view(&inline_view_id, None);
+      (rules, rows, fields)
     };
 
-    let new_cell =
-      apply_cell_changeset(cell_changeset, cell, &field, Some(self.cell_cache.clone()))?;
-    self.update_cell(view_id, row_id, field_id, new_cell).await
+    let mut fired_rule_ids = vec![];
+    for rule in rules {
+      let AutomationTrigger::DateReached { field_id } = &rule.trigger else {
+        continue;
+      };
+      let Some(field) = fields.iter().find(|field| &field.id == field_id) else {
+        continue;
+      };
+      let checked_at = self
+        .date_automation_checked_at
+        .insert(rule.id.clone(), now)
+        .unwrap_or(rule.checked_at)
+        .max(rule.checked_at);
+      let mut is_fired = false;
+      for row in rows.iter() {
+        let timestamp = row_cell(row, field).and_then(|cell| DateCellData::from(&cell).timestamp);
+        if timestamp.is_some_and(|timestamp| timestamp > checked_at && timestamp <= now) {
+          self.run_automation_actions(&rule, row, &fields).await;
+          is_fired = true;
+        }
+      }
+      if is_fired {
+        fired_rule_ids.push(rule.id.clone());
+      }
+    }
+
+    // Only the rules that fired store the checked time, so checking the dates doesn't write to
+    // the database every time. The other rules are checked from their stored time again after
+    // the database is reopened.
+    if !fired_rule_ids.is_empty() {
+      let mut database = self.database.write().await;
+      let mut setting = Self::automation_setting(&database);
+      for rule in setting.rules.iter_mut() {
+        if fired_rule_ids.contains(&rule.id) {
+          rule.checked_at = rule.checked_at.max(now);
+        }
+      }
+      Self::write_automation_setting(&mut database, &setting);
+    }
   }
 
-  /// Update a cell in the database.
-  /// This will notify all views that the cell has been updated.
-  #[instrument(level = "trace", skip_all)]
-  pub async fn update_cell(
+  async fn run_automation_actions(&self, rule: &AutomationRule, row: &Row, fields: &[Field]) {
+    trace!(
+      "[Database]: run automation rule:{} for row:{}",
+      rule.id,
+      row.id
+    );
+    for action in rule.actions.iter() {
+      let result = match action {
+        AutomationAction::SetCell { field_id, value } => {
+          let text = render_template(value, row, fields);
+          self.set_automation_cell(&row.id, field_id, &text).await
+        },
+        AutomationAction::CreateRow { view_id, cells } => {
+          let cells = cells
+            .iter()
+            .map(|cell| AutomationCellValue {
+              field_name: cell.field_name.clone(),
+              value: render_template(&cell.value, row, fields),
+            })
+            .collect();
+          self.automations.push(AutomationRowRequest {
+            view_id: view_id.clone(),
+            cells,
+          });
+          Ok(())
+        },
+        AutomationAction::Notify { message } => {
+          send_notification(
+            &self.database_id,
+            DatabaseNotification::DidTriggerAutomation,
+          )
+          .payload(AutomationNotificationPB {
+            rule_id: rule.id.clone(),
+            rule_name: rule.name.clone(),
+            row_id: row.id.to_string(),
+            message: render_template(message, row, fields),
+          })
+          .send();
+          Ok(())
+        },
+      };
+      if let Err(err) = result {
+        warn!(
+          "[Database]: automation rule:{} failed for row:{}: {}",
+          rule.id, row.id, err
+        );
+      }
+    }
+  }
+
+  /// Sets the cell of a [AutomationAction::SetCell] action. The cell is checked like a cell
+  /// edited by the user, so a locked row is left untouched.
+  async fn set_automation_cell(
     &self,
-    view_id: &str,
     row_id: &RowId,
     field_id: &str,
-    new_cell: Cell,
+    text: &str,
   ) -> FlowyResult<()> {
-    // Get the old row before updating the cell. It would be better to get the old cell
-    let old_row = self.get_row(view_id, row_id).await;
-    trace!("[Database Row]: update cell: {:?}", new_cell);
+    let field = self
+      .get_field(field_id)
+      .await
+      .ok_or_else(FlowyError::field_record_not_found)?;
+    let cell = cell_from_text(text, &field);
+    if cell.is_none() && !text.trim().is_empty() {
+      return Err(FlowyError::invalid_data().with_context(format!(
+        "Can't convert {} to a cell of {}",
+        text, field.name
+      )));
+    }
+
+    let inline_view_id = self.database.read().await.get_inline_view_id();
+    let old_row = self.get_row(&inline_view_id, row_id).await;
+    if let Some(cell) = &cell {
+      self
+        .validate_unique_cell(Some(row_id), field_id, cell)
+        .await?;
+      if let Some(old_row) = &old_row {
+        self
+          .validate_cell_with_validators(old_row, field_id, cell)
+          .await?;
+      }
+    }
     self
       .update_row(row_id.clone(), |row_update| {
         row_update
-          .set_last_modified(timestamp())
-          .update_cells(|cell_update| {
-            cell_update.insert(field_id, new_cell);
+          .set_last_modified(timestamp())
+          .update_cells(|cell_update| match cell {
+            Some(cell) => {
+              cell_update.insert(field_id, cell);
+            },
+            None => {
+              cell_update.clear(field_id);
+            },
           });
       })
       .await?;
-
     self
-      .did_update_row(view_id, row_id, field_id, old_row)
+      .did_update_row(&inline_view_id, row_id, field_id, old_row)
       .await;
-
     Ok(())
   }
 
-  pub async fn update_row<F>(&self, row_id: RowId, modify: F) -> FlowyResult<()>
-  where
-    F: FnOnce(RowUpdate),
-  {
-    if self.finalized_rows.get(row_id.as_str()).await.is_none() {
-      info!(
-        "[Database Row]: row:{} is not finalized when editing, init it",
-        row_id
-      );
-      self.init_database_row(&row_id).await?;
+  /// Creates the row of a [AutomationAction::CreateRow] action in the view. The cells are
+  /// matched to the fields of the view by name. The row doesn't run the automation rules of the
+  /// database.
+  pub(crate) async fn create_automation_row(
+    &self,
+    view_id: &str,
+    cells: &[AutomationCellValue],
+  ) -> FlowyResult<RowId> {
+    self.check_writable()?;
+    let fields = self.get_fields(view_id, None).await;
+    let mut params = CreateRowParams::new(gen_row_id(), self.database_id.clone());
+    for cell in cells {
+      let field = fields
+        .iter()
+        .find(|field| field.name == cell.field_name)
+        .ok_or_else(|| {
+          FlowyError::field_record_not_found()
+            .with_context(format!("Can't find the field: {}", cell.field_name))
+        })?;
+      if let Some(value) = cell_from_text(&cell.value, field) {
+        self.validate_unique_cell(None, &field.id, &value).await?;
+        params.cells.insert(field.id.clone(), value);
+      }
     }
-    self.database.write().await.update_row(row_id, modify).await;
-    Ok(())
+
+    let (_, row_order) = self
+      .database
+      .write()
+      .await
+      .create_row_in_view(view_id, params)
+      .await?;
+    self
+      .emit_row_webhook(WebhookEventType::RowCreated, &row_order.id)
+      .await;
+    Ok(row_order.id)
   }
 
   pub async fn clear_cell(&self, view_id: &str, row_id: RowId, field_id: &str) -> FlowyResult<()> {
+    self.check_writable()?;
     // Get the old row before updating the cell. It would be better to get the old cell
     let old_row = self.get_row(view_id, &row_id).await;
     self
       .update_row(row_id.clone(), |row_update| {
-        row_update.update_cells(|cell_update| {
-          cell_update.clear(field_id);
-        });
+        row_update
+          .set_last_modified(timestamp())
+          .update_cells(|cell_update| {
+            cell_update.clear(field_id);
+          });
       })
       .await?;
 
     self
-      .did_update_row(view_id, &row_id, field_id, old_row)
+      .did_update_row(view_id, &row_id, field_id, old_row.clone())
       .await;
+    if let Some(old_row) = &old_row {
+      self.run_row_automations(&row_id, Some(old_row)).await;
+    }
 
     Ok(())
   }
@@ -1126,7 +3477,7 @@ impl DatabaseEditor {
           None => new_count,
         };
 
-        self
+        if let Err(err) = self
           .update_row_meta(
             row_id,
             UpdateRowMetaParams {
@@ -1138,7 +3489,10 @@ impl DatabaseEditor {
               attachment_count: Some(new_attachment_count),
             },
           )
-          .await;
+          .await
+        {
+          error!("Failed to update the attachment count: {}", err);
+        }
       }
     }
   }
@@ -1188,6 +3542,7 @@ impl DatabaseEditor {
     row_id: RowId,
     options: Vec<SelectOptionPB>,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
     let mut database = self.database.write().await;
     let field = database.get_field(field_id).ok_or_else(|| {
       FlowyError::record_not_found().with_context(format!("Field with id:{} not found", &field_id))
@@ -1219,13 +3574,177 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  /// Delete the options from a single select or multiple select field. The deleted option ids
+  /// are then stripped from every cell of the field while the database write lock is still held,
+  /// so no other change can observe a cell referencing an option that no longer exists. Each row
+  /// is a separate collab, so the rows are not updated atomically, see
+  /// [Self::update_select_options_and_cells].
   pub async fn delete_select_options(
     &self,
     view_id: &str,
     field_id: &str,
-    row_id: RowId,
     options: Vec<SelectOptionPB>,
   ) -> FlowyResult<()> {
+    let option_ids = options
+      .into_iter()
+      .map(|option| option.id)
+      .collect::<Vec<_>>();
+    self
+      .delete_select_options_with_ids(view_id, field_id, option_ids)
+      .await
+  }
+
+  /// Delete a single option from a single select or multiple select field and clean up the
+  /// cells that reference it.
+  pub async fn delete_select_option(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    option_id: &str,
+  ) -> FlowyResult<()> {
+    self
+      .delete_select_options_with_ids(view_id, field_id, vec![option_id.to_string()])
+      .await
+  }
+
+  async fn delete_select_options_with_ids(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    option_ids: Vec<String>,
+  ) -> FlowyResult<()> {
+    self
+      .update_select_options_and_cells(
+        view_id,
+        field_id,
+        |type_option| {
+          for option_id in &option_ids {
+            type_option.delete_option(option_id);
+          }
+          Ok(())
+        },
+        |select_ids| {
+          if !select_ids.iter().any(|id| option_ids.contains(id)) {
+            return false;
+          }
+          select_ids.retain(|id| !option_ids.contains(id));
+          true
+        },
+      )
+      .await
+  }
+
+  /// Merge the option `from_option_id` into `to_option_id`. Every cell that contains the
+  /// `from_option_id` is rewritten to contain the `to_option_id` instead, then the
+  /// `from_option_id` option is removed from the field. Both happen while holding the database
+  /// write lock, see [Self::update_select_options_and_cells].
+  pub async fn merge_select_options(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    from_option_id: &str,
+    to_option_id: &str,
+  ) -> FlowyResult<()> {
+    if from_option_id == to_option_id {
+      return Ok(());
+    }
+
+    self
+      .update_select_options_and_cells(
+        view_id,
+        field_id,
+        |type_option| {
+          for option_id in [from_option_id, to_option_id] {
+            if !type_option
+              .options()
+              .iter()
+              .any(|option| option.id == option_id)
+            {
+              return Err(
+                FlowyError::record_not_found()
+                  .with_context(format!("Select option:{} not found", option_id)),
+              );
+            }
+          }
+          type_option.delete_option(from_option_id);
+          Ok(())
+        },
+        |select_ids| {
+          let index = match select_ids.iter().position(|id| id == from_option_id) {
+            None => return false,
+            Some(index) => index,
+          };
+          if select_ids.iter().any(|id| id == to_option_id) {
+            select_ids.remove(index);
+          } else {
+            select_ids[index] = to_option_id.to_string();
+          }
+          true
+        },
+      )
+      .await
+  }
+
+  /// Returns how many cells of a single select or multiple select field reference each of its
+  /// options, in the order of the options. Every row of the database is counted, including the
+  /// rows that are hidden by the filters of a view.
+  pub async fn get_select_option_usage(
+    &self,
+    field_id: &str,
+  ) -> FlowyResult<Vec<SelectOptionUsagePB>> {
+    let database = self.database.read().await;
+    let field = database
+      .get_field(field_id)
+      .ok_or_else(FlowyError::field_record_not_found)?;
+    let type_option = select_type_option_from_field(&field)?;
+    let mut count_by_option_id: HashMap<String, i64> = HashMap::new();
+    let row_cells = database
+      .get_cells_for_field(&database.get_inline_view_id(), field_id)
+      .await;
+    for cell in row_cells.into_iter().filter_map(|row_cell| row_cell.cell) {
+      for option_id in SelectOptionIds::from(&cell).to_vec() {
+        *count_by_option_id.entry(option_id).or_default() += 1;
+      }
+    }
+
+    Ok(
+      type_option
+        .options()
+        .iter()
+        .map(|option| SelectOptionUsagePB {
+          count: count_by_option_id.get(&option.id).copied().unwrap_or(0),
+          option: SelectOptionPB::from(option.clone()),
+        })
+        .collect(),
+    )
+  }
+
+  /// Update the type option of a single select or multiple select field with `update_type_option`
+  /// and rewrite the option ids of its cells with `rewrite_cell`, which returns true if the cell
+  /// was changed. The type option and the cells are updated while holding the database write lock,
+  /// so the other changes of the database can't interleave with them.
+  ///
+  /// The rewritten cells are checked like the cells of [Self::update_cells] before anything is
+  /// written: the database must be writable, the rows unlocked, and the cells must pass the unique
+  /// constraint and the installed validators. The rewritten rows are recorded in the change log and
+  /// sent to the webhooks.
+  ///
+  /// This is not a transaction: the field and every row are separate collabs that are written
+  /// one after another. If the app stops midway, the type option is already updated and some
+  /// cells may still contain the old option ids. Such ids are ignored when the cells are read,
+  /// because they don't match any option of the field.
+  async fn update_select_options_and_cells<F, G>(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    update_type_option: F,
+    rewrite_cell: G,
+  ) -> FlowyResult<()>
+  where
+    F: FnOnce(&mut Box<dyn SelectTypeOptionSharedAction>) -> FlowyResult<()>,
+    G: Fn(&mut SelectOptionIds) -> bool,
+  {
+    self.check_writable()?;
     let mut database = self.database.write().await;
     let field = match database.get_field(field_id) {
       Some(field) => Ok(field),
@@ -1234,18 +3753,65 @@ impl DatabaseEditor {
         Err(FlowyError::internal().with_context(msg))
       },
     }?;
+    let field_type = FieldType::from(field.field_type);
     let mut type_option = select_type_option_from_field(&field)?;
-    let cell_changeset = SelectOptionCellChangeset {
-      delete_option_ids: options.iter().map(|option| option.id.clone()).collect(),
-      ..Default::default()
-    };
+    update_type_option(&mut type_option)?;
+
+    let inline_view_id = database.get_inline_view_id();
+    let mut rewritten_rows = vec![];
+    for row_cell in database
+      .get_cells_for_field(&inline_view_id, field_id)
+      .await
+    {
+      let cell = match row_cell.cell {
+        None => continue,
+        Some(cell) => cell,
+      };
+      let mut select_ids = SelectOptionIds::from(&cell);
+      if !rewrite_cell(&mut select_ids) {
+        continue;
+      }
+      let Some(old_row) = database.get_row_detail(&row_cell.row_id).await else {
+        continue;
+      };
+      if is_row_locked(&old_row.row) {
+        return Err(FlowyError::new(
+          ErrorCode::RowLocked,
+          format!("The row:{} is locked", row_cell.row_id),
+        ));
+      }
+      rewritten_rows.push((
+        row_cell.row_id,
+        select_ids.to_cell_data(field_type),
+        old_row.row.clone(),
+      ));
+    }
 
-    for option in options {
-      type_option.delete_option(&option.id);
+    let mut unique_values = Self::unique_values(&database, |row_id, cell_field_id| {
+      cell_field_id == field_id
+        && rewritten_rows
+          .iter()
+          .any(|(rewritten_row_id, _, _)| rewritten_row_id == row_id)
+    })
+    .await;
+    for (_, cell, old_row) in &rewritten_rows {
+      unique_values.validate_and_insert(field_id, cell)?;
+      self.cell_validators.validate_cell(old_row, &field, cell)?;
     }
 
     let view_editors = self.database_views.editors().await;
     update_field_type_option_fn(&mut database, type_option.to_type_option_data(), &field).await?;
+    for (row_id, cell, _) in &rewritten_rows {
+      database
+        .update_row(row_id.clone(), |row_update| {
+          row_update
+            .set_last_modified(timestamp())
+            .update_cells(|cell_update| {
+              cell_update.insert(field_id, cell.clone());
+            });
+        })
+        .await;
+    }
 
     // Drop the database write lock ASAP
     drop(database);
@@ -1253,10 +3819,76 @@ impl DatabaseEditor {
     for view_editor in view_editors {
       view_editor.v_did_update_field_type_option(&field).await?;
     }
-
     self
-      .update_cell_with_changeset(view_id, &row_id, field_id, BoxAny::new(cell_changeset))
-      .await?;
+      .emit_field_webhook(WebhookEventType::FieldUpdated, field_id)
+      .await;
+
+    for (row_id, _, old_row) in rewritten_rows {
+      self
+        .emit_row_webhook(WebhookEventType::RowUpdated, &row_id)
+        .await;
+      self
+        .did_update_row(view_id, &row_id, field_id, Some(old_row.clone()))
+        .await;
+      self.run_row_automations(&row_id, Some(&old_row)).await;
+    }
+    Ok(())
+  }
+
+  /// Rename or recolor an existing option of a single select or multiple select field.
+  /// The cells only store the option ids, so they pick up the change without being rewritten.
+  pub async fn update_select_option(&self, params: UpdateSelectOptionParams) -> FlowyResult<()> {
+    self.check_writable()?;
+    let mut database = self.database.write().await;
+    let field = database.get_field(&params.field_id).ok_or_else(|| {
+      FlowyError::record_not_found()
+        .with_context(format!("Field with id:{} not found", &params.field_id))
+    })?;
+    let mut type_option = select_type_option_from_field(&field)?;
+    if type_option
+      .update_option(&params.option_id, params.name, params.color)
+      .is_none()
+    {
+      return Err(
+        FlowyError::record_not_found()
+          .with_context(format!("Select option:{} not found", params.option_id)),
+      );
+    }
+
+    let view_editors = self.database_views.editors().await;
+    update_field_type_option_fn(&mut database, type_option.to_type_option_data(), &field).await?;
+    drop(database);
+
+    for view_editor in view_editors {
+      view_editor.v_did_update_field_type_option(&field).await?;
+    }
+    Ok(())
+  }
+
+  /// Move an option of a single select or multiple select field to `to_index`. The order is
+  /// persisted in the field's type option.
+  pub async fn move_select_option(&self, params: MoveSelectOptionParams) -> FlowyResult<()> {
+    self.check_writable()?;
+    let mut database = self.database.write().await;
+    let field = database.get_field(&params.field_id).ok_or_else(|| {
+      FlowyError::record_not_found()
+        .with_context(format!("Field with id:{} not found", &params.field_id))
+    })?;
+    let mut type_option = select_type_option_from_field(&field)?;
+    if !type_option.move_option(&params.option_id, params.to_index) {
+      return Err(
+        FlowyError::record_not_found()
+          .with_context(format!("Select option:{} not found", params.option_id)),
+      );
+    }
+
+    let view_editors = self.database_views.editors().await;
+    update_field_type_option_fn(&mut database, type_option.to_type_option_data(), &field).await?;
+    drop(database);
+
+    for view_editor in view_editors {
+      view_editor.v_did_update_field_type_option(&field).await?;
+    }
     Ok(())
   }
 
@@ -1267,6 +3899,7 @@ impl DatabaseEditor {
     field_id: &str,
     changeset: ChecklistCellChangeset,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
     let field = self
       .database
       .read()
@@ -1305,6 +3938,7 @@ impl DatabaseEditor {
     from_group: &str,
     to_group: &str,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
     // Do nothing if the group is the same
     if from_group == to_group {
       return Ok(());
@@ -1316,12 +3950,14 @@ impl DatabaseEditor {
   }
 
   pub async fn group_by_field(&self, view_id: &str, field_id: &str) -> FlowyResult<()> {
+    self.check_writable()?;
     let view = self.database_views.get_or_init_view_editor(view_id).await?;
     view.v_group_by_field(field_id).await?;
     Ok(())
   }
 
   pub async fn create_group(&self, view_id: &str, name: &str) -> FlowyResult<()> {
+    self.check_writable()?;
     let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
     view_editor.v_create_group(name).await?;
     Ok(())
@@ -1333,6 +3969,7 @@ impl DatabaseEditor {
     view_id: &str,
     layout_setting: LayoutSettingChangeset,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
     let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
     view_editor.v_set_layout_settings(layout_setting).await?;
     Ok(())
@@ -1368,8 +4005,41 @@ impl DatabaseEditor {
     &self,
     view_id: &str,
   ) -> FlowyResult<Vec<NoDateCalendarEventPB>> {
-    let _database_view = self.database_views.get_or_init_view_editor(view_id).await?;
-    Ok(vec![])
+    let database_view = self.database_views.get_or_init_view_editor(view_id).await?;
+    let events = database_view
+      .v_get_all_calendar_events()
+      .await
+      .unwrap_or_default()
+      .into_iter()
+      .filter(|event| event.timestamp.is_none())
+      .map(|event| NoDateCalendarEventPB {
+        row_id: event.row_meta.id,
+        title: event.title,
+      })
+      .collect();
+    Ok(events)
+  }
+
+  /// Returns the calendar events in the range [start, end), so a calendar only needs the events
+  /// of the visible dates. The events without a date are not returned, see
+  /// [Self::get_all_no_date_calendar_events].
+  pub async fn get_calendar_events_in_range(
+    &self,
+    view_id: &str,
+    start: Option<i64>,
+    end: Option<i64>,
+  ) -> Vec<CalendarEventPB> {
+    self
+      .get_all_calendar_events(view_id)
+      .await
+      .into_iter()
+      .filter(|event| match event.timestamp {
+        None => false,
+        Some(timestamp) => {
+          start.map_or(true, |start| timestamp >= start) && end.map_or(true, |end| timestamp < end)
+        },
+      })
+      .collect()
   }
 
   #[tracing::instrument(level = "trace", skip_all)]
@@ -1389,43 +4059,347 @@ impl DatabaseEditor {
       field: FieldPB::new(field),
       index: index as i32,
     };
-    let notified_changeset = DatabaseFieldChangesetPB::insert(&database_id, vec![index_field]);
-    let _ = self.notify_did_update_database(notified_changeset).await;
+    let notified_changeset = DatabaseFieldChangesetPB::insert(&database_id, vec![index_field]);
+    let _ = self.notify_did_update_database(notified_changeset).await;
+    Ok(())
+  }
+
+  async fn notify_did_update_database(
+    &self,
+    changeset: DatabaseFieldChangesetPB,
+  ) -> FlowyResult<()> {
+    let views = self.database.read().await.get_all_database_views_meta();
+    for view in views {
+      send_notification(&view.id, DatabaseNotification::DidUpdateFields)
+        .payload(changeset.clone())
+        .send();
+    }
+
+    Ok(())
+  }
+
+  pub async fn get_database_view_setting(
+    &self,
+    view_id: &str,
+  ) -> FlowyResult<DatabaseViewSettingPB> {
+    let view = self
+      .database
+      .read()
+      .await
+      .get_view(view_id)
+      .ok_or_else(|| {
+        FlowyError::record_not_found()
+          .with_context(format!("Can't find the database view:{}", view_id))
+      })?;
+    Ok(database_view_setting_pb_from_view(view))
+  }
+
+  /// Returns all the views of the database, starting with the inline view.
+  pub async fn get_database_views(&self) -> Vec<DatabaseViewMetaPB> {
+    let database = self.database.read().await;
+    let inline_view_id = database.get_inline_view_id();
+    let views_setting = Self::database_views_setting(&database, &inline_view_id);
+    let mut view_ids = database
+      .get_all_database_views_meta()
+      .into_iter()
+      .map(|view_meta| view_meta.id)
+      .collect::<Vec<_>>();
+    view_ids.sort_by_key(|view_id| view_id != &inline_view_id);
+    let default_view_id = views_setting.default_view_id(&view_ids, &inline_view_id);
+    views_setting
+      .ordered_view_ids(&view_ids)
+      .into_iter()
+      .flat_map(|view_id| database.get_view(&view_id))
+      .map(|view| DatabaseViewMetaPB {
+        is_inline: view.id == inline_view_id,
+        is_default: view.id == default_view_id,
+        view_id: view.id,
+        name: view.name,
+        layout: view.layout.into(),
+      })
+      .collect()
+  }
+
+  /// Sets the view that opens by default when the database is opened.
+  pub async fn set_default_view(&self, view_id: &str) -> FlowyResult<()> {
+    self.check_writable()?;
+    self
+      .update_database_views_setting(view_id, |views_setting, _| {
+        views_setting.default_view_id = Some(view_id.to_string());
+        true
+      })
+      .await
+  }
+
+  /// Moves the view to the given index of the display order of the views. The index is clamped to
+  /// the number of views.
+  pub async fn move_view(&self, view_id: &str, to_index: usize) -> FlowyResult<()> {
+    self.check_writable()?;
+    self
+      .update_database_views_setting(view_id, |views_setting, view_ids| {
+        views_setting.move_view(view_ids, view_id, to_index)
+      })
+      .await
+  }
+
+  fn row_template_setting(database: &Database) -> RowTemplateSetting {
+    let layout_setting: Option<LayoutSetting> =
+      database.get_layout_setting(&database.get_inline_view_id(), &DatabaseLayout::Grid);
+    layout_setting
+      .map(RowTemplateSetting::from)
+      .unwrap_or_default()
+  }
+
+  pub async fn get_row_templates(&self) -> Vec<RowTemplate> {
+    let database = self.database.read().await;
+    Self::row_template_setting(&database).templates
+  }
+
+  /// Replaces the row templates of the database. A new id is generated for the templates without
+  /// id. Returns an error if a cell of a template refers to a field that doesn't exist or if its
+  /// value isn't valid for the type of the field.
+  pub async fn update_row_templates(&self, templates: Vec<RowTemplate>) -> FlowyResult<()> {
+    self.check_writable()?;
+    let setting = {
+      let mut database = self.database.write().await;
+      let mut setting = RowTemplateSetting::default();
+      for mut template in templates {
+        if template.id.is_empty() {
+          template.id = gen_database_filter_id();
+        }
+        for cell in template.cells.iter() {
+          let field = database
+            .get_field(&cell.field_id)
+            .ok_or_else(FlowyError::field_record_not_found)?;
+          if cell_from_text(&cell.value, &field).is_none() && !cell.value.trim().is_empty() {
+            return Err(FlowyError::invalid_data().with_context(format!(
+              "Can't convert {} to a cell of {}",
+              cell.value, field.name
+            )));
+          }
+        }
+        setting.templates.push(template);
+      }
+
+      let inline_view_id = database.get_inline_view_id();
+      let mut layout_setting: LayoutSetting = database
+        .get_layout_setting(&inline_view_id, &DatabaseLayout::Grid)
+        .unwrap_or_default();
+      setting.write_to(&mut layout_setting);
+      database.insert_layout_setting(&inline_view_id, &DatabaseLayout::Grid, layout_setting);
+      setting
+    };
+
+    send_notification(
+      &self.database_id,
+      DatabaseNotification::DidUpdateRowTemplates,
+    )
+    .payload(RepeatedRowTemplatePB::from(setting.templates.as_slice()))
+    .send();
     Ok(())
   }
 
-  async fn notify_did_update_database(
+  /// Creates a row at the end of the view with the cells of the template. The cells of the fields
+  /// that were deleted since the template was saved are skipped.
+  pub async fn create_row_from_template(
     &self,
-    changeset: DatabaseFieldChangesetPB,
-  ) -> FlowyResult<()> {
-    let views = self.database.read().await.get_all_database_views_meta();
-    for view in views {
-      send_notification(&view.id, DatabaseNotification::DidUpdateFields)
-        .payload(changeset.clone())
-        .send();
+    view_id: &str,
+    template_id: &str,
+  ) -> FlowyResult<RowDetail> {
+    self.check_writable()?;
+    let (template, fields) = {
+      let database = self.database.read().await;
+      let template = Self::row_template_setting(&database)
+        .template(template_id)
+        .cloned()
+        .ok_or_else(|| {
+          FlowyError::record_not_found()
+            .with_context(format!("Can't find the row template: {}", template_id))
+        })?;
+      (template, database.get_fields_in_view(view_id, None))
+    };
+    let cells = template
+      .cells
+      .iter()
+      .filter_map(|cell| {
+        let field = fields.iter().find(|field| field.id == cell.field_id)?;
+        Some((field.id.clone(), cell_from_text(&cell.value, field)?))
+      })
+      .collect::<HashMap<_, _>>();
+
+    let params = CreateRowPayloadPB {
+      view_id: view_id.to_string(),
+      ..Default::default()
+    };
+    self
+      .create_row_with_cells(params, cells)
+      .await?
+      .ok_or_else(|| FlowyError::internal().with_context("Failed to create the row"))
+  }
+
+  fn database_views_setting(database: &Database, inline_view_id: &str) -> DatabaseViewsSetting {
+    let layout_setting: Option<LayoutSetting> =
+      database.get_layout_setting(inline_view_id, &DatabaseLayout::Grid);
+    layout_setting
+      .map(DatabaseViewsSetting::from)
+      .unwrap_or_default()
+  }
+
+  async fn update_database_views_setting<F>(&self, view_id: &str, f: F) -> FlowyResult<()>
+  where
+    F: FnOnce(&mut DatabaseViewsSetting, &[String]) -> bool,
+  {
+    {
+      let mut database = self.database.write().await;
+      let inline_view_id = database.get_inline_view_id();
+      let mut view_ids = database
+        .get_all_database_views_meta()
+        .into_iter()
+        .map(|view_meta| view_meta.id)
+        .collect::<Vec<_>>();
+      if !view_ids.iter().any(|id| id == view_id) {
+        return Err(
+          FlowyError::record_not_found().with_context(format!("Can't find the view: {}", view_id)),
+        );
+      }
+      view_ids.sort_by_key(|id| id != &inline_view_id);
+
+      let mut views_setting = Self::database_views_setting(&database, &inline_view_id);
+      if !f(&mut views_setting, &view_ids) {
+        return Ok(());
+      }
+      let mut layout_setting: LayoutSetting = database
+        .get_layout_setting(&inline_view_id, &DatabaseLayout::Grid)
+        .unwrap_or_default();
+      views_setting.write_to(&mut layout_setting);
+      database.insert_layout_setting(&inline_view_id, &DatabaseLayout::Grid, layout_setting);
     }
 
+    let items = self.get_database_views().await;
+    send_notification(
+      &self.database_id,
+      DatabaseNotification::DidUpdateDatabaseViews,
+    )
+    .payload(RepeatedDatabaseViewMetaPB { items })
+    .send();
     Ok(())
   }
 
-  pub async fn get_database_view_setting(
-    &self,
-    view_id: &str,
-  ) -> FlowyResult<DatabaseViewSettingPB> {
-    let view = self
+  /// Duplicates the view into a new view of the same database. The new view copies the layout,
+  /// filters, sorts, grouping, field settings and row order of the view in a single transaction.
+  pub async fn duplicate_view(&self, view_id: &str) -> FlowyResult<DatabaseViewMetaPB> {
+    self.check_writable()?;
+    let mut database = self.database.write().await;
+    let view = database.duplicate_linked_view(view_id).ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Can't find the view: {}", view_id))
+    })?;
+    Ok(DatabaseViewMetaPB {
+      view_id: view.id,
+      name: view.name,
+      layout: view.layout.into(),
+      is_inline: false,
+      is_default: false,
+    })
+  }
+
+  /// Exports the filters, sorts, grouping and field visibility of the view as JSON. None of the
+  /// rows of the view are exported.
+  pub async fn export_view_settings(&self, view_id: &str) -> FlowyResult<String> {
+    let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
+    let fields = self.get_fields(view_id, None).await;
+    let filters = view_editor.v_get_all_filters().await;
+    let sorts = view_editor.v_get_all_sorts().await;
+    let group_setting: Option<GroupSetting> = self
       .database
       .read()
       .await
-      .get_view(view_id)
-      .ok_or_else(|| {
-        FlowyError::record_not_found()
-          .with_context(format!("Can't find the database view:{}", view_id))
-      })?;
-    Ok(database_view_setting_pb_from_view(view))
+      .get_all_group_setting(view_id)
+      .into_iter()
+      .next();
+    let field_settings = self.get_all_field_settings(view_id).await?;
+    ViewSettingsTemplate::new(
+      &fields,
+      &filters,
+      &sorts,
+      group_setting.as_ref(),
+      &field_settings,
+    )
+    .to_json()
+  }
+
+  /// Applies the settings exported by [Self::export_view_settings] to the view, replacing its
+  /// filters and sorts. Nothing is applied if a filter, a sort or the grouping references a field
+  /// that this database doesn't have. The grouping is only applied to a board view.
+  pub async fn import_view_settings(&self, view_id: &str, json: &str) -> FlowyResult<()> {
+    self.check_writable()?;
+    let fields = self.get_fields(view_id, None).await;
+    let settings = ViewSettingsTemplate::from_json(json)?.resolve(&fields)?;
+    let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
+
+    for filter in view_editor.v_get_all_filters().await {
+      view_editor
+        .v_modify_filters(FilterChangeset::Delete {
+          filter_id: filter.id,
+        })
+        .await?;
+    }
+    for data in settings.filters {
+      view_editor
+        .v_modify_filters(FilterChangeset::Insert {
+          parent_filter_id: None,
+          data,
+        })
+        .await?;
+    }
+
+    view_editor.v_delete_all_sorts().await?;
+    for (field_id, condition, collation) in settings.sorts {
+      let params = UpdateSortPayloadPB {
+        view_id: view_id.to_string(),
+        field_id,
+        sort_id: None,
+        condition: condition.into(),
+        collation: collation.into(),
+      };
+      view_editor.v_create_or_update_sort(params).await?;
+    }
+
+    if let Some((field, content)) = settings.group {
+      if view_editor.v_get_layout_type().await == DatabaseLayout::Board {
+        let setting_content = if content.is_empty() {
+          vec![]
+        } else {
+          group_config_json_to_pb(content, &FieldType::from(field.field_type)).to_vec()
+        };
+        self
+          .set_group_by_field(view_id, &field.id, setting_content)
+          .await?;
+      }
+    }
+
+    for (field_id, visibility) in settings.field_visibilities {
+      let params = FieldSettingsChangesetPB {
+        view_id: view_id.to_string(),
+        field_id,
+        visibility: Some(visibility),
+        width: None,
+        wrap_cell_content: None,
+      };
+      view_editor.v_update_field_settings(params).await?;
+    }
+    Ok(())
   }
 
   pub async fn close_database(&self) {
     info!("[Database]: {} close", self.database_id);
+    self.stop_mirror();
+    if let Ok(uid) = self.user.user_id() {
+      let database = self.database.read().await;
+      self
+        .collab_builder
+        .create_snapshot_on_close(uid, &CollabType::Database, &*database);
+    }
+
     let token = CancellationToken::new();
     let cloned_finalized_rows = self.finalized_rows.clone();
     self
@@ -1507,10 +4481,7 @@ impl DatabaseEditor {
       };
 
       // the order_rows are not filtered and sorted yet
-      let mut order_rows = row_orders
-        .iter()
-        .map(|row_order| RowMetaPB::from(row_order.clone()))
-        .collect::<Vec<RowMetaPB>>();
+      let mut order_rows = to_pb_vec::<RowOrder, RowMetaPB, _>(&row_orders);
 
       trace!(
         "[Database]: database: {}, num fields: {}, num rows: {}",
@@ -1527,10 +4498,7 @@ impl DatabaseEditor {
       if blocking_read {
         // the rows returned here are applied with filters and sorts
         if let Ok(rows) = rx.await {
-          order_rows = rows
-            .into_iter()
-            .map(|row| RowMetaPB::from(row.as_ref()))
-            .collect();
+          order_rows = RepeatedRowMetaPB::from(rows.as_slice()).items;
         }
       }
 
@@ -1544,6 +4512,7 @@ impl DatabaseEditor {
         rows: order_rows,
         layout_type: view_layout.into(),
         is_linked,
+        is_read_only: self.is_read_only(),
       });
       // Mark that the opening process is complete
       if let Some(tx) = self.is_loading_rows.load_full() {
@@ -1623,10 +4592,18 @@ impl DatabaseEditor {
           }
         };
 
+      // A large view without filters, sorts, groups or calculations only loads its first rows,
+      // so opening it doesn't load every row in memory
+      let load_all_rows = blocking_read || view_editor.needs_all_rows().await;
       let mut loaded_rows = vec![];
       const CHUNK_SIZE: usize = 20;
       let row_orders = view_editor.row_orders.read().await;
-      let row_orders_chunks = row_orders.chunks(CHUNK_SIZE).collect::<Vec<_>>();
+      let row_orders_to_load = if load_all_rows {
+        &row_orders[..]
+      } else {
+        &row_orders[..row_orders.len().min(OPEN_VIEW_LOADED_ROWS)]
+      };
+      let row_orders_chunks = row_orders_to_load.chunks(CHUNK_SIZE).collect::<Vec<_>>();
 
       // Iterate over chunks and load rows concurrently
       for chunk_row_orders in row_orders_chunks {
@@ -1663,8 +4640,9 @@ impl DatabaseEditor {
       drop(row_orders);
 
       info!(
-        "[Database]: Finish loading all rows: {}, blocking: {}",
+        "[Database]: Finish loading rows: {}, all rows: {}, blocking: {}",
         loaded_rows.len(),
+        load_all_rows,
         blocking_read
       );
       let loaded_rows = apply_filter_and_sort(loaded_rows, view_editor.clone()).await;
@@ -1681,16 +4659,515 @@ impl DatabaseEditor {
     });
   }
 
-  pub async fn export_csv(&self, style: CSVFormat) -> FlowyResult<String> {
+  /// Exports the database as CSV. The values of sensitive fields are masked unless
+  /// `reveal_sensitive` is true.
+  pub async fn export_csv(&self, style: CSVFormat, reveal_sensitive: bool) -> FlowyResult<String> {
     let database = self.database.clone();
     let database_guard = database.read().await;
     let csv = CSVExport
-      .export_database(&database_guard, style)
+      .export_database(&database_guard, style, reveal_sensitive)
       .await
       .map_err(internal_error)?;
     Ok(csv)
   }
 
+  /// Exports the rows of the view as CSV, in the order of the view and with its filters applied.
+  /// Each cell is formatted the way its field displays it. The fields hidden in the view are
+  /// skipped unless [CSVExportOptions::include_hidden_fields] is set.
+  pub async fn export_view_csv(
+    &self,
+    view_id: &str,
+    options: CSVExportOptions,
+    reveal_sensitive: bool,
+  ) -> FlowyResult<String> {
+    let fields = self
+      .get_fields_for_export(view_id, options.include_hidden_fields)
+      .await?;
+    let rows = self.get_all_rows(view_id).await?;
+    CSVExport.export_rows(&fields, &rows, &options, reveal_sensitive)
+  }
+
+  /// Exports each view of the database as a sheet of an xlsx workbook, in the display order of
+  /// the views. A sheet has the rows of its view with the filters and sorts applied, and skips
+  /// the fields that are hidden in the view.
+  pub async fn export_xlsx(&self, reveal_sensitive: bool) -> FlowyResult<Vec<u8>> {
+    let mut sheets = vec![];
+    for view in self.get_database_views().await {
+      let fields = self.get_fields_for_export(&view.view_id, false).await?;
+      let rows = self.get_all_rows(&view.view_id).await?;
+      sheets.push(XLSXSheet {
+        name: view.name,
+        fields,
+        rows,
+      });
+    }
+    tokio::task::spawn_blocking(move || XLSXExport.export_sheets(&sheets, reveal_sensitive))
+      .await
+      .map_err(internal_error)?
+  }
+
+  /// Exports the rows of the view that have a date in `date_field_id` as the events of an
+  /// iCalendar feed named after the view, see [ICalExport]. The events are titled with the value
+  /// of `title_field_id`, or of the primary field if it's empty.
+  pub async fn export_ical(
+    &self,
+    view_id: &str,
+    date_field_id: &str,
+    title_field_id: &str,
+    reveal_sensitive: bool,
+  ) -> FlowyResult<String> {
+    let date_field = self
+      .get_field(date_field_id)
+      .await
+      .ok_or_else(|| FlowyError::record_not_found().with_context("Can't find the date field"))?;
+    if !matches!(
+      FieldType::from(date_field.field_type),
+      FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime
+    ) {
+      return Err(FlowyError::invalid_data().with_context("The field isn't a date field"));
+    }
+    let title_field = if title_field_id.is_empty() {
+      self.database.read().await.get_primary_field()
+    } else {
+      self.get_field(title_field_id).await
+    }
+    .ok_or_else(|| FlowyError::record_not_found().with_context("Can't find the title field"))?;
+    let calendar_name = self
+      .database
+      .read()
+      .await
+      .get_view(view_id)
+      .map(|view| view.name)
+      .unwrap_or_default();
+    let rows = self.get_all_rows(view_id).await?;
+    ICalExport.export_rows(
+      &calendar_name,
+      &rows,
+      &date_field,
+      &title_field,
+      reveal_sensitive,
+    )
+  }
+
+  async fn get_fields_for_export(
+    &self,
+    view_id: &str,
+    include_hidden_fields: bool,
+  ) -> FlowyResult<Vec<Field>> {
+    let mut fields = self.get_fields(view_id, None).await;
+    if !include_hidden_fields {
+      let hidden_field_ids = self
+        .get_all_field_settings(view_id)
+        .await?
+        .into_iter()
+        .filter(|field_settings| field_settings.visibility == FieldVisibility::AlwaysHidden)
+        .map(|field_settings| field_settings.field_id)
+        .collect::<HashSet<_>>();
+      fields.retain(|field| !hidden_field_ids.contains(&field.id));
+    }
+    Ok(fields)
+  }
+
+  /// Exports the fields and rows of the view as portable JSON, see [GridJson]. The values of
+  /// sensitive fields are masked unless `reveal_sensitive` is true.
+  pub async fn export_json(&self, view_id: &str, reveal_sensitive: bool) -> FlowyResult<String> {
+    let database = self.database.read().await;
+    GridJson::from_database(&database, view_id, reveal_sensitive)
+      .await?
+      .to_json()
+  }
+
+  /// Exports the rows of the view as portable JSON like [Self::export_json], but in the order of
+  /// the view and with its filters applied. The fields hidden in the view are skipped unless
+  /// `include_hidden_fields` is true.
+  pub async fn export_view_json(
+    &self,
+    view_id: &str,
+    include_hidden_fields: bool,
+    reveal_sensitive: bool,
+  ) -> FlowyResult<String> {
+    let fields = self
+      .get_fields_for_export(view_id, include_hidden_fields)
+      .await?;
+    let rows = self.get_all_rows(view_id).await?;
+    GridJson::from_rows(
+      &fields,
+      rows.iter().map(|row| row.as_ref()),
+      reveal_sensitive,
+    )?
+    .to_json()
+  }
+
+  /// Exports the rows of the view as a markdown table, with the filters and sorts of the view
+  /// applied and without the hidden fields. The values of the sensitive fields are masked.
+  pub async fn export_view_markdown(&self, view_id: &str) -> FlowyResult<String> {
+    let fields = self.get_fields_for_export(view_id, false).await?;
+    let rows = self.get_all_rows(view_id).await?;
+    Ok(markdown_table_from_rows(&fields, &rows, false))
+  }
+
+  /// Returns the rows and fields of the view that changed after the revision, see [ChangeLog].
+  /// Only the changes made on this device since the app started are known, so the caller is
+  /// asked to export the database as a whole if the revision is older.
+  pub async fn export_changes_since(
+    &self,
+    view_id: &str,
+    rev_id: i64,
+  ) -> FlowyResult<DatabaseChangesPB> {
+    let changes = self.change_log.changes_since(&self.database_id, rev_id);
+    if changes.is_full_export_required {
+      return Ok(DatabaseChangesPB {
+        rev_id: changes.rev_id,
+        is_full_export_required: true,
+        ..Default::default()
+      });
+    }
+
+    let fields = self.get_fields(view_id, None).await;
+    let field_pbs = |field_ids: &[String]| {
+      fields
+        .iter()
+        .filter(|field| field_ids.contains(&field.id))
+        .map(|field| FieldPB::new(field.clone()))
+        .collect::<Vec<_>>()
+    };
+    Ok(DatabaseChangesPB {
+      rev_id: changes.rev_id,
+      is_full_export_required: false,
+      created_rows: self
+        .row_samples(view_id, &changes.created_row_ids, &fields)
+        .await,
+      updated_rows: self
+        .row_samples(view_id, &changes.updated_row_ids, &fields)
+        .await,
+      deleted_row_ids: changes.deleted_row_ids,
+      created_fields: field_pbs(&changes.created_field_ids),
+      updated_fields: field_pbs(&changes.updated_field_ids),
+      deleted_field_ids: changes.deleted_field_ids,
+    })
+  }
+
+  /// Exports the view as a template that seeds new grids when it's imported. The template has
+  /// the fields with their type options and, if `include_rows` is true, the rows as seed data.
+  /// The values of sensitive fields in the rows are masked unless `reveal_sensitive` is true.
+  pub async fn export_template(
+    &self,
+    view_id: &str,
+    include_rows: bool,
+    reveal_sensitive: bool,
+  ) -> FlowyResult<String> {
+    let database = self.database.read().await;
+    let mut grid = GridJson::from_database(&database, view_id, reveal_sensitive).await?;
+    if !include_rows {
+      grid.rows.clear();
+    }
+    grid.to_json()
+  }
+
+  /// Appends the rows of the CSV data to the database of the view. The columns are matched to
+  /// the fields by name and the columns without a matching field are skipped. The values of a
+  /// select column that aren't an option of its field yet are added as new options.
+  ///
+  /// The data is read in batches on a blocking thread while the rows of the previous batch are
+  /// created, so a large file is never loaded into memory as a whole. Returns the number of
+  /// appended rows.
+  pub async fn append_csv(&self, view_id: &str, source: CSVSource) -> FlowyResult<usize> {
+    self.check_writable()?;
+    let (columns, fields) = self.prepare_csv_columns(view_id, &source).await?;
+
+    let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+    let database_id = self.database_id.clone();
+    let reader = tokio::task::spawn_blocking(move || {
+      csv_rows_in_batches(
+        &source,
+        &database_id,
+        &columns,
+        &fields,
+        CSV_APPEND_BATCH_SIZE,
+        |rows| tx.blocking_send(rows).map_err(internal_error),
+      )
+    });
+
+    let mut count = 0;
+    while let Some(rows) = rx.recv().await {
+      count += self.insert_rows(view_id, rows).await?.len();
+    }
+    reader.await.map_err(internal_error)??;
+    trace!("[Database]: appended {} rows from csv", count);
+    Ok(count)
+  }
+
+  /// Creates the rows at their positions in the view, in order. The database is locked once for
+  /// all the rows, so other changes can't land between them, and the rows are recorded in the
+  /// change log at a single revision. Returns the orders of the created rows.
+  ///
+  /// The rows whose id is the id of an existing row, or of a row before them, are skipped, so the
+  /// rows can be inserted again after an import was interrupted without duplicating them. If a row
+  /// can't be created, the rows before it are kept and recorded, and the error is returned. A row
+  /// with the value of a unique field that an existing row or a row before it has can't be
+  /// created.
+  ///
+  /// Like [Self::create_row], the cells that a row doesn't have are filled in with the default
+  /// values of their fields, see [default_cell_for_field].
+  pub async fn insert_rows(
+    &self,
+    view_id: &str,
+    rows: Vec<CreateRowParams>,
+  ) -> FlowyResult<Vec<RowOrder>> {
+    self.check_writable()?;
+    let mut row_orders = Vec::with_capacity(rows.len());
+    let mut result = Ok(());
+    {
+      let mut database = self.database.write().await;
+      let mut existing_row_ids = Self::existing_row_ids(&database, view_id);
+      let mut unique_values = Self::unique_values(&database, |_, _| false).await;
+      let default_cells = database
+        .get_fields_in_view(view_id, None)
+        .iter()
+        .filter_map(|field| default_cell_for_field(field).map(|cell| (field.id.clone(), cell)))
+        .collect::<Vec<_>>();
+      for mut params in rows {
+        if !existing_row_ids.insert(params.id.to_string()) {
+          trace!("[Database]: skip inserting the existing row {}", params.id);
+          continue;
+        }
+        for (field_id, cell) in default_cells.iter() {
+          if !params.cells.contains_key(field_id) {
+            params.cells.insert(field_id.clone(), cell.clone());
+          }
+        }
+        if let Err(err) = params
+          .cells
+          .iter()
+          .try_for_each(|(field_id, cell)| unique_values.validate_and_insert(field_id, cell))
+        {
+          result = Err(err);
+          break;
+        }
+        match database.create_row_in_view(view_id, params).await {
+          Ok((_, row_order)) => row_orders.push(row_order),
+          Err(err) => {
+            result = Err(err);
+            break;
+          },
+        }
+      }
+    }
+
+    let row_ids = row_orders
+      .iter()
+      .map(|row_order| row_order.id.as_str())
+      .collect::<Vec<_>>();
+    self
+      .change_log
+      .record_all(&self.database_id, WebhookEventType::RowCreated, &row_ids);
+    for row_order in row_orders.iter() {
+      self
+        .send_row_webhook(WebhookEventType::RowCreated, &row_order.id)
+        .await;
+    }
+    trace!("[Database]: inserted {} rows", row_orders.len());
+    result?;
+    Ok(row_orders)
+  }
+
+  /// Matches the columns of the CSV data to the fields of the view and adds the missing options
+  /// of the select fields. Returns the matched field of each column and the updated fields.
+  async fn prepare_csv_columns(
+    &self,
+    view_id: &str,
+    source: &CSVSource,
+  ) -> FlowyResult<(Vec<Option<usize>>, Vec<Field>)> {
+    let mut fields = self.get_fields(view_id, None).await;
+    let (columns, missing_options) = {
+      let source = source.clone();
+      let fields = fields.clone();
+      tokio::task::spawn_blocking(move || -> FlowyResult<_> {
+        let columns = match_csv_columns(&source, &fields)?;
+        let missing_options = missing_select_options(&source, &columns, &fields)?;
+        Ok((columns, missing_options))
+      })
+      .await
+      .map_err(internal_error)??
+    };
+
+    for (field_id, names) in missing_options {
+      let mut database = self.database.write().await;
+      let Some(field) = database.get_field(&field_id) else {
+        continue;
+      };
+      let mut type_option = select_type_option_from_field(&field)?;
+      for name in names {
+        let option = type_option.create_option(&name);
+        type_option.mut_options().push(option);
+      }
+      let view_editors = self.database_views.editors().await;
+      update_field_type_option_fn(&mut database, type_option.to_type_option_data(), &field).await?;
+      let updated_field = database.get_field(&field_id);
+      drop(database);
+
+      for view_editor in view_editors {
+        view_editor.v_did_update_field_type_option(&field).await?;
+      }
+      if let Some(updated_field) = updated_field {
+        if let Some(field) = fields.iter_mut().find(|field| field.id == field_id) {
+          *field = updated_field;
+        }
+      }
+    }
+    Ok((columns, fields))
+  }
+
+  /// Updates the rows of the view to match the rows pulled from the mirrored table. The rows
+  /// are matched by the value of the key column: the matched rows are updated, the new rows are
+  /// created and the rows that aren't in the table anymore are removed. The database must be
+  /// mirrored, see [Self::is_mirrored].
+  pub async fn sync_mirror(&self, view_id: &str, table: MirrorTable) -> FlowyResult<()> {
+    if !self.is_mirrored() {
+      return Err(FlowyError::invalid_data().with_context("The database isn't mirrored"));
+    }
+    let source = table.to_csv_source()?;
+    let (columns, fields) = self.prepare_csv_columns(view_id, &source).await?;
+    let key_field = columns
+      .get(table.key_column)
+      .copied()
+      .flatten()
+      .map(|index| fields[index].clone())
+      .ok_or_else(|| {
+        FlowyError::invalid_data().with_context("The key column isn't mapped to a field")
+      })?;
+    let mapped_fields = columns
+      .iter()
+      .flatten()
+      .map(|index| fields[*index].clone())
+      .collect::<Vec<_>>();
+    let pulled_rows = {
+      let database_id = self.database_id.clone();
+      tokio::task::spawn_blocking(move || -> FlowyResult<_> {
+        let mut pulled_rows = vec![];
+        csv_rows_in_batches(
+          &source,
+          &database_id,
+          &columns,
+          &fields,
+          CSV_APPEND_BATCH_SIZE,
+          |rows| {
+            pulled_rows.extend(rows);
+            Ok(())
+          },
+        )?;
+        Ok(pulled_rows)
+      })
+      .await
+      .map_err(internal_error)??
+    };
+
+    let row_key = |cells: &Cells| {
+      cells
+        .get(&key_field.id)
+        .map(|cell| stringify_cell(cell, &key_field))
+        .unwrap_or_default()
+    };
+    // The existing rows by key, including the rows hidden by the filters of the view. The rows
+    // with a duplicate key are removed.
+    let rows = {
+      let database = self.database.read().await;
+      database
+        .get_rows_for_view(view_id, None)
+        .await
+        .filter_map(|result| async { result.ok() })
+        .collect::<Vec<_>>()
+        .await
+    };
+    let mut existing_rows = HashMap::new();
+    let mut removed_row_ids = vec![];
+    for row in rows {
+      let key = row_key(&row.cells);
+      if key.is_empty() || existing_rows.contains_key(&key) {
+        removed_row_ids.push(row.id.clone());
+      } else {
+        existing_rows.insert(key, row);
+      }
+    }
+
+    let mut pulled_keys = HashSet::new();
+    let (mut created, mut updated) = (0, 0);
+    for params in pulled_rows {
+      let key = row_key(&params.cells);
+      if key.is_empty() || !pulled_keys.insert(key.clone()) {
+        continue;
+      }
+      let Some(row) = existing_rows.remove(&key) else {
+        self
+          .database
+          .write()
+          .await
+          .create_row_in_view(view_id, params)
+          .await?;
+        created += 1;
+        continue;
+      };
+
+      let changed_fields = mapped_fields
+        .iter()
+        .filter(|field| {
+          let old = row
+            .cells
+            .get(&field.id)
+            .map(|cell| stringify_cell(cell, field));
+          let new = params
+            .cells
+            .get(&field.id)
+            .map(|cell| stringify_cell(cell, field));
+          old.unwrap_or_default() != new.unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+      if changed_fields.is_empty() {
+        continue;
+      }
+      let old_row = self.get_row(view_id, &row.id).await;
+      self
+        .apply_row_update(row.id.clone(), |row_update| {
+          row_update
+            .set_last_modified(timestamp())
+            .update_cells(|mut cell_update| {
+              for field in changed_fields {
+                cell_update = match params.cells.get(&field.id) {
+                  Some(cell) => cell_update.insert(&field.id, cell.clone()),
+                  None => cell_update.clear(&field.id),
+                };
+              }
+            });
+        })
+        .await?;
+      if let Some(new_row) = self.get_row(view_id, &row.id).await {
+        for view in self.database_views.editors().await {
+          view.v_did_update_row(&old_row, &new_row, None).await;
+        }
+      }
+      updated += 1;
+    }
+
+    removed_row_ids.extend(existing_rows.into_values().map(|row| row.id));
+    let removed = removed_row_ids.len();
+    if !removed_row_ids.is_empty() {
+      let _ = self
+        .database
+        .write()
+        .await
+        .remove_rows(&removed_row_ids)
+        .await;
+    }
+    trace!(
+      "[Database]: synced mirror of {}: {} created, {} updated, {} removed",
+      self.database_id,
+      created,
+      updated,
+      removed
+    );
+    Ok(())
+  }
+
   pub async fn get_field_settings(
     &self,
     view_id: &str,
@@ -1722,6 +5199,22 @@ impl DatabaseEditor {
     &self,
     params: FieldSettingsChangesetPB,
   ) -> FlowyResult<()> {
+    self.check_writable()?;
+    let field = self
+      .get_field(&params.field_id)
+      .await
+      .ok_or_else(FlowyError::field_record_not_found)?;
+
+    // The primary field identifies the rows, so it's visible in every view
+    if field.is_primary
+      && params
+        .visibility
+        .as_ref()
+        .is_some_and(|visibility| *visibility != FieldVisibility::AlwaysShown)
+    {
+      return Err(FlowyError::invalid_data().with_context("The primary field can't be hidden"));
+    }
+
     let view = self
       .database_views
       .get_or_init_view_editor(&params.view_id)
@@ -1,7 +1,13 @@
-use crate::entities::{DatabaseSyncStatePB, DidFetchRowPB, RowsChangePB};
+use crate::entities::{
+  CellEditingStatePB, DatabaseSyncState, DatabaseSyncStatePB, DidFetchRowPB,
+  RepeatedCellEditingStatePB, RowsChangePB,
+};
 use crate::notification::{send_notification, DatabaseNotification, DATABASE_OBSERVABLE_SOURCE};
-use crate::services::database::{DatabaseEditor, UpdatedRow};
+use crate::services::change_log::ChangeLog;
+use crate::services::database::{CellEditingState, DatabaseEditor, UpdatedRow};
 use crate::services::database_view::DatabaseViewEditor;
+use crate::services::webhook::WebhookEventType;
+use arc_swap::ArcSwapOption;
 use collab::lock::RwLock;
 use collab_database::blocks::BlockEvent;
 use collab_database::database::Database;
@@ -12,10 +18,17 @@ use dashmap::DashMap;
 use flowy_notification::{DebounceNotificationSender, NotificationBuilder};
 use futures::StreamExt;
 use lib_dispatch::prelude::af_spawn;
+use lib_infra::util::timestamp;
 use std::sync::Arc;
 use tracing::{error, trace, warn};
 
-pub(crate) async fn observe_sync_state(database_id: &str, database: &Arc<RwLock<Database>>) {
+/// Sends the sync state of the database to the frontend, and keeps the latest one in
+/// `latest_sync_state` for the diagnostics of the editor.
+pub(crate) async fn observe_sync_state(
+  database_id: &str,
+  database: &Arc<RwLock<Database>>,
+  latest_sync_state: Arc<ArcSwapOption<DatabaseSyncState>>,
+) {
   let weak_database = Arc::downgrade(database);
   let mut sync_state = database.read().await.subscribe_sync_state();
   let database_id = database_id.to_string();
@@ -25,22 +38,72 @@ pub(crate) async fn observe_sync_state(database_id: &str, database: &Arc<RwLock<
         break;
       }
 
+      let sync_state = DatabaseSyncStatePB::from(sync_state);
+      latest_sync_state.store(Some(Arc::new(sync_state.value)));
       send_notification(
         &database_id,
         DatabaseNotification::DidUpdateDatabaseSyncUpdate,
       )
-      .payload(DatabaseSyncStatePB::from(sync_state))
+      .payload(sync_state)
       .send();
     }
   });
 }
 
+/// Sends the cells that are being edited by the other collaborators to the frontend whenever the
+/// awareness states of the database change.
+pub(crate) async fn observe_cell_editing_states(
+  database_id: &str,
+  database: &Arc<RwLock<Database>>,
+) {
+  let database_id = database_id.to_string();
+  let database = database.read().await;
+  let awareness = database.get_awareness();
+  let local_client_id = awareness.client_id();
+  awareness.on_update_with("cell_editing", move |awareness, _, _| {
+    let update = match awareness.update() {
+      Ok(update) => update,
+      Err(err) => {
+        warn!("Failed to read the awareness states: {}", err);
+        return;
+      },
+    };
+    let now = timestamp();
+    let items = update
+      .clients
+      .iter()
+      .filter(|(client_id, _)| **client_id != local_client_id)
+      .flat_map(|(_, entry)| serde_json::from_str::<CellEditingState>(&entry.json).ok())
+      .filter(|state| !state.is_expired(now))
+      .flat_map(|state| {
+        state.cell.map(|cell| CellEditingStatePB {
+          uid: state.uid,
+          view_id: cell.view_id,
+          row_id: cell.row_id,
+          field_id: cell.field_id,
+          timestamp: state.timestamp,
+        })
+      })
+      .collect();
+    send_notification(
+      &database_id,
+      DatabaseNotification::DidUpdateCellEditingStates,
+    )
+    .payload(RepeatedCellEditingStatePB { items })
+    .send();
+  });
+}
+
+/// Notifies the frontend of the edited cells, and records the rows in the [ChangeLog] since the
+/// cells edited by the other collaborators don't go through the editor.
 pub(crate) async fn observe_rows_change(
   database_id: &str,
   database: &Arc<RwLock<Database>>,
   notification_sender: &Arc<DebounceNotificationSender>,
+  change_log: &ChangeLog,
 ) {
   let notification_sender = notification_sender.clone();
+  let change_log = change_log.clone();
   let database_id = database_id.to_string();
   let weak_database = Arc::downgrade(database);
   let sub = database.read().await.subscribe_row_change();
@@ -59,6 +122,11 @@ pub(crate) async fn observe_rows_change(
               row_id,
               value: _,
             } => {
+              change_log.record_observed(
+                &database_id,
+                WebhookEventType::RowUpdated,
+                row_id.as_str(),
+              );
               let cell_id = format!("{}:{}", row_id, field_id);
               notify_cell(&notification_sender, &cell_id);
 
@@ -78,8 +146,15 @@ pub(crate) async fn observe_rows_change(
     });
   }
 }
-#[allow(dead_code)]
-pub(crate) async fn observe_field_change(database_id: &str, database: &Arc<RwLock<Database>>) {
+
+/// Records the created, updated and deleted fields in the [ChangeLog], including the ones changed
+/// by the other collaborators.
+pub(crate) async fn observe_field_change(
+  database_id: &str,
+  database: &Arc<RwLock<Database>>,
+  change_log: &ChangeLog,
+) {
+  let change_log = change_log.clone();
   let database_id = database_id.to_string();
   let weak_database = Arc::downgrade(database);
   let sub = database.read().await.subscribe_field_change();
@@ -95,11 +170,12 @@ pub(crate) async fn observe_field_change(database_id: &str, database: &Arc<RwLoc
           database_id,
           field_change
         );
-        match field_change {
-          FieldChange::DidUpdateField { .. } => {},
-          FieldChange::DidCreateField { .. } => {},
-          FieldChange::DidDeleteField { .. } => {},
-        }
+        let (event, field_id) = match field_change {
+          FieldChange::DidUpdateField { field } => (WebhookEventType::FieldUpdated, field.id),
+          FieldChange::DidCreateField { field } => (WebhookEventType::FieldCreated, field.id),
+          FieldChange::DidDeleteField { field_id } => (WebhookEventType::FieldDeleted, field_id),
+        };
+        change_log.record_observed(&database_id, event, &field_id);
       }
     });
   }
@@ -191,6 +267,17 @@ async fn handle_did_update_row_orders(
   // Delete row: Next, we delete a from its original position at index 0.
   // Delete row indexes: [0]
   // Final state after delete: [b, a, c]
+  if database_editor.database.read().await.get_inline_view_id() == view_id {
+    database_editor.did_update_row_count(insert_row_orders.len(), delete_row_indexes.len());
+    // The rows inserted or deleted by the other collaborators can't be told apart from the moved
+    // ones by their indexes, so the external sync tools are asked to export the database again.
+    if !is_local_change {
+      database_editor
+        .change_log
+        .require_full_export(&database_editor.database_id);
+    }
+  }
+
   let row_changes = DashMap::new();
   // 1. handle insert row orders
   for (row_order, index) in insert_row_orders {
@@ -261,7 +348,8 @@ async fn handle_did_update_row_orders(
             .v_did_delete_row(&row, row_change.is_move_row, is_local_change)
             .await;
         } else {
-          error!("[RowOrder]: row not found: {} in cache", lazy_row.id);
+          // The rows of a large view are only cached once they're loaded
+          trace!("[RowOrder]: row not loaded: {} in cache", lazy_row.id);
         }
       } else {
         warn!(
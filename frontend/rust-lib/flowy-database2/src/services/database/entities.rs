@@ -1,6 +1,8 @@
 use collab_database::rows::{RowDetail, RowId};
 use collab_database::views::DatabaseLayout;
 
+use crate::entities::DatabaseSyncState;
+
 #[derive(Debug, Clone)]
 pub enum DatabaseRowEvent {
   InsertRow(InsertedRow),
@@ -64,3 +66,22 @@ pub struct CreateDatabaseViewParams {
   pub view_id: String,
   pub layout_type: DatabaseLayout,
 }
+
+/// The state of an open database editor, used to inspect the memory and sync issues at runtime.
+#[derive(Debug, Clone)]
+pub struct DatabaseEditorDiagnostics {
+  pub database_id: String,
+  /// The views that are opened in the editor.
+  pub view_ids: Vec<String>,
+  /// The latest sync state of the database, or None if it never synced, e.g. for a local user.
+  /// The collab doesn't expose the number of unsynced updates, a database with local updates
+  /// that are not acknowledged by the server yet is [DatabaseSyncState::Syncing].
+  pub sync_state: Option<DatabaseSyncState>,
+  pub num_of_cached_cells: usize,
+  /// The number of rows kept in memory, including the rows that are loaded but not displayed.
+  pub num_of_loaded_rows: u64,
+  /// The timestamp in seconds of the last time the editor was requested.
+  pub last_activity: i64,
+  /// The editor has no opened view and waits to be dropped.
+  pub is_closing: bool,
+}
@@ -1,8 +1,10 @@
+mod cell_editing;
 mod database_editor;
 mod database_observe;
 mod entities;
 mod util;
 
+pub use cell_editing::*;
 pub use database_editor::*;
 pub use entities::*;
 pub(crate) use util::database_view_setting_pb_from_view;
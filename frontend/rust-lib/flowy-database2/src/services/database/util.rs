@@ -1,13 +1,14 @@
 use crate::entities::{
   DatabaseLayoutPB, DatabaseLayoutSettingPB, DatabaseViewSettingPB, FieldSettingsPB, FilterPB,
-  GroupSettingPB, SortPB,
+  GroupSettingPB, RowSampleModePB, SortPB,
 };
 use crate::services::field_settings::FieldSettings;
 use crate::services::filter::Filter;
 use crate::services::group::GroupSetting;
 use crate::services::sort::Sort;
 use collab_database::entity::DatabaseView;
-use collab_database::views::DatabaseLayout;
+use collab_database::views::{DatabaseLayout, RowOrder};
+use rand::seq::index;
 use tracing::error;
 
 pub(crate) fn database_view_setting_pb_from_view(view: DatabaseView) -> DatabaseViewSettingPB {
@@ -83,3 +84,187 @@ pub(crate) fn database_view_setting_pb_from_view(view: DatabaseView) -> Database
     layout_setting,
   }
 }
+
+const SEARCH_SNIPPET_CONTEXT_LEN: usize = 30;
+
+/// Returns the part of the text around the first match of the query, or `None` if the text
+/// doesn't contain the query. The `query` must be lowercase, the match ignores the case.
+pub(crate) fn search_snippet(text: &str, query: &str) -> Option<String> {
+  if query.is_empty() {
+    return None;
+  }
+  let lowercase_text = LowercaseText::new(text);
+  let start = lowercase_text.text.find(query)?;
+  let (start, end) = lowercase_text.original_range(start, start + query.len());
+
+  let before = text[..start]
+    .char_indices()
+    .rev()
+    .nth(SEARCH_SNIPPET_CONTEXT_LEN - 1)
+    .map_or(0, |(index, _)| index);
+  let after = text[end..]
+    .char_indices()
+    .nth(SEARCH_SNIPPET_CONTEXT_LEN)
+    .map_or(text.len(), |(index, _)| end + index);
+
+  let mut snippet = String::new();
+  if before > 0 {
+    snippet.push('…');
+  }
+  snippet.push_str(&text[before..after]);
+  if after < text.len() {
+    snippet.push('…');
+  }
+  Some(snippet)
+}
+
+/// Replaces every match of the query in the text with the replacement, or returns `None` if the
+/// text doesn't contain the query. The `query` must be lowercase, the match ignores the case.
+/// A match that covers only a part of the lowercase form of a char, like the `i` of `İ`, is not
+/// replaced.
+pub(crate) fn replace_ignore_case(text: &str, query: &str, replacement: &str) -> Option<String> {
+  if query.is_empty() {
+    return None;
+  }
+  let lowercase_text = LowercaseText::new(text);
+  let mut new_text = String::with_capacity(text.len());
+  let mut last_end = 0;
+  for (start, _) in lowercase_text.text.match_indices(query) {
+    let end = start + query.len();
+    if !lowercase_text.is_char_start(start) || !lowercase_text.is_char_start(end) {
+      continue;
+    }
+    let (start, end) = lowercase_text.original_range(start, end);
+    new_text.push_str(&text[last_end..start]);
+    new_text.push_str(replacement);
+    last_end = end;
+  }
+  if last_end == 0 {
+    return None;
+  }
+  new_text.push_str(&text[last_end..]);
+  Some(new_text)
+}
+
+/// The text lowercased char by char. Lowercasing changes the length of a few chars, like `K`
+/// (the Kelvin sign) or `İ`, so the offsets of the lowercase text are mapped back to the chars
+/// of the original text.
+struct LowercaseText {
+  text: String,
+  /// The offset of the original char of every byte of the lowercase text, followed by the
+  /// length of the original text.
+  offsets: Vec<usize>,
+}
+
+impl LowercaseText {
+  fn new(text: &str) -> Self {
+    let mut lowercase_text = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    for (index, c) in text.char_indices() {
+      lowercase_text.extend(c.to_lowercase());
+      offsets.resize(lowercase_text.len(), index);
+    }
+    offsets.push(text.len());
+    Self {
+      text: lowercase_text,
+      offsets,
+    }
+  }
+
+  /// Returns true if the offset of the lowercase text is where the lowercase form of an
+  /// original char starts, or the end of the text.
+  fn is_char_start(&self, offset: usize) -> bool {
+    offset == 0 || self.offsets[offset - 1] != self.offsets[offset]
+  }
+
+  /// Maps the non-empty range of the lowercase text to the range of the original chars it
+  /// covers.
+  fn original_range(&self, start: usize, end: usize) -> (usize, usize) {
+    let last_char = self.offsets[end - 1];
+    let end = self.offsets[end..]
+      .iter()
+      .copied()
+      .find(|offset| *offset != last_char)
+      .unwrap_or(last_char);
+    (self.offsets[start], end)
+  }
+}
+
+/// Picks `count` of the row orders. The picked row orders keep their relative order.
+pub(crate) fn sample_row_orders(
+  row_orders: Vec<RowOrder>,
+  count: usize,
+  mode: RowSampleModePB,
+) -> Vec<RowOrder> {
+  if row_orders.len() <= count {
+    return row_orders;
+  }
+  match mode {
+    RowSampleModePB::First => row_orders.into_iter().take(count).collect(),
+    RowSampleModePB::Last => {
+      let skip = row_orders.len() - count;
+      row_orders.into_iter().skip(skip).collect()
+    },
+    RowSampleModePB::Random => {
+      let mut indices = index::sample(&mut rand::thread_rng(), row_orders.len(), count).into_vec();
+      indices.sort_unstable();
+      indices
+        .into_iter()
+        .map(|index| row_orders[index].clone())
+        .collect()
+    },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{replace_ignore_case, search_snippet};
+
+  #[test]
+  fn search_snippet_test() {
+    assert_eq!(
+      search_snippet("AppFlowy", "flow"),
+      Some("AppFlowy".to_string())
+    );
+    assert_eq!(search_snippet("AppFlowy", "notion"), None);
+
+    let text = format!("{}needle{}", "a".repeat(40), "b".repeat(40));
+    assert_eq!(
+      search_snippet(&text, "needle"),
+      Some(format!("…{}needle{}…", "a".repeat(30), "b".repeat(30)))
+    );
+
+    let text = format!("{}\u{212A}elvin{}", "\u{130}".repeat(40), "b".repeat(40));
+    assert_eq!(
+      search_snippet(&text, "kelvin"),
+      Some(format!(
+        "…{}\u{212A}elvin{}…",
+        "\u{130}".repeat(30),
+        "b".repeat(30)
+      ))
+    );
+  }
+
+  #[test]
+  fn replace_ignore_case_test() {
+    assert_eq!(
+      replace_ignore_case("Todo, TODO and todo", "todo", "Done"),
+      Some("Done, Done and Done".to_string())
+    );
+    assert_eq!(replace_ignore_case("AppFlowy", "notion", "Done"), None);
+
+    // Lowercasing changes the length of the Kelvin sign and of İ
+    assert_eq!(
+      replace_ignore_case("\u{212A}\u{130}\u{130}", "k", "x"),
+      Some("x\u{130}\u{130}".to_string())
+    );
+    assert_eq!(
+      replace_ignore_case("\u{130}stanbul", "i\u{307}stanbul", "Ankara"),
+      Some("Ankara".to_string())
+    );
+    assert_eq!(
+      replace_ignore_case("\u{130}stanbul", "istanbul", "Ankara"),
+      None
+    );
+  }
+}
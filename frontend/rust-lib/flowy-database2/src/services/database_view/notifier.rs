@@ -2,7 +2,7 @@
 use crate::entities::{
   CalculationChangesetNotificationPB, DatabaseViewSettingPB, FilterChangesetNotificationPB,
   GroupChangesPB, GroupRowsNotificationPB, ReorderAllRowsPB, ReorderSingleRowPB,
-  RowsVisibilityChangePB, SortChangesetNotificationPB,
+  RowsVisibilityChangePB, SortChangesetNotificationPB, ViewRowCountPB,
 };
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::filter::FilterResultNotification;
@@ -40,6 +40,11 @@ impl DatabaseViewChangedReceiverRunner {
       .for_each(|changed| async {
         match changed {
           DatabaseViewChanged::FilterNotification(notification) => {
+            let row_count = ViewRowCountPB {
+              view_id: notification.view_id.clone(),
+              visible_count: notification.visible_count as i64,
+              total_count: notification.total_count as i64,
+            };
             let changeset = RowsVisibilityChangePB {
               view_id: notification.view_id,
               visible_rows: notification.visible_rows,
@@ -55,6 +60,13 @@ impl DatabaseViewChangedReceiverRunner {
               DatabaseNotification::DidUpdateViewRowsVisibility,
             )
             .payload(changeset)
+            .send();
+
+            send_notification(
+              &row_count.view_id,
+              DatabaseNotification::DidUpdateViewRowCount,
+            )
+            .payload(row_count)
             .send()
           },
           DatabaseViewChanged::ReorderAllRowsNotification(notification) => {
@@ -2,7 +2,9 @@ use async_trait::async_trait;
 use collab_database::fields::Field;
 use std::sync::Arc;
 
-use collab_database::rows::Cell;
+use collab_database::rows::{new_cell_builder, Cell};
+
+use crate::entities::FieldType;
 
 use crate::services::calculations::{
   Calculation, CalculationsController, CalculationsDelegate, CalculationsTaskHandler,
@@ -47,12 +49,23 @@ struct DatabaseViewCalculationsDelegateImpl(Arc<dyn DatabaseViewOperation>);
 #[async_trait]
 impl CalculationsDelegate for DatabaseViewCalculationsDelegateImpl {
   async fn get_cells_for_field(&self, view_id: &str, field_id: &str) -> Vec<Arc<Cell>> {
+    let field_type = match self.0.get_field(field_id).await {
+      Some(field) => FieldType::from(field.field_type),
+      None => return vec![],
+    };
+
     self
       .0
       .get_cells_for_field(view_id, field_id)
       .await
       .into_iter()
-      .filter_map(|row_cell| row_cell.cell.map(Arc::new))
+      .map(|row_cell| {
+        Arc::new(
+          row_cell
+            .cell
+            .unwrap_or_else(|| new_cell_builder(field_type)),
+        )
+      })
       .collect()
   }
 
@@ -4,15 +4,17 @@ use std::sync::Arc;
 
 use super::notify_did_update_calculation;
 use crate::entities::{
-  CalendarEventPB, CreateRowPayloadPB, DatabaseLayoutMetaPB, DatabaseLayoutSettingPB,
-  DeleteSortPayloadPB, FieldSettingsChangesetPB, FieldType, GroupChangesPB, GroupPB, InsertedRowPB,
-  LayoutSettingChangeset, LayoutSettingParams, RemoveCalculationChangesetPB, ReorderSortPayloadPB,
-  RowMetaPB, RowsChangePB, SortChangesetNotificationPB, SortPB, UpdateCalculationChangesetPB,
-  UpdateSortPayloadPB,
+  CalculationType, CalendarEventPB, CreateRowPayloadPB, DatabaseLayoutMetaPB,
+  DatabaseLayoutSettingPB, DeleteSortPayloadPB, FieldSettingsChangesetPB, FieldType,
+  FilterExplanationPB, GroupAggregatePB, GroupChangesPB, GroupPB, GroupRowsNotificationPB,
+  InsertedRowPB, LayoutSettingChangeset, LayoutSettingParams, QueryExplanationPB,
+  RemoveCalculationChangesetPB, ReorderSortPayloadPB, RepeatedRowColorRulePB, RowColorPB,
+  RowMetaPB, RowsChangePB, SortChangesetNotificationPB, SortKeyExplanationPB, SortPB,
+  UpdateCalculationChangesetPB, UpdateSortPayloadPB,
 };
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::calculations::{
-  Calculation, CalculationChangeset, CalculationEvent, CalculationsController,
+  Calculation, CalculationChangeset, CalculationEvent, CalculationsController, CalculationsService,
 };
 use crate::services::cell::{CellBuilder, CellCache};
 use crate::services::database::{database_view_setting_pb_from_view, DatabaseRowEvent, UpdatedRow};
@@ -26,18 +28,19 @@ use crate::services::database_view::{
   notify_did_update_setting, notify_did_update_sort, DatabaseLayoutDepsResolver,
   DatabaseViewChangedNotifier, DatabaseViewChangedReceiverRunner,
 };
+use crate::services::field::default_cell_for_field;
 use crate::services::field_settings::FieldSettings;
 use crate::services::filter::{Filter, FilterChangeset, FilterController};
 use crate::services::group::{
   DidMoveGroupRowResult, GroupChangeset, GroupController, MoveGroupRowContext, UpdatedCells,
 };
-use crate::services::setting::CalendarLayoutSetting;
+use crate::services::setting::{CalendarLayoutSetting, RowColorSetting};
 use crate::services::sort::{Sort, SortChangeset, SortController};
 use collab_database::database::{gen_database_calculation_id, gen_database_sort_id, gen_row_id};
 use collab_database::entity::DatabaseView;
 use collab_database::fields::Field;
 use collab_database::rows::{Cells, CreateRowParams, Row, RowCell, RowDetail, RowId};
-use collab_database::views::{DatabaseLayout, RowOrder};
+use collab_database::views::{DatabaseLayout, LayoutSetting, RowOrder};
 use dashmap::DashMap;
 use flowy_error::{FlowyError, FlowyResult};
 use lib_infra::priority_task::QualityOfService;
@@ -166,6 +169,19 @@ impl DatabaseViewEditor {
     self.sort_controller.read().await.has_sorts().await
   }
 
+  /// Returns true if every row of the view has to be loaded when the view is opened, to filter,
+  /// sort, group or calculate them. Otherwise the rows can be loaded as they're displayed.
+  pub async fn needs_all_rows(&self) -> bool {
+    self.has_filters().await
+      || self.has_sorts().await
+      || self.group_controller.read().await.is_some()
+      || !self
+        .delegate
+        .get_all_calculations(&self.view_id)
+        .await
+        .is_empty()
+  }
+
   pub async fn v_get_view(&self) -> Option<DatabaseView> {
     self.delegate.get_view(&self.view_id).await
   }
@@ -177,7 +193,7 @@ impl DatabaseViewEditor {
     let timestamp = timestamp();
     trace!("[Database]: will create row at: {:?}", params.row_position);
     let mut result = CreateRowParams {
-      id: gen_row_id(),
+      id: params.row_id.map(RowId::from).unwrap_or_else(gen_row_id),
       database_id: self.database_id.clone(),
       cells: Cells::new(),
       height: 60,
@@ -191,6 +207,16 @@ impl DatabaseViewEditor {
     let fields = self.delegate.get_fields(&params.view_id, None).await;
     let mut cells = CellBuilder::with_cells(params.data, &fields).build();
 
+    // fill in the default values of the fields that are not supplied
+    for field in fields.iter() {
+      if cells.contains_key(&field.id) {
+        continue;
+      }
+      if let Some(cell) = default_cell_for_field(field) {
+        cells.insert(field.id.clone(), cell);
+      }
+    }
+
     // fill in cells according to group_id if supplied
     if let Some(group_id) = params.group_id {
       if let Some(controller) = self.group_controller.read().await.as_ref() {
@@ -236,7 +262,10 @@ impl DatabaseViewEditor {
       let rows = vec![Arc::new(row_detail.row.clone())];
       let mut rows = self.v_filter_rows(rows).await;
       if let Some(row) = rows.pop() {
-        let changesets = controller.did_create_row(&row, index as usize);
+        let mut changesets = controller.did_create_row(&row, index as usize);
+        self
+          .fill_group_aggregates(controller.as_ref(), &mut changesets)
+          .await;
         for changeset in changesets {
           notify_did_update_group_rows(changeset).await;
         }
@@ -283,7 +312,7 @@ impl DatabaseViewEditor {
       let result = self
         .mut_group_controller(|group_controller, _| group_controller.did_delete_row(row))
         .await;
-      handle_mut_group_result(&self.view_id, result).await;
+      self.handle_mut_group_result(result).await;
     }
 
     // Updating calculations for each of the Rows cells is a tedious task
@@ -347,12 +376,29 @@ impl DatabaseViewEditor {
           notify_did_update_num_of_groups(&self.view_id, group_changes).await;
         }
 
-        for changeset in row_changesets {
-          if !changeset.is_empty() {
-            tracing::trace!("Group change after editing the row: {:?}", changeset);
-            notify_did_update_group_rows(changeset).await;
+        let mut row_changesets = row_changesets
+          .into_iter()
+          .filter(|changeset| !changeset.is_empty())
+          .collect::<Vec<_>>();
+        // The row stays in its groups, but their sums change with the aggregate field
+        if field_id.is_some() && field_id.as_deref() == controller.get_aggregate_field_id() {
+          for group in controller.get_all_groups() {
+            if group.contains_row(&row.id)
+              && !row_changesets
+                .iter()
+                .any(|changeset| changeset.group_id == group.id)
+            {
+              row_changesets.push(GroupRowsNotificationPB::new(group.id.clone()));
+            }
           }
         }
+        self
+          .fill_group_aggregates(controller.as_ref(), &mut row_changesets)
+          .await;
+        for changeset in row_changesets {
+          tracing::trace!("Group change after editing the row: {:?}", changeset);
+          notify_did_update_group_rows(changeset).await;
+        }
       }
     }
 
@@ -384,6 +430,92 @@ impl DatabaseViewEditor {
       .await;
   }
 
+  /// Returns the row color rules of the view.
+  pub async fn v_get_row_color_setting(&self) -> RowColorSetting {
+    self
+      .delegate
+      .get_layout_setting(&self.view_id, &DatabaseLayout::Grid)
+      .await
+      .map(RowColorSetting::from)
+      .unwrap_or_default()
+  }
+
+  /// Replaces the row color rules of the view. Returns an error if a rule refers to a field that
+  /// doesn't exist.
+  pub async fn v_set_row_color_setting(&self, setting: RowColorSetting) -> FlowyResult<()> {
+    for rule in setting.rules.iter() {
+      let field_id = rule.field_id().unwrap_or_default();
+      if self.delegate.get_field(field_id).await.is_none() {
+        return Err(FlowyError::field_record_not_found());
+      }
+    }
+
+    // The grid layout settings of the inline view also hold the views setting of the database,
+    // so only the row color rules are replaced.
+    let mut layout_setting = self
+      .delegate
+      .get_layout_setting(&self.view_id, &DatabaseLayout::Grid)
+      .await
+      .unwrap_or_default();
+    layout_setting.extend(LayoutSetting::from(&setting));
+    self
+      .delegate
+      .insert_layout_setting(&self.view_id, &DatabaseLayout::Grid, layout_setting)
+      .await;
+
+    send_notification(&self.view_id, DatabaseNotification::DidUpdateRowColorRules)
+      .payload(RepeatedRowColorRulePB::from(setting.rules.as_slice()))
+      .send();
+    Ok(())
+  }
+
+  /// Returns the color of every visible row of the view that matches one of the row color rules.
+  /// The rules whose field doesn't exist anymore are skipped.
+  pub async fn v_get_row_colors(&self) -> Vec<RowColorPB> {
+    let setting = self.v_get_row_color_setting().await;
+    let mut conditions = vec![];
+    let mut colors = vec![];
+    for rule in setting.rules.iter() {
+      let field_id = rule.field_id().unwrap_or_default();
+      if self.delegate.get_field(field_id).await.is_some() {
+        conditions.push(&rule.condition);
+        colors.push(rule.color.as_str());
+      }
+    }
+    if conditions.is_empty() {
+      return vec![];
+    }
+
+    let rows = self.v_get_all_rows().await;
+    let matches = self
+      .filter_controller
+      .find_matching_conditions(&rows, &conditions)
+      .await;
+    rows
+      .iter()
+      .zip(matches)
+      .filter_map(|(row, index)| {
+        index.map(|index| RowColorPB {
+          row_id: row.id.to_string(),
+          color: colors[index].to_string(),
+        })
+      })
+      .collect()
+  }
+
+  /// Explains how the filters and the sorts of the view apply to the row.
+  pub async fn v_explain_row(&self, row: &Row) -> QueryExplanationPB {
+    let (is_visible, filters) = self.filter_controller.explain_row(row).await;
+    let sorts = self.sort_controller.read().await.explain_row(row).await;
+    QueryExplanationPB {
+      view_id: self.view_id.clone(),
+      row_id: row.id.to_string(),
+      is_visible,
+      filters: filters.into_iter().map(FilterExplanationPB::from).collect(),
+      sorts: sorts.into_iter().map(SortKeyExplanationPB::from).collect(),
+    }
+  }
+
   #[instrument(level = "info", skip(self))]
   pub async fn v_get_all_rows(&self) -> Vec<Arc<Row>> {
     let row_orders = self.delegate.get_all_row_orders(&self.view_id).await;
@@ -393,18 +525,35 @@ impl DatabaseViewEditor {
     rows
   }
 
+  /// Returns at most `limit` rows of the view from `offset`, with the filters and the sorts of the
+  /// view applied, and the number of rows left after the filters. Only the rows of the page are
+  /// loaded if the view has no filters and sorts.
+  pub async fn v_get_rows_paged(&self, offset: usize, limit: usize) -> (Vec<Arc<Row>>, usize) {
+    if !self.has_filters().await && !self.has_sorts().await {
+      let row_orders = self.delegate.get_all_row_orders(&self.view_id).await;
+      let total_count = row_orders.len();
+      let row_orders = row_orders.into_iter().skip(offset).take(limit).collect();
+      let rows = self.delegate.get_all_rows(&self.view_id, row_orders).await;
+      return (rows, total_count);
+    }
+
+    let rows = self.v_get_all_rows().await;
+    let total_count = rows.len();
+    (
+      rows.into_iter().skip(offset).take(limit).collect(),
+      total_count,
+    )
+  }
+
   pub async fn v_get_cells_for_field(&self, field_id: &str) -> Vec<RowCell> {
     let row_orders = self.delegate.get_all_row_orders(&self.view_id).await;
     let rows = self.delegate.get_all_rows(&self.view_id, row_orders).await;
     let rows = self.v_filter_rows(rows).await;
+    // Rows that don't have a cell for the field are included with an empty cell, so they are
+    // still taken into account, e.g. by the calculations that count the empty cells.
     let rows = rows
       .into_iter()
-      .filter_map(|row| {
-        row
-          .cells
-          .get(field_id)
-          .map(|cell| RowCell::new(row.id.clone(), Some(cell.clone())))
-      })
+      .map(|row| RowCell::new(row.id.clone(), row.cells.get(field_id).cloned()))
       .collect::<Vec<_>>();
     trace!(
       "[Database]: get cells for field: {}, total rows:{}",
@@ -438,38 +587,84 @@ impl DatabaseViewEditor {
       })
       .await;
 
-    handle_mut_group_result(&self.view_id, result).await;
+    self.handle_mut_group_result(result).await;
     updated_cells
   }
 
   /// Only call once after database view editor initialized
   #[tracing::instrument(level = "trace", skip(self))]
   pub async fn v_load_groups(&self) -> Option<Vec<GroupPB>> {
-    let groups = self
-      .group_controller
-      .read()
-      .await
-      .as_ref()?
+    let read_guard = self.group_controller.read().await;
+    let controller = read_guard.as_ref()?;
+    let mut groups = controller
       .get_all_groups()
       .into_iter()
       .map(|group_data| GroupPB::from(group_data.clone()))
       .collect::<Vec<_>>();
+    let group_ids = groups
+      .iter()
+      .map(|group| group.group_id.as_str())
+      .collect::<Vec<_>>();
+    let mut aggregates = self.group_aggregates(controller.as_ref(), &group_ids).await;
+    for group in groups.iter_mut() {
+      if let Some(aggregate) = aggregates.remove(&group.group_id) {
+        group.aggregate = aggregate;
+      }
+    }
     tracing::trace!("Number of groups: {}", groups.len());
     Some(groups)
   }
 
   #[tracing::instrument(level = "trace", skip(self))]
   pub async fn v_get_group(&self, group_id: &str) -> FlowyResult<GroupPB> {
-    match self
-      .group_controller
-      .read()
-      .await
+    let read_guard = self.group_controller.read().await;
+    match read_guard
       .as_ref()
-      .and_then(|group| group.get_group(group_id))
+      .and_then(|controller| Some((controller, controller.get_group(group_id)?)))
     {
       None => Err(FlowyError::record_not_found().with_context("Can't find the group")),
-      Some((_, group)) => Ok(GroupPB::from(group)),
+      Some((controller, (_, group))) => {
+        let mut group = GroupPB::from(group);
+        if let Some(aggregate) = self
+          .group_aggregates(controller.as_ref(), &[group_id])
+          .await
+          .remove(group_id)
+        {
+          group.aggregate = aggregate;
+        }
+        Ok(group)
+      },
+    }
+  }
+
+  /// Sets the field whose values are summed up in each group and notifies the new aggregates of
+  /// the groups.
+  pub async fn v_update_group_aggregate_field(&self, field_id: Option<String>) -> FlowyResult<()> {
+    let field_id = field_id.filter(|field_id| !field_id.is_empty());
+    if let Some(field_id) = &field_id {
+      if self.delegate.get_field(field_id).await.is_none() {
+        return Err(FlowyError::field_record_not_found());
+      }
+    }
+
+    let mut write_guard = self.group_controller.write().await;
+    let controller = write_guard
+      .as_mut()
+      .ok_or_else(|| FlowyError::record_not_found().with_context("The view is not grouped"))?;
+    controller.set_aggregate_field_id(field_id)?;
+
+    let mut changesets = controller
+      .get_all_groups()
+      .into_iter()
+      .map(|group| GroupRowsNotificationPB::new(group.id.clone()))
+      .collect::<Vec<_>>();
+    self
+      .fill_group_aggregates(controller.as_ref(), &mut changesets)
+      .await;
+    for changeset in changesets {
+      notify_did_update_group_rows(changeset).await;
     }
+    Ok(())
   }
 
   #[tracing::instrument(level = "trace", skip(self), err)]
@@ -622,6 +817,7 @@ impl DatabaseViewEditor {
       id: sort_id,
       field_id: params.field_id.clone(),
       condition: params.condition.into(),
+      collation: params.collation.into(),
     };
 
     self.delegate.insert_sort(&self.view_id, sort.clone()).await;
@@ -843,6 +1039,17 @@ impl DatabaseViewEditor {
       DatabaseLayout::Board => {
         let layout_setting = params.board.unwrap();
 
+        // The cover of the cards must be an existing field
+        if !layout_setting.card_cover_field_id.is_empty()
+          && self
+            .delegate
+            .get_field(&layout_setting.card_cover_field_id)
+            .await
+            .is_none()
+        {
+          return Err(FlowyError::field_record_not_found());
+        }
+
         self
           .delegate
           .insert_layout_setting(
@@ -1037,7 +1244,7 @@ impl DatabaseViewEditor {
     let (_, row_detail) = self.delegate.get_row_detail(&self.view_id, &row_id).await?;
 
     Some(CalendarEventPB {
-      row_meta: RowMetaPB::from(row_detail.as_ref().clone()),
+      row_meta: RowMetaPB::from(row_detail.as_ref()),
       date_field_id: date_field.id.clone(),
       title,
       timestamp,
@@ -1080,7 +1287,7 @@ impl DatabaseViewEditor {
 
       let (_, row_detail) = self.delegate.get_row_detail(&self.view_id, &row.id).await?;
       let event = CalendarEventPB {
-        row_meta: RowMetaPB::from(row_detail.as_ref().clone()),
+        row_meta: RowMetaPB::from(row_detail.as_ref()),
         date_field_id: calendar_setting.field_id.clone(),
         title,
         timestamp,
@@ -1207,6 +1414,98 @@ impl DatabaseViewEditor {
     });
   }
 
+  async fn handle_mut_group_result(&self, result: Option<DidMoveGroupRowResult>) {
+    if let Some(mut result) = result {
+      if let Some(deleted_group) = result.deleted_group {
+        trace!("Delete group after moving the row: {:?}", deleted_group);
+        let payload = GroupChangesPB {
+          view_id: self.view_id.clone(),
+          deleted_groups: vec![deleted_group.group_id],
+          ..Default::default()
+        };
+        notify_did_update_num_of_groups(&self.view_id, payload).await;
+      }
+      if let Some(controller) = self.group_controller.read().await.as_ref() {
+        self
+          .fill_group_aggregates(controller.as_ref(), &mut result.row_changesets)
+          .await;
+      }
+      for changeset in result.row_changesets {
+        trace!("[RowOrder]: group row changeset: {:?}", changeset);
+        notify_did_update_group_rows(changeset).await;
+      }
+    }
+  }
+
+  /// Attaches the aggregate of each group to its row changeset, so the aggregates are updated
+  /// along with the rows of the groups.
+  async fn fill_group_aggregates(
+    &self,
+    controller: &dyn GroupController,
+    changesets: &mut [GroupRowsNotificationPB],
+  ) {
+    if changesets.is_empty() {
+      return;
+    }
+    let group_ids = changesets
+      .iter()
+      .map(|changeset| changeset.group_id.clone())
+      .collect::<Vec<_>>();
+    let group_ids = group_ids.iter().map(String::as_str).collect::<Vec<_>>();
+    let mut aggregates = self.group_aggregates(controller, &group_ids).await;
+    for changeset in changesets.iter_mut() {
+      changeset.aggregate = aggregates.remove(&changeset.group_id);
+    }
+  }
+
+  /// Returns the row count of each of the given groups and, if the group setting has an
+  /// aggregate field, the sum of the field over the rows of the group.
+  async fn group_aggregates(
+    &self,
+    controller: &dyn GroupController,
+    group_ids: &[&str],
+  ) -> HashMap<String, GroupAggregatePB> {
+    let aggregate_field = match controller.get_aggregate_field_id() {
+      Some(field_id) => self.delegate.get_field(field_id).await,
+      None => None,
+    };
+    // The rows of the groups are snapshots, so the cells are read from the database
+    let cell_by_row_id = match &aggregate_field {
+      Some(field) => self
+        .delegate
+        .get_cells_for_field(&self.view_id, &field.id)
+        .await
+        .into_iter()
+        .filter_map(|row_cell| Some((row_cell.row_id, Arc::new(row_cell.cell?))))
+        .collect::<HashMap<_, _>>(),
+      None => HashMap::new(),
+    };
+
+    controller
+      .get_all_groups()
+      .into_iter()
+      .filter(|group| group_ids.contains(&group.id.as_str()))
+      .map(|group| {
+        let sum = aggregate_field
+          .as_ref()
+          .map(|field| {
+            let cells = group
+              .rows
+              .iter()
+              .filter_map(|row| cell_by_row_id.get(&row.id).cloned())
+              .collect();
+            CalculationsService::new().calculate(field, CalculationType::Sum as i64, cells)
+          })
+          .unwrap_or_default();
+        let aggregate = GroupAggregatePB {
+          count: group.rows.len() as i64,
+          sum,
+        };
+        (group.id.clone(), aggregate)
+      })
+      .collect()
+  }
+
   async fn gen_did_create_row_view_tasks(&self, row: Row) {
     let weak_calculations_controller = Arc::downgrade(&self.calculations_controller);
     tokio::spawn(async move {
@@ -1218,21 +1517,3 @@ impl DatabaseViewEditor {
     });
   }
 }
-
-async fn handle_mut_group_result(view_id: &str, result: Option<DidMoveGroupRowResult>) {
-  if let Some(result) = result {
-    if let Some(deleted_group) = result.deleted_group {
-      trace!("Delete group after moving the row: {:?}", deleted_group);
-      let payload = GroupChangesPB {
-        view_id: view_id.to_string(),
-        deleted_groups: vec![deleted_group.group_id],
-        ..Default::default()
-      };
-      notify_did_update_num_of_groups(view_id, payload).await;
-    }
-    for changeset in result.row_changesets {
-      trace!("[RowOrder]: group row changeset: {:?}", changeset);
-      notify_did_update_group_rows(changeset).await;
-    }
-  }
-}
@@ -0,0 +1,166 @@
+use collab_database::database::Database;
+use collab_database::fields::select_type_option::{SelectOption, SelectOptionIds};
+use collab_database::fields::Field;
+use collab_database::rows::{get_field_type_from_cell, Cell};
+use lib_infra::box_any::BoxAny;
+use tracing::info;
+
+use crate::entities::{CheckboxCellDataPB, FieldType};
+use crate::services::cell::{apply_cell_changeset, stringify_cell};
+use crate::services::field::{select_type_option_from_field, SelectOptionCellChangeset};
+
+/// Converts the cells of a field from one field type to another when the type of the field is
+/// switched, so the cells hold data of the new type instead of stale data of the old one.
+///
+/// The conversion rewrites the stored cells, so the data that the new field type can't hold is
+/// lost: a multi-select switched to a single-select keeps only its first option, and a date
+/// switched to text keeps only its formatted text. Switching the field back converts the cells
+/// again from what they hold now, it doesn't restore the original cells.
+///
+/// A converter handles one pair of field types. A new field type plugs in by implementing the
+/// trait and registering its pairs in [cell_converter].
+pub trait CellConverter: Send + Sync {
+  /// Returns the cell of the new field type. Returns None if the cell can't be converted. The
+  /// cell is kept as is then, and is still transformed when it's read.
+  ///
+  /// # Arguments
+  ///
+  /// * `old_field`: the field before switching its type
+  /// * `new_field`: the field after switching its type, including the transformed type option
+  fn convert(&self, cell: &Cell, old_field: &Field, new_field: &Field) -> Option<Cell>;
+}
+
+/// Returns the converter of the given pair of field types, or None if the cells of the pair are
+/// not converted.
+///
+/// The cells of a text field switched to a number or a select field are already converted when
+/// the type option is transformed, see [TypeOptionTransform](crate::services::field::TypeOptionTransform).
+pub fn cell_converter(from: FieldType, to: FieldType) -> Option<Box<dyn CellConverter>> {
+  match (from, to) {
+    (
+      FieldType::Number
+      | FieldType::DateTime
+      | FieldType::SingleSelect
+      | FieldType::MultiSelect
+      | FieldType::Checkbox
+      | FieldType::URL
+      | FieldType::Time,
+      FieldType::RichText,
+    ) => Some(Box::new(StringConverter)),
+    (FieldType::SingleSelect, FieldType::MultiSelect)
+    | (FieldType::MultiSelect, FieldType::SingleSelect) => Some(Box::new(SelectToSelectConverter)),
+    (FieldType::Checkbox, FieldType::SingleSelect | FieldType::MultiSelect) => {
+      Some(Box::new(CheckboxToSelectConverter))
+    },
+    (FieldType::RichText, FieldType::Checkbox | FieldType::URL) => Some(Box::new(StringConverter)),
+    _ => None,
+  }
+}
+
+/// Converts the cells of the field that still hold data of another field type. Returns the
+/// number of converted cells.
+pub async fn convert_cells_of_field(
+  database: &mut Database,
+  view_id: &str,
+  old_field: &Field,
+  new_field: &Field,
+) -> usize {
+  let old_field_type = FieldType::from(old_field.field_type);
+  let new_field_type = FieldType::from(new_field.field_type);
+  let converter = match cell_converter(old_field_type, new_field_type) {
+    Some(converter) => converter,
+    None => return 0,
+  };
+
+  let cells = database
+    .get_cells_for_field(view_id, &new_field.id)
+    .await
+    .into_iter()
+    .filter_map(|row| row.cell.map(|cell| (row.row_id, cell)))
+    .filter(|(_, cell)| get_field_type_from_cell::<FieldType>(cell) == Some(old_field_type))
+    .filter_map(|(row_id, cell)| {
+      converter
+        .convert(&cell, old_field, new_field)
+        .map(|cell| (row_id, cell))
+    })
+    .collect::<Vec<_>>();
+
+  info!(
+    "Converting {:?} cells to {:?}, updating {} row's cell content",
+    old_field_type,
+    new_field_type,
+    cells.len()
+  );
+  let num_of_cells = cells.len();
+  for (row_id, cell) in cells {
+    database
+      .update_row(row_id, |row| {
+        row.update_cells(|cell_update| {
+          cell_update.insert(&new_field.id, cell);
+        });
+      })
+      .await;
+  }
+  num_of_cells
+}
+
+/// Stringifies the cell with the old field, so a number keeps its unit and a checkbox keeps its
+/// custom label, and applies the text as the input of the new field, e.g. `yes` of a checkbox.
+struct StringConverter;
+impl CellConverter for StringConverter {
+  fn convert(&self, cell: &Cell, old_field: &Field, new_field: &Field) -> Option<Cell> {
+    let s = stringify_cell(cell, old_field);
+    apply_cell_changeset(BoxAny::new(s), None, new_field, None).ok()
+  }
+}
+
+/// Maps the options of the old field to the options of the new field with the same names. A
+/// multi-select cell switched to a single-select keeps its first option.
+struct SelectToSelectConverter;
+impl CellConverter for SelectToSelectConverter {
+  fn convert(&self, cell: &Cell, old_field: &Field, new_field: &Field) -> Option<Cell> {
+    let old_options = select_options(old_field);
+    let new_options = select_options(new_field);
+    let mut option_ids = SelectOptionIds::from(cell)
+      .iter()
+      .flat_map(|id| old_options.iter().find(|option| &option.id == id))
+      .flat_map(|old_option| {
+        new_options
+          .iter()
+          .find(|option| option.name == old_option.name)
+      })
+      .map(|option| option.id.clone())
+      .collect::<Vec<_>>();
+    if FieldType::from(new_field.field_type).is_single_select() {
+      option_ids.truncate(1);
+    }
+    insert_select_options(option_ids, new_field)
+  }
+}
+
+/// Selects the option named after the state of the checkbox. The options are added to the field
+/// when its type option is transformed.
+struct CheckboxToSelectConverter;
+impl CellConverter for CheckboxToSelectConverter {
+  fn convert(&self, cell: &Cell, _old_field: &Field, new_field: &Field) -> Option<Cell> {
+    let name = CheckboxCellDataPB::from(cell).to_string();
+    let option_ids = select_options(new_field)
+      .into_iter()
+      .filter(|option| option.name == name)
+      .map(|option| option.id)
+      .take(1)
+      .collect();
+    insert_select_options(option_ids, new_field)
+  }
+}
+
+fn select_options(field: &Field) -> Vec<SelectOption> {
+  select_type_option_from_field(field)
+    .map(|type_option| type_option.options().clone())
+    .unwrap_or_default()
+}
+
+fn insert_select_options(option_ids: Vec<String>, field: &Field) -> Option<Cell> {
+  let changeset = SelectOptionCellChangeset::from_insert_options(option_ids);
+  apply_cell_changeset(BoxAny::new(changeset), None, field, None).ok()
+}
@@ -0,0 +1,322 @@
+use std::collections::{HashMap, HashSet};
+
+use collab::util::AnyMapExt;
+use collab_database::fields::select_type_option::SelectOptionIds;
+use collab_database::fields::{Field, TypeOptionData, TypeOptionDataBuilder};
+use collab_database::rows::{Cell, Row};
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use lib_infra::box_any::BoxAny;
+use lib_infra::util::timestamp;
+
+use crate::entities::{FieldChangesetPB, FieldType};
+use crate::services::cell::{apply_cell_changeset, stringify_cell};
+use crate::services::field::{
+  select_type_option_from_field, CheckboxOptions, DateCellChangeset, SelectOptionCellChangeset,
+  CHECK,
+};
+
+/// The key under which the [FieldAttributes] are stored in the field's type options. The
+/// attributes are independent of the field type, so they are kept apart from the type option
+/// data of any specific [FieldType] and survive field type switches.
+pub const FIELD_ATTRIBUTES_KEY: &str = "field_attributes";
+
+/// Placeholder used in place of the value of a sensitive cell when it is not revealed.
+pub const MASKED_CELL_VALUE: &str = "******";
+
+const IS_SENSITIVE: &str = "is_sensitive";
+const MAX_SELECTIONS: &str = "max_selections";
+const DEFAULT_VALUE: &str = "default_value";
+const IS_UNIQUE: &str = "is_unique";
+const IS_REQUIRED: &str = "is_required";
+
+/// The default value of a date field that resolves to the date the row is created.
+pub const DEFAULT_VALUE_TODAY: &str = "today";
+
+/// Field level attributes that apply regardless of the field type.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldAttributes {
+  /// The cells of a sensitive field are masked in the read APIs and the exports unless
+  /// the caller explicitly asks to reveal them.
+  pub is_sensitive: bool,
+  /// The maximum number of options a multi-select cell can hold. Zero means no limit.
+  pub max_selections: i64,
+  /// The value of the cell of a new row when the row is created without a value for the field.
+  /// Empty means no default value. See [default_cell_for_field] for how it is interpreted.
+  pub default_value: String,
+  /// No two rows can have the same non-empty value in a unique field, e.g. an ID column.
+  pub is_unique: bool,
+  /// The cells of a required field should not be empty. Rows can still be created with empty
+  /// cells, see [missing_required_fields] for how they are reported.
+  pub is_required: bool,
+}
+
+impl FieldAttributes {
+  pub fn from_field(field: &Field) -> Self {
+    field
+      .type_options
+      .get(FIELD_ATTRIBUTES_KEY)
+      .cloned()
+      .map(Self::from)
+      .unwrap_or_default()
+  }
+
+  /// Applies the attribute changes of the changeset. Returns true if any attribute changed.
+  pub fn apply_changeset(&mut self, changeset: &FieldChangesetPB) -> bool {
+    let old = self.clone();
+    if let Some(is_sensitive) = changeset.is_sensitive {
+      self.is_sensitive = is_sensitive;
+    }
+    if let Some(max_selections) = changeset.max_selections {
+      self.max_selections = max_selections.max(0);
+    }
+    if let Some(default_value) = &changeset.default_value {
+      self.default_value = default_value.trim().to_string();
+    }
+    if let Some(is_unique) = changeset.is_unique {
+      self.is_unique = is_unique;
+    }
+    if let Some(is_required) = changeset.is_required {
+      self.is_required = is_required;
+    }
+    *self != old
+  }
+}
+
+impl From<TypeOptionData> for FieldAttributes {
+  fn from(data: TypeOptionData) -> Self {
+    let is_sensitive: bool = data.get_as(IS_SENSITIVE).unwrap_or_default();
+    let max_selections: i64 = data.get_as(MAX_SELECTIONS).unwrap_or_default();
+    let default_value: String = data.get_as(DEFAULT_VALUE).unwrap_or_default();
+    let is_unique: bool = data.get_as(IS_UNIQUE).unwrap_or_default();
+    let is_required: bool = data.get_as(IS_REQUIRED).unwrap_or_default();
+    Self {
+      is_sensitive,
+      max_selections,
+      default_value,
+      is_unique,
+      is_required,
+    }
+  }
+}
+
+impl From<FieldAttributes> for TypeOptionData {
+  fn from(data: FieldAttributes) -> Self {
+    TypeOptionDataBuilder::from([
+      (IS_SENSITIVE.into(), data.is_sensitive.into()),
+      (MAX_SELECTIONS.into(), data.max_selections.into()),
+      (DEFAULT_VALUE.into(), data.default_value.into()),
+      (IS_UNIQUE.into(), data.is_unique.into()),
+      (IS_REQUIRED.into(), data.is_required.into()),
+    ])
+  }
+}
+
+/// Returns true if the cells of the field should be masked for the caller.
+pub fn should_mask_field(field: &Field, reveal_sensitive: bool) -> bool {
+  !reveal_sensitive && FieldAttributes::from_field(field).is_sensitive
+}
+
+/// Checks the new cell of the field against the constraints in the [FieldAttributes].
+pub fn validate_cell_with_attributes(cell: &Cell, field: &Field) -> FlowyResult<()> {
+  let attributes = FieldAttributes::from_field(field);
+  if attributes.max_selections > 0 && FieldType::from(field.field_type).is_multi_select() {
+    let num_of_selections = SelectOptionIds::from(cell).len() as i64;
+    if num_of_selections > attributes.max_selections {
+      return Err(FlowyError::new(
+        ErrorCode::ExceedMaxSelections,
+        format!(
+          "Can not select more than {} options",
+          attributes.max_selections
+        ),
+      ));
+    }
+  }
+  Ok(())
+}
+
+/// Checks the new cell of a unique field against the cells of the other rows. The cells are
+/// compared by their display strings, so `1` and `1.0` of a number field are the same value.
+/// Empty cells never conflict.
+pub fn validate_unique_cell<'a>(
+  cell: &Cell,
+  field: &Field,
+  other_cells: impl IntoIterator<Item = &'a Cell>,
+) -> FlowyResult<()> {
+  if !FieldAttributes::from_field(field).is_unique {
+    return Ok(());
+  }
+  let value = stringify_cell(cell, field);
+  if value.is_empty() {
+    return Ok(());
+  }
+  if other_cells
+    .into_iter()
+    .any(|other_cell| stringify_cell(other_cell, field) == value)
+  {
+    return Err(duplicate_cell_value_error(&value, field));
+  }
+  Ok(())
+}
+
+/// The values taken in unique fields, to check the new cells of many rows at once. Unlike
+/// [validate_unique_cell], the new cells are checked against each other too.
+#[derive(Debug, Default)]
+pub struct UniqueValues {
+  values_by_field_id: HashMap<String, (Field, HashSet<String>)>,
+}
+
+impl UniqueValues {
+  /// The cells of the given fields are checked whether the fields are unique or not.
+  pub fn new(fields: impl IntoIterator<Item = Field>) -> Self {
+    let values_by_field_id = fields
+      .into_iter()
+      .map(|field| (field.id.clone(), (field, HashSet::new())))
+      .collect();
+    Self { values_by_field_id }
+  }
+
+  pub fn field_ids(&self) -> Vec<String> {
+    self.values_by_field_id.keys().cloned().collect()
+  }
+
+  /// Records the value of an existing cell without checking it.
+  pub fn insert(&mut self, field_id: &str, cell: &Cell) {
+    if let Some((field, values)) = self.values_by_field_id.get_mut(field_id) {
+      let value = stringify_cell(cell, field);
+      if !value.is_empty() {
+        values.insert(value);
+      }
+    }
+  }
+
+  /// Checks the new cell against the values taken in its field, then takes its value. Empty
+  /// cells never conflict.
+  pub fn validate_and_insert(&mut self, field_id: &str, cell: &Cell) -> FlowyResult<()> {
+    if let Some((field, values)) = self.values_by_field_id.get_mut(field_id) {
+      let value = stringify_cell(cell, field);
+      if !value.is_empty() && !values.insert(value.clone()) {
+        return Err(duplicate_cell_value_error(&value, field));
+      }
+    }
+    Ok(())
+  }
+}
+
+fn duplicate_cell_value_error(value: &str, field: &Field) -> FlowyError {
+  FlowyError::new(
+    ErrorCode::DuplicateCellValue,
+    format!(
+      "The value {} already exists in the field {}",
+      value, field.name
+    ),
+  )
+}
+
+/// Returns the ids of the required fields whose cells are empty in the row.
+pub fn missing_required_fields(row: &Row, fields: &[Field]) -> Vec<String> {
+  fields
+    .iter()
+    .filter(|field| FieldAttributes::from_field(field).is_required)
+    .filter(|field| {
+      row
+        .cells
+        .get(&field.id)
+        .map(|cell| stringify_cell(cell, field).is_empty())
+        .unwrap_or(true)
+    })
+    .map(|field| field.id.clone())
+    .collect()
+}
+
+/// Returns the cell a new row gets for the field when the row is created without a value for it.
+///
+/// The default value is interpreted according to the field type:
+/// * Date: [DEFAULT_VALUE_TODAY] or a timestamp in seconds.
+/// * Single and multi-select: the names or ids of the options, separated by commas.
+/// * Checkbox: any input the checkbox accepts, including its custom labels.
+/// * Text, number, URL and time: the cell input as is.
+///
+/// Returns None if the field has no default value, or if the default value can't be applied to
+/// the field, e.g. an option that has been deleted.
+pub fn default_cell_for_field(field: &Field) -> Option<Cell> {
+  let default_value = FieldAttributes::from_field(field).default_value;
+  if default_value.is_empty() {
+    // Checkbox fields had a default state before fields had default values.
+    if CheckboxOptions::from_field(field).is_checked_by_default {
+      return apply_cell_changeset(BoxAny::new(CHECK.to_string()), None, field, None).ok();
+    }
+    return None;
+  }
+
+  default_value_cell(field, &default_value)
+    .map_err(|err| {
+      tracing::warn!(
+        "Failed to apply the default value of the field: {}, error: {}",
+        field.id,
+        err
+      )
+    })
+    .ok()
+}
+
+/// Checks that the default value can be applied to the field, see [default_cell_for_field].
+/// An empty default value removes the default value, so it is always valid.
+pub fn validate_default_value(field: &Field, default_value: &str) -> FlowyResult<()> {
+  if default_value.is_empty() {
+    return Ok(());
+  }
+  default_value_cell(field, default_value)?;
+  Ok(())
+}
+
+fn default_value_cell(field: &Field, default_value: &str) -> FlowyResult<Cell> {
+  let field_type = FieldType::from(field.field_type);
+  let invalid_default_value = || {
+    FlowyError::invalid_data().with_context(format!(
+      "{} is not a valid default value of a {:?} field",
+      default_value, field_type
+    ))
+  };
+  let changeset = match field_type {
+    FieldType::RichText
+    | FieldType::Number
+    | FieldType::Checkbox
+    | FieldType::URL
+    | FieldType::Time => BoxAny::new(default_value.to_string()),
+    FieldType::DateTime => {
+      let timestamp = if default_value.eq_ignore_ascii_case(DEFAULT_VALUE_TODAY) {
+        timestamp()
+      } else {
+        default_value
+          .parse::<i64>()
+          .map_err(|_| invalid_default_value())?
+      };
+      BoxAny::new(DateCellChangeset {
+        timestamp: Some(timestamp),
+        ..Default::default()
+      })
+    },
+    FieldType::SingleSelect | FieldType::MultiSelect => {
+      let options = select_type_option_from_field(field)?.options().clone();
+      let mut option_ids = default_value
+        .split(',')
+        .map(|s| s.trim())
+        .flat_map(|s| {
+          options
+            .iter()
+            .find(|option| option.id == s || option.name == s)
+            .map(|option| option.id.clone())
+        })
+        .collect::<Vec<_>>();
+      if field_type.is_single_select() {
+        option_ids.truncate(1);
+      }
+      if option_ids.is_empty() {
+        return Err(invalid_default_value());
+      }
+      BoxAny::new(SelectOptionCellChangeset::from_insert_options(option_ids))
+    },
+    _ => return Err(invalid_default_value()),
+  };
+  apply_cell_changeset(changeset, None, field, None)
+}
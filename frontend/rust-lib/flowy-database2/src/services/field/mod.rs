@@ -1,8 +1,12 @@
+mod cell_conversion;
+mod field_attributes;
 mod field_builder;
 mod field_operation;
 pub(crate) mod type_option_transform;
 pub mod type_options;
 
+pub use cell_conversion::*;
+pub use field_attributes::*;
 pub use field_builder::*;
 pub use field_operation::*;
 pub use type_options::*;
@@ -0,0 +1,133 @@
+use collab::util::AnyMapExt;
+use collab_database::fields::checkbox_type_option::CheckboxTypeOption;
+use collab_database::fields::{Field, TypeOptionData};
+
+use crate::entities::FieldType;
+use crate::services::field::{CHECK, UNCHECK};
+
+const IS_CHECKED_BY_DEFAULT: &str = "is_checked_by_default";
+const CHECKED_LABEL: &str = "checked_label";
+const UNCHECKED_LABEL: &str = "unchecked_label";
+
+/// The options of a checkbox field. [CheckboxTypeOption] doesn't have any options of its own, so
+/// they are stored alongside it in the checkbox type option data.
+///
+/// The labels only change how the cells are displayed and exported. The cells are always stored
+/// as [CHECK] or [UNCHECK].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CheckboxOptions {
+  /// The cell of a new row is checked unless the row is created with a value for the field.
+  pub is_checked_by_default: bool,
+  pub checked_label: String,
+  pub unchecked_label: String,
+}
+
+impl CheckboxOptions {
+  pub fn from_field(field: &Field) -> Self {
+    if FieldType::from(field.field_type) != FieldType::Checkbox {
+      return Self::default();
+    }
+    field
+      .get_any_type_option(FieldType::Checkbox)
+      .map(|type_option| Self::from(&type_option))
+      .unwrap_or_default()
+  }
+
+  /// Returns the label of the given state. Falls back to [CHECK] or [UNCHECK] if no custom label
+  /// is set.
+  pub fn label(&self, is_checked: bool) -> String {
+    match is_checked {
+      true if !self.checked_label.is_empty() => self.checked_label.clone(),
+      true => CHECK.to_string(),
+      false if !self.unchecked_label.is_empty() => self.unchecked_label.clone(),
+      false => UNCHECK.to_string(),
+    }
+  }
+
+  /// Maps a custom label back to the stored value, so `Done` is saved as [CHECK] if `Done` is the
+  /// checked label. Returns None if the input is not one of the custom labels.
+  pub fn unlabel(&self, s: &str) -> Option<&'static str> {
+    let s = s.trim();
+    if !self.checked_label.is_empty() && s.eq_ignore_ascii_case(&self.checked_label) {
+      Some(CHECK)
+    } else if !self.unchecked_label.is_empty() && s.eq_ignore_ascii_case(&self.unchecked_label) {
+      Some(UNCHECK)
+    } else {
+      None
+    }
+  }
+
+  /// Write the options into the checkbox type option data.
+  pub fn write_to(&self, type_option_data: &mut TypeOptionData) {
+    type_option_data.insert(
+      IS_CHECKED_BY_DEFAULT.into(),
+      self.is_checked_by_default.into(),
+    );
+    type_option_data.insert(CHECKED_LABEL.into(), self.checked_label.clone().into());
+    type_option_data.insert(UNCHECKED_LABEL.into(), self.unchecked_label.clone().into());
+  }
+}
+
+impl From<&TypeOptionData> for CheckboxOptions {
+  fn from(data: &TypeOptionData) -> Self {
+    Self {
+      is_checked_by_default: data.get_as(IS_CHECKED_BY_DEFAULT).unwrap_or_default(),
+      checked_label: data.get_as(CHECKED_LABEL).unwrap_or_default(),
+      unchecked_label: data.get_as(UNCHECKED_LABEL).unwrap_or_default(),
+    }
+  }
+}
+
+/// Builds the type option data of a checkbox field with its [CheckboxOptions].
+#[derive(Debug, Clone, Default)]
+pub struct CheckboxTypeOptionsBuilder {
+  options: CheckboxOptions,
+}
+
+impl CheckboxTypeOptionsBuilder {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn checked_by_default(mut self, is_checked_by_default: bool) -> Self {
+    self.options.is_checked_by_default = is_checked_by_default;
+    self
+  }
+
+  pub fn labels(mut self, checked_label: &str, unchecked_label: &str) -> Self {
+    self.options.checked_label = checked_label.trim().to_string();
+    self.options.unchecked_label = unchecked_label.trim().to_string();
+    self
+  }
+
+  pub fn build(self) -> TypeOptionData {
+    let mut type_option_data: TypeOptionData = CheckboxTypeOption.into();
+    self.options.write_to(&mut type_option_data);
+    type_option_data
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn checkbox_options_label_test() {
+    let options = CheckboxOptions::default();
+    assert_eq!(options.label(true), CHECK);
+    assert_eq!(options.label(false), UNCHECK);
+    assert_eq!(options.unlabel("Yes"), None);
+
+    let type_option_data = CheckboxTypeOptionsBuilder::new()
+      .checked_by_default(true)
+      .labels("Done", "Todo")
+      .build();
+    let options = CheckboxOptions::from(&type_option_data);
+    assert!(options.is_checked_by_default);
+    assert_eq!(options.label(true), "Done");
+    assert_eq!(options.label(false), "Todo");
+    assert_eq!(options.unlabel("done"), Some(CHECK));
+    assert_eq!(options.unlabel(" Todo "), Some(UNCHECK));
+    assert_eq!(options.unlabel("1"), None);
+  }
+}
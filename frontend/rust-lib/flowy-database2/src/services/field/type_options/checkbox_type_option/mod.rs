@@ -1,8 +1,10 @@
 #![allow(clippy::module_inception)]
 mod checkbox_filter;
+mod checkbox_options;
 mod checkbox_tests;
 mod checkbox_type_option;
 mod checkbox_type_option_entities;
 
+pub use checkbox_options::*;
 pub use checkbox_type_option::*;
 pub use checkbox_type_option_entities::*;
@@ -38,6 +38,8 @@ impl ChecklistFilterPB {
         all_option_ids.retain(|option_id| !selected_option_ids.contains(option_id));
         !all_option_ids.is_empty()
       },
+      ChecklistFilterConditionPB::IsEmpty => all_option_ids.is_empty(),
+      ChecklistFilterConditionPB::IsNotEmpty => !all_option_ids.is_empty(),
     }
   }
 }
@@ -3,7 +3,7 @@ use crate::services::cell::insert_date_cell;
 use crate::services::field::TimestampCellData;
 use crate::services::filter::PreFillCellsWithFilter;
 
-use chrono::{Duration, Local, NaiveDate, TimeZone};
+use chrono::{Duration, Local, NaiveDate, TimeZone, Weekday};
 use collab_database::fields::date_type_option::DateCellData;
 use collab_database::fields::Field;
 use collab_database::rows::Cell;
@@ -59,6 +59,27 @@ impl DateFilterPB {
       DateFilterConditionPB::DateStartIsNotEmpty | DateFilterConditionPB::DateEndIsNotEmpty => {
         DateFilterStrategy::NotEmpty
       },
+      DateFilterConditionPB::DateStartsToday | DateFilterConditionPB::DateEndsToday => {
+        let today = Local::now().date_naive();
+        DateFilterStrategy::DateRange {
+          start: today,
+          end: today,
+        }
+      },
+      DateFilterConditionPB::DateStartsThisWeek | DateFilterConditionPB::DateEndsThisWeek => {
+        let week = Local::now().date_naive().week(Weekday::Mon);
+        DateFilterStrategy::DateRange {
+          start: week.first_day(),
+          end: week.last_day(),
+        }
+      },
+      DateFilterConditionPB::DateStartsInLastDays | DateFilterConditionPB::DateEndsInLastDays => {
+        let today = Local::now().date_naive();
+        DateFilterStrategy::DateRange {
+          start: today - Duration::days(self.days?),
+          end: today,
+        }
+      },
     };
 
     Some(strategy)
@@ -79,7 +100,16 @@ enum DateFilterStrategy {
   After(i64),
   OnOrBefore(i64),
   OnOrAfter(i64),
-  DateBetween { start: i64, end: i64 },
+  DateBetween {
+    start: i64,
+    end: i64,
+  },
+  /// The dates are computed when the filter is applied, which keeps relative conditions like
+  /// `DateStartsToday` up to date.
+  DateRange {
+    start: NaiveDate,
+    end: NaiveDate,
+  },
   Empty,
   NotEmpty,
 }
@@ -118,6 +148,9 @@ impl DateFilterStrategy {
         let expected_end_date = naive_date_from_timestamp(end);
         cell_date >= expected_start_date && cell_date <= expected_end_date
       }),
+      DateFilterStrategy::DateRange { start, end } => cell_data.is_some_and(|timestamp| {
+        naive_date_from_timestamp(timestamp).is_some_and(|date| date >= start && date <= end)
+      }),
       DateFilterStrategy::Empty => match cell_data {
         None => true,
         Some(timestamp) if naive_date_from_timestamp(timestamp).is_none() => true,
@@ -172,6 +205,12 @@ impl PreFillCellsWithFilter for DateFilterPB {
       DateFilterConditionPB::DateStartsBetween | DateFilterConditionPB::DateEndsBetween => {
         self.start
       },
+      DateFilterConditionPB::DateStartsToday
+      | DateFilterConditionPB::DateStartsThisWeek
+      | DateFilterConditionPB::DateStartsInLastDays
+      | DateFilterConditionPB::DateEndsToday
+      | DateFilterConditionPB::DateEndsThisWeek
+      | DateFilterConditionPB::DateEndsInLastDays => Some(Local::now().timestamp()),
       _ => None,
     };
 
@@ -182,6 +221,7 @@ impl PreFillCellsWithFilter for DateFilterPB {
 #[cfg(test)]
 mod tests {
   use crate::entities::{DateFilterConditionPB, DateFilterPB};
+  use chrono::{Duration, Local};
   use collab_database::fields::date_type_option::DateCellData;
 
   fn to_cell_data(timestamp: Option<i64>, end_timestamp: Option<i64>) -> DateCellData {
@@ -199,6 +239,7 @@ mod tests {
       timestamp: Some(1668387885),
       end: None,
       start: None,
+      days: None,
     };
 
     for (start, end, is_visible) in [
@@ -217,6 +258,7 @@ mod tests {
       timestamp: None,
       end: None,
       start: None,
+      days: None,
     };
 
     for (start, end, is_visible) in [
@@ -238,6 +280,7 @@ mod tests {
       timestamp: Some(1668387885),
       start: None,
       end: None,
+      days: None,
     };
 
     for (start, end, is_visible) in [
@@ -258,6 +301,7 @@ mod tests {
       timestamp: Some(1668387885),
       start: None,
       end: None,
+      days: None,
     };
 
     for (start, end, is_visible) in [
@@ -277,6 +321,7 @@ mod tests {
       timestamp: Some(1668387885),
       start: None,
       end: None,
+      days: None,
     };
 
     for (start, end, is_visible) in [
@@ -298,6 +343,7 @@ mod tests {
       start: Some(1668272685), // 11/13
       end: Some(1668618285),   // 11/17
       timestamp: None,
+      days: None,
     };
 
     for (start, end, is_visible, msg) in [
@@ -318,6 +364,7 @@ mod tests {
       start: None,
       end: Some(1668618285), // 11/17
       timestamp: None,
+      days: None,
     };
 
     for (start, end, is_visible, msg) in [
@@ -341,6 +388,7 @@ mod tests {
       start: None,
       end: None,
       timestamp: None,
+      days: None,
     };
 
     for (start, end, is_visible) in [(None, None, true), (Some(123), None, false)] {
@@ -358,6 +406,7 @@ mod tests {
       timestamp: Some(1668359085), // 11/14
       end: None,
       start: None,
+      days: None,
     };
 
     for (start, end, is_visible, msg) in [
@@ -381,6 +430,7 @@ mod tests {
       timestamp: None,
       start: None,
       end: None,
+      days: None,
     };
 
     for (start, end, is_visible, msg) in [
@@ -397,6 +447,69 @@ mod tests {
     }
   }
 
+  #[test]
+  fn date_filter_relative_test() {
+    let now = Local::now().timestamp();
+    let one_day = Duration::days(1).num_seconds();
+    let filter = |condition, days| DateFilterPB {
+      condition,
+      start: None,
+      end: None,
+      timestamp: None,
+      days,
+    };
+
+    let today = filter(DateFilterConditionPB::DateStartsToday, None);
+    for (start, end, is_visible, msg) in [
+      (Some(now), None, true, "now"),
+      (Some(now - 2 * one_day), None, false, "two days ago"),
+      (Some(now + 2 * one_day), None, false, "in two days"),
+      (None, None, false, "empty"),
+    ] {
+      assert_eq!(
+        today.is_visible(&to_cell_data(start, end)).unwrap_or(true),
+        is_visible,
+        "{msg}"
+      );
+    }
+
+    let this_week = filter(DateFilterConditionPB::DateEndsThisWeek, None);
+    for (start, end, is_visible, msg) in [
+      (Some(now), None, true, "now"),
+      (Some(now - 8 * one_day), Some(now), true, "ends now"),
+      (Some(now), Some(now + 8 * one_day), false, "ends next week"),
+      (Some(now - 8 * one_day), None, false, "last week"),
+    ] {
+      assert_eq!(
+        this_week
+          .is_visible(&to_cell_data(start, end))
+          .unwrap_or(true),
+        is_visible,
+        "{msg}"
+      );
+    }
+
+    let last_7_days = filter(DateFilterConditionPB::DateStartsInLastDays, Some(7));
+    for (start, end, is_visible, msg) in [
+      (Some(now), None, true, "now"),
+      (Some(now - 3 * one_day), None, true, "three days ago"),
+      (Some(now - 10 * one_day), None, false, "ten days ago"),
+      (Some(now + one_day), None, false, "tomorrow"),
+    ] {
+      assert_eq!(
+        last_7_days
+          .is_visible(&to_cell_data(start, end))
+          .unwrap_or(true),
+        is_visible,
+        "{msg}"
+      );
+    }
+
+    // The filter is ignored until the number of days is set
+    let last_days = filter(DateFilterConditionPB::DateStartsInLastDays, None);
+    assert!(last_days.is_visible(&to_cell_data(None, None)).is_none());
+  }
+
   #[test]
   fn timezoned_filter_test() {
     let filter = DateFilterPB {
@@ -404,6 +517,7 @@ mod tests {
       timestamp: Some(1728975660), // Oct 15, 2024 00:00 PDT
       end: None,
       start: None,
+      days: None,
     };
 
     for (start, end, is_visible, msg) in [
@@ -2,7 +2,9 @@
 mod number_filter;
 mod number_type_option;
 mod number_type_option_entities;
+mod number_unit;
 
 // pub use format::*;
 pub use number_type_option::*;
 pub use number_type_option_entities::*;
+pub use number_unit::*;
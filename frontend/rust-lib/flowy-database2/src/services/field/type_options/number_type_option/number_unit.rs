@@ -0,0 +1,135 @@
+use collab::util::AnyMapExt;
+use collab_database::fields::{Field, TypeOptionData};
+
+use crate::entities::FieldType;
+
+const UNIT: &str = "unit";
+const UNIT_POSITION: &str = "unit_position";
+
+/// Where the unit is placed relative to the number.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum NumberUnitPosition {
+  /// `42 kg`
+  #[default]
+  Suffix = 0,
+  /// `#42`
+  Prefix = 1,
+}
+
+impl From<i64> for NumberUnitPosition {
+  fn from(value: i64) -> Self {
+    match value {
+      1 => NumberUnitPosition::Prefix,
+      _ => NumberUnitPosition::Suffix,
+    }
+  }
+}
+
+/// The unit of a number field, such as `kg`, `ms` or `items`. It is stored alongside the
+/// [NumberTypeOption](collab_database::fields::number_type_option::NumberTypeOption) data and is
+/// used when formatting and parsing the cells of the field.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NumberUnit {
+  pub unit: String,
+  pub position: NumberUnitPosition,
+}
+
+impl NumberUnit {
+  pub fn new(unit: &str, position: NumberUnitPosition) -> Self {
+    Self {
+      unit: unit.trim().to_string(),
+      position,
+    }
+  }
+
+  pub fn from_field(field: &Field) -> Self {
+    if FieldType::from(field.field_type) != FieldType::Number {
+      return Self::default();
+    }
+    field
+      .get_any_type_option(FieldType::Number)
+      .map(|type_option| Self::from(&type_option))
+      .unwrap_or_default()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.unit.is_empty()
+  }
+
+  /// Attach the unit to the formatted number. Empty cells stay empty.
+  pub fn format(&self, s: &str) -> String {
+    if self.is_empty() || s.is_empty() {
+      return s.to_string();
+    }
+    match self.position {
+      NumberUnitPosition::Suffix => format!("{} {}", s, self.unit),
+      NumberUnitPosition::Prefix => format!("{}{}", self.unit, s),
+    }
+  }
+
+  /// Remove the unit from the user input, so `42 kg` and `42kg` are both parsed as `42`.
+  pub fn strip(&self, s: &str) -> String {
+    let s = s.trim();
+    if self.is_empty() {
+      return s.to_string();
+    }
+    let s = match self.position {
+      NumberUnitPosition::Suffix => s.strip_suffix(self.unit.as_str()).unwrap_or(s),
+      NumberUnitPosition::Prefix => s.strip_prefix(self.unit.as_str()).unwrap_or(s),
+    };
+    s.trim().to_string()
+  }
+
+  /// Write the unit into the number type option data.
+  pub fn write_to(&self, type_option_data: &mut TypeOptionData) {
+    type_option_data.insert(UNIT.into(), self.unit.clone().into());
+    type_option_data.insert(UNIT_POSITION.into(), (self.position as i64).into());
+  }
+}
+
+impl From<&TypeOptionData> for NumberUnit {
+  fn from(data: &TypeOptionData) -> Self {
+    let unit: String = data.get_as(UNIT).unwrap_or_default();
+    let position: i64 = data.get_as(UNIT_POSITION).unwrap_or_default();
+    Self {
+      unit,
+      position: NumberUnitPosition::from(position),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use collab_database::fields::TypeOptionDataBuilder;
+
+  #[test]
+  fn number_unit_format_test() {
+    let unit = NumberUnit::new("kg", NumberUnitPosition::Suffix);
+    assert_eq!(unit.format("42"), "42 kg");
+    assert_eq!(unit.format(""), "");
+
+    let unit = NumberUnit::new("#", NumberUnitPosition::Prefix);
+    assert_eq!(unit.format("42"), "#42");
+  }
+
+  #[test]
+  fn number_unit_strip_test() {
+    let unit = NumberUnit::new("kg", NumberUnitPosition::Suffix);
+    assert_eq!(unit.strip("42 kg"), "42");
+    assert_eq!(unit.strip("42kg"), "42");
+    assert_eq!(unit.strip(" 42 "), "42");
+
+    let unit = NumberUnit::new("#", NumberUnitPosition::Prefix);
+    assert_eq!(unit.strip("#42"), "42");
+  }
+
+  #[test]
+  fn number_unit_type_option_data_test() {
+    let unit = NumberUnit::new("ms", NumberUnitPosition::Suffix);
+    let mut data = TypeOptionDataBuilder::new();
+    unit.write_to(&mut data);
+    assert_eq!(NumberUnit::from(&data), unit);
+  }
+}
@@ -0,0 +1,48 @@
+use collab_database::{fields::Field, rows::Cell};
+
+use crate::entities::{RelationFilterConditionPB, RelationFilterPB};
+use crate::services::filter::PreFillCellsWithFilter;
+
+use super::RelationCellData;
+
+impl RelationFilterPB {
+  pub fn is_visible(&self, cell_data: &RelationCellData) -> bool {
+    let contains_any = || {
+      cell_data
+        .row_ids
+        .iter()
+        .any(|row_id| self.row_ids.iter().any(|id| id == row_id.as_str()))
+    };
+
+    match self.condition {
+      RelationFilterConditionPB::RelationIsEmpty => cell_data.row_ids.is_empty(),
+      RelationFilterConditionPB::RelationIsNotEmpty => !cell_data.row_ids.is_empty(),
+      RelationFilterConditionPB::RelationContains
+      | RelationFilterConditionPB::RelationDoesNotContain
+        if self.row_ids.is_empty() =>
+      {
+        true
+      },
+      RelationFilterConditionPB::RelationContains => contains_any(),
+      RelationFilterConditionPB::RelationDoesNotContain => !contains_any(),
+    }
+  }
+}
+
+impl PreFillCellsWithFilter for RelationFilterPB {
+  fn get_compliant_cell(&self, _field: &Field) -> Option<Cell> {
+    match self.condition {
+      RelationFilterConditionPB::RelationContains if !self.row_ids.is_empty() => {
+        let cell_data = RelationCellData {
+          row_ids: self
+            .row_ids
+            .iter()
+            .map(|row_id| row_id.clone().into())
+            .collect(),
+        };
+        Some((&cell_data).into())
+      },
+      _ => None,
+    }
+  }
+}
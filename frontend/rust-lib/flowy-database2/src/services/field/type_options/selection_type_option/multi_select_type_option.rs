@@ -115,7 +115,8 @@ impl TypeOptionCellDataCompare for MultiSelectTypeOption {
   /// - If both cells are empty (`None`), they are considered equal.
   /// - If the left cell is empty and the right is not, the left cell is ordered to come after the right.
   /// - If the right cell is empty and the left is not, the left cell is ordered to come before the right.
-  /// - If both cells are non-empty, they are ordered based on their names. If there is an additional sort condition,
+  /// - If both cells are non-empty, they are ordered based on the position of their options in the
+  ///   type option. If there is an additional sort condition,
   ///   this condition will further evaluate their order.
   ///
   fn apply_cmp(
@@ -127,15 +128,17 @@ impl TypeOptionCellDataCompare for MultiSelectTypeOption {
     match cell_data.len().cmp(&other_cell_data.len()) {
       Ordering::Equal => {
         for (left_id, right_id) in cell_data.iter().zip(other_cell_data.iter()) {
-          let left = self.options.iter().find(|option| &option.id == left_id);
-          let right = self.options.iter().find(|option| &option.id == right_id);
+          let left = self.options.iter().position(|option| &option.id == left_id);
+          let right = self
+            .options
+            .iter()
+            .position(|option| &option.id == right_id);
           let order = match (left, right) {
             (None, None) => Ordering::Equal,
             (None, Some(_)) => Ordering::Greater,
             (Some(_), None) => Ordering::Less,
-            (Some(left_option), Some(right_option)) => {
-              let name_order = left_option.name.cmp(&right_option.name);
-              sort_condition.evaluate_order(name_order)
+            (Some(left_index), Some(right_index)) => {
+              sort_condition.evaluate_order(left_index.cmp(&right_index))
             },
           };
 
@@ -155,7 +158,7 @@ mod tests {
   use crate::services::cell::CellDataChangeset;
   use crate::services::field::type_options::selection_type_option::*;
   use collab_database::fields::select_type_option::{
-    MultiSelectTypeOption, SelectOption, SelectOptionIds, SelectTypeOption,
+    MultiSelectTypeOption, SelectOption, SelectOptionColor, SelectOptionIds, SelectTypeOption,
   };
 
   #[test]
@@ -240,4 +243,24 @@ mod tests {
     let select_option_ids = multi_select.apply_changeset(changeset, None).unwrap().1;
     assert!(select_option_ids.is_empty());
   }
+
+  #[test]
+  fn multi_select_update_option_color_test() {
+    let mut multi_select = SelectTypeOptionBuilder::new()
+      .option_with_color("Google", SelectOptionColor::Blue)
+      .option("Facebook")
+      .build_multi_select();
+    let google_id = multi_select.options[0].id.clone();
+
+    let updated = multi_select
+      .update_option(&google_id, None, Some(SelectOptionColor::Green))
+      .unwrap();
+    assert_eq!(updated.name, "Google");
+    assert_eq!(updated.color, SelectOptionColor::Green);
+    assert_eq!(multi_select.options[0].color, SelectOptionColor::Green);
+
+    assert!(multi_select
+      .update_option("not exist", Some("Bing".to_string()), None)
+      .is_none());
+  }
 }
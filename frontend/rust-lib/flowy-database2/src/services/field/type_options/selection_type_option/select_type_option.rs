@@ -44,6 +44,42 @@ pub trait SelectTypeOptionSharedAction: Send + Sync {
     }
   }
 
+  /// Update the name and/or the color of the option with the given `option_id`.
+  /// Returns the updated option, or `None` if the option does not exist.
+  fn update_option(
+    &mut self,
+    option_id: &str,
+    name: Option<String>,
+    color: Option<SelectOptionColor>,
+  ) -> Option<SelectOption> {
+    let option = self
+      .mut_options()
+      .iter_mut()
+      .find(|option| option.id == option_id)?;
+    if let Some(name) = name {
+      option.name = name;
+    }
+    if let Some(color) = color {
+      option.color = color;
+    }
+    Some(option.clone())
+  }
+
+  /// Move the option with the given `option_id` to `to_index`. The index is clamped to the
+  /// last position. Returns false if the option does not exist.
+  fn move_option(&mut self, option_id: &str, to_index: usize) -> bool {
+    let options = self.mut_options();
+    match options.iter().position(|option| option.id == option_id) {
+      None => false,
+      Some(from_index) => {
+        let option = options.remove(from_index);
+        let to_index = to_index.min(options.len());
+        options.insert(to_index, option);
+        true
+      },
+    }
+  }
+
   fn delete_option(&mut self, option_id: &str) {
     let options = self.mut_options();
     if let Some(index) = options.iter().position(|option| option.id == option_id) {
@@ -207,6 +243,42 @@ pub fn new_select_option_color(options: &[SelectOption]) -> SelectOptionColor {
   }
 }
 
+/// Builds the type option of a SingleSelect or MultiSelect field from a list of options.
+/// Options added without an explicit color pick the least used color of the palette.
+#[derive(Debug, Default)]
+pub struct SelectTypeOptionBuilder {
+  options: Vec<SelectOption>,
+}
+
+impl SelectTypeOptionBuilder {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn option(mut self, name: &str) -> Self {
+    let color = new_select_option_color(&self.options);
+    self.options.push(SelectOption::with_color(name, color));
+    self
+  }
+
+  pub fn option_with_color(mut self, name: &str, color: SelectOptionColor) -> Self {
+    self.options.push(SelectOption::with_color(name, color));
+    self
+  }
+
+  pub fn build_single_select(self) -> SingleSelectTypeOption {
+    let mut type_option = SingleSelectTypeOption::default();
+    type_option.options = self.options;
+    type_option
+  }
+
+  pub fn build_multi_select(self) -> MultiSelectTypeOption {
+    let mut type_option = MultiSelectTypeOption::default();
+    type_option.options = self.options;
+    type_option
+  }
+}
+
 pub struct SelectOptionIdsParser();
 impl CellProtobufBlobParser for SelectOptionIdsParser {
   type Object = SelectOptionIds;
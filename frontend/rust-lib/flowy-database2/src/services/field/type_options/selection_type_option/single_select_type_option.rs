@@ -108,16 +108,18 @@ impl TypeOptionCellDataCompare for SingleSelectTypeOption {
     other_cell_data: &<Self as TypeOption>::CellData,
     sort_condition: SortCondition,
   ) -> Ordering {
+    // Options are ordered by their position in the type option, so the sort follows the
+    // order the user arranged the options in rather than their names.
     match (
       cell_data
         .first()
-        .and_then(|id| self.options.iter().find(|option| &option.id == id)),
+        .and_then(|id| self.options.iter().position(|option| &option.id == id)),
       other_cell_data
         .first()
-        .and_then(|id| self.options.iter().find(|option| &option.id == id)),
+        .and_then(|id| self.options.iter().position(|option| &option.id == id)),
     ) {
       (Some(left), Some(right)) => {
-        let order = left.name.cmp(&right.name);
+        let order = left.cmp(&right);
         sort_condition.evaluate_order(order)
       },
       (Some(_), None) => Ordering::Less,
@@ -170,4 +172,38 @@ mod tests {
     let select_option_ids = single_select.apply_changeset(changeset, None).unwrap().1;
     assert!(select_option_ids.is_cell_empty());
   }
+
+  #[test]
+  fn single_select_move_option_test() {
+    let google = SelectOption::new("Google");
+    let facebook = SelectOption::new("Facebook");
+    let twitter = SelectOption::new("Twitter");
+    let mut single_select = SingleSelectTypeOption(SelectTypeOption {
+      options: vec![google.clone(), facebook.clone(), twitter.clone()],
+      disable_color: false,
+    });
+
+    let option_ids = |single_select: &SingleSelectTypeOption| {
+      single_select
+        .options()
+        .iter()
+        .map(|option| option.id.clone())
+        .collect::<Vec<_>>()
+    };
+
+    assert!(single_select.move_option(&twitter.id, 0));
+    assert_eq!(
+      option_ids(&single_select),
+      vec![twitter.id.clone(), google.id.clone(), facebook.id.clone()]
+    );
+
+    // The index is clamped to the last position
+    assert!(single_select.move_option(&twitter.id, 10));
+    assert_eq!(
+      option_ids(&single_select),
+      vec![google.id, facebook.id, twitter.id]
+    );
+
+    assert!(!single_select.move_option("unknown", 0));
+  }
 }
@@ -32,6 +32,9 @@ impl SelectOptionTypeOptionTransformHelper {
   {
     match old_field_type {
       FieldType::RichText => {
+        // A field that already has options was a select field before, e.g. when switching a
+        // select field to text and back. Its text cells are left as is and transformed when
+        // read, so the cells that still hold the names of the options keep them.
         if !shared.options().is_empty() {
           return;
         }
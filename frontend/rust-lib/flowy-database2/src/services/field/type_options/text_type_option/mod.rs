@@ -1,6 +1,10 @@
 #![allow(clippy::module_inception)]
+mod text_delta;
 mod text_filter;
 mod text_tests;
 mod text_type_option;
+mod text_validation;
 
+pub use text_delta::*;
 pub use text_type_option::*;
+pub use text_validation::*;
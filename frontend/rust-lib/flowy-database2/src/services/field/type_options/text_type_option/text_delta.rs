@@ -0,0 +1,331 @@
+use flowy_error::{FlowyError, FlowyResult};
+
+use crate::entities::TextCellDeltaChangesetPB;
+
+/// An operation of a [TextDelta]. The lengths are measured in UTF-16 code units, the same unit
+/// the text editors of the clients use for their selections.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextDeltaOp {
+  Retain(usize),
+  Insert(String),
+  Delete(usize),
+}
+
+/// A partial edit of a text cell. The content after the last operation is retained.
+///
+/// If the delta carries the [TextDelta::base_content] it was made against and the cell changed
+/// since, the delta is transformed against that change before it's applied, so concurrent edits
+/// to different parts of a long text are all kept. The change is computed from the common prefix
+/// and suffix of the two contents, so the edits that fall into the changed range keep their
+/// text but are moved to its start. Without a base, the delta is applied to whatever the cell
+/// holds when the edit arrives.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextDelta {
+  pub ops: Vec<TextDeltaOp>,
+  /// The content of the cell the delta was made against.
+  pub base_content: Option<String>,
+}
+
+impl TextDelta {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn retain(mut self, len: usize) -> Self {
+    if len > 0 {
+      self.ops.push(TextDeltaOp::Retain(len));
+    }
+    self
+  }
+
+  pub fn insert(mut self, s: &str) -> Self {
+    if !s.is_empty() {
+      self.ops.push(TextDeltaOp::Insert(s.to_string()));
+    }
+    self
+  }
+
+  pub fn delete(mut self, len: usize) -> Self {
+    if len > 0 {
+      self.ops.push(TextDeltaOp::Delete(len));
+    }
+    self
+  }
+
+  pub fn with_base_content(mut self, base_content: &str) -> Self {
+    self.base_content = Some(base_content.to_string());
+    self
+  }
+
+  /// Applies the delta to the current content of the cell, transforming it first if the cell
+  /// changed since the [TextDelta::base_content].
+  pub fn apply_to_current(&self, current: &str) -> FlowyResult<String> {
+    match &self.base_content {
+      Some(base) if base != current => {
+        let change = TextDelta::diff(base, current);
+        let base_len = base.encode_utf16().count();
+        self.transform(&change, base_len)?.apply(current)
+      },
+      _ => self.apply(current),
+    }
+  }
+
+  /// Returns the delta that turns `old` into `new`: the text between their common prefix and
+  /// suffix is deleted and replaced.
+  pub fn diff(old: &str, new: &str) -> TextDelta {
+    let old_units = old.encode_utf16().collect::<Vec<u16>>();
+    let new_units = new.encode_utf16().collect::<Vec<u16>>();
+    let mut prefix = old_units
+      .iter()
+      .zip(new_units.iter())
+      .take_while(|(a, b)| a == b)
+      .count();
+    // Don't split a surrogate pair
+    if prefix > 0 && is_high_surrogate(old_units[prefix - 1]) {
+      prefix -= 1;
+    }
+    let max_suffix = old_units.len().min(new_units.len()) - prefix;
+    let mut suffix = old_units
+      .iter()
+      .rev()
+      .zip(new_units.iter().rev())
+      .take(max_suffix)
+      .take_while(|(a, b)| a == b)
+      .count();
+    if suffix > 0 && is_low_surrogate(old_units[old_units.len() - suffix]) {
+      suffix -= 1;
+    }
+    let inserted = String::from_utf16_lossy(&new_units[prefix..new_units.len() - suffix]);
+    TextDelta::new()
+      .retain(prefix)
+      .delete(old_units.len() - suffix - prefix)
+      .insert(&inserted)
+  }
+
+  /// Transforms the delta against `other`, a concurrent delta that was made against the same
+  /// text of `base_len` UTF-16 code units and has been applied first. The returned delta applies
+  /// to the result of `other`. If both insert at the same position, the text of this delta
+  /// comes first.
+  pub fn transform(&self, other: &TextDelta, base_len: usize) -> FlowyResult<TextDelta> {
+    let mut ops = self.with_trailing_retain(base_len)?.into_iter();
+    let mut other_ops = other.with_trailing_retain(base_len)?.into_iter();
+    let mut op = ops.next();
+    let mut other_op = other_ops.next();
+    let mut transformed = TextDelta::new();
+    loop {
+      match (op.take(), other_op.take()) {
+        (None, None) => break,
+        (Some(TextDeltaOp::Insert(s)), next_other_op) => {
+          transformed = transformed.insert(&s);
+          op = ops.next();
+          other_op = next_other_op;
+        },
+        (next_op, Some(TextDeltaOp::Insert(s))) => {
+          transformed = transformed.retain(s.encode_utf16().count());
+          op = next_op;
+          other_op = other_ops.next();
+        },
+        (Some(a), Some(b)) => {
+          let len = a.len().min(b.len());
+          match (&a, &b) {
+            (TextDeltaOp::Retain(_), TextDeltaOp::Retain(_)) => {
+              transformed = transformed.retain(len)
+            },
+            (TextDeltaOp::Delete(_), TextDeltaOp::Retain(_)) => {
+              transformed = transformed.delete(len)
+            },
+            // The text is deleted by the other delta already
+            _ => {},
+          }
+          op = a.skip(len).or_else(|| ops.next());
+          other_op = b.skip(len).or_else(|| other_ops.next());
+        },
+        // Both deltas cover exactly `base_len` units after adding the trailing retain
+        _ => {
+          return Err(
+            FlowyError::internal().with_context("The deltas cover different lengths of text"),
+          )
+        },
+      }
+    }
+    Ok(transformed)
+  }
+
+  /// Returns the ops with a retain of the rest of the text that the delta leaves implicit.
+  fn with_trailing_retain(&self, base_len: usize) -> FlowyResult<Vec<TextDeltaOp>> {
+    let len = self
+      .ops
+      .iter()
+      .filter(|op| !matches!(op, TextDeltaOp::Insert(_)))
+      .map(TextDeltaOp::len)
+      .sum::<usize>();
+    if len > base_len {
+      return Err(FlowyError::invalid_data().with_context(format!(
+        "The delta exceeds the length of the text: {} > {}",
+        len, base_len
+      )));
+    }
+    let mut ops = self.ops.clone();
+    if base_len > len {
+      ops.push(TextDeltaOp::Retain(base_len - len));
+    }
+    Ok(ops)
+  }
+
+  /// Applies the delta to the given text and returns the new text.
+  ///
+  /// Returns an error if the delta retains or deletes past the end of the text, or if it splits
+  /// a character that is encoded as a surrogate pair.
+  pub fn apply(&self, s: &str) -> FlowyResult<String> {
+    let units = s.encode_utf16().collect::<Vec<u16>>();
+    let mut new_units = Vec::with_capacity(units.len());
+    let mut index = 0;
+    for op in &self.ops {
+      match op {
+        TextDeltaOp::Retain(len) | TextDeltaOp::Delete(len) => {
+          let end = index + len;
+          if end > units.len() {
+            return Err(FlowyError::invalid_data().with_context(format!(
+              "The delta exceeds the length of the text: {} > {}",
+              end,
+              units.len()
+            )));
+          }
+          if matches!(op, TextDeltaOp::Retain(_)) {
+            new_units.extend_from_slice(&units[index..end]);
+          }
+          index = end;
+        },
+        TextDeltaOp::Insert(insert) => new_units.extend(insert.encode_utf16()),
+      }
+    }
+    new_units.extend_from_slice(&units[index..]);
+
+    String::from_utf16(&new_units).map_err(|_| {
+      FlowyError::invalid_data().with_context("The delta splits a character of the text")
+    })
+  }
+}
+
+impl TextDeltaOp {
+  /// The length of the op in UTF-16 code units.
+  fn len(&self) -> usize {
+    match self {
+      TextDeltaOp::Retain(len) | TextDeltaOp::Delete(len) => *len,
+      TextDeltaOp::Insert(s) => s.encode_utf16().count(),
+    }
+  }
+
+  /// Returns what's left of a retain or a delete after the first `len` units.
+  fn skip(self, len: usize) -> Option<TextDeltaOp> {
+    match self {
+      TextDeltaOp::Retain(n) if n > len => Some(TextDeltaOp::Retain(n - len)),
+      TextDeltaOp::Delete(n) if n > len => Some(TextDeltaOp::Delete(n - len)),
+      _ => None,
+    }
+  }
+}
+
+fn is_high_surrogate(unit: u16) -> bool {
+  (0xD800..0xDC00).contains(&unit)
+}
+
+fn is_low_surrogate(unit: u16) -> bool {
+  (0xDC00..0xE000).contains(&unit)
+}
+
+impl From<TextCellDeltaChangesetPB> for TextDelta {
+  fn from(value: TextCellDeltaChangesetPB) -> Self {
+    let delta = value.ops.into_iter().fold(TextDelta::new(), |delta, op| {
+      match (op.retain, op.insert, op.delete) {
+        (Some(len), _, _) => delta.retain(len.max(0) as usize),
+        (_, Some(s), _) => delta.insert(&s),
+        (_, _, Some(len)) => delta.delete(len.max(0) as usize),
+        _ => delta,
+      }
+    });
+    TextDelta {
+      base_content: value.base_content,
+      ..delta
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn text_delta_apply_test() {
+    let delta = TextDelta::new().retain(6).delete(5).insert("AppFlowy");
+    assert_eq!(delta.apply("Hello world!").unwrap(), "Hello AppFlowy!");
+
+    let delta = TextDelta::new().insert("> ");
+    assert_eq!(delta.apply("quote").unwrap(), "> quote");
+    assert_eq!(TextDelta::new().apply("unchanged").unwrap(), "unchanged");
+
+    // The emoji takes two UTF-16 code units.
+    let delta = TextDelta::new().retain(2).insert(" ok");
+    assert_eq!(delta.apply("👍").unwrap(), "👍 ok");
+    assert!(TextDelta::new().retain(1).insert("x").apply("👍").is_err());
+
+    assert!(TextDelta::new().retain(10).apply("short").is_err());
+    assert!(TextDelta::new().delete(6).apply("short").is_err());
+  }
+
+  #[test]
+  fn text_delta_diff_test() {
+    assert_eq!(
+      TextDelta::diff("Hello world!", "Hello AppFlowy!"),
+      TextDelta::new().retain(6).delete(5).insert("AppFlowy")
+    );
+    assert_eq!(TextDelta::diff("same", "same"), TextDelta::new().retain(4));
+    assert_eq!(
+      TextDelta::diff("aaa", "aaaa"),
+      TextDelta::new().retain(3).insert("a")
+    );
+
+    // The two emojis share the first code unit of their surrogate pairs
+    let delta = TextDelta::diff("😀", "😁");
+    assert_eq!(delta, TextDelta::new().delete(2).insert("😁"));
+    assert_eq!(delta.apply("😀").unwrap(), "😁");
+  }
+
+  #[test]
+  fn text_delta_transform_test() {
+    let base = "The quick fox";
+    let theirs = TextDelta::new().retain(13).insert(" jumps");
+    let ours = TextDelta::new().retain(4).delete(5).insert("slow");
+    let current = theirs.apply(base).unwrap();
+    let transformed = ours.transform(&theirs, 13).unwrap();
+    assert_eq!(transformed.apply(&current).unwrap(), "The slow fox jumps");
+
+    // The text deleted by both deltas is only deleted once
+    let theirs = TextDelta::new().delete(4);
+    let ours = TextDelta::new().retain(2).delete(4).insert("!");
+    let current = theirs.apply(base).unwrap();
+    let transformed = ours.transform(&theirs, 13).unwrap();
+    assert_eq!(transformed.apply(&current).unwrap(), "!ick fox");
+
+    // Both inserts at the same position are kept
+    let theirs = TextDelta::new().insert("A");
+    let ours = TextDelta::new().insert("B");
+    let transformed = ours.transform(&theirs, 0).unwrap();
+    assert_eq!(transformed.apply("A").unwrap(), "BA");
+
+    assert!(TextDelta::new().retain(20).transform(&theirs, 13).is_err());
+  }
+
+  #[test]
+  fn text_delta_apply_to_current_test() {
+    let delta = TextDelta::new()
+      .retain(5)
+      .insert(", world")
+      .with_base_content("Hello!");
+    assert_eq!(delta.apply_to_current("Hello!").unwrap(), "Hello, world!");
+    assert_eq!(
+      delta.apply_to_current(">> Hello!").unwrap(),
+      ">> Hello, world!"
+    );
+  }
+}
@@ -26,8 +26,8 @@ impl TextFilterPB {
       TextFilterConditionPB::TextDoesNotContain => !cell_data.contains(content),
       TextFilterConditionPB::TextStartsWith => cell_data.starts_with(content),
       TextFilterConditionPB::TextEndsWith => cell_data.ends_with(content),
-      TextFilterConditionPB::TextIsEmpty => cell_data.is_empty(),
-      TextFilterConditionPB::TextIsNotEmpty => !cell_data.is_empty(),
+      TextFilterConditionPB::TextIsEmpty => cell_data.trim().is_empty(),
+      TextFilterConditionPB::TextIsNotEmpty => !cell_data.trim().is_empty(),
     }
   }
 }
@@ -141,6 +141,7 @@ mod tests {
 
     assert_eq!(text_filter.is_visible(""), true);
     assert_eq!(text_filter.is_visible("App"), false);
+    assert_eq!(text_filter.is_visible("  "), true);
   }
   #[test]
   fn text_filter_contain_test() {
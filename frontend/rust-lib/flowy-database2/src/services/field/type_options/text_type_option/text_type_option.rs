@@ -14,7 +14,7 @@ use crate::services::field::{
   TypeOption, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
   TypeOptionCellDataSerde, TypeOptionTransform, CELL_DATA,
 };
-use crate::services::sort::SortCondition;
+use crate::services::sort::{SortCollation, SortCondition};
 
 impl TypeOption for RichTextTypeOption {
   type CellData = StringCellData;
@@ -111,13 +111,28 @@ impl TypeOptionCellDataCompare for RichTextTypeOption {
     cell_data: &<Self as TypeOption>::CellData,
     other_cell_data: &<Self as TypeOption>::CellData,
     sort_condition: SortCondition,
+  ) -> Ordering {
+    self.apply_cmp_with_collation(
+      cell_data,
+      other_cell_data,
+      sort_condition,
+      SortCollation::Binary,
+    )
+  }
+
+  fn apply_cmp_with_collation(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+    sort_condition: SortCondition,
+    collation: SortCollation,
   ) -> Ordering {
     match (cell_data.is_cell_empty(), other_cell_data.is_cell_empty()) {
       (true, true) => Ordering::Equal,
       (true, false) => Ordering::Greater,
       (false, true) => Ordering::Less,
       (false, false) => {
-        let order = cell_data.0.cmp(&other_cell_data.0);
+        let order = collation.cmp_str(&cell_data.0, &other_cell_data.0);
         sort_condition.evaluate_order(order)
       },
     }
@@ -0,0 +1,123 @@
+use collab::util::AnyMapExt;
+use collab_database::fields::{Field, TypeOptionData};
+use collab_database::rows::Cell;
+use fancy_regex::Regex;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+
+use crate::entities::FieldType;
+use crate::services::field::StringCellData;
+
+const VALIDATION_PATTERN: &str = "validation_pattern";
+const VALIDATION_ERROR_MESSAGE: &str = "validation_error_message";
+
+/// The validation of the cells of a text field.
+/// [RichTextTypeOption](collab_database::fields::text_type_option::RichTextTypeOption) doesn't
+/// have any options of its own, so the validation is stored alongside it in the text type option
+/// data.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextValidation {
+  /// The regular expression the whole text of a cell must match. Empty means no validation.
+  pub pattern: String,
+  /// The message of the error returned when a text doesn't match the pattern.
+  pub error_message: String,
+}
+
+impl TextValidation {
+  pub fn new(pattern: &str, error_message: &str) -> Self {
+    Self {
+      pattern: pattern.to_string(),
+      error_message: error_message.trim().to_string(),
+    }
+  }
+
+  pub fn from_field(field: &Field) -> Self {
+    if FieldType::from(field.field_type) != FieldType::RichText {
+      return Self::default();
+    }
+    field
+      .get_any_type_option(FieldType::RichText)
+      .map(|type_option| Self::from(&type_option))
+      .unwrap_or_default()
+  }
+
+  /// Returns an error if the pattern is not a valid regular expression.
+  pub fn check_pattern(&self) -> FlowyResult<()> {
+    self.regex().map(|_| ())
+  }
+
+  /// Returns an error with the message of the validation if the text doesn't match the pattern.
+  /// An empty text is always valid, so a cell can be cleared.
+  pub fn validate(&self, s: &str) -> FlowyResult<()> {
+    if self.pattern.is_empty() || s.is_empty() {
+      return Ok(());
+    }
+    let is_match = self.regex()?.is_match(s).unwrap_or(false);
+    if is_match {
+      Ok(())
+    } else if self.error_message.is_empty() {
+      Err(FlowyError::new(
+        ErrorCode::TextDoesNotMatchPattern,
+        format!("The text doesn't match the pattern: {}", self.pattern),
+      ))
+    } else {
+      Err(FlowyError::new(
+        ErrorCode::TextDoesNotMatchPattern,
+        &self.error_message,
+      ))
+    }
+  }
+
+  pub fn validate_cell(&self, cell: &Cell) -> FlowyResult<()> {
+    self.validate(&StringCellData::from(cell))
+  }
+
+  /// Write the validation into the text type option data.
+  pub fn write_to(&self, type_option_data: &mut TypeOptionData) {
+    type_option_data.insert(VALIDATION_PATTERN.into(), self.pattern.clone().into());
+    type_option_data.insert(
+      VALIDATION_ERROR_MESSAGE.into(),
+      self.error_message.clone().into(),
+    );
+  }
+
+  fn regex(&self) -> FlowyResult<Regex> {
+    // The pattern must match the whole text instead of a part of it.
+    Regex::new(&format!("^(?:{})$", self.pattern)).map_err(|err| {
+      FlowyError::new(
+        ErrorCode::InvalidValidationPattern,
+        format!("Invalid pattern {}: {}", self.pattern, err),
+      )
+    })
+  }
+}
+
+impl From<&TypeOptionData> for TextValidation {
+  fn from(data: &TypeOptionData) -> Self {
+    Self {
+      pattern: data.get_as(VALIDATION_PATTERN).unwrap_or_default(),
+      error_message: data.get_as(VALIDATION_ERROR_MESSAGE).unwrap_or_default(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn text_validation_test() {
+    let validation = TextValidation::new("[A-Z]{3}-\\d{4}", "Expected a SKU like ABC-1234");
+    assert!(validation.check_pattern().is_ok());
+    assert!(validation.validate("ABC-1234").is_ok());
+    assert!(validation.validate("").is_ok());
+
+    let error = validation.validate("xABC-1234").unwrap_err();
+    assert_eq!(error.code, ErrorCode::TextDoesNotMatchPattern);
+    assert_eq!(error.msg, "Expected a SKU like ABC-1234");
+    assert!(validation.validate("ABC-12345").is_err());
+
+    assert!(TextValidation::default().validate("anything").is_ok());
+    let error = TextValidation::new("[a-", "").check_pattern().unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidValidationPattern);
+  }
+}
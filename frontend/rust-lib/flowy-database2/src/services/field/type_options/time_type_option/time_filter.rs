@@ -7,16 +7,14 @@ use crate::services::filter::PreFillCellsWithFilter;
 
 impl TimeFilterPB {
   pub fn is_visible(&self, cell_time: Option<i64>) -> bool {
-    if self.content.is_empty() {
-      match self.condition {
-        NumberFilterConditionPB::NumberIsEmpty => {
-          return cell_time.is_none();
-        },
-        NumberFilterConditionPB::NumberIsNotEmpty => {
-          return cell_time.is_some();
-        },
-        _ => {},
-      }
+    match self.condition {
+      NumberFilterConditionPB::NumberIsEmpty => {
+        return cell_time.is_none();
+      },
+      NumberFilterConditionPB::NumberIsNotEmpty => {
+        return cell_time.is_some();
+      },
+      _ => {},
     }
 
     if cell_time.is_none() {
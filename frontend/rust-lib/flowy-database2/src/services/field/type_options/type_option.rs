@@ -8,9 +8,11 @@ use crate::services::cell::CellDataDecoder;
 use crate::services::field::checklist_type_option::ChecklistTypeOption;
 use crate::services::field::summary_type_option::summary::SummarizationTypeOption;
 use crate::services::field::translate_type_option::translate::TranslateTypeOption;
-use crate::services::field::RelationTypeOption;
+use crate::services::field::{
+  CheckboxOptions, CheckboxTypeOptionsBuilder, NumberUnit, RelationTypeOption, TextValidation,
+};
 use crate::services::filter::{ParseFilterData, PreFillCellsWithFilter};
-use crate::services::sort::SortCondition;
+use crate::services::sort::{SortCollation, SortCondition};
 use async_trait::async_trait;
 use bytes::Bytes;
 use collab_database::database::Database;
@@ -144,6 +146,18 @@ pub trait TypeOptionCellDataCompare: TypeOption {
     sort_condition: SortCondition,
   ) -> Ordering;
 
+  /// Same as `apply_cmp`, but compares the text of the cells according to the collation. Only
+  /// the text based type options override it, the others ignore the collation.
+  fn apply_cmp_with_collation(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+    sort_condition: SortCondition,
+    _collation: SortCollation,
+  ) -> Ordering {
+    self.apply_cmp(cell_data, other_cell_data, sort_condition)
+  }
+
   /// Compares the two cells where one of the cells is None
   fn apply_cmp_with_uninitialized(
     &self,
@@ -165,12 +179,18 @@ pub fn type_option_data_from_pb<T: Into<Bytes>>(
 ) -> Result<TypeOptionData, ProtobufError> {
   let bytes = bytes.into();
   match field_type {
-    FieldType::RichText => {
-      RichTextTypeOptionPB::try_from(bytes).map(|pb| RichTextTypeOption::from(pb).into())
-    },
-    FieldType::Number => {
-      NumberTypeOptionPB::try_from(bytes).map(|pb| NumberTypeOption::from(pb).into())
-    },
+    FieldType::RichText => RichTextTypeOptionPB::try_from(bytes).map(|pb| {
+      let text_validation = pb.text_validation();
+      let mut type_option_data: TypeOptionData = RichTextTypeOption::from(pb).into();
+      text_validation.write_to(&mut type_option_data);
+      type_option_data
+    }),
+    FieldType::Number => NumberTypeOptionPB::try_from(bytes).map(|pb| {
+      let number_unit = pb.number_unit();
+      let mut type_option_data: TypeOptionData = NumberTypeOption::from(pb).into();
+      number_unit.write_to(&mut type_option_data);
+      type_option_data
+    }),
     FieldType::DateTime => {
       DateTypeOptionPB::try_from(bytes).map(|pb| DateTypeOption::from(pb).into())
     },
@@ -183,9 +203,12 @@ pub fn type_option_data_from_pb<T: Into<Bytes>>(
     FieldType::MultiSelect => {
       MultiSelectTypeOptionPB::try_from(bytes).map(|pb| MultiSelectTypeOption::from(pb).into())
     },
-    FieldType::Checkbox => {
-      CheckboxTypeOptionPB::try_from(bytes).map(|pb| CheckboxTypeOption::from(pb).into())
-    },
+    FieldType::Checkbox => CheckboxTypeOptionPB::try_from(bytes).map(|pb| {
+      CheckboxTypeOptionsBuilder::new()
+        .checked_by_default(pb.is_checked_by_default)
+        .labels(&pb.checked_label, &pb.unchecked_label)
+        .build()
+    }),
     FieldType::URL => URLTypeOptionPB::try_from(bytes).map(|pb| URLTypeOption::from(pb).into()),
     FieldType::Checklist => {
       ChecklistTypeOptionPB::try_from(bytes).map(|pb| ChecklistTypeOption::from(pb).into())
@@ -209,14 +232,18 @@ pub fn type_option_data_from_pb<T: Into<Bytes>>(
 pub fn type_option_to_pb(type_option: TypeOptionData, field_type: &FieldType) -> Bytes {
   match field_type {
     FieldType::RichText => {
+      let text_validation = TextValidation::from(&type_option);
       let rich_text_type_option: RichTextTypeOption = type_option.into();
       RichTextTypeOptionPB::from(rich_text_type_option)
+        .with_text_validation(text_validation)
         .try_into()
         .unwrap()
     },
     FieldType::Number => {
+      let number_unit = NumberUnit::from(&type_option);
       let number_type_option: NumberTypeOption = type_option.into();
       NumberTypeOptionPB::from(number_type_option)
+        .with_number_unit(number_unit)
         .try_into()
         .unwrap()
     },
@@ -243,8 +270,10 @@ pub fn type_option_to_pb(type_option: TypeOptionData, field_type: &FieldType) ->
         .unwrap()
     },
     FieldType::Checkbox => {
+      let checkbox_options = CheckboxOptions::from(&type_option);
       let checkbox_type_option: CheckboxTypeOption = type_option.into();
       CheckboxTypeOptionPB::from(checkbox_type_option)
+        .with_checkbox_options(checkbox_options)
         .try_into()
         .unwrap()
     },
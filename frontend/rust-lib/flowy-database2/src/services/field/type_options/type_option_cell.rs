@@ -7,7 +7,7 @@ use crate::services::field::{
   TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionCellDataSerde,
   TypeOptionTransform,
 };
-use crate::services::sort::SortCondition;
+use crate::services::sort::{SortCollation, SortCondition};
 use collab::preclude::Any;
 use collab_database::fields::checkbox_type_option::CheckboxTypeOption;
 use collab_database::fields::date_type_option::{DateTypeOption, TimeTypeOption};
@@ -65,6 +65,7 @@ pub trait TypeOptionCellDataHandler: Send + Sync + 'static {
   /// - `right_cell`: An optional reference to the right cell's data.
   /// - `field`: A reference to the field information, which includes details about the field type.
   /// - `sort_condition`: The condition that dictates the sort order based on the results of the comparison.
+  /// - `collation`: How the text of the cells is compared, see `apply_cmp_with_collation`.
   ///
   /// # Returns
   /// An `Ordering` indicating:
@@ -75,13 +76,15 @@ pub trait TypeOptionCellDataHandler: Send + Sync + 'static {
   /// # Note
   /// - If only one of the cells is `None`, the other cell is decoded, and the comparison is made using
   ///   the `apply_cmp_with_uninitialized` method.
-  /// - If both cells are present, they are decoded, and the comparison is made using the `apply_cmp` method.
+  /// - If both cells are present, they are decoded, and the comparison is made using the
+  ///   `apply_cmp_with_collation` method.
   fn handle_cell_compare(
     &self,
     left_cell: Option<&Cell>,
     right_cell: Option<&Cell>,
     field: &Field,
     sort_condition: SortCondition,
+    collation: SortCollation,
   ) -> Ordering;
 
   fn handle_cell_filter(&self, field: &Field, cell: &Cell, filter: &BoxAny) -> bool;
@@ -291,6 +294,7 @@ where
     right_cell: Option<&Cell>,
     field: &Field,
     sort_condition: SortCondition,
+    collation: SortCollation,
   ) -> Ordering {
     match (left_cell, right_cell) {
       (None, None) => Ordering::Equal,
@@ -308,7 +312,7 @@ where
         let left_cell_data = self.get_cell_data(left_cell, field).unwrap_or_default();
         let right_cell_data = self.get_cell_data(right_cell, field).unwrap_or_default();
 
-        self.apply_cmp(&left_cell_data, &right_cell_data, sort_condition)
+        self.apply_cmp_with_collation(&left_cell_data, &right_cell_data, sort_condition, collation)
       },
     }
   }
@@ -4,7 +4,7 @@ use crate::services::field::{
   TypeOption, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionCellDataSerde,
   TypeOptionTransform,
 };
-use crate::services::sort::SortCondition;
+use crate::services::sort::{SortCollation, SortCondition};
 use async_trait::async_trait;
 use collab_database::database::Database;
 use collab_database::fields::url_type_option::{URLCellData, URLTypeOption};
@@ -130,6 +130,21 @@ impl TypeOptionCellDataCompare for URLTypeOption {
     cell_data: &<Self as TypeOption>::CellData,
     other_cell_data: &<Self as TypeOption>::CellData,
     sort_condition: SortCondition,
+  ) -> Ordering {
+    self.apply_cmp_with_collation(
+      cell_data,
+      other_cell_data,
+      sort_condition,
+      SortCollation::Binary,
+    )
+  }
+
+  fn apply_cmp_with_collation(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+    sort_condition: SortCondition,
+    collation: SortCollation,
   ) -> Ordering {
     let is_left_empty = cell_data.data.is_empty();
     let is_right_empty = other_cell_data.data.is_empty();
@@ -138,7 +153,7 @@ impl TypeOptionCellDataCompare for URLTypeOption {
       (true, false) => Ordering::Greater,
       (false, true) => Ordering::Less,
       (false, false) => {
-        let order = cell_data.data.cmp(&other_cell_data.data);
+        let order = collation.cmp_str(&cell_data.data, &other_cell_data.data);
         sort_condition.evaluate_order(order)
       },
     }
@@ -1,7 +1,10 @@
 use bytes::Bytes;
 
+use collab::util::AnyMapExt;
 use collab_database::fields::url_type_option::URLCellData;
+use collab_database::rows::Cell;
 
+use flowy_database_pub::cloud::LinkPreview;
 use flowy_error::{internal_error, FlowyResult};
 
 use crate::entities::URLCellDataPB;
@@ -16,7 +19,10 @@ impl TypeOptionCellData for URLCellData {
 
 impl From<URLCellData> for URLCellDataPB {
   fn from(data: URLCellData) -> Self {
-    Self { content: data.data }
+    Self {
+      content: data.data,
+      ..Default::default()
+    }
   }
 }
 
@@ -34,3 +40,20 @@ impl CellProtobufBlobParser for URLCellDataParser {
     URLCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
   }
 }
+
+const LINK_PREVIEW_TITLE: &str = "link_title";
+const LINK_PREVIEW_FAVICON: &str = "link_favicon";
+
+/// The [LinkPreview] of a URL cell is stored in the cell next to the url. Saving a new url
+/// replaces the whole cell, so a stale preview never outlives its url.
+pub fn link_preview_from_cell(cell: &Cell) -> LinkPreview {
+  LinkPreview {
+    title: cell.get_as(LINK_PREVIEW_TITLE).unwrap_or_default(),
+    favicon: cell.get_as(LINK_PREVIEW_FAVICON).unwrap_or_default(),
+  }
+}
+
+pub fn insert_link_preview(cell: &mut Cell, preview: LinkPreview) {
+  cell.insert(LINK_PREVIEW_TITLE.into(), preview.title.into());
+  cell.insert(LINK_PREVIEW_FAVICON.into(), preview.favicon.into());
+}
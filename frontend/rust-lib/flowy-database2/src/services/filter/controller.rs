@@ -21,7 +21,9 @@ use crate::entities::{FieldType, InsertedRowPB, RowMetaPB};
 use crate::services::cell::CellCache;
 use crate::services::database_view::{DatabaseViewChanged, DatabaseViewChangedNotifier};
 use crate::services::field::{TimestampCellData, TimestampCellDataWrapper, TypeOptionCellExt};
-use crate::services::filter::{Filter, FilterChangeset, FilterInner, FilterResultNotification};
+use crate::services::filter::{
+  Filter, FilterChangeset, FilterExplanation, FilterInner, FilterResultNotification,
+};
 
 #[async_trait]
 pub trait FilterDelegate: Send + Sync + 'static {
@@ -316,28 +318,45 @@ impl FilterController {
     Ok(())
   }
 
+  /// Filters the row and notifies the change of its visibility. Nothing is notified if the row
+  /// was already visible or already hidden, so the notification only contains the rows that
+  /// entered or left the view.
   async fn filter_single_row_handler(&self, row_id: RowId) -> FlowyResult<()> {
     let filters = self.filters.read().await;
 
     if let Some((_, row_detail)) = self.delegate.get_row(&self.view_id, &row_id).await {
       let field_by_field_id = self.get_field_map().await;
-      let mut notification = FilterResultNotification::new(self.view_id.clone());
-      if filter_row(
+      let was_visible = self.is_visible(&row_id);
+      let is_visible = filter_row(
         &row_detail.row,
         &self.result_by_row_id,
         &field_by_field_id,
         &self.cell_cache,
         &filters,
-      ) {
-        if let Some((index, _row)) = self.delegate.get_row(&self.view_id, &row_id).await {
-          notification.visible_rows.push(
-            InsertedRowPB::new(RowMetaPB::from(row_detail.as_ref().clone()))
-              .with_index(index as i32),
-          )
+      );
+      if was_visible == is_visible {
+        return Ok(());
+      }
+
+      let rows = self.delegate.get_rows(&self.view_id).await;
+      let visible_rows = rows
+        .iter()
+        .filter(|row| self.is_visible(&row.id))
+        .collect::<Vec<_>>();
+
+      let mut notification = FilterResultNotification::new(self.view_id.clone());
+      if is_visible {
+        // The index of the row among the visible rows of the view
+        if let Some(index) = visible_rows.iter().position(|row| row.id == row_id) {
+          notification
+            .visible_rows
+            .push(InsertedRowPB::new(RowMetaPB::from(row_detail.as_ref())).with_index(index as i32))
         }
       } else {
         notification.invisible_rows.push(row_id);
       }
+      notification.total_count = rows.len();
+      notification.visible_count = visible_rows.len();
 
       let _ = self
         .notifier
@@ -346,6 +365,15 @@ impl FilterController {
     Ok(())
   }
 
+  /// Returns the cached visibility of the row. A row that hasn't been filtered yet is visible.
+  fn is_visible(&self, row_id: &RowId) -> bool {
+    self
+      .result_by_row_id
+      .get(row_id)
+      .map(|result| *result)
+      .unwrap_or(true)
+  }
+
   pub async fn filter_rows_and_notify(&self, rows: &mut Vec<Arc<Row>>) -> FlowyResult<()> {
     let filters = self.filters.read().await;
     let field_by_field_id = self.get_field_map().await;
@@ -374,6 +402,8 @@ impl FilterController {
       view_id: self.view_id.clone(),
       invisible_rows,
       visible_rows,
+      visible_count: rows.len(),
+      total_count: len,
     };
     let _ = self
       .notifier
@@ -407,6 +437,46 @@ impl FilterController {
     rows
   }
 
+  /// Returns, for each row, the index of the first of the conditions that the row matches. The
+  /// conditions are evaluated without updating the cached visibility of the rows.
+  pub async fn find_matching_conditions(
+    &self,
+    rows: &[Arc<Row>],
+    conditions: &[&Filter],
+  ) -> Vec<Option<usize>> {
+    let field_by_field_id = self.get_field_map().await;
+    rows
+      .par_iter()
+      .map(|row| {
+        conditions.iter().position(|condition| {
+          apply_filter(row, &field_by_field_id, &self.cell_cache, condition).unwrap_or(false)
+        })
+      })
+      .collect()
+  }
+
+  /// Evaluates the filters against the row without updating the cached visibility of the row.
+  /// Returns whether the row is visible and the result of every data filter in the filter tree.
+  pub async fn explain_row(&self, row: &Row) -> (bool, Vec<FilterExplanation>) {
+    let filters = self.filters.read().await;
+    let field_by_field_id = self.get_field_map().await;
+    let mut is_visible = true;
+    let mut explanations = vec![];
+    for filter in filters.iter() {
+      if let Some(false) = apply_filter(row, &field_by_field_id, &self.cell_cache, filter) {
+        is_visible = false;
+      }
+      explain_filter(
+        row,
+        &field_by_field_id,
+        &self.cell_cache,
+        filter,
+        &mut explanations,
+      );
+    }
+    (is_visible, explanations)
+  }
+
   async fn get_field_map(&self) -> HashMap<String, Field> {
     self
       .delegate
@@ -470,6 +540,17 @@ fn filter_row(
   new_is_visible
 }
 
+/// Returns true if the row matches the filter. A filter that doesn't produce a result, e.g. a
+/// filter of a field that doesn't exist anymore, matches no row.
+pub(crate) fn is_row_matching_filter(
+  row: &Row,
+  field_by_field_id: &HashMap<String, Field>,
+  cell_data_cache: &CellCache,
+  filter: &Filter,
+) -> bool {
+  apply_filter(row, field_by_field_id, cell_data_cache, filter).unwrap_or(false)
+}
+
 /// Recursively applies a `Filter` to a `Row`'s cells.
 fn apply_filter(
   row: &Row,
@@ -478,27 +559,29 @@ fn apply_filter(
   filter: &Filter,
 ) -> Option<bool> {
   match &filter.inner {
+    // Children that don't produce a result, such as empty nested groups, are ignored. A group
+    // without any child that produces a result doesn't affect the visibility of the row.
     FilterInner::And { children } => {
-      if children.is_empty() {
-        return None;
-      }
+      let mut result = None;
       for child_filter in children.iter() {
-        if let Some(false) = apply_filter(row, field_by_field_id, cell_data_cache, child_filter) {
-          return Some(false);
+        match apply_filter(row, field_by_field_id, cell_data_cache, child_filter) {
+          Some(false) => return Some(false),
+          Some(true) => result = Some(true),
+          None => {},
         }
       }
-      Some(true)
+      result
     },
     FilterInner::Or { children } => {
-      if children.is_empty() {
-        return None;
-      }
+      let mut result = None;
       for child_filter in children.iter() {
-        if let Some(true) = apply_filter(row, field_by_field_id, cell_data_cache, child_filter) {
-          return Some(true);
+        match apply_filter(row, field_by_field_id, cell_data_cache, child_filter) {
+          Some(true) => return Some(true),
+          Some(false) => result = Some(false),
+          None => {},
         }
       }
-      Some(false)
+      result
     },
     FilterInner::Data {
       field_id,
@@ -541,6 +624,42 @@ fn apply_filter(
   }
 }
 
+/// Recursively collects the result of every data filter of the filter tree.
+fn explain_filter(
+  row: &Row,
+  field_by_field_id: &HashMap<String, Field>,
+  cell_data_cache: &CellCache,
+  filter: &Filter,
+  explanations: &mut Vec<FilterExplanation>,
+) {
+  match &filter.inner {
+    FilterInner::And { children } | FilterInner::Or { children } => {
+      for child_filter in children.iter() {
+        explain_filter(
+          row,
+          field_by_field_id,
+          cell_data_cache,
+          child_filter,
+          explanations,
+        );
+      }
+    },
+    FilterInner::Data {
+      field_id,
+      field_type,
+      ..
+    } => {
+      let is_match = apply_filter(row, field_by_field_id, cell_data_cache, filter).unwrap_or(true);
+      explanations.push(FilterExplanation {
+        filter_id: filter.id.clone(),
+        field_id: field_id.clone(),
+        field_type: *field_type,
+        is_match,
+      });
+    },
+  }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 enum FilterEvent {
   FilterDidChanged,
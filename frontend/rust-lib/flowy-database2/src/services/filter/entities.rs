@@ -312,6 +312,74 @@ impl FilterInner {
       FilterInner::Data { .. } => FILTER_DATA_INDEX,
     }
   }
+
+  /// Returns the condition and the content a Data filter was created from with
+  /// [FilterInner::new_data]. Returns None for an AND/OR filter.
+  pub fn raw_condition_and_content(&self) -> Option<(u8, String)> {
+    let (field_type, condition_and_content) = match self {
+      FilterInner::Data {
+        field_type,
+        condition_and_content,
+        ..
+      } => (field_type, condition_and_content),
+      FilterInner::And { .. } | FilterInner::Or { .. } => return None,
+    };
+    let (condition, content) = match field_type {
+      FieldType::RichText | FieldType::URL => {
+        let filter = condition_and_content.cloned::<TextFilterPB>()?;
+        (filter.condition as u8, filter.content)
+      },
+      FieldType::Number => {
+        let filter = condition_and_content.cloned::<NumberFilterPB>()?;
+        (filter.condition as u8, filter.content)
+      },
+      FieldType::DateTime | FieldType::LastEditedTime | FieldType::CreatedTime => {
+        let filter = condition_and_content.cloned::<DateFilterPB>()?;
+        let content = DateFilterContent {
+          start: filter.start,
+          end: filter.end,
+          timestamp: filter.timestamp,
+          days: filter.days,
+        }
+        .to_string();
+        (filter.condition as u8, content)
+      },
+      FieldType::SingleSelect | FieldType::MultiSelect => {
+        let filter = condition_and_content.cloned::<SelectOptionFilterPB>()?;
+        let content = SelectOptionIds::from(filter.option_ids).to_string();
+        (filter.condition as u8, content)
+      },
+      FieldType::Checkbox => {
+        let filter = condition_and_content.cloned::<CheckboxFilterPB>()?;
+        (filter.condition as u8, "".to_string())
+      },
+      FieldType::Checklist => {
+        let filter = condition_and_content.cloned::<ChecklistFilterPB>()?;
+        (filter.condition as u8, "".to_string())
+      },
+      FieldType::Relation => {
+        let filter = condition_and_content.cloned::<RelationFilterPB>()?;
+        (filter.condition as u8, filter.row_ids.join(","))
+      },
+      FieldType::Summary => {
+        let filter = condition_and_content.cloned::<TextFilterPB>()?;
+        (filter.condition as u8, filter.content)
+      },
+      FieldType::Time => {
+        let filter = condition_and_content.cloned::<TimeFilterPB>()?;
+        (filter.condition as u8, filter.content)
+      },
+      FieldType::Translate => {
+        let filter = condition_and_content.cloned::<TextFilterPB>()?;
+        (filter.condition as u8, filter.content)
+      },
+      FieldType::Media => {
+        let filter = condition_and_content.cloned::<MediaFilterPB>()?;
+        (filter.condition as u8, filter.content)
+      },
+    };
+    Some((condition, content))
+  }
 }
 
 const FILTER_ID: &str = "id";
@@ -346,66 +414,9 @@ impl<'a> From<&'a Filter> for FilterMap {
       FilterInner::Data {
         field_id,
         field_type,
-        condition_and_content,
+        ..
       } => {
-        let get_raw_condition_and_content = || -> Option<(u8, String)> {
-          let (condition, content) = match field_type {
-            FieldType::RichText | FieldType::URL => {
-              let filter = condition_and_content.cloned::<TextFilterPB>()?;
-              (filter.condition as u8, filter.content)
-            },
-            FieldType::Number => {
-              let filter = condition_and_content.cloned::<NumberFilterPB>()?;
-              (filter.condition as u8, filter.content)
-            },
-            FieldType::DateTime | FieldType::LastEditedTime | FieldType::CreatedTime => {
-              let filter = condition_and_content.cloned::<DateFilterPB>()?;
-              let content = DateFilterContent {
-                start: filter.start,
-                end: filter.end,
-                timestamp: filter.timestamp,
-              }
-              .to_string();
-              (filter.condition as u8, content)
-            },
-            FieldType::SingleSelect | FieldType::MultiSelect => {
-              let filter = condition_and_content.cloned::<SelectOptionFilterPB>()?;
-              let content = SelectOptionIds::from(filter.option_ids).to_string();
-              (filter.condition as u8, content)
-            },
-            FieldType::Checkbox => {
-              let filter = condition_and_content.cloned::<CheckboxFilterPB>()?;
-              (filter.condition as u8, "".to_string())
-            },
-            FieldType::Checklist => {
-              let filter = condition_and_content.cloned::<ChecklistFilterPB>()?;
-              (filter.condition as u8, "".to_string())
-            },
-            FieldType::Relation => {
-              let filter = condition_and_content.cloned::<RelationFilterPB>()?;
-              (filter.condition as u8, "".to_string())
-            },
-            FieldType::Summary => {
-              let filter = condition_and_content.cloned::<TextFilterPB>()?;
-              (filter.condition as u8, filter.content)
-            },
-            FieldType::Time => {
-              let filter = condition_and_content.cloned::<TimeFilterPB>()?;
-              (filter.condition as u8, filter.content)
-            },
-            FieldType::Translate => {
-              let filter = condition_and_content.cloned::<TextFilterPB>()?;
-              (filter.condition as u8, filter.content)
-            },
-            FieldType::Media => {
-              let filter = condition_and_content.cloned::<MediaFilterPB>()?;
-              (filter.condition as u8, filter.content)
-            },
-          };
-          Some((condition, content))
-        };
-
-        let (condition, content) = get_raw_condition_and_content().unwrap_or_else(|| {
+        let (condition, content) = filter.inner.raw_condition_and_content().unwrap_or_else(|| {
           tracing::error!("cannot deserialize filter condition and content filter properly");
           Default::default()
         });
@@ -513,6 +524,12 @@ pub struct FilterResultNotification {
 
   // Indicates there will be some new rows being invisible from visible state.
   pub invisible_rows: Vec<RowId>,
+
+  // The number of rows that are visible after applying the filters.
+  pub visible_count: usize,
+
+  // The number of rows in the view, regardless of the filters.
+  pub total_count: usize,
 }
 
 impl FilterResultNotification {
@@ -521,6 +538,17 @@ impl FilterResultNotification {
       view_id,
       visible_rows: vec![],
       invisible_rows: vec![],
+      visible_count: 0,
+      total_count: 0,
     }
   }
 }
+
+/// The outcome of a single data filter for a row. Used to explain why a row is visible or not.
+#[derive(Clone, Debug)]
+pub struct FilterExplanation {
+  pub filter_id: String,
+  pub field_id: String,
+  pub field_type: FieldType,
+  pub is_match: bool,
+}
@@ -0,0 +1,574 @@
+use chrono::NaiveDate;
+use collab_database::database::gen_database_filter_id;
+use collab_database::fields::select_type_option::SelectOptionIds;
+use collab_database::fields::Field;
+
+use crate::entities::{
+  CheckboxFilterConditionPB, DateFilterConditionPB, DateFilterContent, FieldType,
+  NumberFilterConditionPB, SelectOptionFilterConditionPB, TextFilterConditionPB,
+};
+use crate::services::field::select_type_option_from_field;
+use crate::services::filter::{Filter, FilterInner};
+
+/// The error of a filter expression that can't be parsed. `start` and `end` are the offsets, in
+/// chars, of the part of the expression that caused the error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterExpressionError {
+  pub message: String,
+  pub start: usize,
+  pub end: usize,
+}
+
+impl FilterExpressionError {
+  fn new(message: impl Into<String>, start: usize, end: usize) -> Self {
+    Self {
+      message: message.into(),
+      start,
+      end,
+    }
+  }
+}
+
+/// Parses a filter expression like `Status = "Live" AND Price > 100` into a filter tree. The
+/// fields are referenced by name, either as a bare word or as a quoted string if the name
+/// contains spaces.
+///
+/// The supported operators are `=`, `!=`, `>`, `>=`, `<`, `<=`, `CONTAINS`, `NOT CONTAINS`,
+/// `IS EMPTY` and `IS NOT EMPTY`. The conditions are combined with `AND` and `OR`, where `AND`
+/// binds tighter than `OR`, and can be grouped with parentheses. The keywords are case
+/// insensitive.
+pub fn parse_filter_expression(
+  expression: &str,
+  fields: &[Field],
+) -> Result<Filter, FilterExpressionError> {
+  let tokens = tokenize(expression)?;
+  if tokens.is_empty() {
+    return Err(FilterExpressionError::new("The expression is empty", 0, 0));
+  }
+
+  let mut parser = ExpressionParser {
+    tokens,
+    pos: 0,
+    fields,
+    len: expression.chars().count(),
+  };
+  let filter = parser.parse_or()?;
+  match parser.peek() {
+    None => Ok(filter),
+    Some(token) => Err(token.unexpected()),
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+  Word(String),
+  Str(String),
+  Operator(CompareOperator),
+  LeftParen,
+  RightParen,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+  kind: TokenKind,
+  text: String,
+  start: usize,
+  end: usize,
+}
+
+impl Token {
+  fn is_keyword(&self, keyword: &str) -> bool {
+    matches!(&self.kind, TokenKind::Word(word) if word.eq_ignore_ascii_case(keyword))
+  }
+
+  fn unexpected(&self) -> FilterExpressionError {
+    FilterExpressionError::new(format!("Unexpected `{}`", self.text), self.start, self.end)
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompareOperator {
+  Equal,
+  NotEqual,
+  GreaterThan,
+  GreaterThanOrEqualTo,
+  LessThan,
+  LessThanOrEqualTo,
+  Contains,
+  DoesNotContain,
+  IsEmpty,
+  IsNotEmpty,
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, FilterExpressionError> {
+  let chars = expression.chars().collect::<Vec<char>>();
+  let mut tokens = vec![];
+  let mut pos = 0;
+  while pos < chars.len() {
+    let start = pos;
+    let c = chars[pos];
+    let kind = match c {
+      c if c.is_whitespace() => {
+        pos += 1;
+        continue;
+      },
+      '(' => {
+        pos += 1;
+        TokenKind::LeftParen
+      },
+      ')' => {
+        pos += 1;
+        TokenKind::RightParen
+      },
+      '"' => {
+        pos += 1;
+        let mut value = String::new();
+        loop {
+          match chars.get(pos) {
+            None => {
+              return Err(FilterExpressionError::new(
+                "The string is not terminated",
+                start,
+                chars.len(),
+              ))
+            },
+            Some('"') => {
+              pos += 1;
+              break;
+            },
+            Some('\\') if matches!(chars.get(pos + 1), Some('"') | Some('\\')) => {
+              value.push(chars[pos + 1]);
+              pos += 2;
+            },
+            Some(c) => {
+              value.push(*c);
+              pos += 1;
+            },
+          }
+        }
+        TokenKind::Str(value)
+      },
+      '=' | '!' | '<' | '>' => {
+        let next = chars.get(pos + 1).copied();
+        let (operator, len) = match (c, next) {
+          ('=', Some('=')) => (CompareOperator::Equal, 2),
+          ('=', _) => (CompareOperator::Equal, 1),
+          ('!', Some('=')) => (CompareOperator::NotEqual, 2),
+          ('<', Some('>')) => (CompareOperator::NotEqual, 2),
+          ('<', Some('=')) => (CompareOperator::LessThanOrEqualTo, 2),
+          ('<', _) => (CompareOperator::LessThan, 1),
+          ('>', Some('=')) => (CompareOperator::GreaterThanOrEqualTo, 2),
+          ('>', _) => (CompareOperator::GreaterThan, 1),
+          _ => {
+            return Err(FilterExpressionError::new(
+              format!("Unexpected `{}`", c),
+              start,
+              start + 1,
+            ))
+          },
+        };
+        pos += len;
+        TokenKind::Operator(operator)
+      },
+      c if is_word_char(c) => {
+        while pos < chars.len() && is_word_char(chars[pos]) {
+          pos += 1;
+        }
+        TokenKind::Word(chars[start..pos].iter().collect())
+      },
+      c => {
+        return Err(FilterExpressionError::new(
+          format!("Unexpected `{}`", c),
+          start,
+          start + 1,
+        ))
+      },
+    };
+    tokens.push(Token {
+      kind,
+      text: chars[start..pos].iter().collect(),
+      start,
+      end: pos,
+    });
+  }
+  Ok(tokens)
+}
+
+fn is_word_char(c: char) -> bool {
+  c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':')
+}
+
+struct ExpressionParser<'a> {
+  tokens: Vec<Token>,
+  pos: usize,
+  fields: &'a [Field],
+  len: usize,
+}
+
+impl<'a> ExpressionParser<'a> {
+  fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.pos)
+  }
+
+  fn next(&mut self) -> Result<Token, FilterExpressionError> {
+    let token = self.tokens.get(self.pos).cloned().ok_or_else(|| {
+      FilterExpressionError::new("Unexpected end of the expression", self.len, self.len)
+    })?;
+    self.pos += 1;
+    Ok(token)
+  }
+
+  fn eat_keyword(&mut self, keyword: &str) -> bool {
+    match self.peek() {
+      Some(token) if token.is_keyword(keyword) => {
+        self.pos += 1;
+        true
+      },
+      _ => false,
+    }
+  }
+
+  fn expect_keyword(&mut self, keyword: &str) -> Result<(), FilterExpressionError> {
+    let token = self.next()?;
+    if token.is_keyword(keyword) {
+      Ok(())
+    } else {
+      Err(FilterExpressionError::new(
+        format!("Expected `{}` but found `{}`", keyword, token.text),
+        token.start,
+        token.end,
+      ))
+    }
+  }
+
+  fn parse_or(&mut self) -> Result<Filter, FilterExpressionError> {
+    let mut children = vec![self.parse_and()?];
+    while self.eat_keyword("OR") {
+      children.push(self.parse_and()?);
+    }
+    Ok(combine(children, |children| FilterInner::Or { children }))
+  }
+
+  fn parse_and(&mut self) -> Result<Filter, FilterExpressionError> {
+    let mut children = vec![self.parse_primary()?];
+    while self.eat_keyword("AND") {
+      children.push(self.parse_primary()?);
+    }
+    Ok(combine(children, |children| FilterInner::And { children }))
+  }
+
+  fn parse_primary(&mut self) -> Result<Filter, FilterExpressionError> {
+    if matches!(self.peek(), Some(token) if token.kind == TokenKind::LeftParen) {
+      self.pos += 1;
+      let filter = self.parse_or()?;
+      let token = self.next()?;
+      if token.kind != TokenKind::RightParen {
+        return Err(FilterExpressionError::new(
+          format!("Expected `)` but found `{}`", token.text),
+          token.start,
+          token.end,
+        ));
+      }
+      return Ok(filter);
+    }
+    self.parse_condition()
+  }
+
+  fn parse_condition(&mut self) -> Result<Filter, FilterExpressionError> {
+    let field_token = self.next()?;
+    let field = match &field_token.kind {
+      TokenKind::Word(name) | TokenKind::Str(name) => self.find_field(name).ok_or_else(|| {
+        FilterExpressionError::new(
+          format!("Unknown field `{}`", name),
+          field_token.start,
+          field_token.end,
+        )
+      })?,
+      _ => return Err(field_token.unexpected()),
+    };
+
+    let operator_token = self.next()?;
+    let operator = match &operator_token.kind {
+      TokenKind::Operator(operator) => *operator,
+      _ if operator_token.is_keyword("CONTAINS") => CompareOperator::Contains,
+      _ if operator_token.is_keyword("NOT") => {
+        self.expect_keyword("CONTAINS")?;
+        CompareOperator::DoesNotContain
+      },
+      _ if operator_token.is_keyword("IS") => {
+        let operator = if self.eat_keyword("NOT") {
+          CompareOperator::IsNotEmpty
+        } else {
+          CompareOperator::IsEmpty
+        };
+        self.expect_keyword("EMPTY")?;
+        operator
+      },
+      _ => {
+        return Err(FilterExpressionError::new(
+          format!("Expected an operator but found `{}`", operator_token.text),
+          operator_token.start,
+          operator_token.end,
+        ))
+      },
+    };
+
+    let value = match operator {
+      CompareOperator::IsEmpty | CompareOperator::IsNotEmpty => None,
+      _ => {
+        let token = self.next()?;
+        match &token.kind {
+          TokenKind::Word(_) | TokenKind::Str(_) => Some(token),
+          _ => {
+            return Err(FilterExpressionError::new(
+              format!("Expected a value but found `{}`", token.text),
+              token.start,
+              token.end,
+            ))
+          },
+        }
+      },
+    };
+
+    let inner = data_filter(field, operator, &operator_token, value.as_ref())?;
+    Ok(Filter {
+      id: gen_database_filter_id(),
+      inner,
+    })
+  }
+
+  /// Finds the field by its exact name, falling back to a case insensitive match.
+  fn find_field(&self, name: &str) -> Option<&'a Field> {
+    self
+      .fields
+      .iter()
+      .find(|field| field.name == name)
+      .or_else(|| {
+        self
+          .fields
+          .iter()
+          .find(|field| field.name.to_lowercase() == name.to_lowercase())
+      })
+  }
+}
+
+fn combine(mut children: Vec<Filter>, f: impl FnOnce(Vec<Filter>) -> FilterInner) -> Filter {
+  if children.len() == 1 {
+    return children.pop().unwrap();
+  }
+  Filter {
+    id: gen_database_filter_id(),
+    inner: f(children),
+  }
+}
+
+fn value_text(value: Option<&Token>) -> String {
+  match value.map(|token| &token.kind) {
+    Some(TokenKind::Word(value)) | Some(TokenKind::Str(value)) => value.clone(),
+    _ => "".to_string(),
+  }
+}
+
+fn data_filter(
+  field: &Field,
+  operator: CompareOperator,
+  operator_token: &Token,
+  value: Option<&Token>,
+) -> Result<FilterInner, FilterExpressionError> {
+  let field_type = FieldType::from(field.field_type);
+  let unsupported_operator = || {
+    FilterExpressionError::new(
+      format!(
+        "The operator `{}` can't be used with the field `{}`",
+        operator_token.text, field.name
+      ),
+      operator_token.start,
+      operator_token.end,
+    )
+  };
+  let invalid_value = |message: String| match value {
+    Some(token) => FilterExpressionError::new(message, token.start, token.end),
+    None => FilterExpressionError::new(message, operator_token.start, operator_token.end),
+  };
+  let text = value_text(value);
+
+  let (condition, content) = match field_type {
+    FieldType::RichText | FieldType::URL | FieldType::Summary | FieldType::Translate => {
+      let condition = match operator {
+        CompareOperator::Equal => TextFilterConditionPB::TextIs,
+        CompareOperator::NotEqual => TextFilterConditionPB::TextIsNot,
+        CompareOperator::Contains => TextFilterConditionPB::TextContains,
+        CompareOperator::DoesNotContain => TextFilterConditionPB::TextDoesNotContain,
+        CompareOperator::IsEmpty => TextFilterConditionPB::TextIsEmpty,
+        CompareOperator::IsNotEmpty => TextFilterConditionPB::TextIsNotEmpty,
+        _ => return Err(unsupported_operator()),
+      };
+      (condition as i64, text)
+    },
+    FieldType::Number => {
+      let condition = match operator {
+        CompareOperator::Equal => NumberFilterConditionPB::Equal,
+        CompareOperator::NotEqual => NumberFilterConditionPB::NotEqual,
+        CompareOperator::GreaterThan => NumberFilterConditionPB::GreaterThan,
+        CompareOperator::GreaterThanOrEqualTo => NumberFilterConditionPB::GreaterThanOrEqualTo,
+        CompareOperator::LessThan => NumberFilterConditionPB::LessThan,
+        CompareOperator::LessThanOrEqualTo => NumberFilterConditionPB::LessThanOrEqualTo,
+        CompareOperator::IsEmpty => NumberFilterConditionPB::NumberIsEmpty,
+        CompareOperator::IsNotEmpty => NumberFilterConditionPB::NumberIsNotEmpty,
+        _ => return Err(unsupported_operator()),
+      };
+      if value.is_some() && text.parse::<f64>().is_err() {
+        return Err(invalid_value(format!(
+          "Expected a number but found `{}`",
+          text
+        )));
+      }
+      (condition as i64, text)
+    },
+    FieldType::Checkbox => {
+      let is_checked = match text.to_lowercase().as_str() {
+        "true" | "yes" => true,
+        "false" | "no" => false,
+        _ => {
+          return Err(invalid_value(format!(
+            "Expected `true` or `false` but found `{}`",
+            text
+          )))
+        },
+      };
+      let is_checked = match operator {
+        CompareOperator::Equal => is_checked,
+        CompareOperator::NotEqual => !is_checked,
+        _ => return Err(unsupported_operator()),
+      };
+      let condition = if is_checked {
+        CheckboxFilterConditionPB::IsChecked
+      } else {
+        CheckboxFilterConditionPB::IsUnChecked
+      };
+      (condition as i64, "".to_string())
+    },
+    FieldType::SingleSelect | FieldType::MultiSelect => {
+      let condition = match operator {
+        CompareOperator::Equal => SelectOptionFilterConditionPB::OptionIs,
+        CompareOperator::NotEqual => SelectOptionFilterConditionPB::OptionIsNot,
+        CompareOperator::Contains => SelectOptionFilterConditionPB::OptionContains,
+        CompareOperator::DoesNotContain => SelectOptionFilterConditionPB::OptionDoesNotContain,
+        CompareOperator::IsEmpty => SelectOptionFilterConditionPB::OptionIsEmpty,
+        CompareOperator::IsNotEmpty => SelectOptionFilterConditionPB::OptionIsNotEmpty,
+        _ => return Err(unsupported_operator()),
+      };
+      let option_ids = match value {
+        None => vec![],
+        Some(_) => {
+          let option = select_type_option_from_field(field)
+            .ok()
+            .and_then(|type_option| {
+              type_option
+                .options()
+                .iter()
+                .find(|option| option.name.to_lowercase() == text.to_lowercase())
+                .cloned()
+            })
+            .ok_or_else(|| {
+              invalid_value(format!(
+                "The field `{}` doesn't have the option `{}`",
+                field.name, text
+              ))
+            })?;
+          vec![option.id]
+        },
+      };
+      (
+        condition as i64,
+        SelectOptionIds::from(option_ids).to_string(),
+      )
+    },
+    FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+      let condition = match operator {
+        CompareOperator::Equal => DateFilterConditionPB::DateStartsOn,
+        CompareOperator::GreaterThan => DateFilterConditionPB::DateStartsAfter,
+        CompareOperator::GreaterThanOrEqualTo => DateFilterConditionPB::DateStartsOnOrAfter,
+        CompareOperator::LessThan => DateFilterConditionPB::DateStartsBefore,
+        CompareOperator::LessThanOrEqualTo => DateFilterConditionPB::DateStartsOnOrBefore,
+        CompareOperator::IsEmpty => DateFilterConditionPB::DateStartIsEmpty,
+        CompareOperator::IsNotEmpty => DateFilterConditionPB::DateStartIsNotEmpty,
+        _ => return Err(unsupported_operator()),
+      };
+      let timestamp = match value {
+        None => None,
+        Some(_) => {
+          let date = NaiveDate::parse_from_str(&text, "%Y-%m-%d").map_err(|_| {
+            invalid_value(format!(
+              "Expected a date like `2024-01-31` but found `{}`",
+              text
+            ))
+          })?;
+          date
+            .and_hms_opt(0, 0, 0)
+            .map(|datetime| datetime.and_utc().timestamp())
+        },
+      };
+      let content = DateFilterContent {
+        timestamp,
+        ..Default::default()
+      };
+      (condition as i64, content.to_string())
+    },
+    FieldType::Checklist | FieldType::Relation | FieldType::Time | FieldType::Media => {
+      return Err(FilterExpressionError::new(
+        format!(
+          "The field `{}` can't be filtered with an expression",
+          field.name
+        ),
+        operator_token.start,
+        operator_token.end,
+      ))
+    },
+  };
+
+  Ok(FilterInner::new_data(
+    field.id.clone(),
+    field_type,
+    condition,
+    content,
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn tokenize_expression_test() {
+    let tokens = tokenize(r#"Status = "Live \"now\"" AND Price >= 100"#).unwrap();
+    let kinds = tokens
+      .into_iter()
+      .map(|token| token.kind)
+      .collect::<Vec<_>>();
+    assert_eq!(
+      kinds,
+      vec![
+        TokenKind::Word("Status".to_string()),
+        TokenKind::Operator(CompareOperator::Equal),
+        TokenKind::Str("Live \"now\"".to_string()),
+        TokenKind::Word("AND".to_string()),
+        TokenKind::Word("Price".to_string()),
+        TokenKind::Operator(CompareOperator::GreaterThanOrEqualTo),
+        TokenKind::Word("100".to_string()),
+      ]
+    );
+  }
+
+  #[test]
+  fn tokenize_unterminated_string_test() {
+    let err = tokenize(r#"Name = "abc"#).unwrap_err();
+    assert_eq!((err.start, err.end), (7, 11));
+  }
+
+  #[test]
+  fn tokenize_unexpected_char_test() {
+    let err = tokenize("Name ~ abc").unwrap_err();
+    assert_eq!((err.start, err.end), (5, 6));
+  }
+}
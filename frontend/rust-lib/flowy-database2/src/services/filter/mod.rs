@@ -1,7 +1,9 @@
 mod controller;
 mod entities;
+mod expression;
 mod task;
 
 pub use controller::*;
 pub use entities::*;
+pub use expression::*;
 pub(crate) use task::*;
@@ -1,8 +1,10 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use collab_database::fields::{Field, TypeOptionData};
 use collab_database::rows::{Cell, Cells, Row, RowId};
 
-use flowy_error::FlowyResult;
+use flowy_error::{FlowyError, FlowyResult};
 
 use crate::entities::{GroupChangesPB, GroupPB, GroupRowsNotificationPB, InsertedGroupPB};
 use crate::services::field::TypeOption;
@@ -113,7 +115,7 @@ pub trait GroupController: Send + Sync {
   ///
   /// * `rows`: rows to be inserted
   /// * `field`: reference to the field being sorted (currently unused)
-  fn fill_groups(&mut self, rows: &[&Row], field: &Field) -> FlowyResult<()>;
+  fn fill_groups(&mut self, rows: &[Arc<Row>], field: &Field) -> FlowyResult<()>;
 
   /// Create a new group, currently only supports single and multi-select.
   ///
@@ -194,6 +196,17 @@ pub trait GroupController: Send + Sync {
 
   /// Called before the row was created.
   fn will_create_row(&self, cells: &mut Cells, field: &Field, group_id: &str);
+
+  /// Returns the id of the field whose values are summed up in each group, if any.
+  fn get_aggregate_field_id(&self) -> Option<&str> {
+    None
+  }
+
+  /// Sets the field whose values are summed up in each group. `None` only counts the rows of
+  /// the groups.
+  fn set_aggregate_field_id(&mut self, _field_id: Option<String>) -> FlowyResult<()> {
+    Err(FlowyError::invalid_data().with_context("The groups don't support aggregates"))
+  }
 }
 
 #[derive(Debug)]
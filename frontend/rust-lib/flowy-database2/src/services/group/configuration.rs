@@ -146,7 +146,12 @@ where
   }
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub(crate) fn add_new_group(&mut self, group: Group) -> FlowyResult<InsertedGroupPB> {
-    let group_data = GroupData::new(group.id.clone(), self.field_id.clone(), group.visible);
+    let group_data = GroupData::new(
+      group.id.clone(),
+      self.field_id.clone(),
+      group.visible,
+      group.collapsed,
+    );
     self.group_by_id.insert(group.id.clone(), group_data);
     let (index, group_data) = self.get_group(&group.id).unwrap();
     let insert_group = InsertedGroupPB {
@@ -283,10 +288,11 @@ where
           Some(pos) => {
             let old_group = configuration.groups.get_mut(pos).unwrap();
             // Take the old group setting
-            if group.visible != old_group.visible {
+            if group.visible != old_group.visible || group.collapsed != old_group.collapsed {
               is_changed = true;
             }
             group.visible = old_group.visible;
+            group.collapsed = old_group.collapsed;
           },
         }
       }
@@ -295,14 +301,24 @@ where
 
     // Update the memory cache of the groups
     all_groups.into_iter().for_each(|group| {
-      let group = GroupData::new(group.id, self.field_id.clone(), group.visible);
+      let group = GroupData::new(
+        group.id,
+        self.field_id.clone(),
+        group.visible,
+        group.collapsed,
+      );
       self.group_by_id.insert(group.id.clone(), group);
     });
 
     let initial_groups = new_groups
       .into_iter()
       .flat_map(|group_rev| {
-        let group = GroupData::new(group_rev.id, self.field_id.clone(), group_rev.visible);
+        let group = GroupData::new(
+          group_rev.id,
+          self.field_id.clone(),
+          group_rev.visible,
+          group_rev.collapsed,
+        );
         Some(GroupPB::from(group))
       })
       .collect();
@@ -328,11 +344,15 @@ where
       if let Some(visible) = group_changeset.visible {
         group.visible = visible;
       }
+      if let Some(collapsed) = group_changeset.collapsed {
+        group.collapsed = collapsed;
+      }
     })?;
 
     if let Some(group) = update_group {
       if let Some(group_data) = self.group_by_id.get_mut(&group.id) {
         group_data.is_visible = group.visible;
+        group_data.is_collapsed = group.collapsed;
       };
     }
     Ok(())
@@ -349,6 +369,22 @@ where
     self.setting.content.clone()
   }
 
+  pub fn get_aggregate_field_id(&self) -> Option<&str> {
+    Some(self.setting.aggregate_field_id.as_str()).filter(|field_id| !field_id.is_empty())
+  }
+
+  pub(crate) fn update_aggregate_field_id(&mut self, field_id: Option<String>) -> FlowyResult<()> {
+    let field_id = field_id.unwrap_or_default();
+    self.mut_configuration(|configuration| {
+      if configuration.aggregate_field_id == field_id {
+        false
+      } else {
+        configuration.aggregate_field_id = field_id;
+        true
+      }
+    })
+  }
+
   /// # Arguments
   ///
   /// * `mut_configuration_fn`: mutate the [GroupSetting] and return whether the [GroupSetting] is
@@ -38,8 +38,11 @@ pub trait GroupControllerDelegate: Send + Sync + 'static {
 ///
 /// See also: [DefaultGroupController] which contains the most basic implementation of
 /// `GroupController` that only has one group.
-pub struct BaseGroupController<C, G, P> {
+pub struct BaseGroupController<C, G: GroupsBuilder, P> {
   pub grouping_field_id: String,
+  /// The type option of the grouping field. Used to compute the group of a cell, e.g. the date
+  /// groups are computed in the timezone of the date field.
+  pub grouping_field_type_option: G::GroupTypeOption,
   pub context: GroupControllerContext<C>,
   group_builder_phantom: PhantomData<G>,
   cell_parser_phantom: PhantomData<P>,
@@ -67,6 +70,7 @@ where
 
     Ok(Self {
       grouping_field_id: grouping_field.id.clone(),
+      grouping_field_type_option: type_option,
       context: configuration,
       group_builder_phantom: PhantomData,
       cell_parser_phantom: PhantomData,
@@ -114,7 +118,7 @@ where
       changeset
         .inserted_rows
         .push(InsertedRowPB::new(RowMetaPB::from(row)));
-      no_status_group.add_row(row.clone());
+      no_status_group.add_row(Arc::new(row.clone()));
     }
 
     // [other_group_delete_rows] contains all the deleted rows except the default group.
@@ -177,7 +181,7 @@ where
   }
 
   #[tracing::instrument(level = "trace", skip_all, fields(row_count=%rows.len(), group_result))]
-  fn fill_groups(&mut self, rows: &[&Row], _field: &Field) -> FlowyResult<()> {
+  fn fill_groups(&mut self, rows: &[Arc<Row>], _field: &Field) -> FlowyResult<()> {
     for row in rows {
       let cell = match row.cells.get(&self.grouping_field_id) {
         None => self.placeholder_cell(),
@@ -190,7 +194,7 @@ where
         for group in self.context.groups() {
           if self.can_group(&group.id, &cell_data) {
             grouped_rows.push(GroupedRow {
-              row: (*row).clone(),
+              row: row.clone(),
               group_id: group.id.clone(),
             });
           }
@@ -208,7 +212,7 @@ where
 
       match self.context.get_mut_no_status_group() {
         None => {},
-        Some(no_status_group) => no_status_group.add_row((*row).clone()),
+        Some(no_status_group) => no_status_group.add_row(row.clone()),
       }
     }
 
@@ -256,11 +260,11 @@ where
       if !suitable_group_ids.is_empty() {
         for group_id in suitable_group_ids.iter() {
           if let Some(group) = self.context.get_mut_group(group_id) {
-            group.add_row((*row).clone());
+            group.add_row(Arc::new(row.clone()));
           }
         }
       } else if let Some(no_status_group) = self.context.get_mut_no_status_group() {
-        no_status_group.add_row((*row).clone());
+        no_status_group.add_row(Arc::new(row.clone()));
         let changeset = GroupRowsNotificationPB::insert(
           no_status_group.id.clone(),
           vec![InsertedRowPB {
@@ -366,7 +370,10 @@ where
     Ok(result)
   }
 
-  fn did_update_group_field(&mut self, _field: &Field) -> FlowyResult<Option<GroupChangesPB>> {
+  fn did_update_group_field(&mut self, field: &Field) -> FlowyResult<Option<GroupChangesPB>> {
+    if let Some(type_option) = field.get_type_option::<T>(FieldType::from(field.field_type)) {
+      self.grouping_field_type_option = type_option;
+    }
     Ok(None)
   }
 
@@ -433,10 +440,18 @@ where
   fn will_create_row(&self, cells: &mut Cells, field: &Field, group_id: &str) {
     <Self as GroupCustomize>::will_create_row(self, cells, field, group_id);
   }
+
+  fn get_aggregate_field_id(&self) -> Option<&str> {
+    self.context.get_aggregate_field_id()
+  }
+
+  fn set_aggregate_field_id(&mut self, field_id: Option<String>) -> FlowyResult<()> {
+    self.context.update_aggregate_field_id(field_id)
+  }
 }
 
 struct GroupedRow {
-  row: Row,
+  row: Arc<Row>,
   group_id: String,
 }
 
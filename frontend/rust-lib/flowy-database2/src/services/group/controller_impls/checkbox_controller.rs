@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use collab_database::fields::checkbox_type_option::CheckboxTypeOption;
 use collab_database::fields::{Field, TypeOptionData};
@@ -66,7 +68,7 @@ impl GroupCustomize for CheckboxGroupController {
             changeset
               .inserted_rows
               .push(InsertedRowPB::new(RowMetaPB::from(row)));
-            group.add_row(row.clone());
+            group.add_row(Arc::new(row.clone()));
           }
         }
       }
@@ -82,7 +84,7 @@ impl GroupCustomize for CheckboxGroupController {
             changeset
               .inserted_rows
               .push(InsertedRowPB::new(RowMetaPB::from(row)));
-            group.add_row(row.clone());
+            group.add_row(Arc::new(row.clone()));
           }
         }
       }
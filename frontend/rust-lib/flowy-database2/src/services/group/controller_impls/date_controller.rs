@@ -1,5 +1,11 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
-use chrono::{DateTime, Datelike, Days, Duration, Local};
+use chrono::{
+  DateTime, Datelike, Days, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, Offset,
+  TimeZone,
+};
+use chrono_tz::Tz;
 use collab_database::database::timestamp;
 use collab_database::fields::date_type_option::{DateCellData, DateTypeOption};
 use collab_database::fields::{Field, TypeOptionData};
@@ -68,7 +74,12 @@ impl GroupCustomize for DateGroupController {
     content: &str,
     cell_data: &<Self::GroupTypeOption as TypeOption>::CellData,
   ) -> bool {
-    content == get_date_group_id(cell_data, &self.context.get_setting_content())
+    content
+      == get_date_group_id(
+        cell_data,
+        &self.grouping_field_type_option,
+        &self.context.get_setting_content(),
+      )
   }
 
   fn create_or_delete_group_when_cell_changed(
@@ -78,23 +89,30 @@ impl GroupCustomize for DateGroupController {
     _cell_data: &<Self::GroupTypeOption as TypeOption>::CellProtobufType,
   ) -> FlowyResult<(Option<InsertedGroupPB>, Option<GroupPB>)> {
     let setting_content = self.context.get_setting_content();
+    let type_option = &self.grouping_field_type_option;
     let mut inserted_group = None;
     if self
       .context
-      .get_group(&get_date_group_id(&_cell_data.into(), &setting_content))
+      .get_group(&get_date_group_id(
+        &_cell_data.into(),
+        type_option,
+        &setting_content,
+      ))
       .is_none()
     {
-      let group = make_group_from_date_cell(&_cell_data.into(), &setting_content);
+      let group = make_group_from_date_cell(&_cell_data.into(), type_option, &setting_content);
       let mut new_group = self.context.add_new_group(group)?;
-      new_group.group.rows.push(RowMetaPB::from(_row.clone()));
+      new_group.group.rows.push(RowMetaPB::from(_row));
       inserted_group = Some(new_group);
     }
 
     // Delete the old group if there are no rows in that group
     let deleted_group = match _old_cell_data.and_then(|old_cell_data| {
-      self
-        .context
-        .get_group(&get_date_group_id(&old_cell_data.into(), &setting_content))
+      self.context.get_group(&get_date_group_id(
+        &old_cell_data.into(),
+        type_option,
+        &setting_content,
+      ))
     }) {
       None => None,
       Some((_, group)) => {
@@ -124,14 +142,19 @@ impl GroupCustomize for DateGroupController {
   ) -> Vec<GroupRowsNotificationPB> {
     let mut changesets = vec![];
     let setting_content = self.context.get_setting_content();
+    let group_id = get_date_group_id(
+      &cell_data.into(),
+      &self.grouping_field_type_option,
+      &setting_content,
+    );
     self.context.iter_mut_status_groups(|group| {
       let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
-      if group.id == get_date_group_id(&cell_data.into(), &setting_content) {
+      if group.id == group_id {
         if !group.contains_row(&row.id) {
           changeset
             .inserted_rows
-            .push(InsertedRowPB::new(RowMetaPB::from(row.clone())));
-          group.add_row(row.clone());
+            .push(InsertedRowPB::new(RowMetaPB::from(row)));
+          group.add_row(Arc::new(row.clone()));
         }
       } else if group.contains_row(&row.id) {
         group.remove_row(&row.id);
@@ -164,10 +187,11 @@ impl GroupCustomize for DateGroupController {
     });
 
     let setting_content = self.context.get_setting_content();
-    let deleted_group = match self
-      .context
-      .get_group(&get_date_group_id(cell_data, &setting_content))
-    {
+    let deleted_group = match self.context.get_group(&get_date_group_id(
+      cell_data,
+      &self.grouping_field_type_option,
+      &setting_content,
+    )) {
       Some((_, group)) if group.rows.len() == 1 => Some(group.clone()),
       _ => None,
     };
@@ -197,10 +221,11 @@ impl GroupCustomize for DateGroupController {
   ) -> Option<GroupPB> {
     let mut deleted_group = None;
     let setting_content = self.context.get_setting_content();
-    if let Some((_, group)) = self
-      .context
-      .get_group(&get_date_group_id(&cell_data.into(), &setting_content))
-    {
+    if let Some((_, group)) = self.context.get_group(&get_date_group_id(
+      &cell_data.into(),
+      &self.grouping_field_type_option,
+      &setting_content,
+    )) {
       if group.rows.len() == 1 {
         deleted_group = Some(GroupPB::from(group.clone()));
       }
@@ -219,10 +244,12 @@ impl GroupCustomize for DateGroupController {
   fn will_create_row(&self, cells: &mut Cells, field: &Field, group_id: &str) {
     match self.context.get_group(group_id) {
       None => tracing::warn!("Can not find the group: {}", group_id),
-      Some((_, _)) => {
-        let date = DateTime::parse_from_str(group_id, GROUP_ID_DATE_FORMAT).unwrap();
-        let cell = insert_date_cell(date.timestamp(), None, Some(false), field);
-        cells.insert(field.id.clone(), cell);
+      Some((_, _)) => match timestamp_from_group_id(group_id, &self.grouping_field_type_option) {
+        None => tracing::warn!("Invalid date group id: {}", group_id),
+        Some(timestamp) => {
+          let cell = insert_date_cell(timestamp, None, Some(false), field);
+          cells.insert(field.id.clone(), cell);
+        },
       },
     }
   }
@@ -237,7 +264,7 @@ impl GroupsBuilder for DateGroupBuilder {
   async fn build(
     field: &Field,
     context: &Self::Context,
-    _type_option: &Self::GroupTypeOption,
+    type_option: &Self::GroupTypeOption,
   ) -> GeneratedGroups {
     // Read all the cells for the grouping field
     let cells = context.get_all_cells().await;
@@ -247,7 +274,7 @@ impl GroupsBuilder for DateGroupBuilder {
       .into_iter()
       .flat_map(|value| value.into_date_field_cell_data())
       .filter(|cell| cell.timestamp.is_some())
-      .map(|cell| make_group_from_date_cell(&cell, &context.get_setting_content()))
+      .map(|cell| make_group_from_date_cell(&cell, type_option, &context.get_setting_content()))
       .collect();
     groups.sort_by(|a, b| a.id.cmp(&b.id));
 
@@ -260,16 +287,26 @@ impl GroupsBuilder for DateGroupBuilder {
   }
 }
 
-fn make_group_from_date_cell(cell_data: &DateCellData, setting_content: &str) -> Group {
-  let group_id = get_date_group_id(cell_data, setting_content);
+fn make_group_from_date_cell(
+  cell_data: &DateCellData,
+  type_option: &DateTypeOption,
+  setting_content: &str,
+) -> Group {
+  let group_id = get_date_group_id(cell_data, type_option, setting_content);
   Group::new(group_id)
 }
 
 const GROUP_ID_DATE_FORMAT: &str = "%Y/%m/%d";
 
-fn get_date_group_id(cell_data: &DateCellData, setting_content: &str) -> String {
+/// Returns the id of the group of the date. The date is bucketed in the timezone of the date
+/// field, so every platform computes the same groups.
+fn get_date_group_id(
+  cell_data: &DateCellData,
+  type_option: &DateTypeOption,
+  setting_content: &str,
+) -> String {
   let config = DateGroupConfiguration::from_json(setting_content).unwrap_or_default();
-  let date_time = date_time_from_timestamp(cell_data.timestamp);
+  let date_time = date_time_from_timestamp(cell_data.timestamp, type_option);
 
   let date_format = GROUP_ID_DATE_FORMAT;
   let month_format = &date_format.replace("%d", "01");
@@ -284,7 +321,7 @@ fn get_date_group_id(cell_data: &DateCellData, setting_content: &str) -> String
       .unwrap()
       .format(date_format),
     DateCondition::Relative => {
-      let now = date_time_from_timestamp(Some(timestamp())).date_naive();
+      let now = date_time_from_timestamp(Some(timestamp()), type_option).date_naive();
       let date_time = date_time.date_naive();
 
       let diff = date_time.signed_duration_since(now).num_days();
@@ -324,17 +361,32 @@ fn get_date_group_id(cell_data: &DateCellData, setting_content: &str) -> String
   date.to_string()
 }
 
-fn date_time_from_timestamp(timestamp: Option<i64>) -> DateTime<Local> {
-  match timestamp {
-    Some(timestamp) => {
-      let naive = DateTime::from_timestamp(timestamp, 0)
-        .unwrap_or_default()
-        .naive_utc();
-      let offset = *Local::now().offset();
+pub(crate) fn date_time_from_timestamp(
+  timestamp: Option<i64>,
+  type_option: &DateTypeOption,
+) -> DateTime<FixedOffset> {
+  let naive = timestamp
+    .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+    .unwrap_or_default()
+    .naive_utc();
+  DateTime::from_naive_utc_and_offset(naive, timezone_offset(type_option, &naive))
+}
 
-      DateTime::<Local>::from_naive_utc_and_offset(naive, offset)
-    },
-    None => DateTime::default(),
+/// Returns the timestamp of the start of the day of the group in the timezone of the date field.
+pub(crate) fn timestamp_from_group_id(group_id: &str, type_option: &DateTypeOption) -> Option<i64> {
+  let date_time = NaiveDate::parse_from_str(group_id, GROUP_ID_DATE_FORMAT)
+    .ok()?
+    .and_hms_opt(0, 0, 0)?;
+  let offset = timezone_offset(type_option, &date_time);
+  Some(date_time.and_utc().timestamp() - offset.local_minus_utc() as i64)
+}
+
+/// Returns the offset of the timezone of the date field at the given UTC time. Falls back to the
+/// local timezone if the field doesn't have a valid timezone.
+fn timezone_offset(type_option: &DateTypeOption, utc: &NaiveDateTime) -> FixedOffset {
+  match type_option.timezone_id.parse::<Tz>() {
+    Ok(timezone) => timezone.offset_from_utc_datetime(utc).fix(),
+    Err(_) => Local.offset_from_utc_datetime(utc).fix(),
   }
 }
 
@@ -348,8 +400,9 @@ mod tests {
 
   #[test]
   fn group_id_name_test() {
-    struct GroupIDTest {
+    struct GroupIDTest<'a> {
       cell_data: DateCellData,
+      type_option: &'a DateTypeOption,
       setting_content: String,
       exp_group_id: String,
     }
@@ -369,10 +422,15 @@ mod tests {
     local_date_type_option.timezone_id = today.offset().to_string();
     let mut default_date_type_option = DateTypeOption::new();
     default_date_type_option.timezone_id = "".to_string();
+    let mut tokyo_date_type_option = DateTypeOption::new();
+    tokyo_date_type_option.timezone_id = "Asia/Tokyo".to_string();
+    let mut los_angeles_date_type_option = DateTypeOption::new();
+    los_angeles_date_type_option.timezone_id = "America/Los_Angeles".to_string();
 
     let tests = vec![
       GroupIDTest {
         cell_data: mar_14_2022_cd.clone(),
+        type_option: &local_date_type_option,
         setting_content: r#"{"condition": 0, "hide_empty": false}"#.to_string(),
         exp_group_id: "2022/03/01".to_string(),
       },
@@ -382,6 +440,7 @@ mod tests {
           include_time: false,
           ..Default::default()
         },
+        type_option: &local_date_type_option,
         setting_content: r#"{"condition": 0, "hide_empty": false}"#.to_string(),
         exp_group_id: today.format(GROUP_ID_DATE_FORMAT).to_string(),
       },
@@ -391,6 +450,7 @@ mod tests {
           include_time: false,
           ..Default::default()
         },
+        type_option: &local_date_type_option,
         setting_content: r#"{"condition": 0, "hide_empty": false}"#.to_string(),
         exp_group_id: today
           .checked_sub_days(Days::new(7))
@@ -400,6 +460,7 @@ mod tests {
       },
       GroupIDTest {
         cell_data: mar_14_2022_cd.clone(),
+        type_option: &local_date_type_option,
         setting_content: r#"{"condition": 1, "hide_empty": false}"#.to_string(),
         exp_group_id: "2022/03/14".to_string(),
       },
@@ -415,16 +476,19 @@ mod tests {
           include_time: false,
           ..Default::default()
         },
+        type_option: &local_date_type_option,
         setting_content: r#"{"condition": 2, "hide_empty": false}"#.to_string(),
         exp_group_id: "2022/03/14".to_string(),
       },
       GroupIDTest {
         cell_data: mar_14_2022_cd.clone(),
+        type_option: &local_date_type_option,
         setting_content: r#"{"condition": 3, "hide_empty": false}"#.to_string(),
         exp_group_id: "2022/03/01".to_string(),
       },
       GroupIDTest {
         cell_data: mar_14_2022_cd,
+        type_option: &local_date_type_option,
         setting_content: r#"{"condition": 4, "hide_empty": false}"#.to_string(),
         exp_group_id: "2022/01/01".to_string(),
       },
@@ -434,6 +498,7 @@ mod tests {
           include_time: false,
           ..Default::default()
         },
+        type_option: &default_date_type_option,
         setting_content: r#"{"condition": 1, "hide_empty": false}"#.to_string(),
         exp_group_id: "2023/06/02".to_string(),
       },
@@ -443,13 +508,34 @@ mod tests {
           include_time: false,
           ..Default::default()
         },
+        type_option: &local_date_type_option,
+        setting_content: r#"{"condition": 1, "hide_empty": false}"#.to_string(),
+        exp_group_id: "2023/06/03".to_string(),
+      },
+      GroupIDTest {
+        cell_data: DateCellData {
+          timestamp: Some(1685815200),
+          include_time: false,
+          ..Default::default()
+        },
+        type_option: &tokyo_date_type_option,
+        setting_content: r#"{"condition": 1, "hide_empty": false}"#.to_string(),
+        exp_group_id: "2023/06/04".to_string(),
+      },
+      GroupIDTest {
+        cell_data: DateCellData {
+          timestamp: Some(1685815200),
+          include_time: false,
+          ..Default::default()
+        },
+        type_option: &los_angeles_date_type_option,
         setting_content: r#"{"condition": 1, "hide_empty": false}"#.to_string(),
         exp_group_id: "2023/06/03".to_string(),
       },
     ];
 
     for (i, test) in tests.iter().enumerate() {
-      let group_id = get_date_group_id(&test.cell_data, &test.setting_content);
+      let group_id = get_date_group_id(&test.cell_data, test.type_option, &test.setting_content);
       assert_eq!(test.exp_group_id, group_id, "test {}", i);
     }
   }
@@ -30,7 +30,12 @@ const DEFAULT_GROUP_CONTROLLER: &str = "DefaultGroupController";
 
 impl DefaultGroupController {
   pub fn new(field: &Field, delegate: Arc<dyn GroupControllerDelegate>) -> Self {
-    let group = GroupData::new(DEFAULT_GROUP_CONTROLLER.to_owned(), field.id.clone(), true);
+    let group = GroupData::new(
+      DEFAULT_GROUP_CONTROLLER.to_owned(),
+      field.id.clone(),
+      true,
+      false,
+    );
     Self {
       field_id: field.id.clone(),
       group,
@@ -53,9 +58,9 @@ impl GroupController for DefaultGroupController {
     Some((0, self.group.clone()))
   }
 
-  fn fill_groups(&mut self, rows: &[&Row], _field: &Field) -> FlowyResult<()> {
+  fn fill_groups(&mut self, rows: &[Arc<Row>], _field: &Field) -> FlowyResult<()> {
     rows.iter().for_each(|row| {
-      self.group.add_row((*row).clone());
+      self.group.add_row(row.clone());
     });
     Ok(())
   }
@@ -72,7 +77,7 @@ impl GroupController for DefaultGroupController {
   }
 
   fn did_create_row(&mut self, row: &Row, index: usize) -> Vec<GroupRowsNotificationPB> {
-    self.group.add_row((*row).clone());
+    self.group.add_row(Arc::new(row.clone()));
 
     vec![GroupRowsNotificationPB::insert(
       self.group.id.clone(),
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use crate::entities::{
   FieldType, GroupRowsNotificationPB, InsertedRowPB, RowMetaPB, SelectOptionCellDataPB,
 };
@@ -5,11 +7,11 @@ use crate::services::cell::{
   insert_checkbox_cell, insert_date_cell, insert_select_option_cell, insert_url_cell,
 };
 use crate::services::field::CHECK;
-use crate::services::group::{Group, GroupData, MoveGroupRowContext};
-use chrono::NaiveDateTime;
+use crate::services::group::{timestamp_from_group_id, Group, GroupData, MoveGroupRowContext};
+use collab_database::fields::date_type_option::DateTypeOption;
 use collab_database::fields::select_type_option::{SelectOption, SelectOptionIds};
 use collab_database::fields::Field;
-use collab_database::rows::{Cell, Row};
+use collab_database::rows::{new_cell_builder, Cell, Row};
 use tracing::debug;
 
 pub fn add_or_remove_select_option_row(
@@ -29,8 +31,8 @@ pub fn add_or_remove_select_option_row(
         if !group.contains_row(&row.id) {
           changeset
             .inserted_rows
-            .push(InsertedRowPB::new(RowMetaPB::from(row.clone())));
-          group.add_row(row.clone());
+            .push(InsertedRowPB::new(RowMetaPB::from(row)));
+          group.add_row(Arc::new(row.clone()));
         }
       } else if group.contains_row(&row.id) {
         group.remove_row(&row.id);
@@ -92,22 +94,22 @@ pub fn move_group_row(
   }
 
   if group.id == *to_group_id {
-    let mut inserted_row = InsertedRowPB::new(RowMetaPB::from((*row).clone()));
+    let mut inserted_row = InsertedRowPB::new(RowMetaPB::from(*row));
     match to_index {
       None => {
         changeset.inserted_rows.push(inserted_row);
-        group.add_row(row.clone());
+        group.add_row(Arc::new(row.clone()));
       },
       Some(to_index) => {
         if to_index < group.number_of_row() {
           inserted_row.index = Some(to_index as i32);
-          group.insert_row(to_index, row.clone());
+          group.insert_row(to_index, Arc::new(row.clone()));
         } else {
           tracing::warn!(
             "[Database Group]: Move to index: {} is out of bounds",
             to_index
           );
-          group.add_row(row.clone());
+          group.add_row(Arc::new(row.clone()));
         }
         changeset.inserted_rows.push(inserted_row);
       },
@@ -117,7 +119,13 @@ pub fn move_group_row(
     // If the from_index is none which means the row is not belong to this group before and
     // it is moved from other groups.
     if from_index.is_none() {
-      let cell = make_inserted_cell(&group.id, field);
+      // The id of the no status group is the id of the field, so it can't be written to the
+      // cell. Moving a row to the no status group clears its cell instead.
+      let cell = if group.is_default {
+        make_empty_cell(field)
+      } else {
+        make_inserted_cell(&group.id, field)
+      };
       if let Some(cell) = cell {
         debug!(
           "[Database Group]: Update content of the cell in the row:{} to group:{}",
@@ -154,10 +162,11 @@ pub fn make_inserted_cell(group_id: &str, field: &Field) -> Option<Cell> {
       Some(cell)
     },
     FieldType::DateTime => {
-      let date =
-        NaiveDateTime::parse_from_str(&format!("{} 00:00:00", group_id), "%Y/%m/%d %H:%M:%S")
-          .unwrap();
-      let cell = insert_date_cell(date.and_utc().timestamp(), None, Some(false), field);
+      let type_option = field
+        .get_type_option::<DateTypeOption>(field_type)
+        .unwrap_or_else(DateTypeOption::new);
+      let timestamp = timestamp_from_group_id(group_id, &type_option)?;
+      let cell = insert_date_cell(timestamp, None, Some(false), field);
       Some(cell)
     },
     _ => {
@@ -167,6 +176,17 @@ pub fn make_inserted_cell(group_id: &str, field: &Field) -> Option<Cell> {
   }
 }
 
+fn make_empty_cell(field: &Field) -> Option<Cell> {
+  let field_type = FieldType::from(field.field_type);
+  match field_type {
+    FieldType::SingleSelect | FieldType::MultiSelect => {
+      Some(insert_select_option_cell(vec![], field))
+    },
+    FieldType::URL => Some(insert_url_cell("".to_string(), field)),
+    _ => Some(new_cell_builder(field_type)),
+  }
+}
+
 pub fn generate_select_option_groups(_field_id: &str, options: &[SelectOption]) -> Vec<Group> {
   let groups = options
     .iter()
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use collab_database::fields::url_type_option::{URLCellData, URLTypeOption};
 use collab_database::fields::{Field, TypeOptionData};
@@ -58,7 +60,7 @@ impl GroupCustomize for URLGroupController {
       let cell_data: URLCellData = _cell_data.clone().into();
       let group = Group::new(cell_data.data);
       let mut new_group = self.context.add_new_group(group)?;
-      new_group.group.rows.push(RowMetaPB::from(_row.clone()));
+      new_group.group.rows.push(RowMetaPB::from(_row));
       inserted_group = Some(new_group);
     }
 
@@ -100,7 +102,7 @@ impl GroupCustomize for URLGroupController {
           changeset
             .inserted_rows
             .push(InsertedRowPB::new(RowMetaPB::from(row)));
-          group.add_row(row.clone());
+          group.add_row(Arc::new(row.clone()));
         }
       } else if group.contains_row(&row.id) {
         group.remove_row(&row.id);
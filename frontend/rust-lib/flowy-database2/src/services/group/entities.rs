@@ -17,6 +17,10 @@ pub struct GroupSetting {
   pub groups: Vec<Group>,
   #[serde(default)]
   pub content: String,
+  /// The id of the field whose values are summed up in each group. Empty if the groups only
+  /// count their rows.
+  #[serde(default)]
+  pub aggregate_field_id: String,
 }
 
 #[derive(Clone, Default, Debug)]
@@ -24,6 +28,7 @@ pub struct GroupChangeset {
   pub group_id: String,
   pub name: Option<String>,
   pub visible: Option<bool>,
+  pub collapsed: Option<bool>,
 }
 
 impl GroupSetting {
@@ -34,6 +39,7 @@ impl GroupSetting {
       field_type,
       groups: vec![],
       content,
+      aggregate_field_id: String::new(),
     }
   }
 }
@@ -43,6 +49,7 @@ const FIELD_ID: &str = "field_id";
 const FIELD_TYPE: &str = "ty";
 const GROUPS: &str = "groups";
 const CONTENT: &str = "content";
+const AGGREGATE_FIELD_ID: &str = "aggregate_field_id";
 
 impl TryFrom<GroupSettingMap> for GroupSetting {
   type Error = anyhow::Error;
@@ -61,6 +68,7 @@ impl From<GroupSetting> for GroupSettingMap {
       (FIELD_TYPE.into(), Any::BigInt(setting.field_type)),
       (GROUPS.into(), groups),
       (CONTENT.into(), setting.content.into()),
+      (AGGREGATE_FIELD_ID.into(), setting.aggregate_field_id.into()),
     ])
   }
 }
@@ -70,6 +78,8 @@ pub struct Group {
   pub id: String,
   #[serde(default = "GROUP_VISIBILITY")]
   pub visible: bool,
+  #[serde(default)]
+  pub collapsed: bool,
 }
 
 impl TryFrom<GroupMap> for Group {
@@ -85,6 +95,7 @@ impl From<Group> for GroupMap {
     GroupMapBuilder::from([
       ("id".into(), group.id.into()),
       ("visible".into(), group.visible.into()),
+      ("collapsed".into(), group.collapsed.into()),
     ])
   }
 }
@@ -93,7 +104,11 @@ const GROUP_VISIBILITY: fn() -> bool = || true;
 
 impl Group {
   pub fn new(id: String) -> Self {
-    Self { id, visible: true }
+    Self {
+      id,
+      visible: true,
+      collapsed: false,
+    }
   }
 }
 
@@ -103,7 +118,10 @@ pub struct GroupData {
   pub field_id: String,
   pub is_default: bool,
   pub is_visible: bool,
-  pub(crate) rows: Vec<Row>,
+  pub is_collapsed: bool,
+  /// The rows are shared with the rows of the view, so filling the groups of a large view doesn't
+  /// copy the cells of every row.
+  pub(crate) rows: Vec<Arc<Row>>,
 }
 
 impl Display for GroupData {
@@ -113,13 +131,14 @@ impl Display for GroupData {
 }
 
 impl GroupData {
-  pub fn new(id: String, field_id: String, is_visible: bool) -> Self {
+  pub fn new(id: String, field_id: String, is_visible: bool, is_collapsed: bool) -> Self {
     let is_default = id == field_id;
     Self {
       id,
       field_id,
       is_default,
       is_visible,
+      is_collapsed,
       rows: vec![],
     }
   }
@@ -144,7 +163,7 @@ impl GroupData {
     }
   }
 
-  pub fn add_row(&mut self, row: Row) {
+  pub fn add_row(&mut self, row: Arc<Row>) {
     #[cfg(feature = "verbose_log")]
     tracing::trace!("[Database Group]: Add row:{} to group:{}", row.id, self.id);
     match self.rows.iter().find(|r| r.id == row.id) {
@@ -155,7 +174,7 @@ impl GroupData {
     }
   }
 
-  pub fn insert_row(&mut self, index: usize, row: Row) {
+  pub fn insert_row(&mut self, index: usize, row: Arc<Row>) {
     #[cfg(feature = "verbose_log")]
     tracing::trace!(
       "[Database Group]: Insert row:{} to group:{} at index:{}",
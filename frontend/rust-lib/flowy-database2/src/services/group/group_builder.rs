@@ -142,12 +142,7 @@ where
   }
 
   // Separates the rows into different groups
-  let row_details = delegate.get_all_rows(view_id).await;
-  let rows = row_details
-    .iter()
-    .map(|row| row.as_ref())
-    .collect::<Vec<_>>();
-
+  let rows = delegate.get_all_rows(view_id).await;
   group_controller.fill_groups(rows.as_slice(), &grouping_field)?;
   #[cfg(feature = "verbose_log")]
   {
@@ -173,5 +168,6 @@ pub fn make_no_status_group(field: &Field) -> Group {
   Group {
     id: field.id.clone(),
     visible: true,
+    collapsed: false,
   }
 }
@@ -0,0 +1,95 @@
+mod routes;
+
+use std::convert::Infallible;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::{Arc, Weak};
+
+use hyper::service::{make_service_fn, service_fn};
+use hyper::Server;
+use lib_dispatch::prelude::af_spawn;
+use nanoid::nanoid;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+
+use flowy_error::{FlowyError, FlowyResult};
+
+use crate::DatabaseManager;
+use routes::{handle_request, LocalServerState};
+
+/// A server on the loopback interface that lets the tools running on the same machine, like
+/// scripts or browser extensions, read and write the grids over HTTP while the app runs. Every
+/// request must send the token of the server in an `Authorization: Bearer <token>` header.
+///
+/// The routes, whose bodies are JSON:
+/// - `GET|POST /views/{view_id}/fields`
+/// - `PATCH|DELETE /views/{view_id}/fields/{field_id}`
+/// - `GET|POST /views/{view_id}/rows`, with the `offset`, `limit` and `filter` query parameters
+///   for `GET`. The filter is a filter expression.
+/// - `GET|PATCH|DELETE /views/{view_id}/rows/{row_id}`
+///
+/// The cells are read and written as text, like the values of a CSV file, and the cells of the
+/// sensitive fields are masked.
+pub struct LocalServer {
+  port: u16,
+  token: String,
+  cancellation: CancellationToken,
+}
+
+impl LocalServer {
+  /// Starts listening on the port, or on a free port if it's 0. A new token is generated each
+  /// time a server starts.
+  pub(crate) fn start(manager: Weak<DatabaseManager>, port: u16) -> FlowyResult<Self> {
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let builder = Server::try_bind(&addr).map_err(|err| {
+      FlowyError::internal().with_context(format!("Can't listen on port {}: {}", port, err))
+    })?;
+    let token = nanoid!(32);
+    let state = Arc::new(LocalServerState {
+      manager,
+      token: token.clone(),
+    });
+    let server = builder.serve(make_service_fn(move |_| {
+      let state = state.clone();
+      async move {
+        Ok::<_, Infallible>(service_fn(move |request| {
+          handle_request(state.clone(), request)
+        }))
+      }
+    }));
+    let port = server.local_addr().port();
+
+    let cancellation = CancellationToken::new();
+    let shutdown = cancellation.clone();
+    af_spawn(async move {
+      let server = server.with_graceful_shutdown(async move { shutdown.cancelled().await });
+      if let Err(err) = server.await {
+        error!("[Database]: local server failed: {}", err);
+      }
+      info!("[Database]: local server on port:{} stopped", port);
+    });
+    info!("[Database]: local server listening on port:{}", port);
+    Ok(Self {
+      port,
+      token,
+      cancellation,
+    })
+  }
+
+  pub fn port(&self) -> u16 {
+    self.port
+  }
+
+  pub fn token(&self) -> &str {
+    &self.token
+  }
+
+  pub fn stop(&self) {
+    self.cancellation.cancel();
+  }
+}
+
+impl Drop for LocalServer {
+  fn drop(&mut self) {
+    self.stop();
+  }
+}
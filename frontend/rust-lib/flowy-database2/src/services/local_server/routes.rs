@@ -0,0 +1,451 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Weak};
+
+use collab_database::fields::Field;
+use collab_database::rows::{Cell, Row, RowId};
+use collab_database::views::OrderObjectPosition;
+use hyper::body::HttpBody;
+use hyper::header::{
+  HeaderMap, HeaderValue, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS,
+  ACCESS_CONTROL_ALLOW_ORIGIN, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE,
+};
+use hyper::{Body, Method, Request, Response, StatusCode};
+use indexmap::IndexMap;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use subtle::ConstantTimeEq;
+
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+
+use crate::entities::{CreateFieldParams, CreateRowPayloadPB, FieldChangesetPB, FieldType};
+use crate::services::cell::stringify_cell;
+use crate::services::database::DatabaseEditor;
+use crate::services::field::{should_mask_field, MASKED_CELL_VALUE};
+use crate::services::share::csv::{cell_from_text, row_cell};
+use crate::DatabaseManager;
+
+const DEFAULT_PAGE_LIMIT: usize = 100;
+const MAX_PAGE_LIMIT: usize = 1000;
+/// The max size of a request body. The bodies only carry the cells of a row or a field.
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+pub(crate) struct LocalServerState {
+  pub(crate) manager: Weak<DatabaseManager>,
+  pub(crate) token: String,
+}
+
+impl LocalServerState {
+  /// The token is compared in constant time, so the time of the response doesn't tell how much
+  /// of a guessed token is right.
+  fn is_authorized(&self, request: &Request<Body>) -> bool {
+    request
+      .headers()
+      .get(AUTHORIZATION)
+      .and_then(|value| value.to_str().ok())
+      .and_then(|value| value.strip_prefix("Bearer "))
+      .map_or(false, |token| {
+        token.as_bytes().ct_eq(self.token.as_bytes()).into()
+      })
+  }
+}
+
+#[derive(Serialize)]
+struct FieldJson {
+  id: String,
+  name: String,
+  field_type: FieldType,
+  is_primary: bool,
+}
+
+impl From<&Field> for FieldJson {
+  fn from(field: &Field) -> Self {
+    Self {
+      id: field.id.clone(),
+      name: field.name.clone(),
+      field_type: FieldType::from(field.field_type),
+      is_primary: field.is_primary,
+    }
+  }
+}
+
+/// A row with its cells as text, keyed by field id.
+#[derive(Serialize)]
+struct RowJson {
+  id: String,
+  created_at: i64,
+  modified_at: i64,
+  cells: HashMap<String, String>,
+}
+
+impl RowJson {
+  fn new(row: &Row, fields: &[Field]) -> Self {
+    let cells = fields
+      .iter()
+      .filter_map(|field| {
+        let text = if should_mask_field(field, false) {
+          MASKED_CELL_VALUE.to_string()
+        } else {
+          stringify_cell(&row_cell(row, field)?, field)
+        };
+        Some((field.id.clone(), text))
+      })
+      .collect();
+    Self {
+      id: row.id.to_string(),
+      created_at: row.created_at,
+      modified_at: row.modified_at,
+      cells,
+    }
+  }
+}
+
+#[derive(Deserialize)]
+struct CreateFieldBody {
+  name: String,
+  field_type: FieldType,
+}
+
+#[derive(Deserialize)]
+struct UpdateFieldBody {
+  name: String,
+}
+
+/// The cells of a created or updated row as text, keyed by the id or the name of their field.
+#[derive(Deserialize)]
+struct RowBody {
+  #[serde(default)]
+  cells: HashMap<String, String>,
+}
+
+pub(crate) async fn handle_request(
+  state: Arc<LocalServerState>,
+  request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+  // The browsers send the preflight requests of the extensions without the token
+  if request.method() == Method::OPTIONS {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = StatusCode::NO_CONTENT;
+    insert_cors_headers(response.headers_mut());
+    return Ok(response);
+  }
+  if !state.is_authorized(&request) {
+    return Ok(error_response(
+      StatusCode::UNAUTHORIZED,
+      &FlowyError::new(ErrorCode::UserUnauthorized, "Missing or invalid token"),
+    ));
+  }
+
+  let response = match route(&state, request).await {
+    Ok(value) => json_response(StatusCode::OK, value),
+    Err(err) => error_response(status_of(&err), &err),
+  };
+  Ok(response)
+}
+
+async fn route(state: &LocalServerState, request: Request<Body>) -> FlowyResult<Value> {
+  let manager = state
+    .manager
+    .upgrade()
+    .ok_or_else(|| FlowyError::internal().with_context("The database manager is dropped"))?;
+  let method = request.method().clone();
+  let path = request.uri().path().to_string();
+  let query = request
+    .uri()
+    .query()
+    .map(|query| {
+      url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .collect::<HashMap<_, _>>()
+    })
+    .unwrap_or_default();
+  let segments = path
+    .split('/')
+    .filter(|segment| !segment.is_empty())
+    .collect::<Vec<_>>();
+  let ["views", view_id, rest @ ..] = segments.as_slice() else {
+    return Err(unknown_route(&method, &path));
+  };
+  let editor = manager.get_database_editor_with_view_id(view_id).await?;
+
+  match (&method, rest) {
+    (&Method::GET, ["fields"]) => get_fields(&editor, view_id).await,
+    (&Method::POST, ["fields"]) => create_field(&editor, view_id, read_body(request).await?).await,
+    (&Method::PATCH, ["fields", field_id]) => {
+      update_field(&editor, view_id, field_id, read_body(request).await?).await
+    },
+    (&Method::DELETE, ["fields", field_id]) => delete_field(&editor, field_id).await,
+    (&Method::GET, ["rows"]) => get_rows(&editor, view_id, &query).await,
+    (&Method::POST, ["rows"]) => create_row(&editor, view_id, read_body(request).await?).await,
+    (&Method::GET, ["rows", row_id]) => get_row(&editor, view_id, row_id).await,
+    (&Method::PATCH, ["rows", row_id]) => {
+      update_row(&editor, view_id, row_id, read_body(request).await?).await
+    },
+    (&Method::DELETE, ["rows", row_id]) => delete_row(&editor, view_id, row_id).await,
+    _ => Err(unknown_route(&method, &path)),
+  }
+}
+
+async fn get_fields(editor: &DatabaseEditor, view_id: &str) -> FlowyResult<Value> {
+  let fields = editor.get_fields(view_id, None).await;
+  to_json(fields.iter().map(FieldJson::from).collect::<Vec<_>>())
+}
+
+async fn create_field(
+  editor: &DatabaseEditor,
+  view_id: &str,
+  body: CreateFieldBody,
+) -> FlowyResult<Value> {
+  let field = editor
+    .create_field_with_type_option(CreateFieldParams {
+      view_id: view_id.to_string(),
+      field_name: Some(body.name),
+      field_type: body.field_type,
+      type_option_data: None,
+      position: OrderObjectPosition::default(),
+    })
+    .await?;
+  let field = editor
+    .get_field(&field.id)
+    .await
+    .ok_or_else(FlowyError::field_record_not_found)?;
+  to_json(FieldJson::from(&field))
+}
+
+async fn update_field(
+  editor: &DatabaseEditor,
+  view_id: &str,
+  field_id: &str,
+  body: UpdateFieldBody,
+) -> FlowyResult<Value> {
+  find_field(editor, field_id).await?;
+  editor
+    .update_field(FieldChangesetPB {
+      field_id: field_id.to_string(),
+      view_id: view_id.to_string(),
+      name: Some(body.name),
+      ..Default::default()
+    })
+    .await?;
+  to_json(FieldJson::from(&find_field(editor, field_id).await?))
+}
+
+async fn delete_field(editor: &DatabaseEditor, field_id: &str) -> FlowyResult<Value> {
+  find_field(editor, field_id).await?;
+  editor.delete_field(field_id).await?;
+  Ok(json!({ "id": field_id }))
+}
+
+/// Returns a page of the rows of the view that match the `filter` query parameter, in the order
+/// of the rows in the view. The filters and the sorts of the view don't apply.
+async fn get_rows(
+  editor: &DatabaseEditor,
+  view_id: &str,
+  query: &HashMap<String, String>,
+) -> FlowyResult<Value> {
+  let offset = query_param(query, "offset")?.unwrap_or(0);
+  let limit = query_param(query, "limit")?
+    .unwrap_or(DEFAULT_PAGE_LIMIT)
+    .clamp(1, MAX_PAGE_LIMIT);
+  let filter = query.get("filter").map(String::as_str).unwrap_or_default();
+
+  let rows = editor.find_rows(view_id, filter, vec![]).await?;
+  let fields = editor.get_fields(view_id, None).await;
+  let items = rows
+    .iter()
+    .skip(offset)
+    .take(limit)
+    .map(|row| RowJson::new(row, &fields))
+    .collect::<Vec<_>>();
+  Ok(json!({ "rows": items, "total_count": rows.len() }))
+}
+
+async fn get_row(editor: &DatabaseEditor, view_id: &str, row_id: &str) -> FlowyResult<Value> {
+  let row = find_row(editor, view_id, row_id).await?;
+  let fields = editor.get_fields(view_id, None).await;
+  to_json(RowJson::new(&row, &fields))
+}
+
+async fn create_row(editor: &DatabaseEditor, view_id: &str, body: RowBody) -> FlowyResult<Value> {
+  let fields = editor.get_fields(view_id, None).await;
+  let cells = cells_from_texts(&fields, body.cells)?
+    .into_iter()
+    .filter_map(|(field_id, cell)| Some((field_id, cell?)))
+    .collect();
+  let params = CreateRowPayloadPB {
+    view_id: view_id.to_string(),
+    ..Default::default()
+  };
+  let row_detail = editor
+    .create_row_with_cells(params, cells)
+    .await?
+    .ok_or_else(|| FlowyError::internal().with_context("Failed to create the row"))?;
+  to_json(RowJson::new(&row_detail.row, &fields))
+}
+
+/// Updates the cells of the row in a single write, so the row is left untouched if any of the
+/// cells is invalid. An empty text clears the cell.
+async fn update_row(
+  editor: &DatabaseEditor,
+  view_id: &str,
+  row_id: &str,
+  body: RowBody,
+) -> FlowyResult<Value> {
+  let row = find_row(editor, view_id, row_id).await?;
+  let fields = editor.get_fields(view_id, None).await;
+  let cells = cells_from_texts(&fields, body.cells)?
+    .into_iter()
+    .collect::<HashMap<_, _>>();
+  if !cells.is_empty() {
+    editor
+      .write_cells(view_id, IndexMap::from([(row.id, cells)]))
+      .await?;
+  }
+  get_row(editor, view_id, row_id).await
+}
+
+async fn delete_row(editor: &DatabaseEditor, view_id: &str, row_id: &str) -> FlowyResult<Value> {
+  editor.check_writable()?;
+  let row = find_row(editor, view_id, row_id).await?;
+  editor.delete_rows(&[row.id]).await?;
+  Ok(json!({ "id": row_id }))
+}
+
+async fn find_field(editor: &DatabaseEditor, field_id: &str) -> FlowyResult<Field> {
+  editor.get_field(field_id).await.ok_or_else(|| {
+    FlowyError::field_record_not_found().with_context(format!("Can't find the field: {}", field_id))
+  })
+}
+
+async fn find_row(editor: &DatabaseEditor, view_id: &str, row_id: &str) -> FlowyResult<Row> {
+  editor
+    .get_row(view_id, &RowId::from(row_id.to_string()))
+    .await
+    .ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Can't find the row: {}", row_id))
+    })
+}
+
+/// Converts the texts, keyed by the id or the name of their field, to the cells of the fields
+/// like the values of an appended CSV file. The cell is None if the text is empty.
+///
+/// The rows are returned with the cells of the sensitive fields masked, so the masked value is
+/// skipped for these fields. Otherwise sending back a row that was read would overwrite its
+/// sensitive cells with the mask.
+fn cells_from_texts(
+  fields: &[Field],
+  texts: HashMap<String, String>,
+) -> FlowyResult<Vec<(String, Option<Cell>)>> {
+  let mut cells = vec![];
+  for (key, text) in texts {
+    let field = fields
+      .iter()
+      .find(|field| field.id == key)
+      .or_else(|| fields.iter().find(|field| field.name == key))
+      .ok_or_else(|| {
+        FlowyError::field_record_not_found().with_context(format!("Can't find the field: {}", key))
+      })?;
+    if should_mask_field(field, false) && text == MASKED_CELL_VALUE {
+      continue;
+    }
+    let cell = cell_from_text(&text, field);
+    if cell.is_none() && !text.trim().is_empty() {
+      return Err(FlowyError::invalid_data().with_context(format!(
+        "Can't convert {} to a cell of {}",
+        text, field.name
+      )));
+    }
+    cells.push((field.id.clone(), cell));
+  }
+  Ok(cells)
+}
+
+fn query_param(query: &HashMap<String, String>, name: &str) -> FlowyResult<Option<usize>> {
+  query
+    .get(name)
+    .map(|value| {
+      value.parse::<usize>().map_err(|_| {
+        FlowyError::invalid_data().with_context(format!("Invalid {}: {}", name, value))
+      })
+    })
+    .transpose()
+}
+
+/// Reads the JSON body of the request. The body is read up to [MAX_BODY_SIZE], a larger body is
+/// rejected without being read to the end.
+async fn read_body<T: DeserializeOwned>(request: Request<Body>) -> FlowyResult<T> {
+  let too_large = || {
+    FlowyError::new(
+      ErrorCode::PayloadTooLarge,
+      format!("The body is larger than {} bytes", MAX_BODY_SIZE),
+    )
+  };
+  let content_length = request
+    .headers()
+    .get(CONTENT_LENGTH)
+    .and_then(|value| value.to_str().ok())
+    .and_then(|value| value.parse::<usize>().ok());
+  if content_length.is_some_and(|len| len > MAX_BODY_SIZE) {
+    return Err(too_large());
+  }
+
+  let mut body = request.into_body();
+  let mut bytes = vec![];
+  while let Some(chunk) = body.data().await {
+    let chunk = chunk.map_err(|err| FlowyError::invalid_data().with_context(err))?;
+    if bytes.len() + chunk.len() > MAX_BODY_SIZE {
+      return Err(too_large());
+    }
+    bytes.extend_from_slice(&chunk);
+  }
+  serde_json::from_slice(&bytes).map_err(|err| FlowyError::invalid_data().with_context(err))
+}
+
+fn to_json<T: Serialize>(value: T) -> FlowyResult<Value> {
+  serde_json::to_value(value).map_err(|err| FlowyError::internal().with_context(err))
+}
+
+fn unknown_route(method: &Method, path: &str) -> FlowyError {
+  FlowyError::record_not_found().with_context(format!("Unknown route: {} {}", method, path))
+}
+
+fn status_of(err: &FlowyError) -> StatusCode {
+  match err.code {
+    ErrorCode::RecordNotFound | ErrorCode::FieldRecordNotFound => StatusCode::NOT_FOUND,
+    ErrorCode::DatabaseReadOnly | ErrorCode::RowLocked => StatusCode::FORBIDDEN,
+    ErrorCode::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+    ErrorCode::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+    _ => StatusCode::BAD_REQUEST,
+  }
+}
+
+fn error_response(status: StatusCode, err: &FlowyError) -> Response<Body> {
+  json_response(
+    status,
+    json!({ "code": err.code.value(), "message": err.msg }),
+  )
+}
+
+fn json_response(status: StatusCode, value: Value) -> Response<Body> {
+  let mut response = Response::new(Body::from(value.to_string()));
+  *response.status_mut() = status;
+  let headers = response.headers_mut();
+  headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+  insert_cors_headers(headers);
+  response
+}
+
+/// The server only listens on the loopback interface and every request needs the token, so the
+/// pages of any origin, like the ones of the browser extensions, are allowed to call it.
+fn insert_cors_headers(headers: &mut HeaderMap) {
+  headers.insert(ACCESS_CONTROL_ALLOW_ORIGIN, HeaderValue::from_static("*"));
+  headers.insert(
+    ACCESS_CONTROL_ALLOW_METHODS,
+    HeaderValue::from_static("GET, POST, PATCH, DELETE, OPTIONS"),
+  );
+  headers.insert(
+    ACCESS_CONTROL_ALLOW_HEADERS,
+    HeaderValue::from_static("Authorization, Content-Type"),
+  );
+}
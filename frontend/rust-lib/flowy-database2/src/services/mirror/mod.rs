@@ -0,0 +1,42 @@
+mod postgres;
+
+pub use postgres::*;
+
+use async_trait::async_trait;
+use flowy_error::{internal_error, FlowyResult};
+
+use crate::services::share::csv::CSVSource;
+
+/// The rows pulled from an external table. Each column is named after the field it's mapped to,
+/// and the values are converted to the type of the field like the values of an appended CSV
+/// file.
+#[derive(Debug, Clone, Default)]
+pub struct MirrorTable {
+  pub columns: Vec<String>,
+  /// The index of the column that identifies a row, so the rows that are pulled again update the
+  /// rows of the grid instead of replacing them.
+  pub key_column: usize,
+  pub rows: Vec<Vec<String>>,
+}
+
+impl MirrorTable {
+  pub fn to_csv_source(&self) -> FlowyResult<CSVSource> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    writer.write_record(&self.columns).map_err(internal_error)?;
+    for row in &self.rows {
+      writer.write_record(row).map_err(internal_error)?;
+    }
+    let data = writer.into_inner().map_err(internal_error)?;
+    Ok(CSVSource::Bytes(data))
+  }
+}
+
+/// Pulls the rows of an external table, e.g. a table of a Postgres database, into a grid that
+/// mirrors it. The grid is read-only while it's mirrored, see [DatabaseManager::start_mirror].
+///
+/// [DatabaseManager::start_mirror]: crate::DatabaseManager::start_mirror
+#[async_trait]
+pub trait MirrorConnector: Send + Sync + 'static {
+  /// Returns all the rows of the external table.
+  async fn pull(&self) -> FlowyResult<MirrorTable>;
+}
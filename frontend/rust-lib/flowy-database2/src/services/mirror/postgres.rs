@@ -0,0 +1,192 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use native_tls::TlsConnector;
+use postgres_native_tls::MakeTlsConnector;
+use tokio_postgres::config::SslMode;
+use tokio_postgres::Config;
+use tracing::error;
+
+use crate::services::mirror::{MirrorConnector, MirrorTable};
+
+/// Where to pull the rows of a mirrored grid from.
+#[derive(Debug, Clone)]
+pub struct PostgresMirrorConfig {
+  /// The connection string, e.g. `host=db.example.com user=postgres dbname=crm`. The connection
+  /// must be encrypted with TLS unless the string sets another `sslmode`, e.g. `sslmode=disable`
+  /// for a local server without TLS.
+  pub connection: String,
+  /// The table or view to pull the rows from, optionally qualified by its schema.
+  pub table: String,
+  /// The column that identifies a row, usually the primary key of the table. It must be one of
+  /// the mapped columns.
+  pub key_column: String,
+  /// The pulled columns, each with the name of the field it's mapped to.
+  pub columns: Vec<PostgresMirrorColumn>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresMirrorColumn {
+  pub column: String,
+  pub field_name: String,
+}
+
+/// Pulls the rows of a Postgres table with a new connection each time. The values are read as
+/// text, so the columns can be of any type that Postgres can cast to text.
+pub struct PostgresMirrorConnector {
+  config: PostgresMirrorConfig,
+  connection_config: Config,
+}
+
+impl PostgresMirrorConnector {
+  pub fn new(config: PostgresMirrorConfig) -> FlowyResult<Self> {
+    if config.columns.is_empty() {
+      return Err(FlowyError::invalid_data().with_context("No column is mapped to a field"));
+    }
+    if !config
+      .columns
+      .iter()
+      .any(|column| column.column == config.key_column)
+    {
+      return Err(
+        FlowyError::invalid_data().with_context("The key column isn't mapped to a field"),
+      );
+    }
+    let connection_config = connection_config(&config.connection)?;
+    Ok(Self {
+      config,
+      connection_config,
+    })
+  }
+
+  fn query(&self) -> String {
+    let columns = self
+      .config
+      .columns
+      .iter()
+      .map(|column| format!("{}::text", quote_identifier(&column.column)))
+      .collect::<Vec<_>>()
+      .join(", ");
+    let table = self
+      .config
+      .table
+      .split('.')
+      .map(quote_identifier)
+      .collect::<Vec<_>>()
+      .join(".");
+    format!(
+      "SELECT {} FROM {} ORDER BY {}",
+      columns,
+      table,
+      quote_identifier(&self.config.key_column)
+    )
+  }
+}
+
+#[async_trait]
+impl MirrorConnector for PostgresMirrorConnector {
+  async fn pull(&self) -> FlowyResult<MirrorTable> {
+    let tls = TlsConnector::new()
+      .map(MakeTlsConnector::new)
+      .map_err(internal_error)?;
+    let (client, connection) = self
+      .connection_config
+      .connect(tls)
+      .await
+      .map_err(|err| FlowyError::internal().with_context(format!("Can't connect: {}", err)))?;
+    tokio::spawn(async move {
+      if let Err(err) = connection.await {
+        error!("[Database]: postgres mirror connection error: {}", err);
+      }
+    });
+
+    let rows = client
+      .query(&self.query(), &[])
+      .await
+      .map_err(internal_error)?;
+    let rows = rows
+      .iter()
+      .map(|row| {
+        (0..self.config.columns.len())
+          .map(|index| {
+            row
+              .try_get::<_, Option<String>>(index)
+              .map(|value| value.unwrap_or_default())
+          })
+          .collect::<Result<Vec<_>, _>>()
+      })
+      .collect::<Result<Vec<_>, _>>()
+      .map_err(internal_error)?;
+    Ok(MirrorTable {
+      columns: self
+        .config
+        .columns
+        .iter()
+        .map(|column| column.field_name.clone())
+        .collect(),
+      key_column: self
+        .config
+        .columns
+        .iter()
+        .position(|column| column.column == self.config.key_column)
+        .unwrap_or_default(),
+      rows,
+    })
+  }
+}
+
+/// Parses the connection string. The connection requires TLS unless the string sets the
+/// `sslmode` itself, since the default of Postgres falls back to a plain connection silently.
+fn connection_config(connection: &str) -> FlowyResult<Config> {
+  let mut config = Config::from_str(connection).map_err(|err| {
+    FlowyError::invalid_data().with_context(format!("Invalid connection string: {}", err))
+  })?;
+  if !connection.contains("sslmode") {
+    config.ssl_mode(SslMode::Require);
+  }
+  Ok(config)
+}
+
+fn quote_identifier(identifier: &str) -> String {
+  format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn query_test() {
+    let column = |column: &str, field_name: &str| PostgresMirrorColumn {
+      column: column.to_string(),
+      field_name: field_name.to_string(),
+    };
+    let config = PostgresMirrorConfig {
+      connection: "host=localhost".to_string(),
+      table: "sales.deals".to_string(),
+      key_column: "id".to_string(),
+      columns: vec![column("id", "Id"), column("amount \"usd\"", "Amount")],
+    };
+    let connector = PostgresMirrorConnector::new(config.clone()).unwrap();
+    assert_eq!(
+      connector.query(),
+      r#"SELECT "id"::text, "amount ""usd"""::text FROM "sales"."deals" ORDER BY "id""#
+    );
+
+    let config = PostgresMirrorConfig {
+      key_column: "missing".to_string(),
+      ..config
+    };
+    assert!(PostgresMirrorConnector::new(config).is_err());
+  }
+
+  #[test]
+  fn connection_config_test() {
+    let config = connection_config("host=db.example.com user=postgres").unwrap();
+    assert_eq!(config.get_ssl_mode(), SslMode::Require);
+    let config = connection_config("postgresql://postgres@localhost/crm?sslmode=disable").unwrap();
+    assert_eq!(config.get_ssl_mode(), SslMode::Disable);
+    assert!(connection_config("host=localhost port=not_a_port").is_err());
+  }
+}
@@ -1,12 +1,20 @@
+pub mod automation;
 pub mod calculations;
 pub mod cell;
+pub mod change_log;
 pub mod database;
 pub mod database_view;
 pub mod field;
 pub mod field_settings;
 pub mod filter;
 pub mod group;
+pub mod local_server;
+pub mod mirror;
+pub mod row_comment;
+pub mod row_document;
+pub mod row_lock;
 pub mod setting;
 pub mod share;
 pub mod snapshot;
 pub mod sort;
+pub mod webhook;
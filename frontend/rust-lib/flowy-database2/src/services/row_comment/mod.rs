@@ -0,0 +1,118 @@
+use collab::preclude::Any;
+use collab::util::AnyMapExt;
+use collab_database::rows::{Cell, Row};
+use lib_infra::util::timestamp;
+use nanoid::nanoid;
+
+/// The key of the comments in the cells of a row. It's never the id of a field, so the comments
+/// don't show up in any field and are skipped by the exports, which only read the cells of the
+/// fields.
+pub const ROW_COMMENTS: &str = "row_comments";
+const COMMENTS: &str = "comments";
+const COMMENT_ID: &str = "id";
+const COMMENT_AUTHOR_ID: &str = "author_id";
+const COMMENT_AUTHOR_NAME: &str = "author_name";
+const COMMENT_CREATED_AT: &str = "created_at";
+const COMMENT_TEXT: &str = "text";
+
+/// A comment on a row. The comments of a row are stored in the row itself, so they're synced
+/// with the row and deleted with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowComment {
+  pub id: String,
+  pub author_id: i64,
+  pub author_name: String,
+  /// The time the comment was added, in seconds.
+  pub created_at: i64,
+  pub text: String,
+}
+
+impl RowComment {
+  pub fn new(author_id: i64, author_name: String, text: String) -> Self {
+    Self {
+      id: nanoid!(6),
+      author_id,
+      author_name,
+      created_at: timestamp(),
+      text,
+    }
+  }
+}
+
+/// The comments added to and deleted from a row by an edit.
+#[derive(Debug, Clone, Default)]
+pub struct RowCommentChangeset {
+  pub row_id: String,
+  pub inserted_comments: Vec<RowComment>,
+  pub deleted_comment_ids: Vec<String>,
+}
+
+/// Returns the comments of the row, oldest first.
+pub fn row_comments(row: &Row) -> Vec<RowComment> {
+  let Some(Any::Array(items)) = row
+    .cells
+    .get(ROW_COMMENTS)
+    .and_then(|cell| cell.get(COMMENTS))
+  else {
+    return vec![];
+  };
+  items
+    .iter()
+    .filter_map(|item| match item {
+      Any::Map(map) => Some(RowComment {
+        id: map.get_as::<String>(COMMENT_ID)?,
+        author_id: map.get_as::<i64>(COMMENT_AUTHOR_ID).unwrap_or_default(),
+        author_name: map
+          .get_as::<String>(COMMENT_AUTHOR_NAME)
+          .unwrap_or_default(),
+        created_at: map.get_as::<i64>(COMMENT_CREATED_AT).unwrap_or_default(),
+        text: map.get_as::<String>(COMMENT_TEXT).unwrap_or_default(),
+      }),
+      _ => None,
+    })
+    .collect()
+}
+
+/// Builds the cell that stores the comments in the row, see [ROW_COMMENTS].
+pub fn row_comments_cell(comments: &[RowComment]) -> Cell {
+  let items = comments
+    .iter()
+    .map(|comment| {
+      let mut map = Cell::new();
+      map.insert(COMMENT_ID.into(), comment.id.as_str().into());
+      map.insert(COMMENT_AUTHOR_ID.into(), Any::BigInt(comment.author_id));
+      map.insert(
+        COMMENT_AUTHOR_NAME.into(),
+        comment.author_name.as_str().into(),
+      );
+      map.insert(COMMENT_CREATED_AT.into(), Any::BigInt(comment.created_at));
+      map.insert(COMMENT_TEXT.into(), comment.text.as_str().into());
+      Any::from(map)
+    })
+    .collect::<Vec<Any>>();
+  let mut cell = Cell::new();
+  cell.insert(COMMENTS.into(), Any::from(items));
+  cell
+}
+
+#[cfg(test)]
+mod tests {
+  use collab_database::database::gen_row_id;
+
+  use super::*;
+
+  #[test]
+  fn row_comments_round_trip_test() {
+    let mut row = Row::new(gen_row_id(), "database");
+    assert!(row_comments(&row).is_empty());
+
+    let comments = vec![
+      RowComment::new(1, "Lucas".to_string(), "First".to_string()),
+      RowComment::new(2, "Nathan".to_string(), "Second".to_string()),
+    ];
+    row
+      .cells
+      .insert(ROW_COMMENTS.to_string(), row_comments_cell(&comments));
+    assert_eq!(row_comments(&row), comments);
+  }
+}
@@ -0,0 +1,60 @@
+use std::sync::{Arc, RwLock};
+
+use flowy_error::{FlowyError, FlowyResult};
+use lib_infra::async_trait::async_trait;
+use tracing::error;
+
+/// Opens and deletes the documents of the rows. The documents are managed by the document
+/// manager, which isn't a dependency of the database, so the app registers its implementation
+/// after both managers are created, see [crate::DatabaseManager::set_row_document_service].
+#[async_trait]
+pub trait RowDocumentService: Send + Sync {
+  /// Opens the document, creating an empty one first if it doesn't exist yet.
+  async fn open_or_create_document(&self, document_id: &str) -> FlowyResult<()>;
+
+  async fn delete_document(&self, document_id: &str) -> FlowyResult<()>;
+}
+
+/// The documents of the rows, shared by the manager and every database editor it opens.
+///
+/// A row has a document id from the start, but its document is only created the first time the
+/// row is opened as a page, so most rows never have one.
+#[derive(Clone, Default)]
+pub struct RowDocuments {
+  service: Arc<RwLock<Option<Arc<dyn RowDocumentService>>>>,
+}
+
+impl RowDocuments {
+  pub fn set_service(&self, service: Arc<dyn RowDocumentService>) {
+    if let Ok(mut current) = self.service.write() {
+      *current = Some(service);
+    }
+  }
+
+  fn service(&self) -> Option<Arc<dyn RowDocumentService>> {
+    self.service.read().ok().and_then(|service| service.clone())
+  }
+
+  pub async fn open(&self, document_id: &str) -> FlowyResult<()> {
+    let service = self.service().ok_or_else(|| {
+      FlowyError::not_support().with_context("The documents of the rows aren't supported")
+    })?;
+    service.open_or_create_document(document_id).await
+  }
+
+  /// Deletes the documents of the deleted rows. The rows without a document are skipped by the
+  /// document manager.
+  pub async fn delete(&self, document_ids: Vec<String>) {
+    let Some(service) = self.service() else {
+      return;
+    };
+    for document_id in document_ids {
+      if let Err(err) = service.delete_document(&document_id).await {
+        error!(
+          "[Database]: failed to delete the row document {}: {}",
+          document_id, err
+        );
+      }
+    }
+  }
+}
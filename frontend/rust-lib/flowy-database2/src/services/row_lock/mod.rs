@@ -0,0 +1,42 @@
+use collab::preclude::Any;
+use collab_database::rows::{Cell, Row};
+
+/// The key of the lock in the cells of a row. Like the comments of a row, see
+/// [crate::services::row_comment::ROW_COMMENTS], it's never the id of a field, so the lock is
+/// synced with the row without showing up in any field.
+pub const ROW_LOCK: &str = "row_lock";
+const LOCKED: &str = "locked";
+
+/// Returns true if the row is locked. The cells of a locked row can't be edited and the row can't
+/// be deleted until it's unlocked.
+pub fn is_row_locked(row: &Row) -> bool {
+  matches!(
+    row.cells.get(ROW_LOCK).and_then(|cell| cell.get(LOCKED)),
+    Some(Any::Bool(true))
+  )
+}
+
+/// Builds the cell that stores the lock in the row, see [ROW_LOCK].
+pub fn row_lock_cell(locked: bool) -> Cell {
+  let mut cell = Cell::new();
+  cell.insert(LOCKED.into(), Any::Bool(locked));
+  cell
+}
+
+#[cfg(test)]
+mod tests {
+  use collab_database::database::gen_row_id;
+
+  use super::*;
+
+  #[test]
+  fn row_lock_test() {
+    let mut row = Row::new(gen_row_id(), "database");
+    assert!(!is_row_locked(&row));
+
+    row.cells.insert(ROW_LOCK.to_string(), row_lock_cell(true));
+    assert!(is_row_locked(&row));
+    row.cells.insert(ROW_LOCK.to_string(), row_lock_cell(false));
+    assert!(!is_row_locked(&row));
+  }
+}
@@ -0,0 +1,139 @@
+use collab::preclude::Any;
+use collab_database::views::LayoutSetting;
+
+const DEFAULT_VIEW_ID: &str = "default_view_id";
+const VIEW_ORDER: &str = "view_order";
+
+/// The view that opens by default and the display order of the views of a database.
+///
+/// The setting belongs to the database rather than to one of its views, so it's stored in the
+/// grid layout settings of the inline view, next to the row color rules of that view.
+#[derive(Debug, Default, Clone)]
+pub struct DatabaseViewsSetting {
+  pub default_view_id: Option<String>,
+  pub view_order: Vec<String>,
+}
+
+impl DatabaseViewsSetting {
+  /// Returns the id of the default view. Falls back to the inline view if no view was set as the
+  /// default or if the default view doesn't exist anymore.
+  pub fn default_view_id<'a>(&'a self, view_ids: &'a [String], inline_view_id: &'a str) -> &'a str {
+    self
+      .default_view_id
+      .as_deref()
+      .filter(|view_id| view_ids.iter().any(|id| id == view_id))
+      .unwrap_or(inline_view_id)
+  }
+
+  /// Orders the given view ids by the display order. The views that were removed are skipped and
+  /// the views that aren't part of the display order yet keep their given order at the end.
+  pub fn ordered_view_ids(&self, view_ids: &[String]) -> Vec<String> {
+    let mut ordered = self
+      .view_order
+      .iter()
+      .filter(|view_id| view_ids.contains(view_id))
+      .cloned()
+      .collect::<Vec<_>>();
+    for view_id in view_ids {
+      if !ordered.contains(view_id) {
+        ordered.push(view_id.clone());
+      }
+    }
+    ordered
+  }
+
+  /// Moves the view to the given index of the display order. Returns false if the view isn't one
+  /// of the given views.
+  pub fn move_view(&mut self, view_ids: &[String], view_id: &str, to_index: usize) -> bool {
+    let mut ordered = self.ordered_view_ids(view_ids);
+    match ordered.iter().position(|id| id == view_id) {
+      None => false,
+      Some(from_index) => {
+        let view_id = ordered.remove(from_index);
+        ordered.insert(to_index.min(ordered.len()), view_id);
+        self.view_order = ordered;
+        true
+      },
+    }
+  }
+
+  /// Writes the setting into the given layout setting, keeping the other keys of it.
+  pub fn write_to(&self, setting: &mut LayoutSetting) {
+    setting.insert(
+      DEFAULT_VIEW_ID.into(),
+      self.default_view_id.clone().unwrap_or_default().into(),
+    );
+    let view_order = self
+      .view_order
+      .iter()
+      .map(|view_id| Any::from(view_id.as_str()))
+      .collect::<Vec<_>>();
+    setting.insert(VIEW_ORDER.into(), Any::from(view_order));
+  }
+}
+
+impl From<LayoutSetting> for DatabaseViewsSetting {
+  fn from(setting: LayoutSetting) -> Self {
+    let mut views_setting = Self::default();
+    if let Any::Map(setting) = Any::from(setting) {
+      if let Some(Any::String(view_id)) = setting.get(DEFAULT_VIEW_ID) {
+        if !view_id.is_empty() {
+          views_setting.default_view_id = Some(view_id.to_string());
+        }
+      }
+      if let Some(Any::Array(items)) = setting.get(VIEW_ORDER) {
+        views_setting.view_order = items
+          .iter()
+          .filter_map(|item| match item {
+            Any::String(view_id) => Some(view_id.to_string()),
+            _ => None,
+          })
+          .collect();
+      }
+    }
+    views_setting
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn view_ids(ids: &[&str]) -> Vec<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+  }
+
+  #[test]
+  fn ordered_view_ids_skips_removed_and_appends_new_views() {
+    let setting = DatabaseViewsSetting {
+      default_view_id: None,
+      view_order: view_ids(&["c", "removed", "a"]),
+    };
+    assert_eq!(
+      setting.ordered_view_ids(&view_ids(&["a", "b", "c"])),
+      view_ids(&["c", "a", "b"])
+    );
+  }
+
+  #[test]
+  fn move_view_test() {
+    let views = view_ids(&["a", "b", "c"]);
+    let mut setting = DatabaseViewsSetting::default();
+    assert!(setting.move_view(&views, "a", 2));
+    assert_eq!(setting.view_order, view_ids(&["b", "c", "a"]));
+    assert!(setting.move_view(&views, "c", 0));
+    assert_eq!(setting.view_order, view_ids(&["c", "b", "a"]));
+    assert!(!setting.move_view(&views, "d", 0));
+  }
+
+  #[test]
+  fn default_view_falls_back_to_inline_view() {
+    let views = view_ids(&["a", "b"]);
+    let mut setting = DatabaseViewsSetting::default();
+    assert_eq!(setting.default_view_id(&views, "a"), "a");
+    setting.default_view_id = Some("b".to_string());
+    assert_eq!(setting.default_view_id(&views, "a"), "b");
+    setting.default_view_id = Some("removed".to_string());
+    assert_eq!(setting.default_view_id(&views, "a"), "a");
+  }
+}
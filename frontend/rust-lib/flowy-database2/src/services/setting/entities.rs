@@ -90,6 +90,12 @@ pub struct BoardLayoutSetting {
   pub hide_ungrouped_column: bool,
   #[serde(default)]
   pub collapse_hidden_groups: bool,
+  /// The id of the field whose content is displayed as the cover of each card. Empty if the
+  /// cards don't have a cover.
+  #[serde(default)]
+  pub card_cover_field_id: String,
+  #[serde(default)]
+  pub card_size: CardSize,
 }
 
 impl BoardLayoutSetting {
@@ -115,6 +121,37 @@ impl From<BoardLayoutSetting> for LayoutSetting {
         "collapse_hidden_groups".into(),
         setting.collapse_hidden_groups.into(),
       ),
+      (
+        "card_cover_field_id".into(),
+        setting.card_cover_field_id.into(),
+      ),
+      ("card_size".into(), Any::BigInt(setting.card_size.value())),
     ])
   }
 }
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum CardSize {
+  Small = 0,
+  #[default]
+  Medium = 1,
+  Large = 2,
+}
+
+impl From<i64> for CardSize {
+  fn from(value: i64) -> Self {
+    match value {
+      0 => CardSize::Small,
+      1 => CardSize::Medium,
+      2 => CardSize::Large,
+      _ => CardSize::Medium,
+    }
+  }
+}
+
+impl CardSize {
+  pub fn value(&self) -> i64 {
+    *self as i64
+  }
+}
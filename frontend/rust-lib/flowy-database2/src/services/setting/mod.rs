@@ -1,3 +1,13 @@
+mod database_views;
 mod entities;
+mod row_color;
+mod row_template;
+mod row_trash;
+mod view_settings;
 
+pub use database_views::*;
 pub use entities::*;
+pub use row_color::*;
+pub use row_template::*;
+pub use row_trash::*;
+pub use view_settings::*;
@@ -0,0 +1,106 @@
+use std::ops::Deref;
+
+use anyhow::bail;
+use collab::preclude::Any;
+use collab::util::AnyMapExt;
+use collab_database::views::{FilterMap, LayoutSetting, LayoutSettingBuilder};
+use tracing::error;
+
+use crate::services::filter::{Filter, FilterInner};
+
+const ROW_COLOR_RULES: &str = "row_color_rules";
+const ROW_COLOR: &str = "color";
+
+/// The rules that color the rows of a view. The rules are evaluated in order and a row takes the
+/// color of the first rule it matches.
+///
+/// The rules are stored in the layout settings of the grid layout, which doesn't have any settings
+/// of its own, so the rules of a view are kept when its layout changes.
+#[derive(Debug, Default)]
+pub struct RowColorSetting {
+  pub rules: Vec<RowColorRule>,
+}
+
+/// A rule that colors the rows matching its condition. The condition is a data filter, so a rule
+/// supports the same conditions as the filters of a view. The id of the rule is the id of its
+/// condition.
+#[derive(Debug)]
+pub struct RowColorRule {
+  pub color: String,
+  pub condition: Filter,
+}
+
+impl RowColorRule {
+  pub fn new(id: String, color: String, condition: FilterInner) -> Self {
+    Self {
+      color,
+      condition: Filter {
+        id,
+        inner: condition,
+      },
+    }
+  }
+
+  pub fn id(&self) -> &str {
+    &self.condition.id
+  }
+
+  /// Returns the id of the field the condition of the rule applies to.
+  pub fn field_id(&self) -> Option<&str> {
+    match &self.condition.inner {
+      FilterInner::Data { field_id, .. } => Some(field_id),
+      _ => None,
+    }
+  }
+}
+
+impl TryFrom<FilterMap> for RowColorRule {
+  type Error = anyhow::Error;
+
+  fn try_from(value: FilterMap) -> Result<Self, Self::Error> {
+    let color = value.get_as::<String>(ROW_COLOR).unwrap_or_default();
+    let condition = Filter::try_from(value)?;
+    if !matches!(condition.inner, FilterInner::Data { .. }) {
+      bail!("The condition of a row color rule must be a data filter")
+    }
+    Ok(Self { color, condition })
+  }
+}
+
+impl<'a> From<&'a RowColorRule> for FilterMap {
+  fn from(rule: &'a RowColorRule) -> Self {
+    let mut map = FilterMap::from(&rule.condition);
+    map.insert(ROW_COLOR.into(), rule.color.as_str().into());
+    map
+  }
+}
+
+impl From<LayoutSetting> for RowColorSetting {
+  fn from(setting: LayoutSetting) -> Self {
+    let mut rules = vec![];
+    if let Any::Map(setting) = Any::from(setting) {
+      if let Some(Any::Array(items)) = setting.get(ROW_COLOR_RULES) {
+        for item in items.iter() {
+          if let Any::Map(rule_map) = item {
+            match RowColorRule::try_from(rule_map.deref().clone()) {
+              Ok(rule) => rules.push(rule),
+              Err(err) => error!("Failed to deserialize row color rule: {:?}", err),
+            }
+          }
+        }
+      }
+    }
+    Self { rules }
+  }
+}
+
+impl From<&RowColorSetting> for LayoutSetting {
+  fn from(setting: &RowColorSetting) -> Self {
+    let rules = setting
+      .rules
+      .iter()
+      .map(|rule| FilterMap::from(rule).into())
+      .collect::<Vec<Any>>();
+    LayoutSettingBuilder::from([(ROW_COLOR_RULES.into(), Any::from(rules))])
+  }
+}
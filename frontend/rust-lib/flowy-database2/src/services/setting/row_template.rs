@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use collab::preclude::Any;
+use collab_database::views::LayoutSetting;
+
+const ROW_TEMPLATES: &str = "row_templates";
+const TEMPLATE_ID: &str = "id";
+const TEMPLATE_NAME: &str = "name";
+const TEMPLATE_CELLS: &str = "cells";
+const CELL_FIELD_ID: &str = "field_id";
+const CELL_VALUE: &str = "value";
+
+/// The row templates of a database. A template prefills the cells of the rows created from it,
+/// e.g. for the tasks that are created with the same shape over and over.
+///
+/// The templates belong to the database rather than to one of its views, so they're stored in the
+/// grid layout settings of the inline view, next to the views setting of the database.
+#[derive(Debug, Default, Clone)]
+pub struct RowTemplateSetting {
+  pub templates: Vec<RowTemplate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowTemplate {
+  pub id: String,
+  pub name: String,
+  pub cells: Vec<RowTemplateCell>,
+}
+
+/// A cell of a template. The value is converted to the type of the field like a value of an
+/// appended CSV file when a row is created from the template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowTemplateCell {
+  pub field_id: String,
+  pub value: String,
+}
+
+impl RowTemplateSetting {
+  pub fn template(&self, template_id: &str) -> Option<&RowTemplate> {
+    self
+      .templates
+      .iter()
+      .find(|template| template.id == template_id)
+  }
+
+  /// Writes the setting into the given layout setting, keeping the other keys of it.
+  pub fn write_to(&self, setting: &mut LayoutSetting) {
+    let templates = self
+      .templates
+      .iter()
+      .map(|template| {
+        let cells = template
+          .cells
+          .iter()
+          .map(|cell| {
+            Any::from(HashMap::from([
+              (CELL_FIELD_ID.to_string(), Any::from(cell.field_id.as_str())),
+              (CELL_VALUE.to_string(), Any::from(cell.value.as_str())),
+            ]))
+          })
+          .collect::<Vec<_>>();
+        Any::from(HashMap::from([
+          (TEMPLATE_ID.to_string(), Any::from(template.id.as_str())),
+          (TEMPLATE_NAME.to_string(), Any::from(template.name.as_str())),
+          (TEMPLATE_CELLS.to_string(), Any::from(cells)),
+        ]))
+      })
+      .collect::<Vec<_>>();
+    setting.insert(ROW_TEMPLATES.into(), Any::from(templates));
+  }
+}
+
+impl From<LayoutSetting> for RowTemplateSetting {
+  fn from(setting: LayoutSetting) -> Self {
+    let mut templates = vec![];
+    if let Any::Map(setting) = Any::from(setting) {
+      if let Some(Any::Array(items)) = setting.get(ROW_TEMPLATES) {
+        for item in items.iter() {
+          let Any::Map(template) = item else {
+            continue;
+          };
+          let Some(Any::String(id)) = template.get(TEMPLATE_ID) else {
+            continue;
+          };
+          let name = match template.get(TEMPLATE_NAME) {
+            Some(Any::String(name)) => name.to_string(),
+            _ => String::new(),
+          };
+          let cells = match template.get(TEMPLATE_CELLS) {
+            Some(Any::Array(cells)) => cells.iter().filter_map(template_cell).collect(),
+            _ => vec![],
+          };
+          templates.push(RowTemplate {
+            id: id.to_string(),
+            name,
+            cells,
+          });
+        }
+      }
+    }
+    Self { templates }
+  }
+}
+
+fn template_cell(item: &Any) -> Option<RowTemplateCell> {
+  let Any::Map(cell) = item else {
+    return None;
+  };
+  match (cell.get(CELL_FIELD_ID), cell.get(CELL_VALUE)) {
+    (Some(Any::String(field_id)), Some(Any::String(value))) => Some(RowTemplateCell {
+      field_id: field_id.to_string(),
+      value: value.to_string(),
+    }),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn row_templates_round_trip_test() {
+    let setting = RowTemplateSetting {
+      templates: vec![RowTemplate {
+        id: "t1".to_string(),
+        name: "Weekly review".to_string(),
+        cells: vec![RowTemplateCell {
+          field_id: "f1".to_string(),
+          value: "Review".to_string(),
+        }],
+      }],
+    };
+    let mut layout_setting = LayoutSetting::default();
+    layout_setting.insert("other".into(), Any::from("kept"));
+    setting.write_to(&mut layout_setting);
+
+    assert!(layout_setting.contains_key("other"));
+    let read = RowTemplateSetting::from(layout_setting);
+    assert_eq!(read.templates, setting.templates);
+    assert!(read.template("t1").is_some());
+    assert!(read.template("t2").is_none());
+  }
+}
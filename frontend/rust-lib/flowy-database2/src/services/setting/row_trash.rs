@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use collab::preclude::Any;
+use collab_database::views::LayoutSetting;
+
+const ROW_TRASH: &str = "row_trash";
+const TRASHED_ROW_ID: &str = "row_id";
+const TRASHED_DELETED_AT: &str = "deleted_at";
+const TRASHED_INDEXES: &str = "indexes";
+
+/// The number of days the deleted rows are kept in the trash before they're purged.
+pub const ROW_TRASH_RETENTION_DAYS: i64 = 30;
+
+/// The rows deleted from a database that can still be restored.
+///
+/// A deleted row is only removed from the row orders of the views, so its cells, its meta and its
+/// document are kept until the row is purged from the trash. The trash belongs to the database
+/// rather than to one of its views, so it's stored in the grid layout settings of the inline
+/// view, next to the views setting of the database.
+#[derive(Debug, Default, Clone)]
+pub struct RowTrash {
+  pub rows: Vec<TrashedRow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashedRow {
+  pub row_id: String,
+  /// The time the row was deleted, in seconds.
+  pub deleted_at: i64,
+  /// The index of the row in each view when it was deleted, keyed by view id, so the row is
+  /// restored at the same place.
+  pub indexes: HashMap<String, i64>,
+}
+
+impl RowTrash {
+  pub fn contains(&self, row_id: &str) -> bool {
+    self.rows.iter().any(|row| row.row_id == row_id)
+  }
+
+  /// Removes the given rows from the trash and returns them, in the order they were deleted.
+  pub fn take(&mut self, row_ids: &[String]) -> Vec<TrashedRow> {
+    let (taken, kept) = std::mem::take(&mut self.rows)
+      .into_iter()
+      .partition(|row| row_ids.contains(&row.row_id));
+    self.rows = kept;
+    taken
+  }
+
+  /// Removes the rows that were deleted more than [ROW_TRASH_RETENTION_DAYS] before `now`, in
+  /// seconds, and returns them.
+  pub fn take_expired(&mut self, now: i64) -> Vec<TrashedRow> {
+    let expires_before = now - ROW_TRASH_RETENTION_DAYS * 24 * 60 * 60;
+    let (expired, kept) = std::mem::take(&mut self.rows)
+      .into_iter()
+      .partition(|row| row.deleted_at < expires_before);
+    self.rows = kept;
+    expired
+  }
+
+  /// Writes the trash into the given layout setting, keeping the other keys of it.
+  pub fn write_to(&self, setting: &mut LayoutSetting) {
+    let rows = self
+      .rows
+      .iter()
+      .map(|row| {
+        let indexes = row
+          .indexes
+          .iter()
+          .map(|(view_id, index)| (view_id.clone(), Any::BigInt(*index)))
+          .collect::<HashMap<_, _>>();
+        Any::from(HashMap::from([
+          (TRASHED_ROW_ID.to_string(), Any::from(row.row_id.as_str())),
+          (TRASHED_DELETED_AT.to_string(), Any::BigInt(row.deleted_at)),
+          (TRASHED_INDEXES.to_string(), Any::from(indexes)),
+        ]))
+      })
+      .collect::<Vec<_>>();
+    setting.insert(ROW_TRASH.into(), Any::from(rows));
+  }
+}
+
+impl From<LayoutSetting> for RowTrash {
+  fn from(setting: LayoutSetting) -> Self {
+    let mut rows = vec![];
+    if let Any::Map(setting) = Any::from(setting) {
+      if let Some(Any::Array(items)) = setting.get(ROW_TRASH) {
+        for item in items.iter() {
+          let Any::Map(row) = item else {
+            continue;
+          };
+          let Some(Any::String(row_id)) = row.get(TRASHED_ROW_ID) else {
+            continue;
+          };
+          let deleted_at = match row.get(TRASHED_DELETED_AT) {
+            Some(Any::BigInt(deleted_at)) => *deleted_at,
+            _ => 0,
+          };
+          let indexes = match row.get(TRASHED_INDEXES) {
+            Some(Any::Map(indexes)) => indexes
+              .iter()
+              .filter_map(|(view_id, index)| match index {
+                Any::BigInt(index) => Some((view_id.clone(), *index)),
+                _ => None,
+              })
+              .collect(),
+            _ => HashMap::new(),
+          };
+          rows.push(TrashedRow {
+            row_id: row_id.to_string(),
+            deleted_at,
+            indexes,
+          });
+        }
+      }
+    }
+    Self { rows }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn trashed_row(row_id: &str, deleted_at: i64) -> TrashedRow {
+    TrashedRow {
+      row_id: row_id.to_string(),
+      deleted_at,
+      indexes: HashMap::from([("view".to_string(), 2)]),
+    }
+  }
+
+  #[test]
+  fn row_trash_round_trip_test() {
+    let trash = RowTrash {
+      rows: vec![trashed_row("a", 10), trashed_row("b", 20)],
+    };
+    let mut layout_setting = LayoutSetting::default();
+    trash.write_to(&mut layout_setting);
+    let read = RowTrash::from(layout_setting);
+    assert_eq!(read.rows, trash.rows);
+    assert!(read.contains("a"));
+    assert!(!read.contains("c"));
+  }
+
+  #[test]
+  fn take_expired_rows_test() {
+    let day = 24 * 60 * 60;
+    let now = 100 * day;
+    let mut trash = RowTrash {
+      rows: vec![
+        trashed_row("old", now - (ROW_TRASH_RETENTION_DAYS + 1) * day),
+        trashed_row("new", now - day),
+      ],
+    };
+    let expired = trash.take_expired(now);
+    assert_eq!(
+      expired,
+      vec![trashed_row(
+        "old",
+        now - (ROW_TRASH_RETENTION_DAYS + 1) * day
+      )]
+    );
+    assert_eq!(trash.rows, vec![trashed_row("new", now - day)]);
+
+    assert_eq!(trash.take(&["new".to_string()]).len(), 1);
+    assert!(trash.rows.is_empty());
+  }
+}
@@ -0,0 +1,330 @@
+use std::str::FromStr;
+
+use collab_database::database::gen_database_filter_id;
+use collab_database::fields::select_type_option::{SelectOption, SelectOptionIds};
+use collab_database::fields::Field;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{FieldType, FieldVisibility};
+use crate::services::field::select_type_option_from_field;
+use crate::services::field_settings::FieldSettings;
+use crate::services::filter::{Filter, FilterInner};
+use crate::services::group::{DateGroupConfiguration, GroupSetting};
+use crate::services::sort::{Sort, SortCollation, SortCondition};
+
+/// The filters, sorts, grouping and field visibility of a database view, without any of its rows.
+///
+/// The fields are referenced by their name and type instead of their id, so the settings of a
+/// view can be applied to a view of another database that has compatible fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViewSettingsTemplate {
+  #[serde(default)]
+  pub filters: Vec<FilterTemplate>,
+  #[serde(default)]
+  pub sorts: Vec<SortTemplate>,
+  #[serde(default)]
+  pub group: Option<GroupTemplate>,
+  #[serde(default)]
+  pub field_visibilities: Vec<FieldVisibilityTemplate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldRef {
+  pub name: String,
+  pub field_type: FieldType,
+}
+
+impl FieldRef {
+  fn new(field: &Field) -> Self {
+    Self {
+      name: field.name.clone(),
+      field_type: FieldType::from(field.field_type),
+    }
+  }
+
+  /// Returns the first field with the same name and type.
+  fn find<'a>(&self, fields: &'a [Field]) -> Option<&'a Field> {
+    fields
+      .iter()
+      .find(|field| field.name == self.name && FieldType::from(field.field_type) == self.field_type)
+  }
+
+  fn find_or_error<'a>(&self, fields: &'a [Field]) -> FlowyResult<&'a Field> {
+    self.find(fields).ok_or_else(|| {
+      FlowyError::new(
+        ErrorCode::IncompatibleViewSettings,
+        format!("Can't find the {:?} field: {}", self.field_type, self.name),
+      )
+    })
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FilterTemplate {
+  And {
+    children: Vec<FilterTemplate>,
+  },
+  Or {
+    children: Vec<FilterTemplate>,
+  },
+  Data {
+    field: FieldRef,
+    condition: i64,
+    #[serde(default)]
+    content: String,
+    /// The names of the options of a select filter. The option ids are different in every
+    /// database, so they are not part of the content.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    options: Vec<String>,
+  },
+}
+
+impl FilterTemplate {
+  /// Returns None if the filter references a field that doesn't exist.
+  fn from_filter(filter: &Filter, fields: &[Field]) -> Option<Self> {
+    match &filter.inner {
+      FilterInner::And { children } => Some(FilterTemplate::And {
+        children: Self::from_filters(children, fields),
+      }),
+      FilterInner::Or { children } => Some(FilterTemplate::Or {
+        children: Self::from_filters(children, fields),
+      }),
+      FilterInner::Data { field_id, .. } => {
+        let field = fields.iter().find(|field| &field.id == field_id)?;
+        let (condition, mut content) = filter.inner.raw_condition_and_content()?;
+        let mut options = vec![];
+        if FieldType::from(field.field_type).is_select_option() {
+          let option_ids = SelectOptionIds::from_str(&content).unwrap_or_default();
+          options = select_options(field)
+            .into_iter()
+            .filter(|option| option_ids.contains(&option.id))
+            .map(|option| option.name)
+            .collect();
+          content.clear();
+        }
+        Some(FilterTemplate::Data {
+          field: FieldRef::new(field),
+          condition: condition as i64,
+          content,
+          options,
+        })
+      },
+    }
+  }
+
+  fn from_filters(filters: &[Filter], fields: &[Field]) -> Vec<Self> {
+    filters
+      .iter()
+      .flat_map(|filter| Self::from_filter(filter, fields))
+      .collect()
+  }
+
+  fn to_filter_inner(&self, fields: &[Field]) -> FlowyResult<FilterInner> {
+    let to_filters = |children: &[FilterTemplate]| -> FlowyResult<Vec<Filter>> {
+      children
+        .iter()
+        .map(|child| {
+          Ok(Filter {
+            id: gen_database_filter_id(),
+            inner: child.to_filter_inner(fields)?,
+          })
+        })
+        .collect()
+    };
+
+    match self {
+      FilterTemplate::And { children } => Ok(FilterInner::And {
+        children: to_filters(children)?,
+      }),
+      FilterTemplate::Or { children } => Ok(FilterInner::Or {
+        children: to_filters(children)?,
+      }),
+      FilterTemplate::Data {
+        field,
+        condition,
+        content,
+        options,
+      } => {
+        let field = field.find_or_error(fields)?;
+        let content = if FieldType::from(field.field_type).is_select_option() {
+          let field_options = select_options(field);
+          let option_ids = options
+            .iter()
+            .map(|name| {
+              field_options
+                .iter()
+                .find(|option| &option.name == name)
+                .map(|option| option.id.clone())
+                .ok_or_else(|| {
+                  FlowyError::new(
+                    ErrorCode::IncompatibleViewSettings,
+                    format!(
+                      "Can't find the option {} of the field: {}",
+                      name, field.name
+                    ),
+                  )
+                })
+            })
+            .collect::<FlowyResult<Vec<_>>>()?;
+          SelectOptionIds::from(option_ids).to_string()
+        } else {
+          content.clone()
+        };
+        Ok(FilterInner::new_data(
+          field.id.clone(),
+          FieldType::from(field.field_type),
+          *condition,
+          content,
+        ))
+      },
+    }
+  }
+}
+
+fn select_options(field: &Field) -> Vec<SelectOption> {
+  select_type_option_from_field(field)
+    .map(|type_option| type_option.options().clone())
+    .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SortTemplate {
+  pub field: FieldRef,
+  pub condition: i64,
+  #[serde(default)]
+  pub collation: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupTemplate {
+  pub field: FieldRef,
+  /// The configuration of the grouping, e.g. the condition of a date grouping.
+  #[serde(default)]
+  pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldVisibilityTemplate {
+  pub field: FieldRef,
+  pub visibility: i64,
+}
+
+/// The settings of a [ViewSettingsTemplate] resolved against the fields of a database.
+pub struct ResolvedViewSettings {
+  pub filters: Vec<FilterInner>,
+  /// The field ids, conditions and collations of the sorts, in order.
+  pub sorts: Vec<(String, SortCondition, SortCollation)>,
+  /// The grouping field and the configuration of the grouping.
+  pub group: Option<(Field, String)>,
+  pub field_visibilities: Vec<(String, FieldVisibility)>,
+}
+
+impl ViewSettingsTemplate {
+  pub(crate) fn new(
+    fields: &[Field],
+    filters: &[Filter],
+    sorts: &[Sort],
+    group_setting: Option<&GroupSetting>,
+    field_settings: &[FieldSettings],
+  ) -> Self {
+    let find_field = |field_id: &str| fields.iter().find(|field| field.id == field_id);
+    Self {
+      filters: FilterTemplate::from_filters(filters, fields),
+      sorts: sorts
+        .iter()
+        .flat_map(|sort| {
+          find_field(&sort.field_id).map(|field| SortTemplate {
+            field: FieldRef::new(field),
+            condition: sort.condition as i64,
+            collation: sort.collation.value(),
+          })
+        })
+        .collect(),
+      group: group_setting.and_then(|group_setting| {
+        find_field(&group_setting.field_id).map(|field| GroupTemplate {
+          field: FieldRef::new(field),
+          content: group_setting.content.clone(),
+        })
+      }),
+      field_visibilities: field_settings
+        .iter()
+        .flat_map(|field_settings| {
+          find_field(&field_settings.field_id).map(|field| FieldVisibilityTemplate {
+            field: FieldRef::new(field),
+            visibility: field_settings.visibility.clone().into(),
+          })
+        })
+        .collect(),
+    }
+  }
+
+  pub fn from_json(s: &str) -> FlowyResult<Self> {
+    serde_json::from_str(s).map_err(|err| {
+      FlowyError::invalid_data().with_context(format!("Invalid view settings: {}", err))
+    })
+  }
+
+  pub fn to_json(&self) -> FlowyResult<String> {
+    serde_json::to_string(self).map_err(|err| FlowyError::internal().with_context(err))
+  }
+
+  /// Maps the field references of the settings to the given fields. Returns an error if a filter,
+  /// sort or grouping references a field that doesn't exist. The visibility of a field that
+  /// doesn't exist is ignored.
+  pub fn resolve(&self, fields: &[Field]) -> FlowyResult<ResolvedViewSettings> {
+    let filters = self
+      .filters
+      .iter()
+      .map(|filter| filter.to_filter_inner(fields))
+      .collect::<FlowyResult<Vec<_>>>()?;
+    let sorts = self
+      .sorts
+      .iter()
+      .map(|sort| {
+        let field = sort.field.find_or_error(fields)?;
+        Ok((
+          field.id.clone(),
+          SortCondition::from(sort.condition),
+          SortCollation::from(sort.collation),
+        ))
+      })
+      .collect::<FlowyResult<Vec<_>>>()?;
+    let group = match &self.group {
+      Some(group) => {
+        let field = group.field.find_or_error(fields)?;
+        let is_date_field = FieldType::from(field.field_type) == FieldType::DateTime;
+        if is_date_field
+          && !group.content.is_empty()
+          && DateGroupConfiguration::from_json(&group.content).is_err()
+        {
+          return Err(
+            FlowyError::invalid_data().with_context("Invalid configuration of the date grouping"),
+          );
+        }
+        Some((field.clone(), group.content.clone()))
+      },
+      None => None,
+    };
+    let field_visibilities = self
+      .field_visibilities
+      .iter()
+      .flat_map(|field_visibility| {
+        field_visibility.field.find(fields).map(|field| {
+          (
+            field.id.clone(),
+            FieldVisibility::from(field_visibility.visibility),
+          )
+        })
+      })
+      .collect();
+
+    Ok(ResolvedViewSettings {
+      filters,
+      sorts,
+      group,
+      field_visibilities,
+    })
+  }
+}
@@ -0,0 +1,209 @@
+use std::io::{Cursor, Read, Write};
+
+use collab_database::database::{gen_database_view_id, DatabaseData};
+use collab_database::entity::{CreateDatabaseParams, EncodedCollabInfo};
+use collab_entity::CollabType;
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+/// The version of the [DatabaseBackup] archive.
+pub const DATABASE_BACKUP_VERSION: i64 = 1;
+
+const META_FILE: &str = "meta.json";
+const DATABASE_FILE: &str = "database.json";
+const DATABASE_COLLAB_FOLDER: &str = "collabs/database/";
+const ROW_COLLAB_FOLDER: &str = "collabs/rows/";
+/// The max uncompressed size of a file in the archive. A small archive can expand to an
+/// unbounded size, so the files are never read past this size.
+const MAX_ENTRY_SIZE: u64 = 256 * 1024 * 1024;
+
+/// A single-file backup of a database, written as a zip archive with:
+/// - `meta.json`: the [DatabaseBackupMeta]
+/// - `database.json`: the raw data of the database, readable without AppFlowy
+/// - `collabs/database/<id>.collab` and `collabs/rows/<id>.collab`: the encoded state of the
+///   database and of each row when the backup was made
+/// - `snapshots/<snapshot id>.collab`: the local snapshots of the database
+///
+/// Only `database.json` is restored, see [DatabaseBackup::read_database_data]. The collabs and
+/// the snapshots are kept in the archive so the original state can be inspected, but a restored
+/// database starts without the edit history and the snapshots of the original one.
+#[derive(Debug, Clone)]
+pub struct DatabaseBackup {
+  pub meta: DatabaseBackupMeta,
+  pub database_json: Vec<u8>,
+  pub collabs: Vec<EncodedCollabInfo>,
+  pub snapshots: Vec<DatabaseBackupSnapshot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseBackupMeta {
+  pub version: i64,
+  pub database_id: String,
+  pub inline_view_id: String,
+  pub created_at: i64,
+  pub num_of_rows: usize,
+  /// The ids of the snapshots in the archive, oldest first.
+  pub snapshot_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DatabaseBackupSnapshot {
+  pub snapshot_id: String,
+  pub created_at: i64,
+  pub data: Vec<u8>,
+}
+
+impl DatabaseBackup {
+  pub fn to_zip(&self) -> FlowyResult<Vec<u8>> {
+    let mut writer = ZipWriter::new(Cursor::new(vec![]));
+    write_zip_file(
+      &mut writer,
+      META_FILE,
+      &serde_json::to_vec_pretty(&self.meta)?,
+    )?;
+    write_zip_file(&mut writer, DATABASE_FILE, &self.database_json)?;
+    for collab in &self.collabs {
+      let folder = match collab.collab_type {
+        CollabType::Database => DATABASE_COLLAB_FOLDER,
+        CollabType::DatabaseRow => ROW_COLLAB_FOLDER,
+        _ => continue,
+      };
+      let data = collab
+        .encoded_collab
+        .encode_to_bytes()
+        .map_err(internal_error)?;
+      let name = format!("{}{}.collab", folder, collab.object_id);
+      write_zip_file(&mut writer, &name, &data)?;
+    }
+    for snapshot in &self.snapshots {
+      let name = format!("snapshots/{}.collab", snapshot.snapshot_id);
+      write_zip_file(&mut writer, &name, &snapshot.data)?;
+    }
+    let cursor = writer.finish().map_err(internal_error)?;
+    Ok(cursor.into_inner())
+  }
+
+  /// Reads the raw data of the database from the backup archive. The archive is rejected if it's
+  /// of a newer version, if any of its files is corrupted or if its files don't match its meta.
+  /// The collabs and the snapshots in the archive are verified but not returned.
+  pub fn read_database_data(data: Vec<u8>) -> FlowyResult<DatabaseData> {
+    let mut archive =
+      ZipArchive::new(Cursor::new(data)).map_err(|err| invalid_backup(&err.to_string()))?;
+    let meta: DatabaseBackupMeta = serde_json::from_slice(&read_zip_file(&mut archive, META_FILE)?)
+      .map_err(|err| invalid_backup(&format!("Invalid meta: {}", err)))?;
+    if meta.version > DATABASE_BACKUP_VERSION {
+      return Err(invalid_backup(&format!(
+        "Unsupported version: {}",
+        meta.version
+      )));
+    }
+
+    // Reading a file to the end verifies its checksum
+    let names = archive
+      .file_names()
+      .map(|name| name.to_string())
+      .collect::<Vec<_>>();
+    for name in &names {
+      read_zip_file(&mut archive, name)?;
+    }
+    let database_collab = format!("{}{}.collab", DATABASE_COLLAB_FOLDER, meta.database_id);
+    let num_of_row_collabs = names
+      .iter()
+      .filter(|name| name.starts_with(ROW_COLLAB_FOLDER))
+      .count();
+    if !names.contains(&database_collab) || num_of_row_collabs != meta.num_of_rows {
+      return Err(invalid_backup("The collabs don't match the meta"));
+    }
+
+    let database_data = DatabaseData::from_json_bytes(read_zip_file(&mut archive, DATABASE_FILE)?)
+      .map_err(|err| invalid_backup(&format!("Invalid database data: {}", err)))?;
+    if database_data.database_id != meta.database_id || database_data.rows.len() != meta.num_of_rows
+    {
+      return Err(invalid_backup("The database data doesn't match the meta"));
+    }
+    Ok(database_data)
+  }
+}
+
+/// Returns the params of a new database with the data of the backup. The database, its views and
+/// its rows get new ids, so the backup can be restored while the original database still exists.
+/// The inline view of the new database is `view_id`.
+pub fn restore_database_params(database_data: DatabaseData, view_id: &str) -> CreateDatabaseParams {
+  let mut params = CreateDatabaseParams::from_database_data(database_data, None);
+  let old_inline_view_id = std::mem::replace(&mut params.inline_view_id, view_id.to_string());
+  for view in params.views.iter_mut() {
+    view.view_id = if view.view_id == old_inline_view_id {
+      view_id.to_string()
+    } else {
+      gen_database_view_id()
+    };
+  }
+  params
+}
+
+fn read_zip_file(archive: &mut ZipArchive<Cursor<Vec<u8>>>, name: &str) -> FlowyResult<Vec<u8>> {
+  read_zip_file_with_limit(archive, name, MAX_ENTRY_SIZE)
+}
+
+fn read_zip_file_with_limit(
+  archive: &mut ZipArchive<Cursor<Vec<u8>>>,
+  name: &str,
+  max_size: u64,
+) -> FlowyResult<Vec<u8>> {
+  let file = archive
+    .by_name(name)
+    .map_err(|err| invalid_backup(&format!("Can't read {}: {}", name, err)))?;
+  let too_large = || invalid_backup(&format!("{} is larger than {} bytes", name, max_size));
+  if file.size() > max_size {
+    return Err(too_large());
+  }
+
+  // The size in the header can be forged, so the read stops one byte past the limit
+  let mut data = vec![];
+  file
+    .take(max_size + 1)
+    .read_to_end(&mut data)
+    .map_err(|err| invalid_backup(&format!("{} is corrupted: {}", name, err)))?;
+  if data.len() as u64 > max_size {
+    return Err(too_large());
+  }
+  Ok(data)
+}
+
+fn invalid_backup(msg: &str) -> FlowyError {
+  FlowyError::invalid_data().with_context(format!("Invalid backup: {}", msg))
+}
+
+fn write_zip_file(
+  writer: &mut ZipWriter<Cursor<Vec<u8>>>,
+  name: &str,
+  data: &[u8],
+) -> FlowyResult<()> {
+  writer
+    .start_file(name, SimpleFileOptions::default())
+    .map_err(|err| FlowyError::internal().with_context(err))?;
+  writer.write_all(data)?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn read_zip_file_with_limit_test() {
+    let mut writer = ZipWriter::new(Cursor::new(vec![]));
+    write_zip_file(&mut writer, "small.json", &[0; 10]).unwrap();
+    write_zip_file(&mut writer, "large.json", &[0; 11]).unwrap();
+    let data = writer.finish().unwrap().into_inner();
+
+    let mut archive = ZipArchive::new(Cursor::new(data)).unwrap();
+    assert_eq!(
+      read_zip_file_with_limit(&mut archive, "small.json", 10).unwrap(),
+      vec![0; 10]
+    );
+    assert!(read_zip_file_with_limit(&mut archive, "large.json", 10).is_err());
+  }
+}
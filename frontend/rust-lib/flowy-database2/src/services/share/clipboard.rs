@@ -0,0 +1,303 @@
+use collab_database::fields::date_type_option::DateCellData;
+use collab_database::fields::select_type_option::{SelectOption, SelectOptionIds};
+use collab_database::fields::Field;
+use collab_database::rows::{Row, RowId};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::FieldType;
+use crate::services::cell::stringify_cell;
+use crate::services::field::{select_type_option_from_field, should_mask_field, MASKED_CELL_VALUE};
+use crate::services::share::csv::{parse_checkbox, parse_date, parse_number};
+
+/// The copied cells. The plain text is for pasting into other apps, e.g. a spreadsheet, and
+/// the payload is for pasting into another database without losing the select options and the
+/// dates.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClipboardData {
+  /// The display strings of the cells, separated by tabs, one line per row.
+  pub plain_text: String,
+  /// The JSON of [ClipboardCells]. Empty if the data is copied from another app.
+  pub payload: String,
+}
+
+impl ClipboardData {
+  /// Returns the copied cells. Falls back to the plain text if the payload is missing or
+  /// can't be parsed.
+  pub fn cells(&self) -> ClipboardCells {
+    serde_json::from_str(&self.payload)
+      .unwrap_or_else(|_| ClipboardCells::from_plain_text(&self.plain_text))
+  }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClipboardCells {
+  pub rows: Vec<Vec<ClipboardCell>>,
+}
+
+impl ClipboardCells {
+  fn from_plain_text(s: &str) -> Self {
+    let rows = s
+      .lines()
+      .map(|line| {
+        line
+          .split('\t')
+          .map(|text| ClipboardCell {
+            field_type: FieldType::RichText,
+            text: text.to_string(),
+            value: ClipboardCellValue::Text,
+          })
+          .collect()
+      })
+      .collect();
+    Self { rows }
+  }
+
+  fn to_plain_text(&self) -> String {
+    self
+      .rows
+      .iter()
+      .map(|row| {
+        row
+          .iter()
+          .map(|cell| cell.text.replace(['\t', '\n', '\r'], " "))
+          .collect::<Vec<_>>()
+          .join("\t")
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClipboardCell {
+  /// The type of the field the cell is copied from.
+  pub field_type: FieldType,
+  /// The display string of the cell. It's pasted if the typed value doesn't fit the target field.
+  pub text: String,
+  #[serde(default)]
+  pub value: ClipboardCellValue,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum ClipboardCellValue {
+  /// The cell is pasted from its text.
+  #[default]
+  Text,
+  Date {
+    timestamp: Option<i64>,
+    end_timestamp: Option<i64>,
+    include_time: bool,
+    is_range: bool,
+  },
+  /// The selected options. They are matched with the options of the target field by name, the
+  /// missing ones are created with the same colors.
+  SelectOptions(Vec<SelectOption>),
+}
+
+/// Copies the cells of the fields in the rows. The cells are in the order of `rows` and
+/// `fields`. The cells of sensitive fields are copied as [MASKED_CELL_VALUE] unless
+/// `reveal_sensitive` is true.
+pub fn serialize_cells(rows: &[Row], fields: &[Field], reveal_sensitive: bool) -> ClipboardData {
+  let cells = ClipboardCells {
+    rows: rows
+      .iter()
+      .map(|row| {
+        fields
+          .iter()
+          .map(|field| copy_cell(row, field, reveal_sensitive))
+          .collect()
+      })
+      .collect(),
+  };
+  ClipboardData {
+    plain_text: cells.to_plain_text(),
+    payload: serde_json::to_string(&cells).unwrap_or_default(),
+  }
+}
+
+fn copy_cell(row: &Row, field: &Field, reveal_sensitive: bool) -> ClipboardCell {
+  let field_type = FieldType::from(field.field_type);
+  if should_mask_field(field, reveal_sensitive) {
+    // Neither the text nor the typed value may carry the masked value
+    return ClipboardCell {
+      field_type,
+      text: MASKED_CELL_VALUE.to_string(),
+      value: ClipboardCellValue::Text,
+    };
+  }
+  let cell = row.cells.get(&field.id);
+  let text = cell
+    .map(|cell| stringify_cell(cell, field))
+    .unwrap_or_default();
+  let value = match (cell, field_type) {
+    (Some(cell), FieldType::DateTime) => {
+      let data = DateCellData::from(cell);
+      ClipboardCellValue::Date {
+        timestamp: data.timestamp,
+        end_timestamp: data.end_timestamp,
+        include_time: data.include_time,
+        is_range: data.is_range,
+      }
+    },
+    (Some(cell), FieldType::SingleSelect | FieldType::MultiSelect) => {
+      let options = select_type_option_from_field(field)
+        .map(|type_option| type_option.options().clone())
+        .unwrap_or_default();
+      let selected_options = SelectOptionIds::from(cell)
+        .iter()
+        .flat_map(|id| options.iter().find(|option| &option.id == id))
+        .cloned()
+        .collect();
+      ClipboardCellValue::SelectOptions(selected_options)
+    },
+    _ => ClipboardCellValue::Text,
+  };
+  ClipboardCell {
+    field_type,
+    text,
+    value,
+  }
+}
+
+/// Returns the names of the options to select when pasting the cell into a select field. The
+/// text is split by commas if the cell is not copied from a select field.
+pub fn select_option_names(cell: &ClipboardCell) -> Vec<(String, Option<SelectOption>)> {
+  match &cell.value {
+    ClipboardCellValue::SelectOptions(options) => options
+      .iter()
+      .map(|option| (option.name.clone(), Some(option.clone())))
+      .collect(),
+    _ => cell
+      .text
+      .split(',')
+      .map(str::trim)
+      .filter(|name| !name.is_empty())
+      .map(|name| (name.to_string(), None))
+      .collect(),
+  }
+}
+
+/// The outcome of pasting the copied cells.
+#[derive(Debug, Clone, Default)]
+pub struct PasteResult {
+  pub num_of_pasted_cells: usize,
+  /// The rows created for the copied rows, if they are pasted as new rows.
+  pub created_row_ids: Vec<RowId>,
+  pub failures: Vec<PasteCellFailure>,
+}
+
+/// A copied cell that couldn't be pasted into its field. The indexes are the position of the cell
+/// in the copied data.
+#[derive(Debug, Clone)]
+pub struct PasteCellFailure {
+  pub row_index: usize,
+  pub column_index: usize,
+  pub field_id: String,
+  pub text: String,
+  pub reason: String,
+}
+
+/// The text of a pasted cell parsed for the type of its target field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PastedText {
+  /// The text is passed to the field as is, e.g. a text or a url.
+  Text(String),
+  Checkbox(bool),
+  Date {
+    timestamp: i64,
+    include_time: bool,
+  },
+}
+
+/// Parses the text of a pasted cell with the parser of the field type. Returns the reason if the
+/// text isn't a valid value of the field, so the caller can report the failure.
+pub fn parse_pasted_text(text: &str, field_type: FieldType) -> Result<PastedText, String> {
+  let text = text.trim();
+  match field_type {
+    FieldType::RichText
+    | FieldType::URL
+    | FieldType::Summary
+    | FieldType::Translate
+    | FieldType::SingleSelect
+    | FieldType::MultiSelect => Ok(PastedText::Text(text.to_string())),
+    FieldType::Number => parse_number(text)
+      .map(PastedText::Text)
+      .ok_or_else(|| format!("`{}` is not a number", text)),
+    FieldType::Time => text
+      .parse::<i64>()
+      .map(|seconds| PastedText::Text(seconds.to_string()))
+      .map_err(|_| format!("`{}` is not a number of seconds", text)),
+    FieldType::Checkbox => parse_checkbox(text)
+      .map(PastedText::Checkbox)
+      .ok_or_else(|| format!("`{}` is not a checkbox value", text)),
+    FieldType::DateTime => parse_date(text)
+      .map(|(timestamp, include_time)| PastedText::Date {
+        timestamp,
+        include_time,
+      })
+      .ok_or_else(|| format!("`{}` is not a date", text)),
+    FieldType::LastEditedTime
+    | FieldType::CreatedTime
+    | FieldType::Checklist
+    | FieldType::Relation
+    | FieldType::Media => Err(format!(
+      "Text can't be pasted into a {:?} field",
+      field_type
+    )),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn clipboard_plain_text_fallback_test() {
+    let data = ClipboardData {
+      plain_text: "a\tb\nc\td".to_string(),
+      payload: "".to_string(),
+    };
+    let cells = data.cells();
+    assert_eq!(cells.rows.len(), 2);
+    assert_eq!(cells.rows[1][0].text, "c");
+    assert_eq!(cells.to_plain_text(), data.plain_text);
+  }
+
+  #[test]
+  fn clipboard_select_option_names_test() {
+    let cell = ClipboardCell {
+      field_type: FieldType::RichText,
+      text: "Done, In progress,".to_string(),
+      value: ClipboardCellValue::Text,
+    };
+    let names = select_option_names(&cell)
+      .into_iter()
+      .map(|(name, _)| name)
+      .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Done", "In progress"]);
+  }
+
+  #[test]
+  fn parse_pasted_text_test() {
+    assert_eq!(
+      parse_pasted_text(" $1,200 ", FieldType::Number),
+      Ok(PastedText::Text("1200".to_string()))
+    );
+    assert!(parse_pasted_text("abc", FieldType::Number).is_err());
+    assert_eq!(
+      parse_pasted_text("Yes", FieldType::Checkbox),
+      Ok(PastedText::Checkbox(true))
+    );
+    assert!(parse_pasted_text("maybe", FieldType::Checkbox).is_err());
+    assert_eq!(
+      parse_pasted_text("2024-01-31", FieldType::DateTime),
+      Ok(PastedText::Date {
+        timestamp: 1_706_659_200,
+        include_time: false,
+      })
+    );
+    assert!(parse_pasted_text("tomorrow", FieldType::DateTime).is_err());
+    assert!(parse_pasted_text("a", FieldType::Checklist).is_err());
+  }
+}
@@ -1,6 +1,8 @@
+use std::sync::Arc;
+
 use collab_database::database::Database;
 use collab_database::fields::Field;
-use collab_database::rows::Cell;
+use collab_database::rows::{Cell, Row};
 use futures::StreamExt;
 use indexmap::IndexMap;
 
@@ -8,7 +10,9 @@ use flowy_error::{FlowyError, FlowyResult};
 
 use crate::entities::FieldType;
 use crate::services::cell::stringify_cell;
-use crate::services::field::{TimestampCellData, TimestampCellDataWrapper};
+use crate::services::field::{
+  should_mask_field, TimestampCellData, TimestampCellDataWrapper, MASKED_CELL_VALUE,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub enum CSVFormat {
@@ -20,12 +24,31 @@ pub enum CSVFormat {
   META,
 }
 
+/// The options of exporting the rows of a view, see [CSVExport::export_rows].
+#[derive(Debug, Clone)]
+pub struct CSVExportOptions {
+  /// The byte that separates the cells of a record.
+  pub delimiter: u8,
+  /// Exports the fields that are hidden in the view too.
+  pub include_hidden_fields: bool,
+}
+
+impl Default for CSVExportOptions {
+  fn default() -> Self {
+    Self {
+      delimiter: b',',
+      include_hidden_fields: false,
+    }
+  }
+}
+
 pub struct CSVExport;
 impl CSVExport {
   pub async fn export_database(
     &self,
     database: &Database,
     style: CSVFormat,
+    reveal_sensitive: bool,
   ) -> FlowyResult<String> {
     let mut wtr = csv::Writer::from_writer(vec![]);
     let inline_view_id = database.get_inline_view_id();
@@ -55,31 +78,22 @@ impl CSVExport {
       .collect::<Vec<_>>()
       .await;
 
-    let stringify = |cell: &Cell, field: &Field, style: CSVFormat| match style {
-      CSVFormat::Original => stringify_cell(cell, field),
-      CSVFormat::META => serde_json::to_string(cell).unwrap_or_else(|_| "".to_string()),
+    let stringify = |cell: &Cell, field: &Field, style: CSVFormat| {
+      if should_mask_field(field, reveal_sensitive) {
+        return MASKED_CELL_VALUE.to_string();
+      }
+      match style {
+        CSVFormat::Original => stringify_cell(cell, field),
+        CSVFormat::META => serde_json::to_string(cell).unwrap_or_else(|_| "".to_string()),
+      }
     };
 
     for row in rows {
       let cells = field_by_field_id
-        .iter()
-        .map(|(field_id, field)| {
-          let field_type = FieldType::from(field.field_type);
-          match field_type {
-            FieldType::LastEditedTime | FieldType::CreatedTime => {
-              let cell_data = if field_type.is_created_time() {
-                TimestampCellData::new(row.created_at)
-              } else {
-                TimestampCellData::new(row.modified_at)
-              };
-              let cell = Cell::from(TimestampCellDataWrapper::from((field_type, cell_data)));
-              stringify(&cell, field, style)
-            },
-            _ => match row.cells.get(field_id) {
-              None => "".to_string(),
-              Some(cell) => stringify(cell, field, style),
-            },
-          }
+        .values()
+        .map(|field| match row_cell(&row, field) {
+          None => "".to_string(),
+          Some(cell) => stringify(&cell, field, style),
         })
         .collect::<Vec<_>>();
 
@@ -94,4 +108,60 @@ impl CSVExport {
     let csv = String::from_utf8(data).map_err(|e| FlowyError::internal().with_context(e))?;
     Ok(csv)
   }
+
+  /// Exports the given rows in order, with a column per field. Each cell is formatted the way
+  /// its field displays it, e.g. with the currency symbol of a number, the date format of a
+  /// date or the names of the selected options.
+  pub fn export_rows(
+    &self,
+    fields: &[Field],
+    rows: &[Arc<Row>],
+    options: &CSVExportOptions,
+    reveal_sensitive: bool,
+  ) -> FlowyResult<String> {
+    let mut wtr = csv::WriterBuilder::new()
+      .delimiter(options.delimiter)
+      .from_writer(vec![]);
+    wtr
+      .write_record(fields.iter().map(|field| field.name.as_str()))
+      .map_err(|e| FlowyError::internal().with_context(e))?;
+
+    for row in rows {
+      let cells = fields.iter().map(|field| {
+        if should_mask_field(field, reveal_sensitive) {
+          return MASKED_CELL_VALUE.to_string();
+        }
+        row_cell(row, field)
+          .map(|cell| stringify_cell(&cell, field))
+          .unwrap_or_default()
+      });
+      wtr
+        .write_record(cells)
+        .map_err(|e| FlowyError::internal().with_context(e))?;
+    }
+
+    let data = wtr
+      .into_inner()
+      .map_err(|e| FlowyError::internal().with_context(e))?;
+    String::from_utf8(data).map_err(|e| FlowyError::internal().with_context(e))
+  }
+}
+
+/// Returns the cell of the row for the field. The cells of the created time and the last edited
+/// time fields are made from the timestamps of the row.
+pub(crate) fn row_cell(row: &Row, field: &Field) -> Option<Cell> {
+  let field_type = FieldType::from(field.field_type);
+  match field_type {
+    FieldType::LastEditedTime | FieldType::CreatedTime => {
+      let cell_data = if field_type.is_created_time() {
+        TimestampCellData::new(row.created_at)
+      } else {
+        TimestampCellData::new(row.modified_at)
+      };
+      Some(Cell::from(TimestampCellDataWrapper::from((
+        field_type, cell_data,
+      ))))
+    },
+    _ => row.cells.get(&field.id).cloned(),
+  }
 }
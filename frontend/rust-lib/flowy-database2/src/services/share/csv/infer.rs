@@ -0,0 +1,700 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::Read;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use collab_database::database::{gen_database_id, gen_field_id, gen_row_id};
+use collab_database::entity::CreateDatabaseParams;
+use collab_database::fields::select_type_option::SelectOption;
+use collab_database::fields::Field;
+use collab_database::rows::{Cell, CreateRowParams};
+use flowy_error::{FlowyError, FlowyResult};
+
+use crate::entities::FieldType;
+use crate::services::cell::{
+  insert_checkbox_cell, insert_date_cell, insert_select_option_cell, insert_text_cell,
+  insert_url_cell,
+};
+use crate::services::field::{
+  default_type_option_data_from_type, select_type_option_from_field, SelectTypeOptionBuilder,
+  SelectTypeOptionSharedAction,
+};
+use crate::services::share::grid_database_params;
+
+const DATE_FORMATS: [&str; 7] = [
+  "%Y-%m-%d",
+  "%Y/%m/%d",
+  "%m/%d/%Y",
+  "%d.%m.%Y",
+  "%B %d, %Y",
+  "%b %d, %Y",
+  "%d %B %Y",
+];
+const DATE_TIME_FORMATS: [&str; 4] = [
+  "%Y-%m-%d %H:%M:%S",
+  "%Y-%m-%d %H:%M",
+  "%Y/%m/%d %H:%M:%S",
+  "%Y/%m/%d %H:%M",
+];
+const CURRENCY_SYMBOLS: [char; 4] = ['$', '€', '£', '¥'];
+
+/// The CSV data to import. A file is read record by record, so a large file is never loaded into
+/// memory as a whole.
+#[derive(Debug, Clone)]
+pub enum CSVSource {
+  Path(String),
+  Bytes(Vec<u8>),
+}
+
+impl CSVSource {
+  /// Opens a new reader at the beginning of the data. The data is read twice by the importer:
+  /// once to infer the field types and once to create the rows.
+  fn reader(&self) -> FlowyResult<csv::Reader<Box<dyn Read + '_>>> {
+    let read: Box<dyn Read + '_> = match self {
+      CSVSource::Path(path) => Box::new(File::open(path)?),
+      CSVSource::Bytes(bytes) => Box::new(bytes.as_slice()),
+    };
+    Ok(csv::ReaderBuilder::new().flexible(true).from_reader(read))
+  }
+
+  fn headers(&self) -> FlowyResult<Vec<String>> {
+    let mut reader = self.reader()?;
+    let headers = reader
+      .headers()
+      .map_err(|err| FlowyError::invalid_data().with_context(format!("Header not found: {}", err)))?
+      .iter()
+      .map(|header| header.to_string())
+      .collect::<Vec<_>>();
+    if headers.is_empty() {
+      return Err(FlowyError::invalid_data().with_context("Import content is empty"));
+    }
+    Ok(headers)
+  }
+
+  /// Calls `f` with the cells of every record of the data, skipping the header.
+  fn for_each_record<F>(&self, mut f: F) -> FlowyResult<()>
+  where
+    F: FnMut(&csv::StringRecord) -> FlowyResult<()>,
+  {
+    let mut reader = self.reader()?;
+    let mut record = csv::StringRecord::new();
+    loop {
+      match reader.read_record(&mut record) {
+        Ok(true) => f(&record)?,
+        Ok(false) => return Ok(()),
+        Err(err) => {
+          return Err(FlowyError::invalid_data().with_context(format!("Invalid record: {}", err)))
+        },
+      }
+    }
+  }
+}
+
+/// Tunes the inference of the field types for the CSV exported by a specific app.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CSVImportPreset {
+  #[default]
+  Generic,
+  /// Airtable joins the options of a multi select value with commas and keeps the line breaks of
+  /// a long text. A column of comma-joined options becomes a multi select field and a column with
+  /// line breaks always becomes a text field.
+  Airtable,
+  /// Notion joins the options of a multi select value with commas too, like Airtable.
+  Notion,
+}
+
+#[derive(Debug, Clone)]
+pub struct CSVImportOptions {
+  /// Infers the type of each field from the values of its column. Every field is a text field
+  /// otherwise. The first column is always imported as the primary text field.
+  pub infer_field_types: bool,
+  /// A column that has at most this many distinct values, with at least one of them repeated,
+  /// becomes a single select field with one option per distinct value.
+  pub max_select_options: usize,
+  pub preset: CSVImportPreset,
+}
+
+impl CSVImportOptions {
+  /// The options for the CSV exported by Airtable, see [CSVImportPreset::Airtable].
+  pub fn airtable() -> Self {
+    Self {
+      max_select_options: 50,
+      preset: CSVImportPreset::Airtable,
+      ..Default::default()
+    }
+  }
+
+  /// The options for the CSV exported by Notion, see [CSVImportPreset::Notion].
+  pub fn notion() -> Self {
+    Self {
+      max_select_options: 50,
+      preset: CSVImportPreset::Notion,
+      ..Default::default()
+    }
+  }
+}
+
+impl Default for CSVImportOptions {
+  fn default() -> Self {
+    Self {
+      infer_field_types: true,
+      max_select_options: 10,
+      preset: CSVImportPreset::Generic,
+    }
+  }
+}
+
+/// Builds the params of a new grid database from the CSV data. The field types are inferred from
+/// the values of each column, see [CSVImportOptions].
+pub fn database_from_csv(
+  view_id: &str,
+  source: &CSVSource,
+  options: &CSVImportOptions,
+) -> FlowyResult<CreateDatabaseParams> {
+  let headers = source.headers()?;
+  let mut columns = headers
+    .iter()
+    .map(|_| ColumnStats::default())
+    .collect::<Vec<_>>();
+  if options.infer_field_types {
+    source.for_each_record(|record| {
+      for (column, value) in columns.iter_mut().zip(record.iter()) {
+        column.observe(value, options);
+      }
+      Ok(())
+    })?;
+  }
+
+  let fields = headers
+    .into_iter()
+    .zip(columns.iter())
+    .enumerate()
+    .map(|(index, (name, column))| {
+      let field_type = if index == 0 || !options.infer_field_types {
+        FieldType::RichText
+      } else {
+        column.field_type(options)
+      };
+      field_from_column(name, field_type, column, index == 0)
+    })
+    .collect::<Vec<_>>();
+
+  let database_id = gen_database_id();
+  let builders = fields.iter().map(CSVCellBuilder::new).collect::<Vec<_>>();
+  let mut rows = vec![];
+  source.for_each_record(|record| {
+    let mut params = CreateRowParams::new(gen_row_id(), database_id.clone());
+    for (builder, value) in builders.iter().zip(record.iter()) {
+      if let Some(cell) = builder.build(value) {
+        params.cells.insert(builder.field.id.clone(), cell);
+      }
+    }
+    rows.push(params);
+    Ok(())
+  })?;
+
+  Ok(grid_database_params(view_id, database_id, fields, rows))
+}
+
+/// Matches the columns of the CSV data to the given fields by name, ignoring the case if there's
+/// no exact match. Returns the index of the matched field of each column, None if the column
+/// doesn't match any field.
+pub fn match_csv_columns(source: &CSVSource, fields: &[Field]) -> FlowyResult<Vec<Option<usize>>> {
+  let headers = source.headers()?;
+  Ok(
+    headers
+      .iter()
+      .map(|header| {
+        fields
+          .iter()
+          .position(|field| &field.name == header)
+          .or_else(|| {
+            fields
+              .iter()
+              .position(|field| field.name.eq_ignore_ascii_case(header))
+          })
+      })
+      .collect(),
+  )
+}
+
+/// Returns the values of the select fields that aren't an option of their field yet, by field id.
+/// The values are returned in the order they first appear in the data.
+pub fn missing_select_options(
+  source: &CSVSource,
+  columns: &[Option<usize>],
+  fields: &[Field],
+) -> FlowyResult<HashMap<String, Vec<String>>> {
+  // The option names of each select column, by column index
+  let mut known_names = HashMap::new();
+  for (column, field_index) in columns.iter().enumerate() {
+    let Some(field) = field_index.map(|index| &fields[index]) else {
+      continue;
+    };
+    if FieldType::from(field.field_type).is_select_option() {
+      let names = CSVCellBuilder::new(field)
+        .options
+        .into_iter()
+        .map(|option| option.name)
+        .collect::<HashSet<_>>();
+      known_names.insert(column, (field, names));
+    }
+  }
+
+  let mut missing = HashMap::<String, Vec<String>>::new();
+  if known_names.is_empty() {
+    return Ok(missing);
+  }
+  source.for_each_record(|record| {
+    for (column, value) in record.iter().enumerate() {
+      if let Some((field, names)) = known_names.get_mut(&column) {
+        for name in select_option_names(value, FieldType::from(field.field_type)) {
+          if names.insert(name.to_string()) {
+            missing
+              .entry(field.id.clone())
+              .or_default()
+              .push(name.to_string());
+          }
+        }
+      }
+    }
+    Ok(())
+  })?;
+  Ok(missing)
+}
+
+/// Calls `f` with the rows built from the CSV data, at most `batch_size` rows at a time. The
+/// values of each column are converted to the type of its matched field.
+pub fn csv_rows_in_batches<F>(
+  source: &CSVSource,
+  database_id: &str,
+  columns: &[Option<usize>],
+  fields: &[Field],
+  batch_size: usize,
+  mut f: F,
+) -> FlowyResult<()>
+where
+  F: FnMut(Vec<CreateRowParams>) -> FlowyResult<()>,
+{
+  let builders = columns
+    .iter()
+    .map(|column| column.map(|index| CSVCellBuilder::new(&fields[index])))
+    .collect::<Vec<_>>();
+  let mut batch = Vec::with_capacity(batch_size);
+  source.for_each_record(|record| {
+    let mut params = CreateRowParams::new(gen_row_id(), database_id.to_string());
+    for (builder, value) in builders.iter().zip(record.iter()) {
+      if let Some(builder) = builder {
+        if let Some(cell) = builder.build(value) {
+          params.cells.insert(builder.field.id.clone(), cell);
+        }
+      }
+    }
+    batch.push(params);
+    if batch.len() >= batch_size {
+      f(std::mem::take(&mut batch))?;
+    }
+    Ok(())
+  })?;
+  if !batch.is_empty() {
+    f(batch)?;
+  }
+  Ok(())
+}
+
+fn field_from_column(
+  name: String,
+  field_type: FieldType,
+  column: &ColumnStats,
+  is_primary: bool,
+) -> Field {
+  let type_option_data = match field_type {
+    FieldType::SingleSelect => column
+      .distinct_values
+      .iter()
+      .fold(SelectTypeOptionBuilder::new(), |builder, value| {
+        builder.option(value)
+      })
+      .build_single_select()
+      .to_type_option_data(),
+    FieldType::MultiSelect => column
+      .distinct_options
+      .iter()
+      .fold(SelectTypeOptionBuilder::new(), |builder, name| {
+        builder.option(name)
+      })
+      .build_multi_select()
+      .to_type_option_data(),
+    _ => default_type_option_data_from_type(field_type),
+  };
+  Field::new(gen_field_id(), name, field_type.into(), is_primary)
+    .with_type_option_data(field_type, type_option_data)
+}
+
+/// Collects what the values of a column look like, without keeping the values themselves except
+/// for a bounded number of distinct values.
+#[derive(Debug, Default)]
+struct ColumnStats {
+  non_empty: usize,
+  numbers: usize,
+  checkboxes: usize,
+  dates: usize,
+  distinct_values: Vec<String>,
+  seen: HashSet<String>,
+  too_many_distinct_values: bool,
+  /// The values with a line break. Only collected for the presets of other apps.
+  multiline: usize,
+  /// The values with more than one comma-joined option. Only collected for the presets of other
+  /// apps, like the options below.
+  multi_options: usize,
+  options: usize,
+  distinct_options: Vec<String>,
+  too_many_distinct_options: bool,
+}
+
+impl ColumnStats {
+  fn observe(&mut self, value: &str, options: &CSVImportOptions) {
+    let max_select_options = options.max_select_options;
+    let value = value.trim();
+    if value.is_empty() {
+      return;
+    }
+    if options.preset != CSVImportPreset::Generic {
+      self.observe_options(value, max_select_options);
+    }
+    self.non_empty += 1;
+    if parse_checkbox(value).is_some() {
+      self.checkboxes += 1;
+    }
+    if parse_number(value).is_some() {
+      self.numbers += 1;
+    }
+    if parse_date(value).is_some() {
+      self.dates += 1;
+    }
+    if !self.too_many_distinct_values && self.seen.insert(value.to_string()) {
+      if self.distinct_values.len() < max_select_options {
+        self.distinct_values.push(value.to_string());
+      } else {
+        self.too_many_distinct_values = true;
+        self.distinct_values.clear();
+        self.seen.clear();
+      }
+    }
+  }
+
+  fn observe_options(&mut self, value: &str, max_select_options: usize) {
+    if value.contains('\n') {
+      self.multiline += 1;
+    }
+    let names = select_option_names(value, FieldType::MultiSelect).collect::<Vec<_>>();
+    if names.len() > 1 {
+      self.multi_options += 1;
+    }
+    self.options += names.len();
+    if self.too_many_distinct_options {
+      return;
+    }
+    for name in names {
+      if !self.distinct_options.iter().any(|option| option == name) {
+        if self.distinct_options.len() < max_select_options {
+          self.distinct_options.push(name.to_string());
+        } else {
+          self.too_many_distinct_options = true;
+          self.distinct_options.clear();
+          return;
+        }
+      }
+    }
+  }
+
+  fn field_type(&self, options: &CSVImportOptions) -> FieldType {
+    let max_select_options = options.max_select_options;
+    if self.non_empty == 0 || self.multiline > 0 {
+      FieldType::RichText
+    } else if self.checkboxes == self.non_empty {
+      FieldType::Checkbox
+    } else if self.numbers == self.non_empty {
+      FieldType::Number
+    } else if self.dates == self.non_empty {
+      FieldType::DateTime
+    } else if self.multi_options > 0
+      && !self.too_many_distinct_options
+      && self.distinct_options.len() < self.options
+    {
+      FieldType::MultiSelect
+    } else if !self.too_many_distinct_values
+      && self.distinct_values.len() <= max_select_options
+      && self.distinct_values.len() < self.non_empty
+    {
+      FieldType::SingleSelect
+    } else {
+      FieldType::RichText
+    }
+  }
+}
+
+/// Converts the text to a cell of the field like a value of an appended CSV file. Returns None if
+/// the text is empty or isn't valid for the type of the field.
+pub(crate) fn cell_from_text(text: &str, field: &Field) -> Option<Cell> {
+  CSVCellBuilder::new(field).build(text)
+}
+
+/// Converts the CSV values of a column to cells of its field.
+struct CSVCellBuilder<'a> {
+  field: &'a Field,
+  field_type: FieldType,
+  options: Vec<SelectOption>,
+}
+
+impl<'a> CSVCellBuilder<'a> {
+  fn new(field: &'a Field) -> Self {
+    let field_type = FieldType::from(field.field_type);
+    let options = if field_type.is_select_option() {
+      select_type_option_from_field(field)
+        .map(|type_option| type_option.options().clone())
+        .unwrap_or_default()
+    } else {
+      vec![]
+    };
+    Self {
+      field,
+      field_type,
+      options,
+    }
+  }
+
+  /// Returns None if the value is empty or isn't valid for the type of the field.
+  fn build(&self, value: &str) -> Option<Cell> {
+    let value = value.trim();
+    if value.is_empty() {
+      return None;
+    }
+    match self.field_type {
+      FieldType::RichText | FieldType::Translate | FieldType::Summary => {
+        Some(insert_text_cell(value.to_string(), self.field))
+      },
+      FieldType::URL => Some(insert_url_cell(value.to_string(), self.field)),
+      FieldType::Number => parse_number(value).map(|number| insert_text_cell(number, self.field)),
+      FieldType::Checkbox => {
+        parse_checkbox(value).map(|is_checked| insert_checkbox_cell(is_checked, self.field))
+      },
+      FieldType::DateTime => parse_date(value).map(|(timestamp, include_time)| {
+        insert_date_cell(timestamp, None, Some(include_time), self.field)
+      }),
+      FieldType::SingleSelect | FieldType::MultiSelect => {
+        let option_ids = select_option_names(value, self.field_type)
+          .filter_map(|name| {
+            self
+              .options
+              .iter()
+              .find(|option| option.name == name)
+              .map(|option| option.id.clone())
+          })
+          .collect::<Vec<_>>();
+        if option_ids.is_empty() {
+          None
+        } else {
+          Some(insert_select_option_cell(option_ids, self.field))
+        }
+      },
+      _ => None,
+    }
+  }
+}
+
+/// Returns the names of the options of a select value. The options of a multi select value are
+/// separated by commas.
+fn select_option_names(value: &str, field_type: FieldType) -> impl Iterator<Item = &str> {
+  let value = value.trim();
+  let names: Vec<&str> = if field_type == FieldType::MultiSelect {
+    value.split(',').map(|name| name.trim()).collect()
+  } else {
+    vec![value]
+  };
+  names.into_iter().filter(|name| !name.is_empty())
+}
+
+/// Returns the number without its currency symbol and thousands separators.
+pub(crate) fn parse_number(value: &str) -> Option<String> {
+  let number = value
+    .trim()
+    .trim_start_matches(&CURRENCY_SYMBOLS[..])
+    .replace(',', "");
+  number
+    .parse::<f64>()
+    .ok()
+    .filter(|number| number.is_finite())
+    .map(|_| number)
+}
+
+pub(crate) fn parse_checkbox(value: &str) -> Option<bool> {
+  match value.trim().to_lowercase().as_str() {
+    "yes" | "true" | "checked" => Some(true),
+    "no" | "false" | "unchecked" => Some(false),
+    _ => None,
+  }
+}
+
+/// Returns the timestamp of the date in UTC and whether the value includes a time.
+pub(crate) fn parse_date(value: &str) -> Option<(i64, bool)> {
+  let value = value.trim();
+  if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+    return Some((date_time.timestamp(), true));
+  }
+  for format in DATE_TIME_FORMATS {
+    if let Ok(date_time) = NaiveDateTime::parse_from_str(value, format) {
+      return Some((date_time.and_utc().timestamp(), true));
+    }
+  }
+  DATE_FORMATS.iter().find_map(|format| {
+    let date = NaiveDate::parse_from_str(value, format).ok()?;
+    let date_time = date.and_hms_opt(0, 0, 0)?;
+    Some((date_time.and_utc().timestamp(), false))
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use collab_database::database::gen_database_view_id;
+
+  use super::*;
+
+  fn field_types(params: &CreateDatabaseParams) -> Vec<FieldType> {
+    params
+      .fields
+      .iter()
+      .map(|field| FieldType::from(field.field_type))
+      .collect()
+  }
+
+  #[test]
+  fn infer_field_types_test() {
+    let s = r#"Name,Price,Done,Due,Status,Notes
+a,"$1,200",Yes,2023-05-26,Todo,first
+b,3.5,no,"May 22, 2023",Done,second
+c,,,2023-06-01 10:30,Todo,third
+d,7,true,,Todo,fourth"#;
+    let params = database_from_csv(
+      &gen_database_view_id(),
+      &CSVSource::Bytes(s.as_bytes().to_vec()),
+      &CSVImportOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+      field_types(&params),
+      vec![
+        FieldType::RichText,
+        FieldType::Number,
+        FieldType::Checkbox,
+        FieldType::DateTime,
+        FieldType::SingleSelect,
+        FieldType::RichText,
+      ]
+    );
+    assert_eq!(params.rows.len(), 4);
+
+    let options = select_type_option_from_field(&params.fields[4])
+      .unwrap()
+      .options()
+      .iter()
+      .map(|option| option.name.clone())
+      .collect::<Vec<_>>();
+    assert_eq!(options, vec!["Todo".to_string(), "Done".to_string()]);
+
+    // The empty cells are skipped
+    assert!(!params.rows[2].cells.contains_key(&params.fields[1].id));
+    assert!(params.rows[3].cells.contains_key(&params.fields[1].id));
+  }
+
+  #[test]
+  fn import_without_inference_test() {
+    let s = "Name,Price\na,1\nb,2";
+    let params = database_from_csv(
+      &gen_database_view_id(),
+      &CSVSource::Bytes(s.as_bytes().to_vec()),
+      &CSVImportOptions {
+        infer_field_types: false,
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    assert_eq!(
+      field_types(&params),
+      vec![FieldType::RichText, FieldType::RichText]
+    );
+  }
+
+  #[test]
+  fn many_distinct_values_are_text_test() {
+    let s = "Name,Tag\na,x\nb,y\nc,z\nd,x";
+    let params = database_from_csv(
+      &gen_database_view_id(),
+      &CSVSource::Bytes(s.as_bytes().to_vec()),
+      &CSVImportOptions {
+        max_select_options: 2,
+        ..Default::default()
+      },
+    )
+    .unwrap();
+    assert_eq!(params.fields.len(), 2);
+    assert_eq!(
+      FieldType::from(params.fields[1].field_type),
+      FieldType::RichText
+    );
+  }
+
+  #[test]
+  fn airtable_preset_test() {
+    let s = "Name,Tags,Notes,Done
+a,\"red,blue\",\"line 1
+line 2\",checked
+b,red,short,
+c,\"green,red\",\"x\",checked";
+    let source = CSVSource::Bytes(s.as_bytes().to_vec());
+    let params = database_from_csv(
+      &gen_database_view_id(),
+      &source,
+      &CSVImportOptions::airtable(),
+    )
+    .unwrap();
+    assert_eq!(
+      field_types(&params),
+      vec![
+        FieldType::RichText,
+        FieldType::MultiSelect,
+        FieldType::RichText,
+        FieldType::Checkbox,
+      ]
+    );
+    let options = select_type_option_from_field(&params.fields[1])
+      .unwrap()
+      .options()
+      .iter()
+      .map(|option| option.name.clone())
+      .collect::<Vec<_>>();
+    assert_eq!(options, vec!["red", "blue", "green"]);
+
+    // The comma-joined values are plain text without the preset
+    let params = database_from_csv(
+      &gen_database_view_id(),
+      &source,
+      &CSVImportOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+      FieldType::from(params.fields[1].field_type),
+      FieldType::RichText
+    );
+  }
+
+  #[test]
+  fn import_empty_csv_test() {
+    let result = database_from_csv(
+      &gen_database_view_id(),
+      &CSVSource::Bytes(vec![]),
+      &CSVImportOptions::default(),
+    );
+    assert!(result.is_err());
+  }
+}
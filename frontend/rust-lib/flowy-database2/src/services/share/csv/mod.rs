@@ -1,5 +1,7 @@
 mod export;
 mod import;
+mod infer;
 
 pub use export::*;
 pub use import::*;
+pub use infer::*;
@@ -0,0 +1,214 @@
+use collab_database::entity::CreateDatabaseParams;
+use collab_database::fields::Field;
+use flowy_error::{FlowyError, FlowyResult};
+use scraper::{ElementRef, Html, Selector};
+use url::Url;
+
+use crate::entities::FieldType;
+use crate::services::cell::insert_url_cell;
+use crate::services::field::default_type_option_data_from_type;
+use crate::services::share::csv::{database_from_csv, CSVImportOptions, CSVSource};
+
+/// The most columns a cell can span, so a broken `colspan` can't blow up the table.
+const MAX_COLSPAN: usize = 100;
+
+/// A table parsed from the HTML copied from a web page or a spreadsheet app, like Google Sheets,
+/// or from a markdown table, see [parse_markdown_table].
+///
+/// [parse_markdown_table]: crate::services::share::markdown::parse_markdown_table
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HtmlTable {
+  pub rows: Vec<Vec<HtmlTableCell>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HtmlTableCell {
+  /// The text of the cell with its whitespace collapsed.
+  pub text: String,
+  /// The first absolute link of the cell.
+  pub link: Option<String>,
+}
+
+impl HtmlTable {
+  /// Parses the first table of the HTML, without the rows of the tables nested in it. A cell that
+  /// spans multiple columns is followed by empty cells, so the cells stay in their columns.
+  /// Returns None if the HTML has no table.
+  pub fn parse(html: &str) -> Option<Self> {
+    let document = Html::parse_fragment(html);
+    let table_selector = Selector::parse("table").ok()?;
+    let row_selector = Selector::parse("tr").ok()?;
+    let link_selector = Selector::parse("a[href]").ok()?;
+    let table = document.select(&table_selector).next()?;
+
+    let rows = table
+      .select(&row_selector)
+      .filter(|row| closest_table(row).map(|element| element.id()) == Some(table.id()))
+      .map(|row| {
+        let mut cells = vec![];
+        for cell in row.children().filter_map(ElementRef::wrap) {
+          if !matches!(cell.value().name(), "td" | "th") {
+            continue;
+          }
+          let text = cell
+            .text()
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+          let link = cell
+            .select(&link_selector)
+            .filter_map(|link| link.value().attr("href"))
+            .find(|href| is_absolute_link(href))
+            .map(|href| href.to_string());
+          let colspan = cell
+            .value()
+            .attr("colspan")
+            .and_then(|colspan| colspan.trim().parse::<usize>().ok())
+            .unwrap_or(1)
+            .clamp(1, MAX_COLSPAN);
+          cells.push(HtmlTableCell { text, link });
+          cells.extend((1..colspan).map(|_| HtmlTableCell::default()));
+        }
+        cells
+      })
+      .collect();
+    Some(Self { rows })
+  }
+}
+
+/// Builds the params of a new grid database from the first table of the HTML. The first row of
+/// the table holds the names of the fields and the empty rows are skipped.
+///
+/// The field types are inferred from the texts of the cells like the values of a CSV file, see
+/// [database_from_csv], so a column of numbers becomes a number field. A column whose cells all
+/// have a link becomes a url field of the links, except for the first column, which is always the
+/// primary text field.
+pub fn database_from_html_table(view_id: &str, html: &str) -> FlowyResult<CreateDatabaseParams> {
+  let table = HtmlTable::parse(html)
+    .ok_or_else(|| FlowyError::invalid_data().with_context("The HTML has no table"))?;
+  database_from_table(view_id, table)
+}
+
+/// Builds the params of a new grid database from the rows of a table, see
+/// [database_from_html_table].
+pub(crate) fn database_from_table(
+  view_id: &str,
+  table: HtmlTable,
+) -> FlowyResult<CreateDatabaseParams> {
+  let num_of_columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+  if num_of_columns == 0 {
+    return Err(FlowyError::invalid_data().with_context("The table is empty"));
+  }
+  let mut rows = table.rows.into_iter();
+  let headers = rows.next().unwrap_or_default();
+  let records = rows
+    .filter(|row| {
+      row
+        .iter()
+        .any(|cell| !cell.text.is_empty() || cell.link.is_some())
+    })
+    .collect::<Vec<_>>();
+
+  let mut writer = csv::Writer::from_writer(vec![]);
+  let names = (0..num_of_columns).map(|index| {
+    headers
+      .get(index)
+      .map(|cell| cell.text.clone())
+      .filter(|name| !name.is_empty())
+      .unwrap_or_else(|| format!("Column {}", index + 1))
+  });
+  writer
+    .write_record(names.collect::<Vec<_>>())
+    .map_err(|e| FlowyError::internal().with_context(e))?;
+  for record in records.iter() {
+    writer
+      .write_record(
+        (0..num_of_columns).map(|index| record.get(index).map_or("", |cell| cell.text.as_str())),
+      )
+      .map_err(|e| FlowyError::internal().with_context(e))?;
+  }
+  let csv = writer
+    .into_inner()
+    .map_err(|e| FlowyError::internal().with_context(e))?;
+  let mut params = database_from_csv(
+    view_id,
+    &CSVSource::Bytes(csv),
+    &CSVImportOptions::default(),
+  )?;
+
+  for index in 1..num_of_columns {
+    let mut cells = records
+      .iter()
+      .filter_map(|record| record.get(index))
+      .filter(|cell| !cell.text.is_empty() || cell.link.is_some())
+      .peekable();
+    if cells.peek().is_none() || !cells.all(|cell| cell.link.is_some()) {
+      continue;
+    }
+
+    let field = &params.fields[index];
+    let field = Field::new(
+      field.id.clone(),
+      field.name.clone(),
+      FieldType::URL.into(),
+      false,
+    )
+    .with_type_option_data(
+      FieldType::URL,
+      default_type_option_data_from_type(FieldType::URL),
+    );
+    // The rows of the CSV are the records, in the same order
+    for (row, record) in params.rows.iter_mut().zip(records.iter()) {
+      match record.get(index).and_then(|cell| cell.link.clone()) {
+        Some(link) => {
+          row
+            .cells
+            .insert(field.id.clone(), insert_url_cell(link, &field));
+        },
+        None => {
+          row.cells.remove(&field.id);
+        },
+      }
+    }
+    params.fields[index] = field;
+  }
+  Ok(params)
+}
+
+fn closest_table<'a>(element: &ElementRef<'a>) -> Option<ElementRef<'a>> {
+  element
+    .ancestors()
+    .filter_map(ElementRef::wrap)
+    .find(|ancestor| ancestor.value().name() == "table")
+}
+
+pub(crate) fn is_absolute_link(href: &str) -> bool {
+  Url::parse(href)
+    .map(|url| matches!(url.scheme(), "http" | "https" | "mailto"))
+    .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_html_table_test() {
+    let html = r#"<meta charset="utf-8"><google-sheets-html-origin><table>
+      <tbody>
+        <tr><td colspan="2">Name  and
+          price</td><td>Link</td></tr>
+        <tr><td>A</td><td>1,200</td><td><a href="/relative">x</a> <a href="https://a.com">A</a></td></tr>
+        <tr><td><table><tr><td>nested</td></tr></table></td></tr>
+      </tbody>
+    </table>"#;
+    let table = HtmlTable::parse(html).unwrap();
+    assert_eq!(table.rows.len(), 3);
+    assert_eq!(table.rows[0].len(), 3);
+    assert_eq!(table.rows[0][0].text, "Name and price");
+    assert_eq!(table.rows[0][1], HtmlTableCell::default());
+    assert_eq!(table.rows[1][2].link.as_deref(), Some("https://a.com"));
+    assert_eq!(table.rows[2][0].text, "nested");
+    assert!(HtmlTable::parse("<p>No table</p>").is_none());
+  }
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use collab_database::fields::date_type_option::{DateCellData, DateTypeOption};
+use collab_database::fields::Field;
+use collab_database::rows::Row;
+use flowy_error::{FlowyError, FlowyResult};
+
+use crate::entities::FieldType;
+use crate::services::cell::stringify_cell;
+use crate::services::field::{should_mask_field, TimestampCellData, MASKED_CELL_VALUE};
+use crate::services::group::date_time_from_timestamp;
+use crate::services::share::csv::row_cell;
+
+const ICAL_PRODID: &str = "-//AppFlowy//Database//EN";
+/// The maximum length of a line in octets, excluding the line break, see RFC 5545 3.1.
+const MAX_LINE_LEN: usize = 75;
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+const DATE_FORMAT: &str = "%Y%m%d";
+
+/// The time of the event of a row. The dates of the all-day events are in the timezone of the
+/// date field, the end date is exclusive.
+#[derive(Debug, Clone, PartialEq)]
+enum EventTime {
+  Timed {
+    start: DateTime<Utc>,
+    end: Option<DateTime<Utc>>,
+  },
+  AllDay {
+    start: NaiveDate,
+    end: NaiveDate,
+  },
+}
+
+pub struct ICalExport;
+impl ICalExport {
+  /// Exports the rows as the events of an iCalendar (RFC 5545) feed. Each row with a date becomes
+  /// an event titled with the value of `title_field`, the rows without a date are skipped. The
+  /// uid of an event is derived from the id of its row, so calendars that subscribe to the feed
+  /// update the events instead of duplicating them.
+  pub fn export_rows(
+    &self,
+    calendar_name: &str,
+    rows: &[Arc<Row>],
+    date_field: &Field,
+    title_field: &Field,
+    reveal_sensitive: bool,
+  ) -> FlowyResult<String> {
+    if should_mask_field(date_field, reveal_sensitive) {
+      return Err(
+        FlowyError::invalid_data().with_context("Can't export the events of a sensitive field"),
+      );
+    }
+    let field_type = FieldType::from(date_field.field_type);
+    let type_option = date_field
+      .get_type_option::<DateTypeOption>(field_type)
+      .unwrap_or_default();
+
+    let mut lines = vec![
+      "BEGIN:VCALENDAR".to_string(),
+      "VERSION:2.0".to_string(),
+      format!("PRODID:{}", ICAL_PRODID),
+      "CALSCALE:GREGORIAN".to_string(),
+      format!("X-WR-CALNAME:{}", escape_text(calendar_name)),
+    ];
+    for row in rows {
+      let Some(time) = event_time(row, date_field, field_type, &type_option) else {
+        continue;
+      };
+      let title = if should_mask_field(title_field, reveal_sensitive) {
+        MASKED_CELL_VALUE.to_string()
+      } else {
+        row_cell(row, title_field)
+          .map(|cell| stringify_cell(&cell, title_field))
+          .unwrap_or_default()
+      };
+      let modified_at = DateTime::from_timestamp(row.modified_at, 0).unwrap_or_default();
+
+      lines.push("BEGIN:VEVENT".to_string());
+      lines.push(format!("UID:{}@appflowy", row.id));
+      lines.push(format!("DTSTAMP:{}", modified_at.format(DATE_TIME_FORMAT)));
+      match time {
+        EventTime::Timed { start, end } => {
+          lines.push(format!("DTSTART:{}", start.format(DATE_TIME_FORMAT)));
+          if let Some(end) = end {
+            lines.push(format!("DTEND:{}", end.format(DATE_TIME_FORMAT)));
+          }
+        },
+        EventTime::AllDay { start, end } => {
+          lines.push(format!("DTSTART;VALUE=DATE:{}", start.format(DATE_FORMAT)));
+          lines.push(format!("DTEND;VALUE=DATE:{}", end.format(DATE_FORMAT)));
+        },
+      }
+      lines.push(format!("SUMMARY:{}", escape_text(title.trim())));
+      lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    Ok(
+      lines
+        .iter()
+        .map(|line| format!("{}\r\n", fold_line(line)))
+        .collect(),
+    )
+  }
+}
+
+fn event_time(
+  row: &Row,
+  date_field: &Field,
+  field_type: FieldType,
+  type_option: &DateTypeOption,
+) -> Option<EventTime> {
+  let cell = row_cell(row, date_field)?;
+  match field_type {
+    FieldType::DateTime => {
+      let data = DateCellData::from(&cell);
+      let timestamp = data.timestamp?;
+      let end_timestamp = data.end_timestamp.filter(|_| data.is_range);
+      if data.include_time {
+        Some(EventTime::Timed {
+          start: DateTime::from_timestamp(timestamp, 0)?,
+          end: end_timestamp.and_then(|timestamp| DateTime::from_timestamp(timestamp, 0)),
+        })
+      } else {
+        let start = date_time_from_timestamp(Some(timestamp), type_option).date_naive();
+        let end = end_timestamp
+          .map(|timestamp| date_time_from_timestamp(Some(timestamp), type_option).date_naive())
+          .filter(|end| *end >= start)
+          .unwrap_or(start);
+        Some(EventTime::AllDay {
+          start,
+          end: end.checked_add_days(Days::new(1))?,
+        })
+      }
+    },
+    FieldType::CreatedTime | FieldType::LastEditedTime => Some(EventTime::Timed {
+      start: DateTime::from_timestamp(TimestampCellData::from(&cell).timestamp?, 0)?,
+      end: None,
+    }),
+    _ => None,
+  }
+}
+
+/// Escapes the characters that have a meaning in the value of a text property.
+fn escape_text(text: &str) -> String {
+  let mut escaped = String::with_capacity(text.len());
+  for c in text.chars() {
+    match c {
+      '\\' => escaped.push_str("\\\\"),
+      ';' => escaped.push_str("\\;"),
+      ',' => escaped.push_str("\\,"),
+      '\n' => escaped.push_str("\\n"),
+      '\r' => {},
+      c => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+/// Splits the line into lines of at most [MAX_LINE_LEN] octets. Each continuation line starts
+/// with a space, which counts towards its length. A line is never split inside a character.
+fn fold_line(line: &str) -> String {
+  let mut folded = String::with_capacity(line.len());
+  let mut line_len = 0;
+  for c in line.chars() {
+    if line_len + c.len_utf8() > MAX_LINE_LEN {
+      folded.push_str("\r\n ");
+      line_len = 1;
+    }
+    folded.push(c);
+    line_len += c.len_utf8();
+  }
+  folded
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn escape_text_test() {
+    assert_eq!(
+      escape_text("Plan; review, ship\r\n\\done"),
+      "Plan\\; review\\, ship\\n\\\\done"
+    );
+  }
+
+  #[test]
+  fn fold_line_test() {
+    assert_eq!(fold_line("SUMMARY:short"), "SUMMARY:short");
+
+    let line = format!("SUMMARY:{}", "é".repeat(40));
+    let folded = fold_line(&line);
+    assert!(folded.split("\r\n").all(|line| line.len() <= MAX_LINE_LEN));
+    assert_eq!(folded.replace("\r\n ", ""), line);
+  }
+}
@@ -0,0 +1,295 @@
+use std::collections::{HashMap, HashSet};
+
+use collab_database::database::{gen_database_id, gen_field_id, gen_row_id, Database};
+use collab_database::entity::CreateDatabaseParams;
+use collab_database::fields::Field;
+use collab_database::rows::{new_cell_builder, Cell, Cells, CreateRowParams, Row};
+use flowy_error::{FlowyError, FlowyResult};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::entities::FieldType;
+use crate::services::field::{should_mask_field, CELL_DATA, MASKED_CELL_VALUE};
+use crate::services::share::grid_database_params;
+
+/// The version of the [GridJson] format. The importer rejects the files of a newer version.
+pub const GRID_JSON_VERSION: i64 = 1;
+
+/// A portable copy of the fields and rows of a grid, e.g. to move a grid to another installation
+/// or to check it into git.
+///
+/// The ids of the database, its views and its rows are left out, so a grid can be imported any
+/// number of times. The field ids are kept because the cells refer to them, unless the grid is
+/// created from a template. The objects are written with sorted keys, so exporting an imported
+/// grid gives back the same bytes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GridJson {
+  pub version: i64,
+  /// The fields with their type options, in the order of the exported view.
+  pub fields: Vec<Value>,
+  /// The rows in the order of the exported view.
+  pub rows: Vec<GridJsonRow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GridJsonRow {
+  /// The cells of the row by field id.
+  pub cells: Value,
+  pub height: i32,
+  pub visibility: bool,
+  pub created_at: i64,
+  pub modified_at: i64,
+}
+
+impl GridJson {
+  /// Exports the fields and rows of the view, ignoring its filters and sorts. The cells of the
+  /// sensitive fields are masked unless `reveal_sensitive` is true.
+  pub async fn from_database(
+    database: &Database,
+    view_id: &str,
+    reveal_sensitive: bool,
+  ) -> FlowyResult<Self> {
+    let fields = database.get_fields_in_view(view_id, None);
+    let rows = database
+      .get_rows_for_view(view_id, None)
+      .await
+      .filter_map(|result| async { result.ok() })
+      .collect::<Vec<_>>()
+      .await;
+    Self::from_rows(&fields, rows.iter(), reveal_sensitive)
+  }
+
+  /// Exports the given fields and rows, in the given order. The cells of the other fields are
+  /// left out, and the cells of the sensitive fields hold [MASKED_CELL_VALUE] unless
+  /// `reveal_sensitive` is true.
+  pub fn from_rows<'a>(
+    fields: &[Field],
+    rows: impl Iterator<Item = &'a Row>,
+    reveal_sensitive: bool,
+  ) -> FlowyResult<Self> {
+    let fields_by_id = fields
+      .iter()
+      .map(|field| (field.id.as_str(), field))
+      .collect::<HashMap<_, _>>();
+    let json_fields = fields
+      .iter()
+      .map(|field| serde_json::to_value(field).map(sorted_value))
+      .collect::<Result<Vec<_>, _>>()?;
+    let rows = rows
+      .map(|row| {
+        let mut cells = Cells::default();
+        for (field_id, cell) in row.cells.iter() {
+          match fields_by_id.get(field_id.as_str()) {
+            Some(field) if should_mask_field(field, reveal_sensitive) => {
+              cells.insert(field_id.clone(), masked_cell(field));
+            },
+            Some(_) => {
+              cells.insert(field_id.clone(), cell.clone());
+            },
+            None => {},
+          }
+        }
+        Ok(GridJsonRow {
+          cells: sorted_value(serde_json::to_value(&cells)?),
+          height: row.height,
+          visibility: row.visibility,
+          created_at: row.created_at,
+          modified_at: row.modified_at,
+        })
+      })
+      .collect::<FlowyResult<Vec<_>>>()?;
+    Ok(Self {
+      version: GRID_JSON_VERSION,
+      fields: json_fields,
+      rows,
+    })
+  }
+
+  pub fn to_json(&self) -> FlowyResult<String> {
+    Ok(serde_json::to_string_pretty(self)?)
+  }
+
+  pub fn from_json(json: &str) -> FlowyResult<Self> {
+    let grid: Self = serde_json::from_str(json)
+      .map_err(|err| FlowyError::invalid_data().with_context(format!("Invalid grid: {}", err)))?;
+    if grid.version > GRID_JSON_VERSION {
+      return Err(
+        FlowyError::invalid_data()
+          .with_context(format!("Unsupported grid version: {}", grid.version)),
+      );
+    }
+    Ok(grid)
+  }
+
+  /// Returns the params of a new grid database whose inline view is `view_id`. The rows get new
+  /// ids and keep their cells and timestamps.
+  pub fn into_params(self, view_id: &str) -> FlowyResult<CreateDatabaseParams> {
+    self.build_params(view_id, false)
+  }
+
+  /// Returns the params of a new grid database like [Self::into_params], but the fields get new
+  /// ids too, so the grids created from the same template don't share any ids.
+  pub fn into_template_params(self, view_id: &str) -> FlowyResult<CreateDatabaseParams> {
+    self.build_params(view_id, true)
+  }
+
+  fn build_params(self, view_id: &str, new_field_ids: bool) -> FlowyResult<CreateDatabaseParams> {
+    let database_id = gen_database_id();
+    let mut fields = self
+      .fields
+      .into_iter()
+      .map(serde_json::from_value::<Field>)
+      .collect::<Result<Vec<_>, _>>()
+      .map_err(|err| FlowyError::invalid_data().with_context(format!("Invalid field: {}", err)))?;
+    validate_fields(&fields)?;
+
+    // The new id of each field by its id in the JSON
+    let field_ids = fields
+      .iter_mut()
+      .map(|field| {
+        let field_id = if new_field_ids {
+          gen_field_id()
+        } else {
+          field.id.clone()
+        };
+        (std::mem::replace(&mut field.id, field_id.clone()), field_id)
+      })
+      .collect::<HashMap<_, _>>();
+
+    let rows = self
+      .rows
+      .into_iter()
+      .map(|row| {
+        let cells = serde_json::from_value::<Cells>(row.cells).map_err(|err| {
+          FlowyError::invalid_data().with_context(format!("Invalid cells: {}", err))
+        })?;
+        // The cells of the fields that aren't part of the grid are dropped
+        let mut row_cells = Cells::default();
+        for (field_id, cell) in cells.iter() {
+          if let Some(field_id) = field_ids.get(field_id) {
+            row_cells.insert(field_id.clone(), cell.clone());
+          }
+        }
+        Ok(CreateRowParams {
+          id: gen_row_id(),
+          database_id: database_id.clone(),
+          cells: row_cells,
+          height: row.height,
+          visibility: row.visibility,
+          row_position: Default::default(),
+          created_at: row.created_at,
+          modified_at: row.modified_at,
+        })
+      })
+      .collect::<FlowyResult<Vec<_>>>()?;
+
+    Ok(grid_database_params(view_id, database_id, fields, rows))
+  }
+}
+
+/// The cell that stands for the value of a sensitive field in an export.
+fn masked_cell(field: &Field) -> Cell {
+  let mut cell = new_cell_builder(FieldType::from(field.field_type));
+  cell.insert(CELL_DATA.into(), MASKED_CELL_VALUE.into());
+  cell
+}
+
+/// Checks that the grid has fields, that the ids of the fields are unique and that exactly one of
+/// them is the primary field.
+fn validate_fields(fields: &[Field]) -> FlowyResult<()> {
+  if fields.is_empty() {
+    return Err(FlowyError::invalid_data().with_context("The grid has no fields"));
+  }
+  let mut field_ids = HashSet::new();
+  if let Some(field) = fields
+    .iter()
+    .find(|field| !field_ids.insert(field.id.as_str()))
+  {
+    return Err(
+      FlowyError::invalid_data().with_context(format!("Duplicate field id: {}", field.id)),
+    );
+  }
+  let num_of_primary_fields = fields.iter().filter(|field| field.is_primary).count();
+  if num_of_primary_fields != 1 {
+    return Err(FlowyError::invalid_data().with_context(format!(
+      "The grid must have one primary field, found {}",
+      num_of_primary_fields
+    )));
+  }
+  Ok(())
+}
+
+/// Rebuilds the objects of the value with their keys sorted, so the output doesn't depend on the
+/// iteration order of the maps it's made from.
+fn sorted_value(value: Value) -> Value {
+  match value {
+    Value::Object(map) => {
+      let mut entries = map.into_iter().collect::<Vec<_>>();
+      entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+      Value::Object(
+        entries
+          .into_iter()
+          .map(|(key, value)| (key, sorted_value(value)))
+          .collect::<Map<_, _>>(),
+      )
+    },
+    Value::Array(values) => Value::Array(values.into_iter().map(sorted_value).collect()),
+    value => value,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn sorted_value_test() {
+    let value = sorted_value(json!({"b": {"d": 1, "c": [{"f": 2, "e": 3}]}, "a": null}));
+    assert_eq!(
+      serde_json::to_string(&value).unwrap(),
+      r#"{"a":null,"b":{"c":[{"e":3,"f":2}],"d":1}}"#
+    );
+  }
+
+  #[test]
+  fn reject_invalid_fields_test() {
+    let field = |id: &str, is_primary: bool| {
+      serde_json::to_value(Field::new(
+        id.to_string(),
+        "Name".to_string(),
+        0,
+        is_primary,
+      ))
+      .unwrap()
+    };
+    let grid = |fields: Vec<Value>| GridJson {
+      version: GRID_JSON_VERSION,
+      fields,
+      rows: vec![],
+    };
+    assert!(grid(vec![]).into_template_params("v").is_err());
+    assert!(grid(vec![field("a", true), field("a", false)])
+      .into_template_params("v")
+      .is_err());
+    assert!(grid(vec![field("a", false)])
+      .into_template_params("v")
+      .is_err());
+
+    let params = grid(vec![field("a", true), field("b", false)])
+      .into_template_params("v")
+      .unwrap();
+    assert!(params
+      .fields
+      .iter()
+      .all(|field| field.id != "a" && field.id != "b"));
+  }
+
+  #[test]
+  fn reject_newer_version_test() {
+    let json = r#"{"version": 2, "fields": [], "rows": []}"#;
+    assert!(GridJson::from_json(json).is_err());
+  }
+}
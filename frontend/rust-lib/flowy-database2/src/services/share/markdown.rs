@@ -0,0 +1,222 @@
+use std::sync::Arc;
+
+use collab_database::entity::CreateDatabaseParams;
+use collab_database::fields::Field;
+use collab_database::rows::Row;
+use flowy_error::{FlowyError, FlowyResult};
+
+use crate::services::cell::stringify_cell;
+use crate::services::field::{should_mask_field, MASKED_CELL_VALUE};
+use crate::services::share::csv::row_cell;
+use crate::services::share::html::{
+  database_from_table, is_absolute_link, HtmlTable, HtmlTableCell,
+};
+
+/// Parses the first table of the markdown, written in the GitHub flavored syntax: a header row,
+/// a delimiter row like `| --- | :-: |` and the body rows, which end at the first line that isn't
+/// part of the table. Returns None if the markdown has no table.
+///
+/// The body rows are padded or cut to the number of columns of the header. The inline formatting
+/// of the cells is dropped, and the first absolute link of a cell is kept as its link.
+pub fn parse_markdown_table(markdown: &str) -> Option<HtmlTable> {
+  let lines = markdown.lines().map(str::trim).collect::<Vec<_>>();
+  let start = (0..lines.len().saturating_sub(1)).find(|&index| {
+    lines[index].contains('|')
+      && is_delimiter_row(lines[index + 1])
+      && split_row(lines[index]).len() == split_row(lines[index + 1]).len()
+  })?;
+  let num_of_columns = split_row(lines[start]).len();
+  let rows = std::iter::once(lines[start])
+    .chain(
+      lines[start + 2..]
+        .iter()
+        .copied()
+        .take_while(|line| line.contains('|')),
+    )
+    .map(|line| {
+      let mut cells = split_row(line)
+        .into_iter()
+        .map(|cell| markdown_cell(&cell))
+        .collect::<Vec<_>>();
+      cells.resize(num_of_columns, HtmlTableCell::default());
+      cells
+    })
+    .collect();
+  Some(HtmlTable { rows })
+}
+
+/// Builds the params of a new grid database from the first table of the markdown. The fields are
+/// made like the fields of a table pasted as HTML, see [database_from_html_table].
+///
+/// [database_from_html_table]: crate::services::share::html::database_from_html_table
+pub fn database_from_markdown_table(
+  view_id: &str,
+  markdown: &str,
+) -> FlowyResult<CreateDatabaseParams> {
+  let table = parse_markdown_table(markdown)
+    .ok_or_else(|| FlowyError::invalid_data().with_context("The markdown has no table"))?;
+  database_from_table(view_id, table)
+}
+
+/// Writes the rows as a GitHub flavored markdown table, with a column per field. Each cell is
+/// formatted the way its field displays it, like the cells of a CSV export, and the values of the
+/// sensitive fields are masked unless `reveal_sensitive` is true. The pipes of the cells are
+/// escaped and their line breaks are written as `<br>`, so [parse_markdown_table] reads the table
+/// back.
+pub fn markdown_table_from_rows(
+  fields: &[Field],
+  rows: &[Arc<Row>],
+  reveal_sensitive: bool,
+) -> String {
+  let mut markdown = String::new();
+  push_row(&mut markdown, fields.iter().map(|field| field.name.clone()));
+  push_row(&mut markdown, fields.iter().map(|_| "---".to_string()));
+  for row in rows {
+    push_row(
+      &mut markdown,
+      fields.iter().map(|field| {
+        if should_mask_field(field, reveal_sensitive) {
+          return MASKED_CELL_VALUE.to_string();
+        }
+        row_cell(row, field)
+          .map(|cell| stringify_cell(&cell, field))
+          .unwrap_or_default()
+      }),
+    );
+  }
+  markdown
+}
+
+fn push_row(markdown: &mut String, cells: impl Iterator<Item = String>) {
+  markdown.push('|');
+  for cell in cells {
+    let cell = cell
+      .replace('|', "\\|")
+      .replace("\r\n", "<br>")
+      .replace('\n', "<br>");
+    markdown.push_str(&format!(" {} |", cell.trim()));
+  }
+  markdown.push('\n');
+}
+
+/// Splits the row at the pipes that aren't escaped, without the optional leading and trailing
+/// pipes. The escaped pipes of the cells are unescaped.
+fn split_row(line: &str) -> Vec<String> {
+  let line = line.strip_prefix('|').unwrap_or(line);
+  let line = match line.strip_suffix('|') {
+    Some(rest) if !rest.ends_with('\\') => rest,
+    _ => line,
+  };
+  let mut cells = vec![];
+  let mut cell = String::new();
+  let mut chars = line.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '\\' if chars.peek() == Some(&'|') => {
+        cell.push('|');
+        chars.next();
+      },
+      '|' => cells.push(std::mem::take(&mut cell)),
+      c => cell.push(c),
+    }
+  }
+  cells.push(cell);
+  cells
+    .into_iter()
+    .map(|cell| cell.trim().to_string())
+    .collect()
+}
+
+fn is_delimiter_row(line: &str) -> bool {
+  line.contains('-')
+    && split_row(line).iter().all(|cell| {
+      let dashes = cell.strip_prefix(':').unwrap_or(cell);
+      let dashes = dashes.strip_suffix(':').unwrap_or(dashes);
+      !dashes.is_empty() && dashes.chars().all(|c| c == '-')
+    })
+}
+
+/// Returns the text of the cell without its inline formatting, and its first absolute link. A
+/// link is written as `[text](url)`, as `<url>` or as the url alone.
+fn markdown_cell(cell: &str) -> HtmlTableCell {
+  let mut text = String::new();
+  let mut link = None;
+  let mut rest = cell;
+  while !rest.is_empty() {
+    if let Some((label, url, len)) = inline_link(rest) {
+      if link.is_none() && is_absolute_link(url) {
+        link = Some(url.to_string());
+      }
+      text.push_str(label);
+      rest = &rest[len..];
+    } else {
+      let c = rest.chars().next().unwrap_or_default();
+      text.push(c);
+      rest = &rest[c.len_utf8()..];
+    }
+  }
+
+  let text = text
+    .replace("<br>", " ")
+    .replace("<br/>", " ")
+    .replace("**", "")
+    .replace("__", "")
+    .replace("~~", "")
+    .replace('`', "")
+    .split_whitespace()
+    .collect::<Vec<_>>()
+    .join(" ");
+  if link.is_none() && is_absolute_link(&text) {
+    link = Some(text.clone());
+  }
+  HtmlTableCell { text, link }
+}
+
+/// Returns the label, the url and the length of the link at the start of the text, if any.
+fn inline_link(text: &str) -> Option<(&str, &str, usize)> {
+  if let Some(rest) = text.strip_prefix('<') {
+    let end = rest.find('>')?;
+    let url = &rest[..end];
+    return is_absolute_link(url).then_some((url, url, end + 2));
+  }
+  let rest = text.strip_prefix('[')?;
+  let label_end = rest.find("](")?;
+  let label = &rest[..label_end];
+  let url_start = label_end + 2;
+  let url_len = rest[url_start..].find(')')?;
+  // The url may be followed by a title, like `[text](url "title")`
+  let url = rest[url_start..url_start + url_len]
+    .split_whitespace()
+    .next()
+    .unwrap_or_default();
+  Some((label, url, 1 + url_start + url_len + 1))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_markdown_table_test() {
+    let markdown = r#"Some text
+| Name | Price | Link |
+|:-----|------:|:----:|
+| **A** | 1,200 | [Site](https://a.com "A") |
+| B \| C | `2` |
+| D | 3 | <https://d.com> | extra |
+
+After the table"#;
+    let table = parse_markdown_table(markdown).unwrap();
+    assert_eq!(table.rows.len(), 4);
+    assert!(table.rows.iter().all(|row| row.len() == 3));
+    assert_eq!(table.rows[0][2].text, "Link");
+    assert_eq!(table.rows[1][0].text, "A");
+    assert_eq!(table.rows[1][2].text, "Site");
+    assert_eq!(table.rows[1][2].link.as_deref(), Some("https://a.com"));
+    assert_eq!(table.rows[2][0].text, "B | C");
+    assert_eq!(table.rows[2][1].text, "2");
+    assert_eq!(table.rows[2][2], HtmlTableCell::default());
+    assert_eq!(table.rows[3][2].link.as_deref(), Some("https://d.com"));
+    assert!(parse_markdown_table("| Not | a table |").is_none());
+  }
+}
@@ -1 +1,44 @@
+use collab_database::database::timestamp;
+use collab_database::entity::{CreateDatabaseParams, CreateViewParams};
+use collab_database::fields::Field;
+use collab_database::rows::CreateRowParams;
+use collab_database::views::DatabaseLayout;
+
+use crate::services::field_settings::default_field_settings_for_fields;
+
+pub mod backup;
+pub mod clipboard;
 pub mod csv;
+pub mod html;
+pub mod ical;
+pub mod json;
+pub mod markdown;
+pub mod notion;
+pub mod xlsx;
+
+/// Returns the params of a new database with a single grid view, used by the importers.
+pub(crate) fn grid_database_params(
+  view_id: &str,
+  database_id: String,
+  fields: Vec<Field>,
+  rows: Vec<CreateRowParams>,
+) -> CreateDatabaseParams {
+  let field_settings = default_field_settings_for_fields(&fields, DatabaseLayout::Grid);
+  let timestamp = timestamp();
+  CreateDatabaseParams {
+    database_id: database_id.clone(),
+    inline_view_id: view_id.to_string(),
+    rows,
+    fields,
+    views: vec![CreateViewParams {
+      database_id,
+      view_id: view_id.to_string(),
+      name: "".to_string(),
+      layout: DatabaseLayout::Grid,
+      field_settings,
+      created_at: timestamp,
+      modified_at: timestamp,
+      ..Default::default()
+    }],
+  }
+}
@@ -0,0 +1,195 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::{Cursor, Read};
+
+use collab::core::origin::CollabOrigin;
+use collab::preclude::Collab;
+use collab_database::entity::CreateDatabaseParams;
+use collab_database::rows::RowId;
+use collab_document::document::Document;
+use collab_document::importer::md_importer::MDImporter;
+use collab_entity::EncodedCollab;
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use zip::ZipArchive;
+
+use crate::services::cell::stringify_cell;
+use crate::services::share::csv::CSVSource;
+
+/// The length of the id that Notion appends to the name of each exported file.
+const NOTION_ID_LEN: usize = 32;
+
+/// A database exported by Notion as a zip file. The zip has the rows as CSV and a markdown page
+/// for each row, named after the title of the row.
+#[derive(Debug, Clone)]
+pub struct NotionExport {
+  pub csv: Vec<u8>,
+  pub pages: Vec<NotionPage>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotionPage {
+  pub title: String,
+  pub markdown: String,
+}
+
+impl NotionExport {
+  /// Reads the CSV and the markdown pages of the database from the zip. The CSV with all the
+  /// rows is preferred over the one with the rows of the exported view only.
+  pub fn from_zip(data: Vec<u8>) -> FlowyResult<Self> {
+    let mut archive = ZipArchive::new(Cursor::new(data))
+      .map_err(|err| FlowyError::invalid_data().with_context(format!("Invalid zip: {}", err)))?;
+    let names = archive
+      .file_names()
+      .map(|name| name.to_string())
+      .collect::<Vec<_>>();
+    let csv_name = names
+      .iter()
+      .filter(|name| name.ends_with(".csv"))
+      .min_by_key(|name| (!name.ends_with("_all.csv"), name.matches('/').count()))
+      .cloned()
+      .ok_or_else(|| FlowyError::invalid_data().with_context("The zip has no CSV file"))?;
+    let csv = read_zip_file(&mut archive, &csv_name)?;
+
+    // The pages of the rows are in the folder that is named like the CSV file
+    let page_folder = format!(
+      "{}/",
+      csv_name.trim_end_matches(".csv").trim_end_matches("_all")
+    );
+    let mut pages = vec![];
+    for name in &names {
+      let Some(file_name) = name.strip_prefix(&page_folder) else {
+        continue;
+      };
+      if file_name.contains('/') || !file_name.ends_with(".md") {
+        continue;
+      }
+      let markdown = String::from_utf8(read_zip_file(&mut archive, name)?)
+        .map_err(|err| FlowyError::invalid_data().with_context(err))?;
+      pages.push(NotionPage {
+        title: notion_title(file_name.trim_end_matches(".md")).to_string(),
+        markdown,
+      });
+    }
+    Ok(Self { csv, pages })
+  }
+
+  pub fn csv_source(&self) -> CSVSource {
+    CSVSource::Bytes(self.csv.clone())
+  }
+
+  /// Matches the pages to the rows of the database by the title of the row, which is the value of
+  /// its primary field. The pages of the rows with the same title are matched in order. Returns
+  /// the content of the page of each matched row, without the title and the properties that are
+  /// columns of the CSV already.
+  pub fn row_pages(&self, params: &CreateDatabaseParams) -> Vec<(RowId, String)> {
+    let mut pages_by_title = HashMap::<&str, VecDeque<&NotionPage>>::new();
+    for page in &self.pages {
+      pages_by_title
+        .entry(page.title.as_str())
+        .or_default()
+        .push_back(page);
+    }
+    let Some(primary_field) = params.fields.iter().find(|field| field.is_primary) else {
+      return vec![];
+    };
+    let headers = params
+      .fields
+      .iter()
+      .map(|field| field.name.as_str())
+      .collect::<Vec<_>>();
+
+    params
+      .rows
+      .iter()
+      .filter_map(|row| {
+        let title = row
+          .cells
+          .get(&primary_field.id)
+          .map(|cell| stringify_cell(cell, primary_field))
+          .unwrap_or_default();
+        let page = pages_by_title.get_mut(title.trim())?.pop_front()?;
+        let content = page_content(&page.markdown, &headers);
+        if content.is_empty() {
+          None
+        } else {
+          Some((row.id.clone(), content.to_string()))
+        }
+      })
+      .collect()
+  }
+}
+
+/// Returns the encoded collab of a new document with the content of the markdown.
+pub fn document_from_markdown(document_id: &str, markdown: &str) -> FlowyResult<EncodedCollab> {
+  let data = MDImporter::new(None)
+    .import(document_id, markdown.to_string())
+    .map_err(internal_error)?;
+  let collab = Collab::new_with_origin(CollabOrigin::Empty, document_id, vec![], false);
+  let document = Document::create_with_data(collab, data).map_err(internal_error)?;
+  document.encode_collab().map_err(internal_error)
+}
+
+fn read_zip_file(archive: &mut ZipArchive<Cursor<Vec<u8>>>, name: &str) -> FlowyResult<Vec<u8>> {
+  let mut file = archive
+    .by_name(name)
+    .map_err(|err| FlowyError::invalid_data().with_context(err))?;
+  let mut data = vec![];
+  file.read_to_end(&mut data)?;
+  Ok(data)
+}
+
+/// Removes the id that Notion appends to the names of the exported files.
+fn notion_title(file_stem: &str) -> &str {
+  match file_stem.rsplit_once(' ') {
+    Some((title, id)) if is_notion_id(id) => title,
+    _ => file_stem,
+  }
+}
+
+fn is_notion_id(id: &str) -> bool {
+  id.len() == NOTION_ID_LEN && id.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Returns the markdown of the page without its title heading and without the lines of the
+/// properties of the row that Notion writes below the title.
+fn page_content<'a>(markdown: &'a str, headers: &[&str]) -> &'a str {
+  let mut content = markdown.trim_start();
+  if content.starts_with("# ") {
+    content = content.split_once('\n').map(|(_, rest)| rest).unwrap_or("");
+  }
+  loop {
+    content = content.trim_start();
+    let line = content.split('\n').next().unwrap_or("");
+    let is_property = line
+      .split_once(':')
+      .map(|(key, _)| headers.contains(&key.trim()))
+      .unwrap_or(false);
+    if !is_property {
+      return content.trim_end();
+    }
+    content = &content[line.len()..];
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn notion_title_test() {
+    assert_eq!(
+      notion_title("Write docs 0123456789abcdef0123456789abcdef"),
+      "Write docs"
+    );
+    assert_eq!(notion_title("Write docs"), "Write docs");
+  }
+
+  #[test]
+  fn page_content_test() {
+    let markdown = "# Write docs\n\nStatus: Done\nTags: a, b\n\nFirst paragraph\n\nNote: kept\n";
+    assert_eq!(
+      page_content(markdown, &["Name", "Status", "Tags"]),
+      "First paragraph\n\nNote: kept"
+    );
+    assert_eq!(page_content("# Title\n\nStatus: Done\n", &["Status"]), "");
+  }
+}
@@ -0,0 +1,201 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use collab_database::fields::date_type_option::DateCellData;
+use collab_database::fields::Field;
+use collab_database::rows::{Cell, Row};
+use flowy_error::{FlowyError, FlowyResult};
+use rust_xlsxwriter::{ExcelDateTime, Format, Workbook, Worksheet, XlsxError};
+
+use crate::entities::{CheckboxCellDataPB, FieldType};
+use crate::services::cell::stringify_cell;
+use crate::services::field::{
+  should_mask_field, NumberCellData, TimestampCellData, MASKED_CELL_VALUE,
+};
+use crate::services::share::csv::{parse_number, row_cell};
+
+const MAX_SHEET_NAME_LEN: usize = 31;
+const INVALID_SHEET_NAME_CHARS: [char; 7] = ['[', ']', ':', '*', '?', '/', '\\'];
+const DATE_FORMAT: &str = "yyyy-mm-dd";
+const DATE_TIME_FORMAT: &str = "yyyy-mm-dd hh:mm";
+
+/// The fields and rows of a view, exported as one sheet of the workbook.
+#[derive(Debug, Clone)]
+pub struct XLSXSheet {
+  pub name: String,
+  pub fields: Vec<Field>,
+  pub rows: Vec<Arc<Row>>,
+}
+
+pub struct XLSXExport;
+impl XLSXExport {
+  /// Exports each sheet as a worksheet of a new xlsx workbook, with a header row of the field
+  /// names. Numbers, checkboxes and dates are written as typed cells, the dates in UTC. The other
+  /// cells are written the way their field displays them.
+  pub fn export_sheets(
+    &self,
+    sheets: &[XLSXSheet],
+    reveal_sensitive: bool,
+  ) -> FlowyResult<Vec<u8>> {
+    let mut workbook = Workbook::new();
+    let header_format = Format::new().set_bold();
+    let date_format = Format::new().set_num_format(DATE_FORMAT);
+    let date_time_format = Format::new().set_num_format(DATE_TIME_FORMAT);
+    let names = sheet_names(sheets.iter().map(|sheet| sheet.name.as_str()));
+    for (sheet, name) in sheets.iter().zip(names) {
+      let worksheet = workbook.add_worksheet();
+      worksheet.set_name(name).map_err(xlsx_error)?;
+      for (column, field) in sheet.fields.iter().enumerate() {
+        worksheet
+          .write_string_with_format(0, column as u16, &field.name, &header_format)
+          .map_err(xlsx_error)?;
+      }
+      worksheet.set_freeze_panes(1, 0).map_err(xlsx_error)?;
+
+      for (index, row) in sheet.rows.iter().enumerate() {
+        let row_num = index as u32 + 1;
+        for (column, field) in sheet.fields.iter().enumerate() {
+          let column = column as u16;
+          if should_mask_field(field, reveal_sensitive) {
+            worksheet
+              .write_string(row_num, column, MASKED_CELL_VALUE)
+              .map_err(xlsx_error)?;
+            continue;
+          }
+          if let Some(cell) = row_cell(row, field) {
+            let formats = (&date_format, &date_time_format);
+            write_cell(worksheet, row_num, column, &cell, field, formats).map_err(xlsx_error)?;
+          }
+        }
+      }
+    }
+    workbook.save_to_buffer().map_err(xlsx_error)
+  }
+}
+
+fn write_cell(
+  worksheet: &mut Worksheet,
+  row: u32,
+  column: u16,
+  cell: &Cell,
+  field: &Field,
+  (date_format, date_time_format): (&Format, &Format),
+) -> Result<(), XlsxError> {
+  match FieldType::from(field.field_type) {
+    FieldType::Checkbox => {
+      worksheet.write_boolean(row, column, CheckboxCellDataPB::from(cell).is_checked)?;
+      return Ok(());
+    },
+    FieldType::Number => {
+      let data = NumberCellData::from(cell);
+      if let Some(number) =
+        parse_number(data.as_ref()).and_then(|number| number.parse::<f64>().ok())
+      {
+        worksheet.write_number(row, column, number)?;
+        return Ok(());
+      }
+    },
+    FieldType::DateTime => {
+      let data = DateCellData::from(cell);
+      if let Some(timestamp) = data.timestamp {
+        let format = if data.include_time {
+          date_time_format
+        } else {
+          date_format
+        };
+        let date_time = ExcelDateTime::from_timestamp(timestamp)?;
+        worksheet.write_datetime_with_format(row, column, &date_time, format)?;
+        return Ok(());
+      }
+    },
+    FieldType::CreatedTime | FieldType::LastEditedTime => {
+      if let Some(timestamp) = TimestampCellData::from(cell).timestamp {
+        let date_time = ExcelDateTime::from_timestamp(timestamp)?;
+        worksheet.write_datetime_with_format(row, column, &date_time, date_time_format)?;
+        return Ok(());
+      }
+    },
+    _ => {},
+  }
+  let text = stringify_cell(cell, field);
+  if !text.is_empty() {
+    worksheet.write_string(row, column, text)?;
+  }
+  Ok(())
+}
+
+/// Returns a valid and unique name for each sheet. Excel limits the names to 31 characters and
+/// doesn't allow some characters in them.
+fn sheet_names<'a>(names: impl Iterator<Item = &'a str>) -> Vec<String> {
+  let mut used = HashSet::new();
+  names
+    .enumerate()
+    .map(|(index, name)| {
+      let name = name
+        .chars()
+        .map(|c| {
+          if INVALID_SHEET_NAME_CHARS.contains(&c) {
+            ' '
+          } else {
+            c
+          }
+        })
+        .collect::<String>();
+      let name = name.trim().trim_matches('\'').trim();
+      let name = if name.is_empty() {
+        format!("Sheet {}", index + 1)
+      } else {
+        name.chars().take(MAX_SHEET_NAME_LEN).collect()
+      };
+
+      let mut unique_name = name.clone();
+      let mut count = 1;
+      while !used.insert(unique_name.to_lowercase()) {
+        count += 1;
+        let suffix = format!(" ({})", count);
+        let prefix = name
+          .chars()
+          .take(MAX_SHEET_NAME_LEN - suffix.chars().count())
+          .collect::<String>();
+        unique_name = format!("{}{}", prefix, suffix);
+      }
+      unique_name
+    })
+    .collect()
+}
+
+fn xlsx_error(err: XlsxError) -> FlowyError {
+  FlowyError::internal().with_context(err)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sheet_names_test() {
+    let long_name = "a".repeat(40);
+    let names = sheet_names(
+      [
+        "Grid",
+        "grid",
+        "",
+        "Q1/Q2: [plan]",
+        long_name.as_str(),
+        long_name.as_str(),
+      ]
+      .into_iter(),
+    );
+    assert_eq!(
+      names,
+      vec![
+        "Grid".to_string(),
+        "grid (2)".to_string(),
+        "Sheet 3".to_string(),
+        "Q1 Q2   plan".to_string(),
+        "a".repeat(31),
+        format!("{} (2)", "a".repeat(27)),
+      ]
+    );
+  }
+}
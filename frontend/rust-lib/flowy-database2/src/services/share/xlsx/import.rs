@@ -0,0 +1,188 @@
+use std::io::Cursor;
+
+use calamine::{open_workbook_from_rs, Data, DataType, Range, Reader, Xlsx};
+use chrono::{NaiveDateTime, NaiveTime};
+use collab_database::database::{gen_database_id, gen_field_id, gen_row_id};
+use collab_database::entity::CreateDatabaseParams;
+use collab_database::fields::Field;
+use collab_database::rows::{Cell, CreateRowParams};
+use flowy_error::{FlowyError, FlowyResult};
+
+use crate::entities::FieldType;
+use crate::services::cell::{insert_checkbox_cell, insert_date_cell, insert_text_cell};
+use crate::services::field::default_type_option_data_from_type;
+use crate::services::share::grid_database_params;
+
+/// Builds the params of a new grid database from a sheet of the xlsx workbook. The first sheet is
+/// imported if no sheet name is given.
+///
+/// The first row of the sheet holds the names of the fields. A column whose cells are all
+/// booleans, numbers or dates becomes a checkbox, number or date field, any other column becomes a
+/// text field. The first column is always the primary text field.
+pub fn database_from_xlsx(
+  view_id: &str,
+  data: Vec<u8>,
+  sheet_name: Option<&str>,
+) -> FlowyResult<CreateDatabaseParams> {
+  let range = read_sheet(data, sheet_name)?;
+  let mut rows = range.rows();
+  let headers = rows.next().unwrap_or_default();
+  let records = rows.collect::<Vec<_>>();
+
+  let fields = headers
+    .iter()
+    .enumerate()
+    .map(|(index, header)| {
+      let name = header.to_string().trim().to_string();
+      let name = if name.is_empty() {
+        format!("Column {}", index + 1)
+      } else {
+        name
+      };
+      let field_type = if index == 0 {
+        FieldType::RichText
+      } else {
+        column_field_type(records.iter().map(|record| record.get(index)))
+      };
+      Field::new(gen_field_id(), name, field_type.into(), index == 0)
+        .with_type_option_data(field_type, default_type_option_data_from_type(field_type))
+    })
+    .collect::<Vec<_>>();
+
+  let database_id = gen_database_id();
+  let rows = records
+    .into_iter()
+    .filter(|record| record.iter().any(|value| !value.is_empty()))
+    .map(|record| {
+      let mut params = CreateRowParams::new(gen_row_id(), database_id.clone());
+      for (field, value) in fields.iter().zip(record.iter()) {
+        if let Some(cell) = xlsx_cell(field, value) {
+          params.cells.insert(field.id.clone(), cell);
+        }
+      }
+      params
+    })
+    .collect::<Vec<_>>();
+
+  Ok(grid_database_params(view_id, database_id, fields, rows))
+}
+
+/// Returns the names of the sheets of the xlsx workbook.
+pub fn xlsx_sheet_names(data: Vec<u8>) -> FlowyResult<Vec<String>> {
+  Ok(open_xlsx(data)?.sheet_names())
+}
+
+fn open_xlsx(data: Vec<u8>) -> FlowyResult<Xlsx<Cursor<Vec<u8>>>> {
+  open_workbook_from_rs(Cursor::new(data))
+    .map_err(|err| FlowyError::invalid_data().with_context(format!("Invalid xlsx: {}", err)))
+}
+
+fn read_sheet(data: Vec<u8>, sheet_name: Option<&str>) -> FlowyResult<Range<Data>> {
+  let mut workbook = open_xlsx(data)?;
+  let sheet_name = match sheet_name {
+    Some(sheet_name) => sheet_name.to_string(),
+    None => workbook
+      .sheet_names()
+      .first()
+      .cloned()
+      .ok_or_else(|| FlowyError::invalid_data().with_context("The workbook has no sheets"))?,
+  };
+  workbook.worksheet_range(&sheet_name).map_err(|err| {
+    FlowyError::invalid_data().with_context(format!("Can't read sheet {}: {}", sheet_name, err))
+  })
+}
+
+/// Returns the field type that fits all the non-empty cells of the column.
+fn column_field_type<'a>(values: impl Iterator<Item = Option<&'a Data>>) -> FieldType {
+  let mut field_type = None;
+  for value in values.flatten() {
+    let value_type = match value {
+      Data::Empty => continue,
+      Data::Bool(_) => FieldType::Checkbox,
+      Data::Int(_) | Data::Float(_) => FieldType::Number,
+      Data::DateTime(_) | Data::DateTimeIso(_) if value.as_datetime().is_some() => {
+        FieldType::DateTime
+      },
+      _ => return FieldType::RichText,
+    };
+    match field_type {
+      None => field_type = Some(value_type),
+      Some(field_type) if field_type != value_type => return FieldType::RichText,
+      Some(_) => {},
+    }
+  }
+  field_type.unwrap_or(FieldType::RichText)
+}
+
+/// Returns None if the cell is empty or doesn't fit the type of the field.
+fn xlsx_cell(field: &Field, value: &Data) -> Option<Cell> {
+  match (FieldType::from(field.field_type), value) {
+    (_, Data::Empty) => None,
+    (FieldType::Checkbox, Data::Bool(is_checked)) => Some(insert_checkbox_cell(*is_checked, field)),
+    (FieldType::Number, Data::Int(_) | Data::Float(_)) => {
+      Some(insert_text_cell(value.to_string(), field))
+    },
+    (FieldType::DateTime, _) => value.as_datetime().map(|date_time| {
+      insert_date_cell(
+        date_time.and_utc().timestamp(),
+        None,
+        Some(has_time(&date_time)),
+        field,
+      )
+    }),
+    (_, value) => {
+      let text = match value.as_datetime() {
+        Some(date_time) if matches!(value, Data::DateTime(_) | Data::DateTimeIso(_)) => {
+          date_time.format("%Y-%m-%d").to_string()
+        },
+        _ => value.to_string(),
+      };
+      let text = text.trim();
+      if text.is_empty() {
+        None
+      } else {
+        Some(insert_text_cell(text.to_string(), field))
+      }
+    },
+  }
+}
+
+fn has_time(date_time: &NaiveDateTime) -> bool {
+  date_time.time() != NaiveTime::MIN
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn column(values: Vec<Data>) -> FieldType {
+    column_field_type(values.iter().map(Some))
+  }
+
+  #[test]
+  fn column_field_type_test() {
+    assert_eq!(
+      column(vec![Data::Bool(true), Data::Empty, Data::Bool(false)]),
+      FieldType::Checkbox
+    );
+    assert_eq!(
+      column(vec![Data::Int(1), Data::Float(2.5)]),
+      FieldType::Number
+    );
+    assert_eq!(
+      column(vec![Data::DateTimeIso("2024-03-01T10:30:00".to_string())]),
+      FieldType::DateTime
+    );
+    assert_eq!(
+      column(vec![Data::Int(1), Data::String("a".to_string())]),
+      FieldType::RichText
+    );
+    assert_eq!(column(vec![Data::Empty]), FieldType::RichText);
+  }
+
+  #[test]
+  fn invalid_xlsx_test() {
+    let view_id = collab_database::database::gen_database_view_id();
+    assert!(database_from_xlsx(&view_id, b"not a workbook".to_vec(), None).is_err());
+  }
+}
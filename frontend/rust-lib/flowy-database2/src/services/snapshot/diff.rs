@@ -0,0 +1,150 @@
+use std::collections::{HashMap, HashSet};
+
+use collab_database::database::DatabaseData;
+use collab_database::fields::Field;
+use collab_database::rows::{Row, RowId};
+
+use crate::services::cell::stringify_cell;
+
+/// The differences between two versions of a database, e.g. a backup and the current state.
+#[derive(Debug, Clone, Default)]
+pub struct DatabaseDiff {
+  pub fields_added: Vec<Field>,
+  pub fields_removed: Vec<Field>,
+  /// The fields whose name or type changed.
+  pub fields_updated: Vec<FieldDiff>,
+  pub rows_added: Vec<RowId>,
+  pub rows_removed: Vec<RowId>,
+  /// The rows that exist in both versions with at least one changed cell.
+  pub rows_changed: Vec<RowDiff>,
+}
+
+impl DatabaseDiff {
+  pub fn is_empty(&self) -> bool {
+    self.fields_added.is_empty()
+      && self.fields_removed.is_empty()
+      && self.fields_updated.is_empty()
+      && self.rows_added.is_empty()
+      && self.rows_removed.is_empty()
+      && self.rows_changed.is_empty()
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldDiff {
+  pub before: Field,
+  pub after: Field,
+}
+
+#[derive(Debug, Clone)]
+pub struct RowDiff {
+  pub row_id: RowId,
+  pub cells: Vec<CellDiff>,
+}
+
+/// The cell is compared by its display string, formatted with the field of its own version. An
+/// empty string means the cell is empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellDiff {
+  pub field_id: String,
+  pub before: String,
+  pub after: String,
+}
+
+/// Compares the `before` version of a database with the `after` version. The fields and rows are
+/// matched by id, so both versions must be of the same database.
+///
+/// The cells of the removed fields are not compared, they are covered by
+/// [DatabaseDiff::fields_removed].
+pub fn diff_database_data(before: &DatabaseData, after: &DatabaseData) -> DatabaseDiff {
+  let before_fields = fields_by_id(&before.fields);
+  let after_fields = fields_by_id(&after.fields);
+
+  let mut diff = DatabaseDiff::default();
+  for field in &after.fields {
+    match before_fields.get(field.id.as_str()) {
+      None => diff.fields_added.push(field.clone()),
+      Some(before_field) => {
+        if before_field.name != field.name || before_field.field_type != field.field_type {
+          diff.fields_updated.push(FieldDiff {
+            before: (*before_field).clone(),
+            after: field.clone(),
+          });
+        }
+      },
+    }
+  }
+  diff.fields_removed = before
+    .fields
+    .iter()
+    .filter(|field| !after_fields.contains_key(field.id.as_str()))
+    .cloned()
+    .collect();
+
+  let before_rows = before
+    .rows
+    .iter()
+    .map(|row| (row.id.clone(), row))
+    .collect::<HashMap<_, _>>();
+  let after_row_ids = after.rows.iter().map(|row| &row.id).collect::<HashSet<_>>();
+  for row in &after.rows {
+    match before_rows.get(&row.id) {
+      None => diff.rows_added.push(row.id.clone()),
+      Some(before_row) => {
+        let cells = diff_row_cells(before_row, &before_fields, row, &after.fields);
+        if !cells.is_empty() {
+          diff.rows_changed.push(RowDiff {
+            row_id: row.id.clone(),
+            cells,
+          });
+        }
+      },
+    }
+  }
+  diff.rows_removed = before
+    .rows
+    .iter()
+    .filter(|row| !after_row_ids.contains(&row.id))
+    .map(|row| row.id.clone())
+    .collect();
+  diff
+}
+
+fn fields_by_id(fields: &[Field]) -> HashMap<&str, &Field> {
+  fields
+    .iter()
+    .map(|field| (field.id.as_str(), field))
+    .collect()
+}
+
+fn diff_row_cells(
+  before_row: &Row,
+  before_fields: &HashMap<&str, &Field>,
+  after_row: &Row,
+  after_fields: &[Field],
+) -> Vec<CellDiff> {
+  after_fields
+    .iter()
+    .filter_map(|field| {
+      let before = before_fields
+        .get(field.id.as_str())
+        .and_then(|before_field| {
+          before_row
+            .cells
+            .get(&field.id)
+            .map(|cell| stringify_cell(cell, before_field))
+        })
+        .unwrap_or_default();
+      let after = after_row
+        .cells
+        .get(&field.id)
+        .map(|cell| stringify_cell(cell, field))
+        .unwrap_or_default();
+      (before != after).then(|| CellDiff {
+        field_id: field.id.clone(),
+        before,
+        after,
+      })
+    })
+    .collect()
+}
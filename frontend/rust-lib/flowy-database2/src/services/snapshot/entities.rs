@@ -1 +1,10 @@
+pub struct DatabaseSnapshotMeta {
+  pub snapshot_id: String,
+  pub object_id: String,
+  pub created_at: i64,
+}
 
+pub struct DatabaseSnapshotData {
+  pub object_id: String,
+  pub encoded_v1: Vec<u8>,
+}
@@ -16,13 +16,15 @@ use lib_infra::priority_task::{QualityOfService, Task, TaskContent, TaskDispatch
 
 use crate::entities::SortChangesetNotificationPB;
 use crate::entities::{FieldType, SortWithIndexPB};
-use crate::services::cell::CellCache;
+use crate::services::cell::{stringify_cell, CellCache};
 use crate::services::database_view::{DatabaseViewChanged, DatabaseViewChangedNotifier};
 use crate::services::field::{
-  default_order, TimestampCellData, TimestampCellDataWrapper, TypeOptionCellExt,
+  default_order, should_mask_field, TimestampCellData, TimestampCellDataWrapper, TypeOptionCellExt,
+  MASKED_CELL_VALUE,
 };
 use crate::services::sort::{
-  ReorderAllRowsResult, ReorderSingleRowResult, Sort, SortChangeset, SortCondition,
+  ReorderAllRowsResult, ReorderSingleRowResult, Sort, SortChangeset, SortCollation, SortCondition,
+  SortKeyExplanation,
 };
 
 #[async_trait]
@@ -146,7 +148,10 @@ impl SortController {
         self.sort_rows_and_notify(&mut rows).await;
       },
       SortEvent::RowDidChanged(row_id) => {
-        let old_row_index = self.row_index_cache.get(&row_id).cloned();
+        let old_row_index = self.row_index_cache.get(&row_id).cloned().or_else(|| {
+          // The row entered the view, so it was inserted at its position among the unsorted rows
+          rows.iter().position(|row| row.id == row_id)
+        });
         self.sort_rows(&mut rows).await;
         let new_row_index = self.row_index_cache.get(&row_id).cloned();
         match (old_row_index, new_row_index) {
@@ -166,6 +171,8 @@ impl SortController {
                 notification,
               ));
           },
+          // The row left the view, which is already notified by the filter
+          (Some(_), None) => {},
           _ => tracing::trace!("The row index cache is outdated"),
         }
       },
@@ -206,14 +213,57 @@ impl SortController {
 
   pub async fn sort_rows(&mut self, rows: &mut Vec<Arc<Row>>) {
     let fields = self.delegate.get_fields(&self.view_id, None).await;
-    for sort in self.sorts.iter().rev() {
-      rows.par_sort_by(|left, right| cmp_row(left, right, sort, &fields, &self.cell_cache));
-    }
+    sort_rows_by(rows, &self.sorts, &fields, &self.cell_cache);
+    // Only the rows of the view are cached, so the rows that left the view are removed.
+    self.row_index_cache.clear();
     rows.iter().enumerate().for_each(|(index, row)| {
       self.row_index_cache.insert(row.id.clone(), index);
     });
   }
 
+  /// Returns the effective sort keys of the row. The first key has the highest priority. The
+  /// content of the cells of sensitive fields is masked.
+  pub async fn explain_row(&self, row: &Row) -> Vec<SortKeyExplanation> {
+    let fields = self.delegate.get_fields(&self.view_id, None).await;
+    self
+      .sorts
+      .iter()
+      .map(|sort| {
+        let cell_content = fields
+          .iter()
+          .find(|field| field.id == sort.field_id)
+          .and_then(|field| {
+            if should_mask_field(field, false) {
+              return Some(MASKED_CELL_VALUE.to_string());
+            }
+            let field_type = FieldType::from(field.field_type);
+            let cell = match field_type {
+              FieldType::LastEditedTime | FieldType::CreatedTime => {
+                let timestamp = if field_type.is_created_time() {
+                  row.created_at
+                } else {
+                  row.modified_at
+                };
+                Cell::from(TimestampCellDataWrapper::from((
+                  field_type,
+                  TimestampCellData::new(timestamp),
+                )))
+              },
+              _ => row.cells.get(&sort.field_id)?.clone(),
+            };
+            Some(stringify_cell(&cell, field))
+          })
+          .unwrap_or_default();
+        SortKeyExplanation {
+          sort_id: sort.id.clone(),
+          field_id: sort.field_id.clone(),
+          condition: sort.condition,
+          cell_content,
+        }
+      })
+      .collect()
+  }
+
   pub async fn delete_all_sorts(&mut self) {
     self.sorts.clear();
     self
@@ -286,6 +336,18 @@ impl SortController {
   }
 }
 
+/// Sorts the rows by the sorts, the first sort has the highest priority.
+pub(crate) fn sort_rows_by(
+  rows: &mut [Arc<Row>],
+  sorts: &[Arc<Sort>],
+  fields: &[Field],
+  cell_data_cache: &CellCache,
+) {
+  for sort in sorts.iter().rev() {
+    rows.par_sort_by(|left, right| cmp_row(left, right, sort, fields, cell_data_cache));
+  }
+}
+
 fn cmp_row(
   left: &Row,
   right: &Row,
@@ -326,6 +388,7 @@ fn cmp_row(
         field_rev,
         cell_data_cache,
         sort.condition,
+        sort.collation,
       )
     },
   }
@@ -337,12 +400,15 @@ fn cmp_cell(
   field: &Field,
   cell_data_cache: &CellCache,
   sort_condition: SortCondition,
+  collation: SortCollation,
 ) -> Ordering {
   match TypeOptionCellExt::new(field, Some(cell_data_cache.clone()))
     .get_type_option_cell_data_handler()
   {
     None => default_order(),
-    Some(handler) => handler.handle_cell_compare(left_cell, right_cell, field, sort_condition),
+    Some(handler) => {
+      handler.handle_cell_compare(left_cell, right_cell, field, sort_condition, collation)
+    },
   }
 }
 
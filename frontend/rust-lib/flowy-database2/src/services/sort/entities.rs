@@ -11,11 +11,13 @@ pub struct Sort {
   pub id: String,
   pub field_id: String,
   pub condition: SortCondition,
+  pub collation: SortCollation,
 }
 
 const SORT_ID: &str = "id";
 const FIELD_ID: &str = "field_id";
 const SORT_CONDITION: &str = "condition";
+const SORT_COLLATION: &str = "collation";
 
 impl TryFrom<SortMap> for Sort {
   type Error = anyhow::Error;
@@ -30,10 +32,15 @@ impl TryFrom<SortMap> for Sort {
           .get_as::<i64>(SORT_CONDITION)
           .map(SortCondition::from)
           .unwrap_or_default();
+        let collation = value
+          .get_as::<i64>(SORT_COLLATION)
+          .map(SortCollation::from)
+          .unwrap_or_default();
         Ok(Self {
           id,
           field_id,
           condition,
+          collation,
         })
       },
       _ => {
@@ -49,6 +56,7 @@ impl From<Sort> for SortMap {
       (SORT_ID.into(), data.id.into()),
       (FIELD_ID.into(), data.field_id.into()),
       (SORT_CONDITION.into(), Any::BigInt(data.condition.value())),
+      (SORT_COLLATION.into(), Any::BigInt(data.collation.value())),
     ])
   }
 }
@@ -87,6 +95,97 @@ impl From<i64> for SortCondition {
   }
 }
 
+/// How the text of two cells is compared. Only applies to the text based fields.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SortCollation {
+  /// Compares the text by its characters, so "B" < "a" and "item10" < "item2".
+  #[default]
+  Binary = 0,
+  /// Compares the text ignoring the case of the letters.
+  CaseInsensitive = 1,
+  /// Compares the numbers in the text by their value and the rest ignoring the case, so
+  /// "item2" < "item10".
+  Natural = 2,
+}
+
+impl SortCollation {
+  pub fn value(&self) -> i64 {
+    *self as i64
+  }
+
+  /// Compares two strings according to the collation, in ascending order.
+  pub fn cmp_str(&self, left: &str, right: &str) -> Ordering {
+    match self {
+      SortCollation::Binary => left.cmp(right),
+      SortCollation::CaseInsensitive => cmp_ignore_case(left, right),
+      SortCollation::Natural => cmp_natural(left, right),
+    }
+  }
+}
+
+impl From<i64> for SortCollation {
+  fn from(value: i64) -> Self {
+    match value {
+      1 => SortCollation::CaseInsensitive,
+      2 => SortCollation::Natural,
+      _ => SortCollation::Binary,
+    }
+  }
+}
+
+fn cmp_ignore_case(left: &str, right: &str) -> Ordering {
+  left
+    .chars()
+    .flat_map(char::to_lowercase)
+    .cmp(right.chars().flat_map(char::to_lowercase))
+}
+
+/// Splits the text into runs of digits and runs of other characters, then compares the runs
+/// pairwise. Runs of digits are compared by their numeric value, without overflowing for long
+/// numbers, and the other runs are compared ignoring the case.
+fn cmp_natural(left: &str, right: &str) -> Ordering {
+  let mut left_chars = left.chars().peekable();
+  let mut right_chars = right.chars().peekable();
+  loop {
+    match (left_chars.peek().copied(), right_chars.peek().copied()) {
+      (None, None) => return Ordering::Equal,
+      (None, Some(_)) => return Ordering::Less,
+      (Some(_), None) => return Ordering::Greater,
+      (Some(l), Some(r)) if l.is_ascii_digit() && r.is_ascii_digit() => {
+        let left_digits = take_digits(&mut left_chars);
+        let right_digits = take_digits(&mut right_chars);
+        let left_number = left_digits.trim_start_matches('0');
+        let right_number = right_digits.trim_start_matches('0');
+        let order = left_number
+          .len()
+          .cmp(&right_number.len())
+          .then_with(|| left_number.cmp(right_number))
+          .then_with(|| left_digits.len().cmp(&right_digits.len()));
+        if order != Ordering::Equal {
+          return order;
+        }
+      },
+      (Some(l), Some(r)) => {
+        let order = l.to_lowercase().cmp(r.to_lowercase());
+        if order != Ordering::Equal {
+          return order;
+        }
+        left_chars.next();
+        right_chars.next();
+      },
+    }
+  }
+}
+
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+  let mut digits = String::new();
+  while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+    digits.push(c);
+  }
+  digits
+}
+
 #[derive(Clone)]
 pub struct ReorderAllRowsResult {
   pub view_id: String,
@@ -110,6 +209,15 @@ pub struct ReorderSingleRowResult {
   pub new_index: usize,
 }
 
+/// The key that a sort uses to order a row, in the order the sorts are applied.
+#[derive(Clone, Debug)]
+pub struct SortKeyExplanation {
+  pub sort_id: String,
+  pub field_id: String,
+  pub condition: SortCondition,
+  pub cell_content: String,
+}
+
 #[derive(Debug, Default)]
 pub struct SortChangeset {
   pub(crate) insert_sort: Option<Sort>,
@@ -147,3 +255,37 @@ impl SortChangeset {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::cmp::Ordering;
+
+  use crate::services::sort::SortCollation;
+
+  #[test]
+  fn sort_collation_natural_test() {
+    let collation = SortCollation::Natural;
+    assert_eq!(collation.cmp_str("item2", "item10"), Ordering::Less);
+    assert_eq!(collation.cmp_str("Item2", "item10"), Ordering::Less);
+    assert_eq!(collation.cmp_str("item10", "item10a"), Ordering::Less);
+    assert_eq!(collation.cmp_str("a99", "b1"), Ordering::Less);
+    assert_eq!(collation.cmp_str("item02", "item2"), Ordering::Greater);
+    assert_eq!(
+      collation.cmp_str("v123456789012345678901234567890", "v99"),
+      Ordering::Greater
+    );
+    assert_eq!(collation.cmp_str("ITEM", "item"), Ordering::Equal);
+  }
+
+  #[test]
+  fn sort_collation_case_insensitive_test() {
+    let collation = SortCollation::CaseInsensitive;
+    assert_eq!(collation.cmp_str("apple", "Banana"), Ordering::Less);
+    assert_eq!(collation.cmp_str("APPLE", "apple"), Ordering::Equal);
+    assert_eq!(collation.cmp_str("item2", "item10"), Ordering::Greater);
+    assert_eq!(
+      SortCollation::Binary.cmp_str("apple", "Banana"),
+      Ordering::Greater
+    );
+  }
+}
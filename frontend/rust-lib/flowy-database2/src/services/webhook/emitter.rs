@@ -0,0 +1,394 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use flowy_error::{FlowyError, FlowyResult};
+use lib_dispatch::prelude::af_spawn;
+use lib_infra::util::timestamp;
+use nanoid::nanoid;
+use tokio::sync::mpsc;
+use tracing::{trace, warn};
+
+use crate::services::webhook::{
+  Webhook, WebhookDelivery, WebhookDeliveryStatus, WebhookPayload, WebhookRetryConfig,
+};
+
+/// The maximum number of deliveries kept in the log. The oldest ones are dropped first.
+const MAX_DELIVERY_LOG_LEN: usize = 500;
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+/// The maximum number of payloads waiting to be delivered to a webhook. The payloads emitted
+/// while the queue is full are dropped and their deliveries fail.
+pub const MAX_QUEUED_DELIVERIES: usize = 100;
+
+/// Posts the payloads of the webhooks. The embedder can replace the default HTTP client with
+/// [crate::DatabaseManager::set_webhook_client], e.g. to sign the payloads.
+#[async_trait]
+pub trait WebhookClient: Send + Sync + 'static {
+  /// Posts the JSON body to the url and returns the HTTP status code of the response.
+  async fn post_json(&self, url: &str, body: String) -> Result<u16, String>;
+}
+
+pub struct HttpWebhookClient {
+  client: reqwest::Client,
+}
+
+impl Default for HttpWebhookClient {
+  fn default() -> Self {
+    let client = reqwest::Client::builder()
+      .timeout(WEBHOOK_TIMEOUT)
+      .build()
+      .unwrap_or_default();
+    Self { client }
+  }
+}
+
+#[async_trait]
+impl WebhookClient for HttpWebhookClient {
+  async fn post_json(&self, url: &str, body: String) -> Result<u16, String> {
+    let response = self
+      .client
+      .post(url)
+      .header(reqwest::header::CONTENT_TYPE, "application/json")
+      .body(body)
+      .send()
+      .await
+      .map_err(|err| err.to_string())?;
+    Ok(response.status().as_u16())
+  }
+}
+
+/// Saves the webhooks registered on this device, see [crate::DatabaseManager::set_webhook_store].
+/// The webhooks are never synced, every device delivers the changes it makes to its own
+/// webhooks.
+pub trait WebhookStore: Send + Sync + 'static {
+  fn get_webhooks(&self, database_id: &str) -> Vec<Webhook>;
+  fn save_webhooks(&self, database_id: &str, webhooks: &[Webhook]) -> FlowyResult<()>;
+}
+
+#[derive(Default)]
+struct WebhookState {
+  webhooks: Vec<Webhook>,
+  /// The databases whose webhooks were read from the [WebhookStore].
+  loaded_databases: HashSet<String>,
+  /// The queue of each webhook that received a payload, by webhook id.
+  queues: HashMap<String, mpsc::Sender<WebhookPayload>>,
+  deliveries: VecDeque<WebhookDelivery>,
+  retry_config: WebhookRetryConfig,
+}
+
+/// The webhooks shared by the manager and every database editor it opens. The webhooks of a
+/// database are read from the [WebhookStore] the first time they're needed and saved whenever
+/// one is added or removed. Without a store they only last while the app is running.
+///
+/// Each webhook has a queue of at most [MAX_QUEUED_DELIVERIES] payloads that are delivered one
+/// by one in the background, so the payloads of a webhook arrive in the order of the changes. A
+/// payload is retried with a backoff if the request fails or the receiver responds with a
+/// server error, see [WebhookRetryConfig].
+#[derive(Clone)]
+pub struct WebhookEmitter {
+  state: Arc<RwLock<WebhookState>>,
+  client: Arc<RwLock<Arc<dyn WebhookClient>>>,
+  store: Arc<RwLock<Option<Arc<dyn WebhookStore>>>>,
+}
+
+impl Default for WebhookEmitter {
+  fn default() -> Self {
+    Self {
+      state: Default::default(),
+      client: Arc::new(RwLock::new(Arc::new(HttpWebhookClient::default()))),
+      store: Default::default(),
+    }
+  }
+}
+
+impl WebhookEmitter {
+  /// Registers the url as a webhook of the database. Only http and https urls are accepted.
+  pub fn add_webhook(&self, database_id: &str, url: &str) -> FlowyResult<Webhook> {
+    let is_http = url::Url::parse(url)
+      .map(|url| matches!(url.scheme(), "http" | "https"))
+      .unwrap_or(false);
+    if !is_http {
+      return Err(FlowyError::invalid_data().with_context(format!("Invalid webhook url: {}", url)));
+    }
+
+    let webhook = Webhook {
+      id: nanoid!(10),
+      database_id: database_id.to_string(),
+      url: url.to_string(),
+    };
+    let mut webhooks = self.webhooks(database_id);
+    webhooks.push(webhook.clone());
+    self.save_webhooks(database_id, &webhooks)?;
+    if let Ok(mut state) = self.state.write() {
+      state.webhooks.push(webhook.clone());
+    }
+    Ok(webhook)
+  }
+
+  /// Returns false if the database has no webhook with the id.
+  pub fn remove_webhook(&self, database_id: &str, webhook_id: &str) -> FlowyResult<bool> {
+    let mut webhooks = self.webhooks(database_id);
+    let len = webhooks.len();
+    webhooks.retain(|webhook| webhook.id != webhook_id);
+    if webhooks.len() == len {
+      return Ok(false);
+    }
+
+    self.save_webhooks(database_id, &webhooks)?;
+    if let Ok(mut state) = self.state.write() {
+      state.webhooks.retain(|webhook| webhook.id != webhook_id);
+      // The worker of the webhook stops once the payloads in its queue are delivered
+      state.queues.remove(webhook_id);
+    }
+    Ok(true)
+  }
+
+  pub fn webhooks(&self, database_id: &str) -> Vec<Webhook> {
+    self.load_webhooks(database_id);
+    self
+      .state
+      .read()
+      .map(|state| {
+        state
+          .webhooks
+          .iter()
+          .filter(|webhook| webhook.database_id == database_id)
+          .cloned()
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  pub fn has_webhooks(&self, database_id: &str) -> bool {
+    self.load_webhooks(database_id);
+    self
+      .state
+      .read()
+      .map(|state| {
+        state
+          .webhooks
+          .iter()
+          .any(|webhook| webhook.database_id == database_id)
+      })
+      .unwrap_or(false)
+  }
+
+  /// Returns the deliveries to the webhooks of the database, oldest first.
+  pub fn deliveries(&self, database_id: &str) -> Vec<WebhookDelivery> {
+    self
+      .state
+      .read()
+      .map(|state| {
+        state
+          .deliveries
+          .iter()
+          .filter(|delivery| delivery.database_id == database_id)
+          .cloned()
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  pub fn set_client(&self, client: Arc<dyn WebhookClient>) {
+    if let Ok(mut current) = self.client.write() {
+      *current = client;
+    }
+  }
+
+  /// Sets the store of the webhooks. It must be set before the webhooks of any database are
+  /// read, the webhooks that were read already are not read again.
+  pub fn set_store(&self, store: Arc<dyn WebhookStore>) {
+    if let Ok(mut current) = self.store.write() {
+      *current = Some(store);
+    }
+  }
+
+  pub fn set_retry_config(&self, retry_config: WebhookRetryConfig) {
+    if let Ok(mut state) = self.state.write() {
+      state.retry_config = retry_config;
+    }
+  }
+
+  /// Queues the payload to each webhook of its database. The payload is dropped for the webhooks
+  /// whose queue is full.
+  pub fn emit(&self, payload: WebhookPayload) {
+    for webhook in self.webhooks(&payload.database_id) {
+      let mut payload = payload.clone();
+      payload.delivery_id = nanoid!(16);
+      let now = timestamp();
+      if let Ok(mut state) = self.state.write() {
+        if state.deliveries.len() >= MAX_DELIVERY_LOG_LEN {
+          state.deliveries.pop_front();
+        }
+        state.deliveries.push_back(WebhookDelivery {
+          id: payload.delivery_id.clone(),
+          webhook_id: webhook.id.clone(),
+          database_id: webhook.database_id.clone(),
+          event: payload.event,
+          status: WebhookDeliveryStatus::Pending,
+          attempts: 0,
+          status_code: None,
+          error: None,
+          created_at: now,
+          updated_at: now,
+        });
+      }
+
+      let Some(queue) = self.queue(&webhook) else {
+        continue;
+      };
+      if let Err(err) = queue.try_send(payload) {
+        let payload = match err {
+          mpsc::error::TrySendError::Full(payload) | mpsc::error::TrySendError::Closed(payload) => {
+            payload
+          },
+        };
+        warn!(
+          "[Webhook]: the queue of {} is full, drop {}",
+          webhook.url, payload.delivery_id
+        );
+        self.update_delivery(&payload.delivery_id, |delivery| {
+          delivery.status = WebhookDeliveryStatus::Failed;
+          delivery.error = Some("The queue of the webhook is full".to_string());
+        });
+      }
+    }
+  }
+
+  /// Returns the queue of the webhook. The first time, it starts the worker that delivers the
+  /// payloads of the queue one after another.
+  fn queue(&self, webhook: &Webhook) -> Option<mpsc::Sender<WebhookPayload>> {
+    let mut state = self.state.write().ok()?;
+    if let Some(tx) = state.queues.get(&webhook.id) {
+      return Some(tx.clone());
+    }
+    let (tx, mut rx) = mpsc::channel(MAX_QUEUED_DELIVERIES);
+    state.queues.insert(webhook.id.clone(), tx.clone());
+    drop(state);
+
+    let emitter = self.clone();
+    let webhook = webhook.clone();
+    af_spawn(async move {
+      while let Some(payload) = rx.recv().await {
+        emitter.deliver(&webhook, payload).await;
+      }
+      trace!("[Webhook]: stop delivering to {}", webhook.url);
+    });
+    Some(tx)
+  }
+
+  /// Reads the webhooks of the database from the store, once.
+  fn load_webhooks(&self, database_id: &str) {
+    let is_loaded = self
+      .state
+      .read()
+      .map(|state| state.loaded_databases.contains(database_id))
+      .unwrap_or(true);
+    if is_loaded {
+      return;
+    }
+    let store = match self.store.read() {
+      Ok(store) => store.clone(),
+      Err(_) => return,
+    };
+    if let Ok(mut state) = self.state.write() {
+      if state.loaded_databases.insert(database_id.to_string()) {
+        if let Some(store) = store {
+          let webhooks = store.get_webhooks(database_id);
+          state.webhooks.extend(webhooks);
+        }
+      }
+    }
+  }
+
+  fn save_webhooks(&self, database_id: &str, webhooks: &[Webhook]) -> FlowyResult<()> {
+    let store = match self.store.read() {
+      Ok(store) => store.clone(),
+      Err(_) => None,
+    };
+    match store {
+      None => Ok(()),
+      Some(store) => store.save_webhooks(database_id, webhooks),
+    }
+  }
+
+  async fn deliver(&self, webhook: &Webhook, payload: WebhookPayload) {
+    let body = match serde_json::to_string(&payload) {
+      Ok(body) => body,
+      Err(err) => {
+        self.update_delivery(&payload.delivery_id, |delivery| {
+          delivery.status = WebhookDeliveryStatus::Failed;
+          delivery.error = Some(err.to_string());
+        });
+        return;
+      },
+    };
+    let retry_config = match self.state.read() {
+      Ok(state) => state.retry_config.clone(),
+      Err(_) => WebhookRetryConfig::default(),
+    };
+    let client = match self.client.read() {
+      Ok(client) => client.clone(),
+      Err(_) => return,
+    };
+
+    let max_attempts = retry_config.max_attempts.max(1);
+    for attempt in 1..=max_attempts {
+      let result = client.post_json(&webhook.url, body.clone()).await;
+      let (is_delivered, is_retryable) = match &result {
+        Ok(status_code) => (
+          (200..300).contains(status_code),
+          *status_code == 408 || *status_code == 429 || *status_code >= 500,
+        ),
+        Err(_) => (false, true),
+      };
+      let is_done = is_delivered || !is_retryable || attempt == max_attempts;
+      self.update_delivery(&payload.delivery_id, |delivery| {
+        delivery.attempts = attempt;
+        delivery.status_code = result.as_ref().ok().copied();
+        delivery.error = result.as_ref().err().cloned();
+        if is_done {
+          delivery.status = if is_delivered {
+            WebhookDeliveryStatus::Delivered
+          } else {
+            WebhookDeliveryStatus::Failed
+          };
+        }
+      });
+
+      if is_delivered {
+        trace!(
+          "[Webhook]: delivered {} to {}",
+          payload.delivery_id,
+          webhook.url
+        );
+        return;
+      }
+      if is_done {
+        warn!(
+          "[Webhook]: failed to deliver {} to {} after {} attempts: {:?}",
+          payload.delivery_id, webhook.url, attempt, result
+        );
+        return;
+      }
+      tokio::time::sleep(retry_config.backoff(attempt)).await;
+    }
+  }
+
+  fn update_delivery<F>(&self, delivery_id: &str, f: F)
+  where
+    F: FnOnce(&mut WebhookDelivery),
+  {
+    if let Ok(mut state) = self.state.write() {
+      if let Some(delivery) = state
+        .deliveries
+        .iter_mut()
+        .find(|delivery| delivery.id == delivery_id)
+      {
+        f(delivery);
+        delivery.updated_at = timestamp();
+      }
+    }
+  }
+}
@@ -0,0 +1,149 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use collab_database::fields::Field;
+use collab_database::rows::Row;
+use serde::{Deserialize, Serialize};
+
+use crate::services::cell::stringify_cell;
+use crate::services::field::{should_mask_field, MASKED_CELL_VALUE};
+use crate::services::share::csv::row_cell;
+
+/// A URL that receives the changes of a database as JSON payloads.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Webhook {
+  pub id: String,
+  pub database_id: String,
+  pub url: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventType {
+  RowCreated,
+  RowUpdated,
+  RowDeleted,
+  FieldCreated,
+  FieldUpdated,
+  FieldDeleted,
+}
+
+/// The JSON body posted to the webhooks of a database.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookPayload {
+  /// The id of the delivery, the same for each attempt to deliver the payload, so the receiver
+  /// can ignore the payloads it received already.
+  pub delivery_id: String,
+  pub event: WebhookEventType,
+  pub database_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub row_id: Option<String>,
+  /// The cells of the created or updated row by field name, formatted the way their field
+  /// displays them. The values of the sensitive fields are masked.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cells: Option<BTreeMap<String, String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub field_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub field_name: Option<String>,
+  pub timestamp: i64,
+}
+
+impl WebhookPayload {
+  pub fn new(event: WebhookEventType, database_id: &str, timestamp: i64) -> Self {
+    Self {
+      delivery_id: String::new(),
+      event,
+      database_id: database_id.to_string(),
+      row_id: None,
+      cells: None,
+      field_id: None,
+      field_name: None,
+      timestamp,
+    }
+  }
+
+  pub fn with_row(mut self, row: &Row, fields: &[Field]) -> Self {
+    let cells = fields
+      .iter()
+      .filter_map(|field| {
+        let value = if should_mask_field(field, false) {
+          MASKED_CELL_VALUE.to_string()
+        } else {
+          stringify_cell(&row_cell(row, field)?, field)
+        };
+        Some((field.name.clone(), value))
+      })
+      .collect();
+    self.row_id = Some(row.id.to_string());
+    self.cells = Some(cells);
+    self
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookDeliveryStatus {
+  Pending,
+  Delivered,
+  Failed,
+}
+
+/// An entry of the delivery log of the webhooks.
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+  pub id: String,
+  pub webhook_id: String,
+  pub database_id: String,
+  pub event: WebhookEventType,
+  pub status: WebhookDeliveryStatus,
+  pub attempts: u32,
+  /// The HTTP status code of the last response, None if no response was received.
+  pub status_code: Option<u16>,
+  pub error: Option<String>,
+  pub created_at: i64,
+  pub updated_at: i64,
+}
+
+/// How many times a delivery is attempted and how long to wait between the attempts. The wait
+/// doubles after each failed attempt, up to `max_backoff`.
+#[derive(Debug, Clone)]
+pub struct WebhookRetryConfig {
+  pub max_attempts: u32,
+  pub initial_backoff: Duration,
+  pub max_backoff: Duration,
+}
+
+impl WebhookRetryConfig {
+  /// Returns how long to wait after the given failed attempt, counted from 1.
+  pub fn backoff(&self, attempt: u32) -> Duration {
+    let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+    self
+      .initial_backoff
+      .saturating_mul(factor)
+      .min(self.max_backoff)
+  }
+}
+
+impl Default for WebhookRetryConfig {
+  fn default() -> Self {
+    Self {
+      max_attempts: 5,
+      initial_backoff: Duration::from_secs(1),
+      max_backoff: Duration::from_secs(60),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn backoff_test() {
+    let config = WebhookRetryConfig::default();
+    assert_eq!(config.backoff(1), Duration::from_secs(1));
+    assert_eq!(config.backoff(3), Duration::from_secs(4));
+    assert_eq!(config.backoff(10), Duration::from_secs(60));
+    assert_eq!(config.backoff(100), Duration::from_secs(60));
+  }
+}
@@ -0,0 +1,5 @@
+mod emitter;
+mod entities;
+
+pub use emitter::*;
+pub use entities::*;
@@ -56,6 +56,10 @@ where
   pub fn is_empty(&self) -> bool {
     self.0.is_empty()
   }
+
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
 }
 
 fn downcast_owned<T: 'static + Send + Sync>(type_value: TypeValue) -> Option<T> {
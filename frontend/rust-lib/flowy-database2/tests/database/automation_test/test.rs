@@ -0,0 +1,360 @@
+use std::time::Duration;
+
+use collab_database::rows::RowId;
+use event_integration_test::folder_event::ViewTest;
+use flowy_config::feature_flag::FeatureFlag;
+use flowy_database2::entities::{
+  AutomationActionPB, AutomationActionTypePB, AutomationCellValuePB, AutomationRulePB,
+  AutomationTriggerTypePB, CheckboxFilterConditionPB, CheckboxFilterPB, CreateRowPayloadPB,
+  FieldType, FilterDataPB, UpdateAutomationRulesPayloadPB,
+};
+use flowy_database2::services::cell::{
+  insert_checkbox_cell, insert_date_cell, insert_text_cell, stringify_cell,
+};
+use lib_infra::util::timestamp;
+
+use crate::database::database_editor::DatabaseEditorTest;
+use crate::database::mock_data::make_test_grid;
+
+/// Returns a grid with the automations enabled, they are disabled by their feature flag by
+/// default.
+async fn new_automation_grid() -> DatabaseEditorTest {
+  let test = DatabaseEditorTest::new_grid().await;
+  test
+    .sdk
+    .feature_flags
+    .set_local_override(FeatureFlag::Automations, Some(true))
+    .unwrap();
+  test
+}
+
+fn set_cell_action(field_id: &str, value: &str) -> AutomationActionPB {
+  AutomationActionPB {
+    action_type: AutomationActionTypePB::SetCell,
+    field_id: field_id.to_string(),
+    value: value.to_string(),
+    ..Default::default()
+  }
+}
+
+async fn update_rules(test: &DatabaseEditorTest, rules: Vec<AutomationRulePB>) {
+  test
+    .editor
+    .update_automation_rules(UpdateAutomationRulesPayloadPB {
+      view_id: test.view_id.clone(),
+      rules,
+    })
+    .await
+    .unwrap();
+}
+
+async fn text_of(test: &DatabaseEditorTest, field_type: FieldType, row_id: &RowId) -> String {
+  let field = test.get_first_field(field_type).await;
+  test
+    .editor
+    .get_cell(&field.id, row_id)
+    .await
+    .map(|cell| stringify_cell(&cell, &field))
+    .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn cell_matches_rule_test() {
+  let test = new_automation_grid().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  let checkbox_field = test.get_first_field(FieldType::Checkbox).await;
+  let other_grid =
+    ViewTest::new_grid_view(&test.sdk, make_test_grid().to_json_bytes().unwrap()).await;
+  let other_view_id = other_grid.child_view.id.clone();
+  let other_editor = test
+    .sdk
+    .database_manager
+    .get_database_editor_with_view_id(&other_view_id)
+    .await
+    .unwrap();
+  let num_of_other_rows = other_editor
+    .get_all_rows(&other_view_id)
+    .await
+    .unwrap()
+    .len();
+
+  update_rules(
+    &test,
+    vec![AutomationRulePB {
+      name: "Archive urgent rows".to_string(),
+      is_enabled: true,
+      trigger_type: AutomationTriggerTypePB::CellMatches,
+      condition: FilterDataPB {
+        field_id: checkbox_field.id.clone(),
+        field_type: FieldType::Checkbox,
+        data: CheckboxFilterPB {
+          condition: CheckboxFilterConditionPB::IsChecked,
+        }
+        .try_into()
+        .unwrap(),
+      },
+      actions: vec![
+        set_cell_action(&text_field.id, "{{Name}} (urgent)"),
+        AutomationActionPB {
+          action_type: AutomationActionTypePB::CreateRow,
+          view_id: other_view_id.clone(),
+          cells: vec![AutomationCellValuePB {
+            field_name: "Name".to_string(),
+            value: "Follow up on {{Name}}".to_string(),
+          }],
+          ..Default::default()
+        },
+      ],
+      ..Default::default()
+    }],
+  )
+  .await;
+  let rules = test.editor.get_automation_rules().await.items;
+  assert_eq!(rules.len(), 1);
+  assert!(!rules[0].id.is_empty());
+  assert_eq!(rules[0].actions.len(), 2);
+
+  let row_id = test.get_rows().await[0].id.clone();
+  test
+    .editor
+    .update_cell(
+      &test.view_id,
+      &row_id,
+      &text_field.id,
+      insert_text_cell("Launch".to_string(), &text_field),
+    )
+    .await
+    .unwrap();
+  for is_checked in [false, true] {
+    test
+      .editor
+      .update_cell(
+        &test.view_id,
+        &row_id,
+        &checkbox_field.id,
+        insert_checkbox_cell(is_checked, &checkbox_field),
+      )
+      .await
+      .unwrap();
+  }
+  assert_eq!(
+    text_of(&test, FieldType::RichText, &row_id).await,
+    "Launch (urgent)"
+  );
+
+  // The rule only fires when the row starts matching the condition
+  test
+    .editor
+    .update_cell(
+      &test.view_id,
+      &row_id,
+      &checkbox_field.id,
+      insert_checkbox_cell(true, &checkbox_field),
+    )
+    .await
+    .unwrap();
+  assert_eq!(
+    text_of(&test, FieldType::RichText, &row_id).await,
+    "Launch (urgent)"
+  );
+
+  // The row is created in the other grid by the manager
+  let mut rows = vec![];
+  for _ in 0..50 {
+    rows = other_editor.get_all_rows(&other_view_id).await.unwrap();
+    if rows.len() > num_of_other_rows {
+      break;
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+  }
+  assert_eq!(rows.len(), num_of_other_rows + 1);
+  let other_text_field = other_editor
+    .get_fields(&other_view_id, None)
+    .await
+    .into_iter()
+    .find(|field| field.name == "Name")
+    .unwrap();
+  assert!(rows.iter().any(|row| {
+    row
+      .cells
+      .get(&other_text_field.id)
+      .map(|cell| stringify_cell(cell, &other_text_field))
+      .as_deref()
+      == Some("Follow up on Launch")
+  }));
+}
+
+#[tokio::test]
+async fn row_created_rule_test() {
+  let test = new_automation_grid().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  update_rules(
+    &test,
+    vec![
+      AutomationRulePB {
+        is_enabled: true,
+        trigger_type: AutomationTriggerTypePB::RowCreated,
+        actions: vec![set_cell_action(&text_field.id, "New task")],
+        ..Default::default()
+      },
+      AutomationRulePB {
+        is_enabled: false,
+        trigger_type: AutomationTriggerTypePB::RowCreated,
+        actions: vec![set_cell_action(&text_field.id, "Disabled")],
+        ..Default::default()
+      },
+    ],
+  )
+  .await;
+
+  let row_detail = test
+    .editor
+    .create_row(CreateRowPayloadPB {
+      view_id: test.view_id.clone(),
+      ..Default::default()
+    })
+    .await
+    .unwrap()
+    .unwrap();
+  assert_eq!(
+    text_of(&test, FieldType::RichText, &row_detail.row.id).await,
+    "New task"
+  );
+
+  // The rules must refer to existing fields
+  let result = test
+    .editor
+    .update_automation_rules(UpdateAutomationRulesPayloadPB {
+      view_id: test.view_id.clone(),
+      rules: vec![AutomationRulePB {
+        is_enabled: true,
+        trigger_type: AutomationTriggerTypePB::RowCreated,
+        actions: vec![set_cell_action("missing", "New task")],
+        ..Default::default()
+      }],
+    })
+    .await;
+  assert!(result.is_err());
+  assert_eq!(test.editor.get_automation_rules().await.items.len(), 2);
+}
+
+#[tokio::test]
+async fn date_reached_rule_test() {
+  let test = new_automation_grid().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  let date_field = test.get_first_field(FieldType::DateTime).await;
+  update_rules(
+    &test,
+    vec![AutomationRulePB {
+      is_enabled: true,
+      trigger_type: AutomationTriggerTypePB::DateReached,
+      date_field_id: date_field.id.clone(),
+      actions: vec![set_cell_action(&text_field.id, "Due")],
+      ..Default::default()
+    }],
+  )
+  .await;
+
+  let now = timestamp();
+  let row_id = test.get_rows().await[0].id.clone();
+  test
+    .editor
+    .update_cell(
+      &test.view_id,
+      &row_id,
+      &date_field.id,
+      insert_date_cell(now + 100, None, Some(true), &date_field),
+    )
+    .await
+    .unwrap();
+
+  test.editor.run_date_automations(now + 50).await;
+  assert_ne!(text_of(&test, FieldType::RichText, &row_id).await, "Due");
+  test.editor.run_date_automations(now + 200).await;
+  assert_eq!(text_of(&test, FieldType::RichText, &row_id).await, "Due");
+
+  // A reached date doesn't fire the rule again
+  test
+    .editor
+    .update_cell(
+      &test.view_id,
+      &row_id,
+      &text_field.id,
+      insert_text_cell("Done".to_string(), &text_field),
+    )
+    .await
+    .unwrap();
+  test.editor.run_date_automations(now + 300).await;
+  assert_eq!(text_of(&test, FieldType::RichText, &row_id).await, "Done");
+
+  // The rules don't change a locked row
+  let locked_row_id = test.get_rows().await[1].id.clone();
+  test
+    .editor
+    .update_cell(
+      &test.view_id,
+      &locked_row_id,
+      &date_field.id,
+      insert_date_cell(now + 400, None, Some(true), &date_field),
+    )
+    .await
+    .unwrap();
+  let text = text_of(&test, FieldType::RichText, &locked_row_id).await;
+  test
+    .editor
+    .set_row_locked(&locked_row_id, true)
+    .await
+    .unwrap();
+  test.editor.run_date_automations(now + 500).await;
+  assert_eq!(
+    text_of(&test, FieldType::RichText, &locked_row_id).await,
+    text
+  );
+}
+
+#[tokio::test]
+async fn disabled_automations_test() {
+  let test = new_automation_grid().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  update_rules(
+    &test,
+    vec![AutomationRulePB {
+      is_enabled: true,
+      trigger_type: AutomationTriggerTypePB::RowCreated,
+      actions: vec![set_cell_action(&text_field.id, "New task")],
+      ..Default::default()
+    }],
+  )
+  .await;
+  test
+    .sdk
+    .feature_flags
+    .set_local_override(FeatureFlag::Automations, Some(false))
+    .unwrap();
+
+  // The rules don't run while the flag is off
+  let row_detail = test
+    .editor
+    .create_row(CreateRowPayloadPB {
+      view_id: test.view_id.clone(),
+      ..Default::default()
+    })
+    .await
+    .unwrap()
+    .unwrap();
+  assert_eq!(
+    text_of(&test, FieldType::RichText, &row_detail.row.id).await,
+    ""
+  );
+
+  // And they can't be changed
+  let result = test
+    .editor
+    .update_automation_rules(UpdateAutomationRulesPayloadPB {
+      view_id: test.view_id.clone(),
+      rules: vec![],
+    })
+    .await;
+  assert!(result.is_err());
+  assert_eq!(test.editor.get_automation_rules().await.items.len(), 1);
+}
@@ -1,7 +1,18 @@
+use collab_database::database::gen_row_id;
 use collab_database::fields::date_type_option::DateCellData;
-use flowy_database2::entities::FieldType;
+use collab_database::fields::Field;
+use collab_database::rows::{CreateRowParams, RowId};
+use flowy_database2::entities::{
+  CheckboxFilterConditionPB, CheckboxFilterPB, CreateRowPayloadPB, FieldType, RowSampleModePB,
+};
+use flowy_database2::services::cell::stringify_cell;
+use flowy_database2::services::filter::{FilterChangeset, FilterInner};
+use flowy_database2::services::setting::{RowTemplate, RowTemplateCell};
+use flowy_error::ErrorCode;
+use futures::{pin_mut, StreamExt};
+use lib_infra::box_any::BoxAny;
 use lib_infra::util::timestamp;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use crate::database::block_test::script::DatabaseRowTest;
 
@@ -62,3 +73,798 @@ async fn update_at_field_test() {
 
   assert!(old_updated_at < new_updated_at);
 }
+
+#[tokio::test]
+async fn row_meta_timestamps_test() {
+  let test = DatabaseRowTest::new().await;
+  let row = test.get_rows().await.remove(0);
+  let row_meta = test
+    .editor
+    .get_row_meta(&test.view_id, &row.id)
+    .await
+    .unwrap();
+  assert_eq!(row_meta.created_at, Some(row.created_at));
+  assert_eq!(row_meta.modified_at, Some(row.modified_at));
+
+  // Wait for 1 second before clearing a cell of the row
+  tokio::time::sleep(Duration::from_millis(1000)).await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  test
+    .editor
+    .clear_cell(&test.view_id, row.id.clone(), &text_field.id)
+    .await
+    .unwrap();
+
+  let row_meta = test
+    .editor
+    .get_row_meta(&test.view_id, &row.id)
+    .await
+    .unwrap();
+  assert_eq!(row_meta.created_at, Some(row.created_at));
+  assert!(row_meta.modified_at.unwrap() > row.modified_at);
+}
+
+#[tokio::test]
+async fn delete_all_rows_test() {
+  let test = DatabaseRowTest::new().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let rev_id = database_manager
+    .export_changes_since(&test.view_id, 0)
+    .await
+    .unwrap()
+    .rev_id;
+  let row_count = test.get_rows().await.len();
+  let field_count = test.get_fields().await.len();
+  assert!(row_count > 0);
+
+  assert_eq!(test.editor.delete_all_rows().await.unwrap(), row_count);
+  assert!(test.get_rows().await.is_empty());
+  assert_eq!(test.get_fields().await.len(), field_count);
+
+  // Every row is listed as deleted by the change log
+  let changes = database_manager
+    .export_changes_since(&test.view_id, rev_id)
+    .await
+    .unwrap();
+  assert_eq!(changes.deleted_row_ids.len(), row_count);
+
+  // The grid keeps working after it was cleared
+  test
+    .editor
+    .create_row(CreateRowPayloadPB {
+      view_id: test.view_id.clone(),
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+  assert_eq!(test.get_rows().await.len(), 1);
+}
+
+#[tokio::test]
+async fn create_row_from_template_test() {
+  let test = DatabaseRowTest::new().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  let number_field = test.get_first_field(FieldType::Number).await;
+  let template = |value: &str| RowTemplate {
+    id: String::new(),
+    name: "Weekly review".to_string(),
+    cells: vec![
+      RowTemplateCell {
+        field_id: text_field.id.clone(),
+        value: "Review the week".to_string(),
+      },
+      RowTemplateCell {
+        field_id: number_field.id.clone(),
+        value: value.to_string(),
+      },
+    ],
+  };
+
+  // A value that isn't valid for its field is rejected
+  assert!(test
+    .editor
+    .update_row_templates(vec![template("not a number")])
+    .await
+    .is_err());
+  test
+    .editor
+    .update_row_templates(vec![template("3")])
+    .await
+    .unwrap();
+  let templates = test.editor.get_row_templates().await;
+  assert_eq!(templates.len(), 1);
+  assert!(!templates[0].id.is_empty());
+
+  let row_count = test.get_rows().await.len();
+  let row_detail = test
+    .editor
+    .create_row_from_template(&test.view_id, &templates[0].id)
+    .await
+    .unwrap();
+  let rows = test.get_rows().await;
+  assert_eq!(rows.len(), row_count + 1);
+  let cell = |field: &Field| {
+    row_detail
+      .row
+      .cells
+      .get(&field.id)
+      .map(|cell| stringify_cell(cell, field))
+      .unwrap_or_default()
+  };
+  assert_eq!(cell(&text_field), "Review the week");
+  assert_eq!(cell(&number_field), "$3");
+
+  assert!(test
+    .editor
+    .create_row_from_template(&test.view_id, "missing")
+    .await
+    .is_err());
+}
+
+#[tokio::test]
+async fn open_row_document_test() {
+  let test = DatabaseRowTest::new().await;
+  let row = test.get_rows().await.remove(0);
+  let document_manager = test.sdk.appflowy_core.document_manager.clone();
+
+  // The document of the row is created the first time the row is opened
+  let document_id = test
+    .editor
+    .open_row_document(&test.view_id, &row.id)
+    .await
+    .unwrap();
+  let row_meta = test
+    .editor
+    .get_row_meta(&test.view_id, &row.id)
+    .await
+    .unwrap();
+  assert_eq!(row_meta.document_id, Some(document_id.clone()));
+  assert!(document_manager
+    .get_document_data(&document_id)
+    .await
+    .is_ok());
+  assert_eq!(
+    test
+      .editor
+      .open_row_document(&test.view_id, &row.id)
+      .await
+      .unwrap(),
+    document_id
+  );
+
+  // The document is kept while the row is in the trash, and deleted once the row is purged
+  test.editor.delete_rows(&[row.id.clone()]).await.unwrap();
+  assert!(document_manager
+    .get_document_data(&document_id)
+    .await
+    .is_ok());
+  test.editor.purge_trash().await.unwrap();
+  assert!(document_manager
+    .get_document_data(&document_id)
+    .await
+    .is_err());
+}
+
+#[tokio::test]
+async fn row_trash_test() {
+  let test = DatabaseRowTest::new().await;
+  let rows = test.get_rows().await;
+  let row_count = rows.len();
+  let deleted_row_ids = vec![rows[1].id.clone(), rows[3].id.clone()];
+
+  // The deleted rows are moved to the trash, keeping their cells
+  test.editor.delete_rows(&deleted_row_ids).await.unwrap();
+  assert_eq!(test.get_rows().await.len(), row_count - 2);
+  let trashed_rows = test.editor.get_trashed_rows().await;
+  assert_eq!(
+    trashed_rows
+      .iter()
+      .map(|row| row.row_id.clone())
+      .collect::<Vec<_>>(),
+    vec![rows[1].id.to_string(), rows[3].id.to_string()]
+  );
+
+  // The restored rows are back at their position with their cells
+  test.editor.restore_rows(&deleted_row_ids).await.unwrap();
+  let restored_rows = test.get_rows().await;
+  assert_eq!(
+    restored_rows
+      .iter()
+      .map(|row| row.id.clone())
+      .collect::<Vec<_>>(),
+    rows.iter().map(|row| row.id.clone()).collect::<Vec<_>>()
+  );
+  assert_eq!(restored_rows[1].cells, rows[1].cells);
+  assert!(test.editor.get_trashed_rows().await.is_empty());
+
+  // The purged rows can't be restored anymore
+  test.editor.delete_rows(&deleted_row_ids).await.unwrap();
+  test.editor.purge_trash().await.unwrap();
+  assert!(test.editor.get_trashed_rows().await.is_empty());
+  test.editor.restore_rows(&deleted_row_ids).await.unwrap();
+  assert_eq!(test.get_rows().await.len(), row_count - 2);
+}
+
+#[tokio::test]
+async fn get_row_by_id_test() {
+  let test = DatabaseRowTest::new().await;
+  let rows = test.get_rows().await;
+  let last_row = rows.last().unwrap();
+  let text_field = test.get_first_field(FieldType::RichText).await;
+
+  let row = test.editor.get_row_by_id(&last_row.id).await.unwrap();
+  assert_eq!(row.id, last_row.id);
+  assert_eq!(row.cells, last_row.cells);
+  assert_eq!(
+    test.editor.get_cell(&text_field.id, &last_row.id).await,
+    last_row.cells.get(&text_field.id).cloned()
+  );
+  assert!(test.editor.get_row_by_id(&gen_row_id()).await.is_none());
+
+  // The rows in the trash aren't returned
+  test
+    .editor
+    .delete_rows(&[last_row.id.clone()])
+    .await
+    .unwrap();
+  assert!(test.editor.get_row_by_id(&last_row.id).await.is_none());
+}
+
+#[tokio::test]
+async fn row_stream_test() {
+  let test = DatabaseRowTest::new().await;
+  let row_ids = get_row_ids(&test).await;
+
+  let row_stream = test.editor.row_stream(&test.view_id).await.unwrap();
+  pin_mut!(row_stream);
+  let mut streamed_row_ids = vec![];
+  while let Some(row) = row_stream.next().await {
+    streamed_row_ids.push(row.id.clone());
+  }
+  assert_eq!(streamed_row_ids, row_ids);
+}
+
+#[tokio::test]
+async fn row_count_test() {
+  let test = DatabaseRowTest::new().await;
+  let rows = test.get_rows().await;
+  assert_eq!(test.editor.row_count(), rows.len());
+
+  // The count is updated when the row orders of the database change
+  test
+    .editor
+    .create_row(CreateRowPayloadPB {
+      view_id: test.view_id.clone(),
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+  wait_for_row_count(&test, rows.len() + 1).await;
+
+  test
+    .editor
+    .delete_rows(&[rows[0].id.clone(), rows[1].id.clone()])
+    .await
+    .unwrap();
+  wait_for_row_count(&test, rows.len() - 1).await;
+}
+
+async fn wait_for_row_count(test: &DatabaseRowTest, count: usize) {
+  let started_at = Instant::now();
+  while test.editor.row_count() != count && started_at.elapsed() < Duration::from_secs(5) {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+  }
+  assert_eq!(test.editor.row_count(), count);
+}
+
+#[tokio::test]
+async fn row_lock_test() {
+  let test = DatabaseRowTest::new().await;
+  let row = test.get_rows().await.remove(0);
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  test.editor.set_row_locked(&row.id, true).await.unwrap();
+  let row_meta = test
+    .editor
+    .get_row_meta(&test.view_id, &row.id)
+    .await
+    .unwrap();
+  assert_eq!(row_meta.is_locked, Some(true));
+
+  // The locked row can't be edited or deleted
+  let error = test
+    .editor
+    .update_cell_with_changeset(
+      &test.view_id,
+      &row.id,
+      &text_field.id,
+      BoxAny::new("Locked".to_string()),
+    )
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::RowLocked);
+  let error = test
+    .editor
+    .delete_rows(&[row.id.clone()])
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::RowLocked);
+  assert!(test.editor.get_row_by_id(&row.id).await.is_some());
+
+  // The row can be edited again once it's unlocked
+  test.editor.set_row_locked(&row.id, false).await.unwrap();
+  test
+    .editor
+    .update_cell_with_changeset(
+      &test.view_id,
+      &row.id,
+      &text_field.id,
+      BoxAny::new("Unlocked".to_string()),
+    )
+    .await
+    .unwrap();
+  test.editor.delete_rows(&[row.id.clone()]).await.unwrap();
+}
+
+#[tokio::test]
+async fn row_comments_test() {
+  let test = DatabaseRowTest::new().await;
+  let row = test.get_rows().await.remove(0);
+  assert!(test.editor.get_comments(&row.id).await.unwrap().is_empty());
+
+  let first = test
+    .editor
+    .add_comment(&row.id, "Lucas".to_string(), " Looks good ".to_string())
+    .await
+    .unwrap();
+  let second = test
+    .editor
+    .add_comment(&row.id, "Nathan".to_string(), "Done".to_string())
+    .await
+    .unwrap();
+  assert_eq!(first.text, "Looks good");
+  assert!(test
+    .editor
+    .add_comment(&row.id, "Lucas".to_string(), "  ".to_string())
+    .await
+    .is_err());
+  let comments = test.editor.get_comments(&row.id).await.unwrap();
+  assert_eq!(comments, vec![first.clone(), second.clone()]);
+
+  // The comments aren't copied to a duplicated row
+  let row_ids = test
+    .get_rows()
+    .await
+    .into_iter()
+    .map(|row| row.id)
+    .collect::<Vec<_>>();
+  test
+    .editor
+    .duplicate_row(&test.view_id, &row.id)
+    .await
+    .unwrap();
+  let copy = test
+    .get_rows()
+    .await
+    .into_iter()
+    .find(|other| !row_ids.contains(&other.id))
+    .unwrap();
+  assert!(test.editor.get_comments(&copy.id).await.unwrap().is_empty());
+
+  test
+    .editor
+    .delete_comment(&row.id, &first.id)
+    .await
+    .unwrap();
+  assert!(test
+    .editor
+    .delete_comment(&row.id, &first.id)
+    .await
+    .is_err());
+  let comments = test.editor.get_comments(&row.id).await.unwrap();
+  assert_eq!(comments, vec![second]);
+}
+
+#[tokio::test]
+async fn sample_rows_test() {
+  let test = DatabaseRowTest::new().await;
+  let rows = test.get_rows().await;
+  let row_ids = rows
+    .iter()
+    .map(|row| row.id.to_string())
+    .collect::<Vec<_>>();
+  let field_count = test.get_fields().await.len();
+  let (test, row_ids) = (&test, &row_ids);
+  let sample_row_ids = |mode: RowSampleModePB| async move {
+    let sample = test
+      .editor
+      .get_sample_rows(&test.view_id, 3, mode)
+      .await
+      .unwrap();
+    assert_eq!(sample.total_count, row_ids.len() as i64);
+    sample
+      .items
+      .into_iter()
+      .map(|item| item.row.id)
+      .collect::<Vec<_>>()
+  };
+
+  assert_eq!(sample_row_ids(RowSampleModePB::First).await, row_ids[..3]);
+  assert_eq!(
+    sample_row_ids(RowSampleModePB::Last).await,
+    row_ids[row_ids.len() - 3..]
+  );
+
+  // The random rows are distinct and keep the order of the view
+  let random_row_ids = sample_row_ids(RowSampleModePB::Random).await;
+  let positions = random_row_ids
+    .iter()
+    .map(|id| row_ids.iter().position(|row_id| row_id == id).unwrap())
+    .collect::<Vec<_>>();
+  assert_eq!(positions.len(), 3);
+  assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+
+  let sample = test
+    .editor
+    .get_sample_rows(&test.view_id, row_ids.len() + 10, RowSampleModePB::Random)
+    .await
+    .unwrap();
+  assert_eq!(sample.items.len(), row_ids.len());
+  assert!(sample
+    .items
+    .iter()
+    .all(|item| item.cells.len() <= field_count));
+}
+
+#[tokio::test]
+async fn database_diagnostics_test() {
+  let test = DatabaseRowTest::new().await;
+  let diagnostics = test.sdk.database_manager.diagnostics().await;
+  let editor_diagnostics = diagnostics
+    .iter()
+    .find(|diagnostics| diagnostics.view_ids.contains(&test.view_id))
+    .unwrap();
+
+  assert!(!editor_diagnostics.is_closing);
+  assert!(editor_diagnostics.last_activity > 0);
+  assert!(editor_diagnostics.last_activity <= timestamp());
+}
+
+#[tokio::test]
+async fn move_row_to_index_in_filtered_view_test() {
+  let test = DatabaseRowTest::new().await;
+  let checkbox_field = test.get_first_field(FieldType::Checkbox).await;
+  test
+    .editor
+    .modify_view_filters(
+      &test.view_id,
+      FilterChangeset::Insert {
+        parent_filter_id: None,
+        data: FilterInner::Data {
+          field_id: checkbox_field.id.clone(),
+          field_type: FieldType::Checkbox,
+          condition_and_content: BoxAny::new(CheckboxFilterPB {
+            condition: CheckboxFilterConditionPB::IsChecked,
+          }),
+        },
+      },
+    )
+    .await
+    .unwrap();
+
+  // Wait until the filter is applied to the rows of the view
+  let (tx, rx) = tokio::sync::oneshot::channel();
+  test
+    .editor
+    .open_database_view(&test.view_id, Some(tx))
+    .await
+    .unwrap();
+  rx.await.unwrap();
+
+  let visible_row_ids = get_row_ids(&test).await;
+  assert_eq!(visible_row_ids.len(), 3);
+
+  // The index refers to the visible rows, so the hidden rows between them are skipped
+  test
+    .editor
+    .move_row_to_index(&test.view_id, visible_row_ids[0].clone(), 2)
+    .await
+    .unwrap();
+  assert_eq!(
+    get_row_ids(&test).await,
+    vec![
+      visible_row_ids[1].clone(),
+      visible_row_ids[2].clone(),
+      visible_row_ids[0].clone(),
+    ]
+  );
+
+  // Rows hidden by the filters can't be moved
+  let hidden_row = test
+    .rows
+    .iter()
+    .find(|row| !visible_row_ids.contains(&row.id))
+    .unwrap();
+  assert!(test
+    .editor
+    .move_row_to_index(&test.view_id, hidden_row.id.clone(), 0)
+    .await
+    .is_err());
+}
+
+#[tokio::test]
+async fn create_row_at_index_test() {
+  let test = DatabaseRowTest::new().await;
+  let row_ids = get_row_ids(&test).await;
+
+  let row = test
+    .editor
+    .create_row_at_index(
+      CreateRowPayloadPB {
+        view_id: test.view_id.clone(),
+        ..Default::default()
+      },
+      1,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+  let new_row_ids = get_row_ids(&test).await;
+  assert_eq!(new_row_ids.len(), row_ids.len() + 1);
+  assert_eq!(new_row_ids[0], row_ids[0]);
+  assert_eq!(new_row_ids[1], row.row.id);
+  assert_eq!(new_row_ids[2], row_ids[1]);
+
+  // An index past the last row creates the row at the end
+  let row = test
+    .editor
+    .create_row_at_index(
+      CreateRowPayloadPB {
+        view_id: test.view_id.clone(),
+        ..Default::default()
+      },
+      100,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+  assert_eq!(get_row_ids(&test).await.last(), Some(&row.row.id));
+}
+
+#[tokio::test]
+async fn get_rows_paged_test() {
+  let test = DatabaseRowTest::new().await;
+  let row_ids = get_row_ids(&test).await;
+
+  let (rows, total_count) = test
+    .editor
+    .get_rows_paged(&test.view_id, 1, 2)
+    .await
+    .unwrap();
+  assert_eq!(total_count, row_ids.len());
+  assert_eq!(
+    rows.iter().map(|row| row.id.clone()).collect::<Vec<_>>(),
+    row_ids[1..3]
+  );
+
+  // A page past the last row is empty
+  let (rows, _) = test
+    .editor
+    .get_rows_paged(&test.view_id, row_ids.len(), 10)
+    .await
+    .unwrap();
+  assert!(rows.is_empty());
+}
+
+#[tokio::test]
+async fn compact_database_test() {
+  let test = DatabaseRowTest::new().await;
+  let row_ids = get_row_ids(&test).await;
+  test.editor.delete_rows(&row_ids[..1]).await.unwrap();
+  test.editor.compact().await.unwrap();
+  assert_eq!(get_row_ids(&test).await, row_ids[1..]);
+}
+
+#[tokio::test]
+async fn insert_rows_test() {
+  let test = DatabaseRowTest::new().await;
+  let row_count = test.rows.len();
+  let rows = new_rows(&test, 10).await;
+  let row_ids = rows.iter().map(|row| row.id.clone()).collect::<Vec<_>>();
+  let row_orders = test.editor.insert_rows(&test.view_id, rows).await.unwrap();
+  assert_eq!(
+    row_orders
+      .into_iter()
+      .map(|row_order| row_order.id)
+      .collect::<Vec<_>>(),
+    row_ids
+  );
+
+  // The rows are appended in order
+  let all_row_ids = get_row_ids(&test).await;
+  assert_eq!(all_row_ids.len(), row_count + 10);
+  assert_eq!(all_row_ids[row_count..], row_ids[..]);
+}
+
+#[tokio::test]
+async fn insert_rows_with_existing_ids_test() {
+  let test = DatabaseRowTest::new().await;
+  let row_count = test.rows.len();
+  let row_ids = (0..3).map(|_| gen_row_id()).collect::<Vec<_>>();
+  let rows = new_rows_with_ids(&test, &row_ids[..2]).await;
+  let inserted = test.editor.insert_rows(&test.view_id, rows).await.unwrap();
+  assert_eq!(inserted.len(), 2);
+
+  // Retrying the insert only creates the rows that weren't created yet
+  let rows = new_rows_with_ids(&test, &row_ids).await;
+  let inserted = test.editor.insert_rows(&test.view_id, rows).await.unwrap();
+  assert_eq!(
+    inserted
+      .into_iter()
+      .map(|row_order| row_order.id)
+      .collect::<Vec<_>>(),
+    row_ids[2..]
+  );
+  let all_row_ids = get_row_ids(&test).await;
+  assert_eq!(all_row_ids.len(), row_count + 3);
+  assert_eq!(all_row_ids[row_count..], row_ids[..]);
+
+  // Creating a row with the id of an existing row returns that row
+  let params = || CreateRowPayloadPB {
+    view_id: test.view_id.clone(),
+    row_id: Some(row_ids[0].to_string()),
+    ..Default::default()
+  };
+  let row = test.editor.create_row(params()).await.unwrap().unwrap();
+  assert_eq!(row.row.id, row_ids[0]);
+  assert_eq!(get_row_ids(&test).await.len(), row_count + 3);
+}
+
+/// Inserting 10k rows must take less than 5 seconds in a release build, see
+/// `cargo test --release insert_10k_rows_benchmark`. The debug builds are given more time so the
+/// test still runs with the others.
+#[tokio::test]
+async fn insert_10k_rows_benchmark() {
+  let max_elapsed = if cfg!(debug_assertions) {
+    Duration::from_secs(60)
+  } else {
+    Duration::from_secs(5)
+  };
+  let test = DatabaseRowTest::new().await;
+  let row_count = test.rows.len();
+  let rows = new_rows(&test, 10_000).await;
+  let start = Instant::now();
+  test.editor.insert_rows(&test.view_id, rows).await.unwrap();
+  let elapsed = start.elapsed();
+  assert!(
+    elapsed < max_elapsed,
+    "inserting 10k rows took {:?}, more than {:?}",
+    elapsed,
+    max_elapsed
+  );
+  assert_eq!(get_row_ids(&test).await.len(), row_count + 10_000);
+}
+
+async fn new_rows(test: &DatabaseRowTest, count: usize) -> Vec<CreateRowParams> {
+  let row_ids = (0..count).map(|_| gen_row_id()).collect::<Vec<_>>();
+  new_rows_with_ids(test, &row_ids).await
+}
+
+async fn new_rows_with_ids(test: &DatabaseRowTest, row_ids: &[RowId]) -> Vec<CreateRowParams> {
+  let database_id = test
+    .sdk
+    .database_manager
+    .get_database_id_with_view_id(&test.view_id)
+    .await
+    .unwrap();
+  row_ids
+    .iter()
+    .map(|row_id| CreateRowParams::new(row_id.clone(), database_id.clone()))
+    .collect()
+}
+
+async fn get_row_ids(test: &DatabaseRowTest) -> Vec<RowId> {
+  test
+    .editor
+    .get_all_rows(&test.view_id)
+    .await
+    .unwrap()
+    .iter()
+    .map(|row| row.id.clone())
+    .collect()
+}
+
+#[tokio::test]
+async fn search_cells_test() {
+  let mut test = DatabaseRowTest::new().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  let url_field = test.get_first_field(FieldType::URL).await;
+
+  let hits = test
+    .editor
+    .search_cells(&test.view_id, "APPFLOWY website", 0, 10)
+    .await
+    .unwrap();
+  assert_eq!(hits.total_count, 1);
+  assert_eq!(hits.items[0].row_id, test.rows[0].id.to_string());
+  assert_eq!(hits.items[0].field_id, url_field.id);
+  assert_eq!(
+    hits.items[0].snippet,
+    "AppFlowy website - https://www.appflowy.io"
+  );
+
+  for row in test.rows.clone().iter().take(3) {
+    test
+      .update_text_cell(
+        row.id.clone(),
+        "The quick brown fox jumps over the lazy dog",
+      )
+      .await;
+  }
+
+  // The hits are paginated
+  let hits = test
+    .editor
+    .search_cells(&test.view_id, "lazy", 1, 1)
+    .await
+    .unwrap();
+  assert_eq!(hits.total_count, 3);
+  assert_eq!(hits.items.len(), 1);
+  assert_eq!(hits.items[0].row_id, test.rows[1].id.to_string());
+  assert_eq!(hits.items[0].field_id, text_field.id);
+
+  // A blank query doesn't match anything
+  let hits = test
+    .editor
+    .search_cells(&test.view_id, "  ", 0, 10)
+    .await
+    .unwrap();
+  assert_eq!(hits.total_count, 0);
+}
+
+#[tokio::test]
+async fn replace_all_cells_test() {
+  let mut test = DatabaseRowTest::new().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  for row in test.rows.clone().iter().take(2) {
+    test.update_text_cell(row.id.clone(), "Todo: ship it").await;
+  }
+
+  // A dry run counts the cells without changing them
+  let num_of_changed_cells = test
+    .editor
+    .replace_all(&test.view_id, "TODO", "Done", vec![], true)
+    .await
+    .unwrap();
+  assert_eq!(num_of_changed_cells, 2);
+  let cell = test
+    .editor
+    .get_cell(&text_field.id, &test.rows[0].id)
+    .await
+    .unwrap();
+  assert_eq!(stringify_cell(&cell, &text_field), "Todo: ship it");
+
+  // Only the given fields are changed
+  let num_of_changed_cells = test
+    .editor
+    .replace_all(
+      &test.view_id,
+      "todo",
+      "Done",
+      vec!["not exist field id".to_string()],
+      false,
+    )
+    .await
+    .unwrap();
+  assert_eq!(num_of_changed_cells, 0);
+
+  let num_of_changed_cells = test
+    .editor
+    .replace_all(&test.view_id, "todo", "Done", vec![], false)
+    .await
+    .unwrap();
+  assert_eq!(num_of_changed_cells, 2);
+  for row in test.rows.iter().take(2) {
+    let cell = test.editor.get_cell(&text_field.id, &row.id).await.unwrap();
+    assert_eq!(stringify_cell(&cell, &text_field), "Done: ship it");
+  }
+}
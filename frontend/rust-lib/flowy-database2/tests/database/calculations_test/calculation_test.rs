@@ -193,3 +193,33 @@ async fn calculations_count_test() {
   tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
   test.assert_calculation_value("8").await;
 }
+
+#[tokio::test]
+async fn calculations_count_rows_without_cell_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+
+  // Only the first row has a cell for the url field
+  let view_id = &test.view_id();
+  let field_id = &test.get_first_field(FieldType::URL).await.id;
+  let calculation_id = "calc_id".to_owned();
+
+  test
+    .insert_calculation(UpdateCalculationChangesetPB {
+      view_id: view_id.clone(),
+      field_id: field_id.clone(),
+      calculation_id: Some(calculation_id.clone()),
+      calculation_type: CalculationType::Count,
+    })
+    .await;
+  test.assert_calculation_value("7").await;
+
+  test
+    .insert_calculation(UpdateCalculationChangesetPB {
+      view_id: view_id.clone(),
+      field_id: field_id.clone(),
+      calculation_id: Some(calculation_id.clone()),
+      calculation_type: CalculationType::CountEmpty,
+    })
+    .await;
+  test.assert_calculation_value("6").await;
+}
@@ -1,14 +1,27 @@
 use crate::database::cell_test::script::DatabaseCellTest;
 use collab_database::fields::date_type_option::DateCellData;
 use collab_database::fields::media_type_option::{MediaFile, MediaFileType, MediaUploadType};
-use collab_database::fields::select_type_option::{MultiSelectTypeOption, SingleSelectTypeOption};
+use collab_database::fields::select_type_option::{
+  MultiSelectTypeOption, SelectOptionIds, SingleSelectTypeOption,
+};
+use collab_database::fields::text_type_option::RichTextTypeOption;
 use collab_database::fields::url_type_option::URLCellData;
-use flowy_database2::entities::{FieldType, MediaCellChangeset};
+use collab_database::fields::{Field, TypeOptionData};
+use collab_database::rows::{Cell, Row, RowCell};
+use flowy_database2::entities::{
+  CellChangesetPB, FieldChangesetPB, FieldType, MediaCellChangeset, URLCellDataPB,
+};
+use flowy_database2::services::cell::{stringify_cell, CellValidator, TypedCellChangeset};
 use flowy_database2::services::field::{
-  ChecklistCellChangeset, ChecklistCellInsertChangeset, DateCellChangeset, RelationCellChangeset,
-  SelectOptionCellChangeset, StringCellData, TimeCellData,
+  link_preview_from_cell, ChecklistCellChangeset, ChecklistCellInsertChangeset, DateCellChangeset,
+  RelationCellChangeset, SelectOptionCellChangeset, StringCellData, TextDelta, TextValidation,
+  TimeCellData, MASKED_CELL_VALUE,
 };
+use flowy_database2::services::share::clipboard::{select_option_names, ClipboardData};
+use flowy_database_pub::cloud::LinkPreview;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use lib_infra::box_any::BoxAny;
+use std::sync::Arc;
 use std::time::Duration;
 
 #[tokio::test]
@@ -104,6 +117,116 @@ async fn text_cell_data_test() {
   }
 }
 
+#[tokio::test]
+async fn text_cell_delta_test() {
+  let test = DatabaseCellTest::new().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  let row_id = test.rows[0].id.clone();
+  let get_text = || async {
+    let cell = test.editor.get_cell(&text_field.id, &row_id).await.unwrap();
+    StringCellData::from(&cell).into_inner()
+  };
+
+  // Two edits based on the same content are both kept
+  for delta in [
+    TextDelta::new().retain(1).insert("ppFlowy"),
+    TextDelta::new().insert("> "),
+  ] {
+    let delta = delta.with_base_content("A");
+    test
+      .update_cell(&test.view_id, &text_field.id, &row_id, BoxAny::new(delta))
+      .await;
+  }
+  assert_eq!(get_text().await, "> AppFlowy");
+
+  test
+    .update_cell(
+      &test.view_id,
+      &text_field.id,
+      &row_id,
+      BoxAny::new(TextDelta::new().retain(2).delete(3).insert("Flowy")),
+    )
+    .await;
+  assert_eq!(get_text().await, "> FlowyFlowy");
+
+  // An edit made against an older content keeps the newer edits
+  for delta in [
+    TextDelta::new().retain(12).insert("!"),
+    TextDelta::new().delete(2),
+  ] {
+    let delta = delta.with_base_content("> FlowyFlowy");
+    test
+      .update_cell(&test.view_id, &text_field.id, &row_id, BoxAny::new(delta))
+      .await;
+  }
+  assert_eq!(get_text().await, "FlowyFlowy!");
+
+  // A delta that goes past the end of the text is rejected
+  let result = test
+    .editor
+    .update_cell_with_changeset(
+      &test.view_id,
+      &row_id,
+      &text_field.id,
+      BoxAny::new(TextDelta::new().retain(100).insert("!")),
+    )
+    .await;
+  assert!(result.is_err());
+  assert_eq!(get_text().await, "FlowyFlowy!");
+}
+
+#[tokio::test]
+async fn text_cell_validation_test() {
+  let test = DatabaseCellTest::new().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  let row_id = test.rows[0].id.clone();
+  let set_validation = |pattern: &str| {
+    let mut type_option_data: TypeOptionData = RichTextTypeOption.into();
+    TextValidation::new(pattern, "Expected a SKU like ABC-1234").write_to(&mut type_option_data);
+    type_option_data
+  };
+
+  let error = test
+    .editor
+    .update_field_type_option(&text_field.id, set_validation("[A-Z"), text_field.clone())
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::InvalidValidationPattern);
+
+  test
+    .editor
+    .update_field_type_option(
+      &text_field.id,
+      set_validation("[A-Z]{3}-\\d{4}"),
+      text_field.clone(),
+    )
+    .await
+    .unwrap();
+  let error = test
+    .editor
+    .update_cell_with_changeset(
+      &test.view_id,
+      &row_id,
+      &text_field.id,
+      BoxAny::new("abc".to_string()),
+    )
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::TextDoesNotMatchPattern);
+  assert_eq!(error.msg, "Expected a SKU like ABC-1234");
+
+  test
+    .update_cell(
+      &test.view_id,
+      &text_field.id,
+      &row_id,
+      BoxAny::new("ABC-1234".to_string()),
+    )
+    .await;
+  let cell = test.editor.get_cell(&text_field.id, &row_id).await.unwrap();
+  assert_eq!(StringCellData::from(&cell).as_str(), "ABC-1234");
+}
+
 #[tokio::test]
 async fn url_cell_data_test() {
   let test = DatabaseCellTest::new().await;
@@ -126,6 +249,68 @@ async fn url_cell_data_test() {
   }
 }
 
+#[tokio::test]
+async fn url_cell_link_preview_test() {
+  let test = DatabaseCellTest::new().await;
+  let url_field = test.get_first_field(FieldType::URL).await;
+  let row_id = test.rows[0].id.clone();
+  let url = "https://appflowy.io".to_string();
+  let preview = LinkPreview {
+    title: "AppFlowy".to_string(),
+    favicon: "https://appflowy.io/favicon.ico".to_string(),
+  };
+  test
+    .update_cell(
+      &test.view_id,
+      &url_field.id,
+      &row_id,
+      BoxAny::new(url.clone()),
+    )
+    .await;
+
+  // The preview of a url that is no longer in the cell is dropped
+  test
+    .editor
+    .update_link_preview(
+      &test.view_id,
+      &row_id,
+      &url_field.id,
+      "https://github.com",
+      preview.clone(),
+    )
+    .await
+    .unwrap();
+  let cell = test.editor.get_cell(&url_field.id, &row_id).await.unwrap();
+  assert!(link_preview_from_cell(&cell).is_empty());
+
+  test
+    .editor
+    .update_link_preview(&test.view_id, &row_id, &url_field.id, &url, preview.clone())
+    .await
+    .unwrap();
+  let cell_pb = test
+    .editor
+    .get_cell_pb(&url_field.id, &row_id, false)
+    .await
+    .unwrap();
+  let url_cell = URLCellDataPB::try_from(cell_pb.data.as_slice()).unwrap();
+  assert_eq!(url_cell.content, url);
+  assert_eq!(url_cell.title, preview.title);
+  assert_eq!(url_cell.favicon, preview.favicon);
+
+  // Saving a new url drops the preview of the old one
+  test
+    .update_cell(
+      &test.view_id,
+      &url_field.id,
+      &row_id,
+      BoxAny::new("https://github.com".to_string()),
+    )
+    .await;
+  let cell = test.editor.get_cell(&url_field.id, &row_id).await.unwrap();
+  assert!(link_preview_from_cell(&cell).is_empty());
+}
+
 #[tokio::test]
 async fn update_updated_at_field_on_other_cell_update() {
   let test = DatabaseCellTest::new().await;
@@ -200,3 +385,655 @@ async fn time_cell_data_test() {
     assert_eq!(cell.0.unwrap_or_default(), 75);
   }
 }
+
+#[tokio::test]
+async fn delete_select_option_cleans_up_cells_test() {
+  let test = DatabaseCellTest::new().await;
+  let field = test.get_first_field(FieldType::MultiSelect).await;
+  let cell_option_ids = |cells: Vec<RowCell>| {
+    cells
+      .into_iter()
+      .filter_map(|row_cell| row_cell.cell)
+      .flat_map(|cell| SelectOptionIds::from(&cell).to_vec())
+      .collect::<Vec<_>>()
+  };
+
+  let cells = test
+    .editor
+    .get_cells_for_field(&test.view_id, &field.id)
+    .await;
+  let option_id = cell_option_ids(cells).first().cloned().unwrap();
+  let option_count = test.get_multi_select_type_option(&field.id).await.len();
+
+  test
+    .editor
+    .delete_select_option(&test.view_id, &field.id, &option_id)
+    .await
+    .unwrap();
+
+  let options = test.get_multi_select_type_option(&field.id).await;
+  assert_eq!(options.len(), option_count - 1);
+  assert!(options.iter().all(|option| option.id != option_id));
+
+  let cells = test
+    .editor
+    .get_cells_for_field(&test.view_id, &field.id)
+    .await;
+  assert!(!cell_option_ids(cells).contains(&option_id));
+}
+
+#[tokio::test]
+async fn merge_select_options_rewrites_cells_test() {
+  let test = DatabaseCellTest::new().await;
+  let field = test.get_first_field(FieldType::SingleSelect).await;
+  let options = test.get_single_select_type_option(&field.id).await;
+  let (to_option, from_option) = (options[0].clone(), options[1].clone());
+  let count_cells_with = |cells: &[RowCell], option_id: &str| {
+    cells
+      .iter()
+      .filter_map(|row_cell| row_cell.cell.as_ref())
+      .filter(|cell| SelectOptionIds::from(*cell).contains(&option_id.to_string()))
+      .count()
+  };
+
+  let cells = test
+    .editor
+    .get_cells_for_field(&test.view_id, &field.id)
+    .await;
+  let expected_count =
+    count_cells_with(&cells, &from_option.id) + count_cells_with(&cells, &to_option.id);
+
+  test
+    .editor
+    .merge_select_options(&test.view_id, &field.id, &from_option.id, &to_option.id)
+    .await
+    .unwrap();
+
+  let options = test.get_single_select_type_option(&field.id).await;
+  assert!(options.iter().all(|option| option.id != from_option.id));
+
+  let cells = test
+    .editor
+    .get_cells_for_field(&test.view_id, &field.id)
+    .await;
+  assert_eq!(count_cells_with(&cells, &from_option.id), 0);
+  assert_eq!(count_cells_with(&cells, &to_option.id), expected_count);
+}
+
+#[tokio::test]
+async fn delete_select_option_in_locked_row_test() {
+  let test = DatabaseCellTest::new().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let field = test.get_first_field(FieldType::SingleSelect).await;
+  let cells = test
+    .editor
+    .get_cells_for_field(&test.view_id, &field.id)
+    .await;
+  let (row_id, option_id) = cells
+    .into_iter()
+    .find_map(|row_cell| {
+      let option_id = SelectOptionIds::from(row_cell.cell.as_ref()?)
+        .first()?
+        .clone();
+      Some((row_cell.row_id, option_id))
+    })
+    .unwrap();
+
+  // The option can't be stripped from a locked row, so nothing is changed
+  test.editor.set_row_locked(&row_id, true).await.unwrap();
+  let error = test
+    .editor
+    .delete_select_option(&test.view_id, &field.id, &option_id)
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::RowLocked);
+  let options = test.get_single_select_type_option(&field.id).await;
+  assert!(options.iter().any(|option| option.id == option_id));
+  let cell = test.editor.get_cell(&field.id, &row_id).await.unwrap();
+  assert_eq!(SelectOptionIds::from(&cell).first(), Some(&option_id));
+
+  // Once unlocked, the rewritten row is recorded in the change log
+  test.editor.set_row_locked(&row_id, false).await.unwrap();
+  let rev_id = database_manager
+    .export_changes_since(&test.view_id, 0)
+    .await
+    .unwrap()
+    .rev_id;
+  test
+    .editor
+    .delete_select_option(&test.view_id, &field.id, &option_id)
+    .await
+    .unwrap();
+  let changes = database_manager
+    .export_changes_since(&test.view_id, rev_id)
+    .await
+    .unwrap();
+  assert!(changes
+    .updated_rows
+    .iter()
+    .any(|sample| sample.row.id == row_id.to_string()));
+  assert!(changes
+    .updated_fields
+    .iter()
+    .any(|updated_field| updated_field.id == field.id));
+}
+
+#[tokio::test]
+async fn multi_select_max_selections_test() {
+  let test = DatabaseCellTest::new().await;
+  let field = test.get_first_field(FieldType::MultiSelect).await;
+  let option_ids = test
+    .get_multi_select_type_option(&field.id)
+    .await
+    .into_iter()
+    .map(|option| option.id)
+    .collect::<Vec<_>>();
+  let row_id = test.rows[0].id.clone();
+  let set_max_selections = |max_selections: i64| FieldChangesetPB {
+    field_id: field.id.clone(),
+    view_id: test.view_id.clone(),
+    max_selections: Some(max_selections),
+    ..Default::default()
+  };
+
+  test
+    .editor
+    .update_field(set_max_selections(2))
+    .await
+    .unwrap();
+  let error = test
+    .editor
+    .update_cell_with_changeset(
+      &test.view_id,
+      &row_id,
+      &field.id,
+      BoxAny::new(SelectOptionCellChangeset::from_insert_options(
+        option_ids.clone(),
+      )),
+    )
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::ExceedMaxSelections);
+
+  // Removing the limit allows the same changeset
+  test
+    .editor
+    .update_field(set_max_selections(0))
+    .await
+    .unwrap();
+  test
+    .update_cell(
+      &test.view_id,
+      &field.id,
+      &row_id,
+      BoxAny::new(SelectOptionCellChangeset::from_insert_options(
+        option_ids.clone(),
+      )),
+    )
+    .await;
+  let cell = test.editor.get_cell(&field.id, &row_id).await.unwrap();
+  assert_eq!(SelectOptionIds::from(&cell).len(), option_ids.len());
+}
+
+#[tokio::test]
+async fn update_cell_with_typed_changeset_test() {
+  let test = DatabaseCellTest::new().await;
+  let row_id = test.rows[0].id.clone();
+  let multi_select_field = test.get_first_field(FieldType::MultiSelect).await;
+  let date_field = test.get_first_field(FieldType::DateTime).await;
+  let number_field = test.get_first_field(FieldType::Number).await;
+  let option_ids = test
+    .get_multi_select_type_option(&multi_select_field.id)
+    .await
+    .into_iter()
+    .map(|option| option.id)
+    .collect::<Vec<_>>();
+
+  // The options that are not in the changeset are unselected
+  for selected_option_ids in [option_ids.clone(), vec![option_ids[1].clone()]] {
+    test
+      .editor
+      .update_cell_with_typed_changeset(
+        &test.view_id,
+        &row_id,
+        &multi_select_field.id,
+        TypedCellChangeset::SelectOptionIds(selected_option_ids.clone()),
+      )
+      .await
+      .unwrap();
+    let cell = test
+      .editor
+      .get_cell(&multi_select_field.id, &row_id)
+      .await
+      .unwrap();
+    assert_eq!(SelectOptionIds::from(&cell).to_vec(), selected_option_ids);
+  }
+
+  test
+    .editor
+    .update_cell_with_typed_changeset(
+      &test.view_id,
+      &row_id,
+      &date_field.id,
+      TypedCellChangeset::Date(1_700_000_000),
+    )
+    .await
+    .unwrap();
+  let cell = test.editor.get_cell(&date_field.id, &row_id).await.unwrap();
+  assert_eq!(DateCellData::from(&cell).timestamp, Some(1_700_000_000));
+
+  let error = test
+    .editor
+    .update_cell_with_typed_changeset(
+      &test.view_id,
+      &row_id,
+      &number_field.id,
+      TypedCellChangeset::Text("12".to_string()),
+    )
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::CellDataTypeMismatch);
+}
+
+#[tokio::test]
+async fn get_cell_with_display_string_test() {
+  let test = DatabaseCellTest::new().await;
+  let field = test.get_first_field(FieldType::Number).await;
+  let row_id = test.rows[0].id.clone();
+
+  let cell = test
+    .editor
+    .get_cell_pb(&field.id, &row_id, false)
+    .await
+    .unwrap();
+  // The stored value is not formatted by the number format of the field
+  assert_eq!(cell.display.display, "$1");
+  assert_eq!(cell.display.value.parse::<f64>().unwrap(), 1.0);
+}
+
+#[tokio::test]
+async fn copy_and_paste_cells_test() {
+  let test = DatabaseCellTest::new().await;
+  let multi_select_field = test.get_first_field(FieldType::MultiSelect).await;
+  let single_select_field = test.get_first_field(FieldType::SingleSelect).await;
+  let date_field = test.get_first_field(FieldType::DateTime).await;
+  let option_ids = test
+    .get_multi_select_type_option(&multi_select_field.id)
+    .await
+    .into_iter()
+    .map(|option| option.id)
+    .collect::<Vec<_>>();
+  let from_row_id = test.rows[0].id.clone();
+  let to_row_id = test.rows[1].id.clone();
+  test
+    .editor
+    .update_cell_with_typed_changeset(
+      &test.view_id,
+      &from_row_id,
+      &multi_select_field.id,
+      TypedCellChangeset::SelectOptionIds(option_ids.clone()),
+    )
+    .await
+    .unwrap();
+  test
+    .editor
+    .update_cell_with_typed_changeset(
+      &test.view_id,
+      &from_row_id,
+      &date_field.id,
+      TypedCellChangeset::Date(1_700_000_000),
+    )
+    .await
+    .unwrap();
+
+  for field in [&multi_select_field, &date_field] {
+    let data = test
+      .editor
+      .serialize_cells(
+        &test.view_id,
+        &[from_row_id.clone()],
+        &[field.id.clone()],
+        false,
+      )
+      .await
+      .unwrap();
+    assert!(!data.payload.is_empty());
+    let result = test
+      .editor
+      .paste_cells(&test.view_id, &to_row_id, &field.id, data)
+      .await
+      .unwrap();
+    assert_eq!(result.num_of_pasted_cells, 1);
+  }
+  let cell = test
+    .editor
+    .get_cell(&multi_select_field.id, &to_row_id)
+    .await
+    .unwrap();
+  assert_eq!(SelectOptionIds::from(&cell).to_vec(), option_ids);
+  let cell = test
+    .editor
+    .get_cell(&date_field.id, &to_row_id)
+    .await
+    .unwrap();
+  assert_eq!(DateCellData::from(&cell).timestamp, Some(1_700_000_000));
+
+  // The text copied from another app creates the missing option
+  let data = ClipboardData {
+    plain_text: "Archived".to_string(),
+    payload: "".to_string(),
+  };
+  test
+    .editor
+    .paste_cells(&test.view_id, &to_row_id, &single_select_field.id, data)
+    .await
+    .unwrap();
+  let options = test
+    .get_single_select_type_option(&single_select_field.id)
+    .await;
+  let archived = options
+    .iter()
+    .find(|option| option.name == "Archived")
+    .unwrap();
+  let cell = test
+    .editor
+    .get_cell(&single_select_field.id, &to_row_id)
+    .await
+    .unwrap();
+  assert_eq!(
+    SelectOptionIds::from(&cell).to_vec(),
+    vec![archived.id.clone()]
+  );
+}
+
+#[tokio::test]
+async fn copy_sensitive_cells_test() {
+  let test = DatabaseCellTest::new().await;
+  let field = test.get_first_field(FieldType::MultiSelect).await;
+  let option_ids = test
+    .get_multi_select_type_option(&field.id)
+    .await
+    .into_iter()
+    .map(|option| option.id)
+    .collect::<Vec<_>>();
+  let row_id = test.rows[0].id.clone();
+  test
+    .editor
+    .update_cell_with_typed_changeset(
+      &test.view_id,
+      &row_id,
+      &field.id,
+      TypedCellChangeset::SelectOptionIds(option_ids),
+    )
+    .await
+    .unwrap();
+  test
+    .editor
+    .update_field(FieldChangesetPB {
+      field_id: field.id.clone(),
+      view_id: test.view_id.clone(),
+      is_sensitive: Some(true),
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+
+  let data = test
+    .editor
+    .serialize_cells(&test.view_id, &[row_id.clone()], &[field.id.clone()], false)
+    .await
+    .unwrap();
+  assert_eq!(data.plain_text, MASKED_CELL_VALUE);
+  let cells = data.cells();
+  assert_eq!(cells.rows[0][0].text, MASKED_CELL_VALUE);
+  assert!(select_option_names(&cells.rows[0][0])
+    .iter()
+    .all(|(_, option)| option.is_none()));
+
+  let data = test
+    .editor
+    .serialize_cells(&test.view_id, &[row_id], &[field.id.clone()], true)
+    .await
+    .unwrap();
+  assert_ne!(data.plain_text, MASKED_CELL_VALUE);
+  assert!(!data.plain_text.is_empty());
+}
+
+#[tokio::test]
+async fn paste_text_as_rows_test() {
+  let test = DatabaseCellTest::new().await;
+  let fields = test.editor.get_fields(&test.view_id, None).await;
+  let number_index = fields
+    .iter()
+    .position(|field| FieldType::from(field.field_type) == FieldType::Number)
+    .unwrap();
+  let row_count = test.editor.get_all_rows(&test.view_id).await.unwrap().len();
+
+  // The primary field is the first field of the grid, followed by the number field
+  assert_eq!(number_index, 1);
+  let data = ClipboardData {
+    plain_text: "pasted 1\t$1,200\npasted 2\tabc".to_string(),
+    payload: "".to_string(),
+  };
+  let result = test.editor.paste_rows(&test.view_id, data).await.unwrap();
+  assert_eq!(result.created_row_ids.len(), 2);
+  assert_eq!(result.num_of_pasted_cells, 3);
+  assert_eq!(result.failures.len(), 1);
+  assert_eq!(result.failures[0].row_index, 1);
+  assert_eq!(result.failures[0].column_index, 1);
+  assert_eq!(result.failures[0].field_id, fields[number_index].id);
+  assert_eq!(result.failures[0].text, "abc");
+
+  let rows = test.editor.get_all_rows(&test.view_id).await.unwrap();
+  assert_eq!(rows.len(), row_count + 2);
+  let cell = test
+    .editor
+    .get_cell(&fields[number_index].id, &result.created_row_ids[0])
+    .await
+    .unwrap();
+  assert_eq!(stringify_cell(&cell, &fields[number_index]), "$1,200");
+}
+
+#[tokio::test]
+async fn update_cells_in_batch_test() {
+  let test = DatabaseCellTest::new().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  let number_field = test.get_first_field(FieldType::Number).await;
+  let row_ids = [test.rows[0].id.clone(), test.rows[1].id.clone()];
+
+  let changesets = row_ids
+    .iter()
+    .enumerate()
+    .flat_map(|(i, row_id)| {
+      [
+        CellChangesetPB {
+          view_id: test.view_id.clone(),
+          row_id: row_id.to_string(),
+          field_id: text_field.id.clone(),
+          cell_changeset: format!("row {}", i),
+        },
+        CellChangesetPB {
+          view_id: test.view_id.clone(),
+          row_id: row_id.to_string(),
+          field_id: number_field.id.clone(),
+          cell_changeset: format!("{}", i + 10),
+        },
+      ]
+    })
+    .collect::<Vec<_>>();
+  test
+    .editor
+    .update_cells(&test.view_id, changesets)
+    .await
+    .unwrap();
+  for (i, row_id) in row_ids.iter().enumerate() {
+    let cell = test.editor.get_cell(&text_field.id, row_id).await.unwrap();
+    assert_eq!(StringCellData::from(&cell).as_str(), format!("row {}", i));
+    let cell = test
+      .editor
+      .get_cell(&number_field.id, row_id)
+      .await
+      .unwrap();
+    assert_eq!(StringCellData::from(&cell).as_str(), format!("{}", i + 10));
+  }
+
+  // Nothing is written if one of the changesets is invalid
+  let changesets = vec![
+    CellChangesetPB {
+      view_id: test.view_id.clone(),
+      row_id: row_ids[0].to_string(),
+      field_id: text_field.id.clone(),
+      cell_changeset: "changed".to_string(),
+    },
+    CellChangesetPB {
+      view_id: test.view_id.clone(),
+      row_id: row_ids[0].to_string(),
+      field_id: "unknown field".to_string(),
+      cell_changeset: "changed".to_string(),
+    },
+  ];
+  assert!(test
+    .editor
+    .update_cells(&test.view_id, changesets)
+    .await
+    .is_err());
+  let cell = test
+    .editor
+    .get_cell(&text_field.id, &row_ids[0])
+    .await
+    .unwrap();
+  assert_eq!(StringCellData::from(&cell).as_str(), "row 0");
+
+  // Nothing is written if one of the rows is locked
+  test.editor.set_row_locked(&row_ids[1], true).await.unwrap();
+  let changesets = row_ids
+    .iter()
+    .map(|row_id| CellChangesetPB {
+      view_id: test.view_id.clone(),
+      row_id: row_id.to_string(),
+      field_id: text_field.id.clone(),
+      cell_changeset: "changed".to_string(),
+    })
+    .collect::<Vec<_>>();
+  let error = test
+    .editor
+    .update_cells(&test.view_id, changesets)
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::RowLocked);
+  let cell = test
+    .editor
+    .get_cell(&text_field.id, &row_ids[0])
+    .await
+    .unwrap();
+  assert_eq!(StringCellData::from(&cell).as_str(), "row 0");
+}
+
+struct MaxNumberValidator(i64);
+
+impl CellValidator for MaxNumberValidator {
+  fn validate_cell(&self, _row: &Row, field: &Field, new_cell: &Cell) -> FlowyResult<()> {
+    if FieldType::from(field.field_type) != FieldType::Number {
+      return Ok(());
+    }
+    let value = StringCellData::from(new_cell)
+      .as_str()
+      .parse::<i64>()
+      .unwrap_or(0);
+    if value > self.0 {
+      return Err(FlowyError::invalid_data().with_context("The number is too large"));
+    }
+    Ok(())
+  }
+}
+
+#[tokio::test]
+async fn cell_validator_rejects_cell_test() {
+  let test = DatabaseCellTest::new().await;
+  let number_field = test.get_first_field(FieldType::Number).await;
+  let row_id = test.rows[0].id.clone();
+  test
+    .sdk
+    .database_manager
+    .add_cell_validator(Arc::new(MaxNumberValidator(100)));
+
+  test
+    .update_cell(
+      &test.view_id,
+      &number_field.id,
+      &row_id,
+      BoxAny::new("99".to_string()),
+    )
+    .await;
+  let error = test
+    .editor
+    .update_cell_with_changeset(
+      &test.view_id,
+      &row_id,
+      &number_field.id,
+      BoxAny::new("101".to_string()),
+    )
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::InvalidParams);
+  let cell = test
+    .editor
+    .get_cell(&number_field.id, &row_id)
+    .await
+    .unwrap();
+  assert_eq!(StringCellData::from(&cell).as_str(), "99");
+}
+
+#[tokio::test]
+async fn select_option_usage_test() {
+  let test = DatabaseCellTest::new().await;
+  let field = test.get_first_field(FieldType::MultiSelect).await;
+  let options = test.get_multi_select_type_option(&field.id).await;
+  let cells = test
+    .editor
+    .get_cells_for_field(&test.view_id, &field.id)
+    .await;
+
+  let usage = test
+    .editor
+    .get_select_option_usage(&field.id)
+    .await
+    .unwrap();
+  assert_eq!(usage.len(), options.len());
+  for (item, option) in usage.iter().zip(options.iter()) {
+    assert_eq!(item.option.id, option.id);
+    let expected_count = cells
+      .iter()
+      .filter_map(|row_cell| row_cell.cell.as_ref())
+      .filter(|cell| SelectOptionIds::from(*cell).contains(&option.id))
+      .count();
+    assert_eq!(item.count, expected_count as i64);
+  }
+
+  // The new option is selected in the cell of the first row only
+  let option = test
+    .editor
+    .create_select_option(&field.id, "New option".to_string())
+    .await
+    .unwrap();
+  test
+    .editor
+    .insert_select_options(
+      &test.view_id,
+      &field.id,
+      test.rows[0].id.clone(),
+      vec![option.clone()],
+    )
+    .await
+    .unwrap();
+  let usage = test
+    .editor
+    .get_select_option_usage(&field.id)
+    .await
+    .unwrap();
+  let item = usage
+    .iter()
+    .find(|item| item.option.id == option.id)
+    .unwrap();
+  assert_eq!(item.count, 1);
+}
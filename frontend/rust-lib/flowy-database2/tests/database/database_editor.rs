@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use collab_database::database::gen_database_view_id;
 use collab_database::fields::checkbox_type_option::CheckboxTypeOption;
@@ -13,7 +14,7 @@ use strum::EnumCount;
 
 use event_integration_test::folder_event::ViewTest;
 use event_integration_test::EventIntegrationTest;
-use flowy_database2::entities::{DatabasePB, FieldType, FilterPB, RowMetaPB};
+use flowy_database2::entities::{DatabasePB, DatabaseSnapshotPB, FieldType, FilterPB, RowMetaPB};
 
 use flowy_database2::services::database::DatabaseEditor;
 use flowy_database2::services::field::checklist_type_option::{
@@ -298,4 +299,31 @@ impl DatabaseEditorTest {
       .await
       .ok()
   }
+
+  pub async fn create_snapshot(&self) -> String {
+    self
+      .sdk
+      .database_manager
+      .create_database_snapshot(&self.view_id)
+      .await
+      .unwrap()
+  }
+
+  /// The snapshots of the snapshot policy are saved in the background, so wait until the
+  /// database has at least `count` snapshots.
+  pub async fn wait_for_snapshots(&self, count: usize) -> Vec<DatabaseSnapshotPB> {
+    for _ in 0..50 {
+      let snapshots = self
+        .sdk
+        .database_manager
+        .get_database_snapshots(&self.view_id, 10)
+        .await
+        .unwrap();
+      if snapshots.len() >= count {
+        return snapshots;
+      }
+      tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("the database doesn't have {} snapshots", count);
+  }
 }
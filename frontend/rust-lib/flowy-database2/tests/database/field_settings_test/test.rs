@@ -3,7 +3,9 @@ use crate::database::field_test::util::*;
 use collab_database::database::gen_option_id;
 use collab_database::fields::select_type_option::SingleSelectTypeOption;
 use collab_database::fields::select_type_option::{SelectOption, SelectTypeOption};
-use flowy_database2::entities::{FieldChangesetPB, FieldType};
+use flowy_database2::entities::{
+  FieldChangesetPB, FieldSettingsChangesetPB, FieldType, FieldVisibility,
+};
 use flowy_database2::services::field::{CHECK, UNCHECK};
 
 #[tokio::test]
@@ -251,3 +253,68 @@ async fn grid_switch_from_checklist_to_text_test() {
     .assert_cell_content(field_rev.id.clone(), 0, "First thing".to_string())
     .await;
 }
+
+#[tokio::test]
+async fn grid_hide_field_in_view_test() {
+  let test = DatabaseFieldTest::new().await;
+  let field = test.get_first_field(FieldType::Number).await;
+
+  test
+    .editor
+    .update_field_settings_with_changeset(FieldSettingsChangesetPB {
+      view_id: test.view_id(),
+      field_id: field.id.clone(),
+      visibility: Some(FieldVisibility::AlwaysHidden),
+      width: None,
+      wrap_cell_content: None,
+    })
+    .await
+    .unwrap();
+
+  let field_settings = test
+    .editor
+    .get_field_settings(&test.view_id(), vec![field.id.clone()])
+    .await
+    .unwrap();
+  assert_eq!(field_settings[0].visibility, FieldVisibility::AlwaysHidden);
+}
+
+#[tokio::test]
+async fn grid_hide_primary_field_test() {
+  let test = DatabaseFieldTest::new().await;
+  let primary_field = test.get_first_field(FieldType::RichText).await;
+  assert!(primary_field.is_primary);
+
+  let result = test
+    .editor
+    .update_field_settings_with_changeset(FieldSettingsChangesetPB {
+      view_id: test.view_id(),
+      field_id: primary_field.id.clone(),
+      visibility: Some(FieldVisibility::AlwaysHidden),
+      width: None,
+      wrap_cell_content: None,
+    })
+    .await;
+  assert!(result.is_err());
+
+  // Other settings of the primary field can still be updated
+  test
+    .editor
+    .update_field_settings_with_changeset(FieldSettingsChangesetPB {
+      view_id: test.view_id(),
+      field_id: primary_field.id.clone(),
+      visibility: None,
+      width: Some(200),
+      wrap_cell_content: None,
+    })
+    .await
+    .unwrap();
+
+  let field_settings = test
+    .editor
+    .get_field_settings(&test.view_id(), vec![primary_field.id.clone()])
+    .await
+    .unwrap();
+  assert_eq!(field_settings[0].visibility, FieldVisibility::AlwaysShown);
+  assert_eq!(field_settings[0].width, 200);
+}
@@ -1,11 +1,23 @@
-use collab_database::database::gen_option_id;
-use collab_database::fields::select_type_option::{SelectOption, SelectTypeOption};
-use flowy_database2::entities::{FieldChangesetPB, FieldType};
-use flowy_database2::services::field::{CHECK, UNCHECK};
+use collab_database::database::{gen_option_id, gen_row_id};
+use collab_database::fields::date_type_option::DateCellData;
+use collab_database::fields::select_type_option::{
+  SelectOption, SelectOptionIds, SelectTypeOption,
+};
+use flowy_database2::entities::{
+  CellChangesetPB, CheckboxCellDataPB, CreateRowPayloadPB, FieldChangesetPB, FieldType,
+};
+use flowy_database2::services::cell::stringify_cell;
+use flowy_database2::services::field::{
+  CheckboxTypeOptionsBuilder, FieldAttributes, StringCellData, CHECK, UNCHECK,
+};
+use flowy_database2::services::share::csv::CSVFormat;
+use flowy_error::ErrorCode;
+use lib_infra::box_any::BoxAny;
 
 use crate::database::field_test::script::DatabaseFieldTest;
 use crate::database::field_test::util::*;
 use collab_database::fields::select_type_option::SingleSelectTypeOption;
+use collab_database::rows::{get_field_type_from_cell, CreateRowParams, RowId};
 
 #[tokio::test]
 async fn grid_create_field() {
@@ -208,6 +220,23 @@ async fn grid_switch_from_checkbox_to_text_test() {
     .await;
 }
 
+#[tokio::test]
+async fn grid_create_snapshot_before_switching_field_type_test() {
+  let mut test = DatabaseFieldTest::new().await;
+  let field = test.get_first_field(FieldType::Checkbox).await;
+
+  let snapshot_id = test.create_snapshot().await;
+  let snapshots = test.wait_for_snapshots(1).await;
+  assert_eq!(snapshots[0].snapshot_id, snapshot_id);
+  assert!(!snapshots[0].data.is_empty());
+  test
+    .switch_to_field(test.view_id(), field.id.clone(), FieldType::RichText)
+    .await;
+  test
+    .assert_cell_content(field.id.clone(), 1, "Yes".to_string())
+    .await;
+}
+
 #[tokio::test]
 async fn grid_switch_from_date_to_text_test() {
   let mut test = DatabaseFieldTest::new().await;
@@ -255,3 +284,546 @@ async fn grid_switch_from_checklist_to_text_test() {
     .assert_cell_content(field_rev.id.clone(), 0, "First thing".to_string())
     .await;
 }
+
+#[tokio::test]
+async fn grid_switch_field_type_converts_cells_test() {
+  let mut test = DatabaseFieldTest::new().await;
+  let view_id = test.view_id();
+  let single_select_field = test.get_first_field(FieldType::SingleSelect).await;
+  let number_field = test.get_first_field(FieldType::Number).await;
+
+  let rows = test.editor.get_all_rows(&view_id).await.unwrap();
+  let selected_options = rows
+    .iter()
+    .map(|row| {
+      row
+        .cells
+        .get(&single_select_field.id)
+        .map(|cell| stringify_cell(cell, &single_select_field))
+        .unwrap_or_default()
+    })
+    .collect::<Vec<_>>();
+
+  test
+    .switch_to_field(
+      view_id.clone(),
+      single_select_field.id.clone(),
+      FieldType::MultiSelect,
+    )
+    .await;
+  test
+    .switch_to_field(
+      view_id.clone(),
+      number_field.id.clone(),
+      FieldType::RichText,
+    )
+    .await;
+
+  // The cells hold the data of the new field type instead of being transformed when read.
+  let rows = test.editor.get_all_rows(&view_id).await.unwrap();
+  for (row_index, row) in rows.iter().enumerate() {
+    if let Some(cell) = row.cells.get(&single_select_field.id) {
+      assert_eq!(
+        get_field_type_from_cell::<FieldType>(cell),
+        Some(FieldType::MultiSelect)
+      );
+      test
+        .assert_cell_content(
+          single_select_field.id.clone(),
+          row_index,
+          selected_options[row_index].clone(),
+        )
+        .await;
+    }
+    if let Some(cell) = row.cells.get(&number_field.id) {
+      assert_eq!(
+        get_field_type_from_cell::<FieldType>(cell),
+        Some(FieldType::RichText)
+      );
+    }
+  }
+  test
+    .assert_cell_content(number_field.id.clone(), 0, "$1".to_string())
+    .await;
+}
+
+#[tokio::test]
+async fn grid_switch_select_field_to_text_and_back_test() {
+  let mut test = DatabaseFieldTest::new().await;
+  let view_id = test.view_id();
+  let multi_select_field = test.get_first_field(FieldType::MultiSelect).await;
+  let rows = test.editor.get_all_rows(&view_id).await.unwrap();
+  let selected_options = rows
+    .iter()
+    .enumerate()
+    .filter_map(|(row_index, row)| {
+      row
+        .cells
+        .get(&multi_select_field.id)
+        .map(|cell| (row_index, stringify_cell(cell, &multi_select_field)))
+    })
+    .collect::<Vec<_>>();
+  assert!(selected_options
+    .iter()
+    .any(|(_, options)| options.contains(',')));
+
+  for field_type in [FieldType::RichText, FieldType::MultiSelect] {
+    test
+      .switch_to_field(view_id.clone(), multi_select_field.id.clone(), field_type)
+      .await;
+  }
+
+  // The text cells still hold the names of the options, so the options are selected again
+  for (row_index, selected_options) in selected_options {
+    test
+      .assert_cell_content(multi_select_field.id.clone(), row_index, selected_options)
+      .await;
+  }
+}
+
+#[tokio::test]
+async fn grid_switch_field_type_with_locked_row_test() {
+  let mut test = DatabaseFieldTest::new().await;
+  let view_id = test.view_id();
+  let field = test.get_first_field(FieldType::MultiSelect).await;
+  let rows = test.editor.get_all_rows(&view_id).await.unwrap();
+  let (row_index, row) = rows
+    .iter()
+    .enumerate()
+    .find(|(_, row)| row.cells.contains_key(&field.id))
+    .unwrap();
+  let content = stringify_cell(&row.cells[&field.id], &field);
+
+  // The cells of the locked row can't be rewritten, so the type isn't switched
+  test.editor.set_row_locked(&row.id, true).await.unwrap();
+  let error = test
+    .editor
+    .switch_to_field_type(&view_id, &field.id, FieldType::RichText, None)
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::RowLocked);
+  let field_type = test.editor.get_field(&field.id).await.unwrap().field_type;
+  assert_eq!(FieldType::from(field_type), FieldType::MultiSelect);
+  test
+    .assert_cell_content(field.id.clone(), row_index, content.clone())
+    .await;
+
+  test.editor.set_row_locked(&row.id, false).await.unwrap();
+  test
+    .switch_to_field(view_id, field.id.clone(), FieldType::RichText)
+    .await;
+  test
+    .assert_cell_content(field.id.clone(), row_index, content)
+    .await;
+}
+
+#[tokio::test]
+async fn grid_mask_sensitive_field_test() {
+  let mut test = DatabaseFieldTest::new().await;
+  let field = test.get_first_field(FieldType::RichText).await;
+  let row_id = test.get_rows().await[0].id.clone();
+
+  test
+    .update_field(FieldChangesetPB {
+      field_id: field.id.clone(),
+      view_id: test.view_id(),
+      is_sensitive: Some(true),
+      ..Default::default()
+    })
+    .await;
+
+  let masked_cell = test
+    .editor
+    .get_cell_pb(&field.id, &row_id, false)
+    .await
+    .unwrap();
+  assert!(masked_cell.is_masked);
+  assert!(masked_cell.data.is_empty());
+
+  let revealed_cell = test
+    .editor
+    .get_cell_pb(&field.id, &row_id, true)
+    .await
+    .unwrap();
+  assert!(!revealed_cell.is_masked);
+  assert!(!revealed_cell.data.is_empty());
+
+  // The sensitive flag is kept when the field's name is updated
+  test
+    .update_field(FieldChangesetPB {
+      field_id: field.id.clone(),
+      view_id: test.view_id(),
+      name: Some("token".to_string()),
+      ..Default::default()
+    })
+    .await;
+  let field = test.editor.get_field(&field.id).await.unwrap();
+  assert!(FieldAttributes::from_field(&field).is_sensitive);
+}
+
+#[tokio::test]
+async fn grid_checkbox_default_value_and_labels_test() {
+  let test = DatabaseFieldTest::new().await;
+  let field = test.get_first_field(FieldType::Checkbox).await;
+  let type_option_data = CheckboxTypeOptionsBuilder::new()
+    .checked_by_default(true)
+    .labels("Done", "Todo")
+    .build();
+  test
+    .editor
+    .update_field_type_option(&field.id, type_option_data, field.clone())
+    .await
+    .unwrap();
+  let field = test.editor.get_field(&field.id).await.unwrap();
+
+  // The cell of a new row is checked by default
+  let row_detail = test
+    .editor
+    .create_row(CreateRowPayloadPB {
+      view_id: test.view_id(),
+      ..Default::default()
+    })
+    .await
+    .unwrap()
+    .unwrap();
+  let row_id = row_detail.row.id.clone();
+  let cell = test.editor.get_cell(&field.id, &row_id).await.unwrap();
+  assert!(CheckboxCellDataPB::from(&cell).is_checked);
+  assert_eq!(stringify_cell(&cell, &field), "Done");
+
+  // The labels are accepted as cell input as well
+  test
+    .editor
+    .update_cell_with_changeset(
+      &test.view_id(),
+      &row_id,
+      &field.id,
+      BoxAny::new("todo".to_string()),
+    )
+    .await
+    .unwrap();
+  let cell = test.editor.get_cell(&field.id, &row_id).await.unwrap();
+  assert!(!CheckboxCellDataPB::from(&cell).is_checked);
+  assert_eq!(stringify_cell(&cell, &field), "Todo");
+
+  let csv = test
+    .editor
+    .export_csv(CSVFormat::Original, false)
+    .await
+    .unwrap();
+  assert!(csv.contains("Todo"));
+}
+
+#[tokio::test]
+async fn grid_field_default_value_test() {
+  let mut test = DatabaseFieldTest::new().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  let select_field = test.get_first_field(FieldType::SingleSelect).await;
+  let date_field = test.get_first_field(FieldType::DateTime).await;
+  let option = test.get_single_select_type_option(&select_field.id).await[0].clone();
+
+  for (field_id, default_value) in [
+    (&text_field.id, "Untitled task".to_string()),
+    (&select_field.id, option.name.clone()),
+    (&date_field.id, "today".to_string()),
+  ] {
+    test
+      .update_field(FieldChangesetPB {
+        field_id: field_id.clone(),
+        view_id: test.view_id(),
+        default_value: Some(default_value),
+        ..Default::default()
+      })
+      .await;
+  }
+  let text_field = test.editor.get_field(&text_field.id).await.unwrap();
+  assert_eq!(
+    FieldAttributes::from_field(&text_field).default_value,
+    "Untitled task"
+  );
+
+  // The cells without a value in the payload are filled in with the default values
+  let row_detail = test
+    .editor
+    .create_row(CreateRowPayloadPB {
+      view_id: test.view_id(),
+      data: [(text_field.id.clone(), "Write the docs".to_string())].into(),
+      ..Default::default()
+    })
+    .await
+    .unwrap()
+    .unwrap();
+  let row_id = row_detail.row.id.clone();
+  let cell = test.editor.get_cell(&text_field.id, &row_id).await.unwrap();
+  assert_eq!(stringify_cell(&cell, &text_field), "Write the docs");
+  let cell = test
+    .editor
+    .get_cell(&select_field.id, &row_id)
+    .await
+    .unwrap();
+  assert_eq!(SelectOptionIds::from(&cell).to_vec(), vec![option.id]);
+  let cell = test.editor.get_cell(&date_field.id, &row_id).await.unwrap();
+  assert!(DateCellData::from(&cell).timestamp.is_some());
+
+  let row_detail = test
+    .editor
+    .create_row(CreateRowPayloadPB {
+      view_id: test.view_id(),
+      ..Default::default()
+    })
+    .await
+    .unwrap()
+    .unwrap();
+  let cell = test
+    .editor
+    .get_cell(&text_field.id, &row_detail.row.id)
+    .await
+    .unwrap();
+  assert_eq!(stringify_cell(&cell, &text_field), "Untitled task");
+
+  // The inserted rows get the default values too
+  let database_id = test
+    .sdk
+    .database_manager
+    .get_database_id_with_view_id(&test.view_id())
+    .await
+    .unwrap();
+  let params = CreateRowParams::new(gen_row_id(), database_id);
+  let row_id = params.id.clone();
+  test
+    .editor
+    .insert_rows(&test.view_id(), vec![params])
+    .await
+    .unwrap();
+  let cell = test.editor.get_cell(&text_field.id, &row_id).await.unwrap();
+  assert_eq!(stringify_cell(&cell, &text_field), "Untitled task");
+  let cell = test
+    .editor
+    .get_cell(&select_field.id, &row_id)
+    .await
+    .unwrap();
+  assert_eq!(SelectOptionIds::from(&cell).len(), 1);
+
+  // A default value that can't be applied to the field is rejected
+  for (field_id, default_value) in [
+    (&select_field.id, "Not an option"),
+    (&date_field.id, "tomorrow"),
+  ] {
+    let error = test
+      .editor
+      .update_field(FieldChangesetPB {
+        field_id: field_id.clone(),
+        view_id: test.view_id(),
+        default_value: Some(default_value.to_string()),
+        ..Default::default()
+      })
+      .await
+      .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidParams);
+  }
+  let date_field = test.editor.get_field(&date_field.id).await.unwrap();
+  assert_eq!(
+    FieldAttributes::from_field(&date_field).default_value,
+    "today"
+  );
+}
+
+#[tokio::test]
+async fn grid_unique_field_test() {
+  let mut test = DatabaseFieldTest::new().await;
+  let field = test.get_first_field(FieldType::RichText).await;
+  let first_row_id = test.rows[0].id.clone();
+  let second_row_id = test.rows[1].id.clone();
+  let unique_changeset = FieldChangesetPB {
+    field_id: field.id.clone(),
+    view_id: test.view_id(),
+    is_unique: Some(true),
+    ..Default::default()
+  };
+
+  // The field can't become unique while two rows have the same value
+  let text = |s: &str| BoxAny::new(s.to_string());
+  test
+    .update_cell(&field.id, first_row_id.clone(), text("ID-1"))
+    .await
+    .unwrap();
+  test
+    .update_cell(&field.id, second_row_id.clone(), text("ID-1"))
+    .await
+    .unwrap();
+  let error = test
+    .editor
+    .update_field(unique_changeset.clone())
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::DuplicateCellValue);
+  for (i, row) in test.rows.clone().iter().enumerate() {
+    test
+      .update_cell(&field.id, row.id.clone(), text(&format!("Row {}", i)))
+      .await
+      .unwrap();
+  }
+  test.update_field(unique_changeset).await;
+
+  test
+    .update_cell(&field.id, first_row_id.clone(), text("ID-1"))
+    .await
+    .unwrap();
+  // Setting the same value to the same row is not a duplicate
+  test
+    .update_cell(&field.id, first_row_id.clone(), text("ID-1"))
+    .await
+    .unwrap();
+  let error = test
+    .update_cell(&field.id, second_row_id.clone(), text("ID-1"))
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::DuplicateCellValue);
+  // Empty cells never conflict
+  test
+    .update_cell(&field.id, second_row_id.clone(), text(""))
+    .await
+    .unwrap();
+
+  let error = test
+    .editor
+    .create_row(CreateRowPayloadPB {
+      view_id: test.view_id(),
+      data: [(field.id.clone(), "ID-1".to_string())].into(),
+      ..Default::default()
+    })
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::DuplicateCellValue);
+
+  // The cells of a batch are checked against each other, and the values they replace are free
+  let changeset = |row_id: &RowId, value: &str| CellChangesetPB {
+    view_id: test.view_id(),
+    row_id: row_id.to_string(),
+    field_id: field.id.clone(),
+    cell_changeset: value.to_string(),
+  };
+  let error = test
+    .editor
+    .update_cells(
+      &test.view_id(),
+      vec![
+        changeset(&first_row_id, "ID-A"),
+        changeset(&second_row_id, "ID-A"),
+      ],
+    )
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::DuplicateCellValue);
+  test
+    .editor
+    .update_cells(
+      &test.view_id(),
+      vec![
+        changeset(&first_row_id, "ID-B"),
+        changeset(&second_row_id, "ID-1"),
+      ],
+    )
+    .await
+    .unwrap();
+
+  // The inserted rows are checked against the existing rows and each other
+  let database_id = test
+    .sdk
+    .database_manager
+    .get_database_id_with_view_id(&test.view_id())
+    .await
+    .unwrap();
+  let new_row = |value: &str| {
+    let mut params = CreateRowParams::new(gen_row_id(), database_id.clone());
+    params
+      .cells
+      .insert(field.id.clone(), StringCellData::from(value).into());
+    params
+  };
+  let error = test
+    .editor
+    .insert_rows(&test.view_id(), vec![new_row("ID-B")])
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::DuplicateCellValue);
+  let result = test
+    .editor
+    .insert_rows(&test.view_id(), vec![new_row("ID-C"), new_row("ID-C")])
+    .await;
+  assert_eq!(result.unwrap_err().code, ErrorCode::DuplicateCellValue);
+
+  // A duplicated row doesn't copy the unique value
+  let row_count = test
+    .editor
+    .get_all_rows(&test.view_id())
+    .await
+    .unwrap()
+    .len();
+  test
+    .editor
+    .duplicate_row(&test.view_id(), &first_row_id)
+    .await
+    .unwrap();
+  let rows = test.editor.get_all_rows(&test.view_id()).await.unwrap();
+  assert_eq!(rows.len(), row_count + 1);
+  let values = rows
+    .iter()
+    .filter_map(|row| row.cells.get(&field.id))
+    .map(|cell| stringify_cell(cell, &field))
+    .filter(|value| value == "ID-B")
+    .count();
+  assert_eq!(values, 1);
+}
+
+#[tokio::test]
+async fn grid_required_field_test() {
+  let test = DatabaseFieldTest::new().await;
+  let field = test.get_first_field(FieldType::URL).await;
+  test
+    .update_field(FieldChangesetPB {
+      field_id: field.id.clone(),
+      view_id: test.view_id(),
+      is_required: Some(true),
+      ..Default::default()
+    })
+    .await;
+
+  // Rows can still be created without the required cells
+  let row_id = test
+    .editor
+    .create_row(CreateRowPayloadPB {
+      view_id: test.view_id(),
+      ..Default::default()
+    })
+    .await
+    .unwrap()
+    .unwrap()
+    .row
+    .id;
+  let validation = test
+    .editor
+    .validate_row(&test.view_id(), &row_id)
+    .await
+    .unwrap();
+  assert_eq!(validation.missing_field_ids, vec![field.id.clone()]);
+  let invalid_rows = test.editor.get_invalid_rows(&test.view_id()).await.unwrap();
+  assert!(invalid_rows
+    .iter()
+    .any(|validation| validation.row_id == row_id.to_string()));
+
+  test
+    .update_cell(
+      &field.id,
+      row_id.clone(),
+      BoxAny::new("https://appflowy.io".to_string()),
+    )
+    .await
+    .unwrap();
+  let validation = test
+    .editor
+    .validate_row(&test.view_id(), &row_id)
+    .await
+    .unwrap();
+  assert!(validation.is_valid());
+}
@@ -312,3 +312,21 @@ async fn create_advanced_filter_with_conversion_test() {
 
   test.assert_number_of_visible_rows(4).await;
 }
+
+/// An OR filter whose only child is an empty AND filter doesn't hide any rows
+#[tokio::test]
+async fn or_filter_with_empty_nested_and_filter_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.rows.len();
+
+  test.create_or_filter(None, None).await;
+  test.wait(100).await;
+  let or_filter = test.get_filter(FilterType::Or, None).await.unwrap();
+
+  test
+    .create_and_filter(Some(or_filter.id.clone()), None)
+    .await;
+  test.wait(100).await;
+
+  test.assert_number_of_visible_rows(row_count).await;
+}
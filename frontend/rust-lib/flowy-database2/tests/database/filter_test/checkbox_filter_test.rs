@@ -49,3 +49,20 @@ async fn grid_filter_checkbox_is_uncheck_test() {
 
   test.assert_number_of_visible_rows(expected).await;
 }
+
+#[tokio::test]
+async fn grid_filter_checkbox_notifies_row_count_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.rows.len();
+
+  test
+    .create_data_filter_with_row_count(
+      FieldType::Checkbox,
+      BoxAny::new(CheckboxFilterPB {
+        condition: CheckboxFilterConditionPB::IsChecked,
+      }),
+      3,
+      row_count,
+    )
+    .await;
+}
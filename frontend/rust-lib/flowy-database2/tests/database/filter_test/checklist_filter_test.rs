@@ -65,6 +65,56 @@ async fn grid_filter_checklist_is_complete_test() {
   test.assert_number_of_visible_rows(expected).await;
 }
 
+#[tokio::test]
+async fn grid_filter_checklist_is_empty_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let expected = 3;
+  let row_count = test.rows.len();
+
+  // Create Checklist "Is Empty" filter
+  test
+    .create_data_filter(
+      None,
+      FieldType::Checklist,
+      BoxAny::new(ChecklistFilterPB {
+        condition: ChecklistFilterConditionPB::IsEmpty,
+      }),
+      Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    )
+    .await;
+
+  // Assert the number of visible rows
+  test.assert_number_of_visible_rows(expected).await;
+}
+
+#[tokio::test]
+async fn grid_filter_checklist_is_not_empty_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let expected = 4;
+  let row_count = test.rows.len();
+
+  // Create Checklist "Is Not Empty" filter
+  test
+    .create_data_filter(
+      None,
+      FieldType::Checklist,
+      BoxAny::new(ChecklistFilterPB {
+        condition: ChecklistFilterConditionPB::IsNotEmpty,
+      }),
+      Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    )
+    .await;
+
+  // Assert the number of visible rows
+  test.assert_number_of_visible_rows(expected).await;
+}
+
 async fn get_checklist_cell_options(test: &DatabaseFilterTest) -> Vec<String> {
   let field = test.get_first_field(FieldType::Checklist).await;
   let row_cell = test.editor.get_cell(&field.id, &test.rows[0].id).await;
@@ -18,6 +18,7 @@ async fn grid_filter_date_is_test() {
         start: None,
         end: None,
         timestamp: Some(1647251762),
+        days: None,
       }),
       Some(FilterRowChanged {
         showing_num_of_rows: 0,
@@ -46,6 +47,7 @@ async fn grid_filter_date_after_test() {
         start: None,
         end: None,
         timestamp: Some(1647251762),
+        days: None,
       }),
       Some(FilterRowChanged {
         showing_num_of_rows: 0,
@@ -74,6 +76,7 @@ async fn grid_filter_date_on_or_after_test() {
         start: None,
         end: None,
         timestamp: Some(1668359085),
+        days: None,
       }),
       Some(FilterRowChanged {
         showing_num_of_rows: 0,
@@ -102,6 +105,7 @@ async fn grid_filter_date_on_or_before_test() {
         start: None,
         end: None,
         timestamp: Some(1668359085),
+        days: None,
       }),
       Some(FilterRowChanged {
         showing_num_of_rows: 0,
@@ -130,6 +134,7 @@ async fn grid_filter_date_within_test() {
         start: Some(1647251762),
         end: Some(1668704685),
         timestamp: None,
+        days: None,
       }),
       Some(FilterRowChanged {
         showing_num_of_rows: 0,
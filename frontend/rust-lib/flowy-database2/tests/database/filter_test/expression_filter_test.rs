@@ -0,0 +1,153 @@
+use crate::database::filter_test::script::DatabaseFilterTest;
+use flowy_database2::entities::{FieldType, QueryRowsPayloadPB, SortConditionPB, SortPB};
+use flowy_database2::services::filter::{FilterChangeset, FilterInner};
+use flowy_error::ErrorCode;
+
+#[tokio::test]
+async fn parse_filter_expression_test() {
+  let test = DatabaseFilterTest::new().await;
+  let filter = test
+    .editor
+    .parse_filter_expression(&test.view_id, r#"Status = "Completed" AND Price > 100"#)
+    .await
+    .unwrap();
+
+  match filter.inner {
+    FilterInner::And { children } => {
+      let field_types = children
+        .iter()
+        .map(|child| match &child.inner {
+          FilterInner::Data { field_type, .. } => *field_type,
+          _ => panic!("Expected a data filter"),
+        })
+        .collect::<Vec<_>>();
+      assert_eq!(
+        field_types,
+        vec![FieldType::SingleSelect, FieldType::Number]
+      );
+    },
+    _ => panic!("Expected an AND filter"),
+  }
+}
+
+#[tokio::test]
+async fn parse_filter_expression_with_parentheses_test() {
+  let test = DatabaseFilterTest::new().await;
+  let filter = test
+    .editor
+    .parse_filter_expression(
+      &test.view_id,
+      r#"name contains "A" or ("is urgent" = true and Price <= 5)"#,
+    )
+    .await
+    .unwrap();
+
+  match filter.inner {
+    FilterInner::Or { children } => {
+      assert_eq!(children.len(), 2);
+      assert!(matches!(children[0].inner, FilterInner::Data { .. }));
+      assert!(matches!(&children[1].inner, FilterInner::And { children } if children.len() == 2));
+    },
+    _ => panic!("Expected an OR filter"),
+  }
+}
+
+#[tokio::test]
+async fn parse_filter_expression_error_test() {
+  let test = DatabaseFilterTest::new().await;
+  let err = test
+    .editor
+    .parse_filter_expression(&test.view_id, r#"Status = "Live""#)
+    .await
+    .unwrap_err();
+  assert_eq!((err.start, err.end), (9, 15));
+
+  let err = test
+    .editor
+    .parse_filter_expression(&test.view_id, "Stock > 100")
+    .await
+    .unwrap_err();
+  assert_eq!((err.start, err.end), (0, 5));
+
+  let err = test
+    .editor
+    .parse_filter_expression(&test.view_id, "Price > abc")
+    .await
+    .unwrap_err();
+  assert_eq!((err.start, err.end), (8, 11));
+
+  let err = test
+    .editor
+    .parse_filter_expression(&test.view_id, "Name = \"A\" AND")
+    .await
+    .unwrap_err();
+  assert_eq!((err.start, err.end), (14, 14));
+}
+
+#[tokio::test]
+async fn apply_filter_expression_test() {
+  let test = DatabaseFilterTest::new().await;
+  let filter = test
+    .editor
+    .parse_filter_expression(&test.view_id, r#""is urgent" = true"#)
+    .await
+    .unwrap();
+
+  test
+    .editor
+    .modify_view_filters(
+      &test.view_id,
+      FilterChangeset::Insert {
+        parent_filter_id: None,
+        data: filter.inner,
+      },
+    )
+    .await
+    .unwrap();
+  test.assert_number_of_visible_rows(3).await;
+}
+
+#[tokio::test]
+async fn query_rows_test() {
+  let test = DatabaseFilterTest::new().await;
+  let price_field = test.get_first_field(FieldType::Number).await;
+  let params = QueryRowsPayloadPB {
+    view_id: test.view_id.clone(),
+    filter_expression: "Price > 1".to_string(),
+    sorts: vec![SortPB {
+      field_id: price_field.id.clone(),
+      condition: SortConditionPB::Descending,
+      ..Default::default()
+    }],
+    offset: 1,
+    limit: 2,
+    field_ids: vec![price_field.id.clone()],
+  };
+  let result = test.editor.query_rows(params.clone()).await.unwrap();
+
+  // The rows with the prices 2, 3, 14 and 5 match, and the page skips the row with 14.
+  assert_eq!(result.total_count, 4);
+  let row_ids = result
+    .items
+    .iter()
+    .map(|item| item.row.id.clone())
+    .collect::<Vec<_>>();
+  assert_eq!(
+    row_ids,
+    vec![test.rows[5].id.to_string(), test.rows[2].id.to_string()]
+  );
+  assert!(result
+    .items
+    .iter()
+    .all(|item| item.cells.len() == 1 && item.cells[0].field_id == price_field.id));
+
+  let err = test
+    .editor
+    .query_rows(QueryRowsPayloadPB {
+      filter_expression: "Stock > 100".to_string(),
+      ..params
+    })
+    .await
+    .unwrap_err();
+  assert_eq!(err.code, ErrorCode::InvalidParams);
+}
@@ -2,7 +2,10 @@ mod advanced_filter_test;
 mod checkbox_filter_test;
 mod checklist_filter_test;
 mod date_filter_test;
+mod expression_filter_test;
 mod number_filter_test;
+mod relation_filter_test;
+mod row_color_test;
 mod script;
 mod select_option_filter_test;
 mod text_filter_test;
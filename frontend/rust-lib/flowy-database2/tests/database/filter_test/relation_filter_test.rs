@@ -0,0 +1,102 @@
+use crate::database::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
+use flowy_database2::entities::{FieldType, RelationFilterConditionPB, RelationFilterPB};
+use flowy_database2::services::field::{RelationCellChangeset, RelationCellData};
+use flowy_database2::services::filter::ParseFilterData;
+use lib_infra::box_any::BoxAny;
+
+async fn insert_related_rows(test: &DatabaseFilterTest) {
+  let relation_field = test.get_first_field(FieldType::Relation).await;
+  let related_row_ids = [vec!["row_a", "row_b"], vec!["row_c"]];
+  for (row, row_ids) in test.rows.iter().zip(related_row_ids) {
+    test
+      .update_cell(
+        &relation_field.id,
+        row.id.clone(),
+        BoxAny::new(RelationCellChangeset {
+          inserted_row_ids: row_ids
+            .into_iter()
+            .map(|row_id| row_id.to_string().into())
+            .collect(),
+          ..Default::default()
+        }),
+      )
+      .await
+      .unwrap();
+  }
+}
+
+#[tokio::test]
+async fn grid_filter_relation_is_not_empty_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  insert_related_rows(&test).await;
+  let expected = 2;
+  let row_count = test.rows.len();
+
+  test
+    .create_data_filter(
+      None,
+      FieldType::Relation,
+      BoxAny::new(RelationFilterPB {
+        condition: RelationFilterConditionPB::RelationIsNotEmpty,
+        row_ids: vec![],
+      }),
+      Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    )
+    .await;
+
+  test.assert_number_of_visible_rows(expected).await;
+}
+
+#[tokio::test]
+async fn grid_filter_relation_contains_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  insert_related_rows(&test).await;
+  let expected = 1;
+  let row_count = test.rows.len();
+
+  test
+    .create_data_filter(
+      None,
+      FieldType::Relation,
+      BoxAny::new(RelationFilterPB {
+        condition: RelationFilterConditionPB::RelationContains,
+        row_ids: vec!["row_b".to_string()],
+      }),
+      Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    )
+    .await;
+
+  test.assert_number_of_visible_rows(expected).await;
+}
+
+#[tokio::test]
+async fn grid_filter_relation_default_condition_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  insert_related_rows(&test).await;
+  let row_count = test.rows.len();
+
+  // A filter without a picked row doesn't hide any row
+  test
+    .create_data_filter(
+      None,
+      FieldType::Relation,
+      BoxAny::new(RelationFilterPB::default()),
+      None,
+    )
+    .await;
+  test.assert_number_of_visible_rows(row_count).await;
+
+  // The filters saved before the conditions were added are parsed the same way
+  let filter = RelationFilterPB::parse(0, "".to_string());
+  assert_eq!(filter, RelationFilterPB::default());
+  let cell_data = RelationCellData {
+    row_ids: vec!["row_a".to_string().into()],
+  };
+  assert!(filter.is_visible(&cell_data));
+}
@@ -0,0 +1,124 @@
+use crate::database::filter_test::script::DatabaseFilterTest;
+use flowy_database2::entities::{
+  CheckboxFilterConditionPB, CheckboxFilterPB, FieldType, FilterDataPB, RowColorRulePB,
+  UpdateRowColorRulesPayloadPB,
+};
+
+fn checkbox_rule(
+  field_id: &str,
+  condition: CheckboxFilterConditionPB,
+  color: &str,
+) -> RowColorRulePB {
+  RowColorRulePB {
+    id: "".to_string(),
+    color: color.to_string(),
+    condition: FilterDataPB {
+      field_id: field_id.to_string(),
+      field_type: FieldType::Checkbox,
+      data: CheckboxFilterPB { condition }.try_into().unwrap(),
+    },
+  }
+}
+
+#[tokio::test]
+async fn row_color_rules_test() {
+  let test = DatabaseFilterTest::new().await;
+  let checkbox_field = test.get_first_field(FieldType::Checkbox).await;
+  assert!(test
+    .editor
+    .get_row_colors(&test.view_id)
+    .await
+    .unwrap()
+    .items
+    .is_empty());
+
+  // The checked rows match both rules and take the color of the first one
+  test
+    .editor
+    .update_row_color_rules(UpdateRowColorRulesPayloadPB {
+      view_id: test.view_id.clone(),
+      rules: vec![
+        checkbox_rule(
+          &checkbox_field.id,
+          CheckboxFilterConditionPB::IsChecked,
+          "red",
+        ),
+        checkbox_rule(
+          &checkbox_field.id,
+          CheckboxFilterConditionPB::IsUnChecked,
+          "blue",
+        ),
+      ],
+    })
+    .await
+    .unwrap();
+
+  let rules = test
+    .editor
+    .get_row_color_rules(&test.view_id)
+    .await
+    .unwrap()
+    .items;
+  assert_eq!(rules.len(), 2);
+  assert!(!rules[0].id.is_empty());
+  assert_eq!(rules[0].color, "red");
+  assert_eq!(rules[0].condition.field_id, checkbox_field.id);
+
+  let colors = test
+    .editor
+    .get_row_colors(&test.view_id)
+    .await
+    .unwrap()
+    .items;
+  assert_eq!(colors.len(), test.rows.len());
+  assert_eq!(
+    colors.iter().filter(|color| color.color == "red").count(),
+    3
+  );
+  assert_eq!(
+    colors.iter().filter(|color| color.color == "blue").count(),
+    4
+  );
+
+  // Only the first rule is kept
+  test
+    .editor
+    .update_row_color_rules(UpdateRowColorRulesPayloadPB {
+      view_id: test.view_id.clone(),
+      rules: vec![rules[0].clone()],
+    })
+    .await
+    .unwrap();
+  let colors = test
+    .editor
+    .get_row_colors(&test.view_id)
+    .await
+    .unwrap()
+    .items;
+  assert_eq!(colors.len(), 3);
+  assert!(colors.iter().all(|color| color.color == "red"));
+}
+
+#[tokio::test]
+async fn row_color_rule_with_unknown_field_test() {
+  let test = DatabaseFilterTest::new().await;
+  let result = test
+    .editor
+    .update_row_color_rules(UpdateRowColorRulesPayloadPB {
+      view_id: test.view_id.clone(),
+      rules: vec![checkbox_rule(
+        "unknown field",
+        CheckboxFilterConditionPB::IsChecked,
+        "red",
+      )],
+    })
+    .await;
+  assert!(result.is_err());
+  assert!(test
+    .editor
+    .get_row_color_rules(&test.view_id)
+    .await
+    .unwrap()
+    .items
+    .is_empty());
+}
@@ -249,6 +249,46 @@ impl DatabaseFilterTest {
     tokio::time::sleep(Duration::from_millis(millisecond)).await;
   }
 
+  /// Creates a data filter on the first field of `field_type` and asserts the number of visible
+  /// rows and the total number of rows that are notified after the filter is applied.
+  pub async fn create_data_filter_with_row_count(
+    &mut self,
+    field_type: FieldType,
+    data: BoxAny,
+    visible_count: usize,
+    total_count: usize,
+  ) {
+    self.subscribe_view_changed().await;
+    let mut receiver = self.recv.take().unwrap();
+    self.create_data_filter(None, field_type, data, None).await;
+    match tokio::time::timeout(Duration::from_secs(2), receiver.recv()).await {
+      Ok(changed) => match changed.unwrap() {
+        DatabaseViewChanged::FilterNotification(notification) => {
+          assert_eq!(notification.visible_count, visible_count);
+          assert_eq!(notification.total_count, total_count);
+        },
+        _ => panic!("Expected a filter notification"),
+      },
+      Err(e) => {
+        panic!("Process filter task timeout: {:?}", e);
+      },
+    }
+  }
+
+  /// Updates the text cell of a row that stays visible or stays hidden and asserts that the
+  /// filter doesn't notify any change of the visibility of the rows.
+  pub async fn update_text_cell_without_change(&mut self, row_id: RowId, text: String) {
+    self.subscribe_view_changed().await;
+    let mut receiver = self.recv.take().unwrap();
+    self.update_text_cell(row_id, &text).await.unwrap();
+    while let Ok(changed) = tokio::time::timeout(Duration::from_millis(500), receiver.recv()).await
+    {
+      if let DatabaseViewChanged::FilterNotification(notification) = changed.unwrap() {
+        panic!("Unexpected filter notification: {:?}", notification);
+      }
+    }
+  }
+
   async fn subscribe_view_changed(&mut self) {
     self.recv = Some(
       self
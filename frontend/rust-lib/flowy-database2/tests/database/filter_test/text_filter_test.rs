@@ -141,6 +141,34 @@ async fn grid_filter_contain_text_test2() {
     .await;
 }
 
+#[tokio::test]
+async fn grid_filter_text_update_without_visibility_change_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_detail = test.rows.clone();
+
+  test
+    .create_data_filter(
+      None,
+      FieldType::RichText,
+      BoxAny::new(TextFilterPB {
+        condition: TextFilterConditionPB::TextContains,
+        content: "A".to_string(),
+      }),
+      Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: 2,
+      }),
+    )
+    .await;
+  test.wait(100).await;
+
+  // The row is still visible after the update, so only the rows entering or leaving the view
+  // are notified and nothing is sent.
+  test
+    .update_text_cell_without_change(row_detail[0].id.clone(), "AB".to_string())
+    .await;
+}
+
 #[tokio::test]
 async fn grid_filter_does_not_contain_text_test() {
   let mut test = DatabaseFilterTest::new().await;
@@ -312,3 +340,39 @@ async fn grid_filter_update_empty_text_cell_test() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn grid_filter_explain_query_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  test
+    .create_data_filter(
+      None,
+      FieldType::RichText,
+      BoxAny::new(TextFilterPB {
+        condition: TextFilterConditionPB::TextIsNotEmpty,
+        content: "".to_string(),
+      }),
+      Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: 1,
+      }),
+    )
+    .await;
+
+  let filter = test.get_all_filters().await.pop().unwrap();
+  let mut hidden_rows = 0;
+  for row in test.rows.clone() {
+    let explanation = test
+      .editor
+      .explain_query(&test.view_id, &row.id)
+      .await
+      .unwrap();
+    assert_eq!(explanation.filters.len(), 1);
+    assert_eq!(explanation.filters[0].filter_id, filter.id);
+    assert_eq!(explanation.filters[0].is_match, explanation.is_visible);
+    if !explanation.is_visible {
+      hidden_rows += 1;
+    }
+  }
+  assert_eq!(hidden_rows, 1);
+}
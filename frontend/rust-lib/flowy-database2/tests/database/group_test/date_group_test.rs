@@ -1,5 +1,5 @@
 use crate::database::group_test::script::DatabaseGroupTest;
-use chrono::{offset, Duration, NaiveDateTime};
+use chrono::{Duration, NaiveDateTime, Utc};
 use collab_database::fields::date_type_option::DateCellData;
 use flowy_database2::entities::{CreateRowPayloadPB, FieldType};
 use std::collections::HashMap;
@@ -11,7 +11,7 @@ async fn group_by_date_test() {
   let date_field = test.get_field(FieldType::DateTime).await;
 
   for diff in date_diffs {
-    let timestamp = offset::Local::now()
+    let timestamp = Utc::now()
       .checked_add_signed(Duration::days(diff))
       .unwrap()
       .timestamp()
@@ -30,7 +30,8 @@ async fn group_by_date_test() {
     assert!(res.is_ok());
   }
 
-  let today = offset::Local::now();
+  // The groups are computed in the timezone of the date field, which is UTC
+  let today = Utc::now();
   let last_day = today
     .checked_add_signed(Duration::days(-1))
     .unwrap()
@@ -2,7 +2,9 @@ use crate::database::database_editor::DatabaseEditorTest;
 use collab_database::fields::select_type_option::{SelectOption, SingleSelectTypeOption};
 use collab_database::fields::Field;
 use collab_database::rows::RowId;
-use flowy_database2::entities::{CreateRowPayloadPB, FieldType, GroupPB, RowMetaPB};
+use flowy_database2::entities::{
+  CreateRowPayloadPB, FieldType, GroupPB, RowMetaPB, UpdateGroupParams,
+};
 use flowy_database2::services::cell::{
   delete_select_option_cell, insert_date_cell, insert_select_option_cell, insert_url_cell,
 };
@@ -59,6 +61,29 @@ impl DatabaseGroupTest {
       .unwrap();
   }
 
+  /// Moves the row to the end of the group, which is the only way to move a row to an empty group.
+  pub async fn move_row_to_group(
+    &self,
+    from_group_index: usize,
+    from_row_index: usize,
+    to_group_index: usize,
+  ) {
+    let from_group = self.group_at_index(from_group_index).await;
+    let from_row = RowId::from(self.row_at_index(from_group_index, from_row_index).await.id);
+    let to_group = self.group_at_index(to_group_index).await;
+    self
+      .editor
+      .move_group_row(
+        &self.view_id,
+        &from_group.group_id,
+        &to_group.group_id,
+        from_row,
+        None,
+      )
+      .await
+      .unwrap();
+  }
+
   pub async fn assert_row(&self, group_index: usize, row_index: usize, row: RowMetaPB) {
     let group = self.group_at_index(group_index).await;
     let compare_row = group.rows.get(row_index).unwrap().clone();
@@ -72,6 +97,7 @@ impl DatabaseGroupTest {
       row_position: Default::default(),
       group_id: Some(group.group_id),
       data: Default::default(),
+      row_id: None,
     };
     self.editor.create_row(params).await.unwrap();
   }
@@ -79,7 +105,7 @@ impl DatabaseGroupTest {
   pub async fn delete_row(&self, group_index: usize, row_index: usize) {
     let row = self.row_at_index(group_index, row_index).await;
     let row_ids = vec![RowId::from(row.id)];
-    self.editor.delete_rows(&row_ids).await;
+    self.editor.delete_rows(&row_ids).await.unwrap();
     tokio::time::sleep(Duration::from_secs(1)).await; // Sleep to allow deletion to propagate
   }
 
@@ -184,6 +210,43 @@ impl DatabaseGroupTest {
     assert_eq!(group_id, group.group_id, "group index: {}", group_index);
   }
 
+  pub async fn collapse_group(&self, group_id: &str, collapsed: bool) {
+    self
+      .editor
+      .update_group(
+        &self.view_id,
+        vec![UpdateGroupParams {
+          view_id: self.view_id.clone(),
+          group_id: group_id.to_string(),
+          name: None,
+          visible: None,
+          collapsed: Some(collapsed),
+        }
+        .into()],
+      )
+      .await
+      .unwrap();
+  }
+
+  /// Asserts the row count of the group and the sum of its aggregate field. `None` asserts that
+  /// the group has no sum.
+  pub async fn assert_group_aggregate(&self, group_index: usize, count: i64, sum: Option<f64>) {
+    let aggregate = self.group_at_index(group_index).await.aggregate;
+    assert_eq!(aggregate.count, count, "group index: {}", group_index);
+    match sum {
+      None => assert!(aggregate.sum.is_empty()),
+      Some(sum) => assert_eq!(aggregate.sum.parse::<f64>().unwrap(), sum),
+    }
+  }
+
+  pub async fn set_group_aggregate_field(&self, field_id: Option<String>) {
+    self
+      .editor
+      .update_group_aggregate_field(&self.view_id, field_id)
+      .await
+      .unwrap();
+  }
+
   pub async fn create_group(&self, name: &str) {
     self.editor.create_group(&self.view_id, name).await.unwrap();
   }
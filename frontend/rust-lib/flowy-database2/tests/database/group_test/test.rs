@@ -1,5 +1,8 @@
 use crate::database::group_test::script::DatabaseGroupTest;
-use collab_database::fields::select_type_option::SelectOption;
+use collab_database::fields::select_type_option::{SelectOption, SelectOptionIds};
+use collab_database::rows::RowId;
+use flowy_database2::entities::FieldType;
+use lib_infra::box_any::BoxAny;
 
 #[tokio::test]
 async fn group_init_test() {
@@ -23,6 +26,27 @@ async fn group_move_row_test() {
     .await;
 }
 
+#[tokio::test]
+async fn group_move_row_to_no_status_group_test() {
+  let test = DatabaseGroupTest::new().await;
+  let row = test.row_at_index(1, 0).await;
+
+  test.move_row_to_group(1, 0, 0).await;
+  test.assert_group_row_count(0, 1).await;
+  test.assert_group_row_count(1, 1).await;
+  test.assert_row(0, 0, row.clone()).await;
+
+  // The cell is cleared instead of selecting the id of the no status group
+  let field = test.get_single_select_field().await;
+  let row = test
+    .editor
+    .get_row(&test.view_id, &RowId::from(row.id))
+    .await
+    .unwrap();
+  let cell = row.cells.get(&field.id).unwrap();
+  assert!(SelectOptionIds::from(cell).is_empty());
+}
+
 #[tokio::test]
 async fn test_row_movement_between_groups_with_assertions() {
   let test = DatabaseGroupTest::new().await;
@@ -232,3 +256,63 @@ async fn group_manual_create_new_group() {
   test.create_group(new_group_name).await;
   test.assert_group_count(5).await;
 }
+
+#[tokio::test]
+async fn group_collapse_test() {
+  let test = DatabaseGroupTest::new().await;
+  let group = test.group_at_index(1).await;
+  assert!(!group.is_collapsed);
+
+  test.collapse_group(&group.group_id, true).await;
+  let collapsed_group = test.group_at_index(1).await;
+  assert!(collapsed_group.is_collapsed);
+  assert!(collapsed_group.is_visible);
+
+  // The collapse state survives reloading the groups
+  test
+    .update_single_select_option(vec![SelectOption {
+      id: "new option".to_string(),
+      name: "new option".to_string(),
+      color: Default::default(),
+    }])
+    .await;
+  test.assert_group_count(5).await;
+  assert!(test.group_at_index(1).await.is_collapsed);
+  assert!(!test.group_at_index(2).await.is_collapsed);
+
+  test.collapse_group(&group.group_id, false).await;
+  assert!(!test.group_at_index(1).await.is_collapsed);
+}
+
+#[tokio::test]
+async fn group_aggregate_test() {
+  let test = DatabaseGroupTest::new().await;
+  let number_field = test.get_field(FieldType::Number).await;
+  test.assert_group_aggregate(1, 2, None).await;
+
+  test
+    .set_group_aggregate_field(Some(number_field.id.clone()))
+    .await;
+  test.assert_group_aggregate(1, 2, Some(3.0)).await;
+  test.assert_group_aggregate(2, 2, Some(7.0)).await;
+
+  // The aggregates follow the row when it moves to another group
+  test.move_row(1, 0, 2, 0).await;
+  test.assert_group_aggregate(1, 1, Some(2.0)).await;
+  test.assert_group_aggregate(2, 3, Some(8.0)).await;
+
+  // The sum follows the value of the aggregate field
+  let row = test.row_at_index(1, 0).await;
+  test
+    .update_cell(
+      &number_field.id,
+      RowId::from(row.id),
+      BoxAny::new("10".to_string()),
+    )
+    .await
+    .unwrap();
+  test.assert_group_aggregate(1, 1, Some(10.0)).await;
+
+  test.set_group_aggregate_field(None).await;
+  test.assert_group_aggregate(1, 1, None).await;
+}
@@ -3,6 +3,7 @@ use collab_database::views::DatabaseLayout;
 
 use flowy_database2::entities::{FieldType, LayoutSettingChangeset, LayoutSettingParams};
 use flowy_database2::services::setting::{BoardLayoutSetting, CalendarLayoutSetting};
+use flowy_error::FlowyResult;
 
 use crate::database::database_editor::DatabaseEditorTest;
 
@@ -71,10 +72,16 @@ impl DatabaseLayoutTest {
     let layout_settings = self.get_layout_setting(&view_id, layout_ty).await;
 
     assert!(layout_settings.calendar.is_none());
+    let board_setting = layout_settings.board.unwrap();
     assert_eq!(
-      layout_settings.board.unwrap().hide_ungrouped_column,
+      board_setting.hide_ungrouped_column,
       expected.hide_ungrouped_column
     );
+    assert_eq!(
+      board_setting.card_cover_field_id,
+      expected.card_cover_field_id
+    );
+    assert_eq!(board_setting.card_size, expected.card_size);
   }
 
   pub async fn assert_calendar_layout_setting(&self, expected: CalendarLayoutSetting) {
@@ -95,6 +102,16 @@ impl DatabaseLayoutTest {
   }
 
   pub async fn update_board_layout_setting(&mut self, new_setting: BoardLayoutSetting) {
+    self
+      .try_update_board_layout_setting(new_setting)
+      .await
+      .unwrap();
+  }
+
+  pub async fn try_update_board_layout_setting(
+    &mut self,
+    new_setting: BoardLayoutSetting,
+  ) -> FlowyResult<()> {
     let changeset = LayoutSettingChangeset {
       view_id: self.database_test.view_id.clone(),
       layout_type: DatabaseLayout::Board,
@@ -106,7 +123,10 @@ impl DatabaseLayoutTest {
       .editor
       .set_layout_setting(&self.database_test.view_id, changeset)
       .await
-      .unwrap();
+  }
+
+  pub async fn get_first_field(&self, field_type: FieldType) -> Field {
+    self.database_test.get_first_field(field_type).await
   }
 
   pub async fn assert_default_all_calendar_events(&self) {
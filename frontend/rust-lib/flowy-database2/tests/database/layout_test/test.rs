@@ -1,6 +1,12 @@
+use crate::database::database_editor::DatabaseEditorTest;
 use crate::database::layout_test::script::DatabaseLayoutTest;
+use collab_database::database::gen_database_view_id;
+use collab_database::rows::Row;
 use collab_database::views::DatabaseLayout;
-use flowy_database2::services::setting::{BoardLayoutSetting, CalendarLayoutSetting};
+use flowy_database2::entities::{CheckboxFilterConditionPB, DatabaseLayoutPB, FieldType};
+use flowy_database2::services::filter::{FilterChangeset, FilterInner};
+use flowy_database2::services::setting::{BoardLayoutSetting, CalendarLayoutSetting, CardSize};
+use std::sync::Arc;
 
 #[tokio::test]
 async fn board_layout_setting_test() {
@@ -23,6 +29,38 @@ async fn board_layout_setting_test() {
   test.assert_board_layout_setting(new_board_setting).await;
 }
 
+#[tokio::test]
+async fn board_card_layout_setting_test() {
+  let mut test = DatabaseLayoutTest::new_board().await;
+  let default_board_setting = BoardLayoutSetting::new();
+  assert_eq!(default_board_setting.card_size, CardSize::Medium);
+  assert!(default_board_setting.card_cover_field_id.is_empty());
+
+  let cover_field = test.get_first_field(FieldType::URL).await;
+  let new_board_setting = BoardLayoutSetting {
+    card_cover_field_id: cover_field.id.clone(),
+    card_size: CardSize::Large,
+    ..default_board_setting.clone()
+  };
+  test
+    .update_board_layout_setting(new_board_setting.clone())
+    .await;
+  test
+    .assert_board_layout_setting(new_board_setting.clone())
+    .await;
+
+  // A card cover that refers to a field that doesn't exist is rejected
+  let invalid_board_setting = BoardLayoutSetting {
+    card_cover_field_id: "not exist field id".to_string(),
+    ..default_board_setting
+  };
+  assert!(test
+    .try_update_board_layout_setting(invalid_board_setting)
+    .await
+    .is_err());
+  test.assert_board_layout_setting(new_board_setting).await;
+}
+
 #[tokio::test]
 async fn calendar_initial_layout_setting_test() {
   let test = DatabaseLayoutTest::new_calendar().await;
@@ -43,6 +81,50 @@ async fn calendar_get_events_test() {
   test.assert_default_all_calendar_events().await;
 }
 
+#[tokio::test]
+async fn calendar_get_events_in_range_test() {
+  let test = DatabaseEditorTest::new_calendar().await;
+
+  let events = test
+    .editor
+    .get_calendar_events_in_range(&test.view_id, Some(1677917978), Some(1678695578))
+    .await;
+  let titles = events
+    .into_iter()
+    .map(|event| event.title)
+    .collect::<Vec<_>>();
+  assert_eq!(titles, vec!["A", "B"]);
+}
+
+#[tokio::test]
+async fn calendar_get_no_date_events_test() {
+  let test = DatabaseEditorTest::new_calendar().await;
+  let no_date_events = test
+    .editor
+    .get_all_no_date_calendar_events(&test.view_id)
+    .await
+    .unwrap();
+  assert!(no_date_events.is_empty());
+
+  // Clear the date of the first row
+  let date_field = test.get_first_field(FieldType::DateTime).await;
+  let row = test.rows.first().unwrap();
+  test
+    .editor
+    .clear_cell(&test.view_id, row.id.clone(), &date_field.id)
+    .await
+    .unwrap();
+
+  let no_date_events = test
+    .editor
+    .get_all_no_date_calendar_events(&test.view_id)
+    .await
+    .unwrap();
+  assert_eq!(no_date_events.len(), 1);
+  assert_eq!(no_date_events[0].row_id, row.id.to_string());
+  assert_eq!(no_date_events[0].title, "A");
+}
+
 #[tokio::test]
 async fn grid_to_calendar_layout_test() {
   let mut test = DatabaseLayoutTest::new_no_date_grid().await;
@@ -51,3 +133,121 @@ async fn grid_to_calendar_layout_test() {
   test.update_database_layout(DatabaseLayout::Calendar).await;
   test.assert_all_calendar_events_count(3).await;
 }
+
+#[tokio::test]
+async fn get_database_views_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let database_id = database_manager
+    .get_database_id_with_view_id(&test.view_id)
+    .await
+    .unwrap();
+  let board_view_id = gen_database_view_id();
+  database_manager
+    .create_linked_view(
+      "Board".to_string(),
+      DatabaseLayout::Board,
+      database_id,
+      board_view_id.clone(),
+      test.view_id.clone(),
+    )
+    .await
+    .unwrap();
+
+  let views = test.editor.get_database_views().await;
+  assert_eq!(views.len(), 2);
+  assert_eq!(views[0].view_id, test.view_id);
+  assert!(views[0].is_inline);
+  assert_eq!(views[1].view_id, board_view_id);
+  assert_eq!(views[1].name, "Board");
+  assert_eq!(views[1].layout, DatabaseLayoutPB::Board);
+  assert!(!views[1].is_inline);
+}
+
+#[tokio::test]
+async fn duplicate_database_view_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let checkbox_field = test.get_first_field(FieldType::Checkbox).await;
+  test
+    .editor
+    .modify_view_filters(
+      &test.view_id,
+      FilterChangeset::Insert {
+        parent_filter_id: None,
+        data: FilterInner::new_data(
+          checkbox_field.id.clone(),
+          FieldType::Checkbox,
+          CheckboxFilterConditionPB::IsChecked as i64,
+          "".to_string(),
+        ),
+      },
+    )
+    .await
+    .unwrap();
+  let rows = test.editor.get_all_rows(&test.view_id).await.unwrap();
+  test
+    .editor
+    .move_row(&test.view_id, rows[1].id.clone(), rows[0].id.clone())
+    .await
+    .unwrap();
+
+  let view = test.editor.duplicate_view(&test.view_id).await.unwrap();
+  assert_ne!(view.view_id, test.view_id);
+  assert!(!view.is_inline);
+  assert_eq!(view.layout, DatabaseLayoutPB::Grid);
+  assert_eq!(test.editor.get_database_views().await.len(), 2);
+  assert_eq!(
+    test.editor.get_all_filters(&view.view_id).await.items.len(),
+    1
+  );
+
+  // The duplicated view keeps the filtered rows in the moved order
+  let row_ids = |rows: Vec<Arc<Row>>| rows.iter().map(|row| row.id.clone()).collect::<Vec<_>>();
+  let rows = row_ids(test.editor.get_all_rows(&test.view_id).await.unwrap());
+  let duplicated_rows = row_ids(test.editor.get_all_rows(&view.view_id).await.unwrap());
+  assert_eq!(duplicated_rows.len(), 3);
+  assert_eq!(duplicated_rows, rows);
+}
+
+#[tokio::test]
+async fn default_view_and_view_order_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let database_id = database_manager
+    .get_database_id_with_view_id(&test.view_id)
+    .await
+    .unwrap();
+  let board_view_id = gen_database_view_id();
+  database_manager
+    .create_linked_view(
+      "Board".to_string(),
+      DatabaseLayout::Board,
+      database_id,
+      board_view_id.clone(),
+      test.view_id.clone(),
+    )
+    .await
+    .unwrap();
+
+  // The inline view opens by default until another view is set as the default
+  let views = test.editor.get_database_views().await;
+  assert!(views[0].is_default);
+  assert!(!views[1].is_default);
+
+  test.editor.set_default_view(&board_view_id).await.unwrap();
+  let views = test.editor.get_database_views().await;
+  assert_eq!(views[1].view_id, board_view_id);
+  assert!(views[1].is_default);
+  assert!(!views[0].is_default);
+
+  // Move the board view in front of the inline view
+  test.editor.move_view(&board_view_id, 0).await.unwrap();
+  let views = test.editor.get_database_views().await;
+  assert_eq!(views[0].view_id, board_view_id);
+  assert!(views[0].is_default);
+  assert_eq!(views[1].view_id, test.view_id);
+  assert!(views[1].is_inline);
+
+  assert!(test.editor.set_default_view("unknown view").await.is_err());
+  assert!(test.editor.move_view("unknown view", 0).await.is_err());
+}
@@ -0,0 +1,190 @@
+use flowy_database2::entities::{FieldChangesetPB, FieldType};
+use flowy_database2::services::cell::stringify_cell;
+use flowy_database2::services::field::MASKED_CELL_VALUE;
+use reqwest::{Client, Method, StatusCode};
+use serde_json::{json, Value};
+
+use crate::database::database_editor::DatabaseEditorTest;
+
+struct LocalServerClient {
+  client: Client,
+  base_url: String,
+  token: String,
+}
+
+impl LocalServerClient {
+  async fn send(&self, method: Method, path: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let mut request = self
+      .client
+      .request(method, format!("{}{}", self.base_url, path))
+      .bearer_auth(&self.token);
+    if let Some(body) = body {
+      request = request.body(body.to_string());
+    }
+    let response = request.send().await.unwrap();
+    let status = response.status();
+    let body = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    (status, body)
+  }
+}
+
+#[tokio::test]
+async fn local_server_row_crud_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let server = database_manager.start_local_server(0).await.unwrap();
+  let client = LocalServerClient {
+    client: Client::new(),
+    base_url: format!("http://127.0.0.1:{}/views/{}", server.port, test.view_id),
+    token: server.token.clone(),
+  };
+  let text_field = test.get_first_field(FieldType::RichText).await;
+
+  // The requests without the token are rejected
+  let response = client
+    .client
+    .get(format!("{}/fields", client.base_url))
+    .send()
+    .await
+    .unwrap();
+  assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+  let (status, row) = client
+    .send(
+      Method::POST,
+      "/rows",
+      Some(json!({ "cells": { "Name": "Buy milk", "Price": "12" } })),
+    )
+    .await;
+  assert_eq!(status, StatusCode::OK);
+  let row_id = row["id"].as_str().unwrap().to_string();
+  assert_eq!(row["cells"][&text_field.id], "Buy milk");
+
+  let (status, rows) = client
+    .send(Method::GET, "/rows?filter=Price%20%3E%2010", None)
+    .await;
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(rows["total_count"], 2);
+
+  let (status, _) = client
+    .send(
+      Method::PATCH,
+      &format!("/rows/{}", row_id),
+      Some(json!({ "cells": { text_field.id.clone(): "Sell milk" } })),
+    )
+    .await;
+  assert_eq!(status, StatusCode::OK);
+  let row = test
+    .editor
+    .get_row(&test.view_id, &row_id.clone().into())
+    .await
+    .unwrap();
+  let cell = row.cells.get(&text_field.id).unwrap();
+  assert_eq!(stringify_cell(cell, &text_field), "Sell milk");
+
+  let (status, _) = client
+    .send(Method::DELETE, &format!("/rows/{}", row_id), None)
+    .await;
+  assert_eq!(status, StatusCode::OK);
+  let (status, _) = client
+    .send(Method::GET, &format!("/rows/{}", row_id), None)
+    .await;
+  assert_eq!(status, StatusCode::NOT_FOUND);
+
+  database_manager.stop_local_server().await;
+  assert!(!database_manager.get_local_server().await.is_running);
+}
+
+#[tokio::test]
+async fn local_server_update_row_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let server = database_manager.start_local_server(0).await.unwrap();
+  let client = LocalServerClient {
+    client: Client::new(),
+    base_url: format!("http://127.0.0.1:{}/views/{}", server.port, test.view_id),
+    token: server.token.clone(),
+  };
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  let number_field = test.get_first_field(FieldType::Number).await;
+  let row_id = test.rows[0].id.clone();
+  let cell_text = |row: &collab_database::rows::Row, field: &collab_database::fields::Field| {
+    row
+      .cells
+      .get(&field.id)
+      .map(|cell| stringify_cell(cell, field))
+      .unwrap_or_default()
+  };
+  let old_row = test.editor.get_row(&test.view_id, &row_id).await.unwrap();
+
+  // The cells are written together, an invalid cell leaves the row untouched
+  let (status, _) = client
+    .send(
+      Method::PATCH,
+      &format!("/rows/{}", row_id),
+      Some(json!({
+        "cells": {
+          text_field.id.clone(): "Sell milk",
+          number_field.id.clone(): "twelve"
+        }
+      })),
+    )
+    .await;
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  let row = test.editor.get_row(&test.view_id, &row_id).await.unwrap();
+  assert_eq!(
+    cell_text(&row, &text_field),
+    cell_text(&old_row, &text_field)
+  );
+
+  // Sending back a row that was read keeps the cells of the sensitive fields
+  test
+    .editor
+    .update_field(FieldChangesetPB {
+      field_id: number_field.id.clone(),
+      view_id: test.view_id.clone(),
+      is_sensitive: Some(true),
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+  let (status, row_json) = client
+    .send(Method::GET, &format!("/rows/{}", row_id), None)
+    .await;
+  assert_eq!(status, StatusCode::OK);
+  let masked_value = row_json["cells"][&number_field.id].clone();
+  assert_eq!(masked_value, MASKED_CELL_VALUE);
+  let (status, _) = client
+    .send(
+      Method::PATCH,
+      &format!("/rows/{}", row_id),
+      Some(json!({
+        "cells": {
+          text_field.id.clone(): "Sell milk",
+          number_field.id.clone(): masked_value
+        }
+      })),
+    )
+    .await;
+  assert_eq!(status, StatusCode::OK);
+  let row = test.editor.get_row(&test.view_id, &row_id).await.unwrap();
+  assert_eq!(cell_text(&row, &text_field), "Sell milk");
+  assert_eq!(
+    cell_text(&row, &number_field),
+    cell_text(&old_row, &number_field)
+  );
+
+  // A body larger than the limit is rejected
+  let (status, _) = client
+    .send(
+      Method::PATCH,
+      &format!("/rows/{}", row_id),
+      Some(json!({
+        "cells": { text_field.id.clone(): "a".repeat(2 * 1024 * 1024) }
+      })),
+    )
+    .await;
+  assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+
+  database_manager.stop_local_server().await;
+}
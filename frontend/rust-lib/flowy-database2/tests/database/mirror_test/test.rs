@@ -0,0 +1,89 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use flowy_database2::entities::FieldType;
+use flowy_database2::services::cell::{insert_text_cell, stringify_cell};
+use flowy_database2::services::mirror::{MirrorConnector, MirrorTable};
+use flowy_error::FlowyResult;
+
+use crate::database::database_editor::DatabaseEditorTest;
+
+struct MockConnector {
+  table: MirrorTable,
+}
+
+#[async_trait]
+impl MirrorConnector for MockConnector {
+  async fn pull(&self) -> FlowyResult<MirrorTable> {
+    Ok(self.table.clone())
+  }
+}
+
+#[tokio::test]
+async fn mirror_table_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let fields = test.get_fields().await;
+  let primary_field = fields.iter().find(|field| field.is_primary).unwrap();
+  let number_field = fields
+    .iter()
+    .find(|field| FieldType::from(field.field_type) == FieldType::Number)
+    .unwrap();
+  let first_row_id = test.get_rows().await[0].id.clone();
+
+  // The row "A" exists already, the row "Z" is new and the other rows are removed
+  let connector = MockConnector {
+    table: MirrorTable {
+      columns: vec![primary_field.name.clone(), number_field.name.clone()],
+      key_column: 0,
+      rows: vec![
+        vec!["A".to_string(), "5".to_string()],
+        vec!["Z".to_string(), "7".to_string()],
+      ],
+    },
+  };
+  let database_manager = test.sdk.database_manager.clone();
+  database_manager
+    .start_mirror(
+      &test.view_id,
+      Arc::new(connector),
+      Duration::from_secs(3600),
+    )
+    .await
+    .unwrap();
+  assert!(test.editor.is_read_only());
+
+  let mut rows = test.get_rows().await;
+  for _ in 0..50 {
+    if rows.len() == 2 {
+      break;
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    rows = test.get_rows().await;
+  }
+  assert_eq!(rows.len(), 2);
+  let row_a = rows.iter().find(|row| row.id == first_row_id).unwrap();
+  let number = stringify_cell(row_a.cells.get(&number_field.id).unwrap(), number_field);
+  assert!(number.contains('5'));
+
+  // The mirrored rows can't be edited
+  let cell = insert_text_cell("B".to_string(), primary_field);
+  assert!(test
+    .editor
+    .update_cell(
+      &test.view_id,
+      &first_row_id,
+      &primary_field.id,
+      cell.clone()
+    )
+    .await
+    .is_err());
+
+  database_manager.stop_mirror(&test.view_id).await.unwrap();
+  assert!(!test.editor.is_read_only());
+  test
+    .editor
+    .update_cell(&test.view_id, &first_row_id, &primary_field.id, cell)
+    .await
+    .unwrap();
+}
@@ -1,3 +1,4 @@
+mod automation_test;
 mod block_test;
 mod calculations_test;
 mod cell_test;
@@ -7,7 +8,10 @@ mod field_test;
 mod filter_test;
 mod group_test;
 mod layout_test;
+mod local_server_test;
+mod mirror_test;
 mod mock_data;
 mod pre_fill_cell_test;
 mod share_test;
 mod sort_test;
+mod webhook_test;
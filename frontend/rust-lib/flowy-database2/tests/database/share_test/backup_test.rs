@@ -0,0 +1,99 @@
+use std::io::{Cursor, Read, Write};
+
+use collab_database::database::gen_database_view_id;
+use flowy_database2::services::share::backup::{DatabaseBackupMeta, DATABASE_BACKUP_VERSION};
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use crate::database::database_editor::DatabaseEditorTest;
+
+#[tokio::test]
+async fn backup_database_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  test.create_snapshot().await;
+  let snapshots = test.wait_for_snapshots(1).await;
+  let data = database_manager
+    .backup_database(&test.view_id)
+    .await
+    .unwrap();
+
+  let mut archive = ZipArchive::new(Cursor::new(data)).unwrap();
+  let mut meta = String::new();
+  archive
+    .by_name("meta.json")
+    .unwrap()
+    .read_to_string(&mut meta)
+    .unwrap();
+  let meta: DatabaseBackupMeta = serde_json::from_str(&meta).unwrap();
+  assert_eq!(meta.version, DATABASE_BACKUP_VERSION);
+  let database_id = database_manager
+    .get_database_id_with_view_id(&test.view_id)
+    .await
+    .unwrap();
+  assert_eq!(meta.database_id, database_id);
+  assert_eq!(meta.num_of_rows, test.get_rows().await.len());
+  assert!(archive.by_name("database.json").is_ok());
+
+  let names = archive.file_names().collect::<Vec<_>>();
+  let count = |prefix: &str| names.iter().filter(|name| name.starts_with(prefix)).count();
+  assert_eq!(count("collabs/database/"), 1);
+  assert_eq!(count("collabs/rows/"), meta.num_of_rows);
+  assert_eq!(count("snapshots/"), 1);
+  assert_eq!(meta.snapshot_ids, vec![snapshots[0].snapshot_id.clone()]);
+  assert!(archive
+    .by_name(&format!("snapshots/{}.collab", snapshots[0].snapshot_id))
+    .is_ok());
+}
+
+#[tokio::test]
+async fn restore_database_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let data = database_manager
+    .backup_database(&test.view_id)
+    .await
+    .unwrap();
+
+  let result = database_manager
+    .restore_database(gen_database_view_id(), data.clone())
+    .await
+    .unwrap();
+  let database_id = database_manager
+    .get_database_id_with_view_id(&test.view_id)
+    .await
+    .unwrap();
+  assert_ne!(result.database_id, database_id);
+  let database = test.get_database(&result.database_id).await.unwrap();
+  assert_eq!(
+    database.get_fields(&result.view_id, None).await.len(),
+    test.get_fields().await.len()
+  );
+  assert_eq!(
+    database.get_all_rows(&result.view_id).await.unwrap().len(),
+    test.get_rows().await.len()
+  );
+
+  // A backup of a newer version is rejected
+  let mut archive = ZipArchive::new(Cursor::new(data)).unwrap();
+  let mut writer = ZipWriter::new(Cursor::new(vec![]));
+  for index in 0..archive.len() {
+    let mut file = archive.by_index(index).unwrap();
+    let mut content = vec![];
+    file.read_to_end(&mut content).unwrap();
+    if file.name() == "meta.json" {
+      let mut meta: DatabaseBackupMeta = serde_json::from_slice(&content).unwrap();
+      meta.version = DATABASE_BACKUP_VERSION + 1;
+      content = serde_json::to_vec(&meta).unwrap();
+    }
+    writer
+      .start_file(file.name(), SimpleFileOptions::default())
+      .unwrap();
+    writer.write_all(&content).unwrap();
+  }
+  let data = writer.finish().unwrap().into_inner();
+  assert!(database_manager
+    .restore_database(gen_database_view_id(), data)
+    .await
+    .is_err());
+}
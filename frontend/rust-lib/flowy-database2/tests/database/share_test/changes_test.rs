@@ -0,0 +1,88 @@
+use flowy_database2::entities::{CreateRowPayloadPB, FieldChangesetPB, FieldType};
+use flowy_database2::services::cell::insert_text_cell;
+
+use crate::database::database_editor::DatabaseEditorTest;
+
+#[tokio::test]
+async fn export_changes_since_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  // The first call asks for a full export and returns the revision to poll from
+  let changes = database_manager
+    .export_changes_since(&test.view_id, 0)
+    .await
+    .unwrap();
+  assert!(changes.is_full_export_required);
+  let rev_id = changes.rev_id;
+
+  let primary_field = test.get_first_field(FieldType::RichText).await;
+  let new_row = test
+    .editor
+    .create_row(CreateRowPayloadPB {
+      view_id: test.view_id.clone(),
+      ..Default::default()
+    })
+    .await
+    .unwrap()
+    .unwrap()
+    .row;
+  let cell = insert_text_cell("Hello".to_string(), &primary_field);
+  test
+    .editor
+    .update_cell(&test.view_id, &new_row.id, &primary_field.id, cell)
+    .await
+    .unwrap();
+  let updated_row_id = test.rows[0].id.clone();
+  let cell = insert_text_cell("Updated".to_string(), &primary_field);
+  test
+    .editor
+    .update_cell(&test.view_id, &updated_row_id, &primary_field.id, cell)
+    .await
+    .unwrap();
+  let deleted_row_id = test.rows[1].id.clone();
+  test
+    .editor
+    .delete_rows(&[deleted_row_id.clone()])
+    .await
+    .unwrap();
+  test
+    .editor
+    .update_field(FieldChangesetPB {
+      field_id: primary_field.id.clone(),
+      view_id: test.view_id.clone(),
+      name: Some("Title".to_string()),
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+
+  let changes = database_manager
+    .export_changes_since(&test.view_id, rev_id)
+    .await
+    .unwrap();
+  assert!(!changes.is_full_export_required);
+  assert!(changes.rev_id > rev_id);
+  // The new row is listed once, as created, with its latest cells
+  assert_eq!(changes.created_rows.len(), 1);
+  assert_eq!(changes.created_rows[0].row.id, new_row.id.to_string());
+  let cell = changes.created_rows[0]
+    .cells
+    .iter()
+    .find(|cell| cell.field_id == primary_field.id)
+    .unwrap();
+  assert_eq!(cell.display.display, "Hello");
+  assert_eq!(changes.updated_rows.len(), 1);
+  assert_eq!(changes.updated_rows[0].row.id, updated_row_id.to_string());
+  assert_eq!(changes.deleted_row_ids, vec![deleted_row_id.to_string()]);
+  assert_eq!(changes.updated_fields.len(), 1);
+  assert_eq!(changes.updated_fields[0].name, "Title");
+
+  // Nothing changed since the last poll
+  let changes = database_manager
+    .export_changes_since(&test.view_id, changes.rev_id)
+    .await
+    .unwrap();
+  assert!(!changes.is_full_export_required);
+  assert!(changes.created_rows.is_empty() && changes.updated_rows.is_empty());
+  assert!(changes.deleted_row_ids.is_empty() && changes.updated_fields.is_empty());
+}
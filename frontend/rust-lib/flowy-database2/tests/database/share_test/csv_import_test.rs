@@ -0,0 +1,94 @@
+use collab_database::database::gen_database_view_id;
+use flowy_database2::entities::FieldType;
+use flowy_database2::services::cell::stringify_cell;
+use flowy_database2::services::share::csv::{CSVImportOptions, CSVSource};
+
+use crate::database::database_editor::DatabaseEditorTest;
+use crate::database::mock_data::COMPLETED;
+
+#[tokio::test]
+async fn import_csv_with_inferred_field_types_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let s = r#"Name,Price,Done,Status
+a,1,Yes,Todo
+b,2.5,No,Done
+c,3,Yes,Todo"#;
+  let result = test
+    .sdk
+    .database_manager
+    .import_csv_with_options(
+      gen_database_view_id(),
+      CSVSource::Bytes(s.as_bytes().to_vec()),
+      CSVImportOptions::default(),
+    )
+    .await
+    .unwrap();
+  let database = test.get_database(&result.database_id).await.unwrap();
+  let field_types = database
+    .get_fields(&result.view_id, None)
+    .await
+    .iter()
+    .map(|field| FieldType::from(field.field_type))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    field_types,
+    vec![
+      FieldType::RichText,
+      FieldType::Number,
+      FieldType::Checkbox,
+      FieldType::SingleSelect,
+    ]
+  );
+  assert_eq!(
+    database.get_all_rows(&result.view_id).await.unwrap().len(),
+    3
+  );
+}
+
+#[tokio::test]
+async fn append_csv_to_grid_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let row_count = test.get_rows().await.len();
+  let s = format!(
+    r#"name,Status,Unknown
+appended 1,{},x
+appended 2,Backlog,y"#,
+    COMPLETED
+  );
+  let count = test
+    .editor
+    .append_csv(&test.view_id, CSVSource::Bytes(s.into_bytes()))
+    .await
+    .unwrap();
+  assert_eq!(count, 2);
+
+  let rows = test.get_rows().await;
+  assert_eq!(rows.len(), row_count + 2);
+
+  // The columns are matched by name ignoring the case, and the unknown values of a select
+  // column are added as options
+  let primary_field = test.get_first_field(FieldType::RichText).await;
+  let status_field = test.get_first_field(FieldType::SingleSelect).await;
+  let last_rows = &rows[row_count..];
+  assert_eq!(
+    stringify_cell(
+      last_rows[0].cells.get(&primary_field.id).unwrap(),
+      &primary_field
+    ),
+    "appended 1"
+  );
+  assert_eq!(
+    stringify_cell(
+      last_rows[0].cells.get(&status_field.id).unwrap(),
+      &status_field
+    ),
+    COMPLETED
+  );
+  assert_eq!(
+    stringify_cell(
+      last_rows[1].cells.get(&status_field.id).unwrap(),
+      &status_field
+    ),
+    "Backlog"
+  );
+}
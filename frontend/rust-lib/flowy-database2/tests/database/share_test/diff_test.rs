@@ -0,0 +1,70 @@
+use flowy_database2::entities::FieldType;
+
+use crate::database::database_editor::DatabaseEditorTest;
+use crate::database::field_test::util::create_text_field;
+
+#[tokio::test]
+async fn diff_database_with_snapshot_test() {
+  let mut test = DatabaseEditorTest::new_grid().await;
+  let manager = test.sdk.database_manager.clone();
+  let before_snapshot_id = test.create_snapshot().await;
+
+  // Compare the snapshot with the unchanged database
+  let diff = manager
+    .diff_database(&test.view_id, &before_snapshot_id, None)
+    .await
+    .unwrap();
+  assert!(diff.is_empty());
+
+  let rows = test.get_rows().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  test
+    .update_text_cell(rows[0].id.clone(), "hello world")
+    .await
+    .unwrap();
+  test
+    .editor
+    .delete_rows(&[rows[1].id.clone()])
+    .await
+    .unwrap();
+  let (params, _) = create_text_field(&test.view_id);
+  let new_field = test
+    .editor
+    .create_field_with_type_option(params)
+    .await
+    .unwrap();
+
+  // Compare the snapshot with the current state, then with a snapshot of the current state
+  let after_snapshot_id = test.create_snapshot().await;
+  for after_snapshot_id in [None, Some(after_snapshot_id.as_str())] {
+    let diff = manager
+      .diff_database(&test.view_id, &before_snapshot_id, after_snapshot_id)
+      .await
+      .unwrap();
+    assert_eq!(diff.fields_added.len(), 1);
+    assert_eq!(diff.fields_added[0].id, new_field.id);
+    assert!(diff.fields_removed.is_empty());
+    assert_eq!(diff.rows_removed, vec![rows[1].id.clone()]);
+    assert!(diff.rows_added.is_empty());
+
+    assert_eq!(diff.rows_changed.len(), 1);
+    assert_eq!(diff.rows_changed[0].row_id, rows[0].id);
+    let cell = diff.rows_changed[0]
+      .cells
+      .iter()
+      .find(|cell| cell.field_id == text_field.id)
+      .unwrap();
+    assert_eq!(cell.after, "hello world");
+    assert_ne!(cell.before, cell.after);
+  }
+}
+
+#[tokio::test]
+async fn diff_database_with_unknown_snapshot_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let manager = test.sdk.database_manager.clone();
+  let result = manager
+    .diff_database(&test.view_id, "unknown snapshot", None)
+    .await;
+  assert!(result.is_err());
+}
@@ -1,7 +1,10 @@
-use flowy_database2::entities::FieldType;
+use std::io::Cursor;
+
+use calamine::{open_workbook_from_rs, Data, Reader, Xlsx};
+use flowy_database2::entities::{FieldSettingsChangesetPB, FieldType, FieldVisibility};
 use flowy_database2::services::cell::stringify_cell;
 use flowy_database2::services::field::CHECK;
-use flowy_database2::services::share::csv::CSVFormat;
+use flowy_database2::services::share::csv::{CSVExportOptions, CSVFormat};
 
 use crate::database::database_editor::DatabaseEditorTest;
 
@@ -9,7 +12,7 @@ use crate::database::database_editor::DatabaseEditorTest;
 async fn export_meta_csv_test() {
   let test = DatabaseEditorTest::new_grid().await;
   let database = test.editor.clone();
-  let s = database.export_csv(CSVFormat::META).await.unwrap();
+  let s = database.export_csv(CSVFormat::META, true).await.unwrap();
   let mut reader = csv::Reader::from_reader(s.as_bytes());
   for header in reader.headers().unwrap() {
     dbg!(header);
@@ -27,7 +30,7 @@ async fn export_and_then_import_meta_csv_test() {
   let test = DatabaseEditorTest::new_grid().await;
   let database = test.editor.clone();
   let format = CSVFormat::META;
-  let csv_1 = database.export_csv(format).await.unwrap();
+  let csv_1 = database.export_csv(format, true).await.unwrap();
 
   let result = test.import(csv_1.clone(), format).await;
   let database = test.get_database(&result.database_id).await.unwrap();
@@ -182,3 +185,138 @@ async fn history_database_import_test() {
     }
   }
 }
+
+#[tokio::test]
+async fn export_view_csv_with_options_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let number_field = test.get_first_field(FieldType::Number).await;
+  let checkbox_field = test.get_first_field(FieldType::Checkbox).await;
+  test
+    .editor
+    .update_field_settings_with_changeset(FieldSettingsChangesetPB {
+      view_id: test.view_id.clone(),
+      field_id: checkbox_field.id.clone(),
+      visibility: Some(FieldVisibility::AlwaysHidden),
+      width: None,
+      wrap_cell_content: None,
+    })
+    .await
+    .unwrap();
+
+  let options = CSVExportOptions {
+    delimiter: b';',
+    include_hidden_fields: false,
+  };
+  let s = test
+    .editor
+    .export_view_csv(&test.view_id, options.clone(), true)
+    .await
+    .unwrap();
+  let mut reader = csv::ReaderBuilder::new()
+    .delimiter(b';')
+    .from_reader(s.as_bytes());
+  let headers = reader.headers().unwrap().clone();
+  assert!(!headers.iter().any(|header| header == checkbox_field.name));
+  let number_index = headers
+    .iter()
+    .position(|header| header == number_field.name)
+    .unwrap();
+  let records = reader
+    .records()
+    .map(|record| record.unwrap())
+    .collect::<Vec<_>>();
+  assert_eq!(records.len(), test.get_rows().await.len());
+  // The number is formatted with the currency of the field
+  assert_eq!(&records[0][number_index], "$1");
+
+  let s = test
+    .editor
+    .export_view_csv(
+      &test.view_id,
+      CSVExportOptions {
+        include_hidden_fields: true,
+        ..options
+      },
+      true,
+    )
+    .await
+    .unwrap();
+  let mut reader = csv::ReaderBuilder::new()
+    .delimiter(b';')
+    .from_reader(s.as_bytes());
+  assert!(reader
+    .headers()
+    .unwrap()
+    .iter()
+    .any(|header| header == checkbox_field.name));
+}
+
+#[tokio::test]
+async fn export_xlsx_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let fields = test.get_fields().await;
+  let data = test.editor.export_xlsx(true).await.unwrap();
+
+  let mut workbook: Xlsx<_> = open_workbook_from_rs(Cursor::new(data)).unwrap();
+  let sheet_names = workbook.sheet_names();
+  assert_eq!(
+    sheet_names.len(),
+    test.editor.get_database_views().await.len()
+  );
+  let range = workbook.worksheet_range(&sheet_names[0]).unwrap();
+  let mut rows = range.rows();
+  let headers = rows.next().unwrap();
+  assert_eq!(headers.len(), fields.len());
+  let records = rows.collect::<Vec<_>>();
+  assert_eq!(records.len(), test.get_rows().await.len());
+
+  let column = |field_type: FieldType| {
+    fields
+      .iter()
+      .position(|field| FieldType::from(field.field_type) == field_type)
+      .unwrap()
+  };
+  // The cells are typed instead of formatted, e.g. the number has no currency symbol
+  assert_eq!(records[0][column(FieldType::Number)], Data::Float(1.0));
+  assert_eq!(records[0][column(FieldType::Checkbox)], Data::Bool(true));
+  assert!(matches!(
+    records[0][column(FieldType::DateTime)],
+    Data::DateTime(_)
+  ));
+}
+
+#[tokio::test]
+async fn export_ical_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let fields = test.get_fields().await;
+  let date_field = fields
+    .iter()
+    .find(|field| FieldType::from(field.field_type) == FieldType::DateTime)
+    .unwrap();
+  let rows = test.get_rows().await;
+  let num_of_dated_rows = rows
+    .iter()
+    .filter(|row| row.cells.contains_key(&date_field.id))
+    .count();
+
+  let ical = test
+    .editor
+    .export_ical(&test.view_id, &date_field.id, "", true)
+    .await
+    .unwrap();
+  assert!(ical.starts_with("BEGIN:VCALENDAR\r\n"));
+  assert!(ical.ends_with("END:VCALENDAR\r\n"));
+  assert_eq!(ical.matches("BEGIN:VEVENT").count(), num_of_dated_rows);
+  // The events are titled with the primary field and last the whole day of the date
+  assert!(ical.contains("SUMMARY:A\r\n"));
+  assert!(ical.contains("DTSTART;VALUE=DATE:"));
+  assert!(ical.contains(&format!("UID:{}@appflowy\r\n", rows[0].id)));
+
+  // The rows can't be exported by a field that isn't a date field
+  let primary_field = fields.iter().find(|field| field.is_primary).unwrap();
+  assert!(test
+    .editor
+    .export_ical(&test.view_id, &primary_field.id, "", true)
+    .await
+    .is_err());
+}
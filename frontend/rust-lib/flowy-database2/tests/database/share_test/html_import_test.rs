@@ -0,0 +1,53 @@
+use collab_database::database::gen_database_view_id;
+use flowy_database2::entities::FieldType;
+use flowy_database2::services::cell::stringify_cell;
+
+use crate::database::database_editor::DatabaseEditorTest;
+
+#[tokio::test]
+async fn import_html_table_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let html = r#"<table>
+    <thead><tr><th>Name</th><th>Price</th><th>Website</th></tr></thead>
+    <tbody>
+      <tr><td>Apple</td><td>$1,200</td><td><a href="https://apple.com">apple.com</a></td></tr>
+      <tr><td>Banana</td><td>3.5</td><td><a href="https://banana.com">banana.com</a></td></tr>
+      <tr><td></td><td></td><td></td></tr>
+    </tbody>
+  </table>"#;
+  let result = test
+    .sdk
+    .database_manager
+    .import_html_table(gen_database_view_id(), html.to_string())
+    .await
+    .unwrap();
+
+  let database = test.get_database(&result.database_id).await.unwrap();
+  let fields = database.get_fields(&result.view_id, None).await;
+  let field_types = fields
+    .iter()
+    .map(|field| FieldType::from(field.field_type))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    field_types,
+    vec![FieldType::RichText, FieldType::Number, FieldType::URL]
+  );
+
+  // The empty row is skipped
+  let rows = database.get_all_rows(&result.view_id).await.unwrap();
+  assert_eq!(rows.len(), 2);
+  let cells = fields
+    .iter()
+    .map(|field| stringify_cell(rows[0].cells.get(&field.id).unwrap(), field))
+    .collect::<Vec<_>>();
+  assert_eq!(cells[0], "Apple");
+  assert_eq!(cells[2], "https://apple.com");
+
+  let err = test
+    .sdk
+    .database_manager
+    .import_html_table(gen_database_view_id(), "<p>Hello</p>".to_string())
+    .await
+    .unwrap_err();
+  assert!(err.msg.contains("no table"));
+}
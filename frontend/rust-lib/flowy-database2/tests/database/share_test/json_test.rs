@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+
+use collab_database::database::gen_database_view_id;
+use collab_database::rows::Cells;
+use flowy_database2::entities::{
+  FieldChangesetPB, FieldSettingsChangesetPB, FieldType, FieldVisibility, SortConditionPB,
+  TextFilterConditionPB, UpdateSortPayloadPB,
+};
+use flowy_database2::services::cell::stringify_cell;
+use flowy_database2::services::field::MASKED_CELL_VALUE;
+use flowy_database2::services::filter::{FilterChangeset, FilterInner};
+use flowy_database2::services::share::json::GridJson;
+
+use crate::database::database_editor::DatabaseEditorTest;
+
+#[tokio::test]
+async fn export_and_import_grid_json_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let json = database_manager
+    .export_grid_json(&test.view_id, false)
+    .await
+    .unwrap();
+  let grid = GridJson::from_json(&json).unwrap();
+  assert_eq!(grid.fields.len(), test.get_fields().await.len());
+  assert_eq!(grid.rows.len(), test.get_rows().await.len());
+
+  let result = database_manager
+    .import_grid_json(gen_database_view_id(), json.clone())
+    .await
+    .unwrap();
+  assert_ne!(result.view_id, test.view_id);
+
+  // Exporting the imported grid gives back the same bytes
+  let reexported_json = database_manager
+    .export_grid_json(&result.view_id, false)
+    .await
+    .unwrap();
+  assert_eq!(reexported_json, json);
+}
+
+#[tokio::test]
+async fn export_template_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let json = database_manager
+    .export_template(&test.view_id, false)
+    .await
+    .unwrap();
+  let template = GridJson::from_json(&json).unwrap();
+  assert_eq!(template.fields.len(), test.get_fields().await.len());
+  assert!(template.rows.is_empty());
+
+  // Every grid created from the template has the same fields with new ids
+  let mut field_ids = HashSet::new();
+  for _ in 0..2 {
+    let result = database_manager
+      .create_grid_from_template(gen_database_view_id(), json.clone().into_bytes())
+      .await
+      .unwrap();
+    let database = test.get_database(&result.database_id).await.unwrap();
+    let fields = database.get_fields(&result.view_id, None).await;
+    assert_eq!(fields.len(), template.fields.len());
+    for field in fields {
+      assert!(field_ids.insert(field.id));
+    }
+    assert!(database
+      .get_all_rows(&result.view_id)
+      .await
+      .unwrap()
+      .is_empty());
+  }
+  for field in test.get_fields().await {
+    assert!(!field_ids.contains(&field.id));
+  }
+
+  assert!(database_manager
+    .create_grid_from_template(gen_database_view_id(), b"{}".to_vec())
+    .await
+    .is_err());
+
+  let json = database_manager
+    .export_template(&test.view_id, true)
+    .await
+    .unwrap();
+  let template = GridJson::from_json(&json).unwrap();
+  assert_eq!(template.rows.len(), test.get_rows().await.len());
+}
+
+#[tokio::test]
+async fn export_view_json_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  let number_field = test.get_first_field(FieldType::Number).await;
+  let checkbox_field = test.get_first_field(FieldType::Checkbox).await;
+  test
+    .editor
+    .modify_view_filters(
+      &test.view_id,
+      FilterChangeset::Insert {
+        parent_filter_id: None,
+        data: FilterInner::new_data(
+          text_field.id.clone(),
+          FieldType::RichText,
+          TextFilterConditionPB::TextContains as i64,
+          "A".to_string(),
+        ),
+      },
+    )
+    .await
+    .unwrap();
+  test
+    .editor
+    .create_or_update_sort(UpdateSortPayloadPB {
+      view_id: test.view_id.clone(),
+      field_id: number_field.id.clone(),
+      sort_id: None,
+      condition: SortConditionPB::Descending,
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+  test
+    .editor
+    .update_field_settings_with_changeset(FieldSettingsChangesetPB {
+      view_id: test.view_id.clone(),
+      field_id: checkbox_field.id.clone(),
+      visibility: Some(FieldVisibility::AlwaysHidden),
+      width: None,
+      wrap_cell_content: None,
+    })
+    .await
+    .unwrap();
+
+  let json = test
+    .sdk
+    .database_manager
+    .export_view_json(&test.view_id, false, false)
+    .await
+    .unwrap();
+  let grid = GridJson::from_json(&json).unwrap();
+  assert_eq!(grid.fields.len(), test.get_fields().await.len() - 1);
+  assert!(!grid
+    .fields
+    .iter()
+    .any(|field| field["id"] == checkbox_field.id.as_str()));
+
+  // The rows are the ones the view shows, in the same order
+  let names = grid
+    .rows
+    .iter()
+    .map(|row| {
+      let cells = serde_json::from_value::<Cells>(row.cells.clone()).unwrap();
+      assert!(!cells.contains_key(&checkbox_field.id));
+      stringify_cell(cells.get(&text_field.id).unwrap(), &text_field)
+    })
+    .collect::<Vec<_>>();
+  let view_names = test
+    .editor
+    .get_all_rows(&test.view_id)
+    .await
+    .unwrap()
+    .iter()
+    .map(|row| stringify_cell(row.cells.get(&text_field.id).unwrap(), &text_field))
+    .collect::<Vec<_>>();
+  assert_eq!(names.len(), 4);
+  assert_eq!(names, view_names);
+
+  let json = test
+    .sdk
+    .database_manager
+    .export_view_json(&test.view_id, true, false)
+    .await
+    .unwrap();
+  let grid = GridJson::from_json(&json).unwrap();
+  assert_eq!(grid.fields.len(), test.get_fields().await.len());
+}
+
+#[tokio::test]
+async fn export_json_masks_sensitive_fields_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  test
+    .editor
+    .update_field(FieldChangesetPB {
+      field_id: text_field.id.clone(),
+      view_id: test.view_id.clone(),
+      is_sensitive: Some(true),
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+
+  let cell_values = |json: String| {
+    GridJson::from_json(&json)
+      .unwrap()
+      .rows
+      .into_iter()
+      .filter_map(|row| {
+        let cells = serde_json::from_value::<Cells>(row.cells).unwrap();
+        cells
+          .get(&text_field.id)
+          .map(|cell| stringify_cell(cell, &text_field))
+      })
+      .collect::<Vec<_>>()
+  };
+
+  let exports = vec![
+    database_manager
+      .export_grid_json(&test.view_id, false)
+      .await
+      .unwrap(),
+    database_manager
+      .export_view_json(&test.view_id, true, false)
+      .await
+      .unwrap(),
+    database_manager
+      .export_template(&test.view_id, true)
+      .await
+      .unwrap(),
+  ];
+  for json in exports {
+    let values = cell_values(json);
+    assert!(!values.is_empty());
+    assert!(values.iter().all(|value| value == MASKED_CELL_VALUE));
+  }
+
+  // The values are only exported when the caller opts in
+  let values = cell_values(
+    database_manager
+      .export_view_json(&test.view_id, true, true)
+      .await
+      .unwrap(),
+  );
+  assert!(values.iter().any(|value| value != MASKED_CELL_VALUE));
+}
@@ -0,0 +1,45 @@
+use collab_database::database::gen_database_view_id;
+use flowy_database2::entities::FieldType;
+use flowy_database2::services::cell::stringify_cell;
+use flowy_database2::services::share::markdown::parse_markdown_table;
+
+use crate::database::database_editor::DatabaseEditorTest;
+
+#[tokio::test]
+async fn export_view_markdown_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let markdown = test
+    .sdk
+    .database_manager
+    .export_view_markdown(&test.view_id)
+    .await
+    .unwrap();
+
+  let table = parse_markdown_table(&markdown).unwrap();
+  let rows = test.get_rows().await;
+  assert_eq!(table.rows.len(), rows.len() + 1);
+  let primary_field = test.get_first_field(FieldType::RichText).await;
+  assert_eq!(table.rows[0][0].text, primary_field.name);
+  for (row, cells) in rows.iter().zip(table.rows.iter().skip(1)) {
+    let text = row
+      .cells
+      .get(&primary_field.id)
+      .map(|cell| stringify_cell(cell, &primary_field))
+      .unwrap_or_default();
+    assert_eq!(
+      cells[0].text,
+      text.split_whitespace().collect::<Vec<_>>().join(" ")
+    );
+  }
+
+  // The table can be imported back as a grid with the same rows
+  let result = test
+    .sdk
+    .database_manager
+    .import_markdown_table(gen_database_view_id(), markdown)
+    .await
+    .unwrap();
+  let database = test.get_database(&result.database_id).await.unwrap();
+  let imported_rows = database.get_all_rows(&result.view_id).await.unwrap();
+  assert!(imported_rows.len() <= rows.len());
+}
@@ -1 +1,11 @@
+mod backup_test;
+mod changes_test;
+mod csv_import_test;
+mod diff_test;
 mod export_test;
+mod html_import_test;
+mod json_test;
+mod markdown_test;
+mod notion_import_test;
+mod snapshot_test;
+mod view_settings_test;
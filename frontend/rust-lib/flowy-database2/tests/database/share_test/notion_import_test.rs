@@ -0,0 +1,86 @@
+use std::io::{Cursor, Write};
+
+use collab_database::database::gen_database_view_id;
+use collab_entity::CollabType;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+use flowy_database2::entities::FieldType;
+
+use crate::database::database_editor::DatabaseEditorTest;
+
+const NOTION_ID: &str = "0123456789abcdef0123456789abcdef";
+
+fn notion_zip(files: &[(String, &str)]) -> Vec<u8> {
+  let mut writer = ZipWriter::new(Cursor::new(vec![]));
+  for (name, content) in files {
+    writer
+      .start_file(name.as_str(), SimpleFileOptions::default())
+      .unwrap();
+    writer.write_all(content.as_bytes()).unwrap();
+  }
+  writer.finish().unwrap().into_inner()
+}
+
+#[tokio::test]
+async fn import_notion_database_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let data = notion_zip(&[
+    (
+      format!("Tasks {}.csv", NOTION_ID),
+      "Name,Tags,Done\nWrite docs,\"docs, web\",Yes\nShip,web,No",
+    ),
+    (
+      format!("Tasks {}/Write docs {}.md", NOTION_ID, NOTION_ID),
+      "# Write docs\n\nTags: docs, web\nDone: Yes\n\nThe outline of the docs",
+    ),
+    (
+      format!("Tasks {}/Ship {}.md", NOTION_ID, NOTION_ID),
+      "# Ship\n\nTags: web\nDone: No\n",
+    ),
+  ]);
+  let result = test
+    .sdk
+    .database_manager
+    .import_notion(gen_database_view_id(), data)
+    .await
+    .unwrap();
+
+  let database = test.get_database(&result.database_id).await.unwrap();
+  let field_types = database
+    .get_fields(&result.view_id, None)
+    .await
+    .iter()
+    .map(|field| FieldType::from(field.field_type))
+    .collect::<Vec<_>>();
+  assert_eq!(
+    field_types,
+    vec![
+      FieldType::RichText,
+      FieldType::MultiSelect,
+      FieldType::Checkbox
+    ]
+  );
+
+  // Only the page with content besides the properties becomes a row document
+  let documents = result
+    .encoded_collabs
+    .iter()
+    .filter(|collab| collab.collab_type == CollabType::Document)
+    .collect::<Vec<_>>();
+  assert_eq!(documents.len(), 1);
+
+  let rows = database.get_all_rows(&result.view_id).await.unwrap();
+  assert_eq!(rows.len(), 2);
+  let row_meta = database
+    .get_row_meta(&result.view_id, &rows[0].id)
+    .await
+    .unwrap();
+  assert_eq!(row_meta.document_id.as_ref(), Some(&documents[0].object_id));
+  assert_eq!(row_meta.is_document_empty, Some(false));
+  let row_meta = database
+    .get_row_meta(&result.view_id, &rows[1].id)
+    .await
+    .unwrap();
+  assert_eq!(row_meta.is_document_empty, Some(true));
+}
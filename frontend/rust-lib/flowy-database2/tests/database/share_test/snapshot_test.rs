@@ -0,0 +1,337 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use collab_integrate::snapshot::SnapshotPolicy;
+use collab_integrate::CollabKVAction;
+use collab_plugins::local_storage::kv::KVTransactionDB;
+use event_integration_test::folder_event::ViewTest;
+use event_integration_test::EventIntegrationTest;
+use flowy_database2::entities::{
+  CreateRowPayloadPB, DatabaseCorruptionPB, FieldType, MoveFieldParams, MoveSelectOptionParams,
+  SelectOptionColorPB, SelectOptionPB, UpdateRowMetaParams, UpdateSelectOptionParams,
+};
+use flowy_database2::notification::DatabaseNotification::DidDetectDatabaseCorruption;
+use flowy_database2::services::field::SingleSelectTypeOption;
+use flowy_error::ErrorCode;
+
+use crate::database::database_editor::DatabaseEditorTest;
+use crate::database::field_test::util::create_text_field;
+use crate::database::mock_data::make_test_grid;
+
+#[tokio::test]
+async fn database_snapshots_are_disabled_by_default_test() {
+  let mut test = DatabaseEditorTest::new_grid().await;
+  let manager = test.sdk.database_manager.clone();
+  assert_eq!(
+    manager.get_database_snapshot_policy(),
+    SnapshotPolicy::disabled()
+  );
+
+  let (params, _) = create_text_field(&test.view_id);
+  test
+    .editor
+    .create_field_with_type_option(params)
+    .await
+    .unwrap();
+  let rows = test.get_rows().await;
+  test
+    .update_text_cell(rows[0].id.clone(), "hello world")
+    .await
+    .unwrap();
+  test.editor.close_database().await;
+
+  tokio::time::sleep(Duration::from_millis(500)).await;
+  let snapshots = manager
+    .get_database_snapshots(&test.view_id, 10)
+    .await
+    .unwrap();
+  assert!(snapshots.is_empty());
+}
+
+#[tokio::test]
+async fn database_snapshot_every_n_updates_test() {
+  let sdk = EventIntegrationTest::new().await;
+  let _ = sdk.init_anon_user().await;
+  sdk
+    .database_manager
+    .set_database_snapshot_policy(SnapshotPolicy::disabled().every_n_updates(1))
+    .unwrap();
+
+  let params = make_test_grid();
+  let view_test = ViewTest::new_grid_view(&sdk, params.to_json_bytes().unwrap()).await;
+  let test = DatabaseEditorTest::new(sdk, view_test).await;
+  let (params, _) = create_text_field(&test.view_id);
+  test
+    .editor
+    .create_field_with_type_option(params)
+    .await
+    .unwrap();
+
+  let snapshots = test.wait_for_snapshots(1).await;
+  assert!(!snapshots[0].data.is_empty());
+}
+
+#[tokio::test]
+async fn open_corrupted_database_in_safe_mode_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let manager = test.sdk.database_manager.clone();
+  let database_id = manager
+    .get_database_id_with_view_id(&test.view_id)
+    .await
+    .unwrap();
+  test.create_snapshot().await;
+  let snapshots = test.wait_for_snapshots(1).await;
+  corrupt_local_data(&test, &database_id).await;
+
+  let mut rx = test
+    .sdk
+    .notification_sender
+    .subscribe::<DatabaseCorruptionPB>(&database_id, DidDetectDatabaseCorruption);
+  let editor = manager
+    .get_database_editor_with_view_id(&test.view_id)
+    .await
+    .unwrap();
+  assert!(editor.is_read_only());
+  let rows = editor.get_all_rows(&test.view_id).await.unwrap();
+  assert_eq!(rows.len(), test.rows.len());
+  let corruption = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+    .await
+    .unwrap()
+    .unwrap();
+  assert_eq!(
+    corruption.snapshot_id,
+    Some(snapshots[0].snapshot_id.clone())
+  );
+
+  // Repairing the database replaces the corrupted data with the snapshot
+  manager.repair_database(&test.view_id).await.unwrap();
+  let editor = manager
+    .get_database_editor_with_view_id(&test.view_id)
+    .await
+    .unwrap();
+  assert!(!editor.is_read_only());
+}
+
+#[tokio::test]
+async fn open_corrupted_database_after_restart_test() {
+  let mut sdk = EventIntegrationTest::new().await;
+  sdk.skip_clean();
+  let _ = sdk.init_anon_user().await;
+  let policy = SnapshotPolicy::disabled().every_n_updates(1);
+  sdk
+    .database_manager
+    .set_database_snapshot_policy(policy.clone())
+    .unwrap();
+
+  let params = make_test_grid();
+  let view_test = ViewTest::new_grid_view(&sdk, params.to_json_bytes().unwrap()).await;
+  let test = DatabaseEditorTest::new(sdk, view_test).await;
+  let database_id = test
+    .sdk
+    .database_manager
+    .get_database_id_with_view_id(&test.view_id)
+    .await
+    .unwrap();
+  let (params, _) = create_text_field(&test.view_id);
+  test
+    .editor
+    .create_field_with_type_option(params)
+    .await
+    .unwrap();
+  test.wait_for_snapshots(1).await;
+  let view_id = test.view_id.clone();
+  let row_count = test.rows.len();
+  let path = test.sdk.user_data_path();
+  let name = test.sdk.instance_name();
+  test.sdk.close_db();
+  drop(test);
+
+  // The policy is restored when the app restarts, and the snapshots created before the restart
+  // are used to open the database once its local data is corrupted
+  let sdk = EventIntegrationTest::new_with_user_data_path(PathBuf::from(path), name).await;
+  let manager = sdk.database_manager.clone();
+  assert_eq!(manager.get_database_snapshot_policy(), policy);
+  let uid = sdk.get_user_profile().await.unwrap().id;
+  let collab_db = sdk
+    .user_manager
+    .get_collab_db(uid)
+    .unwrap()
+    .upgrade()
+    .unwrap();
+  let write_txn = collab_db.write_txn();
+  write_txn
+    .flush_doc(uid, &database_id, vec![1, 2, 3], vec![4, 5, 6])
+    .unwrap();
+  write_txn.commit_transaction().unwrap();
+
+  let editor = manager
+    .get_database_editor_with_view_id(&view_id)
+    .await
+    .unwrap();
+  assert!(editor.is_read_only());
+  let rows = editor.get_all_rows(&view_id).await.unwrap();
+  assert_eq!(rows.len(), row_count);
+}
+
+#[tokio::test]
+async fn edit_database_in_safe_mode_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let manager = test.sdk.database_manager.clone();
+  let database_id = manager
+    .get_database_id_with_view_id(&test.view_id)
+    .await
+    .unwrap();
+  let fields = test.get_fields().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  let select_field = test.get_first_field(FieldType::SingleSelect).await;
+  let options = test.get_single_select_type_option(&select_field.id).await;
+  let row_ids = test
+    .rows
+    .iter()
+    .map(|row| row.id.clone())
+    .collect::<Vec<_>>();
+  test.create_snapshot().await;
+  test.wait_for_snapshots(1).await;
+  corrupt_local_data(&test, &database_id).await;
+
+  let editor = manager
+    .get_database_editor_with_view_id(&test.view_id)
+    .await
+    .unwrap();
+  assert!(editor.is_read_only());
+
+  let view_id = test.view_id.as_str();
+  let option = SelectOptionPB::from(options[0].clone());
+  let results = vec![
+    editor
+      .move_row(view_id, row_ids[0].clone(), row_ids[1].clone())
+      .await,
+    editor
+      .move_row_to_index(view_id, row_ids[0].clone(), 1)
+      .await,
+    editor
+      .move_field(MoveFieldParams {
+        view_id: view_id.to_string(),
+        from_field_id: fields[0].id.clone(),
+        to_field_id: fields[1].id.clone(),
+      })
+      .await,
+    editor.duplicate_field(view_id, &text_field.id).await,
+    editor.clear_field(view_id, &text_field.id).await,
+    editor
+      .clear_cell(view_id, row_ids[0].clone(), &text_field.id)
+      .await,
+    editor
+      .create_row_at_index(
+        CreateRowPayloadPB {
+          view_id: view_id.to_string(),
+          ..Default::default()
+        },
+        0,
+      )
+      .await
+      .map(|_| ()),
+    editor
+      .update_row_meta(
+        &row_ids[0],
+        UpdateRowMetaParams {
+          id: row_ids[0].to_string(),
+          view_id: view_id.to_string(),
+          icon_url: Some("🚀".to_string()),
+          ..Default::default()
+        },
+      )
+      .await,
+    editor
+      .add_comment(&row_ids[0], "nathan".to_string(), "hello".to_string())
+      .await
+      .map(|_| ()),
+    editor
+      .insert_select_options(
+        view_id,
+        &select_field.id,
+        row_ids[0].clone(),
+        vec![SelectOptionPB {
+          id: "new_option".to_string(),
+          name: "New option".to_string(),
+          color: SelectOptionColorPB::Blue,
+        }],
+      )
+      .await,
+    editor
+      .delete_select_options(view_id, &select_field.id, vec![option])
+      .await,
+    editor
+      .update_select_option(UpdateSelectOptionParams {
+        view_id: view_id.to_string(),
+        field_id: select_field.id.clone(),
+        option_id: options[0].id.clone(),
+        name: Some("Renamed".to_string()),
+        color: None,
+      })
+      .await,
+    editor
+      .move_select_option(MoveSelectOptionParams {
+        view_id: view_id.to_string(),
+        field_id: select_field.id.clone(),
+        option_id: options[0].id.clone(),
+        to_index: 1,
+      })
+      .await,
+    editor.delete_all_sorts(view_id).await,
+    editor.set_default_view(view_id).await,
+    editor.duplicate_view(view_id).await.map(|_| ()),
+    editor.compact().await,
+  ];
+  for (index, result) in results.into_iter().enumerate() {
+    let error = result.expect_err(&format!("edit {} should be rejected", index));
+    assert_eq!(error.code, ErrorCode::DatabaseReadOnly);
+  }
+
+  // Nothing was written to the database
+  let rows = editor.get_all_rows(view_id).await.unwrap();
+  assert_eq!(
+    rows.iter().map(|row| row.id.clone()).collect::<Vec<_>>(),
+    row_ids
+  );
+  let select_field = editor.get_field(&select_field.id).await.unwrap();
+  let type_option = select_field
+    .get_type_option::<SingleSelectTypeOption>(FieldType::SingleSelect)
+    .unwrap()
+    .0;
+  assert_eq!(
+    type_option
+      .options
+      .iter()
+      .map(|option| (option.id.clone(), option.name.clone()))
+      .collect::<Vec<_>>(),
+    options
+      .iter()
+      .map(|option| (option.id.clone(), option.name.clone()))
+      .collect::<Vec<_>>()
+  );
+}
+
+/// Reloads the workspace database, so the database is loaded from the disk when it's opened
+/// again, then replaces its local data with data that can't be decoded.
+async fn corrupt_local_data(test: &DatabaseEditorTest, database_id: &str) {
+  let uid = test.sdk.get_user_profile().await.unwrap().id;
+  test
+    .sdk
+    .database_manager
+    .initialize(uid, true)
+    .await
+    .unwrap();
+  let collab_db = test
+    .sdk
+    .user_manager
+    .get_collab_db(uid)
+    .unwrap()
+    .upgrade()
+    .unwrap();
+  let write_txn = collab_db.write_txn();
+  write_txn
+    .flush_doc(uid, database_id, vec![1, 2, 3], vec![4, 5, 6])
+    .unwrap();
+  write_txn.commit_transaction().unwrap();
+}
@@ -0,0 +1,130 @@
+use flowy_database2::entities::{
+  FieldSettingsChangesetPB, FieldType, FieldVisibility, SelectOptionFilterConditionPB,
+  SortConditionPB, TextFilterConditionPB, UpdateSortPayloadPB,
+};
+use flowy_database2::services::filter::{FilterChangeset, FilterInner};
+use flowy_database2::services::setting::{FilterTemplate, ViewSettingsTemplate};
+use flowy_database2::services::share::csv::CSVFormat;
+use flowy_error::ErrorCode;
+
+use crate::database::database_editor::DatabaseEditorTest;
+
+#[tokio::test]
+async fn export_and_then_import_view_settings_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  let number_field = test.get_first_field(FieldType::Number).await;
+  let multi_select_field = test.get_first_field(FieldType::MultiSelect).await;
+  let options = test
+    .get_multi_select_type_option(&multi_select_field.id)
+    .await;
+
+  test
+    .editor
+    .modify_view_filters(
+      &test.view_id,
+      FilterChangeset::Insert {
+        parent_filter_id: None,
+        data: FilterInner::new_data(
+          text_field.id.clone(),
+          FieldType::RichText,
+          TextFilterConditionPB::TextContains as i64,
+          "A".to_string(),
+        ),
+      },
+    )
+    .await
+    .unwrap();
+  test
+    .editor
+    .modify_view_filters(
+      &test.view_id,
+      FilterChangeset::Insert {
+        parent_filter_id: None,
+        data: FilterInner::new_data(
+          multi_select_field.id.clone(),
+          FieldType::MultiSelect,
+          SelectOptionFilterConditionPB::OptionContains as i64,
+          options[0].id.clone(),
+        ),
+      },
+    )
+    .await
+    .unwrap();
+  test
+    .editor
+    .create_or_update_sort(UpdateSortPayloadPB {
+      view_id: test.view_id.clone(),
+      field_id: number_field.id.clone(),
+      sort_id: None,
+      condition: SortConditionPB::Descending,
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+  test
+    .editor
+    .update_field_settings_with_changeset(FieldSettingsChangesetPB {
+      view_id: test.view_id.clone(),
+      field_id: number_field.id.clone(),
+      visibility: Some(FieldVisibility::AlwaysHidden),
+      width: None,
+      wrap_cell_content: None,
+    })
+    .await
+    .unwrap();
+
+  let json = test
+    .editor
+    .export_view_settings(&test.view_id)
+    .await
+    .unwrap();
+  let template = ViewSettingsTemplate::from_json(&json).unwrap();
+  assert_eq!(template.filters.len(), 2);
+  assert_eq!(template.sorts.len(), 1);
+  assert!(matches!(
+    &template.filters[1],
+    FilterTemplate::Data { options: names, .. } if names == &vec![options[0].name.clone()]
+  ));
+
+  // Apply the settings to a grid of another database with the same fields
+  let csv = test.editor.export_csv(CSVFormat::META, true).await.unwrap();
+  let result = test.import(csv, CSVFormat::META).await;
+  let database = test.get_database(&result.database_id).await.unwrap();
+  database
+    .import_view_settings(&result.view_id, &json)
+    .await
+    .unwrap();
+
+  assert_eq!(
+    database.get_all_filters(&result.view_id).await.items.len(),
+    2
+  );
+  assert_eq!(database.get_all_sorts(&result.view_id).await.items.len(), 1);
+  let imported_json = database
+    .export_view_settings(&result.view_id)
+    .await
+    .unwrap();
+  assert_eq!(
+    ViewSettingsTemplate::from_json(&imported_json).unwrap(),
+    template
+  );
+}
+
+#[tokio::test]
+async fn import_incompatible_view_settings_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let json = r#"{"sorts":[{"field":{"name":"Not a field","field_type":1},"condition":0}]}"#;
+  let error = test
+    .editor
+    .import_view_settings(&test.view_id, json)
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::IncompatibleViewSettings);
+  assert!(test
+    .editor
+    .get_all_sorts(&test.view_id)
+    .await
+    .items
+    .is_empty());
+}
@@ -14,7 +14,7 @@ use flowy_database2::entities::{
 use flowy_database2::services::cell::stringify_cell;
 use flowy_database2::services::database_view::DatabaseViewChanged;
 use flowy_database2::services::filter::{FilterChangeset, FilterInner};
-use flowy_database2::services::sort::SortCondition;
+use flowy_database2::services::sort::{SortCollation, SortCondition};
 use lib_infra::box_any::BoxAny;
 
 pub struct DatabaseSortTest {
@@ -32,6 +32,17 @@ impl DatabaseSortTest {
   }
 
   pub async fn insert_sort(&mut self, field: Field, condition: SortCondition) {
+    self
+      .insert_sort_with_collation(field, condition, SortCollation::Binary)
+      .await;
+  }
+
+  pub async fn insert_sort_with_collation(
+    &mut self,
+    field: Field,
+    condition: SortCondition,
+    collation: SortCollation,
+  ) {
     self.recv = Some(
       self
         .editor
@@ -44,6 +55,7 @@ impl DatabaseSortTest {
       field_id: field.id.clone(),
       sort_id: None,
       condition: condition.into(),
+      collation: collation.into(),
     };
     self.editor.create_or_update_sort(params).await.unwrap();
   }
@@ -1,6 +1,10 @@
 use crate::database::sort_test::script::DatabaseSortTest;
-use flowy_database2::entities::{CheckboxFilterConditionPB, CheckboxFilterPB, FieldType};
-use flowy_database2::services::sort::SortCondition;
+use collab_database::fields::select_type_option::SingleSelectTypeOption;
+use flowy_database2::entities::{
+  CheckboxFilterConditionPB, CheckboxFilterPB, FieldChangesetPB, FieldType,
+};
+use flowy_database2::services::field::MASKED_CELL_VALUE;
+use flowy_database2::services::sort::{SortCollation, SortCondition};
 use lib_infra::box_any::BoxAny;
 
 #[tokio::test]
@@ -310,3 +314,125 @@ async fn sort_number_by_descending_test() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn sort_single_select_by_option_order_test() {
+  let mut test = DatabaseSortTest::new().await;
+  let single_select_field = test.get_first_field(FieldType::SingleSelect).await;
+
+  // Reverse the options so that their order no longer matches the order of their names.
+  let mut options = test
+    .get_single_select_type_option(&single_select_field.id)
+    .await;
+  options.reverse();
+  let mut type_option = SingleSelectTypeOption::default();
+  type_option.options = options;
+  test
+    .editor
+    .update_field_type_option(
+      &single_select_field.id,
+      type_option.into(),
+      single_select_field.clone(),
+    )
+    .await
+    .unwrap();
+
+  test
+    .insert_sort(single_select_field.clone(), SortCondition::Ascending)
+    .await;
+  test
+    .assert_cell_content_order(
+      single_select_field.id.clone(),
+      vec!["Planned", "Planned", "Completed", "Completed"],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn sort_text_with_natural_collation_test() {
+  let mut test = DatabaseSortTest::new().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  for (row, text) in test
+    .rows
+    .clone()
+    .into_iter()
+    .zip(["item10", "item2", "Item1"])
+  {
+    test
+      .update_text_cell(row.id.clone(), text.to_string())
+      .await;
+  }
+
+  test
+    .insert_sort_with_collation(
+      text_field.clone(),
+      SortCondition::Ascending,
+      SortCollation::Natural,
+    )
+    .await;
+  test
+    .assert_cell_content_order(
+      text_field.id.clone(),
+      vec!["AE", "AE", "CB", "DA", "Item1", "item2", "item10"],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn sort_text_with_case_insensitive_collation_test() {
+  let mut test = DatabaseSortTest::new().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  for (row, text) in test.rows.clone().into_iter().zip(["b", "", "a"]) {
+    test
+      .update_text_cell(row.id.clone(), text.to_string())
+      .await;
+  }
+
+  test
+    .insert_sort_with_collation(
+      text_field.clone(),
+      SortCondition::Ascending,
+      SortCollation::CaseInsensitive,
+    )
+    .await;
+  test
+    .assert_cell_content_order(
+      text_field.id.clone(),
+      vec!["a", "AE", "AE", "b", "CB", "DA", ""],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn explain_sort_masks_sensitive_field_test() {
+  let mut test = DatabaseSortTest::new().await;
+  let text_field = test.get_first_field(FieldType::RichText).await;
+  test
+    .insert_sort(text_field.clone(), SortCondition::Ascending)
+    .await;
+  let row_id = test.rows[0].id.clone();
+  let explanation = test
+    .editor
+    .explain_query(&test.view_id, &row_id)
+    .await
+    .unwrap();
+  assert_eq!(explanation.sorts[0].cell_content, "A");
+
+  test
+    .editor
+    .update_field(FieldChangesetPB {
+      field_id: text_field.id.clone(),
+      view_id: test.view_id.clone(),
+      is_sensitive: Some(true),
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+  let explanation = test
+    .editor
+    .explain_query(&test.view_id, &row_id)
+    .await
+    .unwrap();
+  assert_eq!(explanation.sorts[0].field_id, text_field.id);
+  assert_eq!(explanation.sorts[0].cell_content, MASKED_CELL_VALUE);
+}
@@ -0,0 +1,263 @@
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use event_integration_test::folder_event::ViewTest;
+use event_integration_test::EventIntegrationTest;
+use flowy_database2::entities::{CreateRowPayloadPB, FieldChangesetPB, FieldType};
+use flowy_database2::services::cell::insert_text_cell;
+use flowy_database2::services::webhook::{
+  WebhookClient, WebhookDelivery, WebhookDeliveryStatus, WebhookRetryConfig, MAX_QUEUED_DELIVERIES,
+};
+use serde_json::Value;
+
+use crate::database::database_editor::DatabaseEditorTest;
+use crate::database::mock_data::make_test_grid;
+
+/// Responds with a server error to the first request and records the bodies of the others.
+#[derive(Default)]
+struct MockWebhookClient {
+  num_of_requests: Mutex<usize>,
+  bodies: Mutex<Vec<Value>>,
+}
+
+#[async_trait]
+impl WebhookClient for MockWebhookClient {
+  async fn post_json(&self, _url: &str, body: String) -> Result<u16, String> {
+    let mut num_of_requests = self.num_of_requests.lock().unwrap();
+    *num_of_requests += 1;
+    if *num_of_requests == 1 {
+      return Ok(500);
+    }
+    self
+      .bodies
+      .lock()
+      .unwrap()
+      .push(serde_json::from_str(&body).unwrap());
+    Ok(200)
+  }
+}
+
+/// Never responds, so the payloads pile up in the queue of the webhook.
+struct PendingWebhookClient;
+
+#[async_trait]
+impl WebhookClient for PendingWebhookClient {
+  async fn post_json(&self, _url: &str, _body: String) -> Result<u16, String> {
+    std::future::pending().await
+  }
+}
+
+async fn wait_for_deliveries(test: &DatabaseEditorTest, count: usize) -> Vec<WebhookDelivery> {
+  let database_manager = test.sdk.database_manager.clone();
+  for _ in 0..50 {
+    let deliveries = database_manager
+      .get_webhook_deliveries(&test.view_id)
+      .await
+      .unwrap();
+    if deliveries.len() == count
+      && deliveries
+        .iter()
+        .all(|delivery| delivery.status != WebhookDeliveryStatus::Pending)
+    {
+      return deliveries;
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+  }
+  panic!("The webhooks weren't delivered");
+}
+
+#[tokio::test]
+async fn webhook_delivery_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let client = Arc::new(MockWebhookClient::default());
+  database_manager.set_webhook_client(client.clone());
+  database_manager.set_webhook_retry_config(WebhookRetryConfig {
+    max_attempts: 3,
+    initial_backoff: Duration::from_millis(10),
+    max_backoff: Duration::from_millis(100),
+  });
+  assert!(database_manager
+    .add_webhook(&test.view_id, "ftp://example.com")
+    .await
+    .is_err());
+  let webhook = database_manager
+    .add_webhook(&test.view_id, "https://example.com/hook")
+    .await
+    .unwrap();
+
+  // The first attempt fails with a server error and is retried
+  let row = test
+    .editor
+    .create_row(CreateRowPayloadPB {
+      view_id: test.view_id.clone(),
+      ..Default::default()
+    })
+    .await
+    .unwrap()
+    .unwrap()
+    .row;
+  let deliveries = wait_for_deliveries(&test, 1).await;
+  assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Delivered);
+  assert_eq!(deliveries[0].attempts, 2);
+  assert_eq!(deliveries[0].webhook_id, webhook.id);
+
+  let primary_field = test.get_first_field(FieldType::RichText).await;
+  let cell = insert_text_cell("Hello".to_string(), &primary_field);
+  test
+    .editor
+    .update_cell(&test.view_id, &row.id, &primary_field.id, cell)
+    .await
+    .unwrap();
+  test
+    .editor
+    .update_field(FieldChangesetPB {
+      field_id: primary_field.id.clone(),
+      view_id: test.view_id.clone(),
+      name: Some("Title".to_string()),
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+  test.editor.delete_rows(&[row.id.clone()]).await.unwrap();
+  wait_for_deliveries(&test, 4).await;
+
+  let bodies = client.bodies.lock().unwrap().clone();
+  let body = |event: &str| {
+    bodies
+      .iter()
+      .find(|body| body["event"] == event)
+      .cloned()
+      .unwrap()
+  };
+  assert_eq!(body("row_created")["row_id"], row.id.to_string());
+  assert_eq!(body("row_updated")["cells"][&primary_field.name], "Hello");
+  assert_eq!(body("field_updated")["field_name"], "Title");
+  assert_eq!(body("row_deleted")["row_id"], row.id.to_string());
+
+  database_manager
+    .remove_webhook(&test.view_id, &webhook.id)
+    .await
+    .unwrap();
+  assert!(database_manager
+    .get_webhooks(&test.view_id)
+    .await
+    .unwrap()
+    .is_empty());
+}
+
+#[tokio::test]
+async fn merged_cell_writes_webhook_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let client = Arc::new(MockWebhookClient::default());
+  database_manager.set_webhook_client(client.clone());
+  database_manager.set_webhook_retry_config(WebhookRetryConfig {
+    max_attempts: 3,
+    initial_backoff: Duration::from_millis(10),
+    max_backoff: Duration::from_millis(100),
+  });
+  database_manager
+    .add_webhook(&test.view_id, "https://example.com/hook")
+    .await
+    .unwrap();
+
+  // Typing in a cell sends the first edit right away and the final value once the user pauses
+  let row = test.rows[0].clone();
+  let primary_field = test.get_first_field(FieldType::RichText).await;
+  for text in ["H", "He", "Hel", "Hell", "Hello"] {
+    let cell = insert_text_cell(text.to_string(), &primary_field);
+    test
+      .editor
+      .update_cell(&test.view_id, &row.id, &primary_field.id, cell)
+      .await
+      .unwrap();
+  }
+  wait_for_deliveries(&test, 2).await;
+  tokio::time::sleep(Duration::from_secs(2)).await;
+  wait_for_deliveries(&test, 2).await;
+
+  let bodies = client.bodies.lock().unwrap().clone();
+  assert_eq!(bodies.len(), 2);
+  assert!(bodies.iter().all(|body| body["event"] == "row_updated"));
+  assert_eq!(bodies[0]["cells"][&primary_field.name], "H");
+  assert_eq!(bodies[1]["cells"][&primary_field.name], "Hello");
+}
+
+#[tokio::test]
+async fn webhook_queue_is_bounded_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let database_manager = test.sdk.database_manager.clone();
+  database_manager.set_webhook_client(Arc::new(PendingWebhookClient));
+  database_manager
+    .add_webhook(&test.view_id, "https://example.com/hook")
+    .await
+    .unwrap();
+
+  // One payload is being delivered and the queue is full, the other payloads are dropped
+  let num_of_rows = MAX_QUEUED_DELIVERIES + 10;
+  for _ in 0..num_of_rows {
+    test
+      .editor
+      .create_row(CreateRowPayloadPB {
+        view_id: test.view_id.clone(),
+        ..Default::default()
+      })
+      .await
+      .unwrap();
+  }
+  let deliveries = database_manager
+    .get_webhook_deliveries(&test.view_id)
+    .await
+    .unwrap();
+  let dropped = deliveries
+    .iter()
+    .filter(|delivery| delivery.status == WebhookDeliveryStatus::Failed)
+    .collect::<Vec<_>>();
+  assert!(deliveries.len() >= num_of_rows);
+  assert!(deliveries.len() - dropped.len() <= MAX_QUEUED_DELIVERIES + 1);
+  assert_eq!(
+    dropped[0].error.as_deref(),
+    Some("The queue of the webhook is full")
+  );
+}
+
+#[tokio::test]
+async fn webhooks_are_saved_test() {
+  let mut sdk = EventIntegrationTest::new().await;
+  sdk.skip_clean();
+  let _ = sdk.init_anon_user().await;
+  let params = make_test_grid();
+  let view_test = ViewTest::new_grid_view(&sdk, params.to_json_bytes().unwrap()).await;
+  let test = DatabaseEditorTest::new(sdk, view_test).await;
+  let webhook = test
+    .sdk
+    .database_manager
+    .add_webhook(&test.view_id, "https://example.com/hook")
+    .await
+    .unwrap();
+  let view_id = test.view_id.clone();
+  let path = test.sdk.user_data_path();
+  let name = test.sdk.instance_name();
+  test.sdk.close_db();
+  drop(test);
+
+  // The webhooks are read again after a restart
+  let sdk = EventIntegrationTest::new_with_user_data_path(PathBuf::from(path), name).await;
+  let database_manager = sdk.database_manager.clone();
+  assert_eq!(
+    database_manager.get_webhooks(&view_id).await.unwrap(),
+    vec![webhook.clone()]
+  );
+  database_manager
+    .remove_webhook(&view_id, &webhook.id)
+    .await
+    .unwrap();
+  assert!(database_manager
+    .get_webhooks(&view_id)
+    .await
+    .unwrap()
+    .is_empty());
+}
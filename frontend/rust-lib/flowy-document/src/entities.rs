@@ -115,6 +115,31 @@ pub struct DownloadFilePB {
   pub local_file_path: String,
 }
 
+/// Replaces the blocks of a document with a grid block, e.g. after the markdown table written in
+/// the blocks was imported as a grid under the document.
+#[derive(Default, ProtoBuf, Validate)]
+pub struct ReplaceBlocksWithGridPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub document_id: String,
+
+  /// The sibling blocks to replace, the grid block takes the place of the first one.
+  #[pb(index = 2)]
+  pub block_ids: Vec<String>,
+
+  /// The view of the grid.
+  #[pb(index = 3)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ReplaceBlocksWithGridResponsePB {
+  /// The id of the grid block.
+  #[pb(index = 1)]
+  pub block_id: String,
+}
+
 #[derive(Default, ProtoBuf)]
 pub struct CreateDocumentPayloadPB {
   #[pb(index = 1)]
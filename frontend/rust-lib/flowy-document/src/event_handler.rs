@@ -131,6 +131,19 @@ pub(crate) async fn apply_action_handler(
   Ok(())
 }
 
+/// Handler for replacing the blocks of a document with a grid block
+pub(crate) async fn replace_blocks_with_grid_handler(
+  data: AFPluginData<ReplaceBlocksWithGridPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<ReplaceBlocksWithGridResponsePB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params = data.try_into_inner()?;
+  let block_id = manager
+    .replace_blocks_with_grid(&params.document_id, &params.block_ids, &params.view_id)
+    .await?;
+  data_result_ok(ReplaceBlocksWithGridResponsePB { block_id })
+}
+
 /// Handler for creating a text
 pub(crate) async fn create_text_handler(
   data: AFPluginData<TextDeltaPayloadPB>,
@@ -394,13 +407,21 @@ pub async fn convert_document_handler(
     return data_result_ok(ConvertDocumentResponsePB::default());
   }
 
-  let root = &parser.to_json();
+  let mut root = parser.to_json();
+  let json = params
+    .parse_types
+    .json
+    .then(|| serde_json::to_string(&root).unwrap_or_default());
+  // The html and the text inline the referenced grids, the json keeps the references
+  if params.parse_types.html || params.parse_types.text {
+    if let Some(root) = root.as_mut() {
+      manager.embed_grids(root).await;
+    }
+  }
+  let root = &root;
 
   data_result_ok(ConvertDocumentResponsePB {
-    json: params
-      .parse_types
-      .json
-      .then(|| serde_json::to_string(root).unwrap_or_default()),
+    json,
     html: params
       .parse_types
       .html
@@ -19,6 +19,10 @@ pub fn init(document_manager: Weak<DocumentManager>) -> AFPlugin {
     .event(DocumentEvent::ApplyAction, apply_action_handler)
     .event(DocumentEvent::GetDocumentData, get_document_data_handler)
     .event(DocumentEvent::GetDocumentText, get_document_text_handler)
+    .event(
+      DocumentEvent::ReplaceBlocksWithGrid,
+      replace_blocks_with_grid_handler,
+    )
     .event(
       DocumentEvent::GetDocEncodedCollab,
       get_encode_collab_handler,
@@ -137,4 +141,13 @@ pub enum DocumentEvent {
 
   #[event(input = "OpenDocumentPayloadPB", output = "DocumentTextPB")]
   GetDocumentText = 20,
+
+  // Replaces the selected blocks, e.g. the lines of a markdown table, with a grid block. The table
+  // is converted into a grid under the document by importing it with the MarkdownTable import
+  // type of the folder first.
+  #[event(
+    input = "ReplaceBlocksWithGridPayloadPB",
+    output = "ReplaceBlocksWithGridResponsePB"
+  )]
+  ReplaceBlocksWithGrid = 21,
 }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Weak;
 
@@ -7,7 +8,9 @@ use collab::core::origin::CollabOrigin;
 use collab::entity::EncodedCollab;
 use collab::lock::RwLock;
 use collab::preclude::Collab;
-use collab_document::blocks::DocumentData;
+use collab_document::blocks::{
+  Block, BlockAction, BlockActionPayload, BlockActionType, DocumentData,
+};
 use collab_document::document::Document;
 use collab_document::document_awareness::DocumentAwarenessState;
 use collab_document::document_awareness::DocumentAwarenessUser;
@@ -16,9 +19,12 @@ use collab_entity::CollabType;
 
 use collab_plugins::CollabKVDB;
 use dashmap::DashMap;
+use lib_infra::async_trait::async_trait;
 use lib_infra::util::timestamp;
+use nanoid::nanoid;
+use serde_json::json;
 use tracing::{event, instrument};
-use tracing::{info, trace};
+use tracing::{info, trace, warn};
 
 use crate::document::{
   subscribe_document_changed, subscribe_document_snapshot_state, subscribe_document_sync_state,
@@ -35,6 +41,8 @@ use crate::entities::UpdateDocumentAwarenessStatePB;
 use crate::entities::{
   DocumentSnapshotData, DocumentSnapshotMeta, DocumentSnapshotMetaPB, DocumentSnapshotPB,
 };
+use crate::parser::constant::{GRID, MARKDOWN, PARENT_ID, VIEW_ID};
+use crate::parser::parser_entities::NestedBlock;
 use crate::reminder::DocumentReminderAction;
 
 pub trait DocumentUserService: Send + Sync {
@@ -52,6 +60,13 @@ pub trait DocumentSnapshotService: Send + Sync {
   fn get_document_snapshot(&self, snapshot_id: &str) -> FlowyResult<DocumentSnapshotData>;
 }
 
+/// Reads the grids referenced by the documents, so a document can be exported with its grids.
+#[async_trait]
+pub trait DocumentDatabaseService: Send + Sync {
+  /// Returns the rows of the grid view as a markdown table.
+  async fn export_view_markdown(&self, view_id: &str) -> FlowyResult<String>;
+}
+
 pub struct DocumentManager {
   pub user_service: Arc<dyn DocumentUserService>,
   collab_builder: Arc<AppFlowyCollabBuilder>,
@@ -60,6 +75,7 @@ pub struct DocumentManager {
   cloud_service: Arc<dyn DocumentCloudService>,
   storage_service: Weak<dyn StorageService>,
   snapshot_service: Arc<dyn DocumentSnapshotService>,
+  database_service: Arc<dyn DocumentDatabaseService>,
 }
 
 impl DocumentManager {
@@ -69,6 +85,7 @@ impl DocumentManager {
     cloud_service: Arc<dyn DocumentCloudService>,
     storage_service: Weak<dyn StorageService>,
     snapshot_service: Arc<dyn DocumentSnapshotService>,
+    database_service: Arc<dyn DocumentDatabaseService>,
   ) -> Self {
     Self {
       user_service,
@@ -78,6 +95,7 @@ impl DocumentManager {
       cloud_service,
       storage_service,
       snapshot_service,
+      database_service,
     }
   }
 
@@ -276,6 +294,100 @@ impl DocumentManager {
     Ok(text)
   }
 
+  /// Inlines the grids referenced by the block and its children as markdown tables, so they're
+  /// exported with the document instead of being dropped. A grid that can't be read, e.g. because
+  /// it was deleted, is left as it is.
+  pub async fn embed_grids(&self, block: &mut NestedBlock) {
+    let mut blocks = vec![block];
+    while let Some(block) = blocks.pop() {
+      let view_id = block
+        .data
+        .get(VIEW_ID)
+        .and_then(|value| value.as_str())
+        .map(|view_id| view_id.to_string());
+      if let Some(view_id) = view_id.filter(|_| block.ty == GRID) {
+        match self.database_service.export_view_markdown(&view_id).await {
+          Ok(markdown) => {
+            block.data.insert(MARKDOWN.to_string(), json!(markdown));
+          },
+          Err(err) => warn!("Can't embed the grid:{} in the document: {}", view_id, err),
+        }
+      }
+      blocks.extend(block.children.iter_mut());
+    }
+  }
+
+  /// Replaces the blocks of the opened document with a grid block that shows the view, e.g. after
+  /// the markdown table written in the blocks was imported as a grid. The blocks must be siblings,
+  /// the grid block takes the place of the first one. Returns the id of the grid block.
+  pub async fn replace_blocks_with_grid(
+    &self,
+    doc_id: &str,
+    block_ids: &[String],
+    view_id: &str,
+  ) -> FlowyResult<String> {
+    let document = self.editable_document(doc_id).await?;
+    let mut document = document.write().await;
+    let data = document.get_document_data().map_err(internal_error)?;
+    let blocks = block_ids
+      .iter()
+      .map(|block_id| {
+        data.blocks.get(block_id).cloned().ok_or_else(|| {
+          FlowyError::record_not_found().with_context(format!("Can't find the block: {}", block_id))
+        })
+      })
+      .collect::<FlowyResult<Vec<_>>>()?;
+    let first_block = blocks
+      .first()
+      .ok_or_else(|| FlowyError::invalid_data().with_context("No blocks to replace"))?;
+    if blocks
+      .iter()
+      .any(|block| block.id == data.page_id || block.parent != first_block.parent)
+    {
+      return Err(FlowyError::invalid_data().with_context("The blocks must be siblings"));
+    }
+
+    let grid_block_id = nanoid!(10);
+    let grid_block = Block {
+      id: grid_block_id.clone(),
+      ty: GRID.to_string(),
+      parent: first_block.parent.clone(),
+      children: nanoid!(10),
+      external_id: None,
+      external_type: None,
+      data: HashMap::from([
+        (VIEW_ID.to_string(), json!(view_id)),
+        (PARENT_ID.to_string(), json!(doc_id)),
+      ]),
+    };
+    let insert_action = BlockAction {
+      action: BlockActionType::Insert,
+      payload: BlockActionPayload {
+        block: Some(grid_block),
+        parent_id: Some(first_block.parent.clone()),
+        prev_id: Some(first_block.id.clone()),
+        text_id: None,
+        delta: None,
+      },
+    };
+    let delete_actions = blocks.into_iter().map(|block| BlockAction {
+      action: BlockActionType::Delete,
+      payload: BlockActionPayload {
+        parent_id: Some(block.parent.clone()),
+        block: Some(block),
+        prev_id: None,
+        text_id: None,
+        delta: None,
+      },
+    });
+    document.apply_action(
+      std::iter::once(insert_action)
+        .chain(delete_actions)
+        .collect(),
+    )?;
+    Ok(grid_block_id)
+  }
+
   /// Return a document instance.
   /// The returned document might or might not be able to sync with the cloud.
   async fn get_document(&self, doc_id: &str) -> FlowyResult<Arc<RwLock<Document>>> {
@@ -305,12 +417,27 @@ impl DocumentManager {
     Ok(())
   }
 
+  /// Opens the document, creating an empty one first if it doesn't exist yet, like the document
+  /// of a database row, which is only created the first time the row is opened as a page.
+  pub async fn open_or_create_document(&self, doc_id: &str) -> FlowyResult<()> {
+    if !self.is_doc_exist(doc_id).await? {
+      let uid = self.user_service.user_id()?;
+      self.create_document(uid, doc_id, None).await?;
+    }
+    self.open_document(doc_id).await
+  }
+
   pub async fn close_document(&self, doc_id: &str) -> FlowyResult<()> {
     if let Some((doc_id, document)) = self.documents.remove(doc_id) {
       {
         // clear the awareness state when close the document
         let mut lock = document.write().await;
         lock.clean_awareness_local_state();
+        if let Ok(uid) = self.user_service.user_id() {
+          self
+            .collab_builder
+            .create_snapshot_on_close(uid, &CollabType::Document, &*lock);
+        }
       }
 
       let clone_doc_id = doc_id.clone();
@@ -11,6 +11,9 @@ pub const WIDTH: &str = "width";
 pub const HEIGHT: &str = "height";
 pub const URL: &str = "url";
 pub const CAPTION: &str = "caption";
+pub const VIEW_ID: &str = "view_id";
+pub const PARENT_ID: &str = "parent_id";
+pub const MARKDOWN: &str = "markdown";
 pub const ALIGN: &str = "align";
 
 pub const PAGE: &str = "page";
@@ -25,6 +28,7 @@ pub const CALLOUT: &str = "callout";
 pub const IMAGE: &str = "image";
 pub const DIVIDER: &str = "divider";
 pub const MATH_EQUATION: &str = "math_equation";
+pub const GRID: &str = "grid";
 pub const BOLD: &str = "bold";
 pub const ITALIC: &str = "italic";
 pub const STRIKETHROUGH: &str = "strikethrough";
@@ -426,6 +426,28 @@ impl NestedBlock {
           html.push_str(&format!("</{}>", BLOCKQUOTE_TAG_NAME));
         }
       },
+      // <pre><code class="language-markdown">| Name |\n| --- |\n| Hello |</code></pre>
+      GRID if self.data.contains_key(MARKDOWN) => {
+        let markdown = self
+          .data
+          .get(MARKDOWN)
+          .and_then(Value::as_str)
+          .unwrap_or_default()
+          .replace('&', "&amp;")
+          .replace('<', "&lt;")
+          .replace('>', "&gt;");
+        html.push_str(&format!(
+          "<{}><{} {}=\"{}-{}\">{}</{}></{}>",
+          PRE_TAG_NAME,
+          CODE_TAG_NAME,
+          CLASS,
+          LANGUAGE,
+          MARKDOWN,
+          markdown,
+          CODE_TAG_NAME,
+          PRE_TAG_NAME
+        ));
+      },
       // <p>Hello</p>
       PAGE => {
         if !text_html.is_empty() {
@@ -474,6 +496,10 @@ impl NestedBlock {
         let formula = self.data.get(FORMULA).unwrap_or(&Value::Null);
         text.push_str(&format!("{}\n", formula.to_string().trim_matches('\"')));
       },
+      GRID if self.data.contains_key(MARKDOWN) => {
+        let markdown = self.data.get(MARKDOWN).and_then(Value::as_str);
+        text.push_str(&format!("{}\n", markdown.unwrap_or_default().trim_end()));
+      },
       PAGE => {
         if !delta_text.is_empty() {
           text.push_str(&format!("{}\n", delta_text));
@@ -1,10 +1,12 @@
+use std::sync::Arc;
 use std::{collections::HashMap, vec};
 
 use collab_document::blocks::{Block, BlockAction, BlockActionPayload, BlockActionType};
-use collab_document::document_data::PARAGRAPH_BLOCK_TYPE;
+use collab_document::document_data::{default_document_data, PARAGRAPH_BLOCK_TYPE};
+use flowy_document::parser::document_data_parser::DocumentDataParser;
 
 use crate::document::util;
-use crate::document::util::gen_id;
+use crate::document::util::{gen_document_id, gen_id, DocumentTest};
 
 #[tokio::test]
 async fn document_apply_insert_block_with_empty_parent_id() {
@@ -41,3 +43,84 @@ async fn document_apply_insert_block_with_empty_parent_id() {
   let block = document.read().await.get_block(&text_block_id).unwrap();
   assert_eq!(block.parent, page_id);
 }
+
+#[tokio::test]
+async fn document_replace_blocks_with_grid() {
+  let test = DocumentTest::new();
+  let doc_id = gen_document_id();
+  let data = default_document_data(&doc_id);
+  let page_id = data.page_id.clone();
+  let uid = test.user_service.user_id().unwrap();
+  test
+    .create_document(uid, &doc_id, Some(data))
+    .await
+    .unwrap();
+  test.open_document(&doc_id).await.unwrap();
+  let document = test.editable_document(&doc_id).await.unwrap();
+
+  // insert the lines of a markdown table as paragraphs after the first paragraph
+  let data = document.read().await.get_document_data().unwrap();
+  let first_block_id = data.meta.children_map[&data.blocks[&page_id].children][0].clone();
+  let mut prev_id = first_block_id.clone();
+  let mut block_ids = vec![];
+  for _ in 0..3 {
+    let block_id = gen_id();
+    let block = Block {
+      id: block_id.clone(),
+      ty: PARAGRAPH_BLOCK_TYPE.to_string(),
+      parent: page_id.clone(),
+      children: gen_id(),
+      external_id: None,
+      external_type: None,
+      data: HashMap::new(),
+    };
+    let insert_action = BlockAction {
+      action: BlockActionType::Insert,
+      payload: BlockActionPayload {
+        block: Some(block),
+        parent_id: Some(page_id.clone()),
+        prev_id: Some(prev_id),
+        delta: None,
+        text_id: None,
+      },
+    };
+    document
+      .write()
+      .await
+      .apply_action(vec![insert_action])
+      .unwrap();
+    prev_id = block_id.clone();
+    block_ids.push(block_id);
+  }
+
+  let grid_block_id = test
+    .replace_blocks_with_grid(&doc_id, &block_ids, "grid_view_id")
+    .await
+    .unwrap();
+
+  // the grid block takes the place of the paragraphs
+  let data = document.read().await.get_document_data().unwrap();
+  let children = &data.meta.children_map[&data.blocks[&page_id].children];
+  assert_eq!(children, &vec![first_block_id, grid_block_id.clone()]);
+  let grid_block = &data.blocks[&grid_block_id];
+  assert_eq!(grid_block.ty, "grid");
+  assert_eq!(grid_block.data["view_id"], "grid_view_id");
+  assert!(block_ids
+    .iter()
+    .all(|block_id| !data.blocks.contains_key(block_id)));
+
+  // the grid is inlined as a markdown table when the document is exported
+  let parser = DocumentDataParser::new(Arc::new(data), None);
+  let mut root = parser.to_json().unwrap();
+  test.embed_grids(&mut root).await;
+  let text = parser.to_text_with_json(&Some(root.clone()));
+  assert!(text.contains("| View |\n| --- |\n| grid_view_id |\n"));
+  let html = parser.to_html_with_json(&Some(root));
+  assert!(html.contains("<pre><code class=\"language-markdown\">| View |"));
+
+  // the page can't be replaced
+  assert!(test
+    .replace_blocks_with_grid(&doc_id, &[page_id], "grid_view_id")
+    .await
+    .is_err());
+}
@@ -18,7 +18,9 @@ use collab_integrate::collab_builder::{
 };
 use collab_integrate::CollabKVDB;
 use flowy_document::entities::{DocumentSnapshotData, DocumentSnapshotMeta};
-use flowy_document::manager::{DocumentManager, DocumentSnapshotService, DocumentUserService};
+use flowy_document::manager::{
+  DocumentDatabaseService, DocumentManager, DocumentSnapshotService, DocumentUserService,
+};
 use flowy_document_pub::cloud::*;
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use flowy_storage_pub::chunked_byte::ChunkedBytes;
@@ -36,6 +38,7 @@ impl DocumentTest {
     let cloud_service = Arc::new(LocalTestDocumentCloudServiceImpl());
     let file_storage = Arc::new(DocumentTestFileStorageService) as Arc<dyn StorageService>;
     let document_snapshot = Arc::new(DocumentTestSnapshot);
+    let database_service = Arc::new(DocumentTestDatabaseService);
 
     let builder = Arc::new(AppFlowyCollabBuilder::new(
       DefaultCollabStorageProvider(),
@@ -50,6 +53,7 @@ impl DocumentTest {
       cloud_service,
       Arc::downgrade(&file_storage),
       document_snapshot,
+      database_service,
     );
     Self { inner: manager }
   }
@@ -258,6 +262,19 @@ impl DocumentSnapshotService for DocumentTestSnapshot {
   }
 }
 
+/// Exports every grid as a table with a single row, which is the id of the view.
+struct DocumentTestDatabaseService;
+
+#[async_trait]
+impl DocumentDatabaseService for DocumentTestDatabaseService {
+  async fn export_view_markdown(&self, view_id: &str) -> FlowyResult<String> {
+    if view_id.is_empty() {
+      return Err(FlowyError::record_not_found());
+    }
+    Ok(format!("| View |\n| --- |\n| {} |\n", view_id))
+  }
+}
+
 struct WorkspaceCollabIntegrateImpl {
   workspace_id: String,
 }
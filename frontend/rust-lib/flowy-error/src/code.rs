@@ -317,6 +317,33 @@ pub enum ErrorCode {
 
   #[error("Group name is empty")]
   GroupNameIsEmpty = 109,
+
+  #[error("Exceeded the maximum number of selected options")]
+  ExceedMaxSelections = 110,
+
+  #[error("View tag is empty")]
+  ViewTagIsEmpty = 111,
+
+  #[error("The view settings are not compatible with the database")]
+  IncompatibleViewSettings = 112,
+
+  #[error("The validation pattern is not a valid regular expression")]
+  InvalidValidationPattern = 113,
+
+  #[error("The text does not match the validation pattern of the field")]
+  TextDoesNotMatchPattern = 114,
+
+  #[error("The cell data does not match the type of the field")]
+  CellDataTypeMismatch = 115,
+
+  #[error("The value already exists in another row of the unique field")]
+  DuplicateCellValue = 116,
+
+  #[error("The database is opened in safe mode and can't be edited")]
+  DatabaseReadOnly = 117,
+
+  #[error("The row is locked and can't be edited")]
+  RowLocked = 118,
 }
 
 impl ErrorCode {
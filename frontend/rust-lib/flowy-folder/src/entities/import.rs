@@ -13,6 +13,14 @@ pub enum ImportTypePB {
   Markdown = 2,
   AFDatabase = 3,
   CSV = 4,
+  GridJSON = 5,
+  XLSX = 6,
+  AirtableCSV = 7,
+  NotionDatabase = 8,
+  GridTemplate = 9,
+  DatabaseBackup = 10,
+  HtmlTable = 11,
+  MarkdownTable = 12,
 }
 
 impl From<ImportTypePB> for ImportType {
@@ -23,6 +31,14 @@ impl From<ImportTypePB> for ImportType {
       ImportTypePB::Markdown => ImportType::Markdown,
       ImportTypePB::AFDatabase => ImportType::AFDatabase,
       ImportTypePB::CSV => ImportType::CSV,
+      ImportTypePB::GridJSON => ImportType::GridJSON,
+      ImportTypePB::XLSX => ImportType::XLSX,
+      ImportTypePB::AirtableCSV => ImportType::AirtableCSV,
+      ImportTypePB::NotionDatabase => ImportType::NotionDatabase,
+      ImportTypePB::GridTemplate => ImportType::GridTemplate,
+      ImportTypePB::DatabaseBackup => ImportType::DatabaseBackup,
+      ImportTypePB::HtmlTable => ImportType::HtmlTable,
+      ImportTypePB::MarkdownTable => ImportType::MarkdownTable,
     }
   }
 }
@@ -4,6 +4,7 @@ mod parser;
 pub mod publish;
 pub mod trash;
 pub mod view;
+pub mod view_tag;
 pub mod workspace;
 
 pub use icon::*;
@@ -11,4 +12,5 @@ pub use import::*;
 pub use publish::*;
 pub use trash::*;
 pub use view::*;
+pub use view_tag::*;
 pub use workspace::*;
@@ -0,0 +1,21 @@
+use flowy_error::ErrorCode;
+
+#[derive(Debug)]
+pub struct ViewTag(pub String);
+
+impl ViewTag {
+  pub fn parse(s: String) -> Result<ViewTag, ErrorCode> {
+    let s = s.trim();
+    if s.is_empty() {
+      return Err(ErrorCode::ViewTagIsEmpty);
+    }
+
+    Ok(Self(s.to_string()))
+  }
+}
+
+impl AsRef<str> for ViewTag {
+  fn as_ref(&self) -> &str {
+    &self.0
+  }
+}
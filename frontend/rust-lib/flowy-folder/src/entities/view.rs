@@ -11,6 +11,7 @@ use flowy_folder_pub::cloud::gen_view_id;
 
 use crate::entities::icon::ViewIconPB;
 use crate::entities::parser::view::{ViewIdentify, ViewName, ViewThumbnail};
+use crate::view_tag::view_tags_from_extra;
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct ChildViewUpdatePB {
@@ -72,6 +73,10 @@ pub struct ViewPB {
   // user_id
   #[pb(index = 12, one_of)]
   pub last_edited_by: Option<i64>,
+
+  /// The tags of the view. Refer to [crate::view_tag] for how they are stored.
+  #[pb(index = 13)]
+  pub tags: Vec<String>,
 }
 
 pub fn view_pb_without_child_views(view: View) -> ViewPB {
@@ -84,6 +89,7 @@ pub fn view_pb_without_child_views(view: View) -> ViewPB {
     layout: view.layout.into(),
     icon: view.icon.clone().map(|icon| icon.into()),
     is_favorite: view.is_favorite,
+    tags: view_tags_from_extra(view.extra.as_deref()),
     extra: view.extra,
     created_by: view.created_by,
     last_edited: view.last_edited_time,
@@ -105,6 +111,7 @@ pub fn view_pb_without_child_views_from_arc(view: Arc<View>) -> ViewPB {
     created_by: view.created_by,
     last_edited: view.last_edited_time,
     last_edited_by: view.last_edited_by,
+    tags: view_tags_from_extra(view.extra.as_deref()),
   }
 }
 
@@ -126,6 +133,7 @@ pub fn view_pb_with_child_views(view: Arc<View>, child_views: Vec<Arc<View>>) ->
     created_by: view.created_by,
     last_edited: view.last_edited_time,
     last_edited_by: view.last_edited_by,
+    tags: view_tags_from_extra(view.extra.as_deref()),
   }
 }
 
@@ -0,0 +1,46 @@
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+
+use crate::entities::parser::view::{ViewIdentify, ViewTag};
+
+#[derive(Default, ProtoBuf)]
+pub struct ViewTagPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub tag: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct ViewTagParams {
+  pub view_id: String,
+  pub tag: String,
+}
+
+impl TryInto<ViewTagParams> for ViewTagPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ViewTagParams, Self::Error> {
+    let view_id = ViewIdentify::parse(self.view_id)?.0;
+    let tag = ViewTag::parse(self.tag)?.0;
+
+    Ok(ViewTagParams { view_id, tag })
+  }
+}
+
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ViewTagPB {
+  #[pb(index = 1)]
+  pub tag: String,
+}
+
+/// The payload of the `DidUpdateViewTags` notification, sent after the tags of a view are changed.
+#[derive(Default, ProtoBuf, Debug, Clone)]
+pub struct ViewTagsPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub tags: Vec<String>,
+}
@@ -418,6 +418,39 @@ pub(crate) async fn update_view_visibility_status_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip(data, folder), err)]
+pub(crate) async fn add_view_tag_handler(
+  data: AFPluginData<ViewTagPayloadPB>,
+  folder: AFPluginState<Weak<FolderManager>>,
+) -> Result<(), FlowyError> {
+  let folder = upgrade_folder(folder)?;
+  let params: ViewTagParams = data.into_inner().try_into()?;
+  folder.add_view_tag(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, folder), err)]
+pub(crate) async fn remove_view_tag_handler(
+  data: AFPluginData<ViewTagPayloadPB>,
+  folder: AFPluginState<Weak<FolderManager>>,
+) -> Result<(), FlowyError> {
+  let folder = upgrade_folder(folder)?;
+  let params: ViewTagParams = data.into_inner().try_into()?;
+  folder.remove_view_tag(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, folder), err)]
+pub(crate) async fn get_views_with_tag_handler(
+  data: AFPluginData<ViewTagPB>,
+  folder: AFPluginState<Weak<FolderManager>>,
+) -> DataResult<RepeatedViewPB, FlowyError> {
+  let folder = upgrade_folder(folder)?;
+  let params: ViewTagPB = data.into_inner();
+  let views = folder.get_views_with_tag(params.tag.trim()).await?;
+  data_result_ok(RepeatedViewPB { items: views })
+}
+
 #[tracing::instrument(level = "debug", skip(data, folder), err)]
 pub(crate) async fn publish_view_handler(
   data: AFPluginData<PublishViewParamsPB>,
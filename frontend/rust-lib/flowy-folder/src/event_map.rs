@@ -48,6 +48,9 @@ pub fn init(folder: Weak<FolderManager>) -> AFPlugin {
     .event(FolderEvent::UnpublishViews, unpublish_views_handler)
     .event(FolderEvent::SetPublishNamespace, set_publish_namespace_handler)
     .event(FolderEvent::GetPublishNamespace, get_publish_namespace_handler)
+    .event(FolderEvent::AddViewTag, add_view_tag_handler)
+    .event(FolderEvent::RemoveViewTag, remove_view_tag_handler)
+    .event(FolderEvent::GetViewsWithTag, get_views_with_tag_handler)
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
@@ -200,4 +203,14 @@ pub enum FolderEvent {
 
   #[event(input = "ImportZipPB")]
   ImportZipFile = 48,
+
+  #[event(input = "ViewTagPayloadPB")]
+  AddViewTag = 49,
+
+  #[event(input = "ViewTagPayloadPB")]
+  RemoveViewTag = 50,
+
+  /// Return the views that have the given tag
+  #[event(input = "ViewTagPB", output = "RepeatedViewPB")]
+  GetViewsWithTag = 51,
 }
@@ -8,6 +8,7 @@ pub mod notification;
 pub mod protobuf;
 mod user_default;
 pub mod view_operation;
+pub mod view_tag;
 
 mod manager_init;
 mod manager_observer;
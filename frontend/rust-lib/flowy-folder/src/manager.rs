@@ -3,7 +3,7 @@ use crate::entities::{
   view_pb_with_child_views, view_pb_without_child_views, view_pb_without_child_views_from_arc,
   CreateViewParams, CreateWorkspaceParams, DeletedViewPB, DuplicateViewParams, FolderSnapshotPB,
   MoveNestedViewParams, RepeatedTrashPB, RepeatedViewIdPB, RepeatedViewPB, UpdateViewParams,
-  ViewLayoutPB, ViewPB, ViewSectionPB, WorkspacePB, WorkspaceSettingPB,
+  ViewLayoutPB, ViewPB, ViewSectionPB, ViewTagParams, ViewTagsPB, WorkspacePB, WorkspaceSettingPB,
 };
 use crate::manager_observer::{
   notify_child_views_changed, notify_did_update_workspace, notify_parent_view_did_change,
@@ -18,6 +18,7 @@ use crate::util::{folder_not_init_error, workspace_data_not_sync_error};
 use crate::view_operation::{
   create_view, EncodedCollabWrapper, FolderOperationHandler, FolderOperationHandlers,
 };
+use crate::view_tag::{extra_with_view_tags, view_tags_from_extra};
 use arc_swap::ArcSwapOption;
 use collab::core::collab::DataSource;
 use collab::lock::RwLock;
@@ -1173,6 +1174,91 @@ impl FolderManager {
     Ok(())
   }
 
+  /// Adds the tag to the view. Adding a tag that the view already has is a no-op.
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub async fn add_view_tag(&self, params: ViewTagParams) -> FlowyResult<()> {
+    self
+      .update_view_tags(&params.view_id, |tags| {
+        if tags.contains(&params.tag) {
+          return false;
+        }
+        tags.push(params.tag);
+        true
+      })
+      .await
+  }
+
+  /// Removes the tag from the view.
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub async fn remove_view_tag(&self, params: ViewTagParams) -> FlowyResult<()> {
+    self
+      .update_view_tags(&params.view_id, |tags| {
+        let len = tags.len();
+        tags.retain(|tag| tag != &params.tag);
+        tags.len() != len
+      })
+      .await
+  }
+
+  /// Returns all the views that have the given tag, regardless of their position in the folder.
+  /// The views in the trash and the private views of other members are excluded.
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub async fn get_views_with_tag(&self, tag: &str) -> FlowyResult<Vec<ViewPB>> {
+    let views = self
+      .get_all_views_pb()
+      .await?
+      .into_iter()
+      .filter(|view| view.tags.iter().any(|view_tag| view_tag == tag))
+      .collect();
+    Ok(views)
+  }
+
+  /// Updates the tags of the view with `f`, which returns true if the tags were changed.
+  async fn update_view_tags<F>(&self, view_id: &str, f: F) -> FlowyResult<()>
+  where
+    F: FnOnce(&mut Vec<String>) -> bool,
+  {
+    let extra = {
+      let lock = self
+        .mutex_folder
+        .load_full()
+        .ok_or_else(folder_not_init_error)?;
+      let folder = lock.read().await;
+      folder
+        .get_view(view_id)
+        .ok_or_else(FlowyError::record_not_found)?
+        .extra
+        .clone()
+    };
+
+    let mut tags = view_tags_from_extra(extra.as_deref());
+    if !f(&mut tags) {
+      return Ok(());
+    }
+
+    let extra = extra_with_view_tags(extra.as_deref(), tags.clone());
+    self
+      .update_view(view_id, |update| {
+        update.set_extra_if_not_none(Some(extra)).done()
+      })
+      .await?;
+
+    send_notification(view_id, FolderNotification::DidUpdateViewTags)
+      .payload(ViewTagsPB {
+        view_id: view_id.to_string(),
+        tags: tags.clone(),
+      })
+      .send();
+    send_current_workspace_notification(
+      FolderNotification::DidUpdateViewTags,
+      ViewTagsPB {
+        view_id: view_id.to_string(),
+        tags,
+      },
+    );
+    Ok(())
+  }
+
   /// Publishes a view identified by the given `view_id`.
   ///
   /// If `publish_name` is `None`, a default name will be generated using the view name and view id.
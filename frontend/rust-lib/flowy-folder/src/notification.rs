@@ -36,6 +36,9 @@ pub enum FolderNotification {
 
   /// Trigger when the ROOT views (the first level) in section are updated
   DidUpdateSectionViews = 39,
+
+  /// Trigger after adding or removing a tag of a view
+  DidUpdateViewTags = 40,
 }
 
 impl std::convert::From<FolderNotification> for i32 {
@@ -63,6 +66,7 @@ impl std::convert::From<i32> for FolderNotification {
       37 => FolderNotification::DidUnfavoriteView,
       38 => FolderNotification::DidUpdateRecentViews,
       39 => FolderNotification::DidUpdateSectionViews,
+      40 => FolderNotification::DidUpdateViewTags,
       _ => FolderNotification::Unknown,
     }
   }
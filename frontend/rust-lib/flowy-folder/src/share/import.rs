@@ -7,6 +7,14 @@ pub enum ImportType {
   Markdown = 2,
   AFDatabase = 3,
   CSV = 4,
+  GridJSON = 5,
+  XLSX = 6,
+  AirtableCSV = 7,
+  NotionDatabase = 8,
+  GridTemplate = 9,
+  DatabaseBackup = 10,
+  HtmlTable = 11,
+  MarkdownTable = 12,
 }
 
 #[derive(Clone, Debug)]
@@ -0,0 +1,28 @@
+use serde_json::{Map, Value};
+
+/// The tags of a view are stored in the `extra` JSON of the view under this key, so they are
+/// synced with the rest of the folder and don't require a separate collab.
+const VIEW_TAGS_KEY: &str = "tags";
+
+/// Returns the tags stored in the extra data of a view.
+pub fn view_tags_from_extra(extra: Option<&str>) -> Vec<String> {
+  extra
+    .and_then(|extra| serde_json::from_str::<Map<String, Value>>(extra).ok())
+    .and_then(|mut map| map.remove(VIEW_TAGS_KEY))
+    .and_then(|tags| serde_json::from_value::<Vec<String>>(tags).ok())
+    .unwrap_or_default()
+}
+
+/// Returns the extra data with the given tags. The other values of the extra data are kept
+/// unchanged. If the extra data is not a JSON object, it will be replaced.
+pub(crate) fn extra_with_view_tags(extra: Option<&str>, tags: Vec<String>) -> String {
+  let mut map = extra
+    .and_then(|extra| serde_json::from_str::<Map<String, Value>>(extra).ok())
+    .unwrap_or_default();
+  if tags.is_empty() {
+    map.remove(VIEW_TAGS_KEY);
+  } else {
+    map.insert(VIEW_TAGS_KEY.to_string(), Value::from(tags));
+  }
+  Value::Object(map).to_string()
+}
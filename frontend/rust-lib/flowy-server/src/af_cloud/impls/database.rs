@@ -12,8 +12,8 @@ use std::sync::Arc;
 use tracing::{error, instrument};
 
 use flowy_database_pub::cloud::{
-  DatabaseAIService, DatabaseCloudService, DatabaseSnapshot, EncodeCollabByOid, SummaryRowContent,
-  TranslateRowContent, TranslateRowResponse,
+  DatabaseAIService, DatabaseCloudService, DatabaseSnapshot, EncodeCollabByOid, LinkPreview,
+  SummaryRowContent, TranslateRowContent, TranslateRowResponse,
 };
 use flowy_error::FlowyError;
 use lib_infra::async_trait::async_trait;
@@ -21,6 +21,7 @@ use lib_infra::async_trait::async_trait;
 use crate::af_cloud::define::ServerUser;
 use crate::af_cloud::impls::util::check_request_workspace_id_is_match;
 use crate::af_cloud::AFServer;
+use crate::link_preview::fetch_link_preview;
 
 pub(crate) struct AFCloudDatabaseCloudServiceImpl<T> {
   pub inner: T,
@@ -131,6 +132,10 @@ where
   ) -> Result<Vec<DatabaseSnapshot>, Error> {
     Ok(vec![])
   }
+
+  async fn get_link_preview(&self, url: &str) -> Result<LinkPreview, Error> {
+    fetch_link_preview(url).await
+  }
 }
 
 #[async_trait]
@@ -1,3 +1,4 @@
+pub use link_preview::LINK_PREVIEW_OPT_IN_KEY;
 pub use server::*;
 
 pub mod af_cloud;
@@ -6,4 +7,5 @@ mod response;
 mod server;
 
 mod default_impl;
+mod link_preview;
 pub mod util;
@@ -0,0 +1,237 @@
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use anyhow::{anyhow, Error};
+use flowy_database_pub::cloud::LinkPreview;
+use reqwest::header::LOCATION;
+use reqwest::redirect::Policy;
+use scraper::{Html, Selector};
+use url::{Host, Url};
+
+/// The key of the preference that lets the app fetch the link previews of the URL cells. The
+/// preference is a bool, set with the key-value events of the config plugin. The previews are
+/// not fetched unless the user opted in, whichever server the user signed in with.
+pub const LINK_PREVIEW_OPT_IN_KEY: &str = "link_preview_opt_in";
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_REDIRECTS: usize = 5;
+/// The title and the icons are declared in the head of the page, so there is no need to read
+/// the whole page.
+const MAX_PAGE_SIZE: usize = 512 * 1024;
+
+/// Fetches the web page at the given url and extracts its title and favicon. The redirects are
+/// followed one by one, and every url must resolve to a public address, so a link can't make the
+/// app reach the loopback or the private network.
+pub(crate) async fn fetch_link_preview(url: &str) -> Result<LinkPreview, Error> {
+  let mut url = Url::parse(url)?;
+  let mut redirects = 0;
+  let mut response = loop {
+    let response = public_client(&url).await?.get(url.clone()).send().await?;
+    if !response.status().is_redirection() {
+      break response.error_for_status()?;
+    }
+    redirects += 1;
+    if redirects > MAX_REDIRECTS {
+      return Err(anyhow!("Too many redirects"));
+    }
+    let location = response
+      .headers()
+      .get(LOCATION)
+      .and_then(|location| location.to_str().ok())
+      .ok_or_else(|| anyhow!("Redirect without a location"))?;
+    url = url.join(location)?;
+  };
+  // The page might be redirected, so the final url is used to resolve the relative links.
+  let base_url = url;
+  let mut page = Vec::new();
+  while let Some(chunk) = response.chunk().await? {
+    page.extend_from_slice(&chunk);
+    if page.len() >= MAX_PAGE_SIZE {
+      break;
+    }
+  }
+
+  let html = String::from_utf8_lossy(&page);
+  Ok(parse_link_preview(&html, &base_url))
+}
+
+/// Returns a client that doesn't follow redirects and connects to the address the host of the
+/// url was checked with, so the host can't resolve to another address when it's connected to.
+async fn public_client(url: &Url) -> Result<reqwest::Client, Error> {
+  if !matches!(url.scheme(), "http" | "https") {
+    return Err(anyhow!("Unsupported url scheme: {}", url.scheme()));
+  }
+  let builder = reqwest::Client::builder()
+    .timeout(FETCH_TIMEOUT)
+    .redirect(Policy::none());
+  let port = url.port_or_known_default().unwrap_or(80);
+  let addrs = match url.host() {
+    None => return Err(anyhow!("The url has no host")),
+    Some(Host::Ipv4(ip)) => vec![SocketAddr::new(IpAddr::V4(ip), port)],
+    Some(Host::Ipv6(ip)) => vec![SocketAddr::new(IpAddr::V6(ip), port)],
+    Some(Host::Domain(domain)) => tokio::net::lookup_host((domain, port)).await?.collect(),
+  };
+  if addrs.is_empty() || !addrs.iter().all(|addr| is_public_ip(addr.ip())) {
+    return Err(anyhow!("The host of {} is not a public address", url));
+  }
+  let builder = match url.domain() {
+    Some(domain) => builder.resolve(domain, addrs[0]),
+    None => builder,
+  };
+  Ok(builder.build()?)
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+  match ip {
+    IpAddr::V4(ip) => {
+      let [a, b, ..] = ip.octets();
+      // 100.64.0.0/10 is the shared address space of the carrier-grade NATs
+      let is_shared = a == 100 && (b & 0xc0) == 64;
+      !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        || is_shared)
+    },
+    IpAddr::V6(ip) => {
+      if let Some(ip) = ip.to_ipv4_mapped() {
+        return is_public_ip(IpAddr::V4(ip));
+      }
+      let first = ip.segments()[0];
+      // fc00::/7 are the unique local addresses and fe80::/10 the link-local ones
+      let is_unique_local = (first & 0xfe00) == 0xfc00;
+      let is_link_local = (first & 0xffc0) == 0xfe80;
+      !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || is_unique_local
+        || is_link_local)
+    },
+  }
+}
+
+fn parse_link_preview(html: &str, base_url: &Url) -> LinkPreview {
+  let document = Html::parse_document(html);
+  let og_title = Selector::parse(r#"meta[property="og:title"]"#).unwrap();
+  let title = Selector::parse("title").unwrap();
+  let title = document
+    .select(&og_title)
+    .find_map(|element| element.value().attr("content"))
+    .map(str::to_string)
+    .or_else(|| {
+      document
+        .select(&title)
+        .next()
+        .map(|element| element.text().collect::<String>())
+    })
+    .map(|title| title.split_whitespace().collect::<Vec<_>>().join(" "))
+    .unwrap_or_default();
+
+  let link = Selector::parse("link[rel][href]").unwrap();
+  let favicon = document
+    .select(&link)
+    .find(|element| {
+      element.value().attr("rel").is_some_and(|rel| {
+        rel
+          .split_whitespace()
+          .any(|rel| rel.eq_ignore_ascii_case("icon"))
+      })
+    })
+    .and_then(|element| element.value().attr("href"))
+    .unwrap_or("/favicon.ico");
+  let favicon = base_url
+    .join(favicon)
+    .map(|url| url.to_string())
+    .unwrap_or_default();
+
+  LinkPreview { title, favicon }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::net::IpAddr;
+
+  use url::Url;
+
+  use super::{fetch_link_preview, is_public_ip, parse_link_preview};
+
+  fn base_url() -> Url {
+    Url::parse("https://appflowy.io/blog/post").unwrap()
+  }
+
+  #[test]
+  fn og_title_test() {
+    let html = r#"<html><head>
+      <title>Page title</title>
+      <meta property="og:title" content="Tom &amp; Jerry" />
+    </head></html>"#;
+    let preview = parse_link_preview(html, &base_url());
+    assert_eq!(preview.title, "Tom & Jerry");
+  }
+
+  #[test]
+  fn title_test() {
+    let html = r#"<html><head><TITLE>
+      AppFlowy &lt;3
+    </TITLE></head><body><title>Not the title</title></body></html>"#;
+    let preview = parse_link_preview(html, &base_url());
+    assert_eq!(preview.title, "AppFlowy <3");
+    assert_eq!(preview.favicon, "https://appflowy.io/favicon.ico");
+  }
+
+  #[test]
+  fn relative_icon_test() {
+    let html = r#"<html><head>
+      <link rel="stylesheet" href="style.css">
+      <link rel="shortcut icon" href="../icons/favicon.png?v=1&amp;s=2">
+    </head></html>"#;
+    let preview = parse_link_preview(html, &base_url());
+    assert_eq!(preview.title, "");
+    assert_eq!(
+      preview.favicon,
+      "https://appflowy.io/icons/favicon.png?v=1&s=2"
+    );
+
+    let html = r#"<link rel=icon href="//cdn.appflowy.io/icon.svg">"#;
+    let preview = parse_link_preview(html, &base_url());
+    assert_eq!(preview.favicon, "https://cdn.appflowy.io/icon.svg");
+  }
+
+  #[test]
+  fn public_ip_test() {
+    for ip in ["93.184.216.34", "2606:2800:220:1:248:1893:25c8:1946"] {
+      assert!(is_public_ip(ip.parse::<IpAddr>().unwrap()), "{}", ip);
+    }
+    for ip in [
+      "127.0.0.1",
+      "10.0.0.1",
+      "172.16.5.4",
+      "192.168.1.1",
+      "169.254.169.254",
+      "100.64.0.1",
+      "0.0.0.0",
+      "::1",
+      "fd00::1",
+      "fe80::1",
+      "::ffff:127.0.0.1",
+    ] {
+      assert!(!is_public_ip(ip.parse::<IpAddr>().unwrap()), "{}", ip);
+    }
+  }
+
+  #[tokio::test]
+  async fn refuse_private_host_test() {
+    for url in [
+      "http://127.0.0.1:8080/",
+      "http://localhost/",
+      "http://[::1]/",
+      "http://192.168.1.1/admin",
+      "file:///etc/passwd",
+    ] {
+      assert!(fetch_link_preview(url).await.is_err(), "{}", url);
+    }
+  }
+}
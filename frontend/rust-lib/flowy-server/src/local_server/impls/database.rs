@@ -5,9 +5,13 @@ use collab_database::workspace_database::default_workspace_database_data;
 use collab_document::document_data::default_document_collab_data;
 use collab_entity::CollabType;
 use collab_user::core::default_user_awareness_data;
-use flowy_database_pub::cloud::{DatabaseCloudService, DatabaseSnapshot, EncodeCollabByOid};
+use flowy_database_pub::cloud::{
+  DatabaseCloudService, DatabaseSnapshot, EncodeCollabByOid, LinkPreview,
+};
 use lib_infra::async_trait::async_trait;
 
+use crate::link_preview::fetch_link_preview;
+
 pub(crate) struct LocalServerDatabaseCloudServiceImpl();
 
 #[async_trait]
@@ -60,4 +64,8 @@ impl DatabaseCloudService for LocalServerDatabaseCloudServiceImpl {
   ) -> Result<Vec<DatabaseSnapshot>, Error> {
     Ok(vec![])
   }
+
+  async fn get_link_preview(&self, url: &str) -> Result<LinkPreview, Error> {
+    fetch_link_preview(url).await
+  }
 }
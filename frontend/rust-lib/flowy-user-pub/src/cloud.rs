@@ -361,6 +361,14 @@ pub trait UserCloudService: Send + Sync + 'static {
   ) -> Result<AFWorkspaceSettings, FlowyError> {
     Err(FlowyError::not_support())
   }
+
+  /// Returns the default values of the feature flags of the workspace, keyed by the flag.
+  async fn get_workspace_feature_flags(
+    &self,
+    workspace_id: &str,
+  ) -> Result<HashMap<String, bool>, FlowyError> {
+    Err(FlowyError::not_support())
+  }
 }
 
 pub type UserUpdateReceiver = tokio::sync::mpsc::Receiver<UserUpdate>;